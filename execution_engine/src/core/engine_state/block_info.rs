@@ -0,0 +1,57 @@
+//! Support for the block context visible to executing contracts.
+use casper_hashing::Digest;
+use casper_types::{BlockTime, EraId};
+
+/// Information about the block in which deploys are executed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlockInfo {
+    block_time: BlockTime,
+    block_height: u64,
+    era_id: EraId,
+    parent_block_hash: Digest,
+    chain_name: String,
+}
+
+impl BlockInfo {
+    /// Creates a new `BlockInfo`.
+    pub fn new(
+        block_time: BlockTime,
+        block_height: u64,
+        era_id: EraId,
+        parent_block_hash: Digest,
+        chain_name: String,
+    ) -> Self {
+        BlockInfo {
+            block_time,
+            block_height,
+            era_id,
+            parent_block_hash,
+            chain_name,
+        }
+    }
+
+    /// Returns the block time.
+    pub fn block_time(&self) -> BlockTime {
+        self.block_time
+    }
+
+    /// Returns the block height.
+    pub fn block_height(&self) -> u64 {
+        self.block_height
+    }
+
+    /// Returns the era id of the block.
+    pub fn era_id(&self) -> EraId {
+        self.era_id
+    }
+
+    /// Returns the hash of the parent block.
+    pub fn parent_block_hash(&self) -> Digest {
+        self.parent_block_hash
+    }
+
+    /// Returns the name of the chain.
+    pub fn chain_name(&self) -> &str {
+        &self.chain_name
+    }
+}
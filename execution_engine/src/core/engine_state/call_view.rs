@@ -0,0 +1,123 @@
+//! Support for evaluating view entry points of stored contracts against global state.
+use casper_hashing::Digest;
+use casper_types::{
+    account::AccountHash, CLValue, ContractHash, Gas, ProtocolVersion, RuntimeArgs,
+};
+
+use crate::core::engine_state::BlockInfo;
+
+/// Represents a request to evaluate a view entry point of a stored contract.
+///
+/// The entry point is executed in a read-only context; any attempt to modify global state causes
+/// the evaluation to fail and nothing is ever committed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallViewRequest {
+    state_hash: Digest,
+    protocol_version: ProtocolVersion,
+    block_info: BlockInfo,
+    caller: AccountHash,
+    contract_hash: ContractHash,
+    entry_point: String,
+    args: RuntimeArgs,
+    gas_limit: Gas,
+}
+
+impl CallViewRequest {
+    /// Creates new request.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        state_hash: Digest,
+        protocol_version: ProtocolVersion,
+        block_info: BlockInfo,
+        caller: AccountHash,
+        contract_hash: ContractHash,
+        entry_point: String,
+        args: RuntimeArgs,
+        gas_limit: Gas,
+    ) -> Self {
+        CallViewRequest {
+            state_hash,
+            protocol_version,
+            block_info,
+            caller,
+            contract_hash,
+            entry_point,
+            args,
+            gas_limit,
+        }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns protocol version.
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    /// Returns the block context visible to the view entry point.
+    pub fn block_info(&self) -> &BlockInfo {
+        &self.block_info
+    }
+
+    /// Returns the account on whose behalf the view entry point is evaluated.
+    pub fn caller(&self) -> AccountHash {
+        self.caller
+    }
+
+    /// Returns the hash of the contract being called.
+    pub fn contract_hash(&self) -> ContractHash {
+        self.contract_hash
+    }
+
+    /// Returns the name of the view entry point.
+    pub fn entry_point(&self) -> &str {
+        &self.entry_point
+    }
+
+    /// Returns the runtime arguments passed to the view entry point.
+    pub fn args(&self) -> &RuntimeArgs {
+        &self.args
+    }
+
+    /// Returns the maximum amount of gas the evaluation may consume.
+    pub fn gas_limit(&self) -> Gas {
+        self.gas_limit
+    }
+}
+
+/// Represents a result of a `call_view` request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallViewResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// The calling account does not exist under the given state root hash.
+    CallerNotFound,
+    /// Evaluation of the view entry point failed.
+    Failure {
+        /// The reason for the failure.
+        error: String,
+        /// The gas consumed before the failure.
+        cost: Gas,
+    },
+    /// The value returned by the view entry point.
+    Success {
+        /// The returned value.
+        value: CLValue,
+        /// The gas consumed by the evaluation.
+        cost: Gas,
+    },
+}
+
+impl CallViewResult {
+    /// Returns the returned [`CLValue`] if this represents a successful evaluation.
+    pub fn into_success(self) -> Option<CLValue> {
+        if let Self::Success { value, .. } = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
@@ -26,6 +26,15 @@ pub struct DeployItem {
     /// A unique identifier of the deploy.
     /// Currently it is the hash of the deploy header (see `DeployHeader` in the `types` crate).
     pub deploy_hash: DeployHash,
+    /// Address of the account paying for this deploy, if other than `address`.
+    ///
+    /// The fee payer's main purse funds standard payment, and refunds are paid back to it.
+    pub fee_payer: Option<AccountHash>,
+    /// Sequence number of this deploy among the deploys of `address`, if any.
+    ///
+    /// A deploy carrying a sequence number only executes if it matches the next sequence number
+    /// expected from its account.
+    pub sequence_number: Option<u64>,
 }
 
 impl DeployItem {
@@ -45,6 +54,20 @@ impl DeployItem {
             gas_price,
             authorization_keys,
             deploy_hash,
+            fee_payer: None,
+            sequence_number: None,
         }
     }
+
+    /// Returns a new [`DeployItem`] whose payment is funded by the given fee payer account.
+    pub fn with_fee_payer(mut self, fee_payer: AccountHash) -> Self {
+        self.fee_payer = Some(fee_payer);
+        self
+    }
+
+    /// Returns a new [`DeployItem`] carrying the given account sequence number.
+    pub fn with_sequence_number(mut self, sequence_number: u64) -> Self {
+        self.sequence_number = Some(sequence_number);
+        self
+    }
 }
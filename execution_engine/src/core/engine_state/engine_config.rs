@@ -8,7 +8,7 @@ use std::collections::BTreeSet;
 use num_rational::Ratio;
 use num_traits::One;
 
-use casper_types::{account::AccountHash, PublicKey};
+use casper_types::{account::AccountHash, ProtocolVersion, PublicKey};
 
 use crate::shared::{system_config::SystemConfig, wasm_config::WasmConfig};
 
@@ -39,6 +39,8 @@ pub const DEFAULT_VESTING_SCHEDULE_LENGTH_MILLIS: u64 =
     VESTING_SCHEDULE_LENGTH_DAYS as u64 * DAY_MILLIS as u64;
 /// Default value for allowing auction bids.
 pub const DEFAULT_ALLOW_AUCTION_BIDS: bool = true;
+/// Default number of eras a delegation rate increase is delayed by.
+pub const DEFAULT_DELEGATION_RATE_CHANGE_NOTICE_ERAS: u64 = 0;
 /// Default value for allowing unrestricted transfers.
 pub const DEFAULT_ALLOW_UNRESTRICTED_TRANSFERS: bool = true;
 /// Default gas cost refund ratio.
@@ -47,6 +49,18 @@ pub const DEFAULT_REFUND_HANDLING: RefundHandling = RefundHandling::Refund {
 };
 /// Default fee handling.
 pub const DEFAULT_FEE_HANDLING: FeeHandling = FeeHandling::PayToProposer;
+/// Default protocol version from which deploy sequence numbers are enforced.
+pub const DEFAULT_START_PROTOCOL_VERSION_WITH_SEQUENCE_NUMBERS: ProtocolVersion =
+    ProtocolVersion::V1_0_0;
+/// Default protocol version from which contract migrations are run.
+pub const DEFAULT_START_PROTOCOL_VERSION_WITH_CONTRACT_MIGRATIONS: ProtocolVersion =
+    ProtocolVersion::V1_0_0;
+/// Default protocol version from which contract invocations may be scheduled.
+pub const DEFAULT_START_PROTOCOL_VERSION_WITH_SCHEDULED_INVOCATIONS: ProtocolVersion =
+    ProtocolVersion::V1_0_0;
+/// Default protocol version from which BLS validator keys are accepted.
+pub const DEFAULT_START_PROTOCOL_VERSION_WITH_AGGREGATE_FINALITY_SIGNATURES: ProtocolVersion =
+    ProtocolVersion::V1_0_0;
 
 /// The runtime configuration of the execution engine
 #[derive(Debug, Clone)]
@@ -71,6 +85,8 @@ pub struct EngineConfig {
     /// Auction entrypoints such as "add_bid" or "delegate" are disabled if this flag is set to
     /// `false`.
     pub(crate) allow_auction_bids: bool,
+    /// Number of eras a validator's delegation rate increase is delayed by before it applies.
+    delegation_rate_change_notice_eras: u64,
     /// Allow unrestricted transfers between normal accounts.
     ///
     /// If set to `true` accounts can transfer tokens between themselves without restrictions. If
@@ -81,6 +97,14 @@ pub struct EngineConfig {
     pub(crate) refund_handling: RefundHandling,
     /// Fee handling.
     pub(crate) fee_handling: FeeHandling,
+    /// Protocol version from which deploys may carry an account sequence number.
+    start_protocol_version_with_sequence_numbers: ProtocolVersion,
+    /// Protocol version from which adding a contract version runs its `migrate` entry point.
+    start_protocol_version_with_contract_migrations: ProtocolVersion,
+    /// Protocol version from which contract invocations may be scheduled.
+    start_protocol_version_with_scheduled_invocations: ProtocolVersion,
+    /// Protocol version from which BLS validator keys are accepted.
+    start_protocol_version_with_aggregate_finality_signatures: ProtocolVersion,
 }
 
 impl Default for EngineConfig {
@@ -97,9 +121,18 @@ impl Default for EngineConfig {
             system_config: SystemConfig::default(),
             administrative_accounts: Default::default(),
             allow_auction_bids: DEFAULT_ALLOW_AUCTION_BIDS,
+            delegation_rate_change_notice_eras: DEFAULT_DELEGATION_RATE_CHANGE_NOTICE_ERAS,
             allow_unrestricted_transfers: DEFAULT_ALLOW_UNRESTRICTED_TRANSFERS,
             refund_handling: DEFAULT_REFUND_HANDLING,
             fee_handling: DEFAULT_FEE_HANDLING,
+            start_protocol_version_with_sequence_numbers:
+                DEFAULT_START_PROTOCOL_VERSION_WITH_SEQUENCE_NUMBERS,
+            start_protocol_version_with_contract_migrations:
+                DEFAULT_START_PROTOCOL_VERSION_WITH_CONTRACT_MIGRATIONS,
+            start_protocol_version_with_scheduled_invocations:
+                DEFAULT_START_PROTOCOL_VERSION_WITH_SCHEDULED_INVOCATIONS,
+            start_protocol_version_with_aggregate_finality_signatures:
+                DEFAULT_START_PROTOCOL_VERSION_WITH_AGGREGATE_FINALITY_SIGNATURES,
         }
     }
 }
@@ -137,9 +170,18 @@ impl EngineConfig {
             system_config,
             administrative_accounts: Default::default(),
             allow_auction_bids: DEFAULT_ALLOW_AUCTION_BIDS,
+            delegation_rate_change_notice_eras: DEFAULT_DELEGATION_RATE_CHANGE_NOTICE_ERAS,
             allow_unrestricted_transfers: DEFAULT_ALLOW_UNRESTRICTED_TRANSFERS,
             refund_handling: DEFAULT_REFUND_HANDLING,
             fee_handling: DEFAULT_FEE_HANDLING,
+            start_protocol_version_with_sequence_numbers:
+                DEFAULT_START_PROTOCOL_VERSION_WITH_SEQUENCE_NUMBERS,
+            start_protocol_version_with_contract_migrations:
+                DEFAULT_START_PROTOCOL_VERSION_WITH_CONTRACT_MIGRATIONS,
+            start_protocol_version_with_scheduled_invocations:
+                DEFAULT_START_PROTOCOL_VERSION_WITH_SCHEDULED_INVOCATIONS,
+            start_protocol_version_with_aggregate_finality_signatures:
+                DEFAULT_START_PROTOCOL_VERSION_WITH_AGGREGATE_FINALITY_SIGNATURES,
         }
     }
 
@@ -193,6 +235,11 @@ impl EngineConfig {
         self.allow_auction_bids
     }
 
+    /// Returns the number of eras a delegation rate increase is delayed by.
+    pub fn delegation_rate_change_notice_eras(&self) -> u64 {
+        self.delegation_rate_change_notice_eras
+    }
+
     /// Returns true if unrestricted transfers are allowed.
     pub fn allow_unrestricted_transfers(&self) -> bool {
         self.allow_unrestricted_transfers
@@ -212,6 +259,26 @@ impl EngineConfig {
     pub fn fee_handling(&self) -> FeeHandling {
         self.fee_handling
     }
+
+    /// Returns the protocol version from which deploys may carry an account sequence number.
+    pub fn start_protocol_version_with_sequence_numbers(&self) -> ProtocolVersion {
+        self.start_protocol_version_with_sequence_numbers
+    }
+
+    /// Returns the protocol version from which contract versions are migrated when added.
+    pub fn start_protocol_version_with_contract_migrations(&self) -> ProtocolVersion {
+        self.start_protocol_version_with_contract_migrations
+    }
+
+    /// Returns the protocol version from which contract invocations may be scheduled.
+    pub fn start_protocol_version_with_scheduled_invocations(&self) -> ProtocolVersion {
+        self.start_protocol_version_with_scheduled_invocations
+    }
+
+    /// Returns the protocol version from which BLS validator keys are accepted.
+    pub fn start_protocol_version_with_aggregate_finality_signatures(&self) -> ProtocolVersion {
+        self.start_protocol_version_with_aggregate_finality_signatures
+    }
 }
 
 /// A builder for an [`EngineConfig`].
@@ -231,9 +298,14 @@ pub struct EngineConfigBuilder {
     system_config: Option<SystemConfig>,
     administrative_accounts: Option<BTreeSet<PublicKey>>,
     allow_auction_bids: Option<bool>,
+    delegation_rate_change_notice_eras: Option<u64>,
     allow_unrestricted_transfers: Option<bool>,
     refund_handling: Option<RefundHandling>,
     fee_handling: Option<FeeHandling>,
+    start_protocol_version_with_sequence_numbers: Option<ProtocolVersion>,
+    start_protocol_version_with_contract_migrations: Option<ProtocolVersion>,
+    start_protocol_version_with_scheduled_invocations: Option<ProtocolVersion>,
+    start_protocol_version_with_aggregate_finality_signatures: Option<ProtocolVersion>,
 }
 
 impl EngineConfigBuilder {
@@ -321,6 +393,15 @@ impl EngineConfigBuilder {
         self
     }
 
+    /// Sets the delegation rate change notice period config option.
+    pub fn with_delegation_rate_change_notice_eras(
+        mut self,
+        delegation_rate_change_notice_eras: u64,
+    ) -> Self {
+        self.delegation_rate_change_notice_eras = Some(delegation_rate_change_notice_eras);
+        self
+    }
+
     /// Sets the allow unrestricted transfers config option.
     pub fn with_allow_unrestricted_transfers(mut self, allow_unrestricted_transfers: bool) -> Self {
         self.allow_unrestricted_transfers = Some(allow_unrestricted_transfers);
@@ -348,6 +429,42 @@ impl EngineConfigBuilder {
         self
     }
 
+    /// Sets the protocol version from which deploy sequence numbers are enforced.
+    pub fn with_start_protocol_version_with_sequence_numbers(
+        mut self,
+        protocol_version: ProtocolVersion,
+    ) -> Self {
+        self.start_protocol_version_with_sequence_numbers = Some(protocol_version);
+        self
+    }
+
+    /// Sets the protocol version from which contract versions are migrated when added.
+    pub fn with_start_protocol_version_with_contract_migrations(
+        mut self,
+        protocol_version: ProtocolVersion,
+    ) -> Self {
+        self.start_protocol_version_with_contract_migrations = Some(protocol_version);
+        self
+    }
+
+    /// Sets the protocol version from which contract invocations may be scheduled.
+    pub fn with_start_protocol_version_with_scheduled_invocations(
+        mut self,
+        protocol_version: ProtocolVersion,
+    ) -> Self {
+        self.start_protocol_version_with_scheduled_invocations = Some(protocol_version);
+        self
+    }
+
+    /// Sets the protocol version from which BLS validator keys are accepted.
+    pub fn with_start_protocol_version_with_aggregate_finality_signatures(
+        mut self,
+        protocol_version: ProtocolVersion,
+    ) -> Self {
+        self.start_protocol_version_with_aggregate_finality_signatures = Some(protocol_version);
+        self
+    }
+
     /// Builds a new [`EngineConfig`] object.
     pub fn build(self) -> EngineConfig {
         let max_query_depth = self.max_query_depth.unwrap_or(DEFAULT_MAX_QUERY_DEPTH);
@@ -372,11 +489,26 @@ impl EngineConfigBuilder {
         let allow_auction_bids = self
             .allow_auction_bids
             .unwrap_or(DEFAULT_ALLOW_AUCTION_BIDS);
+        let delegation_rate_change_notice_eras = self
+            .delegation_rate_change_notice_eras
+            .unwrap_or(DEFAULT_DELEGATION_RATE_CHANGE_NOTICE_ERAS);
         let allow_unrestricted_transfers = self
             .allow_unrestricted_transfers
             .unwrap_or(DEFAULT_ALLOW_UNRESTRICTED_TRANSFERS);
         let refund_handling = self.refund_handling.unwrap_or(DEFAULT_REFUND_HANDLING);
         let fee_handling = self.fee_handling.unwrap_or(DEFAULT_FEE_HANDLING);
+        let start_protocol_version_with_sequence_numbers = self
+            .start_protocol_version_with_sequence_numbers
+            .unwrap_or(DEFAULT_START_PROTOCOL_VERSION_WITH_SEQUENCE_NUMBERS);
+        let start_protocol_version_with_contract_migrations = self
+            .start_protocol_version_with_contract_migrations
+            .unwrap_or(DEFAULT_START_PROTOCOL_VERSION_WITH_CONTRACT_MIGRATIONS);
+        let start_protocol_version_with_scheduled_invocations = self
+            .start_protocol_version_with_scheduled_invocations
+            .unwrap_or(DEFAULT_START_PROTOCOL_VERSION_WITH_SCHEDULED_INVOCATIONS);
+        let start_protocol_version_with_aggregate_finality_signatures = self
+            .start_protocol_version_with_aggregate_finality_signatures
+            .unwrap_or(DEFAULT_START_PROTOCOL_VERSION_WITH_AGGREGATE_FINALITY_SIGNATURES);
 
         let strict_argument_checking = self
             .strict_argument_checking
@@ -395,9 +527,14 @@ impl EngineConfigBuilder {
             system_config,
             administrative_accounts,
             allow_auction_bids,
+            delegation_rate_change_notice_eras,
             allow_unrestricted_transfers,
             refund_handling,
             fee_handling,
+            start_protocol_version_with_sequence_numbers,
+            start_protocol_version_with_contract_migrations,
+            start_protocol_version_with_scheduled_invocations,
+            start_protocol_version_with_aggregate_finality_signatures,
             strict_argument_checking,
             vesting_schedule_period_millis,
             max_delegators_per_validator,
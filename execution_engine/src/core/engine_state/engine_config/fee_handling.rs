@@ -1,4 +1,5 @@
 use datasize::DataSize;
+use num_rational::Ratio;
 use serde::{Deserialize, Serialize};
 
 use casper_types::bytesrepr::{self, FromBytes, ToBytes};
@@ -6,6 +7,7 @@ use casper_types::bytesrepr::{self, FromBytes, ToBytes};
 const FEE_HANDLING_PROPOSER_TAG: u8 = 0;
 const FEE_HANDLING_ACCUMULATE_TAG: u8 = 1;
 const FEE_HANDLING_BURN_TAG: u8 = 2;
+const FEE_HANDLING_SPLIT_TAG: u8 = 3;
 
 /// Defines how fees are handled in the system.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, DataSize)]
@@ -22,6 +24,17 @@ pub enum FeeHandling {
     Accumulate,
     /// Burn the fees.
     Burn,
+    /// A fraction of the transaction fees is burned, and the rest is paid to the block proposer.
+    Split {
+        /// Computes how much of the fee is burned.
+        ///
+        /// burned_part = fee * burn_ratio
+        /// proposer_part = fee - burned_part
+        ///
+        /// Any dust amount that was a result of multiplying by burn_ratio goes to the proposer.
+        #[data_size(skip)]
+        burn_ratio: Ratio<u64>,
+    },
 }
 
 impl ToBytes for FeeHandling {
@@ -30,11 +43,20 @@ impl ToBytes for FeeHandling {
             FeeHandling::PayToProposer => Ok(vec![FEE_HANDLING_PROPOSER_TAG]),
             FeeHandling::Accumulate => Ok(vec![FEE_HANDLING_ACCUMULATE_TAG]),
             FeeHandling::Burn => Ok(vec![FEE_HANDLING_BURN_TAG]),
+            FeeHandling::Split { burn_ratio } => {
+                let mut buffer = bytesrepr::allocate_buffer(self)?;
+                buffer.push(FEE_HANDLING_SPLIT_TAG);
+                buffer.extend(burn_ratio.to_bytes()?);
+                Ok(buffer)
+            }
         }
     }
 
     fn serialized_length(&self) -> usize {
-        1
+        1 + match self {
+            FeeHandling::PayToProposer | FeeHandling::Accumulate | FeeHandling::Burn => 0,
+            FeeHandling::Split { burn_ratio } => burn_ratio.serialized_length(),
+        }
     }
 }
 
@@ -45,6 +67,10 @@ impl FromBytes for FeeHandling {
             FEE_HANDLING_PROPOSER_TAG => Ok((FeeHandling::PayToProposer, rem)),
             FEE_HANDLING_ACCUMULATE_TAG => Ok((FeeHandling::Accumulate, rem)),
             FEE_HANDLING_BURN_TAG => Ok((FeeHandling::Burn, rem)),
+            FEE_HANDLING_SPLIT_TAG => {
+                let (burn_ratio, rem) = FromBytes::from_bytes(rem)?;
+                Ok((FeeHandling::Split { burn_ratio }, rem))
+            }
             _ => Err(bytesrepr::Error::Formatting),
         }
     }
@@ -71,4 +97,12 @@ mod tests {
         let fee_config = FeeHandling::Burn;
         bytesrepr::test_serialization_roundtrip(&fee_config);
     }
+
+    #[test]
+    fn bytesrepr_roundtrip_for_split() {
+        let fee_config = FeeHandling::Split {
+            burn_ratio: Ratio::new(1, 3),
+        };
+        bytesrepr::test_serialization_roundtrip(&fee_config);
+    }
 }
@@ -106,6 +106,20 @@ pub enum Error {
     /// Failed to retrieve accumulation purse from handle payment system contract.
     #[error("Failed to retrieve accumulation purse from the handle payment contract")]
     FailedToRetrieveAccumulationPurse,
+    /// A fee payer was specified for a deploy which doesn't use standard payment.
+    #[error("Fee payer is only supported for deploys using standard payment")]
+    UnsupportedFeePayer,
+    /// A sequence number was specified for a deploy before sequence numbers were activated.
+    #[error("Deploy sequence numbers are not supported in protocol version {0}")]
+    UnsupportedSequenceNumber(ProtocolVersion),
+    /// The deploy's sequence number is not the next one expected from its account.
+    #[error("Invalid sequence number: expected {expected}, got {got}")]
+    InvalidSequenceNumber {
+        /// The next sequence number expected from the account.
+        expected: u64,
+        /// The sequence number of the deploy.
+        got: u64,
+    },
 }
 
 impl Error {
@@ -2,9 +2,10 @@
 use std::mem;
 
 use casper_hashing::Digest;
-use casper_types::{ProtocolVersion, PublicKey, SecretKey};
+use casper_types::{BlockTime, EraId, ProtocolVersion, PublicKey, SecretKey};
 
-use super::deploy_item::DeployItem;
+use super::{block_info::BlockInfo, deploy_item::DeployItem};
+use crate::shared::gas_profile::GasProfiler;
 
 /// Represents an execution request that can contain multiple deploys.
 #[derive(Debug)]
@@ -13,19 +14,35 @@ pub struct ExecuteRequest {
     pub parent_state_hash: Digest,
     /// Block time represented as a unix timestamp.
     pub block_time: u64,
+    /// Height of the block in which the deploys are executed.
+    pub block_height: u64,
+    /// Era of the block in which the deploys are executed.
+    pub era_id: EraId,
+    /// Hash of the parent of the block in which the deploys are executed.
+    pub parent_block_hash: Digest,
+    /// Name of the chain on which the deploys are executed.
+    pub chain_name: String,
     /// List of deploys that will be executed as part of this request.
     pub deploys: Vec<DeployItem>,
     /// Protocol version used to execute deploys from the list.
     pub protocol_version: ProtocolVersion,
     /// The owner of the node that proposed the block containing this request.
     pub proposer: PublicKey,
+    /// Profiler to which the gas charged by the payment and session code of the deploys is
+    /// attributed, if any.
+    pub gas_profiler: Option<GasProfiler>,
 }
 
 impl ExecuteRequest {
     /// Creates new execute request.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         parent_state_hash: Digest,
         block_time: u64,
+        block_height: u64,
+        era_id: EraId,
+        parent_block_hash: Digest,
+        chain_name: String,
         deploys: Vec<DeployItem>,
         protocol_version: ProtocolVersion,
         proposer: PublicKey,
@@ -33,9 +50,14 @@ impl ExecuteRequest {
         Self {
             parent_state_hash,
             block_time,
+            block_height,
+            era_id,
+            parent_block_hash,
+            chain_name,
             deploys,
             protocol_version,
             proposer,
+            gas_profiler: None,
         }
     }
 
@@ -48,6 +70,17 @@ impl ExecuteRequest {
     pub fn deploys(&self) -> &Vec<DeployItem> {
         &self.deploys
     }
+
+    /// Returns the block context in which the deploys are executed.
+    pub fn block_info(&self) -> BlockInfo {
+        BlockInfo::new(
+            BlockTime::new(self.block_time),
+            self.block_height,
+            self.era_id,
+            self.parent_block_hash,
+            self.chain_name.clone(),
+        )
+    }
 }
 
 impl Default for ExecuteRequest {
@@ -58,9 +91,14 @@ impl Default for ExecuteRequest {
         Self {
             parent_state_hash: Digest::hash([]),
             block_time: 0,
+            block_height: 0,
+            era_id: EraId::default(),
+            parent_block_hash: Digest::default(),
+            chain_name: String::new(),
             deploys: vec![],
             protocol_version: Default::default(),
             proposer,
+            gas_profiler: None,
         }
     }
 }
@@ -3,7 +3,8 @@
 use std::collections::VecDeque;
 
 use casper_types::{
-    bytesrepr::FromBytes, CLTyped, CLValue, Gas, Key, Motes, StoredValue, TransferAddr,
+    bytesrepr::FromBytes, CLTyped, CLValue, FeeBreakdown, Gas, Key, Motes, StoredValue,
+    TransferAddr,
 };
 
 use super::error;
@@ -49,6 +50,8 @@ pub enum ExecutionResult {
         cost: Gas,
         /// Journal of execution.
         execution_journal: ExecutionJournal,
+        /// How the fee was split between the block proposer and burning, if fees are split.
+        fee_breakdown: Option<FeeBreakdown>,
     },
     /// Execution was finished successfully
     Success {
@@ -58,6 +61,8 @@ pub enum ExecutionResult {
         cost: Gas,
         /// Journal of execution.
         execution_journal: ExecutionJournal,
+        /// How the fee was split between the block proposer and burning, if fees are split.
+        fee_breakdown: Option<FeeBreakdown>,
     },
 }
 
@@ -67,6 +72,7 @@ impl Default for ExecutionResult {
             execution_journal: Default::default(),
             transfers: Default::default(),
             cost: Default::default(),
+            fee_breakdown: None,
         }
     }
 }
@@ -94,6 +100,7 @@ impl ExecutionResult {
             transfers: Vec::default(),
             cost: Gas::default(),
             execution_journal: Default::default(),
+            fee_breakdown: None,
         }
     }
 
@@ -156,6 +163,14 @@ impl ExecutionResult {
         }
     }
 
+    /// Returns how the fee was split between the block proposer and burning regardless of variant.
+    pub fn fee_breakdown(&self) -> Option<&FeeBreakdown> {
+        match self {
+            ExecutionResult::Failure { fee_breakdown, .. } => fee_breakdown.as_ref(),
+            ExecutionResult::Success { fee_breakdown, .. } => fee_breakdown.as_ref(),
+        }
+    }
+
     /// Returns a new execution result with updated gas cost.
     ///
     /// This method preserves the [`ExecutionResult`] variant and updates the cost field
@@ -166,21 +181,25 @@ impl ExecutionResult {
                 error,
                 transfers,
                 execution_journal,
+                fee_breakdown,
                 ..
             } => ExecutionResult::Failure {
                 error,
                 transfers,
                 cost,
                 execution_journal,
+                fee_breakdown,
             },
             ExecutionResult::Success {
                 transfers,
                 execution_journal,
+                fee_breakdown,
                 ..
             } => ExecutionResult::Success {
                 transfers,
                 cost,
                 execution_journal,
+                fee_breakdown,
             },
         }
     }
@@ -195,21 +214,25 @@ impl ExecutionResult {
                 error,
                 cost,
                 execution_journal,
+                fee_breakdown,
                 ..
             } => ExecutionResult::Failure {
                 error,
                 transfers,
                 cost,
                 execution_journal,
+                fee_breakdown,
             },
             ExecutionResult::Success {
                 cost,
                 execution_journal,
+                fee_breakdown,
                 ..
             } => ExecutionResult::Success {
                 transfers,
                 cost,
                 execution_journal,
+                fee_breakdown,
             },
         }
     }
@@ -225,20 +248,70 @@ impl ExecutionResult {
                 transfers,
                 cost,
                 execution_journal: _,
+                fee_breakdown,
             } => ExecutionResult::Failure {
                 error,
                 transfers,
                 cost,
                 execution_journal,
+                fee_breakdown,
             },
             ExecutionResult::Success {
                 transfers,
                 cost,
                 execution_journal: _,
+                fee_breakdown,
+            } => ExecutionResult::Success {
+                transfers,
+                cost,
+                execution_journal,
+                fee_breakdown,
+            },
+        }
+    }
+
+    /// Returns a new execution result with the given transform appended to its execution journal.
+    pub(crate) fn with_transform(mut self, key: Key, transform: Transform) -> Self {
+        match &mut self {
+            ExecutionResult::Failure {
+                execution_journal, ..
+            }
+            | ExecutionResult::Success {
+                execution_journal, ..
+            } => execution_journal.push((key, transform)),
+        }
+        self
+    }
+
+    /// Returns a new execution result with the given fee breakdown.
+    ///
+    /// This method preserves the [`ExecutionResult`] variant and updates the
+    /// `fee_breakdown` field only.
+    pub fn with_fee_breakdown(self, fee_breakdown: FeeBreakdown) -> Self {
+        match self {
+            ExecutionResult::Failure {
+                error,
+                transfers,
+                cost,
+                execution_journal,
+                ..
+            } => ExecutionResult::Failure {
+                error,
+                transfers,
+                cost,
+                execution_journal,
+                fee_breakdown: Some(fee_breakdown),
+            },
+            ExecutionResult::Success {
+                transfers,
+                cost,
+                execution_journal,
+                ..
             } => ExecutionResult::Success {
                 transfers,
                 cost,
                 execution_journal,
+                fee_breakdown: Some(fee_breakdown),
             },
         }
     }
@@ -328,6 +401,7 @@ impl ExecutionResult {
             execution_journal,
             transfers,
             cost: gas_cost,
+            fee_breakdown: None,
         })
     }
 
@@ -349,21 +423,25 @@ impl From<&ExecutionResult> for casper_types::ExecutionResult {
                 transfers,
                 cost,
                 execution_journal,
+                fee_breakdown,
             } => casper_types::ExecutionResult::Success {
                 effect: execution_journal.into(),
                 transfers: transfers.clone(),
                 cost: cost.value(),
+                fee_breakdown: *fee_breakdown,
             },
             ExecutionResult::Failure {
                 error,
                 transfers,
                 cost,
                 execution_journal,
+                fee_breakdown,
             } => casper_types::ExecutionResult::Failure {
                 effect: execution_journal.into(),
                 transfers: transfers.clone(),
                 cost: cost.value(),
                 error_message: error.to_string(),
+                fee_breakdown: *fee_breakdown,
             },
         }
     }
@@ -376,21 +454,25 @@ impl From<ExecutionResult> for casper_types::ExecutionResult {
                 transfers,
                 cost,
                 execution_journal,
+                fee_breakdown,
             } => casper_types::ExecutionResult::Success {
                 effect: execution_journal.into(),
                 transfers,
                 cost: cost.value(),
+                fee_breakdown,
             },
             ExecutionResult::Failure {
                 error,
                 transfers,
                 cost,
                 execution_journal,
+                fee_breakdown,
             } => casper_types::ExecutionResult::Failure {
                 effect: execution_journal.into(),
                 transfers,
                 cost: cost.value(),
                 error_message: error.to_string(),
+                fee_breakdown,
             },
         }
     }
@@ -500,8 +582,7 @@ impl ExecutionResultBuilder {
             Some(ExecutionResult::Failure {
                 error: session_error,
                 transfers: session_transfers,
-                execution_journal: _,
-                cost: _,
+                ..
             }) => {
                 error = Some(session_error);
                 transfers = session_transfers;
@@ -530,12 +611,14 @@ impl ExecutionResultBuilder {
                 transfers,
                 cost,
                 execution_journal: journal,
+                fee_breakdown: None,
             }),
             Some(error) => Ok(ExecutionResult::Failure {
                 error,
                 transfers,
                 cost,
                 execution_journal: journal,
+                fee_breakdown: None,
             }),
         }
     }
@@ -1356,8 +1356,20 @@ where
             initial_seigniorage_recipients_uref.into(),
         );
 
-        for (validator_public_key, bid) in validators.into_iter() {
+        for (validator_public_key, mut bid) in validators.into_iter() {
             let validator_account_hash = AccountHash::from(&validator_public_key);
+            // Delegators are stored separately from the bid they delegate to.
+            let delegators =
+                bid.take_delegators()
+                    .map_err(|_| GenesisError::InvalidDelegatedAmount {
+                        public_key: validator_public_key.clone(),
+                    })?;
+            for delegator in delegators {
+                self.tracking_copy.borrow_mut().write(
+                    Key::Delegator(delegator.delegator_addr()),
+                    StoredValue::Delegator(Box::new(delegator)),
+                );
+            }
             self.tracking_copy.borrow_mut().write(
                 Key::Bid(validator_account_hash),
                 StoredValue::Bid(Box::new(bid)),
@@ -0,0 +1,68 @@
+//! Support for obtaining a page of the delegators of a single validator.
+use casper_hashing::Digest;
+use casper_types::{account::AccountHash, system::auction::Delegator, PublicKey};
+
+/// Represents a request to obtain a page of the delegators of a validator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetDelegatorsRequest {
+    state_hash: Digest,
+    validator_public_key: PublicKey,
+    cursor: Option<AccountHash>,
+    page_size: u32,
+}
+
+impl GetDelegatorsRequest {
+    /// Creates new request.
+    pub fn new(
+        state_hash: Digest,
+        validator_public_key: PublicKey,
+        cursor: Option<AccountHash>,
+        page_size: u32,
+    ) -> Self {
+        GetDelegatorsRequest {
+            state_hash,
+            validator_public_key,
+            cursor,
+            page_size,
+        }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns the public key of the validator whose delegators are requested.
+    pub fn validator_public_key(&self) -> &PublicKey {
+        &self.validator_public_key
+    }
+
+    /// Returns the account hash of the last delegator of the previous page, after which the
+    /// requested page starts. `None` for the first page.
+    pub fn cursor(&self) -> Option<AccountHash> {
+        self.cursor
+    }
+
+    /// Returns the maximum number of delegators in a page.
+    pub fn page_size(&self) -> u32 {
+        self.page_size
+    }
+}
+
+/// Represents a result of a `get_delegators` request.
+#[derive(Debug)]
+pub enum GetDelegatorsResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// The validator has no bid under the given state root hash.
+    ValidatorNotFound,
+    /// Contains the requested page of delegators.
+    Success {
+        /// Delegators on the requested page, ordered by delegator account hash.
+        delegators: Vec<Delegator>,
+        /// Total number of delegators of the validator.
+        total: u64,
+        /// The cursor to request the next page with, or `None` if this is the last page.
+        next_cursor: Option<AccountHash>,
+    },
+}
@@ -0,0 +1,49 @@
+//! Support for obtaining the pending unbonding and withdraw purses of a validator.
+use casper_hashing::Digest;
+use casper_types::{account::AccountHash, system::auction::UnbondingPurse};
+
+/// Represents a request to obtain the pending unbonding and withdraw purses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetUnbondingQueueRequest {
+    state_hash: Digest,
+    validator: Option<AccountHash>,
+}
+
+impl GetUnbondingQueueRequest {
+    /// Creates new request.
+    ///
+    /// If `validator` is `None`, the purses of all validators are requested.
+    pub fn new(state_hash: Digest, validator: Option<AccountHash>) -> Self {
+        GetUnbondingQueueRequest {
+            state_hash,
+            validator,
+        }
+    }
+
+    /// Returns state root hash.
+    pub fn state_hash(&self) -> Digest {
+        self.state_hash
+    }
+
+    /// Returns the account hash of the validator whose purses, including those of its
+    /// delegators, are requested, if any.
+    pub fn validator(&self) -> Option<AccountHash> {
+        self.validator
+    }
+}
+
+/// Represents a result of a `get_unbonding_queue` request.
+#[derive(Debug)]
+pub enum GetUnbondingQueueResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// Contains the pending purses.
+    Success {
+        /// The number of eras after its era of creation at whose end a purse is released, as
+        /// currently recorded by the auction contract.
+        unbonding_delay: u64,
+        /// The pending purses, including any legacy withdraw purses, ordered by validator and then
+        /// by era of creation.
+        unbonding_purses: Vec<UnbondingPurse>,
+    },
+}
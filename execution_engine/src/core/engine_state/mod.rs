@@ -1,5 +1,7 @@
 //!  This module contains all the execution related code.
 pub mod balance;
+pub mod block_info;
+pub mod call_view;
 pub mod chainspec_registry;
 pub mod checksum_registry;
 pub mod deploy_item;
@@ -12,10 +14,13 @@ pub mod execution_effect;
 pub mod execution_result;
 pub mod genesis;
 pub mod get_bids;
+pub mod get_delegators;
+pub mod get_unbonding_queue;
 pub mod op;
 mod prune;
 pub mod query;
 pub mod run_genesis_request;
+pub mod scheduled_invocations;
 pub mod step;
 pub mod system_contract_registry;
 mod transfer;
@@ -25,6 +30,7 @@ use std::{
     cell::RefCell,
     collections::{BTreeMap, BTreeSet},
     convert::TryFrom,
+    iter,
     rc::Rc,
 };
 
@@ -36,25 +42,29 @@ use tracing::{debug, error, trace, warn};
 use casper_hashing::Digest;
 use casper_types::{
     account::{Account, AccountHash},
-    bytesrepr::ToBytes,
+    bytesrepr::{Bytes, ToBytes},
     contracts::NamedKeys,
     system::{
         auction::{
-            EraValidators, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_EVICTED_VALIDATORS,
-            ARG_REWARD_FACTORS, ARG_VALIDATOR_PUBLIC_KEYS, AUCTION_DELAY_KEY,
-            LOCKED_FUNDS_PERIOD_KEY, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, UNBONDING_DELAY_KEY,
-            VALIDATOR_SLOTS_KEY,
+            Delegator, EraValidators, UnbondingPurse, ARG_ERA_END_TIMESTAMP_MILLIS,
+            ARG_EVICTED_VALIDATORS, ARG_REWARD_FACTORS, ARG_VALIDATOR_PUBLIC_KEYS,
+            AUCTION_DELAY_KEY, LOCKED_FUNDS_PERIOD_KEY, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY,
+            UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
         },
         handle_payment::{self, ACCUMULATION_PURSE_KEY},
         mint::{self, ROUND_SEIGNIORAGE_RATE_KEY},
-        AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT,
+        standard_payment, AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT,
     },
-    AccessRights, ApiError, BlockTime, CLValue, ContractHash, DeployHash, DeployInfo, Gas, Key,
-    KeyTag, Motes, Phase, ProtocolVersion, PublicKey, RuntimeArgs, StoredValue, URef, U512,
+    AccessRights, ApiError, CLValue, ContractHash, DelegatorAddr, DeployHash, DeployInfo,
+    FeeBreakdown, Gas, Key, KeyTag, Motes, Phase, ProtocolVersion, PublicKey, RuntimeArgs,
+    ScheduledInvocation, ScheduledInvocationBucket, StoredValue, StoredValueTypeMismatch,
+    Timestamp, URef, SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS, U512,
 };
 
 pub use self::{
     balance::{BalanceRequest, BalanceResult},
+    block_info::BlockInfo,
+    call_view::{CallViewRequest, CallViewResult},
     chainspec_registry::ChainspecRegistry,
     checksum_registry::ChecksumRegistry,
     deploy_item::DeployItem,
@@ -70,9 +80,14 @@ pub use self::{
     execution_result::{ExecutionResult, ForcedTransferResult},
     genesis::{ExecConfig, GenesisAccount, GenesisConfig, GenesisSuccess},
     get_bids::{GetBidsRequest, GetBidsResult},
+    get_delegators::{GetDelegatorsRequest, GetDelegatorsResult},
+    get_unbonding_queue::{GetUnbondingQueueRequest, GetUnbondingQueueResult},
     prune::{PruneConfig, PruneResult},
     query::{QueryRequest, QueryResult},
     run_genesis_request::RunGenesisRequest,
+    scheduled_invocations::{
+        ScheduledInvocationsRequest, ScheduledInvocationsResult, SCHEDULED_INVOCATION_GAS_PRICE,
+    },
     step::{RewardItem, SlashItem, StepError, StepRequest, StepSuccess},
     system_contract_registry::SystemContractRegistry,
     transfer::{TransferArgs, TransferRuntimeArgsBuilder, TransferTargetMode},
@@ -91,7 +106,12 @@ use crate::{
         runtime::RuntimeStack,
         tracking_copy::{TrackingCopy, TrackingCopyExt},
     },
-    shared::{additive_map::AdditiveMap, newtypes::CorrelationId, transform::Transform},
+    shared::{
+        additive_map::AdditiveMap,
+        execution_journal::ExecutionJournal,
+        newtypes::CorrelationId,
+        transform::{Error as TransformError, Transform},
+    },
     storage::{
         global_state::{
             lmdb::LmdbGlobalState, scratch::ScratchGlobalState, CommitProvider, StateProvider,
@@ -100,7 +120,7 @@ use crate::{
         trie::{merkle_proof::TrieMerkleProof, TrieRaw},
         trie_store::operations::DeleteResult,
     },
-    system::auction,
+    system::{auction, handle_payment::internal as handle_payment_internal},
 };
 
 /// The maximum amount of motes that payment code execution can cost.
@@ -167,6 +187,26 @@ impl EngineState<LmdbGlobalState> {
             .put_stored_values(CorrelationId::new(), state_root_hash, stored_values)
             .map_err(Into::into)
     }
+
+    /// Computes the state root hash which writing the state cached in an
+    /// `EngineState<ScratchEngineState>` to LMDB and then pruning each batch of keys would
+    /// produce, without modifying LMDB.
+    pub fn compute_scratch_post_state_hash(
+        &self,
+        state_root_hash: Digest,
+        scratch_global_state: ScratchGlobalState,
+        keys_to_prune: &[Vec<Key>],
+    ) -> Result<Digest, Error> {
+        let stored_values = scratch_global_state.into_inner();
+        self.state
+            .compute_post_state_hash(
+                CorrelationId::new(),
+                state_root_hash,
+                stored_values,
+                keys_to_prune,
+            )
+            .map_err(Into::into)
+    }
 }
 
 impl<S> EngineState<S>
@@ -435,6 +475,13 @@ where
         for (key, value) in upgrade_config.global_state_update() {
             tracking_copy.borrow_mut().write(*key, value.clone());
         }
+
+        // Bids may carry delegators written before they were stored under their own keys,
+        // including bids supplied by the global state update above.
+        system_upgrader
+            .migrate_delegators(correlation_id)
+            .map_err(Error::ProtocolUpgrade)?;
+
         // We insert the new unbonding delay once the purses to be paid out have been transformed
         // based on the previous unbonding delay.
         if let Some(new_unbonding_delay) = upgrade_config.new_unbonding_delay() {
@@ -552,19 +599,34 @@ where
         correlation_id: CorrelationId,
         mut exec_request: ExecuteRequest,
     ) -> Result<ExecutionResults, Error> {
-        let executor = Executor::new(self.config().clone());
+        let executor = Executor::new(self.config().clone())
+            .with_gas_profiler(exec_request.gas_profiler.clone());
 
         let deploys = exec_request.take_deploys();
+        let block_info = exec_request.block_info();
         let mut results = ExecutionResults::with_capacity(deploys.len());
 
         for deploy_item in deploys {
+            let sequenced_account = match self.use_sequence_number(
+                correlation_id,
+                exec_request.protocol_version,
+                exec_request.parent_state_hash,
+                &deploy_item,
+            ) {
+                Ok(sequenced_account) => sequenced_account,
+                Err(error @ Error::RootNotFound(_)) => return Err(error),
+                Err(error) => {
+                    results.push_back(ExecutionResult::precondition_failure(error));
+                    continue;
+                }
+            };
             let result = match deploy_item.session {
                 ExecutableDeployItem::Transfer { .. } => self.transfer(
                     correlation_id,
                     &executor,
                     exec_request.protocol_version,
                     exec_request.parent_state_hash,
-                    BlockTime::new(exec_request.block_time),
+                    &block_info,
                     deploy_item,
                     exec_request.proposer.clone(),
                 ),
@@ -573,13 +635,20 @@ where
                     &executor,
                     exec_request.protocol_version,
                     exec_request.parent_state_hash,
-                    BlockTime::new(exec_request.block_time),
+                    &block_info,
                     deploy_item,
                     exec_request.proposer.clone(),
                 ),
             };
             match result {
-                Ok(result) => results.push_back(result),
+                // The sequence number is used up by any deploy the account is charged for, even if
+                // its payment or session code fails.
+                Ok(result) => match sequenced_account {
+                    Some(account) if !result.has_precondition_failure() => {
+                        results.push_back(record_sequence_number_used(result, account)?)
+                    }
+                    _ => results.push_back(result),
+                },
                 Err(error) => {
                     return Err(error);
                 }
@@ -589,6 +658,51 @@ where
         Ok(results)
     }
 
+    /// Checks the sequence number of the given deploy, if any, against the next sequence number
+    /// expected from its account.
+    ///
+    /// Returns the deploy's account with its sequence number advanced, to be recorded once the
+    /// deploy has been executed.
+    fn use_sequence_number(
+        &self,
+        correlation_id: CorrelationId,
+        protocol_version: ProtocolVersion,
+        prestate_hash: Digest,
+        deploy_item: &DeployItem,
+    ) -> Result<Option<Account>, Error> {
+        let sequence_number = match deploy_item.sequence_number {
+            Some(sequence_number) => sequence_number,
+            None => return Ok(None),
+        };
+
+        if protocol_version < self.config.start_protocol_version_with_sequence_numbers() {
+            return Err(Error::UnsupportedSequenceNumber(protocol_version));
+        }
+
+        let mut tracking_copy = match self.tracking_copy(prestate_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Err(Error::RootNotFound(prestate_hash)),
+        };
+
+        let mut account = tracking_copy
+            .get_account(correlation_id, deploy_item.address)
+            .map_err(|_| Error::Authorization)?;
+        let expected = account.sequence_number();
+
+        let next = match sequence_number.checked_add(1) {
+            Some(next) if sequence_number == expected => next,
+            _ => {
+                return Err(Error::InvalidSequenceNumber {
+                    expected,
+                    got: sequence_number,
+                })
+            }
+        };
+
+        account.set_sequence_number(next);
+        Ok(Some(account))
+    }
+
     fn get_authorized_account(
         &self,
         correlation_id: CorrelationId,
@@ -626,6 +740,49 @@ where
         Ok(account)
     }
 
+    /// Splits the authorization keys of a deploy funded by `fee_payer` into those authorizing the
+    /// deploy's account and those authorizing the fee payer.
+    ///
+    /// Each key authorizes whichever of the two accounts it is associated with, and administrator
+    /// keys authorize both.  Keys associated with neither account fail authorization.
+    fn split_sponsored_authorization_keys(
+        &self,
+        correlation_id: CorrelationId,
+        account_hash: AccountHash,
+        fee_payer: AccountHash,
+        authorization_keys: BTreeSet<AccountHash>,
+        tracking_copy: Rc<RefCell<TrackingCopy<<S as StateProvider>::Reader>>>,
+    ) -> Result<(BTreeSet<AccountHash>, BTreeSet<AccountHash>), Error> {
+        let read_account = |account_hash| {
+            tracking_copy
+                .borrow_mut()
+                .get_account(correlation_id, account_hash)
+                .map_err(|_| Error::Authorization)
+        };
+        let account = read_account(account_hash)?;
+        let payer = read_account(fee_payer)?;
+
+        let admin_set = self.config().administrative_accounts();
+        let (account_keys, payer_keys) = authorization_keys.iter().fold(
+            (BTreeSet::new(), BTreeSet::new()),
+            |(mut account_keys, mut payer_keys), key| {
+                let is_admin = admin_set.contains(key);
+                if is_admin || account.associated_keys().contains_key(key) {
+                    account_keys.insert(*key);
+                }
+                if is_admin || payer.associated_keys().contains_key(key) {
+                    payer_keys.insert(*key);
+                }
+                (account_keys, payer_keys)
+            },
+        );
+
+        if account_keys.union(&payer_keys).count() != authorization_keys.len() {
+            return Err(Error::Authorization);
+        }
+        Ok((account_keys, payer_keys))
+    }
+
     /// Get the balance of a passed purse referenced by its [`URef`].
     pub fn get_purse_balance(
         &self,
@@ -659,7 +816,7 @@ where
         executor: &Executor,
         protocol_version: ProtocolVersion,
         prestate_hash: Digest,
-        blocktime: BlockTime,
+        block_info: &BlockInfo,
         deploy_item: DeployItem,
         proposer: PublicKey,
     ) -> Result<ExecutionResult, Error> {
@@ -669,6 +826,13 @@ where
             Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
         };
 
+        // Native transfers are paid from the source purse, so they can't be funded by a fee payer.
+        if deploy_item.fee_payer.is_some() {
+            return Ok(ExecutionResult::precondition_failure(
+                Error::UnsupportedFeePayer,
+            ));
+        }
+
         let account_hash = deploy_item.address;
 
         let authorization_keys = deploy_item.authorization_keys;
@@ -849,7 +1013,7 @@ where
                         RuntimeArgs::new(), // mint create takes no arguments
                         &account,
                         authorization_keys.clone(),
-                        blocktime,
+                        block_info,
                         deploy_item.deploy_hash,
                         gas_limit,
                         protocol_version,
@@ -939,7 +1103,7 @@ where
                     RuntimeArgs::default(),
                     &account,
                     authorization_keys.clone(),
-                    blocktime,
+                    block_info,
                     deploy_item.deploy_hash,
                     gas_limit,
                     protocol_version,
@@ -982,7 +1146,7 @@ where
                     runtime_args,
                     &account,
                     authorization_keys.clone(),
-                    blocktime,
+                    block_info,
                     deploy_item.deploy_hash,
                     gas_limit,
                     protocol_version,
@@ -1068,7 +1232,7 @@ where
                 runtime_args,
                 &account,
                 authorization_keys.clone(),
-                blocktime,
+                block_info,
                 deploy_item.deploy_hash,
                 gas_limit,
                 protocol_version,
@@ -1125,7 +1289,7 @@ where
                     handle_payment_args,
                     &system_account,
                     authorization_keys,
-                    blocktime,
+                    block_info,
                     deploy_item.deploy_hash,
                     gas_limit,
                     protocol_version,
@@ -1166,10 +1330,16 @@ where
         execution_result_builder.set_session_execution_result(session_result);
         execution_result_builder.set_finalize_execution_result(finalize_result);
 
-        let execution_result = execution_result_builder
+        let mut execution_result = execution_result_builder
             .build()
             .expect("ExecutionResultBuilder not initialized properly");
 
+        if let Some(fee_breakdown) =
+            self.fee_breakdown(wasmless_transfer_motes, wasmless_transfer_motes)
+        {
+            execution_result = execution_result.with_fee_breakdown(fee_breakdown);
+        }
+
         Ok(execution_result)
     }
 
@@ -1190,7 +1360,7 @@ where
         executor: &Executor,
         protocol_version: ProtocolVersion,
         prestate_hash: Digest,
-        blocktime: BlockTime,
+        block_info: &BlockInfo,
         deploy_item: DeployItem,
         proposer: PublicKey,
     ) -> Result<ExecutionResult, Error> {
@@ -1208,7 +1378,24 @@ where
         // Get addr bytes from `address` (which is actually a Key)
         // validation_spec_3: account validity
 
-        let authorization_keys = deploy_item.authorization_keys;
+        // A sponsored deploy is approved by keys of both its account and its fee payer, so each of
+        // the two accounts is authorized by those of the keys associated with it.
+        let (authorization_keys, payer_authorization_keys) = match deploy_item.fee_payer {
+            Some(fee_payer) => match self.split_sponsored_authorization_keys(
+                correlation_id,
+                deploy_item.address,
+                fee_payer,
+                deploy_item.authorization_keys,
+                Rc::clone(&tracking_copy),
+            ) {
+                Ok(authorization_keys) => authorization_keys,
+                Err(e) => return Ok(ExecutionResult::precondition_failure(e)),
+            },
+            None => (
+                deploy_item.authorization_keys.clone(),
+                deploy_item.authorization_keys,
+            ),
+        };
 
         // Get account from tracking copy
         // validation_spec_3: account validity
@@ -1225,6 +1412,28 @@ where
             }
         };
 
+        // Get the account funding payment, which is the fee payer if one is specified, authorized
+        // by the approvals of the fee payer's associated keys.
+        let payer = match deploy_item.fee_payer {
+            Some(fee_payer) => {
+                if !deploy_item.payment.is_standard_payment(Phase::Payment) {
+                    return Ok(ExecutionResult::precondition_failure(
+                        Error::UnsupportedFeePayer,
+                    ));
+                }
+                match self.get_authorized_account(
+                    correlation_id,
+                    fee_payer,
+                    &payer_authorization_keys,
+                    Rc::clone(&tracking_copy),
+                ) {
+                    Ok(payer) => payer,
+                    Err(e) => return Ok(ExecutionResult::precondition_failure(e)),
+                }
+            }
+            None => account.clone(),
+        };
+
         // Finalization is executed by system account (currently genesis account)
         // payment_code_spec_5: system executes finalization
         let system_account = match tracking_copy
@@ -1258,13 +1467,13 @@ where
             }
         };
 
-        // Get account main purse balance key
+        // Get payer's main purse balance key
         // validation_spec_5: account main purse minimum balance
-        let account_main_purse_balance_key: Key = {
-            let account_key = Key::URef(account.main_purse());
+        let payer_main_purse_balance_key: Key = {
+            let payer_key = Key::URef(payer.main_purse());
             match tracking_copy
                 .borrow_mut()
-                .get_purse_balance_key(correlation_id, account_key)
+                .get_purse_balance_key(correlation_id, payer_key)
             {
                 Ok(key) => key,
                 Err(error) => {
@@ -1273,11 +1482,11 @@ where
             }
         };
 
-        // Get account main purse balance to enforce precondition and in case of forced
+        // Get payer's main purse balance to enforce precondition and in case of forced
         // transfer validation_spec_5: account main purse minimum balance
-        let account_main_purse_balance: Motes = match tracking_copy
+        let payer_main_purse_balance: Motes = match tracking_copy
             .borrow_mut()
-            .get_purse_balance(correlation_id, account_main_purse_balance_key)
+            .get_purse_balance(correlation_id, payer_main_purse_balance_key)
         {
             Ok(balance) => balance,
             Err(error) => return Ok(ExecutionResult::precondition_failure(error.into())),
@@ -1287,7 +1496,7 @@ where
 
         // Enforce minimum main purse balance validation
         // validation_spec_5: account main purse minimum balance
-        if account_main_purse_balance < max_payment_cost {
+        if payer_main_purse_balance < max_payment_cost {
             return Ok(ExecutionResult::precondition_failure(
                 Error::InsufficientPayment,
             ));
@@ -1381,14 +1590,14 @@ where
             let phase = Phase::Payment;
 
             let payment_stack = RuntimeStack::from_account_hash(
-                deploy_item.address,
+                payer.account_hash(),
                 self.config.max_runtime_call_stack_height() as usize,
             );
 
             // payment_code_spec_2: execute payment code
-            let payment_access_rights = account.extract_access_rights();
+            let payment_access_rights = payer.extract_access_rights();
 
-            let mut payment_named_keys = account.named_keys().clone();
+            let mut payment_named_keys = payer.named_keys().clone();
 
             let payment_args = payment.args().clone();
 
@@ -1396,12 +1605,12 @@ where
                 // Todo potentially could be moved to Executor::Exec
                 executor.exec_standard_payment(
                     payment_args,
-                    Key::Account(account.account_hash()),
-                    &account,
+                    Key::Account(payer.account_hash()),
+                    &payer,
                     &mut payment_named_keys,
                     payment_access_rights,
-                    authorization_keys.clone(),
-                    blocktime,
+                    payer_authorization_keys,
+                    block_info,
                     deploy_hash,
                     payment_gas_limit,
                     protocol_version,
@@ -1413,7 +1622,7 @@ where
             } else {
                 let payment_execution_kind = match ExecutionKind::new(
                     Rc::clone(&tracking_copy),
-                    account.named_keys(),
+                    payer.named_keys(),
                     payment,
                     correlation_id,
                     &protocol_version,
@@ -1427,11 +1636,11 @@ where
                 executor.exec(
                     payment_execution_kind,
                     payment_args,
-                    &account,
+                    &payer,
                     &mut payment_named_keys,
                     payment_access_rights,
-                    authorization_keys.clone(),
-                    blocktime,
+                    payer_authorization_keys,
+                    block_info,
                     deploy_hash,
                     payment_gas_limit,
                     protocol_version,
@@ -1454,9 +1663,9 @@ where
             match ExecutionResult::new_payment_code_error(
                 error,
                 max_payment_cost,
-                account_main_purse_balance,
+                payer_main_purse_balance,
                 payment_result.cost(),
-                account_main_purse_balance_key,
+                payer_main_purse_balance_key,
                 rewards_target_purse_balance_key,
             ) {
                 Ok(execution_result) => return Ok(execution_result),
@@ -1505,9 +1714,9 @@ where
             match ExecutionResult::new_payment_code_error(
                 error,
                 max_payment_cost,
-                account_main_purse_balance,
+                payer_main_purse_balance,
                 gas_cost,
-                account_main_purse_balance_key,
+                payer_main_purse_balance_key,
                 rewards_target_purse_balance_key,
             ) {
                 Ok(execution_result) => return Ok(execution_result),
@@ -1556,7 +1765,7 @@ where
                 &mut session_named_keys,
                 session_access_rights,
                 authorization_keys.clone(),
-                blocktime,
+                block_info,
                 deploy_hash,
                 session_gas_limit,
                 protocol_version,
@@ -1576,7 +1785,7 @@ where
                 deploy_hash,
                 transfers,
                 account.account_hash(),
-                account.main_purse(),
+                payer.main_purse(),
                 cost,
             );
             session_tracking_copy.borrow_mut().write(
@@ -1600,9 +1809,9 @@ where
             match ExecutionResult::new_payment_code_error(
                 error,
                 max_payment_cost,
-                account_main_purse_balance,
+                payer_main_purse_balance,
                 session_result.cost(),
-                account_main_purse_balance_key,
+                payer_main_purse_balance_key,
                 rewards_target_purse_balance_key,
             ) {
                 Ok(execution_result) => return Ok(execution_result),
@@ -1625,27 +1834,26 @@ where
         execution_result_builder.set_session_execution_result(session_result);
 
         // payment_code_spec_5: run finalize process
+        //((gas spent during payment code execution) + (gas spent during session code execution)) *
+        //((gas gas_price
+        let finalize_cost_motes =
+            match Motes::from_gas(execution_result_builder.total_cost(), deploy_item.gas_price) {
+                Some(motes) => motes,
+                None => {
+                    return Ok(ExecutionResult::precondition_failure(
+                        Error::GasConversionOverflow,
+                    ))
+                }
+            };
+
         let finalize_result: ExecutionResult = {
             let post_session_tc = post_session_rc.borrow();
             let finalization_tc = Rc::new(RefCell::new(post_session_tc.fork()));
 
             let handle_payment_args = {
-                //((gas spent during payment code execution) + (gas spent during session code execution)) * gas_price
-                let finalize_cost_motes = match Motes::from_gas(
-                    execution_result_builder.total_cost(),
-                    deploy_item.gas_price,
-                ) {
-                    Some(motes) => motes,
-                    None => {
-                        return Ok(ExecutionResult::precondition_failure(
-                            Error::GasConversionOverflow,
-                        ))
-                    }
-                };
-
                 let maybe_runtime_args = RuntimeArgs::try_new(|args| {
                     args.insert(handle_payment::ARG_AMOUNT, finalize_cost_motes.value())?;
-                    args.insert(handle_payment::ARG_ACCOUNT, account.account_hash())?;
+                    args.insert(handle_payment::ARG_ACCOUNT, payer.account_hash())?;
                     args.insert(handle_payment::ARG_TARGET, rewards_target_purse)?;
                     Ok(())
                 });
@@ -1693,7 +1901,7 @@ where
                     handle_payment_args,
                     &system_account,
                     authorization_keys,
-                    blocktime,
+                    block_info,
                     deploy_hash,
                     gas_limit,
                     protocol_version,
@@ -1710,16 +1918,45 @@ where
         execution_result_builder.set_finalize_execution_result(finalize_result);
 
         // We panic here to indicate that the builder was not used properly.
-        let ret = execution_result_builder
+        let mut ret = execution_result_builder
             .build()
             .expect("ExecutionResultBuilder not initialized properly");
 
+        if let Some(fee_breakdown) = self.fee_breakdown(finalize_cost_motes, payment_purse_balance)
+        {
+            ret = ret.with_fee_breakdown(fee_breakdown);
+        }
+
         // NOTE: payment_code_spec_5_a is enforced in execution_result_builder.build()
         // payment_code_spec_6: return properly combined set of transforms and
         // appropriate error
         Ok(ret)
     }
 
+    /// Returns how the fee charged for a deploy is split between the block proposer and burning,
+    /// or `None` if fees are not configured to be split.
+    ///
+    /// Mirrors the computation made by the handle payment contract when finalizing the payment.
+    fn fee_breakdown(
+        &self,
+        gas_spent: Motes,
+        payment_purse_balance: Motes,
+    ) -> Option<FeeBreakdown> {
+        let burn_ratio = match self.config.fee_handling() {
+            FeeHandling::Split { burn_ratio } => burn_ratio,
+            FeeHandling::PayToProposer | FeeHandling::Accumulate | FeeHandling::Burn => {
+                return None
+            }
+        };
+        let (_refund, fee) = handle_payment_internal::calculate_refund_and_fee(
+            gas_spent.value(),
+            payment_purse_balance.value(),
+            self.config.refund_handling(),
+        )
+        .ok()?;
+        handle_payment_internal::calculate_fee_split(fee, &burn_ratio).ok()
+    }
+
     fn get_rewards_purse(
         &self,
         correlation_id: CorrelationId,
@@ -1732,9 +1969,9 @@ where
             Ok(Some(tracking_copy)) => Rc::new(RefCell::new(tracking_copy)),
         };
         match self.config.fee_handling() {
-            FeeHandling::PayToProposer => {
-                // the proposer of the block this deploy is in receives the gas from this deploy
-                // execution
+            FeeHandling::PayToProposer | FeeHandling::Split { .. } => {
+                // the proposer of the block this deploy is in receives the gas (or its unburned
+                // part) from this deploy execution
                 let proposer_account: Account = match tracking_copy
                     .borrow_mut()
                     .get_account(correlation_id, AccountHash::from(&proposer))
@@ -1909,9 +2146,13 @@ where
         let mut bids = BTreeMap::new();
 
         for key in bid_keys.iter() {
-            if let Some(StoredValue::Bid(bid)) =
+            if let Some(StoredValue::Bid(mut bid)) =
                 tracking_copy.get(correlation_id, key).map_err(Into::into)?
             {
+                let validator_account_hash = bid.validator_public_key().to_account_hash();
+                let delegators =
+                    tracking_copy.get_delegators(correlation_id, validator_account_hash)?;
+                bid.delegators_mut().extend(delegators);
                 bids.insert(bid.validator_public_key().clone(), *bid);
             };
         }
@@ -1919,6 +2160,376 @@ where
         Ok(GetBidsResult::Success { bids })
     }
 
+    /// Obtains a page of the delegators of a single validator, ordered by delegator public key.
+    pub fn get_delegators(
+        &self,
+        correlation_id: CorrelationId,
+        get_delegators_request: GetDelegatorsRequest,
+    ) -> Result<GetDelegatorsResult, Error> {
+        let mut tracking_copy = match self.tracking_copy(get_delegators_request.state_hash())? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetDelegatorsResult::RootNotFound),
+        };
+
+        let validator_account_hash = get_delegators_request
+            .validator_public_key()
+            .to_account_hash();
+        let bid = match tracking_copy
+            .get(correlation_id, &Key::Bid(validator_account_hash))
+            .map_err(Into::into)?
+        {
+            Some(StoredValue::Bid(bid)) => bid,
+            _ => return Ok(GetDelegatorsResult::ValidatorNotFound),
+        };
+
+        // Only the keys are listed, and delegators are read for the requested page alone.
+        let prefix = DelegatorAddr::validator_prefix(&validator_account_hash);
+        let delegator_keys = tracking_copy
+            .get_keys_by_prefix(correlation_id, &prefix)
+            .map_err(Into::into)?;
+        let cursor = get_delegators_request
+            .cursor()
+            .map(|delegator_account_hash| {
+                Key::Delegator(DelegatorAddr::new(
+                    validator_account_hash,
+                    delegator_account_hash,
+                ))
+            });
+        let page_size = get_delegators_request.page_size() as usize;
+
+        let mut delegators: Vec<Delegator> = Vec::with_capacity(page_size);
+        let mut next_cursor = None;
+        for key in delegator_keys
+            .into_iter()
+            .filter(|key| cursor.map_or(true, |cursor| *key > cursor))
+        {
+            if delegators.len() == page_size {
+                next_cursor = delegators
+                    .last()
+                    .map(|delegator| delegator.delegator_addr().delegator());
+                break;
+            }
+            match tracking_copy
+                .get(correlation_id, &key)
+                .map_err(Into::into)?
+            {
+                Some(StoredValue::Delegator(delegator)) => {
+                    if !delegator.staked_amount().is_zero() {
+                        delegators.push(*delegator);
+                    }
+                }
+                Some(other) => {
+                    return Err(Error::Exec(ExecError::TypeMismatch(
+                        StoredValueTypeMismatch::new("Delegator".to_string(), other.type_name()),
+                    )))
+                }
+                None => {}
+            }
+        }
+
+        Ok(GetDelegatorsResult::Success {
+            delegators,
+            total: u64::from(bid.delegator_count()),
+            next_cursor,
+        })
+    }
+
+    /// Obtains the pending unbonding and withdraw purses, either of a single validator and its
+    /// delegators or of all validators, along with the unbonding delay currently recorded by the
+    /// auction contract.
+    pub fn get_unbonding_queue(
+        &self,
+        correlation_id: CorrelationId,
+        get_unbonding_queue_request: GetUnbondingQueueRequest,
+    ) -> Result<GetUnbondingQueueResult, Error> {
+        let state_hash = get_unbonding_queue_request.state_hash();
+        let mut tracking_copy = match self.tracking_copy(state_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetUnbondingQueueResult::RootNotFound),
+        };
+
+        let auction_hash = self.get_system_auction_hash(correlation_id, state_hash)?;
+        let auction_contract = tracking_copy.get_contract(correlation_id, auction_hash)?;
+        let unbonding_delay = match auction_contract.named_keys().get(UNBONDING_DELAY_KEY) {
+            Some(unbonding_delay_key) => match tracking_copy
+                .get(correlation_id, unbonding_delay_key)
+                .map_err(Into::into)?
+            {
+                Some(StoredValue::CLValue(cl_value)) => CLValue::into_t::<u64>(cl_value)
+                    .map_err(|_| Error::FailedToRetrieveUnbondingDelay)?,
+                _ => return Err(Error::FailedToRetrieveUnbondingDelay),
+            },
+            None => return Err(Error::FailedToRetrieveUnbondingDelay),
+        };
+
+        // Purses are stored under the account hash of their validator, so those of a single
+        // validator can be read directly rather than by iterating over all of them.
+        let keys = match get_unbonding_queue_request.validator() {
+            Some(validator) => vec![Key::Withdraw(validator), Key::Unbond(validator)],
+            None => {
+                let mut keys = Vec::new();
+                for key_tag in [KeyTag::Withdraw, KeyTag::Unbond] {
+                    keys.extend(
+                        tracking_copy
+                            .get_keys(correlation_id, &key_tag)
+                            .map_err(|err| Error::Exec(err.into()))?,
+                    );
+                }
+                keys
+            }
+        };
+
+        let mut unbonding_purses = Vec::new();
+        for key in keys.iter() {
+            match tracking_copy.get(correlation_id, key).map_err(Into::into)? {
+                Some(StoredValue::Withdraw(withdraw_purses)) => {
+                    unbonding_purses.extend(withdraw_purses.into_iter().map(UnbondingPurse::from))
+                }
+                Some(StoredValue::Unbonding(purses)) => unbonding_purses.extend(purses),
+                _ => {}
+            }
+        }
+
+        unbonding_purses.sort_by(|lhs, rhs| {
+            lhs.validator_public_key()
+                .cmp(rhs.validator_public_key())
+                .then(lhs.era_of_creation().cmp(&rhs.era_of_creation()))
+        });
+
+        Ok(GetUnbondingQueueResult::Success {
+            unbonding_delay,
+            unbonding_purses,
+        })
+    }
+
+    /// Evaluates a view entry point of a stored contract against the global state under the
+    /// state root hash of the request.
+    ///
+    /// The evaluation happens in a read-only context and its effects are never committed.
+    pub fn call_view(
+        &self,
+        correlation_id: CorrelationId,
+        call_view_request: CallViewRequest,
+    ) -> Result<CallViewResult, Error> {
+        let tracking_copy = match self.tracking_copy(call_view_request.state_hash())? {
+            Some(tracking_copy) => Rc::new(RefCell::new(tracking_copy)),
+            None => return Ok(CallViewResult::RootNotFound),
+        };
+
+        let caller = call_view_request.caller();
+        let account = match tracking_copy
+            .borrow_mut()
+            .get_account(correlation_id, caller)
+        {
+            Ok(account) => account,
+            Err(execution::Error::KeyNotFound(_)) => return Ok(CallViewResult::CallerNotFound),
+            Err(error) => return Err(error.into()),
+        };
+
+        let stack = RuntimeStack::from_account_hash(
+            caller,
+            self.config.max_runtime_call_stack_height() as usize,
+        );
+
+        let executor = Executor::new(self.config().clone());
+        let (result, cost) = executor.call_view(
+            call_view_request.contract_hash(),
+            call_view_request.entry_point(),
+            call_view_request.args().clone(),
+            &account,
+            call_view_request.block_info(),
+            call_view_request.gas_limit(),
+            call_view_request.protocol_version(),
+            correlation_id,
+            tracking_copy,
+            stack,
+        );
+
+        Ok(match result {
+            Ok(value) => CallViewResult::Success { value, cost },
+            Err(error) => CallViewResult::Failure {
+                error: error.to_string(),
+                cost,
+            },
+        })
+    }
+
+    /// Executes and commits the scheduled invocations which are due in the block of the request.
+    ///
+    /// Only the invocations of the buckets which can become due in the block are read: the bucket
+    /// of the block's era, and those of the timestamps since the parent block.
+    ///
+    /// The due invocations are executed one after another in the order of their addresses, each on
+    /// top of the state left by the previous one. Before an invocation is executed, its prepaid
+    /// amount is released from its escrow purse into the main purse of the initiator, which then
+    /// pays for the invocation the same way it would pay for a deploy calling the stored contract
+    /// with standard payment. Whatever is not spent stays in the initiator's main purse.
+    ///
+    /// The executed invocations are not removed from global state: deleting keys is not possible
+    /// through the effects of an execution, so the caller is expected to prune them once the
+    /// resulting state is persisted.
+    pub fn run_scheduled_invocations(
+        &self,
+        correlation_id: CorrelationId,
+        request: ScheduledInvocationsRequest,
+    ) -> Result<ScheduledInvocationsResult, Error> {
+        let mut state_root_hash = request.pre_state_hash();
+        let mut tracking_copy = match self.tracking_copy(state_root_hash)? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(ScheduledInvocationsResult::RootNotFound),
+        };
+
+        if request.protocol_version()
+            < self
+                .config
+                .start_protocol_version_with_scheduled_invocations()
+        {
+            return Ok(ScheduledInvocationsResult::Success {
+                post_state_hash: state_root_hash,
+                execution_results: Vec::new(),
+            });
+        }
+
+        let block_info = request.block_info();
+        let block_time = Timestamp::from(u64::from(block_info.block_time()));
+
+        let first_timestamp_span =
+            request.parent_block_time().millis() / SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS;
+        let last_timestamp_span =
+            block_time.millis() / SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS;
+        let buckets = iter::once(ScheduledInvocationBucket::Era(block_info.era_id())).chain(
+            (first_timestamp_span..=last_timestamp_span).map(ScheduledInvocationBucket::Timestamp),
+        );
+
+        let mut invocation_keys = BTreeSet::new();
+        for bucket in buckets {
+            let keys = tracking_copy
+                .get_keys_by_prefix(correlation_id, &bucket.key_prefix())
+                .map_err(|err| Error::Exec(err.into()))?;
+            invocation_keys.extend(keys);
+        }
+
+        let mut due_invocations = Vec::new();
+        for key in invocation_keys {
+            let addr = match key {
+                Key::ScheduledInvocation(addr) => addr,
+                _ => continue,
+            };
+            let invocation: ScheduledInvocation = match tracking_copy
+                .get(correlation_id, &key)
+                .map_err(Into::into)?
+            {
+                Some(StoredValue::CLValue(cl_value)) => {
+                    cl_value.into_t().map_err(ExecError::from)?
+                }
+                Some(_) | None => continue,
+            };
+            if invocation
+                .scheduled_time()
+                .is_due(block_info.era_id(), block_time)
+            {
+                due_invocations.push((addr, invocation));
+            }
+        }
+
+        let executor = Executor::new(self.config().clone());
+        let mut execution_results = Vec::with_capacity(due_invocations.len());
+
+        for (addr, invocation) in due_invocations {
+            let mut tracking_copy = match self.tracking_copy(state_root_hash)? {
+                Some(tracking_copy) => tracking_copy,
+                None => return Err(Error::RootNotFound(state_root_hash)),
+            };
+
+            let account = match tracking_copy.get_account(correlation_id, invocation.initiator()) {
+                Ok(account) => account,
+                Err(error) => {
+                    execution_results
+                        .push((addr, ExecutionResult::precondition_failure(error.into())));
+                    continue;
+                }
+            };
+
+            // Release the prepaid amount into the main purse of the initiator.
+            let escrow_balance_key = Key::Balance(invocation.payment_purse().addr());
+            let escrow_balance =
+                match tracking_copy.get_purse_balance(correlation_id, escrow_balance_key) {
+                    Ok(escrow_balance) => escrow_balance,
+                    Err(error) => {
+                        execution_results
+                            .push((addr, ExecutionResult::precondition_failure(error.into())));
+                        continue;
+                    }
+                };
+            let release_journal = ExecutionJournal::new(vec![
+                (
+                    escrow_balance_key,
+                    Transform::Write(StoredValue::CLValue(
+                        CLValue::from_t(U512::zero()).map_err(ExecError::from)?,
+                    )),
+                ),
+                (
+                    Key::Balance(account.main_purse().addr()),
+                    Transform::AddUInt512(escrow_balance.value()),
+                ),
+            ]);
+            state_root_hash = self.apply_effect(
+                correlation_id,
+                state_root_hash,
+                release_journal.clone().into(),
+            )?;
+
+            let payment_args = RuntimeArgs::try_new(|args| {
+                args.insert(standard_payment::ARG_AMOUNT, invocation.payment_amount())?;
+                Ok(())
+            })
+            .map_err(ExecError::from)?;
+            let deploy_item = DeployItem::new(
+                invocation.initiator(),
+                ExecutableDeployItem::StoredContractByHash {
+                    hash: invocation.contract_hash(),
+                    entry_point: invocation.entry_point().to_string(),
+                    args: invocation.args().clone(),
+                },
+                ExecutableDeployItem::ModuleBytes {
+                    module_bytes: Bytes::new(),
+                    args: payment_args,
+                },
+                SCHEDULED_INVOCATION_GAS_PRICE,
+                account
+                    .associated_keys()
+                    .iter()
+                    .map(|(account_hash, _weight)| *account_hash)
+                    .collect(),
+                DeployHash::new(addr),
+            );
+
+            let execution_result = self.deploy(
+                correlation_id,
+                &executor,
+                request.protocol_version(),
+                state_root_hash,
+                block_info,
+                deploy_item,
+                request.proposer().clone(),
+            )?;
+            state_root_hash = self.apply_effect(
+                correlation_id,
+                state_root_hash,
+                execution_result.execution_journal().clone().into(),
+            )?;
+
+            let mut execution_journal = release_journal;
+            execution_journal.extend(execution_result.execution_journal().clone());
+            execution_results.push((addr, execution_result.with_journal(execution_journal)));
+        }
+
+        Ok(ScheduledInvocationsResult::Success {
+            post_state_hash: state_root_hash,
+            execution_results,
+        })
+    }
+
     /// Executes a step request.
     pub fn commit_step(
         &self,
@@ -1963,7 +2574,7 @@ where
             RuntimeArgs::default(),
             &virtual_system_account,
             authorization_keys.clone(),
-            BlockTime::default(),
+            &BlockInfo::default(),
             deploy_hash,
             gas_limit,
             step_request.protocol_version,
@@ -2000,7 +2611,7 @@ where
             reward_args,
             &virtual_system_account,
             authorization_keys.clone(),
-            BlockTime::default(),
+            &BlockInfo::default(),
             deploy_hash,
             gas_limit,
             step_request.protocol_version,
@@ -2034,7 +2645,7 @@ where
                     slash_args,
                     &virtual_system_account,
                     authorization_keys.clone(),
-                    BlockTime::default(),
+                    &BlockInfo::default(),
                     deploy_hash,
                     gas_limit,
                     step_request.protocol_version,
@@ -2073,7 +2684,7 @@ where
             run_auction_args,
             &virtual_system_account,
             authorization_keys,
-            BlockTime::default(),
+            &BlockInfo::default(),
             deploy_hash,
             gas_limit,
             step_request.protocol_version,
@@ -2282,6 +2893,7 @@ fn log_execution_result(preamble: &'static str, result: &ExecutionResult) {
             transfers,
             cost,
             execution_journal,
+            ..
         } => {
             debug!(
                 %cost,
@@ -2296,6 +2908,7 @@ fn log_execution_result(preamble: &'static str, result: &ExecutionResult) {
             transfers,
             cost,
             execution_journal,
+            ..
         } => {
             debug!(
                 %error,
@@ -2309,6 +2922,39 @@ fn log_execution_result(preamble: &'static str, result: &ExecutionResult) {
     }
 }
 
+/// Appends a write of the given account, carrying the advanced sequence number, to the execution
+/// journal of the deploy's result.
+///
+/// The transforms the deploy applied to the account are replayed onto it first, so that the write
+/// doesn't revert them.
+fn record_sequence_number_used(
+    result: ExecutionResult,
+    account: Account,
+) -> Result<ExecutionResult, Error> {
+    let key = Key::Account(account.account_hash());
+    let sequence_number = account.sequence_number();
+    let stored_value = result
+        .execution_journal()
+        .iter()
+        .filter(|(transformed_key, _)| *transformed_key == key)
+        .try_fold(
+            StoredValue::Account(account),
+            |stored_value, (_, transform)| transform.clone().apply(stored_value),
+        )
+        .map_err(|error| match error {
+            TransformError::Serialization(error) => Error::Exec(ExecError::BytesRepr(error)),
+            TransformError::TypeMismatch(mismatch) => {
+                Error::Exec(ExecError::TypeMismatch(mismatch))
+            }
+        })?;
+    let mut account = match stored_value {
+        StoredValue::Account(account) => account,
+        _ => return Err(Error::Exec(ExecError::UnexpectedStoredValueVariant)),
+    };
+    account.set_sequence_number(sequence_number);
+    Ok(result.with_transform(key, Transform::Write(StoredValue::Account(account))))
+}
+
 fn should_charge_for_errors_in_wasm(execution_result: &ExecutionResult) -> bool {
     match execution_result {
         ExecutionResult::Failure {
@@ -2316,6 +2962,7 @@ fn should_charge_for_errors_in_wasm(execution_result: &ExecutionResult) -> bool
             transfers: _,
             cost: _,
             execution_journal: _,
+            fee_breakdown: _,
         } => match error {
             Error::Exec(err) => match err {
                 ExecError::WasmPreprocessing(_) | ExecError::UnsupportedWasmStart => true,
@@ -2365,7 +3012,15 @@ fn should_charge_for_errors_in_wasm(execution_result: &ExecutionResult) -> bool
                 | ExecError::ValueTooLarge
                 | ExecError::MissingRuntimeStack
                 | ExecError::DisabledContract(_)
-                | ExecError::DisabledUnrestrictedTransfers => false,
+                | ExecError::DisabledUnrestrictedTransfers
+                | ExecError::NotAViewEntryPoint(_)
+                | ExecError::WriteInReadOnlyContext
+                | ExecError::UnschedulableEntryPoint(_)
+                | ExecError::ScheduledTimeNotInFuture
+                | ExecError::ScheduledInvocationNotPrepaid
+                | ExecError::ScheduleInvocationFromContract
+                | ExecError::DisabledScheduledInvocations
+                | ExecError::MigrationNotCallable(_) => false,
             },
             Error::WasmPreprocessing(_) => true,
             Error::WasmSerialization(_) => true,
@@ -2394,7 +3049,10 @@ fn should_charge_for_errors_in_wasm(execution_result: &ExecutionResult) -> bool
             | Error::FailedToRetrieveUnbondingDelay
             | Error::FailedToRetrieveEraId
             | Error::MissingTrieNodeChildren(_)
-            | Error::FailedToRetrieveAccumulationPurse => false,
+            | Error::FailedToRetrieveAccumulationPurse
+            | Error::UnsupportedFeePayer
+            | Error::UnsupportedSequenceNumber(_)
+            | Error::InvalidSequenceNumber { .. } => false,
         },
         ExecutionResult::Success { .. } => false,
     }
@@ -0,0 +1,84 @@
+//! Support for executing the scheduled invocations which are due in a block.
+use casper_hashing::Digest;
+use casper_types::{ProtocolVersion, PublicKey, ScheduledInvocationAddr, Timestamp};
+
+use crate::core::engine_state::{BlockInfo, ExecutionResult};
+
+/// The gas price at which scheduled invocations are executed.
+pub const SCHEDULED_INVOCATION_GAS_PRICE: u64 = 1;
+
+/// Represents a request to execute the scheduled invocations which are due in a given block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledInvocationsRequest {
+    pre_state_hash: Digest,
+    protocol_version: ProtocolVersion,
+    block_info: BlockInfo,
+    parent_block_time: Timestamp,
+    proposer: PublicKey,
+}
+
+impl ScheduledInvocationsRequest {
+    /// Creates new request.
+    pub fn new(
+        pre_state_hash: Digest,
+        protocol_version: ProtocolVersion,
+        block_info: BlockInfo,
+        parent_block_time: Timestamp,
+        proposer: PublicKey,
+    ) -> Self {
+        ScheduledInvocationsRequest {
+            pre_state_hash,
+            protocol_version,
+            block_info,
+            parent_block_time,
+            proposer,
+        }
+    }
+
+    /// Returns the state root hash on top of which the invocations are executed.
+    pub fn pre_state_hash(&self) -> Digest {
+        self.pre_state_hash
+    }
+
+    /// Returns protocol version.
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    /// Returns the block in which the invocations are executed.
+    ///
+    /// Its era and block time determine which invocations are due.
+    pub fn block_info(&self) -> &BlockInfo {
+        &self.block_info
+    }
+
+    /// Returns the timestamp of the parent of the block in which the invocations are executed.
+    ///
+    /// The invocations scheduled for a timestamp up to this one were executed in earlier blocks.
+    pub fn parent_block_time(&self) -> Timestamp {
+        self.parent_block_time
+    }
+
+    /// Returns the proposer of the block in which the invocations are executed.
+    pub fn proposer(&self) -> &PublicKey {
+        &self.proposer
+    }
+}
+
+/// Represents a result of executing the due scheduled invocations.
+#[derive(Debug)]
+pub enum ScheduledInvocationsResult {
+    /// Invalid state root hash.
+    RootNotFound,
+    /// The due invocations were executed.
+    Success {
+        /// State root hash after all the invocations were committed.
+        post_state_hash: Digest,
+        /// The addresses of the executed invocations along with their execution results, in
+        /// order of execution.
+        ///
+        /// The invocations themselves are still present under `post_state_hash` and should be
+        /// pruned by the caller once that state is persisted.
+        execution_results: Vec<(ScheduledInvocationAddr, ExecutionResult)>,
+    },
+}
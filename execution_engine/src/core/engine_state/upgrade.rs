@@ -9,7 +9,7 @@ use casper_types::{
     bytesrepr::{self, ToBytes},
     contracts::NamedKeys,
     system::{handle_payment::ACCUMULATION_PURSE_KEY, SystemContractType},
-    AccessRights, CLValue, CLValueError, Contract, ContractHash, EraId, Key, Phase,
+    AccessRights, CLValue, CLValueError, Contract, ContractHash, EraId, Key, KeyTag, Phase,
     ProtocolVersion, StoredValue, U512,
 };
 
@@ -179,6 +179,9 @@ pub enum ProtocolUpgradeError {
     /// Found unexpected variant of a stored value.
     #[error("Unexpected stored value variant")]
     UnexpectedStoredValueVariant,
+    /// Unable to move the delegators embedded in validator bids to their own records.
+    #[error("Failed to migrate delegators: {0}")]
+    FailedToMigrateDelegators(String),
 }
 
 impl From<bytesrepr::Error> for ProtocolUpgradeError {
@@ -357,7 +360,9 @@ where
         engine_config: &EngineConfig,
     ) -> Result<(), ProtocolUpgradeError> {
         match engine_config.fee_handling() {
-            FeeHandling::PayToProposer | FeeHandling::Burn => return Ok(()),
+            FeeHandling::PayToProposer | FeeHandling::Burn | FeeHandling::Split { .. } => {
+                return Ok(())
+            }
             FeeHandling::Accumulate => {}
         }
 
@@ -411,4 +416,54 @@ where
 
         Ok(())
     }
+
+    /// Moves the delegators embedded in validator bids to their own `Key::Delegator` records.
+    ///
+    /// Bids written before delegators were split out of them carry their delegators inline; after
+    /// the migration every bid is stored with an empty delegators map.
+    pub(crate) fn migrate_delegators(
+        &self,
+        correlation_id: CorrelationId,
+    ) -> Result<(), ProtocolUpgradeError> {
+        let mut tracking_copy = self.tracking_copy.borrow_mut();
+
+        let bid_keys = tracking_copy
+            .get_keys(correlation_id, &KeyTag::Bid)
+            .map_err(|_| {
+                ProtocolUpgradeError::FailedToMigrateDelegators("unable to list bids".to_string())
+            })?;
+
+        for bid_key in bid_keys {
+            let mut bid = match tracking_copy.read(correlation_id, &bid_key).map_err(|_| {
+                ProtocolUpgradeError::FailedToMigrateDelegators(format!(
+                    "unable to read {}",
+                    bid_key
+                ))
+            })? {
+                Some(StoredValue::Bid(bid)) => bid,
+                Some(_) => return Err(ProtocolUpgradeError::UnexpectedStoredValueVariant),
+                None => continue,
+            };
+
+            if bid.delegators().is_empty() {
+                continue;
+            }
+
+            let delegators = bid.take_delegators().map_err(|_| {
+                ProtocolUpgradeError::FailedToMigrateDelegators(format!(
+                    "invalid delegated amount in {}",
+                    bid_key
+                ))
+            })?;
+            for delegator in delegators {
+                tracking_copy.write(
+                    Key::Delegator(delegator.delegator_addr()),
+                    StoredValue::Delegator(Box::new(delegator)),
+                );
+            }
+            tracking_copy.write(bid_key, StoredValue::Bid(bid));
+        }
+
+        Ok(())
+    }
 }
@@ -176,6 +176,31 @@ pub enum Error {
     /// Failed to transfer tokens on a private chain.
     #[error("Failed to transfer with unrestricted transfers disabled")]
     DisabledUnrestrictedTransfers,
+    /// Called entry point is not a view entry point.
+    #[error("Entry point is not a view: {}", _0)]
+    NotAViewEntryPoint(String),
+    /// An attempt to modify global state from within a read-only context.
+    #[error("Write attempted in a read-only context")]
+    WriteInReadOnlyContext,
+    /// Entry point can't be the target of a scheduled invocation.
+    #[error("Entry point cannot be scheduled: {}", _0)]
+    UnschedulableEntryPoint(String),
+    /// An attempt to schedule an invocation at a time which has already been reached.
+    #[error("Scheduled time is not in the future")]
+    ScheduledTimeNotInFuture,
+    /// An attempt to schedule an invocation without prepaying for it.
+    #[error("Scheduled invocation is not prepaid")]
+    ScheduledInvocationNotPrepaid,
+    /// An attempt to schedule an invocation from contract code.
+    #[error("Invocations can only be scheduled by session code")]
+    ScheduleInvocationFromContract,
+    /// An attempt to schedule an invocation before scheduled invocations are activated.
+    #[error("Scheduled invocations are disabled")]
+    DisabledScheduledInvocations,
+    /// An attempt to call the migration entry point of a contract other than when its version is
+    /// added.
+    #[error("Migration entry point can only be called by the engine: {}", _0)]
+    MigrationNotCallable(ContractHash),
 }
 
 impl From<wasm_prep::PreprocessingError> for Error {
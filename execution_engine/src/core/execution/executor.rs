@@ -5,22 +5,22 @@ use casper_types::{
     bytesrepr::FromBytes,
     contracts::NamedKeys,
     system::{auction, handle_payment, mint, AUCTION, HANDLE_PAYMENT, MINT},
-    BlockTime, CLTyped, ContextAccessRights, DeployHash, EntryPointType, Gas, Key, Phase,
-    ProtocolVersion, RuntimeArgs, StoredValue, U512,
+    CLTyped, CLValue, ContextAccessRights, ContractHash, DeployHash, EntryPointType, Gas, Key,
+    Phase, ProtocolVersion, RuntimeArgs, StoredValue, U512,
 };
 
 use crate::{
     core::{
         engine_state::{
-            executable_deploy_item::ExecutionKind, execution_result::ExecutionResult, EngineConfig,
-            ExecError,
+            executable_deploy_item::ExecutionKind, execution_result::ExecutionResult, BlockInfo,
+            EngineConfig, ExecError,
         },
         execution::{address_generator::AddressGenerator, Error},
         runtime::{Runtime, RuntimeStack},
         runtime_context::RuntimeContext,
         tracking_copy::{TrackingCopy, TrackingCopyExt},
     },
-    shared::newtypes::CorrelationId,
+    shared::{gas_profile::GasProfiler, newtypes::CorrelationId},
     storage::global_state::StateReader,
 };
 
@@ -35,12 +35,40 @@ fn try_get_amount(runtime_args: &RuntimeArgs) -> Result<U512, ExecError> {
 /// Executor object deals with execution of WASM modules.
 pub struct Executor {
     config: EngineConfig,
+    gas_profiler: Option<GasProfiler>,
 }
 
 impl Executor {
     /// Creates new executor object.
     pub fn new(config: EngineConfig) -> Self {
-        Executor { config }
+        Executor {
+            config,
+            gas_profiler: None,
+        }
+    }
+
+    /// Attributes the gas charged by executed payment and session code to `gas_profiler`.
+    pub(crate) fn with_gas_profiler(mut self, gas_profiler: Option<GasProfiler>) -> Self {
+        self.gas_profiler = gas_profiler;
+        self
+    }
+
+    /// Enables gas profiling for a context executing the given phase, if a profiler is set.
+    fn attach_gas_profiler<R>(&self, context: &mut RuntimeContext<R>, phase: Phase)
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<Error>,
+    {
+        if let Some(gas_profiler) = &self.gas_profiler {
+            let root_frame = match phase {
+                Phase::System => "system",
+                Phase::Payment => "payment",
+                Phase::Session => "session",
+                Phase::FinalizePayment => "finalize_payment",
+            };
+            gas_profiler.begin_execution(root_frame);
+            context.set_gas_profiler(gas_profiler.clone());
+        }
     }
 
     /// Executes a WASM module.
@@ -56,7 +84,7 @@ impl Executor {
         named_keys: &mut NamedKeys,
         access_rights: ContextAccessRights,
         authorization_keys: BTreeSet<AccountHash>,
-        blocktime: BlockTime,
+        block_info: &BlockInfo,
         deploy_hash: DeployHash,
         gas_limit: Gas,
         protocol_version: ProtocolVersion,
@@ -81,7 +109,7 @@ impl Executor {
             Rc::new(RefCell::new(generator))
         };
 
-        let context = self.create_runtime_context(
+        let mut context = self.create_runtime_context(
             EntryPointType::Session,
             args.clone(),
             named_keys,
@@ -89,7 +117,7 @@ impl Executor {
             Key::from(account.account_hash()),
             account,
             authorization_keys,
-            blocktime,
+            block_info,
             deploy_hash,
             gas_limit,
             address_generator,
@@ -99,6 +127,7 @@ impl Executor {
             phase,
             spending_limit,
         );
+        self.attach_gas_profiler(&mut context, phase);
 
         let mut runtime = Runtime::new(self.config.clone(), context);
 
@@ -122,12 +151,14 @@ impl Executor {
                 execution_journal: runtime.context().execution_journal(),
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                fee_breakdown: None,
             },
             Err(error) => ExecutionResult::Failure {
                 error: error.into(),
                 execution_journal: runtime.context().execution_journal(),
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                fee_breakdown: None,
             },
         }
     }
@@ -142,7 +173,7 @@ impl Executor {
         payment_named_keys: &mut NamedKeys,
         access_rights: ContextAccessRights,
         authorization_keys: BTreeSet<AccountHash>,
-        blocktime: BlockTime,
+        block_info: &BlockInfo,
         deploy_hash: DeployHash,
         payment_gas_limit: Gas,
         protocol_version: ProtocolVersion,
@@ -167,7 +198,7 @@ impl Executor {
             Rc::new(RefCell::new(generator))
         };
 
-        let runtime_context = self.create_runtime_context(
+        let mut runtime_context = self.create_runtime_context(
             EntryPointType::Session,
             payment_args,
             payment_named_keys,
@@ -175,7 +206,7 @@ impl Executor {
             payment_base_key,
             account,
             authorization_keys,
-            blocktime,
+            block_info,
             deploy_hash,
             payment_gas_limit,
             address_generator,
@@ -185,6 +216,7 @@ impl Executor {
             phase,
             spending_limit,
         );
+        self.attach_gas_profiler(&mut runtime_context, phase);
 
         let execution_journal = tracking_copy.borrow().execution_journal();
 
@@ -197,12 +229,14 @@ impl Executor {
                 execution_journal: runtime.context().execution_journal(),
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                fee_breakdown: None,
             },
             Err(error) => ExecutionResult::Failure {
                 execution_journal,
                 error: error.into(),
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                fee_breakdown: None,
             },
         }
     }
@@ -216,7 +250,7 @@ impl Executor {
         runtime_args: RuntimeArgs,
         account: &Account,
         authorization_keys: BTreeSet<AccountHash>,
-        blocktime: BlockTime,
+        block_info: &BlockInfo,
         deploy_hash: DeployHash,
         gas_limit: Gas,
         protocol_version: ProtocolVersion,
@@ -295,7 +329,7 @@ impl Executor {
             base_key,
             account,
             authorization_keys,
-            blocktime,
+            block_info,
             deploy_hash,
             gas_limit,
             address_generator,
@@ -322,6 +356,7 @@ impl Executor {
                     execution_journal: runtime.context().execution_journal(),
                     transfers: runtime.context().transfers().to_owned(),
                     cost: runtime.context().gas_counter(),
+                    fee_breakdown: None,
                 }
                 .take_with_ret(ret),
                 Err(error) => ExecutionResult::Failure {
@@ -329,6 +364,7 @@ impl Executor {
                     error: Error::CLValue(error).into(),
                     transfers: runtime.context().transfers().to_owned(),
                     cost: runtime.context().gas_counter(),
+                    fee_breakdown: None,
                 }
                 .take_without_ret(),
             },
@@ -337,11 +373,74 @@ impl Executor {
                 error: error.into(),
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                fee_breakdown: None,
             }
             .take_without_ret(),
         }
     }
 
+    /// Evaluates a view entry point of a stored contract in a read-only context.
+    ///
+    /// The view is called on behalf of `account` from a session context, exactly as if it was
+    /// invoked by session code, but any attempt to modify global state fails the evaluation.
+    /// Returns the value returned by the entry point along with the gas it consumed.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn call_view<R>(
+        &self,
+        contract_hash: ContractHash,
+        entry_point_name: &str,
+        args: RuntimeArgs,
+        account: &Account,
+        block_info: &BlockInfo,
+        gas_limit: Gas,
+        protocol_version: ProtocolVersion,
+        correlation_id: CorrelationId,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        stack: RuntimeStack,
+    ) -> (Result<CLValue, Error>, Gas)
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<Error>,
+    {
+        // Views are not part of any deploy, so there is no deploy hash to seed the address
+        // generator with. This is fine as no new addresses can be written to global state.
+        let deploy_hash = DeployHash::default();
+        let phase = Phase::Session;
+        let address_generator = {
+            let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
+            Rc::new(RefCell::new(generator))
+        };
+
+        let mut named_keys = account.named_keys().clone();
+        let access_rights = account.extract_access_rights();
+        let authorization_keys = BTreeSet::from([account.account_hash()]);
+
+        let mut runtime_context = self.create_runtime_context(
+            EntryPointType::Session,
+            args.clone(),
+            &mut named_keys,
+            access_rights,
+            Key::from(account.account_hash()),
+            account,
+            authorization_keys,
+            block_info,
+            deploy_hash,
+            gas_limit,
+            address_generator,
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            phase,
+            U512::zero(),
+        );
+        runtime_context.set_read_only();
+
+        let mut runtime = Runtime::new(self.config.clone(), runtime_context);
+        let result =
+            runtime.call_contract_view_with_stack(contract_hash, entry_point_name, args, stack);
+        (result, runtime.context().gas_counter())
+    }
+
     /// Creates new runtime context.
     #[allow(clippy::too_many_arguments)]
     fn create_runtime_context<'a, R>(
@@ -353,7 +452,7 @@ impl Executor {
         base_key: Key,
         account: &'a Account,
         authorization_keys: BTreeSet<AccountHash>,
-        blocktime: BlockTime,
+        block_info: &BlockInfo,
         deploy_hash: DeployHash,
         gas_limit: Gas,
         address_generator: Rc<RefCell<AddressGenerator>>,
@@ -379,7 +478,7 @@ impl Executor {
             authorization_keys,
             account,
             base_key,
-            blocktime,
+            block_info.clone(),
             deploy_hash,
             gas_limit,
             gas_counter,
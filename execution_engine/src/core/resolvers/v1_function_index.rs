@@ -58,6 +58,10 @@ pub(crate) enum FunctionIndex {
     RandomBytes,
     DictionaryReadFuncIndex,
     EnableContractVersion,
+    GetBlockInfoIndex,
+    CallContractViewIndex,
+    AddContractVersionWithStorageSchema,
+    ScheduleInvocationIndex,
 }
 
 impl From<FunctionIndex> for usize {
@@ -245,6 +245,22 @@ impl ModuleImportResolver for RuntimeModuleImportResolver {
                 Signature::new(&[ValueType::I32; 4][..], Some(ValueType::I32)),
                 FunctionIndex::EnableContractVersion.into(),
             ),
+            "casper_get_block_info" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 2][..], Some(ValueType::I32)),
+                FunctionIndex::GetBlockInfoIndex.into(),
+            ),
+            "casper_call_contract_view" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 7][..], Some(ValueType::I32)),
+                FunctionIndex::CallContractViewIndex.into(),
+            ),
+            "casper_add_contract_version_with_storage_schema" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 11][..], Some(ValueType::I32)),
+                FunctionIndex::AddContractVersionWithStorageSchema.into(),
+            ),
+            "casper_schedule_invocation" => FuncInstance::alloc_host(
+                Signature::new(&[ValueType::I32; 13][..], Some(ValueType::I32)),
+                FunctionIndex::ScheduleInvocationIndex.into(),
+            ),
             _ => {
                 return Err(InterpreterError::Function(format!(
                     "host module doesn't export function with name {}",
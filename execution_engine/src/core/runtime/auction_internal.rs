@@ -5,10 +5,10 @@ use casper_types::{
     bytesrepr::{FromBytes, ToBytes},
     crypto,
     system::{
-        auction::{Bid, EraInfo, Error, UnbondingPurse},
+        auction::{Bid, Delegator, EraInfo, Error, UnbondingPurse},
         mint,
     },
-    CLTyped, CLValue, EraId, Key, KeyTag, PublicKey, RuntimeArgs, StoredValue, URef,
+    CLTyped, CLValue, DelegatorAddr, EraId, Key, KeyTag, PublicKey, RuntimeArgs, StoredValue, URef,
     BLAKE2B_DIGEST_LENGTH, U512,
 };
 
@@ -80,6 +80,34 @@ where
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
     }
 
+    fn read_delegator(
+        &mut self,
+        validator_account_hash: AccountHash,
+        delegator_account_hash: AccountHash,
+    ) -> Result<Option<Delegator>, Error> {
+        let key = Key::Delegator(DelegatorAddr::new(
+            validator_account_hash,
+            delegator_account_hash,
+        ));
+        match self.context.read_gs(&key) {
+            Ok(Some(StoredValue::Delegator(delegator))) => Ok(Some(*delegator)),
+            Ok(Some(_)) => Err(Error::Storage),
+            Ok(None) => Ok(None),
+            Err(execution::Error::BytesRepr(_)) => Err(Error::Serialization),
+            // NOTE: This extra condition is needed to correctly propagate GasLimit to the user. See
+            // also [`Runtime::reverter`] and [`to_auction_error`]
+            Err(execution::Error::GasLimit) => Err(Error::GasLimit),
+            Err(_) => Err(Error::Storage),
+        }
+    }
+
+    fn write_delegator(&mut self, delegator: Delegator) -> Result<(), Error> {
+        let key = Key::Delegator(delegator.delegator_addr());
+        self.context
+            .metered_write_gs_unsafe(key, StoredValue::Delegator(Box::new(delegator)))
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
     fn read_unbond(&mut self, account_hash: &AccountHash) -> Result<Vec<UnbondingPurse>, Error> {
         match self.context.read_gs(&Key::Unbond(*account_hash)) {
             Ok(Some(StoredValue::Unbonding(unbonding_purses))) => Ok(unbonding_purses),
@@ -133,6 +161,12 @@ where
         self.context.get_keys(key_tag).map_err(|_| Error::Storage)
     }
 
+    fn get_keys_by_prefix(&mut self, prefix: &[u8]) -> Result<BTreeSet<Key>, Error> {
+        self.context
+            .get_keys_by_prefix(prefix)
+            .map_err(|_| Error::Storage)
+    }
+
     fn blake2b<T: AsRef<[u8]>>(&self, data: T) -> [u8; BLAKE2B_DIGEST_LENGTH] {
         crypto::blake2b(data)
     }
@@ -144,6 +178,17 @@ where
     fn allow_auction_bids(&self) -> bool {
         self.config.allow_auction_bids()
     }
+
+    fn delegation_rate_change_notice_eras(&self) -> u64 {
+        self.config.delegation_rate_change_notice_eras()
+    }
+
+    fn allow_bls_keys(&self) -> bool {
+        self.context.protocol_version()
+            >= self
+                .config
+                .start_protocol_version_with_aggregate_finality_signatures()
+    }
 }
 
 impl<'a, R> MintProvider for Runtime<'a, R>
@@ -9,7 +9,7 @@ use casper_types::{
     contracts::{ContractPackageStatus, EntryPoints, NamedKeys},
     crypto,
     system::auction::EraInfo,
-    ApiError, ContractHash, ContractPackageHash, ContractVersion, EraId, Gas, Group, Key,
+    ApiError, ContractHash, ContractPackageHash, ContractVersion, EraId, Group, Key, ScheduledTime,
     StoredValue, URef, U512, UREF_SERIALIZED_LENGTH,
 };
 
@@ -32,6 +32,13 @@ where
     ) -> Result<Option<RuntimeValue>, Trap> {
         let func = FunctionIndex::try_from(index).expect("unknown function index");
 
+        let _gas_profiler_frame = match func {
+            FunctionIndex::GasFuncIndex => None,
+            _ => self
+                .context
+                .enter_gas_profiler_frame(|| format!("host:{:?}", func)),
+        };
+
         let host_function_costs = self.config.wasm_config().take_host_function_costs();
 
         match func {
@@ -189,7 +196,7 @@ where
                 let (gas_arg,): (u32,) = Args::parse(args)?;
                 // Gas is special cased internal host function and for accounting purposes it isn't
                 // represented in protocol data.
-                self.gas(Gas::new(gas_arg.into()))?;
+                self.charge_wasm_gas(gas_arg)?;
                 Ok(None)
             }
 
@@ -643,6 +650,7 @@ where
                     output_size as usize,
                     bytes_written_ptr,
                     version_ptr,
+                    None,
                 )?;
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
             }
@@ -1097,6 +1105,195 @@ where
 
                 Ok(Some(RuntimeValue::I32(api_error::i32_from(result))))
             }
+
+            FunctionIndex::GetBlockInfoIndex => {
+                // args(0) = field of the block info to read
+                // args(1) = pointer to output size (output param)
+                let (field_idx, output_size_ptr): (u32, u32) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.get_block_info,
+                    [field_idx, output_size_ptr],
+                )?;
+                let ret = self.get_block_info(field_idx, output_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::CallContractViewIndex => {
+                // args(0) = pointer to contract hash where contract is at in global state
+                // args(1) = size of contract hash
+                // args(2) = pointer to view entry point
+                // args(3) = size of view entry point
+                // args(4) = pointer to function arguments in Wasm memory
+                // args(5) = size of arguments
+                // args(6) = pointer to result size (output)
+                let (
+                    contract_hash_ptr,
+                    contract_hash_size,
+                    entry_point_name_ptr,
+                    entry_point_name_size,
+                    args_ptr,
+                    args_size,
+                    result_size_ptr,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.call_contract_view,
+                    [
+                        contract_hash_ptr,
+                        contract_hash_size,
+                        entry_point_name_ptr,
+                        entry_point_name_size,
+                        args_ptr,
+                        args_size,
+                        result_size_ptr,
+                    ],
+                )?;
+
+                let contract_hash: ContractHash =
+                    self.t_from_mem(contract_hash_ptr, contract_hash_size)?;
+                let entry_point_name: String =
+                    self.t_from_mem(entry_point_name_ptr, entry_point_name_size)?;
+                let args_bytes: Vec<u8> = {
+                    let args_size: u32 = args_size;
+                    self.bytes_from_mem(args_ptr, args_size as usize)?.to_vec()
+                };
+
+                let ret = self.call_contract_view_host_buffer(
+                    contract_hash,
+                    &entry_point_name,
+                    &args_bytes,
+                    result_size_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::AddContractVersionWithStorageSchema => {
+                // args(0) = pointer to package key in wasm memory
+                // args(1) = size of package key in wasm memory
+                // args(2) = pointer to contract version in wasm memory (output param)
+                // args(3) = pointer to entrypoints in wasm memory
+                // args(4) = size of entrypoints in wasm memory
+                // args(5) = pointer to named keys in wasm memory
+                // args(6) = size of named keys in wasm memory
+                // args(7) = storage schema version of the new contract version
+                // args(8) = pointer to output buffer for serialized key
+                // args(9) = size of output buffer
+                // args(10) = pointer to bytes written
+                let (
+                    contract_package_hash_ptr,
+                    contract_package_hash_size,
+                    version_ptr,
+                    entry_points_ptr,
+                    entry_points_size,
+                    named_keys_ptr,
+                    named_keys_size,
+                    storage_schema_version,
+                    output_ptr,
+                    output_size,
+                    bytes_written_ptr,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.add_contract_version_with_storage_schema,
+                    [
+                        contract_package_hash_ptr,
+                        contract_package_hash_size,
+                        version_ptr,
+                        entry_points_ptr,
+                        entry_points_size,
+                        named_keys_ptr,
+                        named_keys_size,
+                        storage_schema_version,
+                        output_ptr,
+                        output_size,
+                        bytes_written_ptr,
+                    ],
+                )?;
+
+                let contract_package_hash: ContractPackageHash =
+                    self.t_from_mem(contract_package_hash_ptr, contract_package_hash_size)?;
+                let entry_points: EntryPoints =
+                    self.t_from_mem(entry_points_ptr, entry_points_size)?;
+                let named_keys: NamedKeys = self.t_from_mem(named_keys_ptr, named_keys_size)?;
+                let ret = self.add_contract_version(
+                    contract_package_hash,
+                    entry_points,
+                    named_keys,
+                    output_ptr,
+                    output_size as usize,
+                    bytes_written_ptr,
+                    version_ptr,
+                    Some(storage_schema_version),
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::ScheduleInvocationIndex => {
+                // args(0) = pointer to contract hash where contract is at in global state
+                // args(1) = size of contract hash
+                // args(2) = pointer to entry point name
+                // args(3) = size of entry point name
+                // args(4) = pointer to function arguments in Wasm memory
+                // args(5) = size of arguments
+                // args(6) = pointer to scheduled time in Wasm memory
+                // args(7) = size of scheduled time
+                // args(8) = pointer to source purse in Wasm memory
+                // args(9) = size of source purse
+                // args(10) = pointer to amount in Wasm memory
+                // args(11) = size of amount
+                // args(12) = pointer to output buffer for the address of the invocation
+                let (
+                    contract_hash_ptr,
+                    contract_hash_size,
+                    entry_point_name_ptr,
+                    entry_point_name_size,
+                    args_ptr,
+                    args_size,
+                    scheduled_time_ptr,
+                    scheduled_time_size,
+                    source_ptr,
+                    source_size,
+                    amount_ptr,
+                    amount_size,
+                    dest_ptr,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.schedule_invocation,
+                    [
+                        contract_hash_ptr,
+                        contract_hash_size,
+                        entry_point_name_ptr,
+                        entry_point_name_size,
+                        args_ptr,
+                        args_size,
+                        scheduled_time_ptr,
+                        scheduled_time_size,
+                        source_ptr,
+                        source_size,
+                        amount_ptr,
+                        amount_size,
+                        dest_ptr,
+                    ],
+                )?;
+
+                let contract_hash: ContractHash =
+                    self.t_from_mem(contract_hash_ptr, contract_hash_size)?;
+                let entry_point_name: String =
+                    self.t_from_mem(entry_point_name_ptr, entry_point_name_size)?;
+                let args: casper_types::RuntimeArgs = self.t_from_mem(args_ptr, args_size)?;
+                let scheduled_time: ScheduledTime =
+                    self.t_from_mem(scheduled_time_ptr, scheduled_time_size)?;
+                let source: URef = self.t_from_mem(source_ptr, source_size)?;
+                let amount: U512 = self.t_from_mem(amount_ptr, amount_size)?;
+                let ret = self.schedule_invocation(
+                    contract_hash,
+                    entry_point_name,
+                    args,
+                    scheduled_time,
+                    source,
+                    amount,
+                    dest_ptr,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
         }
     }
 }
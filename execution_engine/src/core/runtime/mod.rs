@@ -18,7 +18,7 @@ use std::{
 
 use casper_wasm::elements::Module;
 use casper_wasmi::{MemoryRef, Trap, TrapCode};
-use tracing::error;
+use tracing::{error, warn};
 
 #[cfg(feature = "test-support")]
 use casper_wasmi::RuntimeValue;
@@ -32,7 +32,8 @@ use casper_types::{
     contracts::{
         self, Contract, ContractPackage, ContractPackageStatus, ContractVersion, ContractVersions,
         DisabledVersions, EntryPoint, EntryPointAccess, EntryPoints, Group, Groups, NamedKeys,
-        DEFAULT_ENTRY_POINT_NAME,
+        StorageSchemaVersion, DEFAULT_ENTRY_POINT_NAME, MIGRATE_ARG_PREVIOUS_CONTRACT_HASH,
+        MIGRATE_ARG_PREVIOUS_STORAGE_SCHEMA_VERSION, MIGRATE_ENTRY_POINT_NAME,
     },
     system::{
         self,
@@ -40,10 +41,11 @@ use casper_types::{
         handle_payment, mint, standard_payment, CallStackElement, SystemContractType, AUCTION,
         HANDLE_PAYMENT, MINT, STANDARD_PAYMENT,
     },
-    AccessRights, ApiError, CLTyped, CLValue, ContextAccessRights, ContractHash,
+    AccessRights, ApiError, BlockInfoField, CLTyped, CLValue, ContextAccessRights, ContractHash,
     ContractPackageHash, ContractVersionKey, ContractWasm, DeployHash, EntryPointType, Gas,
-    GrantedAccess, Key, NamedArg, Parameter, Phase, PublicKey, RuntimeArgs, StoredValue, Transfer,
-    TransferResult, TransferredTo, URef, DICTIONARY_ITEM_KEY_MAX_LENGTH, U512,
+    GrantedAccess, Key, NamedArg, Parameter, Phase, PublicKey, RuntimeArgs, ScheduledInvocation,
+    ScheduledTime, Signature, StoredValue, Timestamp, Transfer, TransferResult, TransferredTo,
+    URef, DICTIONARY_ITEM_KEY_MAX_LENGTH, U512,
 };
 
 use crate::{
@@ -55,6 +57,7 @@ use crate::{
         tracking_copy::TrackingCopyExt,
     },
     shared::{
+        gas_profile::{self, GasSites},
         host_function_costs::{Cost, HostFunction},
         wasm_prep::{self, PreprocessingError},
     },
@@ -76,6 +79,17 @@ enum CallContractIdentifier {
     },
 }
 
+/// The way an entry point of a stored contract is called.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum CallKind {
+    /// A call made by session or contract code.
+    Regular,
+    /// A call to a view entry point, executed in a read-only context.
+    View,
+    /// The engine's call to the migration entry point of a contract version being added.
+    Migration,
+}
+
 /// Represents the runtime properties of a WASM execution.
 pub struct Runtime<'a, R> {
     config: EngineConfig,
@@ -85,6 +99,9 @@ pub struct Runtime<'a, R> {
     context: RuntimeContext<'a, R>,
     stack: Option<RuntimeStack>,
     host_function_flag: HostFunctionFlag,
+    gas_sites: Option<GasSites>,
+    /// Gas site whose amount is passed by the next call to the gas function.
+    announced_gas_site: Option<u32>,
 }
 
 impl<'a, R> Runtime<'a, R>
@@ -102,6 +119,8 @@ where
             context,
             stack: None,
             host_function_flag: HostFunctionFlag::default(),
+            gas_sites: None,
+            announced_gas_site: None,
         }
     }
 
@@ -122,6 +141,8 @@ where
             context,
             stack: Some(stack),
             host_function_flag: self.host_function_flag.clone(),
+            gas_sites: None,
+            announced_gas_site: None,
         }
     }
 
@@ -140,6 +161,8 @@ where
             context,
             stack: Some(stack),
             host_function_flag: self.host_function_flag.clone(),
+            gas_sites: None,
+            announced_gas_site: None,
         }
     }
 
@@ -166,6 +189,53 @@ where
         self.context.charge_gas(amount)
     }
 
+    /// Charges the gas injected at the start of a metered block of Wasm code.
+    ///
+    /// If the module was instrumented for gas profiling, `gas_arg` is the id of the block's gas
+    /// site rather than its cost, unless it follows the id of a site whose cost is only known at
+    /// runtime, in which case it is that cost.
+    fn charge_wasm_gas(&mut self, gas_arg: u32) -> Result<(), Error> {
+        let gas_sites = match self.gas_sites.clone() {
+            Some(gas_sites) => gas_sites,
+            None => return self.gas(Gas::from(gas_arg)),
+        };
+        let (id, amount) = match self.announced_gas_site.take() {
+            Some(id) => (id, Some(Gas::from(gas_arg))),
+            None => (gas_arg, None),
+        };
+        let gas_site = gas_sites
+            .get(id)
+            .ok_or_else(|| Error::Interpreter(format!("unknown gas site {}", id)))?;
+        match amount.or_else(|| gas_site.cost()) {
+            Some(amount) => self.context.charge_gas_site(gas_site, amount),
+            None => {
+                self.announced_gas_site = Some(id);
+                Ok(())
+            }
+        }
+    }
+
+    /// Instruments a module for gas profiling if it is enabled, returning the module to
+    /// instantiate along with its gas sites.
+    ///
+    /// The original module is kept for everything else, e.g. installing contracts, so profiling
+    /// never leaks into global state.
+    fn instrument_for_gas_profiling(&self, module: &Module) -> (Module, Option<GasSites>) {
+        if self.context.gas_profiler().is_none() {
+            return (module.clone(), None);
+        }
+        match gas_profile::instrument_gas_sites(module, &self.config.wasm_config().opcode_costs()) {
+            Some((instrumented, gas_sites)) => (instrumented, Some(gas_sites)),
+            None => {
+                warn!(
+                    "couldn't instrument module for gas profiling; its gas won't be attributed \
+                    to Wasm functions"
+                );
+                (module.clone(), None)
+            }
+        }
+    }
+
     /// Returns current gas counter.
     fn gas_counter(&self) -> Gas {
         self.context.gas_counter()
@@ -427,6 +497,51 @@ where
             .map_err(|e| Error::Interpreter(e.into()).into())
     }
 
+    /// Writes the requested field of the current block context into the host buffer and its
+    /// serialized size to `output_size_ptr` in Wasm memory.
+    fn get_block_info(
+        &mut self,
+        field_idx: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+        let field = match u8::try_from(field_idx)
+            .ok()
+            .and_then(BlockInfoField::from_u8)
+        {
+            Some(field) => field,
+            None => return Ok(Err(ApiError::InvalidArgument)),
+        };
+        let block_info = self.context.block_info();
+        let value = match field {
+            BlockInfoField::BlockHeight => CLValue::from_t(block_info.block_height()),
+            BlockInfoField::EraId => CLValue::from_t(block_info.era_id()),
+            BlockInfoField::ParentBlockHash => {
+                CLValue::from_t(block_info.parent_block_hash().value())
+            }
+            BlockInfoField::DeployHash => CLValue::from_t(self.context.get_deploy_hash().value()),
+            BlockInfoField::ChainName => CLValue::from_t(block_info.chain_name().to_string()),
+        }
+        .map_err(Error::CLValue)?;
+        let value_size = value.inner_bytes().len();
+
+        if let Err(error) = self.write_host_buffer(value) {
+            return Ok(Err(error));
+        }
+
+        let output_size_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self
+            .try_get_memory()?
+            .set(output_size_ptr, &output_size_bytes)
+        {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+        Ok(Ok(()))
+    }
+
     /// Load the uref known by the given name into the Wasm memory
     fn load_call_stack(
         &mut self,
@@ -576,6 +691,20 @@ where
             .map_err(|_| Error::Revert(ApiError::InvalidArgument))
     }
 
+    /// Returns the named argument of type `Option<T>`, treating a missing argument as `None`.
+    fn get_optional_named_argument<T: FromBytes + CLTyped>(
+        args: &RuntimeArgs,
+        name: &str,
+    ) -> Result<Option<T>, Error> {
+        match args.get(name) {
+            Some(arg) => arg
+                .clone()
+                .into_t()
+                .map_err(|_| Error::Revert(ApiError::InvalidArgument)),
+            None => Ok(None),
+        }
+    }
+
     fn reverter<T: Into<ApiError>>(error: T) -> Error {
         let api_error: ApiError = error.into();
         // NOTE: This is special casing needed to keep the native system contracts propagate
@@ -706,11 +835,13 @@ where
 
         // Charge just for the amount that particular entry point cost - using gas cost from the
         // isolated runtime might have a recursive costs whenever system contract calls other system
-        // contract.
-        self.gas(match mint_runtime.gas_counter().checked_sub(gas_counter) {
-            None => gas_counter,
-            Some(new_gas) => new_gas,
-        })?;
+        // contract.  The gas was already attributed while profiling the isolated runtime.
+        self.context.charge_gas_unprofiled(
+            match mint_runtime.gas_counter().checked_sub(gas_counter) {
+                None => gas_counter,
+                Some(new_gas) => new_gas,
+            },
+        )?;
 
         // Result still contains a result, but the entrypoints logic does not exit early on errors.
         let ret = result?;
@@ -807,10 +938,12 @@ where
             _ => CLValue::from_t(()).map_err(Self::reverter),
         };
 
-        self.gas(match runtime.gas_counter().checked_sub(gas_counter) {
-            None => gas_counter,
-            Some(new_gas) => new_gas,
-        })?;
+        self.context.charge_gas_unprofiled(
+            match runtime.gas_counter().checked_sub(gas_counter) {
+                None => gas_counter,
+                Some(new_gas) => new_gas,
+            },
+        )?;
 
         let ret = result?;
         let urefs = utils::extract_urefs(&ret)?;
@@ -883,9 +1016,17 @@ where
                 let delegation_rate =
                     Self::get_named_argument(runtime_args, auction::ARG_DELEGATION_RATE)?;
                 let amount = Self::get_named_argument(runtime_args, auction::ARG_AMOUNT)?;
+                let proof_of_possession: Option<Signature> =
+                    Self::get_optional_named_argument::<Bytes>(
+                        runtime_args,
+                        auction::ARG_PROOF_OF_POSSESSION,
+                    )?
+                    .map(|proof| bytesrepr::deserialize(proof.into()))
+                    .transpose()
+                    .map_err(|_| Error::Revert(ApiError::InvalidArgument))?;
 
                 let result = runtime
-                    .add_bid(account_hash, delegation_rate, amount)
+                    .add_bid(account_hash, delegation_rate, amount, proof_of_possession)
                     .map_err(Self::reverter)?;
 
                 CLValue::from_t(result).map_err(Self::reverter)
@@ -926,6 +1067,32 @@ where
                 CLValue::from_t(result).map_err(Self::reverter)
             })(),
 
+            auction::METHOD_DELEGATE_WITH_VESTING => (|| {
+                runtime.charge_system_contract_call(auction_costs.delegate)?;
+
+                let delegator = Self::get_named_argument(runtime_args, auction::ARG_DELEGATOR)?;
+                let validator = Self::get_named_argument(runtime_args, auction::ARG_VALIDATOR)?;
+                let amount = Self::get_named_argument(runtime_args, auction::ARG_AMOUNT)?;
+                let release_timestamp_millis =
+                    Self::get_named_argument(runtime_args, auction::ARG_RELEASE_TIMESTAMP_MILLIS)?;
+
+                let max_delegators_per_validator = self.config.max_delegators_per_validator();
+                let minimum_delegation_amount = self.config.minimum_delegation_amount();
+
+                let result = runtime
+                    .delegate_with_vesting(
+                        delegator,
+                        validator,
+                        amount,
+                        release_timestamp_millis,
+                        max_delegators_per_validator,
+                        minimum_delegation_amount,
+                    )
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
             auction::METHOD_UNDELEGATE => (|| {
                 runtime.charge_system_contract_call(auction_costs.undelegate)?;
 
@@ -1031,11 +1198,14 @@ where
             _ => CLValue::from_t(()).map_err(Self::reverter),
         };
 
-        // Charge for the gas spent during execution in an isolated runtime.
-        self.gas(match runtime.gas_counter().checked_sub(gas_counter) {
-            None => gas_counter,
-            Some(new_gas) => new_gas,
-        })?;
+        // Charge for the gas spent during execution in an isolated runtime, which was already
+        // attributed while profiling it.
+        self.context.charge_gas_unprofiled(
+            match runtime.gas_counter().checked_sub(gas_counter) {
+                None => gas_counter,
+                Some(new_gas) => new_gas,
+            },
+        )?;
 
         // Result still contains a result, but the entrypoints logic does not exit early on errors.
         let ret = result?;
@@ -1070,10 +1240,12 @@ where
         let protocol_version = self.context.protocol_version();
         let engine_config = self.config.clone();
         let module = wasm_prep::preprocess(*engine_config.wasm_config(), module_bytes)?;
+        let (instance_module, gas_sites) = self.instrument_for_gas_profiling(&module);
         let (instance, memory) =
-            utils::instance_and_memory(module.clone(), protocol_version, &engine_config)?;
+            utils::instance_and_memory(instance_module, protocol_version, &engine_config)?;
         self.memory = Some(memory);
         self.module = Some(module);
+        self.gas_sites = gas_sites;
         self.stack = Some(stack);
         self.context.set_args(utils::attenuate_uref_in_args(
             self.context.args().clone(),
@@ -1122,7 +1294,35 @@ where
     ) -> Result<CLValue, Error> {
         let identifier = CallContractIdentifier::Contract { contract_hash };
 
-        self.execute_contract(identifier, entry_point_name, args)
+        self.execute_contract(identifier, entry_point_name, args, CallKind::Regular)
+    }
+
+    /// Calls the view entry point `entry_point_name` of the contract living under
+    /// `contract_hash`, with supplied `args`.
+    ///
+    /// The view is executed in a read-only context: any attempt by it, or by contracts it calls,
+    /// to modify global state results in an error.
+    pub fn call_contract_view(
+        &mut self,
+        contract_hash: ContractHash,
+        entry_point_name: &str,
+        args: RuntimeArgs,
+    ) -> Result<CLValue, Error> {
+        let identifier = CallContractIdentifier::Contract { contract_hash };
+
+        self.execute_contract(identifier, entry_point_name, args, CallKind::View)
+    }
+
+    /// Call a contract's view entry point by pushing a stack element onto the frame.
+    pub(crate) fn call_contract_view_with_stack(
+        &mut self,
+        contract_hash: ContractHash,
+        entry_point_name: &str,
+        args: RuntimeArgs,
+        stack: RuntimeStack,
+    ) -> Result<CLValue, Error> {
+        self.stack = Some(stack);
+        self.call_contract_view(contract_hash, entry_point_name, args)
     }
 
     /// Calls `version` of the contract living at `key`, invoking `method` with
@@ -1140,7 +1340,7 @@ where
             version: contract_version,
         };
 
-        self.execute_contract(identifier, &entry_point_name, args)
+        self.execute_contract(identifier, &entry_point_name, args, CallKind::Regular)
     }
 
     fn get_context_key_for_contract_call(
@@ -1185,6 +1385,7 @@ where
         identifier: CallContractIdentifier,
         entry_point_name: &str,
         args: RuntimeArgs,
+        call_kind: CallKind,
     ) -> Result<CLValue, Error> {
         let (contract, contract_hash, contract_package) = match identifier {
             CallContractIdentifier::Contract { contract_hash } => {
@@ -1243,6 +1444,30 @@ where
             .cloned()
             .ok_or_else(|| Error::NoSuchMethod(entry_point_name.to_owned()))?;
 
+        if call_kind == CallKind::View && !entry_point.is_view() {
+            return Err(Error::NotAViewEntryPoint(entry_point_name.to_owned()));
+        }
+
+        // The migration of a contract version is run by the engine only, once, as the version is
+        // added.
+        if call_kind != CallKind::Migration
+            && entry_point_name == MIGRATE_ENTRY_POINT_NAME
+            && contract.protocol_version()
+                >= self
+                    .config
+                    .start_protocol_version_with_contract_migrations()
+        {
+            return Err(Error::MigrationNotCallable(contract_hash));
+        }
+
+        let _gas_profiler_frame = self.context.enter_gas_profiler_frame(|| {
+            format!(
+                "{}::{}",
+                contract_hash.to_formatted_string(),
+                entry_point_name
+            )
+        });
+
         // Get contract entry point hash
         // if public, allowed
         // if not public, restricted to user group access
@@ -1395,17 +1620,22 @@ where
             casper_wasm::deserialize_buffer(contract_wasm.bytes())?
         };
 
-        let context = self.context.new_from_self(
+        let mut context = self.context.new_from_self(
             context_key,
             entry_point.entry_point_type(),
             &mut named_keys,
             access_rights,
             context_args,
         );
+        if call_kind == CallKind::View {
+            context.set_read_only();
+        }
         let protocol_version = self.context.protocol_version();
+        let (instance_module, gas_sites) = self.instrument_for_gas_profiling(&module);
         let (instance, memory) =
-            utils::instance_and_memory(module.clone(), protocol_version, &self.config)?;
+            utils::instance_and_memory(instance_module, protocol_version, &self.config)?;
         let runtime = &mut Runtime::new_invocation_runtime(self, context, module, memory, stack);
+        runtime.gas_sites = gas_sites;
 
         let result = instance.invoke_export(entry_point.name(), &[], runtime);
 
@@ -1488,6 +1718,22 @@ where
         self.manage_call_contract_host_buffer(result_size_ptr, result)
     }
 
+    fn call_contract_view_host_buffer(
+        &mut self,
+        contract_hash: ContractHash,
+        entry_point_name: &str,
+        args_bytes: &[u8],
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        // Exit early if the host buffer is already occupied
+        if let Err(err) = self.check_host_buffer() {
+            return Ok(Err(err));
+        }
+        let args: RuntimeArgs = bytesrepr::deserialize_from_slice(args_bytes)?;
+        let result = self.call_contract_view(contract_hash, entry_point_name, args)?;
+        self.manage_call_contract_host_buffer(result_size_ptr, result)
+    }
+
     fn call_versioned_contract_host_buffer(
         &mut self,
         contract_package_hash: ContractPackageHash,
@@ -1704,6 +1950,7 @@ where
         output_size: usize,
         bytes_written_ptr: u32,
         version_ptr: u32,
+        storage_schema_version: Option<StorageSchemaVersion>,
     ) -> Result<Result<(), ApiError>, Error> {
         self.context
             .validate_key(&Key::from(contract_package_hash))?;
@@ -1730,8 +1977,12 @@ where
         let protocol_version = self.context.protocol_version();
         let major = protocol_version.value().major;
 
+        let previous_contract_hash = contract_package.current_contract_hash();
+        let previous_storage_schema_version =
+            version.and_then(|version| contract_package.storage_schema_version(version));
+
         // TODO: EE-1032 - Implement different ways of carrying on existing named keys
-        if let Some(previous_contract_hash) = contract_package.current_contract_hash() {
+        if let Some(previous_contract_hash) = previous_contract_hash {
             let previous_contract: Contract =
                 self.context.read_gs_typed(&previous_contract_hash.into())?;
 
@@ -1739,6 +1990,12 @@ where
             named_keys.append(&mut previous_named_keys);
         }
 
+        let has_migration = entry_points.has_entry_point(MIGRATE_ENTRY_POINT_NAME)
+            && protocol_version
+                >= self
+                    .config
+                    .start_protocol_version_with_contract_migrations();
+
         let contract = Contract::new(
             contract_package_hash,
             contract_wasm_hash.into(),
@@ -1750,6 +2007,14 @@ where
         let insert_contract_result =
             contract_package.insert_contract_version(major, contract_hash.into());
 
+        if let Some(storage_schema_version) = storage_schema_version {
+            if let Err(err) = contract_package
+                .set_storage_schema_version(insert_contract_result, storage_schema_version)
+            {
+                return Ok(Err(err.into()));
+            }
+        }
+
         self.context
             .metered_write_gs_unsafe(Key::Hash(contract_wasm_hash), contract_wasm)?;
         self.context
@@ -1757,6 +2022,29 @@ where
         self.context
             .metered_write_gs_unsafe(contract_package_hash, contract_package)?;
 
+        // Run the new version's migration within this execution. Any failure is propagated as an
+        // execution error, so the version addition is discarded together with the rest of the
+        // execution's effects.
+        if has_migration {
+            let args = RuntimeArgs::try_new(|args| {
+                args.insert(MIGRATE_ARG_PREVIOUS_CONTRACT_HASH, previous_contract_hash)?;
+                args.insert(
+                    MIGRATE_ARG_PREVIOUS_STORAGE_SCHEMA_VERSION,
+                    previous_storage_schema_version,
+                )?;
+                Ok(())
+            })?;
+            let identifier = CallContractIdentifier::Contract {
+                contract_hash: contract_hash.into(),
+            };
+            self.execute_contract(
+                identifier,
+                MIGRATE_ENTRY_POINT_NAME,
+                args,
+                CallKind::Migration,
+            )?;
+        }
+
         // return contract key to caller
         {
             let key_bytes = match contract_hash.to_bytes() {
@@ -1908,7 +2196,7 @@ where
             transfers.push(transfer_addr);
         }
         self.context
-            .write_transfer(Key::Transfer(transfer_addr), transfer);
+            .write_transfer(Key::Transfer(transfer_addr), transfer)?;
         Ok(())
     }
 
@@ -1922,7 +2210,7 @@ where
             return Ok(());
         }
 
-        self.context.write_era_info(Key::EraSummary, era_info);
+        self.context.write_era_info(Key::EraSummary, era_info)?;
 
         Ok(())
     }
@@ -2431,6 +2719,86 @@ where
         }
     }
 
+    /// Registers an invocation of `entry_point_name` of the stored contract under `contract_hash`,
+    /// to be executed by the system on behalf of the caller once `scheduled_time` is reached.
+    ///
+    /// Only session code can schedule invocations, as they are executed on behalf of the account
+    /// of the deploy. The invocation is prepaid with `amount` motes taken from `source`, which are
+    /// held in a purse of their own until the invocation is executed. The address of the
+    /// invocation is written to `dest_ptr`.
+    #[allow(clippy::too_many_arguments)]
+    fn schedule_invocation(
+        &mut self,
+        contract_hash: ContractHash,
+        entry_point_name: String,
+        args: RuntimeArgs,
+        scheduled_time: ScheduledTime,
+        source: URef,
+        amount: U512,
+        dest_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Error> {
+        if self.context.protocol_version()
+            < self
+                .config
+                .start_protocol_version_with_scheduled_invocations()
+        {
+            return Err(Error::DisabledScheduledInvocations);
+        }
+        // The caller recorded as the initiator has to be the account the invocation is paid and
+        // executed for, which stored contract code is not.
+        if self.context.entry_point_type() != EntryPointType::Session {
+            return Err(Error::ScheduleInvocationFromContract);
+        }
+
+        let contract: Contract = self.context.read_gs_typed(&Key::from(contract_hash))?;
+        let entry_point = contract
+            .entry_point(&entry_point_name)
+            .ok_or_else(|| Error::NoSuchMethod(entry_point_name.clone()))?;
+        // Session entry points would run in the context of the initiator's account, handing the
+        // scheduling code an authority it was never granted.
+        if entry_point.entry_point_type() != EntryPointType::Contract {
+            return Err(Error::UnschedulableEntryPoint(entry_point_name));
+        }
+
+        let block_info = self.context.block_info();
+        let block_time = Timestamp::from(u64::from(block_info.block_time()));
+        if scheduled_time.is_due(block_info.era_id(), block_time) {
+            return Err(Error::ScheduledTimeNotInFuture);
+        }
+        if amount.is_zero() {
+            return Err(Error::ScheduledInvocationNotPrepaid);
+        }
+
+        let payment_purse = self.create_purse()?;
+        if let Err(mint_error) =
+            self.transfer_from_purse_to_purse(source, payment_purse, amount, None)?
+        {
+            return Ok(Err(mint_error.into()));
+        }
+
+        let scheduled_invocation = ScheduledInvocation::new(
+            self.context.get_caller(),
+            contract_hash,
+            entry_point_name,
+            args,
+            scheduled_time,
+            payment_purse,
+            amount,
+        );
+        let addr = scheduled_time
+            .bucket()
+            .addr(&self.context.new_hash_address()?);
+        self.context.metered_write_gs_unsafe(
+            Key::ScheduledInvocation(addr),
+            CLValue::from_t(scheduled_invocation)?,
+        )?;
+
+        self.try_get_memory()?
+            .set(dest_ptr, &addr)
+            .map_err(|e| Error::Interpreter(e.into()))?;
+        Ok(Ok(()))
+    }
+
     fn get_balance(&mut self, purse: URef) -> Result<Option<U512>, Error> {
         let maybe_value = self.context.read_gs_direct(&Key::Balance(purse.addr()))?;
         match maybe_value {
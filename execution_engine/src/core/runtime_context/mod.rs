@@ -25,12 +25,18 @@ use casper_types::{
 
 use crate::{
     core::{
-        engine_state::{execution_effect::ExecutionEffect, EngineConfig, SystemContractRegistry},
+        engine_state::{
+            execution_effect::ExecutionEffect, BlockInfo, EngineConfig, SystemContractRegistry,
+        },
         execution::{AddressGenerator, Error},
         runtime_context::dictionary::DictionaryValue,
         tracking_copy::{AddResult, TrackingCopy, TrackingCopyExt},
     },
-    shared::{execution_journal::ExecutionJournal, newtypes::CorrelationId},
+    shared::{
+        execution_journal::ExecutionJournal,
+        gas_profile::{GasProfiler, GasProfilerFrame, GasSite},
+        newtypes::CorrelationId,
+    },
     storage::global_state::StateReader,
 };
 
@@ -92,7 +98,7 @@ pub struct RuntimeContext<'a, R> {
     // Key pointing to the entity we are currently running
     //(could point at an account or contract in the global state)
     base_key: Key,
-    blocktime: BlockTime,
+    block_info: BlockInfo,
     deploy_hash: DeployHash,
     gas_limit: Gas,
     gas_counter: Gas,
@@ -104,6 +110,10 @@ pub struct RuntimeContext<'a, R> {
     entry_point_type: EntryPointType,
     transfers: Vec<TransferAddr>,
     remaining_spending_limit: U512,
+    // Whether any modification of global state is forbidden in this context and all contexts
+    // derived from it.
+    read_only: bool,
+    gas_profiler: Option<GasProfiler>,
 }
 
 impl<'a, R> RuntimeContext<'a, R>
@@ -124,7 +134,7 @@ where
         authorization_keys: BTreeSet<AccountHash>,
         account: &'a Account,
         base_key: Key,
-        blocktime: BlockTime,
+        block_info: BlockInfo,
         deploy_hash: DeployHash,
         gas_limit: Gas,
         gas_counter: Gas,
@@ -144,7 +154,7 @@ where
             args: runtime_args,
             account,
             authorization_keys,
-            blocktime,
+            block_info,
             deploy_hash,
             base_key,
             gas_limit,
@@ -156,6 +166,8 @@ where
             engine_config,
             transfers,
             remaining_spending_limit,
+            read_only: false,
+            gas_profiler: None,
         }
     }
 
@@ -173,7 +185,7 @@ where
         let tracking_copy = self.state();
         let authorization_keys = self.authorization_keys.clone();
         let account = self.account;
-        let blocktime = self.blocktime;
+        let block_info = self.block_info.clone();
         let deploy_hash = self.deploy_hash;
         let gas_limit = self.gas_limit;
         let gas_counter = self.gas_counter;
@@ -184,6 +196,8 @@ where
         let engine_config = self.engine_config.clone();
         let transfers = self.transfers.clone();
         let remaining_spending_limit = self.remaining_spending_limit();
+        let read_only = self.read_only;
+        let gas_profiler = self.gas_profiler.clone();
 
         RuntimeContext {
             tracking_copy,
@@ -193,7 +207,7 @@ where
             args: runtime_args,
             account,
             authorization_keys,
-            blocktime,
+            block_info,
             deploy_hash,
             base_key,
             gas_limit,
@@ -205,6 +219,8 @@ where
             engine_config,
             transfers,
             remaining_spending_limit,
+            read_only,
+            gas_profiler,
         }
     }
 
@@ -338,6 +354,14 @@ where
                 error!("should not remove the checksum registry key");
                 Err(Error::RemoveKeyFailure(RemoveKeyFailure::PermissionDenied))
             }
+            Key::ScheduledInvocation(_) => {
+                self.named_keys.remove(name);
+                Ok(())
+            }
+            Key::Delegator(_) => {
+                self.named_keys.remove(name);
+                Ok(())
+            }
         }
     }
 
@@ -348,7 +372,45 @@ where
 
     /// Returns the block time.
     pub fn get_blocktime(&self) -> BlockTime {
-        self.blocktime
+        self.block_info.block_time()
+    }
+
+    /// Returns the context of the block in which the deploy is executed.
+    pub fn block_info(&self) -> &BlockInfo {
+        &self.block_info
+    }
+
+    /// Returns `true` if global state can't be modified from this context.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Forbids any modification of global state from this context and from all contexts derived
+    /// from it.
+    pub(crate) fn set_read_only(&mut self) {
+        self.read_only = true;
+    }
+
+    /// Returns the gas profiler attributing gas charged in this context, if profiling is enabled.
+    pub(crate) fn gas_profiler(&self) -> Option<&GasProfiler> {
+        self.gas_profiler.as_ref()
+    }
+
+    /// Enables gas profiling for this context and all contexts derived from it.
+    pub(crate) fn set_gas_profiler(&mut self, gas_profiler: GasProfiler) {
+        self.gas_profiler = Some(gas_profiler);
+    }
+
+    /// Pushes a frame onto the gas profiler's stack until the returned guard is dropped.
+    ///
+    /// The frame is only built if profiling is enabled.
+    pub(crate) fn enter_gas_profiler_frame<F>(&self, frame: F) -> Option<GasProfilerFrame>
+    where
+        F: FnOnce() -> String,
+    {
+        self.gas_profiler
+            .as_ref()
+            .map(|gas_profiler| gas_profiler.enter_frame(&frame()))
     }
 
     /// Returns the deploy hash.
@@ -402,6 +464,11 @@ where
 
     /// Sets the gas counter to a new value.
     pub fn set_gas_counter(&mut self, new_gas_counter: Gas) {
+        if let Some(gas_profiler) = &self.gas_profiler {
+            if new_gas_counter < self.gas_counter {
+                gas_profiler.rollback(new_gas_counter);
+            }
+        }
         self.gas_counter = new_gas_counter;
     }
 
@@ -558,6 +625,14 @@ where
             .map_err(Into::into)
     }
 
+    /// Returns all keys whose serialized form starts with `prefix`.
+    pub fn get_keys_by_prefix(&mut self, prefix: &[u8]) -> Result<BTreeSet<Key>, Error> {
+        self.tracking_copy
+            .borrow_mut()
+            .get_keys_by_prefix(self.correlation_id, prefix)
+            .map_err(Into::into)
+    }
+
     /// Read an account from the global state.
     pub fn read_account(&mut self, key: &Key) -> Result<Option<StoredValue>, Error> {
         if let Key::Account(_) = key {
@@ -584,24 +659,32 @@ where
     }
 
     /// Write a transfer instance to the global state.
-    pub fn write_transfer(&mut self, key: Key, value: Transfer) {
+    pub fn write_transfer(&mut self, key: Key, value: Transfer) -> Result<(), Error> {
+        if self.read_only {
+            return Err(Error::WriteInReadOnlyContext);
+        }
         if let Key::Transfer(_) = key {
             // Writing a `Transfer` will not exceed write size limit.
             self.tracking_copy
                 .borrow_mut()
                 .write(key, StoredValue::Transfer(value));
+            Ok(())
         } else {
             panic!("Do not use this function for writing non-transfer keys")
         }
     }
 
     /// Write an era info instance to the global state.
-    pub fn write_era_info(&mut self, key: Key, value: EraInfo) {
+    pub fn write_era_info(&mut self, key: Key, value: EraInfo) -> Result<(), Error> {
+        if self.read_only {
+            return Err(Error::WriteInReadOnlyContext);
+        }
         if let Key::EraSummary = key {
             // Writing an `EraInfo` for 100 validators will not exceed write size limit.
             self.tracking_copy
                 .borrow_mut()
                 .write(key, StoredValue::EraInfo(value));
+            Ok(())
         } else {
             panic!("Do not use this function for writing non-era-info keys")
         }
@@ -718,6 +801,7 @@ where
             StoredValue::Bid(_) => Ok(()),
             StoredValue::Withdraw(_) => Ok(()),
             StoredValue::Unbonding(_) => Ok(()),
+            StoredValue::Delegator(_) => Ok(()),
         }
     }
 
@@ -796,6 +880,8 @@ where
             Key::Unbond(_) => true,
             Key::ChainspecRegistry => true,
             Key::ChecksumRegistry => true,
+            Key::ScheduledInvocation(_) => true,
+            Key::Delegator(_) => true,
         }
     }
 
@@ -816,6 +902,8 @@ where
             Key::Unbond(_) => false,
             Key::ChainspecRegistry => false,
             Key::ChecksumRegistry => false,
+            Key::ScheduledInvocation(_) => false,
+            Key::Delegator(_) => false,
         }
     }
 
@@ -836,6 +924,8 @@ where
             Key::Unbond(_) => false,
             Key::ChainspecRegistry => false,
             Key::ChecksumRegistry => false,
+            Key::ScheduledInvocation(_) => false,
+            Key::Delegator(_) => false,
         }
     }
 
@@ -845,6 +935,30 @@ where
     /// Intuition about the return value sense is to answer the question 'are we
     /// allowed to continue?'
     pub(crate) fn charge_gas(&mut self, amount: Gas) -> Result<(), Error> {
+        let prev = self.gas_counter();
+        let result = self.charge_gas_unprofiled(amount);
+        if let Some(gas_profiler) = &self.gas_profiler {
+            gas_profiler.record(self.charged_since(prev), self.gas_counter);
+        }
+        result
+    }
+
+    /// Charges `amount` of gas at a gas site of Wasm code, attributing it to the site's function
+    /// and opcode classes.
+    pub(crate) fn charge_gas_site(&mut self, gas_site: &GasSite, amount: Gas) -> Result<(), Error> {
+        let prev = self.gas_counter();
+        let result = self.charge_gas_unprofiled(amount);
+        if let Some(gas_profiler) = &self.gas_profiler {
+            gas_profiler.record_gas_site(gas_site, self.charged_since(prev), self.gas_counter);
+        }
+        result
+    }
+
+    /// Charges gas without attributing it in the gas profile.
+    ///
+    /// Used for charges which settle gas already attributed while executing in a separate
+    /// context, e.g. a system contract's runtime.
+    pub(crate) fn charge_gas_unprofiled(&mut self, amount: Gas) -> Result<(), Error> {
         let prev = self.gas_counter();
         let gas_limit = self.gas_limit();
         // gas charge overflow protection
@@ -864,6 +978,10 @@ where
         }
     }
 
+    fn charged_since(&self, prev: Gas) -> Gas {
+        self.gas_counter.checked_sub(prev).unwrap_or_default()
+    }
+
     /// Checks if we are calling a system contract.
     pub(crate) fn is_system_contract(&self, contract_hash: &ContractHash) -> Result<bool, Error> {
         Ok(self
@@ -885,7 +1003,12 @@ where
 
         let gas_cost = storage_costs.calculate_gas_cost(bytes_count);
 
-        self.charge_gas(gas_cost)
+        let prev = self.gas_counter();
+        let result = self.charge_gas_unprofiled(gas_cost);
+        if let Some(gas_profiler) = &self.gas_profiler {
+            gas_profiler.record_storage(bytes_count, self.charged_since(prev), self.gas_counter);
+        }
+        result
     }
 
     /// Charges gas for using a host system contract's entrypoint.
@@ -912,6 +1035,10 @@ where
         K: Into<Key>,
         V: Into<StoredValue>,
     {
+        if self.read_only {
+            return Err(Error::WriteInReadOnlyContext);
+        }
+
         let stored_value = value.into();
 
         // Charge for amount as measured by serialized length
@@ -946,6 +1073,10 @@ where
         key: Key,
         value: StoredValue,
     ) -> Result<(), Error> {
+        if self.read_only {
+            return Err(Error::WriteInReadOnlyContext);
+        }
+
         let value_bytes_count = value.serialized_length();
         self.charge_gas_storage(value_bytes_count)?;
 
@@ -15,15 +15,15 @@ use casper_types::{
     bytesrepr::ToBytes,
     contracts::NamedKeys,
     system::{AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT},
-    AccessRights, BlockTime, CLValue, ContextAccessRights, Contract, ContractHash, DeployHash,
-    EntryPointType, EntryPoints, Gas, Key, Phase, ProtocolVersion, PublicKey, RuntimeArgs,
-    SecretKey, StoredValue, URef, KEY_HASH_LENGTH, U256, U512,
+    AccessRights, CLValue, ContextAccessRights, Contract, ContractHash, DeployHash, EntryPointType,
+    EntryPoints, Gas, Key, Phase, ProtocolVersion, PublicKey, RuntimeArgs, SecretKey, StoredValue,
+    URef, KEY_HASH_LENGTH, U256, U512,
 };
 
 use super::{Error, RuntimeContext};
 use crate::{
     core::{
-        engine_state::{EngineConfig, SystemContractRegistry},
+        engine_state::{BlockInfo, EngineConfig, SystemContractRegistry},
         execution::AddressGenerator,
         tracking_copy::TrackingCopy,
     },
@@ -130,7 +130,7 @@ fn new_runtime_context<'a>(
         BTreeSet::from_iter(vec![account.account_hash()]),
         account,
         base_key,
-        BlockTime::new(0),
+        BlockInfo::default(),
         DeployHash::new([1u8; 32]),
         Gas::new(U512::from(GAS_LIMIT)),
         Gas::default(),
@@ -391,7 +391,7 @@ fn contract_key_addable_valid() {
         authorization_keys,
         &account,
         contract_key,
-        BlockTime::new(0),
+        BlockInfo::default(),
         DeployHash::new(DEPLOY_HASH),
         Gas::new(U512::from(GAS_LIMIT)),
         Gas::default(),
@@ -467,7 +467,7 @@ fn contract_key_addable_invalid() {
         authorization_keys,
         &account,
         other_contract_key,
-        BlockTime::new(0),
+        BlockInfo::default(),
         DeployHash::new(DEPLOY_HASH),
         Gas::default(),
         Gas::default(),
@@ -1,7 +1,11 @@
+use std::collections::BTreeMap;
+
 use casper_types::{
     account::{Account, AccountHash},
+    system::auction::Delegator,
     CLValue, Contract, ContractHash, ContractPackage, ContractPackageHash, ContractWasm,
-    ContractWasmHash, Key, Motes, StoredValue, StoredValueTypeMismatch, URef,
+    ContractWasmHash, DelegatorAddr, Key, Motes, PublicKey, StoredValue, StoredValueTypeMismatch,
+    URef,
 };
 
 use crate::{
@@ -93,6 +97,16 @@ pub trait TrackingCopyExt<R> {
         &mut self,
         correlation_id: CorrelationId,
     ) -> Result<Option<ChecksumRegistry>, Self::Error>;
+
+    /// Gets the delegators of the validator with the given account address, keyed by delegator
+    /// public key.
+    ///
+    /// Delegator records with a zero staked amount have been removed and are skipped.
+    fn get_delegators(
+        &mut self,
+        correlation_id: CorrelationId,
+        validator_account_hash: AccountHash,
+    ) -> Result<BTreeMap<PublicKey, Delegator>, Self::Error>;
 }
 
 impl<R> TrackingCopyExt<R> for TrackingCopy<R>
@@ -287,4 +301,33 @@ where
             None => Ok(None),
         }
     }
+
+    fn get_delegators(
+        &mut self,
+        correlation_id: CorrelationId,
+        validator_account_hash: AccountHash,
+    ) -> Result<BTreeMap<PublicKey, Delegator>, Self::Error> {
+        let prefix = DelegatorAddr::validator_prefix(&validator_account_hash);
+        let delegator_keys = self
+            .get_keys_by_prefix(correlation_id, &prefix)
+            .map_err(Into::into)?;
+
+        let mut delegators = BTreeMap::new();
+        for key in delegator_keys {
+            match self.get(correlation_id, &key).map_err(Into::into)? {
+                Some(StoredValue::Delegator(delegator)) => {
+                    if !delegator.staked_amount().is_zero() {
+                        delegators.insert(delegator.delegator_public_key().clone(), *delegator);
+                    }
+                }
+                Some(other) => {
+                    return Err(execution::Error::TypeMismatch(
+                        StoredValueTypeMismatch::new("Delegator".to_string(), other.type_name()),
+                    ))
+                }
+                None => {}
+            }
+        }
+        Ok(delegators)
+    }
 }
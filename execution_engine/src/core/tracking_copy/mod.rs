@@ -18,7 +18,7 @@ use thiserror::Error;
 
 use casper_hashing::Digest;
 use casper_types::{
-    bytesrepr::{self},
+    bytesrepr::{self, ToBytes},
     CLType, CLValue, CLValueError, Key, KeyTag, StoredValue, StoredValueTypeMismatch, Tagged, U512,
 };
 
@@ -216,6 +216,30 @@ impl<M: Meter<Key, StoredValue>> TrackingCopyCache<M> {
     pub fn get_key_tag_reads_cached(&mut self, key_tag: &KeyTag) -> Option<&BTreeSet<Key>> {
         self.key_tag_reads_cached.get_refresh(key_tag).map(|v| &*v)
     }
+
+    /// Gets the mutated keys in the cache whose serialized form starts with `prefix`.
+    ///
+    /// Only the keys of the tag the prefix starts with are serialized to be compared, reusing a
+    /// single buffer.
+    pub fn get_muts_cached_by_prefix(&self, prefix: &[u8]) -> BTreeSet<Key> {
+        let tag = match prefix.first() {
+            Some(tag) => *tag,
+            None => return self.muts_cached.keys().cloned().collect(),
+        };
+        let mut buffer = Vec::with_capacity(Key::max_serialized_length());
+        self.muts_cached
+            .keys()
+            .filter(|key| {
+                let key_tag: u8 = key.tag();
+                if key_tag != tag {
+                    return false;
+                }
+                buffer.clear();
+                key.write_bytes(&mut buffer).is_ok() && buffer.starts_with(prefix)
+            })
+            .cloned()
+            .collect()
+    }
 }
 
 /// An interface for the global state that caches all operations (reads and writes) instead of
@@ -327,6 +351,23 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         Ok(ret)
     }
 
+    /// Gets the set of keys in the state whose serialized form starts with `prefix`.
+    ///
+    /// Unlike [`TrackingCopy::get_keys`], results of the underlying read are not cached.
+    pub fn get_keys_by_prefix(
+        &mut self,
+        correlation_id: CorrelationId,
+        prefix: &[u8],
+    ) -> Result<BTreeSet<Key>, R::Error> {
+        let mut ret: BTreeSet<Key> = self
+            .reader
+            .keys_with_prefix(correlation_id, prefix)?
+            .into_iter()
+            .collect();
+        ret.extend(self.cache.get_muts_cached_by_prefix(prefix));
+        Ok(ret)
+    }
+
     /// Reads the value stored under `key`.
     pub fn read(
         &mut self,
@@ -556,6 +597,9 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
                 StoredValue::Unbonding(_) => {
                     return Ok(query.into_not_found_result("UnbondingPurses value found."));
                 }
+                StoredValue::Delegator(_) => {
+                    return Ok(query.into_not_found_result("Delegator value found."));
+                }
             }
         }
     }
@@ -10,8 +10,8 @@ use casper_types::{
     },
     contracts::NamedKeys,
     gens::*,
-    AccessRights, CLValue, Contract, EntryPoints, HashAddr, Key, KeyTag, ProtocolVersion,
-    StoredValue, URef, U256, U512,
+    AccessRights, CLValue, Contract, DelegatorAddr, EntryPoints, HashAddr, Key, KeyTag,
+    ProtocolVersion, StoredValue, URef, U256, U512,
 };
 
 use super::{
@@ -952,6 +952,58 @@ fn get_keys_should_return_keys_in_the_uref_keyspace() {
     assert!(!key_set.contains(&account_key));
 }
 
+#[test]
+fn get_keys_by_prefix_should_return_delegators_of_a_validator() {
+    let validator_1 = AccountHash::new([1; 32]);
+    let validator_2 = AccountHash::new([2; 32]);
+
+    let delegator_1_key =
+        Key::Delegator(DelegatorAddr::new(validator_1, AccountHash::new([3; 32])));
+    let delegator_2_key =
+        Key::Delegator(DelegatorAddr::new(validator_2, AccountHash::new([4; 32])));
+    let value = StoredValue::CLValue(CLValue::unit());
+
+    // persist them
+    let correlation_id = CorrelationId::new();
+    let (global_state, root_hash) = InMemoryGlobalState::from_pairs(
+        correlation_id,
+        &[
+            (delegator_1_key, value.clone()),
+            (delegator_2_key, value.clone()),
+        ],
+    )
+    .unwrap();
+
+    let view = global_state
+        .checkout(root_hash)
+        .expect("should checkout")
+        .expect("should have view");
+
+    let mut tracking_copy = TrackingCopy::new(view);
+
+    let prefix = DelegatorAddr::validator_prefix(&validator_1);
+    let key_set = tracking_copy
+        .get_keys_by_prefix(correlation_id, &prefix)
+        .unwrap();
+
+    assert_eq!(key_set.len(), 1);
+    assert!(key_set.contains(&delegator_1_key));
+
+    // cached writes are included
+    let delegator_3_key =
+        Key::Delegator(DelegatorAddr::new(validator_1, AccountHash::new([5; 32])));
+    tracking_copy.write(delegator_3_key, value);
+
+    let key_set = tracking_copy
+        .get_keys_by_prefix(correlation_id, &prefix)
+        .unwrap();
+
+    assert_eq!(key_set.len(), 2);
+    assert!(key_set.contains(&delegator_1_key));
+    assert!(key_set.contains(&delegator_3_key));
+    assert!(!key_set.contains(&delegator_2_key));
+}
+
 #[test]
 fn get_keys_should_handle_reads_from_empty_trie() {
     let correlation_id = CorrelationId::new();
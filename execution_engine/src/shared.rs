@@ -1,6 +1,7 @@
 //! The shared logic of the execution engine.
 pub mod additive_map;
 pub mod execution_journal;
+pub mod gas_profile;
 pub mod host_function_costs;
 pub mod logging;
 pub mod newtypes;
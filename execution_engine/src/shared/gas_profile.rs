@@ -0,0 +1,634 @@
+//! Gas profiling of contract execution.
+//!
+//! A [`GasProfiler`] attached to an execute request attributes every gas charge made while
+//! executing the request's deploys to a stack of frames: the execution phase, the stored contracts
+//! called, the host functions invoked and, for Wasm execution, the Wasm function and opcode class
+//! the gas was charged for.  Storage costs are recorded under a `storage` frame along with the
+//! number of bytes written to global state.
+//!
+//! The collected [`GasProfile`] can be exported in the folded stack format understood by flamegraph
+//! tooling such as `inferno-flamegraph` or `flamegraph.pl`.
+//!
+//! Profiling doesn't change the amount of gas charged, but it is intended for development only:
+//! Wasm modules are re-instrumented on every profiled execution.
+use std::{
+    collections::BTreeMap,
+    io,
+    rc::Rc,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use casper_wasm::elements::{External, Instruction, Internal, Module};
+use casper_wasm_utils::rules::Rules;
+
+use casper_types::{Gas, U512};
+
+use super::{
+    opcode_costs::{self, OpcodeCosts},
+    wasm_prep::{DEFAULT_GAS_MODULE_NAME, INTERNAL_GAS_FUNCTION_NAME},
+};
+
+/// Separator between the frames of a folded stack.
+const FRAME_SEPARATOR: char = ';';
+/// Frame under which storage costs are recorded.
+const STORAGE_FRAME: &str = "storage";
+/// Prefix of frames representing Wasm functions.
+const WASM_FUNCTION_FRAME_PREFIX: &str = "wasm:";
+/// Prefix of frames representing opcode classes.
+const OPCODE_CLASS_FRAME_PREFIX: &str = "opcode:";
+/// Opcode class of gas which couldn't be attributed to any instruction.
+const UNKNOWN_OPCODE_CLASS: &str = "unknown";
+
+/// Gas usage of an execution, attributed to stacks of frames.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct GasProfile {
+    gas: BTreeMap<String, u64>,
+    storage_bytes: BTreeMap<String, u64>,
+}
+
+impl GasProfile {
+    /// Returns the gas attributed to each stack, keyed by `;`-separated frames.
+    pub fn gas(&self) -> &BTreeMap<String, u64> {
+        &self.gas
+    }
+
+    /// Returns the number of bytes written to global state by each stack, keyed by `;`-separated
+    /// frames.
+    pub fn storage_bytes(&self) -> &BTreeMap<String, u64> {
+        &self.storage_bytes
+    }
+
+    /// Returns the total gas attributed by this profile.
+    pub fn total_gas(&self) -> u64 {
+        self.gas.values().sum()
+    }
+
+    /// Returns the total gas attributed to stacks which contain the given frame.
+    pub fn gas_for_frame(&self, frame: &str) -> u64 {
+        self.gas
+            .iter()
+            .filter(|(stack, _)| stack.split(FRAME_SEPARATOR).any(|name| name == frame))
+            .map(|(_, gas)| gas)
+            .sum()
+    }
+
+    /// Returns the profile in the folded stack format, i.e. one `<frames> <gas>` line per stack.
+    pub fn to_folded(&self) -> String {
+        self.gas
+            .iter()
+            .filter(|(_, gas)| **gas > 0)
+            .map(|(stack, gas)| format!("{} {}\n", stack, gas))
+            .collect()
+    }
+
+    /// Writes the profile in the folded stack format to `writer`.
+    pub fn write_folded<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(self.to_folded().as_bytes())
+    }
+}
+
+/// A single gas charge or storage write attributed to a stack.
+#[derive(Debug)]
+struct Sample {
+    stack: String,
+    gas: u64,
+    storage_bytes: u64,
+    /// The gas counter of the execution right after the sample was recorded.
+    gas_counter: Gas,
+}
+
+#[derive(Default, Debug)]
+struct ProfilerState {
+    frames: Vec<String>,
+    samples: Vec<Sample>,
+    /// Index of the first sample recorded by the current execution.
+    execution_start: usize,
+}
+
+impl ProfilerState {
+    fn stack(&self, leaf: &[&str]) -> String {
+        self.frames
+            .iter()
+            .map(String::as_str)
+            .chain(leaf.iter().copied())
+            .collect::<Vec<_>>()
+            .join(&FRAME_SEPARATOR.to_string())
+    }
+
+    fn push_sample(&mut self, leaf: &[&str], gas: u64, storage_bytes: u64, gas_counter: Gas) {
+        let stack = self.stack(leaf);
+        self.samples.push(Sample {
+            stack,
+            gas,
+            storage_bytes,
+            gas_counter,
+        });
+    }
+}
+
+/// Collects a [`GasProfile`] while deploys are executed.
+///
+/// The profiler is a cheaply cloneable handle: keep a clone to read the profile once execution has
+/// finished.
+#[derive(Clone, Default, Debug)]
+pub struct GasProfiler {
+    state: Arc<Mutex<ProfilerState>>,
+}
+
+impl GasProfiler {
+    /// Creates a new, empty profiler.
+    pub fn new() -> Self {
+        GasProfiler::default()
+    }
+
+    /// Returns the profile collected so far.
+    pub fn profile(&self) -> GasProfile {
+        let state = self.state();
+        let mut profile = GasProfile::default();
+        for sample in &state.samples {
+            if sample.gas > 0 {
+                *profile.gas.entry(sample.stack.clone()).or_default() += sample.gas;
+            }
+            if sample.storage_bytes > 0 {
+                *profile
+                    .storage_bytes
+                    .entry(sample.stack.clone())
+                    .or_default() += sample.storage_bytes;
+            }
+        }
+        profile
+    }
+
+    /// Returns the profile collected so far and resets the profiler.
+    pub fn take_profile(&self) -> GasProfile {
+        let profile = self.profile();
+        *self.state() = ProfilerState::default();
+        profile
+    }
+
+    fn state(&self) -> MutexGuard<'_, ProfilerState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Starts attributing gas to a new execution with its own gas counter, e.g. the payment or the
+    /// session code of a deploy.
+    pub(crate) fn begin_execution(&self, root_frame: &str) {
+        let mut state = self.state();
+        state.frames = vec![sanitize_frame(root_frame)];
+        state.execution_start = state.samples.len();
+    }
+
+    /// Pushes a frame which stays on the stack until the returned guard is dropped.
+    pub(crate) fn enter_frame(&self, frame: &str) -> GasProfilerFrame {
+        let mut state = self.state();
+        let depth = state.frames.len();
+        state.frames.push(sanitize_frame(frame));
+        GasProfilerFrame {
+            profiler: self.clone(),
+            depth,
+        }
+    }
+
+    /// Attributes `gas` to the current stack.
+    pub(crate) fn record(&self, gas: Gas, gas_counter: Gas) {
+        self.state()
+            .push_sample(&[], gas_to_u64(gas), 0, gas_counter);
+    }
+
+    /// Attributes `gas` charged for writing `bytes` to global state to the current stack.
+    pub(crate) fn record_storage(&self, bytes: usize, gas: Gas, gas_counter: Gas) {
+        let bytes = u64::try_from(bytes).unwrap_or(u64::MAX);
+        self.state()
+            .push_sample(&[STORAGE_FRAME], gas_to_u64(gas), bytes, gas_counter);
+    }
+
+    /// Attributes `gas` charged at the start of a metered block to the block's Wasm function,
+    /// split across the opcode classes of the block.
+    pub(crate) fn record_gas_site(&self, site: &GasSite, gas: Gas, gas_counter: Gas) {
+        let function_frame = format!(
+            "{}{}",
+            WASM_FUNCTION_FRAME_PREFIX,
+            sanitize_frame(&site.function)
+        );
+        let mut state = self.state();
+        for (opcode_class, share) in site.split(gas_to_u64(gas)) {
+            if share == 0 {
+                continue;
+            }
+            let opcode_class_frame = format!("{}{}", OPCODE_CLASS_FRAME_PREFIX, opcode_class);
+            state.push_sample(
+                &[&function_frame, &opcode_class_frame],
+                share,
+                0,
+                gas_counter,
+            );
+        }
+    }
+
+    /// Discards samples of the current execution recorded after its gas counter was at
+    /// `gas_counter`, i.e. gas which has been refunded by resetting the counter.
+    pub(crate) fn rollback(&self, gas_counter: Gas) {
+        let mut state = self.state();
+        let start = state.execution_start;
+        let retained = state.samples[start..]
+            .iter()
+            .take_while(|sample| sample.gas_counter <= gas_counter)
+            .count();
+        state.samples.truncate(start + retained);
+    }
+}
+
+/// Guard returned by [`GasProfiler::enter_frame`] which pops the frame when dropped.
+#[derive(Debug)]
+pub(crate) struct GasProfilerFrame {
+    profiler: GasProfiler,
+    depth: usize,
+}
+
+impl Drop for GasProfilerFrame {
+    fn drop(&mut self) {
+        self.profiler.state().frames.truncate(self.depth);
+    }
+}
+
+/// A gas charge injected at the start of a metered block of a Wasm function, or before growing
+/// memory.
+#[derive(Debug)]
+pub(crate) struct GasSite {
+    function: String,
+    /// The cost of the metered block, or `None` for the charge made before growing memory, whose
+    /// amount depends on the number of pages grown.
+    cost: Option<u32>,
+    /// Costs of the block's instructions, summed per opcode class.
+    opcode_classes: Vec<(&'static str, u64)>,
+}
+
+impl GasSite {
+    /// Returns the amount of gas charged by this site, or `None` if it is only known at runtime, in
+    /// which case it is passed to the gas function by the call following the one passing the id
+    /// of this site.
+    pub(crate) fn cost(&self) -> Option<Gas> {
+        self.cost.map(Gas::from)
+    }
+
+    /// Splits `gas` across the opcode classes of the block proportionally to their costs.
+    ///
+    /// The breakdown is derived from the instructions following the charge up to the next one,
+    /// which can differ slightly from the block boundaries used for metering; scaling it to the
+    /// charged amount keeps the profile consistent with the gas actually charged.
+    fn split(&self, gas: u64) -> Vec<(&'static str, u64)> {
+        let weight: u64 = self.opcode_classes.iter().map(|(_, cost)| cost).sum();
+        if weight == 0 {
+            return vec![(UNKNOWN_OPCODE_CLASS, gas)];
+        }
+        let mut remaining = gas;
+        let mut shares: Vec<(&'static str, u64)> = self
+            .opcode_classes
+            .iter()
+            .map(|(opcode_class, cost)| {
+                let share = (u128::from(gas) * u128::from(*cost) / u128::from(weight)) as u64;
+                remaining -= share;
+                (*opcode_class, share)
+            })
+            .collect();
+        // The rounding remainder goes to the costliest class.
+        if let Some(costliest) = shares.iter_mut().max_by_key(|(_, share)| *share) {
+            costliest.1 += remaining;
+        }
+        shares
+    }
+}
+
+/// Gas sites of an instrumented module, indexed by the id passed to the gas function.
+#[derive(Clone, Default, Debug)]
+pub(crate) struct GasSites(Rc<Vec<GasSite>>);
+
+impl GasSites {
+    /// Returns the gas site with the given id.
+    pub(crate) fn get(&self, id: u32) -> Option<&GasSite> {
+        self.0.get(usize::try_from(id).ok()?)
+    }
+}
+
+/// Rewrites the gas charges injected by [`casper_wasm_utils::inject_gas_counter`] to pass the id
+/// of their gas site instead of the cost of the metered block, and returns the instrumented module
+/// along with the sites mapping ids back to costs.
+///
+/// The charge made by the function wrapping `memory.grow`, which multiplies the number of pages by
+/// the cost per page, is kept as is and preceded by a call passing the id of its gas site.
+///
+/// Returns `None` if the module doesn't import the gas function or contains a gas charge which
+/// follows neither of the injected patterns.
+pub(crate) fn instrument_gas_sites(
+    module: &Module,
+    opcode_costs: &OpcodeCosts,
+) -> Option<(Module, GasSites)> {
+    let mut function_imports: u32 = 0;
+    let mut gas_function = None;
+    for entry in module.import_section()?.entries() {
+        if let External::Function(_) = entry.external() {
+            if entry.module() == DEFAULT_GAS_MODULE_NAME
+                && entry.field() == INTERNAL_GAS_FUNCTION_NAME
+            {
+                gas_function = Some(function_imports);
+            }
+            function_imports = function_imports.checked_add(1)?;
+        }
+    }
+    let gas_function = gas_function?;
+
+    let function_names: BTreeMap<u32, &str> = module
+        .export_section()
+        .map(|export_section| {
+            export_section
+                .entries()
+                .iter()
+                .filter_map(|entry| match entry.internal() {
+                    Internal::Function(index) => Some((*index, entry.field())),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut instrumented = module.clone();
+    let mut sites = Vec::new();
+    for (index, body) in instrumented
+        .code_section_mut()?
+        .bodies_mut()
+        .iter_mut()
+        .enumerate()
+    {
+        let function_index = function_imports.checked_add(u32::try_from(index).ok()?)?;
+        let function = function_names
+            .get(&function_index)
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| format!("func[{}]", function_index));
+
+        let instructions = body.code_mut().elements_mut();
+        let charges: Vec<usize> = instructions
+            .iter()
+            .enumerate()
+            .filter(|(_, instruction)| matches!(instruction, Instruction::Call(index) if *index == gas_function))
+            .map(|(position, _)| position)
+            .collect();
+
+        let mut grow_memory_charges = Vec::new();
+        for (nth, &call_position) in charges.iter().enumerate() {
+            let const_position = call_position.checked_sub(1)?;
+            let cost = match instructions[const_position] {
+                Instruction::I32Const(cost) => cost as u32,
+                Instruction::I32Mul
+                    if matches!(
+                        instructions[const_position.checked_sub(1)?],
+                        Instruction::I32Const(_)
+                    ) =>
+                {
+                    let id = i32::try_from(sites.len()).ok()?;
+                    grow_memory_charges.push((call_position, id));
+                    sites.push(GasSite {
+                        function: function.clone(),
+                        cost: None,
+                        opcode_classes: sum_opcode_classes(
+                            &[Instruction::GrowMemory(0)],
+                            opcode_costs,
+                        ),
+                    });
+                    continue;
+                }
+                _ => return None,
+            };
+            let block_start = call_position + 1;
+            let block_end = charges
+                .get(nth + 1)
+                .map(|next_call_position| next_call_position.saturating_sub(1))
+                .unwrap_or(instructions.len())
+                .max(block_start);
+            let opcode_classes =
+                sum_opcode_classes(&instructions[block_start..block_end], opcode_costs);
+
+            let id = i32::try_from(sites.len()).ok()?;
+            instructions[const_position] = Instruction::I32Const(id);
+            sites.push(GasSite {
+                function: function.clone(),
+                cost: Some(cost),
+                opcode_classes,
+            });
+        }
+
+        // The amount charged is left on the stack while the id of its site is passed, so that the
+        // runtime knows which site the following charge belongs to.
+        for (call_position, id) in grow_memory_charges.into_iter().rev() {
+            instructions.splice(
+                call_position..call_position,
+                [Instruction::I32Const(id), Instruction::Call(gas_function)],
+            );
+        }
+    }
+
+    Some((instrumented, GasSites(Rc::new(sites))))
+}
+
+fn sum_opcode_classes(
+    instructions: &[Instruction],
+    opcode_costs: &OpcodeCosts,
+) -> Vec<(&'static str, u64)> {
+    let mut opcode_classes: BTreeMap<&'static str, u64> = BTreeMap::new();
+    for instruction in instructions {
+        let opcode_class = opcode_costs::opcode_class(instruction).unwrap_or(UNKNOWN_OPCODE_CLASS);
+        let cost = opcode_costs
+            .instruction_cost(instruction)
+            .unwrap_or_default();
+        *opcode_classes.entry(opcode_class).or_default() += u64::from(cost);
+    }
+    opcode_classes.into_iter().collect()
+}
+
+/// Frames are separated by `;` in the folded stack format, so it must not appear within a frame.
+fn sanitize_frame(frame: &str) -> String {
+    frame.replace(FRAME_SEPARATOR, "_")
+}
+
+fn gas_to_u64(gas: Gas) -> u64 {
+    if gas.value() > U512::from(u64::MAX) {
+        u64::MAX
+    } else {
+        gas.value().as_u64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_wasm::{
+        builder,
+        elements::{Instructions, ValueType},
+    };
+
+    use super::*;
+
+    fn gas(amount: u64) -> Gas {
+        Gas::new(U512::from(amount))
+    }
+
+    #[test]
+    fn should_attribute_gas_to_frames() {
+        let profiler = GasProfiler::new();
+        profiler.begin_execution("session");
+        profiler.record(gas(10), gas(10));
+        {
+            let _frame = profiler.enter_frame("host:WriteFuncIndex");
+            profiler.record(gas(5), gas(15));
+            profiler.record_storage(3, gas(30), gas(45));
+        }
+        profiler.record(gas(1), gas(46));
+
+        let profile = profiler.profile();
+        assert_eq!(profile.total_gas(), 46);
+        assert_eq!(profile.gas()["session"], 11);
+        assert_eq!(profile.gas()["session;host:WriteFuncIndex"], 5);
+        assert_eq!(profile.gas()["session;host:WriteFuncIndex;storage"], 30);
+        assert_eq!(
+            profile.storage_bytes()["session;host:WriteFuncIndex;storage"],
+            3
+        );
+        assert_eq!(profile.gas_for_frame("host:WriteFuncIndex"), 35);
+        assert_eq!(
+            profile.to_folded(),
+            "session 11\nsession;host:WriteFuncIndex 5\nsession;host:WriteFuncIndex;storage 30\n"
+        );
+    }
+
+    #[test]
+    fn should_rollback_refunded_gas_within_execution() {
+        let profiler = GasProfiler::new();
+        profiler.begin_execution("payment");
+        profiler.record(gas(10), gas(10));
+        profiler.begin_execution("session");
+        profiler.record(gas(5), gas(5));
+        profiler.record(gas(20), gas(25));
+        profiler.rollback(gas(5));
+
+        let profile = profiler.take_profile();
+        assert_eq!(profile.gas()["payment"], 10);
+        assert_eq!(profile.gas()["session"], 5);
+        assert_eq!(profile.total_gas(), 15);
+        assert_eq!(profiler.profile(), GasProfile::default());
+    }
+
+    #[test]
+    fn should_split_gas_site_across_opcode_classes() {
+        let site = GasSite {
+            function: "call".to_string(),
+            cost: Some(10),
+            opcode_classes: vec![("add", 1), ("load", 3)],
+        };
+        assert_eq!(site.split(10), vec![("add", 2), ("load", 8)]);
+        assert_eq!(site.split(0), vec![("add", 0), ("load", 0)]);
+    }
+
+    #[test]
+    fn should_instrument_gas_sites() {
+        let opcode_costs = OpcodeCosts::default();
+        let module = builder::module()
+            .signature()
+            .with_param(ValueType::I32)
+            .build()
+            .import()
+            .module(DEFAULT_GAS_MODULE_NAME)
+            .field(INTERNAL_GAS_FUNCTION_NAME)
+            .external()
+            .func(0)
+            .build()
+            .function()
+            .signature()
+            .build()
+            .body()
+            .with_instructions(Instructions::new(vec![
+                Instruction::I32Const(1234),
+                Instruction::Call(0),
+                Instruction::I32Const(1),
+                Instruction::Drop,
+                Instruction::End,
+            ]))
+            .build()
+            .build()
+            .export()
+            .field("call")
+            .internal()
+            .func(1)
+            .build()
+            .build();
+
+        let (instrumented, sites) =
+            instrument_gas_sites(&module, &opcode_costs).expect("should instrument");
+        let site = sites.get(0).expect("should have gas site");
+        assert_eq!(site.function, "call");
+        assert_eq!(site.cost(), Some(gas(1234)));
+        assert!(sites.get(1).is_none());
+
+        let body = &instrumented
+            .code_section()
+            .expect("should have code")
+            .bodies()[0];
+        assert_eq!(body.code().elements()[0], Instruction::I32Const(0));
+    }
+
+    #[test]
+    fn should_instrument_grow_memory_gas_site() {
+        let opcode_costs = OpcodeCosts::default();
+        let module = builder::module()
+            .signature()
+            .with_param(ValueType::I32)
+            .build()
+            .import()
+            .module(DEFAULT_GAS_MODULE_NAME)
+            .field(INTERNAL_GAS_FUNCTION_NAME)
+            .external()
+            .func(0)
+            .build()
+            .function()
+            .signature()
+            .with_param(ValueType::I32)
+            .with_result(ValueType::I32)
+            .build()
+            .body()
+            .with_instructions(Instructions::new(vec![
+                Instruction::GetLocal(0),
+                Instruction::GetLocal(0),
+                Instruction::I32Const(4096),
+                Instruction::I32Mul,
+                Instruction::Call(0),
+                Instruction::GrowMemory(0),
+                Instruction::End,
+            ]))
+            .build()
+            .build()
+            .build();
+
+        let (instrumented, sites) =
+            instrument_gas_sites(&module, &opcode_costs).expect("should instrument");
+        let site = sites.get(0).expect("should have gas site");
+        assert_eq!(site.function, "func[1]");
+        assert_eq!(site.cost(), None);
+        assert_eq!(site.split(8192), vec![("grow_memory", 8192)]);
+
+        let body = &instrumented
+            .code_section()
+            .expect("should have code")
+            .bodies()[0];
+        assert_eq!(
+            body.code().elements(),
+            &[
+                Instruction::GetLocal(0),
+                Instruction::GetLocal(0),
+                Instruction::I32Const(4096),
+                Instruction::I32Mul,
+                Instruction::I32Const(0),
+                Instruction::Call(0),
+                Instruction::Call(0),
+                Instruction::GrowMemory(0),
+                Instruction::End,
+            ]
+        );
+    }
+}
@@ -290,6 +290,14 @@ pub struct HostFunctionCosts {
     pub random_bytes: HostFunction<[Cost; 2]>,
     /// Cost of calling the `enable_contract_version` host function.
     pub enable_contract_version: HostFunction<[Cost; 4]>,
+    /// Cost of calling the `get_block_info` host function.
+    pub get_block_info: HostFunction<[Cost; 2]>,
+    /// Cost of calling the `call_contract_view` host function.
+    pub call_contract_view: HostFunction<[Cost; 7]>,
+    /// Cost of calling the `add_contract_version_with_storage_schema` host function.
+    pub add_contract_version_with_storage_schema: HostFunction<[Cost; 11]>,
+    /// Cost of calling the `schedule_invocation` host function.
+    pub schedule_invocation: HostFunction<[Cost; 13]>,
 }
 
 impl Default for HostFunctionCosts {
@@ -422,6 +430,21 @@ impl Default for HostFunctionCosts {
             blake2b: HostFunction::default(),
             random_bytes: HostFunction::default(),
             enable_contract_version: HostFunction::default(),
+            get_block_info: HostFunction::default(),
+            call_contract_view: HostFunction::new(
+                DEFAULT_CALL_CONTRACT_COST,
+                [
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    DEFAULT_CALL_CONTRACT_ARGS_SIZE_WEIGHT,
+                    NOT_USED,
+                ],
+            ),
+            add_contract_version_with_storage_schema: HostFunction::default(),
+            schedule_invocation: HostFunction::default(),
         }
     }
 }
@@ -473,6 +496,10 @@ impl ToBytes for HostFunctionCosts {
         ret.append(&mut self.blake2b.to_bytes()?);
         ret.append(&mut self.random_bytes.to_bytes()?);
         ret.append(&mut self.enable_contract_version.to_bytes()?);
+        ret.append(&mut self.get_block_info.to_bytes()?);
+        ret.append(&mut self.call_contract_view.to_bytes()?);
+        ret.append(&mut self.add_contract_version_with_storage_schema.to_bytes()?);
+        ret.append(&mut self.schedule_invocation.to_bytes()?);
         Ok(ret)
     }
 
@@ -521,6 +548,12 @@ impl ToBytes for HostFunctionCosts {
             + self.blake2b.serialized_length()
             + self.random_bytes.serialized_length()
             + self.enable_contract_version.serialized_length()
+            + self.get_block_info.serialized_length()
+            + self.call_contract_view.serialized_length()
+            + self
+                .add_contract_version_with_storage_schema
+                .serialized_length()
+            + self.schedule_invocation.serialized_length()
     }
 }
 
@@ -570,6 +603,10 @@ impl FromBytes for HostFunctionCosts {
         let (blake2b, rem) = FromBytes::from_bytes(rem)?;
         let (random_bytes, rem) = FromBytes::from_bytes(rem)?;
         let (enable_contract_version, rem) = FromBytes::from_bytes(rem)?;
+        let (get_block_info, rem) = FromBytes::from_bytes(rem)?;
+        let (call_contract_view, rem) = FromBytes::from_bytes(rem)?;
+        let (add_contract_version_with_storage_schema, rem) = FromBytes::from_bytes(rem)?;
+        let (schedule_invocation, rem) = FromBytes::from_bytes(rem)?;
         Ok((
             HostFunctionCosts {
                 read_value,
@@ -616,6 +653,10 @@ impl FromBytes for HostFunctionCosts {
                 blake2b,
                 random_bytes,
                 enable_contract_version,
+                get_block_info,
+                call_contract_view,
+                add_contract_version_with_storage_schema,
+                schedule_invocation,
             },
             rem,
         ))
@@ -669,6 +710,10 @@ impl Distribution<HostFunctionCosts> for Standard {
             blake2b: rng.gen(),
             random_bytes: rng.gen(),
             enable_contract_version: rng.gen(),
+            get_block_info: rng.gen(),
+            call_contract_view: rng.gen(),
+            add_contract_version_with_storage_schema: rng.gen(),
+            schedule_invocation: rng.gen(),
         }
     }
 }
@@ -730,6 +775,10 @@ pub mod gens {
             blake2b in host_function_cost_arb(),
             random_bytes in host_function_cost_arb(),
             enable_contract_version in host_function_cost_arb(),
+            get_block_info in host_function_cost_arb(),
+            call_contract_view in host_function_cost_arb(),
+            add_contract_version_with_storage_schema in host_function_cost_arb(),
+            schedule_invocation in host_function_cost_arb(),
         ) -> HostFunctionCosts {
             HostFunctionCosts {
                 read_value,
@@ -776,6 +825,10 @@ pub mod gens {
                 blake2b,
                 random_bytes,
                 enable_contract_version,
+                get_block_info,
+                call_contract_view,
+                add_contract_version_with_storage_schema,
+                schedule_invocation,
             }
         }
     }
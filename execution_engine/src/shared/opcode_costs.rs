@@ -575,6 +575,72 @@ impl Rules for OpcodeCosts {
     }
 }
 
+/// Names of the opcode classes priced by [`OpcodeCosts`], indexed by the marker cost used for
+/// each class in [`opcode_class`].
+const OPCODE_CLASSES: [&str; 16] = [
+    "bit",
+    "add",
+    "mul",
+    "div",
+    "load",
+    "store",
+    "const",
+    "local",
+    "global",
+    "integer_comparison",
+    "conversion",
+    "unreachable",
+    "nop",
+    "current_memory",
+    "grow_memory",
+    "control_flow",
+];
+
+/// Returns the name of the [`OpcodeCosts`] class under which the given instruction is charged, or
+/// `None` if the instruction is not allowed.
+///
+/// The classification reuses the [`Rules`] implementation by pricing every class with a distinct
+/// marker, so it can never diverge from the way gas is actually charged.
+pub(crate) fn opcode_class(instruction: &Instruction) -> Option<&'static str> {
+    const MARKER_COSTS: OpcodeCosts = OpcodeCosts {
+        bit: 0,
+        add: 1,
+        mul: 2,
+        div: 3,
+        load: 4,
+        store: 5,
+        op_const: 6,
+        local: 7,
+        global: 8,
+        integer_comparison: 9,
+        conversion: 10,
+        unreachable: 11,
+        nop: 12,
+        current_memory: 13,
+        grow_memory: 14,
+        control_flow: ControlFlowCosts {
+            block: 15,
+            op_loop: 15,
+            op_if: 15,
+            op_else: 15,
+            end: 15,
+            br: 15,
+            br_if: 15,
+            op_return: 15,
+            call: 15,
+            call_indirect: 15,
+            drop: 15,
+            select: 15,
+            br_table: BrTableCost {
+                cost: 15,
+                size_multiplier: 0,
+            },
+        },
+    };
+    let marker = MARKER_COSTS.instruction_cost(instruction)?;
+    OPCODE_CLASSES.get(marker as usize).copied()
+}
+
 impl Default for OpcodeCosts {
     fn default() -> Self {
         OpcodeCosts {
@@ -839,11 +905,12 @@ pub mod gens {
 
 #[cfg(test)]
 mod tests {
+    use casper_wasm::elements::{BlockType, BrTableData, Instruction};
     use proptest::proptest;
 
     use casper_types::bytesrepr;
 
-    use super::gens;
+    use super::{gens, opcode_class};
 
     proptest! {
         #[test]
@@ -853,4 +920,23 @@ mod tests {
             bytesrepr::test_serialization_roundtrip(&opcode_costs);
         }
     }
+
+    #[test]
+    fn should_classify_instructions_like_rules() {
+        assert_eq!(opcode_class(&Instruction::I32Add), Some("add"));
+        assert_eq!(opcode_class(&Instruction::I64Store(0, 0)), Some("store"));
+        assert_eq!(opcode_class(&Instruction::I32Const(1)), Some("const"));
+        assert_eq!(
+            opcode_class(&Instruction::Block(BlockType::NoResult)),
+            Some("control_flow")
+        );
+        assert_eq!(
+            opcode_class(&Instruction::BrTable(Box::new(BrTableData {
+                table: vec![0, 1, 2].into_boxed_slice(),
+                default: 0,
+            }))),
+            Some("control_flow")
+        );
+        assert_eq!(opcode_class(&Instruction::F32Add), None);
+    }
 }
@@ -232,6 +232,11 @@ impl Transform {
                     let found = "Unbonding".to_string();
                     Err(StoredValueTypeMismatch::new(expected, found).into())
                 }
+                StoredValue::Delegator(_) => {
+                    let expected = "Contract or Account".to_string();
+                    let found = "Delegator".to_string();
+                    Err(StoredValueTypeMismatch::new(expected, found).into())
+                }
             },
             Transform::Failure(error) => Err(error),
         }
@@ -369,6 +374,9 @@ impl From<&Transform> for casper_types::Transform {
             Transform::Write(StoredValue::Unbonding(unbonding_purses)) => {
                 casper_types::Transform::WriteUnbonding(unbonding_purses.clone())
             }
+            Transform::Write(StoredValue::Delegator(delegator)) => {
+                casper_types::Transform::WriteDelegator(delegator.clone())
+            }
             Transform::AddInt32(value) => casper_types::Transform::AddInt32(*value),
             Transform::AddUInt64(value) => casper_types::Transform::AddUInt64(*value),
             Transform::AddUInt128(value) => casper_types::Transform::AddUInt128(*value),
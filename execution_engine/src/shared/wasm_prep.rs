@@ -8,9 +8,9 @@ use thiserror::Error;
 use super::wasm_config::WasmConfig;
 use crate::core::execution;
 
-const DEFAULT_GAS_MODULE_NAME: &str = "env";
+pub(crate) const DEFAULT_GAS_MODULE_NAME: &str = "env";
 /// Name of the internal gas function injected by [`casper_wasm_utils::inject_gas_counter`].
-const INTERNAL_GAS_FUNCTION_NAME: &str = "gas";
+pub(crate) const INTERNAL_GAS_FUNCTION_NAME: &str = "gas";
 
 /// We only allow maximum of 4k function pointers in a table section.
 pub const DEFAULT_MAX_TABLE_SIZE: u32 = 4096;
@@ -8,8 +8,8 @@ use crate::{
     storage::{
         error,
         global_state::{
-            commit, put_stored_values, scratch::ScratchGlobalState, CommitProvider, StateProvider,
-            StateReader,
+            commit, put_stored_values, scratch::ScratchGlobalState, CommitError, CommitProvider,
+            StateProvider, StateReader,
         },
         store::Store,
         transaction_source::{lmdb::LmdbEnvironment, Transaction, TransactionSource},
@@ -64,6 +64,16 @@ impl LmdbGlobalState {
         Ok(LmdbGlobalState::new(environment, trie_store, root_hash))
     }
 
+    /// Creates a state from an existing environment and trie_store without writing the empty root
+    /// to it, e.g. for an environment opened read-only.
+    pub fn open(
+        environment: Arc<LmdbEnvironment>,
+        trie_store: Arc<LmdbTrieStore>,
+    ) -> Result<Self, error::Error> {
+        let (root_hash, _root) = compute_empty_root_hash()?;
+        Ok(LmdbGlobalState::new(environment, trie_store, root_hash))
+    }
+
     /// Creates a state from an existing environment, store, and root_hash.
     /// Intended to be used for testing.
     pub fn new(
@@ -106,6 +116,52 @@ impl LmdbGlobalState {
         Ok(new_state_root)
     }
 
+    /// Computes the state root hash which would result from writing the stored values and then
+    /// deleting each batch of keys, without writing anything to LMDB.
+    ///
+    /// As with [`CommitProvider::delete_keys`], a batch containing a key which does not exist is
+    /// skipped as a whole.
+    pub fn compute_post_state_hash(
+        &self,
+        correlation_id: CorrelationId,
+        prestate_hash: Digest,
+        stored_values: HashMap<Key, StoredValue>,
+        keys_to_delete: &[Vec<Key>],
+    ) -> Result<Digest, error::Error> {
+        let scratch_trie = self.get_scratch_store();
+        let mut state_root_hash = put_stored_values::<_, _, error::Error>(
+            &scratch_trie,
+            &scratch_trie,
+            correlation_id,
+            prestate_hash,
+            stored_values,
+        )?;
+
+        let mut txn = scratch_trie.create_read_write_txn()?;
+        'batches: for keys in keys_to_delete {
+            let mut batch_root_hash = state_root_hash;
+            for key in keys {
+                match delete::<Key, StoredValue, _, _, error::Error>(
+                    correlation_id,
+                    &mut txn,
+                    &scratch_trie,
+                    &batch_root_hash,
+                    key,
+                )? {
+                    DeleteResult::Deleted(root) => batch_root_hash = root,
+                    DeleteResult::DoesNotExist => continue 'batches,
+                    DeleteResult::RootNotFound => {
+                        return Err(CommitError::RootNotFound(batch_root_hash).into())
+                    }
+                }
+            }
+            state_root_hash = batch_root_hash;
+        }
+        txn.commit()?;
+
+        Ok(state_root_hash)
+    }
+
     /// Gets a scratch trie store.
     fn get_scratch_store(&self) -> ScratchTrieStore {
         ScratchTrieStore::new(Arc::clone(&self.trie_store), Arc::clone(&self.environment))
@@ -1,18 +1,20 @@
 pub(crate) mod detail;
 pub(crate) mod providers;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use num_rational::Ratio;
 use num_traits::{CheckedMul, CheckedSub};
 
 use casper_types::{
     account::AccountHash,
+    crypto,
     system::auction::{
         Bid, DelegationRate, EraInfo, EraValidators, Error, SeigniorageAllocation,
-        SeigniorageRecipients, ValidatorWeights, BLOCK_REWARD, DELEGATION_RATE_DENOMINATOR,
+        SeigniorageRecipient, SeigniorageRecipients, ValidatorWeights, BLOCK_REWARD,
+        DELEGATION_RATE_DENOMINATOR,
     },
-    ApiError, EraId, PublicKey, U512,
+    ApiError, EraId, PublicKey, Signature, Tagged, BLS_TAG, U512,
 };
 
 use self::providers::{AccountProvider, MintProvider, RuntimeProvider, StorageProvider};
@@ -55,7 +57,11 @@ pub trait Auction:
     /// its stake.
     ///
     /// Validators cannot create a bid with 0 amount, and the delegation rate can't exceed
-    /// [`DELEGATION_RATE_DENOMINATOR`].
+    /// [`DELEGATION_RATE_DENOMINATOR`]. An increase of an existing validator's delegation rate
+    /// only takes effect after the configured notice period, while a decrease applies immediately.
+    ///
+    /// A new bid for a BLS12-381 key requires a `proof_of_possession` of its secret key, and is
+    /// only accepted once BLS12-381 keys are enabled.
     ///
     /// Returns a [`U512`] value indicating total amount of tokens staked for given `public_key`.
     fn add_bid(
@@ -63,6 +69,7 @@ pub trait Auction:
         public_key: PublicKey,
         delegation_rate: DelegationRate,
         amount: U512,
+        proof_of_possession: Option<Signature>,
     ) -> Result<U512, ApiError> {
         if !self.allow_auction_bids() {
             // Validation set rotation might be disabled on some private chains and we should not
@@ -70,6 +77,10 @@ pub trait Auction:
             return Err(Error::AuctionBidsDisabled.into());
         }
 
+        if public_key.tag() == BLS_TAG && !self.allow_bls_keys() {
+            return Err(Error::BlsKeysNotAllowed.into());
+        }
+
         let provided_account_hash = AccountHash::from_public_key(&public_key, |x| self.blake2b(x));
 
         if amount.is_zero() {
@@ -108,13 +119,22 @@ pub trait Auction:
                     // unapproved spending limit error.
                     ApiError::from(mint_error)
                 })?;
-                let updated_amount = bid
-                    .with_delegation_rate(delegation_rate)
-                    .increase_stake(amount)?;
+                let era_id = detail::get_era_id(self)?;
+                bid.change_delegation_rate(
+                    delegation_rate,
+                    era_id,
+                    self.delegation_rate_change_notice_eras(),
+                );
+                let updated_amount = bid.increase_stake(amount)?;
                 self.write_bid(account_hash, bid)?;
                 updated_amount
             }
             None => {
+                if public_key.tag() == BLS_TAG {
+                    let proof = proof_of_possession.ok_or(Error::InvalidProofOfPossession)?;
+                    crypto::verify_proof_of_possession(&public_key, &proof)
+                        .map_err(|_| Error::InvalidProofOfPossession)?;
+                }
                 let bonding_purse = self.create_purse()?;
                 self.mint_transfer_direct(
                     Some(PublicKey::System.to_account_hash()),
@@ -181,19 +201,21 @@ pub trait Auction:
 
         if updated_stake.is_zero() {
             // Automatically unbond delegators
-            for (delegator_public_key, delegator) in bid.delegators() {
+            let delegators = detail::read_delegators(self, provided_account_hash)?;
+            for (delegator_public_key, mut delegator) in delegators {
+                let previous_staked_amount = *delegator.staked_amount();
                 detail::create_unbonding_purse(
                     self,
                     public_key.clone(),
-                    delegator_public_key.clone(),
+                    delegator_public_key,
                     *delegator.bonding_purse(),
-                    *delegator.staked_amount(),
+                    previous_staked_amount,
                     None,
                 )?;
+                *delegator.staked_amount_mut() = U512::zero();
+                detail::write_delegator(self, &mut bid, previous_staked_amount, delegator)?;
             }
 
-            *bid.delegators_mut() = BTreeMap::new();
-
             bid.deactivate();
         }
 
@@ -236,16 +258,19 @@ pub trait Auction:
 
         let validator_account_hash = AccountHash::from(&validator_public_key);
 
+        // Fails if the validator doesn't exist.
         let bid = detail::read_bid_for_validator(self, validator_account_hash)?;
 
-        let delegator_already_exists = bid.delegators().contains_key(&delegator_public_key);
+        let delegator_already_exists =
+            detail::read_delegator(self, validator_account_hash, provided_account_hash)?.is_some();
 
-        if let Some(max_delegators_per_validator) = max_delegators_per_validator {
-            if bid.delegators().len() >= max_delegators_per_validator as usize
-                && !delegator_already_exists
-            {
-                return Err(Error::ExceededDelegatorSizeLimit.into());
-            }
+        if !delegator_already_exists
+            && !detail::is_under_max_delegator_cap(
+                max_delegators_per_validator,
+                bid.delegator_count(),
+            )
+        {
+            return Err(Error::ExceededDelegatorSizeLimit.into());
         }
 
         if amount < U512::from(minimum_delegation_amount) {
@@ -262,6 +287,72 @@ pub trait Auction:
         )
     }
 
+    /// Creates a new delegation of `amount` motes, funded from the caller's main purse, whose stake
+    /// is locked until `release_timestamp_millis` and released weekly over the configured vesting
+    /// schedule period afterwards.
+    ///
+    /// The caller has to be the delegator, so that stake can't be locked on behalf of another
+    /// party. The delegator must not already delegate to the validator.
+    ///
+    /// This entry point returns the number of tokens delegated to a given validator.
+    fn delegate_with_vesting(
+        &mut self,
+        delegator_public_key: PublicKey,
+        validator_public_key: PublicKey,
+        amount: U512,
+        release_timestamp_millis: u64,
+        max_delegators_per_validator: Option<u32>,
+        minimum_delegation_amount: u64,
+    ) -> Result<U512, ApiError> {
+        if !self.allow_auction_bids() {
+            // Validation set rotation might be disabled on some private chains and we should not
+            // allow new bids to come in.
+            return Err(Error::AuctionBidsDisabled.into());
+        }
+        let delegator_account_hash =
+            AccountHash::from_public_key(&delegator_public_key, |x| self.blake2b(x));
+
+        if amount.is_zero() {
+            return Err(Error::BondTooSmall.into());
+        }
+
+        if !self.is_allowed_session_caller(&delegator_account_hash) {
+            return Err(Error::InvalidContext.into());
+        }
+
+        let source = self.get_main_purse()?;
+
+        let validator_account_hash = AccountHash::from(&validator_public_key);
+
+        // Fails if the validator doesn't exist.
+        let bid = detail::read_bid_for_validator(self, validator_account_hash)?;
+
+        // Attaching a vesting schedule to an existing delegation would lock stake the delegator
+        // didn't agree to lock.
+        if detail::read_delegator(self, validator_account_hash, delegator_account_hash)?.is_some() {
+            return Err(Error::DelegationAlreadyExists.into());
+        }
+
+        if !detail::is_under_max_delegator_cap(max_delegators_per_validator, bid.delegator_count())
+        {
+            return Err(Error::ExceededDelegatorSizeLimit.into());
+        }
+
+        if amount < U512::from(minimum_delegation_amount) {
+            return Err(Error::DelegationAmountTooSmall.into());
+        }
+
+        detail::handle_vesting_delegation(
+            self,
+            bid,
+            delegator_public_key,
+            validator_public_key,
+            source,
+            amount,
+            release_timestamp_millis,
+        )
+    }
+
     /// Removes specified amount of motes (or the value from the collection altogether, if the
     /// remaining amount is 0) from the entry in delegators map for given validator and creates a
     /// new unbonding request to the queue.
@@ -289,29 +380,33 @@ pub trait Auction:
             None => return Err(Error::ValidatorNotFound),
         };
 
-        let delegators = bid.delegators_mut();
-
-        let new_amount = match delegators.get_mut(&delegator_public_key) {
-            Some(delegator) => {
-                detail::create_unbonding_purse(
-                    self,
-                    validator_public_key,
-                    delegator_public_key.clone(),
-                    *delegator.bonding_purse(),
-                    amount,
-                    None,
-                )?;
+        let mut delegator =
+            match detail::read_delegator(self, validator_account_hash, provided_account_hash)? {
+                Some(delegator) => delegator,
+                None => return Err(Error::DelegatorNotFound),
+            };
 
-                let era_end_timestamp_millis = detail::get_era_end_timestamp_millis(self)?;
-                let updated_stake = delegator.decrease_stake(amount, era_end_timestamp_millis)?;
-                if updated_stake == U512::zero() {
-                    delegators.remove(&delegator_public_key);
-                };
-                updated_stake
-            }
-            None => return Err(Error::DelegatorNotFound),
-        };
+        detail::create_unbonding_purse(
+            self,
+            validator_public_key,
+            delegator_public_key,
+            *delegator.bonding_purse(),
+            amount,
+            None,
+        )?;
 
+        let era_end_timestamp_millis = detail::get_era_end_timestamp_millis(self)?;
+        // Vesting schedules of delegators are only initialized by the auction for winning
+        // validators, so one which became due while its validator didn't win is initialized here.
+        delegator.process_with_vesting_schedule(
+            era_end_timestamp_millis,
+            self.vesting_schedule_period_millis(),
+        );
+        let previous_staked_amount = *delegator.staked_amount();
+        let new_amount = delegator.decrease_stake(amount, era_end_timestamp_millis)?;
+
+        // A delegation without any stake left is treated as removed.
+        detail::write_delegator(self, &mut bid, previous_staked_amount, delegator)?;
         self.write_bid(validator_account_hash, bid)?;
 
         Ok(new_amount)
@@ -352,29 +447,33 @@ pub trait Auction:
             None => return Err(Error::ValidatorNotFound),
         };
 
-        let delegators = bid.delegators_mut();
-
-        let new_amount = match delegators.get_mut(&delegator_public_key) {
-            Some(delegator) => {
-                detail::create_unbonding_purse(
-                    self,
-                    validator_public_key,
-                    delegator_public_key.clone(),
-                    *delegator.bonding_purse(),
-                    amount,
-                    Some(new_validator),
-                )?;
+        let mut delegator =
+            match detail::read_delegator(self, validator_account_hash, provided_account_hash)? {
+                Some(delegator) => delegator,
+                None => return Err(Error::DelegatorNotFound),
+            };
 
-                let era_end_timestamp_millis = detail::get_era_end_timestamp_millis(self)?;
-                let updated_stake = delegator.decrease_stake(amount, era_end_timestamp_millis)?;
-                if updated_stake == U512::zero() {
-                    delegators.remove(&delegator_public_key);
-                };
-                updated_stake
-            }
-            None => return Err(Error::DelegatorNotFound),
-        };
+        detail::create_unbonding_purse(
+            self,
+            validator_public_key,
+            delegator_public_key,
+            *delegator.bonding_purse(),
+            amount,
+            Some(new_validator),
+        )?;
 
+        let era_end_timestamp_millis = detail::get_era_end_timestamp_millis(self)?;
+        // Vesting schedules of delegators are only initialized by the auction for winning
+        // validators, so one which became due while its validator didn't win is initialized here.
+        delegator.process_with_vesting_schedule(
+            era_end_timestamp_millis,
+            self.vesting_schedule_period_millis(),
+        );
+        let previous_staked_amount = *delegator.staked_amount();
+        let new_amount = delegator.decrease_stake(amount, era_end_timestamp_millis)?;
+
+        // A delegation without any stake left is treated as removed.
+        detail::write_delegator(self, &mut bid, previous_staked_amount, delegator)?;
         self.write_bid(validator_account_hash, bid)?;
 
         Ok(new_amount)
@@ -398,8 +497,11 @@ pub trait Auction:
                 *bid.staked_amount_mut() = U512::zero();
                 bid.deactivate();
                 // Reset delegator stakes when deactivating validator bid.
-                for delegator in bid.delegators_mut().values_mut() {
+                let delegators = detail::read_delegators(self, validator_account_hash)?;
+                for (_, mut delegator) in delegators {
+                    let previous_staked_amount = *delegator.staked_amount();
                     *delegator.staked_amount_mut() = U512::zero();
+                    detail::write_delegator(self, &mut bid, previous_staked_amount, delegator)?;
                 }
                 self.write_bid(validator_account_hash, bid)?;
             };
@@ -448,17 +550,21 @@ pub trait Auction:
         let mut bids = detail::get_bids(self)?;
 
         // Process bids
-        let mut bids_modified = false;
+        let mut modified_bids = BTreeSet::new();
         for (validator_public_key, bid) in bids.iter_mut() {
+            if bid.apply_pending_delegation_rate(era_id.successor()) {
+                modified_bids.insert(validator_public_key.clone());
+            }
+
             if bid.process_with_vesting_schedule(
                 era_end_timestamp_millis,
                 self.vesting_schedule_period_millis(),
             ) {
-                bids_modified = true;
+                modified_bids.insert(validator_public_key.clone());
             }
 
-            if evicted_validators.contains(validator_public_key) {
-                bids_modified = bid.deactivate()
+            if evicted_validators.contains(validator_public_key) && bid.deactivate() {
+                modified_bids.insert(validator_public_key.clone());
             }
         }
 
@@ -525,11 +631,26 @@ pub trait Auction:
 
             let mut recipients = SeigniorageRecipients::new();
 
+            // Only the delegators of the winning validators are read, as their stakes are recorded
+            // in the snapshot.
             for era_validator in winners.keys() {
-                let seigniorage_recipient = match bids.get(era_validator) {
-                    Some(bid) => bid.into(),
-                    None => return Err(Error::BidNotFound.into()),
-                };
+                let bid = bids.get(era_validator).ok_or(Error::BidNotFound)?;
+                let delegators = detail::read_delegators(self, era_validator.to_account_hash())?;
+                let mut delegator_stake = BTreeMap::new();
+                for (delegator_public_key, mut delegator) in delegators {
+                    if delegator.process_with_vesting_schedule(
+                        era_end_timestamp_millis,
+                        vesting_schedule_period_millis,
+                    ) {
+                        self.write_delegator(delegator.clone())?;
+                    }
+                    delegator_stake.insert(delegator_public_key, *delegator.staked_amount());
+                }
+                let seigniorage_recipient = SeigniorageRecipient::new(
+                    *bid.staked_amount(),
+                    *bid.delegation_rate(),
+                    delegator_stake,
+                );
                 recipients.insert(era_validator.clone(), seigniorage_recipient);
             }
 
@@ -543,8 +664,13 @@ pub trait Auction:
         detail::set_era_id(self, era_id)?;
         detail::set_era_end_timestamp_millis(self, era_end_timestamp_millis)?;
 
-        if bids_modified {
-            detail::set_bids(self, bids)?;
+        if !modified_bids.is_empty() {
+            // Only write back the bids which changed.
+            let modified_bids = bids
+                .into_iter()
+                .filter(|(validator_public_key, _)| modified_bids.contains(validator_public_key))
+                .collect();
+            detail::set_bids(self, modified_bids)?;
         }
 
         Ok(())
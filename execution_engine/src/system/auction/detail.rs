@@ -10,7 +10,7 @@ use casper_types::{
         UnbondingPurse, UnbondingPurses, AUCTION_DELAY_KEY, ERA_END_TIMESTAMP_MILLIS_KEY,
         ERA_ID_KEY, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
     },
-    ApiError, CLTyped, EraId, Key, KeyTag, PublicKey, URef, U512,
+    ApiError, CLTyped, DelegatorAddr, EraId, Key, KeyTag, PublicKey, URef, U512,
 };
 use tracing::error;
 
@@ -62,6 +62,10 @@ where
     Ok(ret)
 }
 
+/// Writes the given bids.
+///
+/// Delegators are stored under their own [`Key::Delegator`]s and are written separately, see
+/// [`write_delegator`].
 pub fn set_bids<P>(provider: &mut P, validators: Bids) -> Result<(), Error>
 where
     P: StorageProvider + RuntimeProvider + ?Sized,
@@ -73,6 +77,70 @@ where
     Ok(())
 }
 
+/// Writes `delegator`, whose stake was `previous_staked_amount` before, and records the change of
+/// its stake in the `bid` of its validator.
+///
+/// The bid has to be written by the caller.
+pub(crate) fn write_delegator<P>(
+    provider: &mut P,
+    bid: &mut Bid,
+    previous_staked_amount: U512,
+    delegator: Delegator,
+) -> Result<(), Error>
+where
+    P: StorageProvider + ?Sized,
+{
+    bid.record_delegation_change(previous_staked_amount, *delegator.staked_amount())?;
+    provider.write_delegator(delegator)
+}
+
+/// Reads the delegation of `delegator_account_hash` to `validator_account_hash`.
+///
+/// A delegation record left without any stake has been withdrawn and is reported as missing.
+pub(crate) fn read_delegator<P>(
+    provider: &mut P,
+    validator_account_hash: AccountHash,
+    delegator_account_hash: AccountHash,
+) -> Result<Option<Delegator>, Error>
+where
+    P: StorageProvider + ?Sized,
+{
+    let delegator = provider
+        .read_delegator(validator_account_hash, delegator_account_hash)?
+        .filter(|delegator| !delegator.staked_amount().is_zero());
+    Ok(delegator)
+}
+
+/// Reads all delegations to the given validator, keyed by the delegators' public keys.
+pub(crate) fn read_delegators<P>(
+    provider: &mut P,
+    validator_account_hash: AccountHash,
+) -> Result<BTreeMap<PublicKey, Delegator>, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    let prefix = DelegatorAddr::validator_prefix(&validator_account_hash);
+    let delegator_keys = provider.get_keys_by_prefix(&prefix)?;
+
+    let mut ret = BTreeMap::new();
+
+    for key in delegator_keys {
+        let delegator_addr = match key {
+            Key::Delegator(delegator_addr) => delegator_addr,
+            _ => return Err(Error::InvalidKeyVariant),
+        };
+        if let Some(delegator) = read_delegator(
+            provider,
+            delegator_addr.validator(),
+            delegator_addr.delegator(),
+        )? {
+            ret.insert(delegator.delegator_public_key().clone(), delegator);
+        }
+    }
+
+    Ok(ret)
+}
+
 pub fn get_unbonding_purses<P>(provider: &mut P) -> Result<UnbondingPurses, Error>
 where
     P: StorageProvider + RuntimeProvider + ?Sized,
@@ -219,7 +287,7 @@ pub(crate) fn process_unbond_requests<P: Auction + ?Sized>(
                                 if !new_validator_bid.staked_amount().is_zero() {
                                     if is_under_max_delegator_cap(
                                         max_delegators_per_validator,
-                                        new_validator_bid.delegators().len(),
+                                        new_validator_bid.delegator_count(),
                                     ) {
                                         handle_delegation(
                                             provider,
@@ -327,16 +395,19 @@ where
         None => return Err(Error::ValidatorNotFound),
     };
 
-    let delegators = bid.delegators_mut();
-
     for (delegator_key, delegator_reward) in rewards {
-        let delegator = match delegators.get_mut(&delegator_key) {
+        let mut delegator = match read_delegator(
+            provider,
+            validator_account_hash,
+            delegator_key.to_account_hash(),
+        )? {
             Some(delegator) => delegator,
             None => continue,
         };
 
         let delegator_reward_trunc = delegator_reward.to_integer();
 
+        let previous_staked_amount = *delegator.staked_amount();
         delegator.increase_stake(delegator_reward_trunc)?;
 
         delegator_payouts.push((
@@ -352,6 +423,8 @@ where
         );
 
         seigniorage_allocations.push(allocation);
+
+        write_delegator(provider, &mut bid, previous_staked_amount, delegator)?;
     }
 
     provider.write_bid(validator_account_hash, bid)?;
@@ -403,62 +476,107 @@ where
     P: StorageProvider + MintProvider,
 {
     let validator_account_hash = AccountHash::from(&validator_public_key);
-
-    let delegators = bid.delegators_mut();
-
-    let new_delegation_amount = match delegators.get_mut(&delegator_public_key) {
-        Some(delegator) => {
-            provider
-                .mint_transfer_direct(
-                    Some(PublicKey::System.to_account_hash()),
-                    source,
-                    *delegator.bonding_purse(),
+    let delegator_account_hash = AccountHash::from(&delegator_public_key);
+
+    let new_delegation_amount =
+        match read_delegator(provider, validator_account_hash, delegator_account_hash)? {
+            Some(mut delegator) => {
+                provider
+                    .mint_transfer_direct(
+                        Some(PublicKey::System.to_account_hash()),
+                        source,
+                        *delegator.bonding_purse(),
+                        amount,
+                        None,
+                    )
+                    .map_err(|_| Error::TransferToDelegatorPurse)?
+                    .map_err(|mint_error| {
+                        // Propagate mint contract's error that occured during execution of transfer
+                        // entrypoint. This will improve UX in case of (for example)
+                        // unapproved spending limit error.
+                        ApiError::from(mint_error)
+                    })?;
+                let previous_staked_amount = *delegator.staked_amount();
+                let updated_amount = delegator.increase_stake(amount)?;
+                write_delegator(provider, &mut bid, previous_staked_amount, delegator)?;
+                updated_amount
+            }
+            None => {
+                let bonding_purse = provider.create_purse()?;
+                provider
+                    .mint_transfer_direct(
+                        Some(PublicKey::System.to_account_hash()),
+                        source,
+                        bonding_purse,
+                        amount,
+                        None,
+                    )
+                    .map_err(|_| Error::TransferToDelegatorPurse)?
+                    .map_err(|mint_error| {
+                        // Propagate mint contract's error that occured during execution of transfer
+                        // entrypoint. This will improve UX in case of (for example)
+                        // unapproved spending limit error.
+                        ApiError::from(mint_error)
+                    })?;
+                let delegator = Delegator::unlocked(
+                    delegator_public_key,
                     amount,
-                    None,
-                )
-                .map_err(|_| Error::TransferToDelegatorPurse)?
-                .map_err(|mint_error| {
-                    // Propagate mint contract's error that occured during execution of transfer
-                    // entrypoint. This will improve UX in case of (for example)
-                    // unapproved spending limit error.
-                    ApiError::from(mint_error)
-                })?;
-            delegator.increase_stake(amount)?;
-            *delegator.staked_amount()
-        }
-        None => {
-            let bonding_purse = provider.create_purse()?;
-            provider
-                .mint_transfer_direct(
-                    Some(PublicKey::System.to_account_hash()),
-                    source,
                     bonding_purse,
-                    amount,
-                    None,
-                )
-                .map_err(|_| Error::TransferToDelegatorPurse)?
-                .map_err(|mint_error| {
-                    // Propagate mint contract's error that occured during execution of transfer
-                    // entrypoint. This will improve UX in case of (for example)
-                    // unapproved spending limit error.
-                    ApiError::from(mint_error)
-                })?;
-            let delegator = Delegator::unlocked(
-                delegator_public_key.clone(),
-                amount,
-                bonding_purse,
-                validator_public_key,
-            );
-            delegators.insert(delegator_public_key.clone(), delegator);
-            amount
-        }
-    };
+                    validator_public_key,
+                );
+                write_delegator(provider, &mut bid, U512::zero(), delegator)?;
+                amount
+            }
+        };
 
     provider.write_bid(validator_account_hash, bid)?;
 
     Ok(new_delegation_amount)
 }
 
+/// Creates a new delegation of `amount` motes transferred from `source` whose stake is locked
+/// under a vesting schedule starting at `release_timestamp_millis`.
+pub(crate) fn handle_vesting_delegation<P>(
+    provider: &mut P,
+    mut bid: Bid,
+    delegator_public_key: PublicKey,
+    validator_public_key: PublicKey,
+    source: URef,
+    amount: U512,
+    release_timestamp_millis: u64,
+) -> Result<U512, ApiError>
+where
+    P: StorageProvider + MintProvider,
+{
+    let bonding_purse = provider.create_purse()?;
+    provider
+        .mint_transfer_direct(
+            Some(PublicKey::System.to_account_hash()),
+            source,
+            bonding_purse,
+            amount,
+            None,
+        )
+        .map_err(|_| Error::TransferToDelegatorPurse)?
+        .map_err(|mint_error| {
+            // Propagate mint contract's error that occured during execution of transfer
+            // entrypoint. This will improve UX in case of (for example)
+            // unapproved spending limit error.
+            ApiError::from(mint_error)
+        })?;
+    let validator_account_hash = AccountHash::from(&validator_public_key);
+    let delegator = Delegator::locked(
+        delegator_public_key,
+        amount,
+        bonding_purse,
+        validator_public_key,
+        release_timestamp_millis,
+    );
+    write_delegator(provider, &mut bid, U512::zero(), delegator)?;
+    provider.write_bid(validator_account_hash, bid)?;
+    Ok(amount)
+}
+
 pub(crate) fn read_bid_for_validator<P>(
     provider: &mut P,
     validator_account_hash: AccountHash,
@@ -494,11 +612,12 @@ pub(crate) fn era_validators_from_snapshot(
         .collect()
 }
 
-fn is_under_max_delegator_cap(
+/// Returns `true` if a validator with `delegator_count` delegators can accept another delegator.
+pub(crate) fn is_under_max_delegator_cap(
     max_delegators_per_validator: Option<u32>,
-    new_validator_delegator_len: usize,
+    delegator_count: u32,
 ) -> bool {
     max_delegators_per_validator
-        .map(|limit| new_validator_delegator_len < limit as usize)
+        .map(|limit| delegator_count < limit)
         .unwrap_or(true)
 }
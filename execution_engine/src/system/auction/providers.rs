@@ -4,7 +4,7 @@ use casper_types::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
     system::{
-        auction::{Bid, EraInfo, Error, UnbondingPurse},
+        auction::{Bid, Delegator, EraInfo, Error, UnbondingPurse},
         mint,
     },
     CLTyped, EraId, Key, KeyTag, URef, BLAKE2B_DIGEST_LENGTH, U512,
@@ -24,6 +24,9 @@ pub trait RuntimeProvider {
     /// Gets keys in a given keyspace
     fn get_keys(&mut self, key_tag: &KeyTag) -> Result<BTreeSet<Key>, Error>;
 
+    /// Gets keys whose serialized form starts with `prefix`
+    fn get_keys_by_prefix(&mut self, prefix: &[u8]) -> Result<BTreeSet<Key>, Error>;
+
     /// Returns a 32-byte BLAKE2b digest
     fn blake2b<T: AsRef<[u8]>>(&self, data: T) -> [u8; BLAKE2B_DIGEST_LENGTH];
 
@@ -32,6 +35,12 @@ pub trait RuntimeProvider {
 
     /// Check if auction bids are allowed.
     fn allow_auction_bids(&self) -> bool;
+
+    /// Returns the number of eras a delegation rate increase is delayed by.
+    fn delegation_rate_change_notice_eras(&self) -> u64;
+
+    /// Check if BLS12-381 keys are allowed as validator keys.
+    fn allow_bls_keys(&self) -> bool;
 }
 
 /// Provides functionality of a contract storage.
@@ -48,6 +57,18 @@ pub trait StorageProvider {
     /// Writes given [`Bid`] at account hash derived from given public key
     fn write_bid(&mut self, account_hash: AccountHash, bid: Bid) -> Result<(), Error>;
 
+    /// Reads [`Delegator`] at the address derived from given validator and delegator account
+    /// hashes
+    fn read_delegator(
+        &mut self,
+        validator_account_hash: AccountHash,
+        delegator_account_hash: AccountHash,
+    ) -> Result<Option<Delegator>, Error>;
+
+    /// Writes given [`Delegator`] at the address derived from its validator and delegator public
+    /// keys
+    fn write_delegator(&mut self, delegator: Delegator) -> Result<(), Error>;
+
     /// Reads collection of [`UnbondingPurse`]s at account hash derived from given public key
     fn read_unbond(&mut self, account_hash: &AccountHash) -> Result<Vec<UnbondingPurse>, Error>;
 
@@ -1,4 +1,4 @@
-mod internal;
+pub(crate) mod internal;
 pub(crate) mod mint_provider;
 pub(crate) mod runtime_provider;
 pub(crate) mod storage_provider;
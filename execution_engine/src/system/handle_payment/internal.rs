@@ -5,7 +5,7 @@ use tracing::error;
 use casper_types::{
     account::AccountHash,
     system::handle_payment::{Error, ACCUMULATION_PURSE_KEY, PAYMENT_PURSE_KEY, REFUND_PURSE_KEY},
-    Key, Phase, PublicKey, URef, U512,
+    FeeBreakdown, Key, Phase, PublicKey, URef, U512,
 };
 
 use super::{
@@ -53,7 +53,7 @@ pub(crate) fn get_refund_purse<R: RuntimeProvider>(
 /// # Note
 ///
 /// Any dust amounts are added to the fee.
-fn calculate_refund_and_fee(
+pub(crate) fn calculate_refund_and_fee(
     gas_spent: U512,
     payment_purse_balance: U512,
     refund_handling: &RefundHandling,
@@ -85,6 +85,35 @@ fn calculate_refund_and_fee(
     Ok((refund, fee))
 }
 
+/// Returns how the fee is split between the part paid to the proposer and the part which is burned.
+///
+/// # Note
+///
+/// Any dust amounts are added to the proposer's part.
+pub(crate) fn calculate_fee_split(
+    fee: U512,
+    burn_ratio: &Ratio<u64>,
+) -> Result<FeeBreakdown, Error> {
+    debug_assert!(
+        burn_ratio <= &Ratio::one(),
+        "burn ratio should be a proper fraction"
+    );
+    let (numer, denom) = (*burn_ratio).into();
+    let burn_ratio = Ratio::new_raw(U512::from(numer), U512::from(denom));
+
+    let burned = Ratio::from(fee)
+        .checked_mul(&burn_ratio)
+        .ok_or(Error::ArithmeticOverflow)?
+        .to_integer();
+
+    let paid_to_proposer = fee.checked_sub(burned).ok_or(Error::ArithmeticOverflow)?;
+
+    Ok(FeeBreakdown {
+        paid_to_proposer,
+        burned,
+    })
+}
+
 /// Transfers funds from the payment purse to the proposer, accumulation purse or burns the amount
 /// depending on a [`FeeHandling`] configuration option. This function can also transfer funds to a
 /// refund purse, depending on how much was spent on the computation, or burns the refund. This code
@@ -197,6 +226,24 @@ pub(crate) fn finalize_payment<P: MintProvider + RuntimeProvider + StorageProvid
             provider.write_balance(payment_purse, U512::zero())?;
             provider.reduce_total_supply(fee)?;
         }
+        FeeHandling::Split { burn_ratio } => {
+            let FeeBreakdown {
+                paid_to_proposer,
+                burned,
+            } = calculate_fee_split(fee, &burn_ratio)?;
+            // Target purse is the proposer's main purse.
+            match provider.transfer_purse_to_purse(payment_purse, target, paid_to_proposer) {
+                Ok(()) => {}
+                Err(error) => {
+                    error!(%error, %paid_to_proposer, %target, "unable to transfer fee");
+                    return Err(Error::FailedTransferToRewardsPurse);
+                }
+            }
+            // Deduct the rest of the fee from the payment purse, leaving it empty, and reduce the
+            // total supply (i.e. burn it).
+            provider.write_balance(payment_purse, U512::zero())?;
+            provider.reduce_total_supply(burned)?;
+        }
     }
     Ok(())
 }
@@ -236,7 +283,7 @@ where
 
     // Distribute accumulation purse balance into all administrators
     match provider.fee_handling() {
-        FeeHandling::PayToProposer | FeeHandling::Burn => return Ok(()),
+        FeeHandling::PayToProposer | FeeHandling::Burn | FeeHandling::Split { .. } => return Ok(()),
         FeeHandling::Accumulate => {}
     }
 
@@ -302,6 +349,27 @@ mod tests {
             assert_eq!(a + b, Ratio::from(purse_bal));
         }
     }
+
+    #[test]
+    fn should_move_burn_dust_to_proposer() {
+        let fee = U512::from(10u64);
+        let breakdown = calculate_fee_split(fee, &Ratio::new_raw(1, 3)).unwrap();
+        // 10 * 1/3 ~ 3.33 (.33 is dust)
+        assert_eq!(breakdown.burned, U512::from(3u64));
+        assert_eq!(breakdown.paid_to_proposer, U512::from(7u64));
+
+        let breakdown = calculate_fee_split(fee, &Ratio::new_raw(0, 1)).unwrap();
+        assert_eq!(
+            (breakdown.paid_to_proposer, breakdown.burned),
+            (fee, U512::zero())
+        );
+
+        let breakdown = calculate_fee_split(fee, &Ratio::new_raw(1, 1)).unwrap();
+        assert_eq!(
+            (breakdown.paid_to_proposer, breakdown.burned),
+            (U512::zero(), fee)
+        );
+    }
 }
 
 #[cfg(test)]
@@ -335,5 +403,11 @@ mod proptests {
             let (refund, fee) = calculate_refund_and_fee(gas, balance, &refund).unwrap();
             prop_assert_eq!(refund + fee, balance);
         }
+
+        #[test]
+        fn fee_split_equals_fee(burn_ratio in proper_fraction(DENOM_MAX), (fee, _) in balance_and_gas(BALANCE_MAX)) {
+            let breakdown = calculate_fee_split(fee, &burn_ratio).unwrap();
+            prop_assert_eq!(breakdown.paid_to_proposer + breakdown.burned, fee);
+        }
     }
 }
@@ -74,6 +74,8 @@ pub struct CoreConfig {
     pub(crate) strict_argument_checking: bool,
     /// The maximum amount of delegators per validator.
     pub(crate) max_delegators_per_validator: Option<u32>,
+    /// The number of eras a delegation rate increase is delayed by.
+    pub(crate) delegation_rate_change_notice_eras: u64,
     /// Refund handling.
     pub(crate) refund_handling: RefundHandling,
     /// Fee handling.
@@ -158,6 +160,7 @@ impl ChainspecConfig {
             minimum_delegation_amount: _,
             strict_argument_checking: _,
             max_delegators_per_validator: _,
+            delegation_rate_change_notice_eras: _,
             refund_handling: _,
             fee_handling: _,
         } = core_config;
@@ -21,6 +21,8 @@ struct DeployItemData {
     pub gas_price: u64,
     pub authorization_keys: BTreeSet<AccountHash>,
     pub deploy_hash: Option<DeployHash>,
+    pub fee_payer: Option<AccountHash>,
+    pub sequence_number: Option<u64>,
 }
 
 /// Builds a [`DeployItem`].
@@ -261,6 +263,18 @@ impl DeployItemBuilder {
         self
     }
 
+    /// Sets the account paying for the deploy.
+    pub fn with_fee_payer(mut self, fee_payer: AccountHash) -> Self {
+        self.deploy_item.fee_payer = Some(fee_payer);
+        self
+    }
+
+    /// Sets the account sequence number of the deploy.
+    pub fn with_sequence_number(mut self, sequence_number: u64) -> Self {
+        self.deploy_item.sequence_number = Some(sequence_number);
+        self
+    }
+
     /// Consumes self and returns a [`DeployItem`].
     pub fn build(self) -> DeployItem {
         DeployItem {
@@ -282,6 +296,8 @@ impl DeployItemBuilder {
                 .deploy_item
                 .deploy_hash
                 .unwrap_or_else(|| rand::thread_rng().gen()),
+            fee_payer: self.deploy_item.fee_payer,
+            sequence_number: self.deploy_item.sequence_number,
         }
     }
 }
@@ -1,10 +1,12 @@
 use rand::Rng;
 
-use casper_execution_engine::core::engine_state::{
-    deploy_item::DeployItem, execute_request::ExecuteRequest,
+use casper_execution_engine::{
+    core::engine_state::{deploy_item::DeployItem, execute_request::ExecuteRequest},
+    shared::gas_profile::GasProfiler,
 };
+use casper_hashing::Digest;
 use casper_types::{
-    account::AccountHash, runtime_args, ContractHash, ContractPackageHash, ContractVersion,
+    account::AccountHash, runtime_args, ContractHash, ContractPackageHash, ContractVersion, EraId,
     ProtocolVersion, RuntimeArgs,
 };
 
@@ -47,6 +49,30 @@ impl ExecuteRequestBuilder {
         self
     }
 
+    /// Sets the block height of the [`ExecuteRequest`].
+    pub fn with_block_height(mut self, block_height: u64) -> Self {
+        self.execute_request.block_height = block_height;
+        self
+    }
+
+    /// Sets the era id of the [`ExecuteRequest`].
+    pub fn with_era_id(mut self, era_id: EraId) -> Self {
+        self.execute_request.era_id = era_id;
+        self
+    }
+
+    /// Sets the parent block hash of the [`ExecuteRequest`].
+    pub fn with_parent_block_hash(mut self, parent_block_hash: Digest) -> Self {
+        self.execute_request.parent_block_hash = parent_block_hash;
+        self
+    }
+
+    /// Sets the chain name of the [`ExecuteRequest`].
+    pub fn with_chain_name<T: Into<String>>(mut self, chain_name: T) -> Self {
+        self.execute_request.chain_name = chain_name.into();
+        self
+    }
+
     /// Sets the protocol version of the [`ExecuteRequest`].
     pub fn with_protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
         self.execute_request.protocol_version = protocol_version;
@@ -59,6 +85,12 @@ impl ExecuteRequestBuilder {
         self
     }
 
+    /// Sets the gas profiler to which the gas charged by the [`ExecuteRequest`] is attributed.
+    pub fn with_gas_profiler(mut self, gas_profiler: GasProfiler) -> Self {
+        self.execute_request.gas_profiler = Some(gas_profiler);
+        self
+    }
+
     /// Consumes self and returns an [`ExecuteRequest`].
     pub fn build(self) -> ExecuteRequest {
         self.execute_request
@@ -27,14 +27,15 @@ use casper_execution_engine::{
             step::{EvictItem, StepRequest, StepSuccess},
             BalanceResult, EngineConfig, EngineConfigBuilder, EngineState, Error, GenesisSuccess,
             GetBidsRequest, PruneConfig, PruneResult, QueryRequest, QueryResult, RewardItem,
-            StepError, SystemContractRegistry, UpgradeConfig, UpgradeSuccess,
-            DEFAULT_MAX_QUERY_DEPTH,
+            ScheduledInvocationsRequest, ScheduledInvocationsResult, StepError,
+            SystemContractRegistry, UpgradeConfig, UpgradeSuccess, DEFAULT_MAX_QUERY_DEPTH,
         },
         execution,
     },
     shared::{
         additive_map::AdditiveMap,
         execution_journal::ExecutionJournal,
+        gas_profile::{GasProfile, GasProfiler},
         logging::{self, Settings, Style},
         newtypes::CorrelationId,
         system_config::{
@@ -70,7 +71,7 @@ use casper_types::{
     },
     CLTyped, CLValue, Contract, ContractHash, ContractPackage, ContractPackageHash, ContractWasm,
     DeployHash, DeployInfo, EraId, Gas, Key, KeyTag, Motes, ProtocolVersion, PublicKey,
-    RuntimeArgs, StoredValue, Transfer, TransferAddr, URef, U512,
+    RuntimeArgs, ScheduledInvocationAddr, StoredValue, Transfer, TransferAddr, URef, U512,
 };
 
 use crate::{
@@ -232,6 +233,7 @@ impl InMemoryWasmTestBuilder {
             minimum_delegation_amount,
             strict_argument_checking,
             max_delegators_per_validator,
+            delegation_rate_change_notice_eras,
             refund_handling,
             fee_handling,
         } = core_config;
@@ -244,6 +246,7 @@ impl InMemoryWasmTestBuilder {
             .with_strict_argument_checking(strict_argument_checking)
             .with_vesting_schedule_period_millis(vesting_schedule_period.millis())
             .with_max_delegators_per_validator(max_delegators_per_validator)
+            .with_delegation_rate_change_notice_eras(delegation_rate_change_notice_eras)
             .with_wasm_config(wasm_config)
             .with_system_config(system_costs_config)
             .with_refund_handling(refund_handling)
@@ -370,6 +373,11 @@ impl LmdbWasmTestBuilder {
             .with_max_delegators_per_validator(
                 chainspec_config.core_config.max_delegators_per_validator,
             )
+            .with_delegation_rate_change_notice_eras(
+                chainspec_config
+                    .core_config
+                    .delegation_rate_change_notice_eras,
+            )
             .with_wasm_config(chainspec_config.wasm_config)
             .with_system_config(chainspec_config.system_costs_config)
             .build();
@@ -763,6 +771,16 @@ where
         self
     }
 
+    /// Runs an [`ExecuteRequest`] with gas profiling enabled and returns the collected profile.
+    ///
+    /// The execution results are recorded as for [`WasmTestBuilder::exec`].
+    pub fn exec_with_gas_profile(&mut self, mut exec_request: ExecuteRequest) -> GasProfile {
+        let gas_profiler = GasProfiler::new();
+        exec_request.gas_profiler = Some(gas_profiler.clone());
+        self.exec(exec_request);
+        gas_profiler.take_profile()
+    }
+
     /// Commit effects of previous exec call on the latest post-state hash.
     pub fn commit(&mut self) -> &mut Self {
         let prestate_hash = self.post_state_hash.expect("Should have genesis hash");
@@ -867,6 +885,38 @@ where
         step_result
     }
 
+    /// Executes the scheduled invocations which are due in the block of the request, then prunes
+    /// them from global state.
+    ///
+    /// Returns the addresses of the executed invocations along with their execution results.
+    pub fn run_scheduled_invocations(
+        &mut self,
+        request: ScheduledInvocationsRequest,
+    ) -> Vec<(ScheduledInvocationAddr, ExecutionResult)> {
+        let execution_results = match self
+            .engine_state
+            .run_scheduled_invocations(CorrelationId::new(), request)
+            .expect("should run scheduled invocations")
+        {
+            ScheduledInvocationsResult::RootNotFound => panic!("root should exist"),
+            ScheduledInvocationsResult::Success {
+                post_state_hash,
+                execution_results,
+            } => {
+                self.post_state_hash = Some(post_state_hash);
+                execution_results
+            }
+        };
+
+        let keys_to_prune = execution_results
+            .iter()
+            .map(|(addr, _)| Key::ScheduledInvocation(*addr))
+            .collect::<Vec<_>>();
+        self.commit_prune(PruneConfig::new(self.get_post_state_hash(), keys_to_prune));
+
+        execution_results
+    }
+
     /// Expects a successful run
     pub fn expect_success(&mut self) -> &mut Self {
         // Check first result, as only first result is interesting for a simple test
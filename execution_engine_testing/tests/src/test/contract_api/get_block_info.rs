@@ -0,0 +1,53 @@
+use casper_engine_test_support::{
+    DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_PAYMENT, PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_hashing::Digest;
+use casper_types::{runtime_args, EraId, RuntimeArgs};
+
+const CONTRACT_GET_BLOCK_INFO: &str = "get_block_info.wasm";
+const ARG_KNOWN_BLOCK_HEIGHT: &str = "known_block_height";
+const ARG_KNOWN_ERA_ID: &str = "known_era_id";
+const ARG_KNOWN_PARENT_BLOCK_HASH: &str = "known_parent_block_hash";
+const ARG_KNOWN_DEPLOY_HASH: &str = "known_deploy_hash";
+const ARG_KNOWN_CHAIN_NAME: &str = "known_chain_name";
+
+#[ignore]
+#[test]
+fn should_run_get_block_info_contract() {
+    let block_height: u64 = 42;
+    let era_id = EraId::new(7);
+    let parent_block_hash = Digest::hash(b"parent block");
+    let deploy_hash = [55; 32];
+    let chain_name = "casper-test";
+
+    let deploy_item = DeployItemBuilder::new()
+        .with_address(*DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(
+            CONTRACT_GET_BLOCK_INFO,
+            runtime_args! {
+                ARG_KNOWN_BLOCK_HEIGHT => block_height,
+                ARG_KNOWN_ERA_ID => era_id,
+                ARG_KNOWN_PARENT_BLOCK_HASH => parent_block_hash.value(),
+                ARG_KNOWN_DEPLOY_HASH => deploy_hash,
+                ARG_KNOWN_CHAIN_NAME => chain_name,
+            },
+        )
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash(deploy_hash)
+        .build();
+
+    let exec_request = ExecuteRequestBuilder::from_deploy_item(deploy_item)
+        .with_block_height(block_height)
+        .with_era_id(era_id)
+        .with_parent_block_hash(parent_block_hash)
+        .with_chain_name(chain_name)
+        .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+}
@@ -1,7 +1,9 @@
 mod account;
+mod call_contract_view;
 mod create_purse;
 mod dictionary;
 mod get_arg;
+mod get_block_info;
 mod get_blocktime;
 mod get_call_stack;
 mod get_caller;
@@ -14,6 +16,7 @@ mod multisig_authorization;
 mod named_dictionaries;
 mod revert;
 mod runtime;
+mod schedule_invocation;
 mod subcall;
 mod transfer;
 mod transfer_cached;
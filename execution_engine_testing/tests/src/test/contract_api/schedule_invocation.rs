@@ -0,0 +1,332 @@
+use casper_engine_test_support::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_PROPOSER_PUBLIC_KEY, DEFAULT_PROTOCOL_VERSION, PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::{
+    engine_state::{self, BlockInfo, EngineConfigBuilder, ScheduledInvocationsRequest},
+    execution,
+};
+use casper_hashing::Digest;
+use casper_types::{
+    runtime_args, BlockTime, ContractHash, EraId, Key, KeyTag, ProtocolVersion, RuntimeArgs,
+    ScheduledInvocation, ScheduledTime, Timestamp, SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS,
+    U512,
+};
+
+const SCHEDULED_COUNTER: &str = "scheduled_counter.wasm";
+const SCHEDULE_INVOCATION: &str = "schedule_invocation.wasm";
+const HASH_KEY_NAME: &str = "scheduled_counter_hash";
+const COUNT_KEY_NAME: &str = "count";
+const INVOCATION_KEY_NAME: &str = "scheduled_invocation";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_ENTRY_POINT: &str = "entry_point";
+const ARG_SCHEDULED_TIME: &str = "scheduled_time";
+const ARG_AMOUNT: &str = "amount";
+const ENTRY_POINT_INCREMENT: &str = "increment";
+const ENTRY_POINT_SESSION_INCREMENT: &str = "session_increment";
+const ENTRY_POINT_SCHEDULE_INCREMENT: &str = "schedule_increment";
+const SCHEDULED_ERA: u64 = 5;
+const PREPAID_AMOUNT: u64 = 10_000_000_000;
+
+fn setup() -> (InMemoryWasmTestBuilder, ContractHash) {
+    setup_with_builder(InMemoryWasmTestBuilder::default())
+}
+
+fn setup_with_builder(
+    mut builder: InMemoryWasmTestBuilder,
+) -> (InMemoryWasmTestBuilder, ContractHash) {
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        SCHEDULED_COUNTER,
+        RuntimeArgs::new(),
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let contract_hash = builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(HASH_KEY_NAME)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash");
+
+    (builder, contract_hash)
+}
+
+fn schedule_invocation(
+    builder: &mut InMemoryWasmTestBuilder,
+    contract_hash: ContractHash,
+    entry_point: &str,
+    scheduled_time: ScheduledTime,
+) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        SCHEDULE_INVOCATION,
+        runtime_args! {
+            ARG_CONTRACT_HASH => contract_hash,
+            ARG_ENTRY_POINT => entry_point,
+            ARG_SCHEDULED_TIME => scheduled_time,
+            ARG_AMOUNT => U512::from(PREPAID_AMOUNT),
+        },
+    )
+    .build();
+    builder.exec(exec_request).commit();
+}
+
+fn scheduled_invocations_request(
+    builder: &InMemoryWasmTestBuilder,
+    era_id: u64,
+) -> ScheduledInvocationsRequest {
+    block_scheduled_invocations_request(builder, era_id, Timestamp::zero(), Timestamp::zero())
+}
+
+fn block_scheduled_invocations_request(
+    builder: &InMemoryWasmTestBuilder,
+    era_id: u64,
+    parent_block_time: Timestamp,
+    block_time: Timestamp,
+) -> ScheduledInvocationsRequest {
+    ScheduledInvocationsRequest::new(
+        builder.get_post_state_hash(),
+        *DEFAULT_PROTOCOL_VERSION,
+        BlockInfo::new(
+            BlockTime::new(block_time.millis()),
+            0,
+            EraId::new(era_id),
+            Digest::default(),
+            String::new(),
+        ),
+        parent_block_time,
+        DEFAULT_PROPOSER_PUBLIC_KEY.clone(),
+    )
+}
+
+fn get_count(builder: &InMemoryWasmTestBuilder, contract_hash: ContractHash) -> u64 {
+    builder
+        .query(
+            None,
+            Key::from(contract_hash),
+            &[COUNT_KEY_NAME.to_string()],
+        )
+        .expect("should have count")
+        .as_cl_value()
+        .cloned()
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be u64")
+}
+
+#[ignore]
+#[test]
+fn should_execute_scheduled_invocation_once_due() {
+    let (mut builder, contract_hash) = setup();
+
+    schedule_invocation(
+        &mut builder,
+        contract_hash,
+        ENTRY_POINT_INCREMENT,
+        ScheduledTime::Era(EraId::new(SCHEDULED_ERA)),
+    );
+    builder.expect_success();
+
+    let invocation_key = *builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(INVOCATION_KEY_NAME)
+        .expect("should have invocation key");
+    let invocation: ScheduledInvocation = builder
+        .query(None, invocation_key, &[])
+        .expect("should have invocation")
+        .as_cl_value()
+        .cloned()
+        .expect("should be a CLValue")
+        .into_t()
+        .expect("should be a scheduled invocation");
+    assert_eq!(invocation.initiator(), *DEFAULT_ACCOUNT_ADDR);
+    assert_eq!(invocation.contract_hash(), contract_hash);
+    assert_eq!(invocation.entry_point(), ENTRY_POINT_INCREMENT);
+    assert_eq!(
+        builder.get_purse_balance(invocation.payment_purse()),
+        U512::from(PREPAID_AMOUNT)
+    );
+
+    let request = scheduled_invocations_request(&builder, SCHEDULED_ERA - 1);
+    let execution_results = builder.run_scheduled_invocations(request);
+    assert!(execution_results.is_empty());
+    assert_eq!(get_count(&builder, contract_hash), 0);
+
+    let account_balance_before = builder.get_purse_balance(
+        builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .main_purse(),
+    );
+
+    let request = scheduled_invocations_request(&builder, SCHEDULED_ERA);
+    let execution_results = builder.run_scheduled_invocations(request);
+    assert_eq!(execution_results.len(), 1);
+    let (addr, execution_result) = &execution_results[0];
+    assert_eq!(Key::ScheduledInvocation(*addr), invocation_key);
+    assert!(execution_result.is_success(), "{:?}", execution_result);
+    assert_eq!(get_count(&builder, contract_hash), 1);
+
+    // Whatever the invocation didn't spend is returned to the initiator.
+    let account_balance_after = builder.get_purse_balance(
+        builder
+            .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+            .main_purse(),
+    );
+    assert!(account_balance_after > account_balance_before);
+    assert_eq!(
+        builder.get_purse_balance(invocation.payment_purse()),
+        U512::zero()
+    );
+
+    assert!(builder
+        .get_keys(KeyTag::ScheduledInvocation)
+        .expect("should get keys")
+        .is_empty());
+
+    let request = scheduled_invocations_request(&builder, SCHEDULED_ERA + 1);
+    assert!(builder.run_scheduled_invocations(request).is_empty());
+    assert_eq!(get_count(&builder, contract_hash), 1);
+}
+
+#[ignore]
+#[test]
+fn should_not_schedule_invocation_in_the_past() {
+    let (mut builder, contract_hash) = setup();
+
+    schedule_invocation(
+        &mut builder,
+        contract_hash,
+        ENTRY_POINT_INCREMENT,
+        ScheduledTime::Era(EraId::new(0)),
+    );
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::ScheduledTimeNotInFuture)
+        ),
+        "Error received {:?}",
+        error,
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_schedule_session_entry_point() {
+    let (mut builder, contract_hash) = setup();
+
+    schedule_invocation(
+        &mut builder,
+        contract_hash,
+        ENTRY_POINT_SESSION_INCREMENT,
+        ScheduledTime::Era(EraId::new(SCHEDULED_ERA)),
+    );
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::UnschedulableEntryPoint(ref name))
+            if name == ENTRY_POINT_SESSION_INCREMENT,
+        ),
+        "Error received {:?}",
+        error,
+    );
+}
+
+#[ignore]
+#[test]
+fn should_execute_invocation_scheduled_for_timestamp_between_blocks() {
+    let (mut builder, contract_hash) = setup();
+
+    // Scheduled in the middle of a bucket, and due in a block several buckets later.
+    let scheduled_time = Timestamp::from(3 * SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS + 1);
+    schedule_invocation(
+        &mut builder,
+        contract_hash,
+        ENTRY_POINT_INCREMENT,
+        ScheduledTime::Timestamp(scheduled_time),
+    );
+    builder.expect_success();
+
+    let parent_block_time = Timestamp::from(SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS);
+    let block_time = Timestamp::from(3 * SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS);
+    let request = block_scheduled_invocations_request(&builder, 0, parent_block_time, block_time);
+    assert!(builder.run_scheduled_invocations(request).is_empty());
+    assert_eq!(get_count(&builder, contract_hash), 0);
+
+    let parent_block_time = block_time;
+    let block_time = Timestamp::from(5 * SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS);
+    let request = block_scheduled_invocations_request(&builder, 0, parent_block_time, block_time);
+    let execution_results = builder.run_scheduled_invocations(request);
+    assert_eq!(execution_results.len(), 1);
+    assert!(
+        execution_results[0].1.is_success(),
+        "{:?}",
+        execution_results[0].1
+    );
+    assert_eq!(get_count(&builder, contract_hash), 1);
+}
+
+#[ignore]
+#[test]
+fn should_not_schedule_invocation_from_contract() {
+    let (mut builder, contract_hash) = setup();
+
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        ENTRY_POINT_SCHEDULE_INCREMENT,
+        runtime_args! {
+            ARG_CONTRACT_HASH => contract_hash,
+        },
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::ScheduleInvocationFromContract)
+        ),
+        "Error received {:?}",
+        error,
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_schedule_invocation_before_activation() {
+    let engine_config = EngineConfigBuilder::default()
+        .with_start_protocol_version_with_scheduled_invocations(ProtocolVersion::from_parts(
+            2, 0, 0,
+        ))
+        .build();
+    let (mut builder, contract_hash) =
+        setup_with_builder(InMemoryWasmTestBuilder::new_with_config(engine_config));
+
+    schedule_invocation(
+        &mut builder,
+        contract_hash,
+        ENTRY_POINT_INCREMENT,
+        ScheduledTime::Era(EraId::new(SCHEDULED_ERA)),
+    );
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::DisabledScheduledInvocations)
+        ),
+        "Error received {:?}",
+        error,
+    );
+}
@@ -0,0 +1,197 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_PAYMENT, PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::Error;
+use casper_types::{account::AccountHash, runtime_args, RuntimeArgs, U512};
+
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([42u8; 32]);
+const ACCOUNT_1_INITIAL_FUND: u64 = 100_000_000_000;
+const ARG_AMOUNT: &str = "amount";
+const ARG_TARGET: &str = "target";
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+const TRANSFER_PURSE_TO_ACCOUNT_WASM: &str = "transfer_purse_to_account.wasm";
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let fund_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        TRANSFER_PURSE_TO_ACCOUNT_WASM,
+        runtime_args! {
+            ARG_TARGET => ACCOUNT_1_ADDR,
+            ARG_AMOUNT => U512::from(ACCOUNT_1_INITIAL_FUND),
+        },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST)
+        .exec(fund_request)
+        .expect_success()
+        .commit();
+    builder
+}
+
+#[ignore]
+#[test]
+fn should_charge_fee_payer_instead_of_account() {
+    let mut builder = setup();
+
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account");
+    let account_1 = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1");
+    let default_balance_before = builder.get_purse_balance(default_account.main_purse());
+    let account_1_balance_before = builder.get_purse_balance(account_1.main_purse());
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(DO_NOTHING_WASM, RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR, ACCOUNT_1_ADDR])
+            .with_fee_payer(ACCOUNT_1_ADDR)
+            .with_deploy_hash([1; 32])
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    builder.exec(exec_request).expect_success().commit();
+
+    let default_balance_after = builder.get_purse_balance(default_account.main_purse());
+    let account_1_balance_after = builder.get_purse_balance(account_1.main_purse());
+
+    assert_eq!(
+        default_balance_after, default_balance_before,
+        "the deploy's account should not be charged"
+    );
+    assert!(
+        account_1_balance_after < account_1_balance_before,
+        "the fee payer should be charged"
+    );
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_fee_payer_with_custom_payment() {
+    let mut builder = setup();
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(DO_NOTHING_WASM, RuntimeArgs::default())
+            .with_payment_code(
+                DO_NOTHING_WASM,
+                runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT },
+            )
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR, ACCOUNT_1_ADDR])
+            .with_fee_payer(ACCOUNT_1_ADDR)
+            .with_deploy_hash([1; 32])
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_result_owned(1)
+        .expect("there should be a response");
+
+    let precondition_failure = utils::get_precondition_failure(&response);
+    assert_matches!(precondition_failure, Error::UnsupportedFeePayer);
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_nonexistent_fee_payer() {
+    let mut builder = setup();
+    let nonexistent_account_addr = AccountHash::new([99u8; 32]);
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(DO_NOTHING_WASM, RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR, nonexistent_account_addr])
+            .with_fee_payer(nonexistent_account_addr)
+            .with_deploy_hash([1; 32])
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_result_owned(1)
+        .expect("there should be a response");
+
+    let precondition_failure = utils::get_precondition_failure(&response);
+    assert_matches!(precondition_failure, Error::Authorization);
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_fee_payer_without_approval() {
+    let mut builder = setup();
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(DO_NOTHING_WASM, RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .with_fee_payer(ACCOUNT_1_ADDR)
+            .with_deploy_hash([1; 32])
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    builder.exec(exec_request).commit();
+
+    let response = builder
+        .get_exec_result_owned(1)
+        .expect("there should be a response");
+
+    let precondition_failure = utils::get_precondition_failure(&response);
+    assert_matches!(precondition_failure, Error::Authorization);
+}
+
+#[ignore]
+#[test]
+fn should_charge_account_paying_for_its_own_deploy() {
+    let mut builder = setup();
+
+    let default_account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have default account");
+    let default_balance_before = builder.get_purse_balance(default_account.main_purse());
+
+    let exec_request = {
+        let deploy = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_session_code(DO_NOTHING_WASM, RuntimeArgs::default())
+            .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .with_fee_payer(*DEFAULT_ACCOUNT_ADDR)
+            .with_deploy_hash([1; 32])
+            .build();
+
+        ExecuteRequestBuilder::new().push_deploy(deploy).build()
+    };
+
+    builder.exec(exec_request).expect_success().commit();
+
+    let default_balance_after = builder.get_purse_balance(default_account.main_purse());
+    assert!(
+        default_balance_after < default_balance_before,
+        "the account should be charged as its own fee payer"
+    );
+}
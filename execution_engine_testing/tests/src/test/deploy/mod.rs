@@ -1,5 +1,7 @@
 mod context_association;
+mod fee_payer;
 mod non_standard_payment;
 mod preconditions;
 mod receipts;
+mod sequence_number;
 mod stored_contracts;
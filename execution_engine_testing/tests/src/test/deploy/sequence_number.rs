@@ -0,0 +1,163 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    utils, DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
+    DEFAULT_PAYMENT, PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::{
+    engine_state::{EngineConfigBuilder, Error, ExecuteRequest},
+    execution,
+};
+use casper_types::{account::AccountHash, runtime_args, ProtocolVersion, RuntimeArgs};
+
+const ARG_ACCOUNT: &str = "account";
+const ARG_AMOUNT: &str = "amount";
+const ADD_UPDATE_ASSOCIATED_KEY_WASM: &str = "add_update_associated_key.wasm";
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+const REVERT_WASM: &str = "revert.wasm";
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+
+fn sequenced_request(session_file: &str, sequence_number: u64, hash: u8) -> ExecuteRequest {
+    sequenced_request_with_args(session_file, RuntimeArgs::default(), sequence_number, hash)
+}
+
+fn sequenced_request_with_args(
+    session_file: &str,
+    session_args: RuntimeArgs,
+    sequence_number: u64,
+    hash: u8,
+) -> ExecuteRequest {
+    let deploy = DeployItemBuilder::new()
+        .with_address(*DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(session_file, session_args)
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+        .with_sequence_number(sequence_number)
+        .with_deploy_hash([hash; 32])
+        .build();
+
+    ExecuteRequestBuilder::new().push_deploy(deploy).build()
+}
+
+fn next_sequence_number(builder: &InMemoryWasmTestBuilder) -> u64 {
+    builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .sequence_number()
+}
+
+fn last_precondition_failure(builder: &InMemoryWasmTestBuilder) -> Error {
+    let response = builder
+        .get_last_exec_results()
+        .expect("there should be a response");
+    utils::get_precondition_failure(&response).clone()
+}
+
+#[ignore]
+#[test]
+fn should_execute_deploys_in_sequence_number_order() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    assert_eq!(next_sequence_number(&builder), 0);
+
+    builder
+        .exec(sequenced_request(DO_NOTHING_WASM, 0, 1))
+        .expect_success()
+        .commit();
+    assert_eq!(next_sequence_number(&builder), 1);
+
+    builder
+        .exec(sequenced_request(DO_NOTHING_WASM, 1, 2))
+        .expect_success()
+        .commit();
+    assert_eq!(next_sequence_number(&builder), 2);
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_out_of_order_sequence_number() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    builder
+        .exec(sequenced_request(DO_NOTHING_WASM, 1, 1))
+        .commit();
+    assert_matches!(
+        last_precondition_failure(&builder),
+        Error::InvalidSequenceNumber {
+            expected: 0,
+            got: 1
+        }
+    );
+    assert_eq!(next_sequence_number(&builder), 0);
+
+    builder
+        .exec(sequenced_request(DO_NOTHING_WASM, 0, 2))
+        .expect_success()
+        .commit();
+
+    // Replaying an already used sequence number is rejected.
+    builder
+        .exec(sequenced_request(DO_NOTHING_WASM, 0, 3))
+        .commit();
+    assert_matches!(
+        last_precondition_failure(&builder),
+        Error::InvalidSequenceNumber {
+            expected: 1,
+            got: 0
+        }
+    );
+    assert_eq!(next_sequence_number(&builder), 1);
+}
+
+#[ignore]
+#[test]
+fn should_use_sequence_number_of_failed_deploy() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    builder.exec(sequenced_request(REVERT_WASM, 0, 1)).commit();
+    let error = builder.get_error().expect("session should have failed");
+    assert_matches!(error, Error::Exec(execution::Error::Revert(_)));
+
+    assert_eq!(next_sequence_number(&builder), 1);
+}
+
+#[ignore]
+#[test]
+fn should_raise_precondition_failure_for_sequence_number_before_activation() {
+    let engine_config = EngineConfigBuilder::default()
+        .with_start_protocol_version_with_sequence_numbers(ProtocolVersion::from_parts(2, 0, 0))
+        .build();
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    builder
+        .exec(sequenced_request(DO_NOTHING_WASM, 0, 1))
+        .commit();
+    assert_matches!(
+        last_precondition_failure(&builder),
+        Error::UnsupportedSequenceNumber(_)
+    );
+    assert_eq!(next_sequence_number(&builder), 0);
+}
+
+#[ignore]
+#[test]
+fn should_keep_account_changes_of_sequenced_deploy() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    builder
+        .exec(sequenced_request_with_args(
+            ADD_UPDATE_ASSOCIATED_KEY_WASM,
+            runtime_args! { ARG_ACCOUNT => ACCOUNT_1_ADDR },
+            0,
+            1,
+        ))
+        .expect_success()
+        .commit();
+
+    let account = builder.get_expected_account(*DEFAULT_ACCOUNT_ADDR);
+    assert_eq!(account.sequence_number(), 1);
+    assert!(account.associated_keys().contains_key(&ACCOUNT_1_ADDR));
+}
@@ -0,0 +1,178 @@
+use casper_wasm::{
+    builder,
+    elements::{Instruction, Instructions},
+};
+
+use casper_engine_test_support::{
+    DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_PAYMENT, DEFAULT_WASM_CONFIG, PRODUCTION_RUN_GENESIS_REQUEST,
+};
+use casper_types::{
+    contracts::DEFAULT_ENTRY_POINT_NAME, runtime_args, ContractHash, RuntimeArgs, U512,
+};
+
+const CONTRACT_VIEW: &str = "contract_view.wasm";
+const CONTRACT_VIEW_CALL: &str = "contract_view_call.wasm";
+const HASH_KEY_NAME: &str = "contract_view_hash";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_ENTRY_POINT: &str = "entry_point";
+const ENTRY_POINT_GET_VALUE: &str = "get_value";
+
+fn install_contract(builder: &mut InMemoryWasmTestBuilder) -> ContractHash {
+    let install_request =
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CONTRACT_VIEW, RuntimeArgs::new())
+            .build();
+    builder.exec(install_request).expect_success().commit();
+
+    builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(HASH_KEY_NAME)
+        .and_then(|key| key.into_hash())
+        .map(ContractHash::new)
+        .expect("should have contract hash")
+}
+
+#[ignore]
+#[test]
+fn should_attribute_all_gas_of_session_code() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let install_request =
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CONTRACT_VIEW, RuntimeArgs::new())
+            .build();
+    let profile = builder.exec_with_gas_profile(install_request);
+    builder.expect_success().commit();
+
+    assert_eq!(
+        U512::from(profile.total_gas()),
+        builder.last_exec_gas_cost().value()
+    );
+    assert!(profile
+        .gas()
+        .keys()
+        .any(|stack| stack.starts_with("session;wasm:call;opcode:")));
+    assert!(profile.gas_for_frame("host:PutKeyFuncIndex") > 0);
+    assert!(profile
+        .storage_bytes()
+        .keys()
+        .all(|stack| stack.ends_with(";storage")));
+    assert!(!profile.storage_bytes().is_empty());
+
+    let folded = profile.to_folded();
+    assert!(folded.lines().all(|line| {
+        let (stack, gas) = line.rsplit_once(' ').expect("should have gas");
+        !stack.is_empty() && gas.parse::<u64>().is_ok()
+    }));
+}
+
+#[ignore]
+#[test]
+fn should_attribute_gas_to_called_contract() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    let contract_hash = install_contract(&mut builder);
+
+    let call_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_VIEW_CALL,
+        runtime_args! {
+            ARG_CONTRACT_HASH => contract_hash,
+            ARG_ENTRY_POINT => ENTRY_POINT_GET_VALUE,
+        },
+    )
+    .build();
+    let profile = builder.exec_with_gas_profile(call_request);
+    builder.expect_success();
+
+    let contract_frame = format!(
+        "{}::{}",
+        contract_hash.to_formatted_string(),
+        ENTRY_POINT_GET_VALUE
+    );
+    assert!(profile.gas_for_frame(&contract_frame) > 0);
+    assert!(profile.gas().keys().any(|stack| stack.contains(&format!(
+        "host:CallContractViewIndex;{};wasm:{};opcode:",
+        contract_frame, ENTRY_POINT_GET_VALUE
+    ))));
+    assert_eq!(
+        U512::from(profile.total_gas()),
+        builder.last_exec_gas_cost().value()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_change_gas_cost_when_profiling() {
+    let exec_request = || {
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, CONTRACT_VIEW, RuntimeArgs::new())
+            .build()
+    };
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    builder.exec(exec_request()).expect_success();
+    let cost = builder.last_exec_gas_cost();
+
+    let mut profiled_builder = InMemoryWasmTestBuilder::default();
+    profiled_builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    profiled_builder.exec_with_gas_profile(exec_request());
+    profiled_builder.expect_success();
+
+    assert_eq!(profiled_builder.last_exec_gas_cost(), cost);
+}
+
+#[ignore]
+#[test]
+fn should_attribute_gas_of_growing_memory() {
+    const GROW_PAGES: u32 = 2;
+
+    let module = builder::module()
+        .function()
+        .signature()
+        .build()
+        .body()
+        .with_instructions(Instructions::new(vec![
+            Instruction::I32Const(GROW_PAGES as i32),
+            Instruction::GrowMemory(0),
+            Instruction::Drop,
+            Instruction::End,
+        ]))
+        .build()
+        .build()
+        .export()
+        .field(DEFAULT_ENTRY_POINT_NAME)
+        .build()
+        .memory()
+        .build()
+        .build();
+    let session_bytes = casper_wasm::serialize(module).expect("should serialize");
+    let deploy_item = DeployItemBuilder::new()
+        .with_address(*DEFAULT_ACCOUNT_ADDR)
+        .with_session_bytes(session_bytes, RuntimeArgs::default())
+        .with_empty_payment_bytes(runtime_args! {
+            ARG_AMOUNT => *DEFAULT_PAYMENT
+        })
+        .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash([42; 32])
+        .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+    let profile =
+        builder.exec_with_gas_profile(ExecuteRequestBuilder::from_deploy_item(deploy_item).build());
+    builder.expect_success();
+
+    // The charge for the pages grown is made by an unexported function wrapping `memory.grow`.
+    let grow_memory_cost = u64::from(DEFAULT_WASM_CONFIG.opcode_costs().grow_memory);
+    assert!(profile.gas().iter().any(|(stack, gas)| {
+        stack.starts_with("session;wasm:func[")
+            && stack.ends_with(";opcode:grow_memory")
+            && *gas == grow_memory_cost * u64::from(GROW_PAGES)
+    }));
+    assert_eq!(
+        U512::from(profile.total_gas()),
+        builder.last_exec_gas_cost().value()
+    );
+}
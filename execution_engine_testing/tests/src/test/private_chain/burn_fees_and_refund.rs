@@ -8,7 +8,7 @@ use casper_execution_engine::{
 use casper_types::{
     runtime_args,
     system::{handle_payment::ACCUMULATION_PURSE_KEY, mint},
-    RuntimeArgs, U512,
+    FeeBreakdown, RuntimeArgs, U512,
 };
 use num_rational::Ratio;
 use num_traits::{One, Zero};
@@ -98,6 +98,62 @@ fn should_burn_full_refund_and_burn_fees() {
     test_burning_fees(full_refund_handling, fee_handling, expected_fee_amount);
 }
 
+#[ignore]
+#[test]
+fn should_split_the_fees_between_burning_and_proposer() {
+    let zero_refund_handling = RefundHandling::Refund {
+        refund_ratio: Ratio::zero(),
+    };
+    let fee_handling = FeeHandling::Split {
+        burn_ratio: Ratio::new(1, 3),
+    };
+    let mut builder = private_chain::custom_setup_genesis_only(
+        PRIVATE_CHAIN_ALLOW_AUCTION_BIDS,
+        PRIVATE_CHAIN_ALLOW_UNRESTRICTED_TRANSFERS,
+        zero_refund_handling,
+        fee_handling,
+    );
+    let exec_request = ExecuteRequestBuilder::module_bytes(
+        *DEFAULT_ADMIN_ACCOUNT_ADDR,
+        wasm_utils::do_minimum_bytes(),
+        RuntimeArgs::default(),
+    )
+    .build();
+    let proposer_account = builder
+        .get_account(exec_request.proposer.to_account_hash())
+        .expect("should have proposer account");
+    let proposer_balance_before = builder.get_purse_balance(proposer_account.main_purse());
+    let total_supply_before = builder.total_supply(None);
+
+    builder.exec(exec_request).expect_success().commit();
+
+    // Any dust left over from multiplying by the burn ratio goes to the proposer.
+    let expected_burned = *DEFAULT_PAYMENT / U512::from(3u64);
+    let expected_paid_to_proposer = *DEFAULT_PAYMENT - expected_burned;
+    assert_eq!(
+        total_supply_before - builder.total_supply(None),
+        expected_burned,
+        "total supply should be burned exactly by the burned part of the fee"
+    );
+    assert_eq!(
+        builder.get_purse_balance(proposer_account.main_purse()) - proposer_balance_before,
+        expected_paid_to_proposer,
+        "proposer should receive the rest of the fee"
+    );
+
+    let fee_breakdown = *builder
+        .last_exec_result()
+        .fee_breakdown()
+        .expect("should record the fee breakdown");
+    assert_eq!(
+        fee_breakdown,
+        FeeBreakdown {
+            paid_to_proposer: expected_paid_to_proposer,
+            burned: expected_burned,
+        }
+    );
+}
+
 fn test_burning_fees(
     refund_handling: RefundHandling,
     fee_handling: FeeHandling,
@@ -165,5 +221,8 @@ fn test_burning_fees(
                 "total supply should be burned exactly by the amount of calculated fees"
             );
         }
+        FeeHandling::Split { .. } => {
+            unreachable!("split fees are covered by a dedicated test")
+        }
     }
 }
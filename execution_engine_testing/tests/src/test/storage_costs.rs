@@ -139,6 +139,10 @@ static NEW_HOST_FUNCTION_COSTS: Lazy<HostFunctionCosts> = Lazy::new(|| HostFunct
     blake2b: HostFunction::fixed(0),
     random_bytes: HostFunction::fixed(0),
     enable_contract_version: HostFunction::fixed(0),
+    get_block_info: HostFunction::fixed(0),
+    call_contract_view: HostFunction::fixed(0),
+    add_contract_version_with_storage_schema: HostFunction::fixed(0),
+    schedule_invocation: HostFunction::fixed(0),
 });
 static STORAGE_COSTS_ONLY: Lazy<WasmConfig> = Lazy::new(|| {
     WasmConfig::new(
@@ -6,11 +6,11 @@ use once_cell::sync::Lazy;
 
 use casper_engine_test_support::{
     utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, StepRequestBuilder, DEFAULT_ACCOUNTS,
-    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE, DEFAULT_CHAINSPEC_REGISTRY,
-    DEFAULT_EXEC_CONFIG, DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
-    DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_PROTOCOL_VERSION, DEFAULT_UNBONDING_DELAY,
-    MINIMUM_ACCOUNT_CREATION_BALANCE, PRODUCTION_RUN_GENESIS_REQUEST, SYSTEM_ADDR,
-    TIMESTAMP_MILLIS_INCREMENT,
+    DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE, DEFAULT_ACCOUNT_PUBLIC_KEY,
+    DEFAULT_CHAINSPEC_REGISTRY, DEFAULT_EXEC_CONFIG, DEFAULT_GENESIS_CONFIG_HASH,
+    DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_PROTOCOL_VERSION,
+    DEFAULT_UNBONDING_DELAY, MINIMUM_ACCOUNT_CREATION_BALANCE, PRODUCTION_RUN_GENESIS_REQUEST,
+    SYSTEM_ADDR, TIMESTAMP_MILLIS_INCREMENT,
 };
 use casper_execution_engine::{
     core::{
@@ -20,17 +20,20 @@ use casper_execution_engine::{
             genesis::{ExecConfigBuilder, GenesisAccount, GenesisValidator},
             run_genesis_request::RunGenesisRequest,
             step::EvictItem,
-            EngineConfigBuilder, Error, RewardItem,
+            EngineConfigBuilder, Error, GetDelegatorsRequest, GetDelegatorsResult,
+            GetUnbondingQueueRequest, GetUnbondingQueueResult, RewardItem,
         },
         execution,
     },
+    shared::newtypes::CorrelationId,
     storage::global_state::in_memory::InMemoryGlobalState,
 };
 use casper_types::{
     self,
     account::AccountHash,
     api_error::ApiError,
-    runtime_args,
+    bytesrepr::{Bytes, ToBytes},
+    crypto, runtime_args,
     system::{
         self,
         auction::{
@@ -39,7 +42,8 @@ use casper_types::{
             ARG_PUBLIC_KEY, ARG_VALIDATOR, ERA_ID_KEY, INITIAL_ERA_ID,
         },
     },
-    EraId, Motes, ProtocolVersion, PublicKey, RuntimeArgs, SecretKey, U256, U512,
+    DelegatorAddr, EraId, Key, Motes, ProtocolVersion, PublicKey, RuntimeArgs, SecretKey,
+    Signature, U256, U512,
 };
 
 const ARG_TARGET: &str = "target";
@@ -260,7 +264,15 @@ fn should_increase_existing_bid() {
         builder.get_purse_balance(*active_bid.bonding_purse()),
         U512::from(ADD_BID_AMOUNT_1 + BID_AMOUNT_2)
     );
-    assert_eq!(*active_bid.delegation_rate(), ADD_BID_DELEGATION_RATE_2);
+    // The increase of the delegation rate only takes effect after the notice period.
+    assert_eq!(*active_bid.delegation_rate(), ADD_BID_DELEGATION_RATE_1);
+    let pending_delegation_rate = active_bid
+        .pending_delegation_rate()
+        .expect("should have pending delegation rate");
+    assert_eq!(
+        pending_delegation_rate.delegation_rate(),
+        ADD_BID_DELEGATION_RATE_2
+    );
 }
 
 #[ignore]
@@ -488,6 +500,32 @@ fn should_run_delegate_and_undelegate() {
     assert!(!unbond_list[0].is_validator());
 
     assert_eq!(unbond_list[0].era_of_creation(), INITIAL_ERA_ID);
+
+    // The unbonding queue of a validator includes the purses of its delegators.
+    let get_unbonding_queue_request = GetUnbondingQueueRequest::new(
+        builder.get_post_state_hash(),
+        Some(*NON_FOUNDER_VALIDATOR_1_ADDR),
+    );
+    let get_unbonding_queue_result = builder
+        .get_engine_state()
+        .get_unbonding_queue(CorrelationId::new(), get_unbonding_queue_request)
+        .expect("should get unbonding queue");
+    assert_matches!(
+        get_unbonding_queue_result,
+        GetUnbondingQueueResult::Success { unbonding_delay, unbonding_purses }
+        if unbonding_delay == DEFAULT_UNBONDING_DELAY && unbonding_purses == *unbond_list
+    );
+
+    let get_unbonding_queue_request =
+        GetUnbondingQueueRequest::new(builder.get_post_state_hash(), Some(*BID_ACCOUNT_1_ADDR));
+    let get_unbonding_queue_result = builder
+        .get_engine_state()
+        .get_unbonding_queue(CorrelationId::new(), get_unbonding_queue_request)
+        .expect("should get unbonding queue");
+    assert_matches!(
+        get_unbonding_queue_result,
+        GetUnbondingQueueResult::Success { unbonding_purses, .. } if unbonding_purses.is_empty()
+    );
 }
 
 #[ignore]
@@ -4368,3 +4406,529 @@ fn should_increase_existing_delegation_when_limit_exceeded() {
         .expect_success()
         .commit();
 }
+
+#[ignore]
+#[test]
+fn should_store_delegators_under_their_own_keys() {
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::account(
+            BID_ACCOUNT_1_PK.clone(),
+            Motes::new(BID_ACCOUNT_1_BALANCE.into()),
+            None,
+        );
+        let account_2 = GenesisAccount::account(
+            BID_ACCOUNT_2_PK.clone(),
+            Motes::new(BID_ACCOUNT_2_BALANCE.into()),
+            None,
+        );
+        tmp.push(account_1);
+        tmp.push(account_2);
+        tmp
+    };
+
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&run_genesis_request);
+
+    let transfer_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => *NON_FOUNDER_VALIDATOR_1_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let add_bid_request = ExecuteRequestBuilder::standard(
+        *NON_FOUNDER_VALIDATOR_1_ADDR,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            ARG_PUBLIC_KEY => NON_FOUNDER_VALIDATOR_1_PK.clone(),
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_1,
+        },
+    )
+    .build();
+
+    let delegate_request_1 = ExecuteRequestBuilder::standard(
+        *BID_ACCOUNT_1_ADDR,
+        CONTRACT_DELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(DELEGATE_AMOUNT_1),
+            ARG_VALIDATOR => NON_FOUNDER_VALIDATOR_1_PK.clone(),
+            ARG_DELEGATOR => BID_ACCOUNT_1_PK.clone(),
+        },
+    )
+    .build();
+
+    let delegate_request_2 = ExecuteRequestBuilder::standard(
+        *BID_ACCOUNT_2_ADDR,
+        CONTRACT_DELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(DELEGATE_AMOUNT_2),
+            ARG_VALIDATOR => NON_FOUNDER_VALIDATOR_1_PK.clone(),
+            ARG_DELEGATOR => BID_ACCOUNT_2_PK.clone(),
+        },
+    )
+    .build();
+
+    for request in [
+        transfer_request,
+        add_bid_request,
+        delegate_request_1,
+        delegate_request_2,
+    ] {
+        builder.exec(request).expect_success().commit();
+    }
+
+    // The bid itself no longer carries its delegators, only their number and total stake.
+    let stored_bid = builder
+        .query(None, Key::Bid(*NON_FOUNDER_VALIDATOR_1_ADDR), &[])
+        .expect("should have bid");
+    let stored_bid = stored_bid.as_bid().expect("should be bid");
+    assert!(stored_bid.delegators().is_empty());
+    assert_eq!(stored_bid.delegator_count(), 2);
+    assert_eq!(
+        *stored_bid.delegated_amount(),
+        U512::from(DELEGATE_AMOUNT_1 + DELEGATE_AMOUNT_2)
+    );
+
+    let delegator_key = Key::Delegator(DelegatorAddr::new(
+        *NON_FOUNDER_VALIDATOR_1_ADDR,
+        *BID_ACCOUNT_1_ADDR,
+    ));
+    let stored_delegator = builder
+        .query(None, delegator_key, &[])
+        .expect("should have delegator");
+    assert_eq!(
+        *stored_delegator
+            .as_delegator()
+            .expect("should be delegator")
+            .staked_amount(),
+        U512::from(DELEGATE_AMOUNT_1)
+    );
+
+    // Bids are reassembled with their delegators.
+    let bids: Bids = builder.get_bids();
+    let delegators = bids[&NON_FOUNDER_VALIDATOR_1_PK].delegators();
+    assert_eq!(delegators.len(), 2);
+
+    // Pages are ordered by delegator account hash.
+    let mut expected_delegators: Vec<_> = delegators.values().cloned().collect();
+    expected_delegators.sort_by_key(|delegator| delegator.delegator_addr().delegator());
+
+    let get_delegators_request = GetDelegatorsRequest::new(
+        builder.get_post_state_hash(),
+        NON_FOUNDER_VALIDATOR_1_PK.clone(),
+        None,
+        1,
+    );
+    let get_delegators_result = builder
+        .get_engine_state()
+        .get_delegators(CorrelationId::new(), get_delegators_request)
+        .expect("should get delegators");
+    let first_delegator = expected_delegators[0].clone();
+    let cursor = first_delegator.delegator_addr().delegator();
+    assert_matches!(
+        get_delegators_result,
+        GetDelegatorsResult::Success { delegators, total, next_cursor }
+        if total == 2 && delegators == vec![first_delegator] && next_cursor == Some(cursor)
+    );
+
+    let get_delegators_request = GetDelegatorsRequest::new(
+        builder.get_post_state_hash(),
+        NON_FOUNDER_VALIDATOR_1_PK.clone(),
+        Some(cursor),
+        1,
+    );
+    let get_delegators_result = builder
+        .get_engine_state()
+        .get_delegators(CorrelationId::new(), get_delegators_request)
+        .expect("should get delegators");
+    let second_delegator = expected_delegators[1].clone();
+    assert_matches!(
+        get_delegators_result,
+        GetDelegatorsResult::Success { delegators, total, next_cursor }
+        if total == 2 && delegators == vec![second_delegator] && next_cursor.is_none()
+    );
+
+    let undelegate_request = ExecuteRequestBuilder::standard(
+        *BID_ACCOUNT_1_ADDR,
+        CONTRACT_UNDELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(DELEGATE_AMOUNT_1),
+            ARG_VALIDATOR => NON_FOUNDER_VALIDATOR_1_PK.clone(),
+            ARG_DELEGATOR => BID_ACCOUNT_1_PK.clone(),
+        },
+    )
+    .build();
+
+    builder.exec(undelegate_request).expect_success().commit();
+
+    // A fully undelegated delegator is gone from the bid.
+    let bids: Bids = builder.get_bids();
+    let bid = &bids[&NON_FOUNDER_VALIDATOR_1_PK];
+    assert_eq!(bid.delegator_count(), 1);
+    assert_eq!(*bid.delegated_amount(), U512::from(DELEGATE_AMOUNT_2));
+    let delegators = bid.delegators();
+    assert_eq!(delegators.len(), 1);
+    assert!(!delegators.contains_key(&BID_ACCOUNT_1_PK));
+    assert!(delegators.contains_key(&BID_ACCOUNT_2_PK));
+}
+
+#[ignore]
+#[test]
+fn should_apply_delegation_rate_increase_after_notice_period() {
+    const DELEGATION_RATE_CHANGE_NOTICE_ERAS: u64 = 3;
+    const LOWERED_DELEGATION_RATE: DelegationRate = 5;
+
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let account_1 = GenesisAccount::account(
+            BID_ACCOUNT_1_PK.clone(),
+            Motes::new(BID_ACCOUNT_1_BALANCE.into()),
+            None,
+        );
+        tmp.push(account_1);
+        tmp
+    };
+
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let engine_config = EngineConfigBuilder::default()
+        .with_delegation_rate_change_notice_eras(DELEGATION_RATE_CHANGE_NOTICE_ERAS)
+        .build();
+
+    let global_state = InMemoryGlobalState::empty().expect("should create global state");
+    let mut builder = InMemoryWasmTestBuilder::new(global_state, engine_config, None);
+
+    builder.run_genesis(&run_genesis_request);
+
+    let add_bid = |delegation_rate: DelegationRate| {
+        ExecuteRequestBuilder::standard(
+            *BID_ACCOUNT_1_ADDR,
+            CONTRACT_ADD_BID,
+            runtime_args! {
+                ARG_PUBLIC_KEY => BID_ACCOUNT_1_PK.clone(),
+                ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+                ARG_DELEGATION_RATE => delegation_rate,
+            },
+        )
+        .build()
+    };
+
+    builder
+        .exec(add_bid(ADD_BID_DELEGATION_RATE_1))
+        .expect_success()
+        .commit();
+    builder
+        .exec(add_bid(ADD_BID_DELEGATION_RATE_2))
+        .expect_success()
+        .commit();
+
+    let bid = builder.get_bids().remove(&*BID_ACCOUNT_1_PK).unwrap();
+    assert_eq!(*bid.delegation_rate(), ADD_BID_DELEGATION_RATE_1);
+    let pending_delegation_rate = *bid
+        .pending_delegation_rate()
+        .expect("should have pending delegation rate");
+    assert_eq!(
+        pending_delegation_rate.era_id(),
+        builder
+            .get_era()
+            .saturating_add(DELEGATION_RATE_CHANGE_NOTICE_ERAS)
+    );
+
+    let mut timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
+    while builder.get_era().successor() < pending_delegation_rate.era_id() {
+        builder.run_auction(timestamp_millis, Vec::new());
+        timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
+
+        let bid = builder.get_bids().remove(&*BID_ACCOUNT_1_PK).unwrap();
+        assert_eq!(*bid.delegation_rate(), ADD_BID_DELEGATION_RATE_1);
+    }
+
+    builder.run_auction(timestamp_millis, Vec::new());
+
+    let bid = builder.get_bids().remove(&*BID_ACCOUNT_1_PK).unwrap();
+    assert_eq!(*bid.delegation_rate(), ADD_BID_DELEGATION_RATE_2);
+    assert!(bid.pending_delegation_rate().is_none());
+    assert_eq!(bid.delegation_rate_history(), &[pending_delegation_rate]);
+
+    // Decreases apply immediately.
+    builder
+        .exec(add_bid(LOWERED_DELEGATION_RATE))
+        .expect_success()
+        .commit();
+
+    let bid = builder.get_bids().remove(&*BID_ACCOUNT_1_PK).unwrap();
+    assert_eq!(*bid.delegation_rate(), LOWERED_DELEGATION_RATE);
+    assert!(bid.pending_delegation_rate().is_none());
+    assert_eq!(
+        bid.delegation_rate_history().last().unwrap().era_id(),
+        builder.get_era()
+    );
+}
+
+#[ignore]
+#[test]
+fn should_delegate_with_vesting_schedule() {
+    let release_timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS + WEEK_MILLIS;
+
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let delegator_1 = GenesisAccount::account(
+            DELEGATOR_1.clone(),
+            Motes::new(DELEGATOR_1_BALANCE.into()),
+            None,
+        );
+        tmp.push(delegator_1);
+        tmp
+    };
+
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&run_genesis_request);
+
+    let transfer_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => *NON_FOUNDER_VALIDATOR_1_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let add_bid_request = ExecuteRequestBuilder::standard(
+        *NON_FOUNDER_VALIDATOR_1_ADDR,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            ARG_PUBLIC_KEY => NON_FOUNDER_VALIDATOR_1_PK.clone(),
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_1,
+        },
+    )
+    .build();
+
+    builder.exec(transfer_request).expect_success().commit();
+    builder.exec(add_bid_request).expect_success().commit();
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let delegate_with_vesting_request = |caller: AccountHash| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            caller,
+            auction_hash,
+            auction::METHOD_DELEGATE_WITH_VESTING,
+            runtime_args! {
+                ARG_DELEGATOR => DELEGATOR_1.clone(),
+                ARG_VALIDATOR => NON_FOUNDER_VALIDATOR_1_PK.clone(),
+                ARG_AMOUNT => U512::from(DELEGATOR_1_STAKE),
+                auction::ARG_RELEASE_TIMESTAMP_MILLIS => release_timestamp_millis,
+            },
+        )
+        .build()
+    };
+
+    // Stake can't be locked on behalf of another party.
+    builder
+        .exec(delegate_with_vesting_request(*DEFAULT_ACCOUNT_ADDR))
+        .expect_failure();
+    let error = builder.get_error().expect("must get error");
+    assert!(matches!(
+        error,
+        Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+        if auction_error == AuctionError::InvalidContext as u8));
+
+    builder
+        .exec(delegate_with_vesting_request(*DELEGATOR_1_ADDR))
+        .expect_success()
+        .commit();
+
+    let bids: Bids = builder.get_bids();
+    let delegator = bids[&NON_FOUNDER_VALIDATOR_1_PK]
+        .delegators()
+        .get(&*DELEGATOR_1)
+        .expect("should have delegator");
+    assert_eq!(*delegator.staked_amount(), U512::from(DELEGATOR_1_STAKE));
+    let vesting_schedule = delegator
+        .vesting_schedule()
+        .expect("should have vesting schedule");
+    assert_eq!(
+        vesting_schedule.initial_release_timestamp_millis(),
+        release_timestamp_millis
+    );
+    assert!(vesting_schedule.locked_amounts().is_none());
+
+    // A vesting schedule can't be attached to an existing delegation.
+    builder
+        .exec(delegate_with_vesting_request(*DELEGATOR_1_ADDR))
+        .expect_failure();
+    let error = builder.get_error().expect("must get error");
+    assert!(matches!(
+        error,
+        Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+        if auction_error == AuctionError::DelegationAlreadyExists as u8));
+
+    let undelegate_request = |amount: u64| {
+        ExecuteRequestBuilder::standard(
+            *DELEGATOR_1_ADDR,
+            CONTRACT_UNDELEGATE,
+            runtime_args! {
+                ARG_AMOUNT => U512::from(amount),
+                ARG_VALIDATOR => NON_FOUNDER_VALIDATOR_1_PK.clone(),
+                ARG_DELEGATOR => DELEGATOR_1.clone(),
+            },
+        )
+        .build()
+    };
+
+    let assert_funds_locked = |builder: &mut InMemoryWasmTestBuilder| {
+        let error = builder.get_error().expect("must get error");
+        assert!(matches!(
+            error,
+            Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+            if auction_error == AuctionError::DelegatorFundsLocked as u8));
+    };
+
+    // Before the release the whole stake is locked.
+    builder
+        .exec(undelegate_request(DEFAULT_MINIMUM_DELEGATION_AMOUNT))
+        .expect_failure();
+    assert_funds_locked(&mut builder);
+
+    let redelegate_request = ExecuteRequestBuilder::standard(
+        *DELEGATOR_1_ADDR,
+        CONTRACT_REDELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(DEFAULT_MINIMUM_DELEGATION_AMOUNT),
+            ARG_VALIDATOR => NON_FOUNDER_VALIDATOR_1_PK.clone(),
+            ARG_DELEGATOR => DELEGATOR_1.clone(),
+            ARG_NEW_VALIDATOR => DEFAULT_ACCOUNT_PUBLIC_KEY.clone(),
+        },
+    )
+    .build();
+    builder.exec(redelegate_request).expect_failure();
+    assert_funds_locked(&mut builder);
+
+    // The release starts, but most of the stake remains locked for the first week.
+    builder.run_auction(release_timestamp_millis, Vec::new());
+
+    let bids: Bids = builder.get_bids();
+    let delegator = bids[&NON_FOUNDER_VALIDATOR_1_PK]
+        .delegators()
+        .get(&*DELEGATOR_1)
+        .expect("should have delegator");
+    assert!(delegator
+        .vesting_schedule()
+        .expect("should have vesting schedule")
+        .locked_amounts()
+        .is_some());
+
+    builder
+        .exec(undelegate_request(DELEGATOR_1_STAKE))
+        .expect_failure();
+    assert_funds_locked(&mut builder);
+
+    // Once the vesting schedule period passed, all of the stake can be undelegated.
+    builder.run_auction(
+        release_timestamp_millis + CASPER_VESTING_SCHEDULE_PERIOD_MILLIS,
+        Vec::new(),
+    );
+
+    builder
+        .exec(undelegate_request(DELEGATOR_1_STAKE))
+        .expect_success()
+        .commit();
+
+    let bids: Bids = builder.get_bids();
+    assert!(!bids[&NON_FOUNDER_VALIDATOR_1_PK]
+        .delegators()
+        .contains_key(&*DELEGATOR_1));
+}
+
+#[ignore]
+#[test]
+fn should_require_proof_of_possession_for_bls_validator_keys() {
+    let bls_secret_key = SecretKey::bls_from_bytes([0x11; SecretKey::BLS_LENGTH]).unwrap();
+    let bls_public_key = PublicKey::from(&bls_secret_key);
+    let bls_account_hash = AccountHash::from(&bls_public_key);
+    let other_secret_key = SecretKey::bls_from_bytes([0x22; SecretKey::BLS_LENGTH]).unwrap();
+
+    let accounts = {
+        let mut tmp: Vec<GenesisAccount> = DEFAULT_ACCOUNTS.clone();
+        let bls_account = GenesisAccount::account(
+            bls_public_key.clone(),
+            Motes::new(DEFAULT_ACCOUNT_INITIAL_BALANCE.into()),
+            None,
+        );
+        tmp.push(bls_account);
+        tmp
+    };
+    let run_genesis_request = utils::create_run_genesis_request(accounts);
+
+    let add_bid_request = |builder: &InMemoryWasmTestBuilder, proof: Option<Signature>| {
+        let mut args = runtime_args! {
+            ARG_PUBLIC_KEY => bls_public_key.clone(),
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT_1),
+            ARG_DELEGATION_RATE => ADD_BID_DELEGATION_RATE_1,
+        };
+        if let Some(proof) = proof {
+            let proof = Bytes::from(proof.to_bytes().unwrap());
+            args.insert(auction::ARG_PROOF_OF_POSSESSION, Some(proof))
+                .unwrap();
+        }
+        ExecuteRequestBuilder::contract_call_by_hash(
+            bls_account_hash,
+            builder.get_auction_contract_hash(),
+            auction::METHOD_ADD_BID,
+            args,
+        )
+        .build()
+    };
+    let assert_auction_error = |builder: &InMemoryWasmTestBuilder, expected: AuctionError| {
+        let error = builder.get_error().expect("must get error");
+        assert!(matches!(
+            error,
+            Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+            if auction_error == expected as u8));
+    };
+
+    // BLS12-381 keys can't bid before they are activated.
+    let engine_config = EngineConfigBuilder::default()
+        .with_start_protocol_version_with_aggregate_finality_signatures(
+            ProtocolVersion::from_parts(2, 0, 0),
+        )
+        .build();
+    let mut builder = InMemoryWasmTestBuilder::new_with_config(engine_config);
+    builder.run_genesis(&run_genesis_request);
+    let proof = crypto::prove_possession(&bls_secret_key).unwrap();
+    let request = add_bid_request(&builder, Some(proof));
+    builder.exec(request).expect_failure();
+    assert_auction_error(&builder, AuctionError::BlsKeysNotAllowed);
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&run_genesis_request);
+
+    let request = add_bid_request(&builder, None);
+    builder.exec(request).expect_failure();
+    assert_auction_error(&builder, AuctionError::InvalidProofOfPossession);
+
+    let wrong_proof = crypto::prove_possession(&other_secret_key).unwrap();
+    let request = add_bid_request(&builder, Some(wrong_proof));
+    builder.exec(request).expect_failure();
+    assert_auction_error(&builder, AuctionError::InvalidProofOfPossession);
+
+    let request = add_bid_request(&builder, Some(proof));
+    builder.exec(request).expect_success().commit();
+    let bids: Bids = builder.get_bids();
+    assert_eq!(
+        *bids[&bls_public_key].staked_amount(),
+        U512::from(ADD_BID_AMOUNT_1)
+    );
+}
@@ -4,7 +4,8 @@ use num_rational::Ratio;
 
 use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder, DEFAULT_ACCOUNT_ADDR,
-    DEFAULT_MAX_ASSOCIATED_KEYS, DEFAULT_UNBONDING_DELAY, PRODUCTION_RUN_GENESIS_REQUEST,
+    DEFAULT_ACCOUNT_PUBLIC_KEY, DEFAULT_MAX_ASSOCIATED_KEYS, DEFAULT_UNBONDING_DELAY,
+    PRODUCTION_RUN_GENESIS_REQUEST,
 };
 
 use casper_execution_engine::{
@@ -35,16 +36,19 @@ use casper_types::{
     runtime_args,
     system::{
         auction::{
-            AUCTION_DELAY_KEY, LOCKED_FUNDS_PERIOD_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
+            Delegator, AUCTION_DELAY_KEY, LOCKED_FUNDS_PERIOD_KEY, UNBONDING_DELAY_KEY,
+            VALIDATOR_SLOTS_KEY,
         },
         mint::ROUND_SEIGNIORAGE_RATE_KEY,
     },
-    CLValue, EraId, ProtocolVersion, RuntimeArgs, StoredValue, U256, U512,
+    AccessRights, CLValue, DelegatorAddr, EraId, Key, ProtocolVersion, RuntimeArgs, StoredValue,
+    URef, U256, U512,
 };
 
 const PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion::V1_0_0;
 const DEFAULT_ACTIVATION_POINT: EraId = EraId::new(1);
 const ARG_ACCOUNT: &str = "account";
+const DELEGATED_AMOUNT: u64 = 1_000_000_000;
 
 fn get_upgraded_wasm_config() -> WasmConfig {
     let opcode_cost = OpcodeCosts {
@@ -656,6 +660,81 @@ fn should_apply_global_state_upgrade() {
     );
 }
 
+#[ignore]
+#[test]
+fn should_move_delegators_out_of_bids_on_upgrade() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let (validator_public_key, mut bid) = builder
+        .get_bids()
+        .into_iter()
+        .next()
+        .expect("should have a genesis bid");
+    let validator_account_hash = validator_public_key.to_account_hash();
+
+    // Write the bid the way it was stored before delegators got their own keys.
+    let delegator_public_key = DEFAULT_ACCOUNT_PUBLIC_KEY.clone();
+    let delegator = Delegator::unlocked(
+        delegator_public_key.clone(),
+        U512::from(DELEGATED_AMOUNT),
+        URef::new([42; 32], AccessRights::READ_ADD_WRITE),
+        validator_public_key.clone(),
+    );
+    bid.delegators_mut()
+        .insert(delegator_public_key.clone(), delegator.clone());
+
+    let mut update_map = BTreeMap::new();
+    update_map.insert(
+        Key::Bid(validator_account_hash),
+        StoredValue::Bid(Box::new(bid)),
+    );
+
+    let mut upgrade_request = {
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .with_global_state_update(update_map)
+            .build()
+    };
+
+    builder
+        .upgrade_with_upgrade_request_and_config(None, &mut upgrade_request)
+        .expect_upgrade_success();
+
+    let stored_bid = builder
+        .query(None, Key::Bid(validator_account_hash), &[])
+        .expect("should have bid");
+    assert!(stored_bid
+        .as_bid()
+        .expect("should be bid")
+        .delegators()
+        .is_empty());
+
+    let delegator_key = Key::Delegator(DelegatorAddr::new(
+        validator_account_hash,
+        delegator_public_key.to_account_hash(),
+    ));
+    let stored_delegator = builder
+        .query(None, delegator_key, &[])
+        .expect("should have delegator");
+    assert_eq!(stored_delegator.as_delegator(), Some(&delegator));
+
+    let bids = builder.get_bids();
+    assert_eq!(
+        bids[&validator_public_key]
+            .delegators()
+            .get(&delegator_public_key),
+        Some(&delegator)
+    );
+}
+
 #[ignore]
 #[test]
 fn should_increase_max_associated_keys_after_upgrade() {
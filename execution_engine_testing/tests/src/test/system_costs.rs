@@ -979,6 +979,10 @@ fn should_verify_wasm_add_bid_wasm_cost_is_not_recursive() {
         blake2b: HostFunction::fixed(0),
         random_bytes: HostFunction::fixed(0),
         enable_contract_version: HostFunction::fixed(0),
+        get_block_info: HostFunction::fixed(0),
+        call_contract_view: HostFunction::fixed(0),
+        add_contract_version_with_storage_schema: HostFunction::fixed(0),
+        schedule_invocation: HostFunction::fixed(0),
     };
 
     let new_wasm_config = WasmConfig::new(
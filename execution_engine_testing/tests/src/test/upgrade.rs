@@ -2,9 +2,15 @@ use casper_engine_test_support::{
     ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
+use casper_execution_engine::core::{engine_state, execution};
 use casper_types::{
-    contracts::{ContractVersion, CONTRACT_INITIAL_VERSION},
-    runtime_args, CLValue, ContractPackageHash, RuntimeArgs, StoredValue,
+    contracts::{
+        ContractVersion, StorageSchemaVersion, CONTRACT_INITIAL_VERSION,
+        MIGRATE_ARG_PREVIOUS_CONTRACT_HASH, MIGRATE_ARG_PREVIOUS_STORAGE_SCHEMA_VERSION,
+        MIGRATE_ENTRY_POINT_NAME,
+    },
+    runtime_args, ApiError, CLValue, ContractHash, ContractPackageHash, ContractVersionKey, Key,
+    RuntimeArgs, StoredValue,
 };
 
 const DO_NOTHING_STORED_CONTRACT_NAME: &str = "do_nothing_stored";
@@ -31,6 +37,16 @@ const ARG_CONTRACT_PACKAGE: &str = "contract_package";
 const ARG_VERSION: &str = "version";
 const ARG_NEW_PURSE_NAME: &str = "new_purse_name";
 const ARG_IS_LOCKED: &str = "is_locked";
+const CONTRACT_MIGRATION_STORED: &str = "contract_migration_stored.wasm";
+const CONTRACT_MIGRATION_UPGRADER: &str = "contract_migration_upgrader.wasm";
+const CONTRACT_MIGRATION_HASH_KEY_NAME: &str = "contract_migration_hash";
+const CONTRACT_MIGRATION_PACKAGE_HASH_KEY_NAME: &str = "contract_migration_package_hash";
+const ARG_INITIAL_VALUE: &str = "initial_value";
+const ARG_STORAGE_SCHEMA_VERSION: &str = "storage_schema_version";
+const VALUE_KEY_NAME: &str = "value";
+const PREVIOUS_CONTRACT_KEY_NAME: &str = "previous_contract";
+const PREVIOUS_STORAGE_SCHEMA_VERSION_KEY_NAME: &str = "previous_storage_schema_version";
+const MIGRATION_NOTHING_TO_MIGRATE_ERROR: u16 = 1;
 
 /// Performs define and execution of versioned contracts, calling them directly from hash
 #[ignore]
@@ -642,3 +658,235 @@ fn should_fail_upgrade_for_locked_contract() {
         assert!(builder.exec(exec_request).is_error());
     }
 }
+
+fn install_contract_migration(builder: &mut InMemoryWasmTestBuilder, initial_value: u64) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_MIGRATION_STORED,
+        runtime_args! {
+            ARG_INITIAL_VALUE => initial_value,
+        },
+    )
+    .build();
+
+    builder.exec(exec_request).expect_success().commit();
+}
+
+fn upgrade_contract_migration(
+    builder: &mut InMemoryWasmTestBuilder,
+    storage_schema_version: StorageSchemaVersion,
+) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_MIGRATION_UPGRADER,
+        runtime_args! {
+            ARG_STORAGE_SCHEMA_VERSION => storage_schema_version,
+        },
+    )
+    .build();
+
+    builder.exec(exec_request).commit();
+}
+
+fn contract_migration_named_key(builder: &InMemoryWasmTestBuilder, name: &str) -> Key {
+    *builder
+        .get_expected_account(*DEFAULT_ACCOUNT_ADDR)
+        .named_keys()
+        .get(name)
+        .expect("should have named key")
+}
+
+fn contract_migration_value(builder: &InMemoryWasmTestBuilder, contract_hash: ContractHash) -> u64 {
+    builder
+        .query(None, contract_hash.into(), &[VALUE_KEY_NAME.to_string()])
+        .expect("should have value")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be u64")
+}
+
+#[ignore]
+#[test]
+fn should_run_migration_when_adding_contract_version() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    install_contract_migration(&mut builder, 21);
+
+    let initial_contract_hash: ContractHash =
+        contract_migration_named_key(&builder, CONTRACT_MIGRATION_HASH_KEY_NAME)
+            .into_hash()
+            .expect("should be hash")
+            .into();
+
+    upgrade_contract_migration(&mut builder, 2);
+    builder.expect_success();
+
+    let upgraded_contract_hash: ContractHash =
+        contract_migration_named_key(&builder, CONTRACT_MIGRATION_HASH_KEY_NAME)
+            .into_hash()
+            .expect("should be hash")
+            .into();
+    assert_ne!(upgraded_contract_hash, initial_contract_hash);
+    assert_eq!(
+        contract_migration_value(&builder, upgraded_contract_hash),
+        42,
+        "migration should have rewritten the state of the previous version"
+    );
+
+    let upgraded_contract = builder
+        .get_contract(upgraded_contract_hash)
+        .expect("should have contract");
+    assert_eq!(
+        upgraded_contract
+            .named_keys()
+            .get(PREVIOUS_CONTRACT_KEY_NAME),
+        Some(&Key::from(initial_contract_hash)),
+        "migration should receive the previous contract hash"
+    );
+    let previous_storage_schema_version: Option<StorageSchemaVersion> = builder
+        .query(
+            None,
+            upgraded_contract_hash.into(),
+            &[PREVIOUS_STORAGE_SCHEMA_VERSION_KEY_NAME.to_string()],
+        )
+        .expect("should have previous storage schema version")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be Option<u32>");
+    assert_eq!(previous_storage_schema_version, None);
+
+    let contract_package_hash: ContractPackageHash =
+        contract_migration_named_key(&builder, CONTRACT_MIGRATION_PACKAGE_HASH_KEY_NAME)
+            .into_hash()
+            .expect("should be hash")
+            .into();
+    let contract_package = builder
+        .get_contract_package(contract_package_hash)
+        .expect("should have package");
+    let major = upgraded_contract.protocol_version().value().major;
+    assert_eq!(
+        contract_package.storage_schema_version(ContractVersionKey::new(major, INITIAL_VERSION)),
+        None
+    );
+    assert_eq!(
+        contract_package.storage_schema_version(ContractVersionKey::new(major, UPGRADED_VERSION)),
+        Some(2)
+    );
+
+    // A further upgrade sees the storage schema version recorded for the current version.
+    upgrade_contract_migration(&mut builder, 3);
+    builder.expect_success();
+
+    let latest_contract_hash: ContractHash =
+        contract_migration_named_key(&builder, CONTRACT_MIGRATION_HASH_KEY_NAME)
+            .into_hash()
+            .expect("should be hash")
+            .into();
+    assert_eq!(contract_migration_value(&builder, latest_contract_hash), 84);
+    let previous_storage_schema_version: Option<StorageSchemaVersion> = builder
+        .query(
+            None,
+            latest_contract_hash.into(),
+            &[PREVIOUS_STORAGE_SCHEMA_VERSION_KEY_NAME.to_string()],
+        )
+        .expect("should have previous storage schema version")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should be Option<u32>");
+    assert_eq!(previous_storage_schema_version, Some(2));
+}
+
+#[ignore]
+#[test]
+fn should_not_add_contract_version_when_migration_fails() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    // The migration reverts when there is nothing to migrate.
+    install_contract_migration(&mut builder, 0);
+
+    let contract_package_hash: ContractPackageHash =
+        contract_migration_named_key(&builder, CONTRACT_MIGRATION_PACKAGE_HASH_KEY_NAME)
+            .into_hash()
+            .expect("should be hash")
+            .into();
+    let contract_package_before = builder
+        .get_contract_package(contract_package_hash)
+        .expect("should have package");
+
+    upgrade_contract_migration(&mut builder, 2);
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::Revert(ApiError::User(
+                MIGRATION_NOTHING_TO_MIGRATE_ERROR
+            )))
+        ),
+        "{:?}",
+        error
+    );
+
+    let contract_package_after = builder
+        .get_contract_package(contract_package_hash)
+        .expect("should have package");
+    assert_eq!(
+        contract_package_after, contract_package_before,
+        "failed migration should revert the version addition"
+    );
+    assert_eq!(contract_package_after.versions().len(), 1);
+    assert!(contract_package_after.storage_schema_versions().is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_not_allow_calling_migration_directly() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&PRODUCTION_RUN_GENESIS_REQUEST);
+
+    install_contract_migration(&mut builder, 21);
+    upgrade_contract_migration(&mut builder, 2);
+    builder.expect_success();
+
+    let contract_hash: ContractHash =
+        contract_migration_named_key(&builder, CONTRACT_MIGRATION_HASH_KEY_NAME)
+            .into_hash()
+            .expect("should be hash")
+            .into();
+
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        MIGRATE_ENTRY_POINT_NAME,
+        runtime_args! {
+            MIGRATE_ARG_PREVIOUS_CONTRACT_HASH => Option::<ContractHash>::None,
+            MIGRATE_ARG_PREVIOUS_STORAGE_SCHEMA_VERSION => Option::<StorageSchemaVersion>::None,
+        },
+    )
+    .build();
+    builder.exec(exec_request).commit();
+
+    let error = builder.get_error().expect("should have error");
+    assert!(
+        matches!(
+            error,
+            engine_state::Error::Exec(execution::Error::MigrationNotCallable(hash))
+                if hash == contract_hash
+        ),
+        "{:?}",
+        error
+    );
+    assert_eq!(
+        contract_migration_value(&builder, contract_hash),
+        42,
+        "migration should have run only once"
+    );
+}
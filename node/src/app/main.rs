@@ -13,7 +13,7 @@ use structopt::StructOpt;
 use tokio::runtime::Builder;
 use tracing::info;
 
-use casper_node::{cli::Cli, MAX_THREAD_COUNT};
+use casper_node::{cli::Cli, utils::take_key_passphrase_from_environment, MAX_THREAD_COUNT};
 
 /// Aborting panic hook.
 ///
@@ -42,6 +42,10 @@ fn main() -> anyhow::Result<()> {
     // The exit code is determined in a block to ensure that all acquired resources are dropped
     // before exiting with the given exit code.
     let exit_code = {
+        // Read the secret key passphrase while still single-threaded, since doing so clears it from
+        // the environment.
+        take_key_passphrase_from_environment();
+
         let num_cpus = num_cpus::get();
         let runtime = Builder::new_multi_thread()
             .enable_all()
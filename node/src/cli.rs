@@ -3,6 +3,8 @@
 //! Most configuration is done via config files (see [`config`](../config/index.html) for details).
 
 pub mod arglang;
+mod replay_block;
+mod validate_chainspec;
 
 use std::{
     alloc::System,
@@ -13,6 +15,7 @@ use std::{
 };
 
 use anyhow::{self, bail, Context};
+use casper_hashing::Digest;
 use prometheus::Registry;
 use regex::Regex;
 use stats_alloc::{StatsAlloc, INSTRUMENTED_SYSTEM};
@@ -23,9 +26,9 @@ use tracing::info;
 use crate::{
     components::network::Identity as NetworkIdentity,
     logging,
-    reactor::{main_reactor, Runner},
+    reactor::{main_reactor, ConfigLoader, Runner},
     setup_signal_hooks,
-    types::{Chainspec, ChainspecRawBytes, ExitCode},
+    types::{BlockHash, Chainspec, ChainspecRawBytes, ExitCode},
     utils::{Loadable, WithDir},
 };
 
@@ -78,6 +81,45 @@ pub enum Cli {
         #[structopt(long)]
         new_config: PathBuf,
     },
+    /// Validate a chainspec before deploying it.
+    ///
+    /// Runs all checks performed by the node on startup. If given, compares it against the
+    /// chainspec of the previous protocol version and checks its global state update against the
+    /// global state with the given state root hash.
+    ValidateChainspec {
+        /// Path to the directory containing the chainspec.toml to validate.
+        chainspec: PathBuf,
+        /// Path to the directory containing the chainspec.toml of the previous protocol version.
+        #[structopt(long)]
+        previous: Option<PathBuf>,
+        /// State root hash of the global state the upgrade will be applied to.
+        #[structopt(long, requires = "data-dir", parse(try_from_str = Digest::from_hex))]
+        state_root_hash: Option<Digest>,
+        /// Path to the directory containing the global state database (data.lmdb).
+        #[structopt(long, requires = "state-root-hash")]
+        data_dir: Option<PathBuf>,
+    },
+    /// Re-execute a historical block and compare the outcome with the stored one.
+    ///
+    /// The block is executed on top of the global state of its parent. Its state root hash and the
+    /// execution results of all its deploys are compared with the ones stored, reporting the first
+    /// divergent transform of each deploy. Storage and global state are opened read-only and left
+    /// unmodified.
+    ReplayBlock {
+        /// Path to the directory containing the chainspec.toml the block was executed under.
+        chainspec: PathBuf,
+        /// Path to the storage directory, as set in `storage.path` in the configuration file.
+        #[structopt(long)]
+        storage_dir: PathBuf,
+        /// Path to the directory containing the global state database (data.lmdb).
+        ///
+        /// Defaults to the network's subdirectory of the storage directory.
+        #[structopt(long)]
+        trie_dir: Option<PathBuf>,
+        /// Hash of the block to re-execute.
+        #[structopt(parse(try_from_str = parse_block_hash))]
+        block_hash: BlockHash,
+    },
 }
 
 #[derive(Debug)]
@@ -141,6 +183,11 @@ impl FromStr for ConfigExt {
     }
 }
 
+/// Parses a hex-encoded block hash.
+fn parse_block_hash(input: &str) -> Result<BlockHash, casper_hashing::Error> {
+    Digest::from_hex(input).map(BlockHash::new)
+}
+
 impl Cli {
     /// Executes selected CLI command.
     pub async fn run(self) -> anyhow::Result<i32> {
@@ -149,7 +196,7 @@ impl Cli {
                 // Setup UNIX signal hooks.
                 setup_signal_hooks();
 
-                let mut validator_config = Self::init(&config, config_ext)?;
+                let mut validator_config = Self::init(&config, &config_ext)?;
 
                 // We use a `ChaCha20Rng` for the production node. For one, we want to completely
                 // eliminate any chance of runtime failures, regardless of how small (these
@@ -173,6 +220,7 @@ impl Cli {
                 }
 
                 validator_config.value_mut().ensure_valid(&chainspec);
+                let chainspec = Arc::new(chainspec);
 
                 let network_identity = NetworkIdentity::from_config(WithDir::new(
                     validator_config.dir(),
@@ -182,7 +230,7 @@ impl Cli {
 
                 let mut main_runner = Runner::<main_reactor::MainReactor>::with_metrics(
                     validator_config,
-                    Arc::new(chainspec),
+                    Arc::clone(&chainspec),
                     Arc::new(chainspec_raw_bytes),
                     network_identity,
                     &mut rng,
@@ -190,14 +238,23 @@ impl Cli {
                 )
                 .await?;
 
+                // Reloads re-read the same file and command line overrides the node was started
+                // with.
+                main_runner.set_config_loader(ConfigLoader::new(move || {
+                    let mut validator_config = Self::load(&config, &config_ext)?;
+                    validator_config.value_mut().ensure_valid(&chainspec);
+                    Ok(validator_config)
+                }));
+
                 let exit_code = main_runner.run(&mut rng).await;
+                logging::shutdown_span_export();
                 Ok(exit_code as i32)
             }
             Cli::MigrateConfig {
                 old_config,
                 new_config,
             } => {
-                let new_config = Self::init(&new_config, vec![])?;
+                let new_config = Self::init(&new_config, &[])?;
 
                 let old_root = old_config
                     .parent()
@@ -219,7 +276,7 @@ impl Cli {
                 old_config,
                 new_config,
             } => {
-                let new_config = Self::init(&new_config, vec![])?;
+                let new_config = Self::init(&new_config, &[])?;
 
                 let old_root = old_config
                     .parent()
@@ -237,13 +294,55 @@ impl Cli {
                 )?;
                 Ok(ExitCode::Success as i32)
             }
+            Cli::ValidateChainspec {
+                chainspec,
+                previous,
+                state_root_hash,
+                data_dir,
+            } => {
+                logging::init_with_config(&Default::default())?;
+                validate_chainspec::validate_chainspec(
+                    &chainspec,
+                    previous.as_deref(),
+                    data_dir.as_deref().zip(state_root_hash),
+                )?;
+                Ok(ExitCode::Success as i32)
+            }
+            Cli::ReplayBlock {
+                chainspec,
+                storage_dir,
+                trie_dir,
+                block_hash,
+            } => {
+                logging::init_with_config(&Default::default())?;
+                replay_block::replay_block(
+                    &chainspec,
+                    &storage_dir,
+                    trie_dir.as_deref(),
+                    block_hash,
+                )?;
+                Ok(ExitCode::Success as i32)
+            }
         }
     }
 
     /// Parses the config file for the current version of casper-node, and initializes logging.
     fn init(
         config: &Path,
-        config_ext: Vec<ConfigExt>,
+        config_ext: &[ConfigExt],
+    ) -> anyhow::Result<WithDir<main_reactor::Config>> {
+        let main_config = Self::load(config, config_ext)?;
+        let mut logging_config = main_config.value().logging.clone();
+        logging_config.resolve_paths(main_config.dir());
+        logging::init_with_config(&logging_config)?;
+
+        Ok(main_config)
+    }
+
+    /// Parses the config file for the current version of casper-node.
+    fn load(
+        config: &Path,
+        config_ext: &[ConfigExt],
     ) -> anyhow::Result<WithDir<main_reactor::Config>> {
         // Determine the parent directory of the configuration file, if any.
         // Otherwise, we default to `/`.
@@ -268,7 +367,6 @@ impl Cli {
 
         // Create main config, including any overridden values.
         let main_config: main_reactor::Config = config_table.try_into()?;
-        logging::init_with_config(&main_config.logging)?;
 
         Ok(WithDir::new(root, main_config))
     }
@@ -0,0 +1,322 @@
+//! Re-execution of historical blocks.
+//!
+//! A stored block is executed again on top of the global state of its parent, and the resulting
+//! state root hash and execution results are compared with the ones stored when it was originally
+//! executed.
+//!
+//! Both storage and global state are opened read-only, and the post-state hash is computed in
+//! memory, so replaying a block never modifies the node's data.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    path::Path,
+};
+
+use anyhow::{anyhow, bail, Context};
+use prometheus::Registry;
+use tracing::warn;
+
+use casper_types::{ExecutionEffect, ExecutionResult, TransformEntry};
+
+use crate::{
+    components::{
+        contract_runtime::{self, ContractRuntime},
+        storage::{self, Storage},
+    },
+    types::{BlockHash, Chainspec, ChainspecRawBytes},
+    utils::{Loadable, WithDir},
+};
+
+/// File name of the global state database within the trie directory.
+const GLOBAL_STATE_DB_FILENAME: &str = "data.lmdb";
+
+/// Re-executes the block with the given hash and compares the outcome with the stored one.
+///
+/// Returns an error if the block could not be re-executed or the outcome diverges.
+pub(super) fn replay_block(
+    chainspec_dir: &Path,
+    storage_dir: &Path,
+    trie_dir: Option<&Path>,
+    block_hash: BlockHash,
+) -> anyhow::Result<()> {
+    let (chainspec, _): (Chainspec, ChainspecRawBytes) = Loadable::from_path(chainspec_dir)
+        .with_context(|| format!("could not load chainspec from {}", chainspec_dir.display()))?;
+    let protocol_version = chainspec.protocol_version();
+    let activation_era = chainspec.protocol_config.activation_point.era_id();
+
+    let storage_config = storage::Config {
+        path: ".".into(),
+        ..Default::default()
+    };
+    let storage = Storage::open_read_only(
+        &WithDir::new(storage_dir, storage_config),
+        activation_era,
+        &chainspec.network_config.name,
+        chainspec.deploy_config.max_ttl.into(),
+        chainspec.core_config.recent_era_count(),
+        chainspec
+            .core_config
+            .start_protocol_version_with_aggregate_finality_signatures,
+    )
+    .context("could not open storage")?;
+
+    let block = storage
+        .read_block(&block_hash)?
+        .ok_or_else(|| anyhow!("block {} not found in storage", block_hash))?;
+    let header = block.header();
+    if header.protocol_version() != protocol_version {
+        bail!(
+            "block {} was executed under protocol version {}, but the chainspec is for {}",
+            block_hash,
+            header.protocol_version(),
+            protocol_version
+        );
+    }
+    if header.height() == 0 {
+        bail!("the genesis block can not be replayed");
+    }
+    let parent = storage
+        .read_block_header(header.parent_hash())?
+        .ok_or_else(|| anyhow!("parent block {} not found in storage", header.parent_hash()))?;
+    if parent.protocol_version() != protocol_version {
+        // The block was executed on top of the upgraded global state, not the parent's.
+        bail!("the first block after an upgrade can not be replayed");
+    }
+    let (finalized_block, deploys) = storage
+        .make_executable_block(&block_hash)?
+        .ok_or_else(|| anyhow!("deploys or approvals of block {} are missing", block_hash))?;
+    let stored_results: BTreeMap<_, _> = storage
+        .read_execution_results(&block_hash)?
+        .ok_or_else(|| anyhow!("execution results of block {} are missing", block_hash))?
+        .into_iter()
+        .map(|(deploy_hash, _, execution_result)| (deploy_hash, execution_result))
+        .collect();
+
+    let key_block_height_for_activation_point = storage
+        .read_switch_block_by_era_id(activation_era.predecessor().unwrap_or_default())?
+        .map(|key_block| key_block.height())
+        .unwrap_or_else(|| {
+            warn!("key block height for current activation point unknown");
+            0
+        });
+
+    // Report a missing database explicitly rather than as a failure to open it.
+    let trie_dir = trie_dir.unwrap_or_else(|| storage.root_path());
+    if !trie_dir.join(GLOBAL_STATE_DB_FILENAME).is_file() {
+        bail!("no global state database found in {}", trie_dir.display());
+    }
+    let max_delegators_per_validator = match chainspec.core_config.max_delegators_per_validator {
+        0 => None,
+        max => Some(max),
+    };
+    let contract_runtime = ContractRuntime::new(
+        protocol_version,
+        trie_dir,
+        &contract_runtime::Config::default(),
+        chainspec.wasm_config,
+        chainspec.system_costs_config,
+        chainspec.core_config.max_associated_keys,
+        chainspec.core_config.max_runtime_call_stack_height,
+        chainspec.core_config.minimum_delegation_amount,
+        chainspec.protocol_config.activation_point,
+        chainspec.core_config.prune_batch_size,
+        chainspec.core_config.strict_argument_checking,
+        chainspec.core_config.vesting_schedule_period.millis(),
+        max_delegators_per_validator,
+        &Registry::new(),
+        chainspec.core_config.administrators.clone(),
+        chainspec.core_config.allow_auction_bids,
+        chainspec.core_config.delegation_rate_change_notice_eras,
+        chainspec.core_config.allow_unrestricted_transfers,
+        chainspec.core_config.refund_handling,
+        chainspec.core_config.fee_handling,
+        chainspec
+            .core_config
+            .start_protocol_version_with_deploy_sequence_numbers,
+        chainspec
+            .core_config
+            .start_protocol_version_with_contract_migrations,
+        chainspec
+            .core_config
+            .start_protocol_version_with_scheduled_invocations,
+        chainspec
+            .core_config
+            .start_protocol_version_with_aggregate_finality_signatures,
+        chainspec.network_config.name.clone(),
+        true,
+    )
+    .context("could not open global state")?;
+
+    println!(
+        "replaying block {} at height {} with {} deploys",
+        block_hash,
+        header.height(),
+        deploys.len()
+    );
+    let (replayed_state_root_hash, replayed_results) = contract_runtime
+        .reexecute_block(
+            &parent,
+            finalized_block,
+            deploys,
+            key_block_height_for_activation_point,
+        )
+        .context("could not execute block")?;
+
+    let mut divergent = false;
+    for (deploy_hash, _, replayed_result) in &replayed_results {
+        match stored_results.get(deploy_hash) {
+            None => {
+                divergent = true;
+                println!("deploy {}: no stored execution result", deploy_hash);
+            }
+            Some(stored_result) => {
+                if let Some(divergence) = first_divergence(stored_result, replayed_result) {
+                    divergent = true;
+                    println!("deploy {}: {}", deploy_hash, divergence);
+                }
+            }
+        }
+    }
+
+    let stored_state_root_hash = *header.state_root_hash();
+    if replayed_state_root_hash == stored_state_root_hash {
+        println!("state root hash {} matches", stored_state_root_hash);
+    } else {
+        divergent = true;
+        println!(
+            "state root hash diverges: stored {}, replayed {}",
+            stored_state_root_hash, replayed_state_root_hash
+        );
+    }
+
+    if divergent {
+        bail!("replayed block {} diverges from the stored one", block_hash);
+    }
+    println!("execution results of all deploys match");
+    Ok(())
+}
+
+/// Describes the first difference between a stored and a replayed execution result, if any.
+///
+/// Transforms are compared first, in the order they were applied.
+fn first_divergence(stored: &ExecutionResult, replayed: &ExecutionResult) -> Option<String> {
+    let stored_transforms = &effect(stored).transforms;
+    let replayed_transforms = &effect(replayed).transforms;
+    let common_len = stored_transforms.len().min(replayed_transforms.len());
+    let mut divergent_index = stored_transforms
+        .iter()
+        .zip(replayed_transforms)
+        .position(|(stored, replayed)| stored != replayed);
+    if divergent_index.is_none() && stored_transforms.len() != replayed_transforms.len() {
+        // One of the results has additional transforms at the end.
+        divergent_index = Some(common_len);
+    }
+    if let Some(index) = divergent_index {
+        return Some(format!(
+            "transform {} diverges: stored {}, replayed {}",
+            index,
+            DisplayTransform(stored_transforms.get(index)),
+            DisplayTransform(replayed_transforms.get(index))
+        ));
+    }
+
+    if stored != replayed {
+        return Some(format!(
+            "transforms match, but results diverge: stored {:?}, replayed {:?}",
+            stored, replayed
+        ));
+    }
+    None
+}
+
+/// Returns the effect of executing the deploy, regardless of its outcome.
+fn effect(execution_result: &ExecutionResult) -> &ExecutionEffect {
+    match execution_result {
+        ExecutionResult::Failure { effect, .. } | ExecutionResult::Success { effect, .. } => effect,
+    }
+}
+
+/// Displays a possibly absent transform entry.
+struct DisplayTransform<'a>(Option<&'a TransformEntry>);
+
+impl<'a> Display for DisplayTransform<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            None => f.write_str("(none)"),
+            Some(entry) => write!(f, "{:?} on {}", entry.transform, entry.key),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{Transform, U512};
+
+    use super::*;
+
+    fn result(transforms: &[(&str, Transform)], cost: u64) -> ExecutionResult {
+        ExecutionResult::Success {
+            effect: ExecutionEffect::new(
+                transforms
+                    .iter()
+                    .map(|(key, transform)| TransformEntry {
+                        key: key.to_string(),
+                        transform: transform.clone(),
+                    })
+                    .collect(),
+            ),
+            transfers: vec![],
+            cost: U512::from(cost),
+            fee_breakdown: None,
+        }
+    }
+
+    #[test]
+    fn should_find_no_divergence_in_equal_results() {
+        let stored = result(
+            &[("a", Transform::Identity), ("b", Transform::AddUInt64(1))],
+            1,
+        );
+        assert_eq!(first_divergence(&stored, &stored.clone()), None);
+    }
+
+    #[test]
+    fn should_find_first_divergent_transform() {
+        let stored = result(
+            &[
+                ("a", Transform::Identity),
+                ("b", Transform::AddUInt64(1)),
+                ("c", Transform::AddUInt64(1)),
+            ],
+            1,
+        );
+        let replayed = result(
+            &[
+                ("a", Transform::Identity),
+                ("b", Transform::AddUInt64(2)),
+                ("c", Transform::AddUInt64(2)),
+            ],
+            1,
+        );
+        assert_eq!(
+            first_divergence(&stored, &replayed).unwrap(),
+            "transform 1 diverges: stored AddUInt64(1) on b, replayed AddUInt64(2) on b"
+        );
+
+        let truncated = result(&[("a", Transform::Identity)], 1);
+        assert_eq!(
+            first_divergence(&stored, &truncated).unwrap(),
+            "transform 1 diverges: stored AddUInt64(1) on b, replayed (none)"
+        );
+    }
+
+    #[test]
+    fn should_find_divergence_outside_transforms() {
+        let stored = result(&[("a", Transform::Identity)], 1);
+        let replayed = result(&[("a", Transform::Identity)], 2);
+        assert!(first_divergence(&stored, &replayed)
+            .unwrap()
+            .starts_with("transforms match, but results diverge"));
+    }
+}
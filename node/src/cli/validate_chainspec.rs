@@ -0,0 +1,352 @@
+//! Offline validation of a chainspec, as prepared for an upgrade.
+//!
+//! Besides the checks performed by the node on startup, the chainspec can be compared against the
+//! chainspec of the protocol version it upgrades, and its global state update can be checked
+//! against the global state it will be applied to.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    path::Path,
+    sync::Arc,
+};
+
+use anyhow::{anyhow, bail, Context};
+use serde_json::Value;
+
+use casper_execution_engine::{
+    shared::newtypes::CorrelationId,
+    storage::{
+        global_state::{lmdb::LmdbGlobalState, StateProvider, StateReader},
+        transaction_source::lmdb::LmdbEnvironment,
+        trie_store::lmdb::LmdbTrieStore,
+    },
+};
+use casper_hashing::Digest;
+
+use crate::{
+    components::contract_runtime,
+    reactor::changed_settings,
+    types::{ActivationPoint, Chainspec},
+};
+
+/// File name of the global state database within the data directory.
+const GLOBAL_STATE_DB_FILENAME: &str = "data.lmdb";
+
+/// Settings containing more elements than this are summarized instead of printed in full.
+const MAX_PRINTED_LIST_LEN: usize = 8;
+
+/// A single change between two chainspecs.
+#[derive(Debug, PartialEq)]
+pub(super) struct ChainspecChange {
+    /// Path of the changed setting, e.g. `core.auction_delay`.
+    pub(super) path: String,
+    /// The previous value, if the setting existed before.
+    pub(super) old: Option<Value>,
+    /// The new value, if the setting still exists.
+    pub(super) new: Option<Value>,
+}
+
+impl Display for ChainspecChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            DisplayValue(self.old.as_ref()),
+            DisplayValue(self.new.as_ref())
+        )
+    }
+}
+
+/// Displays a possibly absent JSON value, summarizing long lists.
+struct DisplayValue<'a>(Option<&'a Value>);
+
+impl<'a> Display for DisplayValue<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            None => f.write_str("(unset)"),
+            Some(Value::Array(items)) if items.len() > MAX_PRINTED_LIST_LEN => {
+                write!(f, "({} entries)", items.len())
+            }
+            Some(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Returns all changes between the two chainspecs, ordered by path.
+///
+/// The global state update is not included, as it only applies to a single upgrade.
+pub(super) fn chainspec_diff(
+    previous: &Chainspec,
+    chainspec: &Chainspec,
+) -> anyhow::Result<Vec<ChainspecChange>> {
+    let old = serde_json::to_value(previous)?;
+    let new = serde_json::to_value(chainspec)?;
+    Ok(changed_settings(previous, chainspec)?
+        .into_iter()
+        .filter(|path| !path.starts_with("protocol.global_state_update"))
+        .map(|path| ChainspecChange {
+            old: lookup(&old, &path).cloned(),
+            new: lookup(&new, &path).cloned(),
+            path,
+        })
+        .collect())
+}
+
+/// Looks up the value at the given `.`-separated path.
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |value, key| value.as_object()?.get(key))
+}
+
+/// Returns the reasons why `chainspec` is not a valid upgrade of `previous`, if any.
+pub(super) fn upgrade_errors(previous: &Chainspec, chainspec: &Chainspec) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    if chainspec.network_config.name != previous.network_config.name {
+        errors.push(format!(
+            "network name changed from {} to {}",
+            previous.network_config.name, chainspec.network_config.name
+        ));
+    }
+
+    if chainspec.protocol_version() <= previous.protocol_version() {
+        errors.push(format!(
+            "protocol version {} is not greater than previous protocol version {}",
+            chainspec.protocol_version(),
+            previous.protocol_version()
+        ));
+    }
+
+    match (
+        previous.protocol_config.activation_point,
+        chainspec.protocol_config.activation_point,
+    ) {
+        (_, ActivationPoint::Genesis(_)) => {
+            errors.push("an upgrade must be activated at an era, not at genesis".to_string())
+        }
+        (ActivationPoint::EraId(previous_era), ActivationPoint::EraId(era))
+            if era <= previous_era =>
+        {
+            errors.push(format!(
+                "activation point {} is not after previous activation point {}",
+                era, previous_era
+            ))
+        }
+        _ => (),
+    }
+
+    errors
+}
+
+/// Checks the global state update of `chainspec` against the global state with the given root.
+///
+/// Returns a description of every entry which would be added or change the type of the stored
+/// value.
+fn check_global_state_update(
+    chainspec: &Chainspec,
+    data_dir: &Path,
+    state_root_hash: Digest,
+) -> anyhow::Result<Vec<String>> {
+    let update = chainspec
+        .protocol_config
+        .get_update_mapping()
+        .map_err(|error| anyhow!("could not parse global state update: {}", error))?;
+
+    // The global state is only ever read here: it is opened read-only so that neither the database
+    // nor the empty root is written, which also means the database must already exist.
+    if !data_dir.join(GLOBAL_STATE_DB_FILENAME).is_file() {
+        bail!("no global state database found in {}", data_dir.display());
+    }
+    let config = contract_runtime::Config::default();
+    let environment = Arc::new(
+        LmdbEnvironment::new_read_only(
+            data_dir,
+            config.max_global_state_size_or_default(),
+            config.max_readers_or_default(),
+        )
+        .context("could not open global state")?,
+    );
+    let trie_store =
+        Arc::new(LmdbTrieStore::open(&environment, None).context("could not open trie store")?);
+    let global_state =
+        LmdbGlobalState::open(environment, trie_store).context("could not open global state")?;
+    let reader = global_state
+        .checkout(state_root_hash)?
+        .ok_or_else(|| anyhow!("state root hash {} not found", state_root_hash))?;
+
+    let correlation_id = CorrelationId::new();
+    let mut notes = Vec::new();
+    for (key, value) in update {
+        match reader.read(correlation_id, &key)? {
+            None => notes.push(format!(
+                "{} is not present yet and will be added as {}",
+                key.to_formatted_string(),
+                value.type_name()
+            )),
+            Some(existing) if existing.type_name() != value.type_name() => notes.push(format!(
+                "{} changes type from {} to {}",
+                key.to_formatted_string(),
+                existing.type_name(),
+                value.type_name()
+            )),
+            Some(_) => (),
+        }
+    }
+    Ok(notes)
+}
+
+/// Validates the chainspec in `chainspec_dir`, printing a report.
+///
+/// Returns an error if the chainspec is invalid.
+pub(super) fn validate_chainspec(
+    chainspec_dir: &Path,
+    previous_dir: Option<&Path>,
+    global_state: Option<(&Path, Digest)>,
+) -> anyhow::Result<()> {
+    let chainspec = load(chainspec_dir)?;
+    println!(
+        "chainspec for protocol version {}, {}, hash {}",
+        chainspec.protocol_version(),
+        chainspec.protocol_config.activation_point,
+        chainspec.hash()
+    );
+
+    // Reasons for failing are logged by the validation itself.
+    let mut errors = Vec::new();
+    if !chainspec.is_valid() {
+        errors.push("chainspec is invalid, see log output for details".to_string());
+    }
+
+    if let Some(global_state_update) = chainspec.protocol_config.global_state_update.as_ref() {
+        println!(
+            "global state update with {} entries{}",
+            global_state_update.entries.len(),
+            if global_state_update.validators.is_some() {
+                ", replacing the validator set"
+            } else {
+                ""
+            }
+        );
+        match global_state {
+            Some((data_dir, state_root_hash)) => {
+                match check_global_state_update(&chainspec, data_dir, state_root_hash) {
+                    Ok(notes) => {
+                        println!(
+                            "global state update checked against state root hash {}",
+                            state_root_hash
+                        );
+                        for note in notes {
+                            println!("  {}", note);
+                        }
+                    }
+                    Err(error) => errors.push(format!("{:#}", error)),
+                }
+            }
+            None => {
+                if let Err(error) = chainspec.protocol_config.get_update_mapping() {
+                    errors.push(format!("could not parse global state update: {}", error));
+                }
+            }
+        }
+    } else if global_state.is_some() {
+        println!("no global state update to check");
+    }
+
+    if let Some(previous_dir) = previous_dir {
+        let previous = load(previous_dir)?;
+        let changes = chainspec_diff(&previous, &chainspec)?;
+        println!(
+            "{} changes from protocol version {}:",
+            changes.len(),
+            previous.protocol_version()
+        );
+        for change in changes {
+            println!("  {}", change);
+        }
+        errors.extend(upgrade_errors(&previous, &chainspec));
+    }
+
+    if errors.is_empty() {
+        println!("chainspec is valid");
+        return Ok(());
+    }
+    for error in &errors {
+        println!("error: {}", error);
+    }
+    bail!("chainspec validation failed with {} errors", errors.len())
+}
+
+/// Loads the chainspec from the given directory.
+fn load(dir: &Path) -> anyhow::Result<Chainspec> {
+    let (chainspec, _) = crate::utils::Loadable::from_path(dir)
+        .with_context(|| format!("could not load chainspec from {}", dir.display()))?;
+    Ok(chainspec)
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{bytesrepr, testing::TestRng, EraId, ProtocolVersion, Timestamp};
+
+    use super::*;
+
+    fn random_pair(rng: &mut TestRng) -> (Chainspec, Chainspec) {
+        let mut previous = Chainspec::random(rng);
+        previous.protocol_config.version = ProtocolVersion::from_parts(1, 0, 0);
+        previous.protocol_config.activation_point = ActivationPoint::EraId(EraId::new(10));
+        previous.protocol_config.global_state_update = None;
+        let chainspec: Chainspec =
+            bytesrepr::deserialize(bytesrepr::serialize(&previous).unwrap()).unwrap();
+        (previous, chainspec)
+    }
+
+    #[test]
+    fn should_diff_chainspecs() {
+        let mut rng = TestRng::new();
+        let (previous, mut chainspec) = random_pair(&mut rng);
+        assert!(chainspec_diff(&previous, &chainspec).unwrap().is_empty());
+
+        chainspec.core_config.auction_delay = previous.core_config.auction_delay + 1;
+        chainspec.protocol_config.activation_point = ActivationPoint::EraId(EraId::new(20));
+
+        let changes = chainspec_diff(&previous, &chainspec).unwrap();
+        assert_eq!(
+            changes,
+            vec![
+                ChainspecChange {
+                    path: "core.auction_delay".to_string(),
+                    old: Some(previous.core_config.auction_delay.into()),
+                    new: Some(chainspec.core_config.auction_delay.into()),
+                },
+                ChainspecChange {
+                    path: "protocol.activation_point".to_string(),
+                    old: Some(10.into()),
+                    new: Some(20.into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_accept_valid_upgrade() {
+        let mut rng = TestRng::new();
+        let (previous, mut chainspec) = random_pair(&mut rng);
+        chainspec.protocol_config.version = ProtocolVersion::from_parts(1, 1, 0);
+        chainspec.protocol_config.activation_point = ActivationPoint::EraId(EraId::new(11));
+
+        assert!(upgrade_errors(&previous, &chainspec).is_empty());
+    }
+
+    #[test]
+    fn should_reject_invalid_upgrades() {
+        let mut rng = TestRng::new();
+        let (previous, mut chainspec) = random_pair(&mut rng);
+        // Neither the protocol version nor the activation point were increased.
+        assert_eq!(upgrade_errors(&previous, &chainspec).len(), 2);
+
+        chainspec.protocol_config.version = ProtocolVersion::from_parts(1, 1, 0);
+        chainspec.protocol_config.activation_point = ActivationPoint::Genesis(Timestamp::now());
+        chainspec.network_config.name = format!("{}-other", previous.network_config.name);
+        assert_eq!(upgrade_errors(&previous, &chainspec).len(), 2);
+    }
+}
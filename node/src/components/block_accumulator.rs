@@ -1,5 +1,6 @@
 mod block_acceptor;
 mod config;
+mod debug;
 mod error;
 mod event;
 mod leap_instruction;
@@ -52,6 +53,7 @@ use crate::{
 };
 
 pub(crate) use config::Config;
+pub(crate) use debug::BlockAccumulatorDump;
 pub(crate) use error::Error;
 pub(crate) use event::Event;
 pub(crate) use sync_identifier::SyncIdentifier;
@@ -510,6 +512,18 @@ impl BlockAccumulator {
             .map(|acceptor| acceptor.peers().iter().cloned().collect())
     }
 
+    fn dump(&self) -> BlockAccumulatorDump {
+        BlockAccumulatorDump {
+            local_tip: self.local_tip,
+            last_progress: self.last_progress,
+            block_acceptors: self
+                .block_acceptors
+                .values()
+                .map(BlockAcceptor::dump)
+                .collect(),
+        }
+    }
+
     fn is_stale(&mut self) -> bool {
         // we expect to be receiving gossiped blocks from other nodes
         // if we haven't received any messages describing higher blocks
@@ -794,6 +808,9 @@ impl<REv: ReactorEvent> Component<REv> for BlockAccumulator {
                 block_hash,
                 responder,
             }) => responder.respond(self.get_peers(block_hash)).ignore(),
+            Event::Request(BlockAccumulatorRequest::Dump { responder }) => {
+                responder.respond(self.dump()).ignore()
+            }
             Event::RegisterPeer {
                 block_hash,
                 era_id,
@@ -8,7 +8,10 @@ use casper_types::{EraId, PublicKey, Timestamp};
 
 use crate::{
     components::{
-        block_accumulator::error::{Bogusness, Error as AcceptorError, InvalidGossipError},
+        block_accumulator::{
+            debug::BlockAcceptorDump,
+            error::{Bogusness, Error as AcceptorError, InvalidGossipError},
+        },
         fetcher::{EmptyValidationMetadata, FetchItem},
     },
     types::{
@@ -377,6 +380,25 @@ impl BlockAcceptor {
         self.our_signature.as_ref()
     }
 
+    pub(super) fn dump(&self) -> BlockAcceptorDump {
+        BlockAcceptorDump {
+            block_hash: self.block_hash,
+            block_height: self.block_height(),
+            era_id: self.era_id(),
+            has_sufficient_finality: self.has_sufficient_finality(),
+            has_our_signature: self.our_signature.is_some(),
+            last_progress: self.last_progress,
+            peers: self.peers.iter().copied().collect(),
+            signatures: self
+                .signatures
+                .iter()
+                .map(|(public_key, (_, senders))| {
+                    (public_key.clone(), senders.iter().copied().collect())
+                })
+                .collect(),
+        }
+    }
+
     pub(super) fn set_our_signature(&mut self, signature: FinalitySignature) {
         self.our_signature = Some(signature);
     }
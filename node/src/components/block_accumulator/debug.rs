@@ -0,0 +1,80 @@
+//! Data types used solely for dumping of block accumulator data via the diagnostics port.
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::Serialize;
+
+use casper_types::{EraId, PublicKey, Timestamp};
+
+use super::local_tip_identifier::LocalTipIdentifier;
+use crate::{
+    types::{BlockHash, NodeId},
+    utils::{opt_display::OptDisplay, DisplayIter},
+};
+
+/// Debug dump of the block accumulator used for serialization.
+#[derive(Debug, Serialize)]
+pub(crate) struct BlockAccumulatorDump {
+    /// The subjective local tip of the chain, if set.
+    pub(super) local_tip: Option<LocalTipIdentifier>,
+    /// The time at which the accumulator last saw progress.
+    pub(super) last_progress: Timestamp,
+    /// All current block acceptors, ordered by block hash.
+    pub(super) block_acceptors: Vec<BlockAcceptorDump>,
+}
+
+/// Debug dump of a single block acceptor.
+#[derive(Debug, Serialize)]
+pub(super) struct BlockAcceptorDump {
+    /// The hash of the block being accepted.
+    pub(super) block_hash: BlockHash,
+    /// The height of the block, if the block itself has been received.
+    pub(super) block_height: Option<u64>,
+    /// The era of the block, if known from the block or any of its signatures.
+    pub(super) era_id: Option<EraId>,
+    /// Whether the block has been found to have sufficient finality.
+    pub(super) has_sufficient_finality: bool,
+    /// Whether this node has created its own signature for the block.
+    pub(super) has_our_signature: bool,
+    /// The time at which the acceptor last saw progress.
+    pub(super) last_progress: Timestamp,
+    /// Peers known to hold the block.
+    pub(super) peers: Vec<NodeId>,
+    /// The signers of all received finality signatures, along with the peers that sent them.
+    pub(super) signatures: Vec<(PublicKey, Vec<NodeId>)>,
+}
+
+impl Display for BlockAccumulatorDump {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let local_tip = self
+            .local_tip
+            .map(|local_tip| format!("{} in {}", local_tip.height, local_tip.era_id));
+        writeln!(
+            f,
+            "local tip: {}, last progress: {}",
+            OptDisplay::new(local_tip, "unset"),
+            self.last_progress
+        )?;
+
+        writeln!(f, "{} block acceptors:", self.block_acceptors.len())?;
+        for acceptor in &self.block_acceptors {
+            writeln!(
+                f,
+                "  {} (height {}, {}): sufficient finality: {}, our signature: {}, last progress: {}",
+                acceptor.block_hash,
+                OptDisplay::new(acceptor.block_height, "unknown"),
+                OptDisplay::new(acceptor.era_id, "unknown era"),
+                acceptor.has_sufficient_finality,
+                acceptor.has_our_signature,
+                acceptor.last_progress
+            )?;
+            writeln!(f, "    peers: {}", DisplayIter::new(&acceptor.peers))?;
+            writeln!(f, "    {} signatures:", acceptor.signatures.len())?;
+            for (public_key, senders) in &acceptor.signatures {
+                writeln!(f, "      {} from {}", public_key, DisplayIter::new(senders))?;
+            }
+        }
+
+        Ok(())
+    }
+}
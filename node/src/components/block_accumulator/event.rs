@@ -51,6 +51,9 @@ impl Display for Event {
                     block_hash
                 )
             }
+            Event::Request(BlockAccumulatorRequest::Dump { .. }) => {
+                write!(f, "block accumulator dump request")
+            }
             Event::RegisterPeer {
                 block_hash, sender, ..
             } => {
@@ -85,7 +88,7 @@ impl Display for Event {
                     meta_block.block.hash(),
                     maybe_block_signatures
                         .as_ref()
-                        .map(|sigs| sigs.proofs.len())
+                        .map(|sigs| sigs.signers().len())
                         .unwrap_or_default()
                 )
             }
@@ -98,7 +101,7 @@ impl Display for Event {
                     "stored {} finality signatures",
                     maybe_block_signatures
                         .as_ref()
-                        .map(|sigs| sigs.proofs.len())
+                        .map(|sigs| sigs.signers().len())
                         .unwrap_or_default()
                 )
             }
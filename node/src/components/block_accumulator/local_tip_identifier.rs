@@ -2,8 +2,9 @@ use std::cmp::Ordering;
 
 use casper_types::EraId;
 use datasize::DataSize;
+use serde::Serialize;
 
-#[derive(Clone, Copy, DataSize, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, DataSize, Debug, Eq, PartialEq, Serialize)]
 pub(super) struct LocalTipIdentifier {
     pub(super) height: u64,
     pub(super) era_id: EraId,
@@ -183,6 +183,7 @@ impl Reactor for MockReactor {
             "test",
             chainspec.deploy_config.max_ttl.into(),
             chainspec.core_config.recent_era_count(),
+            ProtocolVersion::V1_0_0,
             Some(registry),
             false,
         )
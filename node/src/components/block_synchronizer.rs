@@ -3,6 +3,7 @@ mod block_acquisition_action;
 mod block_builder;
 mod block_synchronizer_progress;
 mod config;
+mod debug;
 mod deploy_acquisition;
 mod error;
 mod event;
@@ -63,6 +64,7 @@ use crate::{
 use block_builder::BlockBuilder;
 pub(crate) use block_synchronizer_progress::BlockSynchronizerProgress;
 pub(crate) use config::Config;
+pub(crate) use debug::BlockSynchronizerDump;
 pub(crate) use error::BlockAcquisitionError;
 pub(crate) use event::Event;
 use execution_results_acquisition::ExecutionResultsAcquisition;
@@ -236,6 +238,14 @@ impl BlockSynchronizer {
         })
     }
 
+    /// Replaces the configuration.
+    ///
+    /// Settings only used when constructing the global state synchronizer or new block builders
+    /// do not affect syncing already in progress.
+    pub(crate) fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+
     /// Returns the progress being made on the historical syncing.
     pub(crate) fn historical_progress(&mut self) -> BlockSynchronizerProgress {
         match &self.historical {
@@ -1298,6 +1308,19 @@ impl BlockSynchronizer {
         )
     }
 
+    fn dump(&self, rng: &mut NodeRng) -> BlockSynchronizerDump {
+        BlockSynchronizerDump {
+            forward: self
+                .forward
+                .as_ref()
+                .map(|builder| builder.dump(rng, self.max_simultaneous_peers)),
+            historical: self
+                .historical
+                .as_ref()
+                .map(|builder| builder.dump(rng, self.max_simultaneous_peers)),
+        }
+    }
+
     fn get_builder(
         &mut self,
         block_hash: BlockHash,
@@ -1420,6 +1443,10 @@ impl<REv: ReactorEvent> Component<REv> for BlockSynchronizer {
                     BlockSynchronizerRequest::Status { responder } => {
                         responder.respond(self.status()).ignore()
                     }
+                    // the diagnostics port dumps the full state of the builders
+                    BlockSynchronizerRequest::Dump { responder } => {
+                        responder.respond(self.dump(rng)).ignore()
+                    }
                     // prompts for what data (if any) is needed next to acquire block(s) being
                     // sync'd
                     BlockSynchronizerRequest::NeedNext => self.need_next(effect_builder, rng),
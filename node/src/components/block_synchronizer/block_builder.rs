@@ -17,6 +17,7 @@ use casper_types::{EraId, ProtocolVersion, PublicKey, TimeDiff, Timestamp};
 use super::{
     block_acquisition::{Acceptance, BlockAcquisitionState, RegisterExecResultsOutcome},
     block_acquisition_action::BlockAcquisitionAction,
+    debug::BlockBuilderDump,
     execution_results_acquisition::{self, ExecutionResultsChecksum},
     peer_list::{PeerList, PeersStatus},
     signature_acquisition::SignatureAcquisition,
@@ -72,7 +73,7 @@ impl ExecutionProgress {
     }
 }
 
-#[derive(DataSize, Debug)]
+#[derive(Clone, DataSize, Debug)]
 pub(super) struct BlockBuilder {
     // imputed
     block_hash: BlockHash,
@@ -448,6 +449,27 @@ impl BlockBuilder {
         }
     }
 
+    /// Returns a debug dump of the builder, including what it would need next if asked now.
+    ///
+    /// The next action is determined on a copy of the builder, leaving its state untouched.
+    pub(super) fn dump(&self, rng: &mut NodeRng, max_simultaneous_peers: u8) -> BlockBuilderDump {
+        let need_next = self
+            .clone()
+            .block_acquisition_action(rng, max_simultaneous_peers)
+            .need_next();
+        BlockBuilderDump {
+            block_hash: self.block_hash,
+            block_height: self.block_height(),
+            era_id: self.era_id,
+            should_fetch_execution_state: self.should_fetch_execution_state,
+            acquisition_state: self.acquisition_state.to_string(),
+            need_next: need_next.to_string(),
+            latch_count: self.latch_count(),
+            last_progress: self.last_progress,
+            peers: self.peer_list.peers_with_quality(),
+        }
+    }
+
     pub(super) fn register_era_validator_weights(&mut self, validator_matrix: &ValidatorMatrix) {
         if self.validator_weights.is_some() || self.era_id.is_none() {
             return;
@@ -4,7 +4,7 @@ use tracing::warn;
 
 use casper_types::{TimeDiff, Timestamp};
 
-#[derive(Debug, Default, DataSize)]
+#[derive(Clone, Debug, Default, DataSize)]
 pub(super) struct Latch {
     #[data_size(skip)]
     latch: u8,
@@ -0,0 +1,85 @@
+//! Data types used solely for dumping of block synchronizer data via the diagnostics port.
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::Serialize;
+
+use casper_types::{EraId, Timestamp};
+
+use super::peer_list::PeerQuality;
+use crate::{
+    types::{BlockHash, NodeId},
+    utils::opt_display::OptDisplay,
+};
+
+/// Debug dump of the block synchronizer used for serialization.
+#[derive(Debug, Serialize)]
+pub(crate) struct BlockSynchronizerDump {
+    /// The builder for the block being synced forward, if any.
+    pub(super) forward: Option<BlockBuilderDump>,
+    /// The builder for the block being synced historically, if any.
+    pub(super) historical: Option<BlockBuilderDump>,
+}
+
+/// Debug dump of a single block builder.
+#[derive(Debug, Serialize)]
+pub(super) struct BlockBuilderDump {
+    /// The hash of the block being acquired.
+    pub(super) block_hash: BlockHash,
+    /// The height of the block, if known.
+    pub(super) block_height: Option<u64>,
+    /// The era of the block, if known.
+    pub(super) era_id: Option<EraId>,
+    /// Whether global state and execution results are acquired as well.
+    pub(super) should_fetch_execution_state: bool,
+    /// The current `BlockAcquisitionState`.
+    pub(super) acquisition_state: String,
+    /// What the builder would need next if asked now.
+    pub(super) need_next: String,
+    /// The number of outstanding responses the builder is latched on.
+    pub(super) latch_count: u8,
+    /// The time at which the builder last saw progress.
+    pub(super) last_progress: Timestamp,
+    /// All peers known to the builder, along with their quality.
+    pub(super) peers: Vec<(NodeId, PeerQuality)>,
+}
+
+impl Display for BlockSynchronizerDump {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "forward: {}",
+            OptDisplay::new(self.forward.as_ref(), "not syncing")
+        )?;
+        writeln!(
+            f,
+            "historical: {}",
+            OptDisplay::new(self.historical.as_ref(), "not syncing")
+        )
+    }
+}
+
+impl Display for BlockBuilderDump {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} (height {}, {}), fetch execution state: {}",
+            self.block_hash,
+            OptDisplay::new(self.block_height, "unknown"),
+            OptDisplay::new(self.era_id, "unknown era"),
+            self.should_fetch_execution_state
+        )?;
+        writeln!(f, "  acquisition state: {}", self.acquisition_state)?;
+        writeln!(f, "  {}", self.need_next)?;
+        writeln!(
+            f,
+            "  latch count: {}, last progress: {}",
+            self.latch_count, self.last_progress
+        )?;
+        write!(f, "  {} peers:", self.peers.len())?;
+        for (node_id, quality) in &self.peers {
+            write!(f, "\n    {} ({:?})", node_id, quality)?;
+        }
+        Ok(())
+    }
+}
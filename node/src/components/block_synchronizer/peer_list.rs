@@ -6,13 +6,14 @@ use std::collections::{btree_map::Entry, BTreeMap};
 use datasize::DataSize;
 use itertools::Itertools;
 use rand::seq::IteratorRandom;
+use serde::Serialize;
 use tracing::debug;
 
 use crate::{types::NodeId, NodeRng};
 use casper_types::{TimeDiff, Timestamp};
 
-#[derive(Copy, Clone, PartialEq, Eq, DataSize, Debug, Default)]
-enum PeerQuality {
+#[derive(Copy, Clone, PartialEq, Eq, DataSize, Debug, Default, Serialize)]
+pub(super) enum PeerQuality {
     #[default]
     Unknown,
     Unreliable,
@@ -64,6 +65,13 @@ impl PeerList {
             .collect_vec()
     }
 
+    pub(super) fn peers_with_quality(&self) -> Vec<(NodeId, PeerQuality)> {
+        self.peer_list
+            .iter()
+            .map(|(node_id, pq)| (*node_id, *pq))
+            .collect_vec()
+    }
+
     pub(super) fn flush(&mut self) {
         self.peer_list.clear();
     }
@@ -6,6 +6,11 @@
 //! When multiple requests are made to validate the same block payload, they will eagerly return
 //! true if valid, but only fail if all sources have been exhausted. This is only relevant when
 //! calling for validation of the same proposed block multiple times at the same time.
+//!
+//! It also checks that the dependencies of every deploy are executed before it: either earlier in
+//! the same block, in one of the block's ancestors, or in a stored block no older than `max_ttl`
+//! before it.  This is only enforced from the chainspec's
+//! `start_protocol_version_with_deploy_dependencies` on.
 
 mod config;
 mod event;
@@ -13,12 +18,15 @@ mod state;
 #[cfg(test)]
 mod tests;
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::Arc,
+};
 
 use datasize::DataSize;
 use tracing::{debug, error, warn};
 
-use casper_types::Timestamp;
+use casper_types::{TimeDiff, Timestamp};
 
 use crate::{
     components::{
@@ -31,8 +39,8 @@ use crate::{
         EffectBuilder, EffectExt, Effects, Responder,
     },
     types::{
-        ApprovalsHash, Chainspec, Deploy, DeployHashWithApprovals, DeployId, DeployOrTransferHash,
-        NodeId,
+        ApprovalsHash, Chainspec, Deploy, DeployHash, DeployHashWithApprovals, DeployId,
+        DeployOrTransferHash, NodeId,
     },
     NodeRng,
 };
@@ -219,7 +227,7 @@ impl BlockValidator {
         result: FetchResult<Deploy>,
     ) -> Effects<Event>
     where
-        REv: From<Event> + From<FetcherRequest<Deploy>> + Send,
+        REv: From<Event> + From<FetcherRequest<Deploy>> + From<StorageRequest> + Send,
     {
         match &result {
             Ok(FetchedData::FromPeer { peer, .. }) => {
@@ -262,13 +270,22 @@ impl BlockValidator {
                     }
                 };
 
+                let max_ttl = self.chainspec.deploy_config.max_ttl;
                 let mut effects = Effects::new();
-                for state in self.validation_states.values_mut() {
+                for (block, state) in self.validation_states.iter_mut() {
                     let responders = state.try_add_deploy_footprint(&dt_hash, &deploy_footprint);
                     if !responders.is_empty() {
                         let is_valid = matches!(state, BlockValidationState::Valid(_));
                         effects.extend(respond(is_valid, responders));
                     }
+                    if let Some(dependencies) = state.take_dependencies_to_check() {
+                        effects.extend(check_dependencies(
+                            effect_builder,
+                            block.clone(),
+                            dependencies,
+                            max_ttl,
+                        ));
+                    }
                 }
                 effects
             }
@@ -355,6 +372,25 @@ where
             Event::DeployFetched { dt_hash, result } => {
                 self.handle_deploy_fetched(effect_builder, dt_hash, result)
             }
+            Event::DependenciesChecked {
+                block,
+                unexecuted_dependencies,
+            } => {
+                let all_executed = unexecuted_dependencies.is_empty();
+                if !all_executed {
+                    warn!(
+                        %block,
+                        ?unexecuted_dependencies,
+                        "dependencies not executed - block invalid"
+                    );
+                }
+                match self.validation_states.get_mut(&block) {
+                    Some(state) => {
+                        respond(all_executed, state.try_resolve_dependencies(all_executed))
+                    }
+                    None => Effects::new(),
+                }
+            }
         }
     }
 
@@ -382,6 +418,41 @@ where
         .collect()
 }
 
+/// Checks that the given dependencies were executed in stored blocks no older than `max_ttl` before
+/// the proposed block.  Nodes keep at least `max_ttl` of history for replay protection, so all
+/// validators reach the same result regardless of how much older history they hold.
+fn check_dependencies<REv>(
+    effect_builder: EffectBuilder<REv>,
+    block: ProposedBlock<ClContext>,
+    dependencies: BTreeSet<DeployHash>,
+    max_ttl: TimeDiff,
+) -> Effects<Event>
+where
+    REv: From<StorageRequest> + Send,
+{
+    async move {
+        let executed = effect_builder
+            .get_executed_deploys(dependencies.iter().copied().collect())
+            .await;
+        let window_start = block.timestamp().saturating_sub(max_ttl);
+        let unexecuted_dependencies = dependencies
+            .into_iter()
+            .filter(|dependency| {
+                executed
+                    .get(dependency)
+                    .map_or(true, |executed_at| *executed_at < window_start)
+            })
+            .collect();
+        (block, unexecuted_dependencies)
+    }
+    .event(
+        |(block, unexecuted_dependencies)| Event::DependenciesChecked {
+            block,
+            unexecuted_dependencies,
+        },
+    )
+}
+
 fn respond(
     is_valid: bool,
     responders: impl IntoIterator<Item = Responder<bool>>,
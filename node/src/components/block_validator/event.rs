@@ -1,9 +1,14 @@
+use std::collections::BTreeSet;
+
 use derive_more::{Display, From};
 
 use crate::{
-    components::fetcher::FetchResult,
+    components::{
+        consensus::{ClContext, ProposedBlock},
+        fetcher::FetchResult,
+    },
     effect::requests::BlockValidationRequest,
-    types::{Deploy, DeployOrTransferHash},
+    types::{Deploy, DeployHash, DeployOrTransferHash},
 };
 
 #[derive(Debug, From, Display)]
@@ -16,4 +21,10 @@ pub(crate) enum Event {
         dt_hash: DeployOrTransferHash,
         result: FetchResult<Deploy>,
     },
+
+    #[display(fmt = "dependencies of {} checked", block)]
+    DependenciesChecked {
+        block: ProposedBlock<ClContext>,
+        unexecuted_dependencies: BTreeSet<DeployHash>,
+    },
 }
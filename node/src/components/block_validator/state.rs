@@ -1,5 +1,5 @@
 use std::{
-    collections::{hash_map::Entry, BTreeSet, HashMap},
+    collections::{hash_map::Entry, BTreeSet, HashMap, HashSet},
     fmt::{self, Debug, Display, Formatter},
     iter, mem,
 };
@@ -9,14 +9,12 @@ use tracing::{debug, error, warn};
 
 use casper_types::Timestamp;
 
-#[cfg(test)]
-use crate::types::DeployHash;
 use crate::{
     components::consensus::{ClContext, ProposedBlock},
     effect::Responder,
     types::{
         appendable_block::AppendableBlock, Approval, ApprovalsHash, Chainspec, DeployFootprint,
-        DeployHashWithApprovals, DeployOrTransferHash, NodeId,
+        DeployHash, DeployHashWithApprovals, DeployOrTransferHash, NodeId,
     },
 };
 
@@ -92,6 +90,26 @@ pub(super) enum BlockValidationState {
         holders: HashMap<NodeId, HolderState>,
         /// A list of responders that are awaiting an answer.
         responders: Vec<Responder<bool>>,
+        /// The position of each deploy in the block's execution order: all non-transfer deploys
+        /// are executed before all transfers.
+        positions: HashMap<DeployHash, usize>,
+        /// The deploys included in the ancestors of the proposed block in the current era.
+        ancestor_deploys: HashSet<DeployHash>,
+        /// Dependencies of the block's deploys which are neither in the block nor in its
+        /// ancestors, and hence need to have been executed in an earlier block.
+        unresolved_dependencies: BTreeSet<DeployHash>,
+        /// Whether the protocol version enforces deploy dependencies.
+        dependencies_enforced: bool,
+    },
+    /// All deploys are present and valid, but we still need to check that the given dependencies
+    /// have been executed in earlier blocks.
+    CheckingDependencies {
+        /// The timestamp of the proposed block.
+        timestamp: Timestamp,
+        /// The dependencies to be checked.  This is empty once the check has been requested.
+        dependencies: BTreeSet<DeployHash>,
+        /// A list of responders that are awaiting an answer.
+        responders: Vec<Responder<bool>>,
     },
     /// The proposed block with the given timestamp is valid.
     Valid(Timestamp),
@@ -134,6 +152,20 @@ impl BlockValidationState {
 
         let appendable_block = AppendableBlock::new(chainspec.deploy_config, block.timestamp());
 
+        let positions = block
+            .value()
+            .deploy_and_transfer_hashes()
+            .enumerate()
+            .map(|(position, deploy_hash)| (*deploy_hash, position))
+            .collect();
+        let ancestor_deploys = block
+            .context()
+            .ancestor_values()
+            .iter()
+            .flat_map(|payload| payload.deploy_and_transfer_hashes())
+            .copied()
+            .collect();
+
         let mut missing_deploys = HashMap::new();
         let deploys_iter = block.deploys().iter().map(|dhwa| {
             let dt_hash = DeployOrTransferHash::Deploy(*dhwa.deploy_hash());
@@ -165,19 +197,27 @@ impl BlockValidationState {
             missing_deploys,
             holders: iter::once((sender, HolderState::Unasked)).collect(),
             responders: vec![responder],
+            positions,
+            ancestor_deploys,
+            unresolved_dependencies: BTreeSet::new(),
+            dependencies_enforced: chainspec.protocol_version()
+                >= chainspec
+                    .core_config
+                    .start_protocol_version_with_deploy_dependencies,
         };
 
         (state, None)
     }
 
-    /// Adds the given responder to the collection if the current state is `InProgress` and returns
-    /// `Added`.
+    /// Adds the given responder to the collection if the current state is `InProgress` or
+    /// `CheckingDependencies` and returns `Added`.
     ///
-    /// If the state is not `InProgress`, `ValidationCompleted` is returned with the responder and
-    /// the value which should be provided to the responder.
+    /// If the state is `Valid` or `Invalid`, `ValidationCompleted` is returned with the responder
+    /// and the value which should be provided to the responder.
     pub(super) fn add_responder(&mut self, responder: Responder<bool>) -> AddResponderResult {
         match self {
-            BlockValidationState::InProgress { responders, .. } => {
+            BlockValidationState::InProgress { responders, .. }
+            | BlockValidationState::CheckingDependencies { responders, .. } => {
                 responders.push(responder);
                 AddResponderResult::Added
             }
@@ -212,6 +252,9 @@ impl BlockValidationState {
                     entry.insert(HolderState::Unasked);
                 }
             },
+            BlockValidationState::CheckingDependencies { .. } => {
+                debug!(state = %self, "all deploys already fetched - not adding holder");
+            }
             BlockValidationState::Valid(_) | BlockValidationState::Invalid(_) => {
                 error!(state = %self, "unexpected state when adding holder");
             }
@@ -234,6 +277,7 @@ impl BlockValidationState {
     ///     one `Unasked` holder, returns `Start`
     ///   * if `InProgress` and any holder `Asked`, returns `Ongoing`
     ///   * if `InProgress` and all holders `Failed`, returns `Unable`
+    ///   * if `CheckingDependencies`, returns `Ongoing`
     ///   * if `Valid` or `Invalid`, returns `ValidationSucceeded` or `ValidationFailed`
     ///     respectively
     pub(super) fn start_fetching(&mut self) -> MaybeStartFetching {
@@ -274,6 +318,7 @@ impl BlockValidationState {
                     missing_deploys,
                 }
             }
+            BlockValidationState::CheckingDependencies { .. } => MaybeStartFetching::Ongoing,
             BlockValidationState::Valid(_) => MaybeStartFetching::ValidationSucceeded,
             BlockValidationState::Invalid(_) => MaybeStartFetching::ValidationFailed,
         }
@@ -281,7 +326,10 @@ impl BlockValidationState {
 
     pub(super) fn take_responders(&mut self) -> Vec<Responder<bool>> {
         match self {
-            BlockValidationState::InProgress { responders, .. } => mem::take(responders),
+            BlockValidationState::InProgress { responders, .. }
+            | BlockValidationState::CheckingDependencies { responders, .. } => {
+                mem::take(responders)
+            }
             BlockValidationState::Valid(_) | BlockValidationState::Invalid(_) => vec![],
         }
     }
@@ -298,6 +346,10 @@ impl BlockValidationState {
                 appendable_block,
                 missing_deploys,
                 responders,
+                positions,
+                ancestor_deploys,
+                unresolved_dependencies,
+                dependencies_enforced,
                 ..
             } => {
                 let approvals_info = match missing_deploys.remove(dt_hash) {
@@ -319,6 +371,29 @@ impl BlockValidationState {
                 };
                 match add_result {
                     Ok(()) => {
+                        let dependencies_result = if *dependencies_enforced {
+                            check_dependencies(
+                                dt_hash,
+                                footprint,
+                                positions,
+                                ancestor_deploys,
+                                unresolved_dependencies,
+                            )
+                        } else {
+                            Ok(())
+                        };
+                        if let Err(dependency) = dependencies_result {
+                            warn!(
+                                %dt_hash,
+                                %dependency,
+                                "dependency not executed before deploy - block invalid"
+                            );
+                            let new_state =
+                                BlockValidationState::Invalid(appendable_block.timestamp());
+                            let responders = mem::take(responders);
+                            *self = new_state;
+                            return responders;
+                        }
                         if !missing_deploys.is_empty() {
                             // The appendable block is still valid, but we still have missing
                             // deploys - nothing further to do here.
@@ -329,6 +404,19 @@ impl BlockValidationState {
                             );
                             return vec![];
                         }
+                        if !unresolved_dependencies.is_empty() {
+                            debug!(
+                                block_timestamp = %appendable_block.timestamp(),
+                                unresolved_dependencies_len = unresolved_dependencies.len(),
+                                "no further missing deploys - checking dependencies"
+                            );
+                            *self = BlockValidationState::CheckingDependencies {
+                                timestamp: appendable_block.timestamp(),
+                                dependencies: mem::take(unresolved_dependencies),
+                                responders: mem::take(responders),
+                            };
+                            return vec![];
+                        }
                         debug!(
                             block_timestamp = %appendable_block.timestamp(),
                             "no further missing deploys - block validation complete"
@@ -343,12 +431,51 @@ impl BlockValidationState {
                     }
                 }
             }
-            BlockValidationState::Valid(_) | BlockValidationState::Invalid(_) => return vec![],
+            BlockValidationState::CheckingDependencies { .. }
+            | BlockValidationState::Valid(_)
+            | BlockValidationState::Invalid(_) => return vec![],
         };
         *self = new_state;
         responders
     }
 
+    /// If the current state is `CheckingDependencies` and the check has not been requested yet,
+    /// returns the dependencies which need to have been executed in earlier blocks.
+    pub(super) fn take_dependencies_to_check(&mut self) -> Option<BTreeSet<DeployHash>> {
+        match self {
+            BlockValidationState::CheckingDependencies { dependencies, .. }
+                if !dependencies.is_empty() =>
+            {
+                Some(mem::take(dependencies))
+            }
+            BlockValidationState::InProgress { .. }
+            | BlockValidationState::CheckingDependencies { .. }
+            | BlockValidationState::Valid(_)
+            | BlockValidationState::Invalid(_) => None,
+        }
+    }
+
+    /// If the current state is `CheckingDependencies`, sets the state to `Valid` if all
+    /// dependencies have been executed, or `Invalid` otherwise, and returns the responders.
+    pub(super) fn try_resolve_dependencies(&mut self, all_executed: bool) -> Vec<Responder<bool>> {
+        let (timestamp, responders) = match self {
+            BlockValidationState::CheckingDependencies {
+                timestamp,
+                responders,
+                ..
+            } => (*timestamp, mem::take(responders)),
+            BlockValidationState::InProgress { .. }
+            | BlockValidationState::Valid(_)
+            | BlockValidationState::Invalid(_) => return vec![],
+        };
+        *self = if all_executed {
+            BlockValidationState::Valid(timestamp)
+        } else {
+            BlockValidationState::Invalid(timestamp)
+        };
+        responders
+    }
+
     /// If the current state is `InProgress` and `dt_hash` is present, sets the state to `Invalid`
     /// and returns the responders.
     pub(super) fn try_mark_invalid(
@@ -367,7 +494,9 @@ impl BlockValidationState {
                 }
                 (appendable_block.timestamp(), mem::take(responders))
             }
-            BlockValidationState::Valid(_) | BlockValidationState::Invalid(_) => return vec![],
+            BlockValidationState::CheckingDependencies { .. }
+            | BlockValidationState::Valid(_)
+            | BlockValidationState::Invalid(_) => return vec![],
         };
         *self = BlockValidationState::Valid(timestamp);
         responders
@@ -375,7 +504,8 @@ impl BlockValidationState {
 
     pub(super) fn block_timestamp_if_completed(&self) -> Option<Timestamp> {
         match self {
-            BlockValidationState::InProgress { .. } => None,
+            BlockValidationState::InProgress { .. }
+            | BlockValidationState::CheckingDependencies { .. } => None,
             BlockValidationState::Valid(timestamp) | BlockValidationState::Invalid(timestamp) => {
                 Some(*timestamp)
             }
@@ -391,7 +521,9 @@ impl BlockValidationState {
                 .keys()
                 .map(|dt_hash| *dt_hash.deploy_hash())
                 .collect(),
-            BlockValidationState::Valid(_) | BlockValidationState::Invalid(_) => vec![],
+            BlockValidationState::CheckingDependencies { .. }
+            | BlockValidationState::Valid(_)
+            | BlockValidationState::Invalid(_) => vec![],
         }
     }
 
@@ -399,22 +531,55 @@ impl BlockValidationState {
     pub(super) fn holders_mut(&mut self) -> Option<&mut HashMap<NodeId, HolderState>> {
         match self {
             BlockValidationState::InProgress { holders, .. } => Some(holders),
-            BlockValidationState::Valid(_) | BlockValidationState::Invalid(_) => None,
+            BlockValidationState::CheckingDependencies { .. }
+            | BlockValidationState::Valid(_)
+            | BlockValidationState::Invalid(_) => None,
         }
     }
 
     #[cfg(test)]
     pub(super) fn responder_count(&self) -> usize {
         match self {
-            BlockValidationState::InProgress { responders, .. } => responders.len(),
+            BlockValidationState::InProgress { responders, .. }
+            | BlockValidationState::CheckingDependencies { responders, .. } => responders.len(),
             BlockValidationState::Valid(_) | BlockValidationState::Invalid(_) => 0,
         }
     }
 
     #[cfg(test)]
     pub(super) fn completed(&self) -> bool {
-        !matches!(self, BlockValidationState::InProgress { .. })
+        !matches!(
+            self,
+            BlockValidationState::InProgress { .. }
+                | BlockValidationState::CheckingDependencies { .. }
+        )
+    }
+}
+
+/// Checks that each dependency of the given deploy is either executed earlier in the block or
+/// included in an ancestor block.  Dependencies found in neither are added to
+/// `unresolved_dependencies`.
+///
+/// Returns the first dependency which is included in the block but not executed before the deploy.
+fn check_dependencies(
+    dt_hash: &DeployOrTransferHash,
+    footprint: &DeployFootprint,
+    positions: &HashMap<DeployHash, usize>,
+    ancestor_deploys: &HashSet<DeployHash>,
+    unresolved_dependencies: &mut BTreeSet<DeployHash>,
+) -> Result<(), DeployHash> {
+    let position = positions.get(dt_hash.deploy_hash());
+    for dependency in footprint.header.dependencies() {
+        match positions.get(dependency) {
+            Some(dependency_position) if Some(dependency_position) < position => {}
+            Some(_) => return Err(*dependency),
+            None if ancestor_deploys.contains(dependency) => {}
+            None => {
+                unresolved_dependencies.insert(*dependency);
+            }
+        }
     }
+    Ok(())
 }
 
 impl Display for BlockValidationState {
@@ -425,6 +590,7 @@ impl Display for BlockValidationState {
                 missing_deploys,
                 holders,
                 responders,
+                ..
             } => {
                 write!(
                     formatter,
@@ -435,6 +601,19 @@ impl Display for BlockValidationState {
                     responders.len()
                 )
             }
+            BlockValidationState::CheckingDependencies {
+                timestamp,
+                dependencies,
+                responders,
+            } => {
+                write!(
+                    formatter,
+                    "BlockValidationState::CheckingDependencies({}, {} dependencies, {} responders)",
+                    timestamp,
+                    dependencies.len(),
+                    responders.len()
+                )
+            }
             BlockValidationState::Valid(timestamp) => {
                 write!(formatter, "BlockValidationState::Valid({timestamp})")
             }
@@ -450,7 +629,7 @@ mod tests {
     use futures::channel::oneshot;
     use rand::Rng;
 
-    use casper_types::{testing::TestRng, TimeDiff};
+    use casper_types::{testing::TestRng, ProtocolVersion, TimeDiff};
 
     use super::{super::tests::*, *};
     use crate::{
@@ -616,7 +795,9 @@ mod tests {
                 assert_eq!(holders.values().next().unwrap(), &HolderState::Unasked);
                 assert_eq!(responders.len(), 1);
             }
-            BlockValidationState::Valid(_) | BlockValidationState::Invalid(_) => {
+            BlockValidationState::CheckingDependencies { .. }
+            | BlockValidationState::Valid(_)
+            | BlockValidationState::Invalid(_) => {
                 panic!("unexpected state")
             }
         }
@@ -879,7 +1060,9 @@ mod tests {
                 missing_deploys.clone(),
                 holders.clone(),
             ),
-            BlockValidationState::Valid(_) | BlockValidationState::Invalid(_) => {
+            BlockValidationState::CheckingDependencies { .. }
+            | BlockValidationState::Valid(_)
+            | BlockValidationState::Invalid(_) => {
                 panic!("unexpected state")
             }
         };
@@ -903,7 +1086,9 @@ mod tests {
                 assert_eq!(&missing_deploys_before, missing_deploys);
                 assert_eq!(&holders_before, holders);
             }
-            BlockValidationState::Valid(_) | BlockValidationState::Invalid(_) => {
+            BlockValidationState::CheckingDependencies { .. }
+            | BlockValidationState::Valid(_)
+            | BlockValidationState::Invalid(_) => {
                 panic!("unexpected state")
             }
         };
@@ -938,4 +1123,112 @@ mod tests {
         assert_eq!(responders.len(), 1);
         assert!(matches!(state, BlockValidationState::Invalid(_)));
     }
+
+    #[test]
+    fn state_should_change_to_checking_dependencies() {
+        let mut fixture = Fixture::new();
+        let dependency = DeployHash::random(&mut fixture.rng);
+        let dependent_deploy = new_deploy_with_dependencies(
+            &mut fixture.rng,
+            Timestamp::from(900),
+            TimeDiff::from_seconds(10),
+            vec![dependency],
+        );
+        fixture.deploys.push(dependent_deploy);
+        let (mut state, _maybe_responder) = fixture.new_state(2, 2);
+
+        for (dt_hash, footprint) in fixture.footprints() {
+            let responders = state.try_add_deploy_footprint(&dt_hash, &footprint);
+            assert!(responders.is_empty());
+        }
+        assert!(matches!(
+            state,
+            BlockValidationState::CheckingDependencies { .. }
+        ));
+        assert_eq!(state.start_fetching(), MaybeStartFetching::Ongoing);
+
+        // The dependencies should only be returned once.
+        let dependencies = state.take_dependencies_to_check().unwrap();
+        assert_eq!(dependencies, iter::once(dependency).collect());
+        assert!(state.take_dependencies_to_check().is_none());
+
+        let responders = state.try_resolve_dependencies(true);
+        assert_eq!(responders.len(), 1);
+        assert!(matches!(state, BlockValidationState::Valid(_)));
+    }
+
+    #[test]
+    fn state_should_ignore_dependencies_before_activation() {
+        let mut fixture = Fixture::new();
+        fixture
+            .chainspec
+            .core_config
+            .start_protocol_version_with_deploy_dependencies =
+            ProtocolVersion::from_parts(u32::MAX, 0, 0);
+        let dependent_deploy = new_deploy_with_dependencies(
+            &mut fixture.rng,
+            Timestamp::from(900),
+            TimeDiff::from_seconds(10),
+            vec![DeployHash::random(&mut fixture.rng)],
+        );
+        fixture.deploys.push(dependent_deploy);
+        let (mut state, _maybe_responder) = fixture.new_state(2, 2);
+
+        let mut responders = vec![];
+        for (dt_hash, footprint) in fixture.footprints() {
+            responders.extend(state.try_add_deploy_footprint(&dt_hash, &footprint));
+        }
+        assert_eq!(responders.len(), 1);
+        assert!(matches!(state, BlockValidationState::Valid(_)));
+        assert!(state.take_dependencies_to_check().is_none());
+    }
+
+    #[test]
+    fn state_should_change_to_validation_failed_if_dependencies_unexecuted() {
+        let mut fixture = Fixture::new();
+        let dependent_deploy = new_deploy_with_dependencies(
+            &mut fixture.rng,
+            Timestamp::from(900),
+            TimeDiff::from_seconds(10),
+            vec![DeployHash::random(&mut fixture.rng)],
+        );
+        fixture.deploys.push(dependent_deploy);
+        let (mut state, _maybe_responder) = fixture.new_state(2, 2);
+
+        for (dt_hash, footprint) in fixture.footprints() {
+            let _ = state.try_add_deploy_footprint(&dt_hash, &footprint);
+        }
+        assert!(state.take_dependencies_to_check().is_some());
+
+        let responders = state.try_resolve_dependencies(false);
+        assert_eq!(responders.len(), 1);
+        assert!(matches!(state, BlockValidationState::Invalid(_)));
+    }
+
+    #[test]
+    fn state_should_change_to_validation_failed_if_dependency_executed_later() {
+        let mut fixture = Fixture::new();
+        let (mut state, _maybe_responder) = {
+            // Transfers are executed after all non-transfer deploys, so a deploy depending on a
+            // transfer in the same block is invalid.
+            let ttl = TimeDiff::from_seconds(10);
+            let transfer = new_transfer(&mut fixture.rng, Timestamp::from(900), ttl);
+            let dependent_deploy = new_deploy_with_dependencies(
+                &mut fixture.rng,
+                Timestamp::from(900),
+                ttl,
+                vec![*transfer.hash()],
+            );
+            fixture.transfers.push(transfer);
+            fixture.deploys.push(dependent_deploy);
+            fixture.new_state(0, 0)
+        };
+
+        let mut responders = vec![];
+        for (dt_hash, footprint) in fixture.footprints() {
+            responders.extend(state.try_add_deploy_footprint(&dt_hash, &footprint));
+        }
+        assert_eq!(responders.len(), 1);
+        assert!(matches!(state, BlockValidationState::Invalid(_)));
+    }
 }
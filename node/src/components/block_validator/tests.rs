@@ -114,6 +114,15 @@ pub(super) fn new_proposed_block(
 }
 
 pub(super) fn new_deploy(rng: &mut TestRng, timestamp: Timestamp, ttl: TimeDiff) -> Deploy {
+    new_deploy_with_dependencies(rng, timestamp, ttl, vec![])
+}
+
+pub(super) fn new_deploy_with_dependencies(
+    rng: &mut TestRng,
+    timestamp: Timestamp,
+    ttl: TimeDiff,
+    dependencies: Vec<DeployHash>,
+) -> Deploy {
     let secret_key = SecretKey::random(rng);
     let chain_name = "chain".to_string();
     let payment = ExecutableDeployItem::ModuleBytes {
@@ -124,7 +133,6 @@ pub(super) fn new_deploy(rng: &mut TestRng, timestamp: Timestamp, ttl: TimeDiff)
         module_bytes: Bytes::new(),
         args: RuntimeArgs::new(),
     };
-    let dependencies = vec![];
     let gas_price = 1;
 
     Deploy::new(
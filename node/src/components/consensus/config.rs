@@ -22,7 +22,7 @@ const DEFAULT_MAX_EXECUTION_DELAY: u64 = 3;
 // Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
 #[serde(deny_unknown_fields)]
 pub struct Config {
-    /// Path to secret key file.
+    /// Path to secret key file, which may be encrypted (see `utils::key_passphrase`).
     pub secret_key_path: External,
     /// The maximum number of blocks by which execution is allowed to lag behind finalization.
     /// If it is more than that, consensus will pause, and resume once the executor has caught up.
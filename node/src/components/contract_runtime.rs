@@ -32,8 +32,8 @@ use casper_execution_engine::{
         self,
         engine_config::{FeeHandling, RefundHandling},
         genesis::GenesisError,
-        ChainspecRegistry, DeployItem, EngineConfigBuilder, EngineState, GenesisSuccess,
-        SystemContractRegistry, UpgradeConfig, UpgradeSuccess,
+        BlockInfo, CallViewRequest, ChainspecRegistry, DeployItem, EngineConfigBuilder,
+        EngineState, GenesisSuccess, SystemContractRegistry, UpgradeConfig, UpgradeSuccess,
     },
     shared::{newtypes::CorrelationId, system_config::SystemConfig, wasm_config::WasmConfig},
     storage::{
@@ -42,7 +42,10 @@ use casper_execution_engine::{
     },
 };
 use casper_hashing::Digest;
-use casper_types::{bytesrepr::Bytes, EraId, ProtocolVersion, PublicKey, Timestamp};
+use casper_types::{
+    bytesrepr::Bytes, BlockTime, EraId, ExecutionResult, Gas, Key, ProtocolVersion, PublicKey,
+    Timestamp,
+};
 
 use crate::{
     components::{fetcher::FetchResponse, Component, ComponentState},
@@ -59,7 +62,8 @@ use crate::{
     protocol::Message,
     types::{
         ActivationPoint, BlockHash, BlockHeader, Chainspec, ChainspecRawBytes, ChunkingError,
-        Deploy, FinalizedBlock, MetaBlock, MetaBlockState, TrieOrChunk, TrieOrChunkId,
+        Deploy, DeployHash, DeployHeader, FinalizedBlock, MetaBlock, MetaBlockState, TrieOrChunk,
+        TrieOrChunkId,
     },
     NodeRng,
 };
@@ -67,7 +71,7 @@ pub(crate) use config::Config;
 pub(crate) use error::{BlockExecutionError, ConfigError};
 use metrics::Metrics;
 pub use operations::execute_finalized_block;
-use operations::execute_only;
+use operations::{execute_only, replay_finalized_block};
 pub(crate) use types::{
     BlockAndExecutionResults, EraValidatorsRequest, StepEffectAndUpcomingEraValidators,
 };
@@ -126,6 +130,12 @@ pub struct SpeculativeExecutionState {
     pub block_time: Timestamp,
     /// Protocol version used when creating the original block.
     pub protocol_version: ProtocolVersion,
+    /// Height of the block.
+    pub block_height: u64,
+    /// Era of the block.
+    pub era_id: EraId,
+    /// Hash of the parent of the block.
+    pub parent_hash: BlockHash,
 }
 
 /// State to use to construct the next block in the blockchain. Includes the state root hash for the
@@ -142,6 +152,8 @@ pub struct ExecutionPreState {
     /// The accumulated seed for the pseudo-random number generator to be incorporated into the
     /// next `Block`, where additional entropy will be introduced.
     parent_seed: Digest,
+    /// The timestamp of the parent of the next `Block`.
+    parent_timestamp: Timestamp,
 }
 
 impl ExecutionPreState {
@@ -150,12 +162,14 @@ impl ExecutionPreState {
         pre_state_root_hash: Digest,
         parent_hash: BlockHash,
         parent_seed: Digest,
+        parent_timestamp: Timestamp,
     ) -> Self {
         ExecutionPreState {
             next_block_height,
             pre_state_root_hash,
             parent_hash,
             parent_seed,
+            parent_timestamp,
         }
     }
 
@@ -167,6 +181,7 @@ impl ExecutionPreState {
             next_block_height: block_header.height() + 1,
             parent_hash: block_header.block_hash(),
             parent_seed: block_header.accumulated_seed(),
+            parent_timestamp: block_header.timestamp(),
         }
     }
 }
@@ -212,6 +227,10 @@ pub(crate) struct ContractRuntime {
     system_contract_registry: Option<SystemContractRegistry>,
     activation_point: ActivationPoint,
     prune_batch_size: u64,
+    /// The name of the chain, made available to executing contracts.
+    chain_name: String,
+    /// The maximum amount of gas a single view entry point evaluation may consume.
+    max_view_gas: Gas,
 }
 
 impl Debug for ContractRuntime {
@@ -494,6 +513,7 @@ impl ContractRuntime {
                         let shared_pre_state = Arc::clone(&self.execution_pre_state);
                         let activation_point = self.activation_point;
                         let prune_batch_size = self.prune_batch_size;
+                        let chain_name = self.chain_name.clone();
                         effects.extend(
                             Self::execute_finalized_block_or_requeue(
                                 engine_state,
@@ -508,6 +528,7 @@ impl ContractRuntime {
                                 activation_point,
                                 key_block_height_for_activation_point,
                                 prune_batch_size,
+                                chain_name,
                                 meta_block_state,
                             )
                             .ignore(),
@@ -551,6 +572,36 @@ impl ContractRuntime {
                 }
                 .ignore()
             }
+            ContractRuntimeRequest::GetDelegators {
+                get_delegators_request,
+                responder,
+            } => {
+                trace!(?get_delegators_request, "get delegators request");
+                let engine_state = Arc::clone(&self.engine_state);
+                async move {
+                    let correlation_id = CorrelationId::new();
+                    let result =
+                        engine_state.get_delegators(correlation_id, get_delegators_request);
+                    trace!(?result, "get delegators result");
+                    responder.respond(result).await
+                }
+                .ignore()
+            }
+            ContractRuntimeRequest::GetUnbondingQueue {
+                get_unbonding_queue_request,
+                responder,
+            } => {
+                trace!(?get_unbonding_queue_request, "get unbonding queue request");
+                let engine_state = Arc::clone(&self.engine_state);
+                async move {
+                    let correlation_id = CorrelationId::new();
+                    let result = engine_state
+                        .get_unbonding_queue(correlation_id, get_unbonding_queue_request);
+                    trace!(?result, "get unbonding queue result");
+                    responder.respond(result).await
+                }
+                .ignore()
+            }
             ContractRuntimeRequest::GetExecutionResultsChecksum {
                 state_root_hash,
                 responder,
@@ -569,15 +620,19 @@ impl ContractRuntime {
             ContractRuntimeRequest::SpeculativeDeployExecution {
                 execution_prestate,
                 deploy,
+                gas_profiler,
                 responder,
             } => {
                 let engine_state = Arc::clone(&self.engine_state);
+                let chain_name = self.chain_name.clone();
                 async move {
                     let result = run_intensive_task(move || {
                         execute_only(
                             engine_state.as_ref(),
+                            chain_name,
                             execution_prestate,
                             DeployItem::from((*deploy).clone()),
+                            gas_profiler,
                         )
                     })
                     .await;
@@ -585,6 +640,52 @@ impl ContractRuntime {
                 }
                 .ignore()
             }
+            ContractRuntimeRequest::CallView {
+                execution_prestate,
+                caller,
+                contract_hash,
+                entry_point,
+                args,
+                responder,
+            } => {
+                let SpeculativeExecutionState {
+                    state_root_hash,
+                    block_time,
+                    protocol_version,
+                    block_height,
+                    era_id,
+                    parent_hash,
+                } = execution_prestate;
+                let block_info = BlockInfo::new(
+                    BlockTime::new(block_time.millis()),
+                    block_height,
+                    era_id,
+                    *parent_hash.inner(),
+                    self.chain_name.clone(),
+                );
+                let call_view_request = CallViewRequest::new(
+                    state_root_hash,
+                    protocol_version,
+                    block_info,
+                    caller,
+                    contract_hash,
+                    entry_point,
+                    args,
+                    self.max_view_gas,
+                );
+                trace!(?call_view_request, "call view request");
+                let engine_state = Arc::clone(&self.engine_state);
+                async move {
+                    let result = run_intensive_task(move || {
+                        let correlation_id = CorrelationId::new();
+                        engine_state.call_view(correlation_id, call_view_request)
+                    })
+                    .await;
+                    trace!(?result, "call view result");
+                    responder.respond(result).await
+                }
+                .ignore()
+            }
         }
     }
 }
@@ -608,9 +709,16 @@ impl ContractRuntime {
         registry: &Registry,
         administrative_accounts: BTreeSet<PublicKey>,
         allow_auction_bids: bool,
+        delegation_rate_change_notice_eras: u64,
         allow_unrestricted_transfers: bool,
         refund_handling: RefundHandling,
         fee_handling: FeeHandling,
+        start_protocol_version_with_deploy_sequence_numbers: ProtocolVersion,
+        start_protocol_version_with_contract_migrations: ProtocolVersion,
+        start_protocol_version_with_scheduled_invocations: ProtocolVersion,
+        start_protocol_version_with_aggregate_finality_signatures: ProtocolVersion,
+        chain_name: String,
+        read_only: bool,
     ) -> Result<Self, ConfigError> {
         // TODO: This is bogus, get rid of this
         let execution_pre_state = Arc::new(Mutex::new(ExecutionPreState {
@@ -618,22 +726,33 @@ impl ContractRuntime {
             next_block_height: 0,
             parent_hash: Default::default(),
             parent_seed: Default::default(),
+            parent_timestamp: Timestamp::zero(),
         }));
 
-        let environment = Arc::new(LmdbEnvironment::new(
-            storage_dir,
-            contract_runtime_config.max_global_state_size_or_default(),
-            contract_runtime_config.max_readers_or_default(),
-            contract_runtime_config.manual_sync_enabled_or_default(),
-        )?);
-
-        let trie_store = Arc::new(LmdbTrieStore::new(
-            &environment,
-            None,
-            DatabaseFlags::empty(),
-        )?);
-
-        let global_state = LmdbGlobalState::empty(environment, trie_store)?;
+        let global_state = if read_only {
+            // Opening the global state read-only neither creates the database nor writes the empty
+            // root to it, so any attempt to commit to it fails.
+            let environment = Arc::new(LmdbEnvironment::new_read_only(
+                storage_dir,
+                contract_runtime_config.max_global_state_size_or_default(),
+                contract_runtime_config.max_readers_or_default(),
+            )?);
+            let trie_store = Arc::new(LmdbTrieStore::open(&environment, None)?);
+            LmdbGlobalState::open(environment, trie_store)?
+        } else {
+            let environment = Arc::new(LmdbEnvironment::new(
+                storage_dir,
+                contract_runtime_config.max_global_state_size_or_default(),
+                contract_runtime_config.max_readers_or_default(),
+                contract_runtime_config.manual_sync_enabled_or_default(),
+            )?);
+            let trie_store = Arc::new(LmdbTrieStore::new(
+                &environment,
+                None,
+                DatabaseFlags::empty(),
+            )?);
+            LmdbGlobalState::empty(environment, trie_store)?
+        };
         let engine_config = EngineConfigBuilder::new()
             .with_max_query_depth(contract_runtime_config.max_query_depth_or_default())
             .with_max_associated_keys(max_associated_keys)
@@ -646,9 +765,22 @@ impl ContractRuntime {
             .with_system_config(system_config)
             .with_administrative_accounts(administrative_accounts)
             .with_allow_auction_bids(allow_auction_bids)
+            .with_delegation_rate_change_notice_eras(delegation_rate_change_notice_eras)
             .with_allow_unrestricted_transfers(allow_unrestricted_transfers)
             .with_refund_handling(refund_handling)
             .with_fee_handling(fee_handling)
+            .with_start_protocol_version_with_sequence_numbers(
+                start_protocol_version_with_deploy_sequence_numbers,
+            )
+            .with_start_protocol_version_with_contract_migrations(
+                start_protocol_version_with_contract_migrations,
+            )
+            .with_start_protocol_version_with_scheduled_invocations(
+                start_protocol_version_with_scheduled_invocations,
+            )
+            .with_start_protocol_version_with_aggregate_finality_signatures(
+                start_protocol_version_with_aggregate_finality_signatures,
+            )
             .build();
 
         let engine_state = Arc::new(EngineState::new(global_state, engine_config));
@@ -665,6 +797,8 @@ impl ContractRuntime {
             system_contract_registry: None,
             activation_point,
             prune_batch_size,
+            chain_name,
+            max_view_gas: Gas::from(contract_runtime_config.max_view_gas_or_default()),
         })
     }
 
@@ -742,6 +876,33 @@ impl ContractRuntime {
         debug!(next_block_height, "ContractRuntime: set initial state");
     }
 
+    /// Re-executes an already executed block on top of the global state of its parent.
+    ///
+    /// Returns the resulting state root hash and the execution results of the block's deploys.
+    /// Nothing is written to global state, and neither the execution pre-state nor the queue of
+    /// blocks awaiting execution are affected.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn reexecute_block(
+        &self,
+        parent: &BlockHeader,
+        finalized_block: FinalizedBlock,
+        deploys: Vec<Deploy>,
+        key_block_height_for_activation_point: u64,
+    ) -> Result<(Digest, Vec<(DeployHash, DeployHeader, ExecutionResult)>), BlockExecutionError>
+    {
+        replay_finalized_block(
+            self.engine_state.as_ref(),
+            self.protocol_version,
+            ExecutionPreState::from_block_header(parent),
+            finalized_block,
+            deploys,
+            self.activation_point.era_id(),
+            key_block_height_for_activation_point,
+            self.prune_batch_size,
+            self.chain_name.clone(),
+        )
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn execute_finalized_block_or_requeue<REv>(
         engine_state: Arc<EngineState<LmdbGlobalState>>,
@@ -756,6 +917,7 @@ impl ContractRuntime {
         activation_point: ActivationPoint,
         key_block_height_for_activation_point: u64,
         prune_batch_size: u64,
+        chain_name: String,
         mut meta_block_state: MetaBlockState,
     ) where
         REv: From<ContractRuntimeRequest>
@@ -771,6 +933,7 @@ impl ContractRuntime {
             block,
             approvals_hashes,
             execution_results,
+            scheduled_invocation_results,
             maybe_step_effect_and_upcoming_era_validators,
         } = match run_intensive_task(move || {
             debug!("ContractRuntime: execute_finalized_block");
@@ -784,6 +947,7 @@ impl ContractRuntime {
                 activation_point.era_id(),
                 key_block_height_for_activation_point,
                 prune_batch_size,
+                chain_name,
             )
         })
         .await
@@ -817,6 +981,15 @@ impl ContractRuntime {
             }
         }
 
+        if !scheduled_invocation_results.is_empty() {
+            effect_builder
+                .announce_scheduled_invocations_processed(
+                    *block.hash(),
+                    scheduled_invocation_results.clone(),
+                )
+                .await;
+        }
+
         let current_era_id = block.header().era_id();
 
         if let Some(StepEffectAndUpcomingEraValidators {
@@ -855,10 +1028,20 @@ impl ContractRuntime {
             "executed block"
         );
 
+        // Scheduled invocations are stored alongside the block's deploys, keyed by the same hash
+        // the engine executed them under.
         let execution_results_map: HashMap<_, _> = execution_results
             .iter()
             .cloned()
             .map(|(deploy_hash, _, execution_result)| (deploy_hash, execution_result))
+            .chain(scheduled_invocation_results.into_iter().filter_map(
+                |(key, execution_result)| match key {
+                    Key::ScheduledInvocation(addr) => {
+                        Some((DeployHash::new(Digest::from(addr)), execution_result))
+                    }
+                    _ => None,
+                },
+            ))
             .collect();
 
         if meta_block_state.register_as_stored().was_updated() {
@@ -980,7 +1163,13 @@ impl ContractRuntime {
 #[cfg(test)]
 mod trie_chunking_tests {
     use casper_execution_engine::{
-        core::engine_state::engine_config::{DEFAULT_FEE_HANDLING, DEFAULT_REFUND_HANDLING},
+        core::engine_state::engine_config::{
+            DEFAULT_FEE_HANDLING, DEFAULT_REFUND_HANDLING,
+            DEFAULT_START_PROTOCOL_VERSION_WITH_AGGREGATE_FINALITY_SIGNATURES,
+            DEFAULT_START_PROTOCOL_VERSION_WITH_CONTRACT_MIGRATIONS,
+            DEFAULT_START_PROTOCOL_VERSION_WITH_SCHEDULED_INVOCATIONS,
+            DEFAULT_START_PROTOCOL_VERSION_WITH_SEQUENCE_NUMBERS,
+        },
         shared::{
             additive_map::AdditiveMap, newtypes::CorrelationId, system_config::SystemConfig,
             transform::Transform, wasm_config::WasmConfig,
@@ -1068,9 +1257,16 @@ mod trie_chunking_tests {
             &Registry::default(),
             Default::default(),
             true,
+            0,
             true,
             DEFAULT_REFUND_HANDLING,
             DEFAULT_FEE_HANDLING,
+            DEFAULT_START_PROTOCOL_VERSION_WITH_SEQUENCE_NUMBERS,
+            DEFAULT_START_PROTOCOL_VERSION_WITH_CONTRACT_MIGRATIONS,
+            DEFAULT_START_PROTOCOL_VERSION_WITH_SCHEDULED_INVOCATIONS,
+            DEFAULT_START_PROTOCOL_VERSION_WITH_AGGREGATE_FINALITY_SIGNATURES,
+            "test".to_string(),
+            false,
         )
         .unwrap();
         let empty_state_root = contract_runtime
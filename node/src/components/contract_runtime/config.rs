@@ -6,6 +6,7 @@ use casper_execution_engine::shared::utils;
 const DEFAULT_MAX_GLOBAL_STATE_SIZE: usize = 805_306_368_000; // 750 GiB
 const DEFAULT_MAX_READERS: u32 = 512;
 const DEFAULT_MAX_QUERY_DEPTH: u64 = 5;
+const DEFAULT_MAX_VIEW_GAS: u64 = 10_000_000_000;
 const DEFAULT_MANUAL_SYNC_ENABLED: bool = true;
 
 /// Contract runtime configuration.
@@ -27,6 +28,11 @@ pub struct Config {
     ///
     /// Defaults to 5.
     pub max_query_depth: Option<u64>,
+    /// The maximum amount of gas a single evaluation of a view entry point requested via the RPC
+    /// interface may consume.
+    ///
+    /// Defaults to 10,000,000,000.
+    pub max_view_gas: Option<u64>,
     /// Enable synchronizing to disk only after each block is written.
     ///
     /// Defaults to `true`.
@@ -53,6 +59,11 @@ impl Config {
         self.max_query_depth.unwrap_or(DEFAULT_MAX_QUERY_DEPTH)
     }
 
+    /// Max gas for a single view entry point evaluation.
+    pub fn max_view_gas_or_default(&self) -> u64 {
+        self.max_view_gas.unwrap_or(DEFAULT_MAX_VIEW_GAS)
+    }
+
     /// Is manual sync enabled.
     pub fn manual_sync_enabled_or_default(&self) -> bool {
         self.enable_manual_sync
@@ -66,6 +77,7 @@ impl Default for Config {
             max_global_state_size: Some(DEFAULT_MAX_GLOBAL_STATE_SIZE),
             max_readers: Some(DEFAULT_MAX_READERS),
             max_query_depth: Some(DEFAULT_MAX_QUERY_DEPTH),
+            max_view_gas: Some(DEFAULT_MAX_VIEW_GAS),
             enable_manual_sync: Some(DEFAULT_MANUAL_SYNC_ENABLED),
         }
     }
@@ -7,6 +7,7 @@ use casper_execution_engine::{
     core::engine_state::{Error as EngineStateError, StepError},
     storage::error::lmdb::Error as StorageLmdbError,
 };
+use casper_hashing::Digest;
 
 use crate::{
     components::contract_runtime::ExecutionPreState,
@@ -45,6 +46,9 @@ pub enum BlockExecutionError {
         /// The state of the block chain prior to block execution that was to be used.
         execution_pre_state: Box<ExecutionPreState>,
     },
+    /// The global state root hash on top of which the block was being executed was not found.
+    #[error("root not found: {0}")]
+    RootNotFound(Digest),
     /// A core error thrown by the execution engine.
     #[error(transparent)]
     EngineState(
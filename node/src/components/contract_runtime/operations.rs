@@ -6,19 +6,26 @@ use tracing::{debug, error, info, trace, warn};
 use casper_execution_engine::{
     core::{
         engine_state::{
-            self, execution_result::ExecutionResults, step::EvictItem, ChecksumRegistry,
+            self, execution_result::ExecutionResults, step::EvictItem, BlockInfo, ChecksumRegistry,
             DeployItem, EngineState, ExecuteRequest, ExecutionResult as EngineExecutionResult,
-            GetEraValidatorsRequest, PruneConfig, PruneResult, RewardItem, StepError, StepRequest,
+            GetEraValidatorsRequest, PruneConfig, PruneResult, RewardItem,
+            ScheduledInvocationsRequest, ScheduledInvocationsResult, StepError, StepRequest,
             StepSuccess,
         },
         execution,
     },
-    shared::{additive_map::AdditiveMap, newtypes::CorrelationId, transform::Transform},
-    storage::global_state::{lmdb::LmdbGlobalState, CommitProvider, StateProvider},
+    shared::{
+        additive_map::AdditiveMap, execution_journal::ExecutionJournal, gas_profile::GasProfiler,
+        newtypes::CorrelationId, transform::Transform,
+    },
+    storage::global_state::{
+        lmdb::LmdbGlobalState, scratch::ScratchGlobalState, CommitProvider, StateProvider,
+    },
 };
 use casper_hashing::Digest;
 use casper_types::{
-    CLValue, DeployHash, EraId, ExecutionResult, Key, ProtocolVersion, PublicKey, U512,
+    BlockTime, CLValue, DeployHash, EraId, ExecutionResult, Key, ProtocolVersion, PublicKey,
+    StoredValue, U512,
 };
 
 use crate::{
@@ -33,7 +40,7 @@ use crate::{
     },
     types::{
         self, error::BlockCreationError, ApprovalsHashes, Block, Chunkable, Deploy, DeployHeader,
-        FinalizedBlock,
+        DeployId, FinalizedBlock,
     },
 };
 
@@ -87,31 +94,47 @@ fn calculate_prune_eras(
     Some(range.map(EraId::new).map(Key::EraInfo).collect())
 }
 
-/// Executes a finalized block.
+/// The outcome of executing a finalized block against an in-memory scratch copy of global state,
+/// before any of it is written to LMDB.
+struct ScratchExecution {
+    /// The scratch state holding all changes made by the block.
+    scratch_state: EngineState<ScratchGlobalState>,
+    /// The state root hash the changes cached in `scratch_state` apply to.
+    state_root_hash: Digest,
+    /// The IDs of the block's deploys, in execution order.
+    deploy_ids: Vec<DeployId>,
+    /// The execution results of the block's deploys.
+    execution_results: Vec<(types::DeployHash, DeployHeader, ExecutionResult)>,
+    /// The execution results of the scheduled invocations run at the start of the block.
+    scheduled_invocation_results: Vec<(Key, ExecutionResult)>,
+    /// The execution journal of the step, if the block is a switch block.
+    step_execution_journal: Option<ExecutionJournal>,
+}
+
+/// Executes the scheduled invocations, deploys and, for a switch block, the step of a finalized
+/// block against a scratch copy of global state.
 #[allow(clippy::too_many_arguments)]
-pub fn execute_finalized_block(
+fn execute_on_scratch(
     engine_state: &EngineState<LmdbGlobalState>,
     metrics: Option<Arc<Metrics>>,
     protocol_version: ProtocolVersion,
-    execution_pre_state: ExecutionPreState,
-    finalized_block: FinalizedBlock,
+    execution_pre_state: &ExecutionPreState,
+    finalized_block: &FinalizedBlock,
     deploys: Vec<Deploy>,
-    activation_point_era_id: EraId,
-    key_block_height_for_activation_point: u64,
-    prune_batch_size: u64,
-) -> Result<BlockAndExecutionResults, BlockExecutionError> {
+    chain_name: String,
+) -> Result<ScratchExecution, BlockExecutionError> {
     if finalized_block.height() != execution_pre_state.next_block_height {
         return Err(BlockExecutionError::WrongBlockHeight {
-            finalized_block: Box::new(finalized_block),
-            execution_pre_state: Box::new(execution_pre_state),
+            finalized_block: Box::new(finalized_block.clone()),
+            execution_pre_state: Box::new(execution_pre_state.clone()),
         });
     }
     let ExecutionPreState {
         pre_state_root_hash,
         parent_hash,
-        parent_seed,
-        next_block_height: _,
-    } = execution_pre_state;
+        parent_timestamp,
+        ..
+    } = *execution_pre_state;
     let mut state_root_hash = pre_state_root_hash;
     let mut execution_results: Vec<(_, DeployHeader, ExecutionResult)> =
         Vec::with_capacity(deploys.len());
@@ -125,6 +148,44 @@ pub fn execute_finalized_block(
     // Create a new EngineState that reads from LMDB but only caches changes in memory.
     let scratch_state = engine_state.get_scratch_engine_state();
 
+    // Run any scheduled invocations which are due in this block before its deploys.
+    let scheduled_invocations_request = ScheduledInvocationsRequest::new(
+        state_root_hash,
+        protocol_version,
+        BlockInfo::new(
+            BlockTime::new(block_time),
+            finalized_block.height(),
+            finalized_block.era_id(),
+            *parent_hash.inner(),
+            chain_name.clone(),
+        ),
+        parent_timestamp,
+        finalized_block.proposer().clone(),
+    );
+    let scheduled_invocation_results = match scratch_state
+        .run_scheduled_invocations(CorrelationId::new(), scheduled_invocations_request)?
+    {
+        ScheduledInvocationsResult::RootNotFound => {
+            return Err(BlockExecutionError::RootNotFound(state_root_hash));
+        }
+        ScheduledInvocationsResult::Success {
+            post_state_hash,
+            execution_results,
+        } => {
+            state_root_hash = post_state_hash;
+            execution_results
+                .into_iter()
+                .map(|(addr, result)| {
+                    trace!(?addr, ?result, "scheduled invocation execution result");
+                    (
+                        Key::ScheduledInvocation(addr),
+                        ExecutionResult::from(&result),
+                    )
+                })
+                .collect_vec()
+        }
+    };
+
     // WARNING: Do not change the order of `deploys` as it will result in a different root hash.
     for deploy in deploys {
         let deploy_hash = *deploy.hash();
@@ -132,6 +193,10 @@ pub fn execute_finalized_block(
         let execute_request = ExecuteRequest::new(
             state_root_hash,
             block_time,
+            finalized_block.height(),
+            finalized_block.era_id(),
+            *parent_hash.inner(),
+            chain_name.clone(),
             vec![DeployItem::from(deploy)],
             protocol_version,
             *finalized_block.proposer(),
@@ -185,13 +250,12 @@ pub fn execute_finalized_block(
         metrics.exec_block.observe(start.elapsed().as_secs_f64());
     }
 
-    // If the finalized block has an era report, run the auction contract and get the upcoming era
-    // validators.
-    let maybe_step_effect_and_upcoming_era_validators =
-        if let Some(era_report) = finalized_block.era_report() {
+    // If the finalized block has an era report, run the auction contract.
+    let step_execution_journal = match finalized_block.era_report() {
+        Some(era_report) => {
             let StepSuccess {
                 post_state_hash: _, // ignore the post-state-hash returned from scratch
-                execution_journal: step_execution_journal,
+                execution_journal,
             } = commit_step(
                 &scratch_state, // engine_state
                 metrics,
@@ -201,10 +265,65 @@ pub fn execute_finalized_block(
                 finalized_block.timestamp().millis(),
                 finalized_block.era_id().successor(),
             )?;
+            Some(execution_journal)
+        }
+        None => None,
+    };
+
+    Ok(ScratchExecution {
+        scratch_state,
+        state_root_hash,
+        deploy_ids,
+        execution_results,
+        scheduled_invocation_results,
+        step_execution_journal,
+    })
+}
+
+/// Executes a finalized block.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_finalized_block(
+    engine_state: &EngineState<LmdbGlobalState>,
+    metrics: Option<Arc<Metrics>>,
+    protocol_version: ProtocolVersion,
+    execution_pre_state: ExecutionPreState,
+    finalized_block: FinalizedBlock,
+    deploys: Vec<Deploy>,
+    activation_point_era_id: EraId,
+    key_block_height_for_activation_point: u64,
+    prune_batch_size: u64,
+    chain_name: String,
+) -> Result<BlockAndExecutionResults, BlockExecutionError> {
+    let ScratchExecution {
+        scratch_state,
+        state_root_hash,
+        deploy_ids,
+        execution_results,
+        scheduled_invocation_results,
+        step_execution_journal,
+    } = execute_on_scratch(
+        engine_state,
+        metrics,
+        protocol_version,
+        &execution_pre_state,
+        &finalized_block,
+        deploys,
+        chain_name,
+    )?;
+    let ExecutionPreState {
+        parent_hash,
+        parent_seed,
+        ..
+    } = execution_pre_state;
 
-            state_root_hash =
-                engine_state.write_scratch_to_db(state_root_hash, scratch_state.into_inner())?;
+    // Finally, the new state-root-hash from the cumulative changes to global state is returned when
+    // they are written to LMDB.
+    let mut state_root_hash =
+        engine_state.write_scratch_to_db(state_root_hash, scratch_state.into_inner())?;
 
+    // If the finalized block has an era report, get the upcoming era validators.
+    let maybe_step_effect_and_upcoming_era_validators = match step_execution_journal {
+        Some(step_execution_journal) => {
             // In this flow we execute using a recent state root hash where the system contract
             // registry is guaranteed to exist.
             let system_contract_registry = None;
@@ -218,13 +337,9 @@ pub fn execute_finalized_block(
                 step_execution_journal,
                 upcoming_era_validators,
             })
-        } else {
-            // Finally, the new state-root-hash from the cumulative changes to global state is
-            // returned when they are written to LMDB.
-            state_root_hash =
-                engine_state.write_scratch_to_db(state_root_hash, scratch_state.into_inner())?;
-            None
-        };
+        }
+        None => None,
+    };
 
     // Flush once, after all deploys have been executed.
     engine_state.flush_environment()?;
@@ -292,6 +407,33 @@ pub fn execute_finalized_block(
         }
     }
 
+    // Remove the executed scheduled invocations and the delegators left without a stake now that
+    // the block's state has been persisted.
+    let keys_to_prune = executed_invocation_and_withdrawn_delegator_keys(
+        &scheduled_invocation_results,
+        &execution_results,
+        maybe_step_effect_and_upcoming_era_validators
+            .as_ref()
+            .map(|step_effect| &step_effect.step_execution_journal),
+    );
+    if !keys_to_prune.is_empty() {
+        let prune_config = PruneConfig::new(state_root_hash, keys_to_prune);
+        match engine_state.commit_prune(CorrelationId::new(), prune_config)? {
+            PruneResult::RootNotFound => {
+                return Err(BlockExecutionError::RootNotFound(state_root_hash));
+            }
+            PruneResult::DoesNotExist => {
+                warn!(
+                    %state_root_hash,
+                    "commit prune: scheduled invocation or delegator does not exist"
+                );
+            }
+            PruneResult::Success { post_state_hash } => {
+                state_root_hash = post_state_hash;
+            }
+        }
+    }
+
     let next_era_validator_weights: Option<BTreeMap<PublicKey, U512>> =
         maybe_step_effect_and_upcoming_era_validators
             .as_ref()
@@ -331,10 +473,132 @@ pub fn execute_finalized_block(
         block,
         approvals_hashes,
         execution_results,
+        scheduled_invocation_results,
         maybe_step_effect_and_upcoming_era_validators,
     })
 }
 
+/// Re-executes a finalized block without modifying global state.
+///
+/// Returns the execution results of the block's deploys and the state root hash which executing
+/// the block would have produced, computed in memory.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(super) fn replay_finalized_block(
+    engine_state: &EngineState<LmdbGlobalState>,
+    protocol_version: ProtocolVersion,
+    execution_pre_state: ExecutionPreState,
+    finalized_block: FinalizedBlock,
+    deploys: Vec<Deploy>,
+    activation_point_era_id: EraId,
+    key_block_height_for_activation_point: u64,
+    prune_batch_size: u64,
+    chain_name: String,
+) -> Result<
+    (
+        Digest,
+        Vec<(types::DeployHash, DeployHeader, ExecutionResult)>,
+    ),
+    BlockExecutionError,
+> {
+    let ScratchExecution {
+        scratch_state,
+        state_root_hash,
+        deploy_ids: _,
+        execution_results,
+        scheduled_invocation_results,
+        step_execution_journal,
+    } = execute_on_scratch(
+        engine_state,
+        None,
+        protocol_version,
+        &execution_pre_state,
+        &finalized_block,
+        deploys,
+        chain_name,
+    )?;
+
+    // Apply the same prunes as `execute_finalized_block`, in the same order.
+    let era_keys_to_prune = finalized_block
+        .height()
+        .checked_sub(1)
+        .and_then(|previous_block_height| {
+            calculate_prune_eras(
+                activation_point_era_id,
+                key_block_height_for_activation_point,
+                previous_block_height,
+                prune_batch_size,
+            )
+        })
+        .unwrap_or_default();
+    let keys_to_prune = executed_invocation_and_withdrawn_delegator_keys(
+        &scheduled_invocation_results,
+        &execution_results,
+        step_execution_journal.as_ref(),
+    );
+    let post_state_hash = engine_state.compute_scratch_post_state_hash(
+        state_root_hash,
+        scratch_state.into_inner(),
+        &[era_keys_to_prune, keys_to_prune],
+    )?;
+
+    Ok((post_state_hash, execution_results))
+}
+
+/// Returns the keys of the executed scheduled invocations and of the delegators left without a
+/// stake by the block.
+fn executed_invocation_and_withdrawn_delegator_keys(
+    scheduled_invocation_results: &[(Key, ExecutionResult)],
+    execution_results: &[(types::DeployHash, DeployHeader, ExecutionResult)],
+    step_execution_journal: Option<&ExecutionJournal>,
+) -> Vec<Key> {
+    scheduled_invocation_results
+        .iter()
+        .map(|(key, _)| *key)
+        .chain(withdrawn_delegator_keys(
+            scheduled_invocation_results
+                .iter()
+                .map(|(_, result)| result)
+                .chain(execution_results.iter().map(|(_, _, result)| result)),
+            step_execution_journal,
+        ))
+        .collect()
+}
+
+/// Returns the keys of the delegators whose last write in the given execution results or step
+/// journal left them without a stake.
+fn withdrawn_delegator_keys<'a>(
+    execution_results: impl Iterator<Item = &'a ExecutionResult>,
+    step_execution_journal: Option<&ExecutionJournal>,
+) -> Vec<Key> {
+    let mut delegator_stakes = BTreeMap::new();
+    for execution_result in execution_results {
+        let effect = match execution_result {
+            ExecutionResult::Failure { effect, .. } | ExecutionResult::Success { effect, .. } => {
+                effect
+            }
+        };
+        for entry in &effect.transforms {
+            if let casper_types::Transform::WriteDelegator(delegator) = &entry.transform {
+                if let Ok(key) = Key::from_formatted_str(&entry.key) {
+                    delegator_stakes.insert(key, *delegator.staked_amount());
+                }
+            }
+        }
+    }
+    for (key, transform) in step_execution_journal
+        .into_iter()
+        .flat_map(ExecutionJournal::iter)
+    {
+        if let Transform::Write(StoredValue::Delegator(delegator)) = transform {
+            delegator_stakes.insert(*key, *delegator.staked_amount());
+        }
+    }
+    delegator_stakes
+        .into_iter()
+        .filter_map(|(key, staked_amount)| staked_amount.is_zero().then_some(key))
+        .collect()
+}
+
 /// Commits the execution results.
 fn commit_execution_results<S>(
     engine_state: &EngineState<S>,
@@ -410,8 +674,10 @@ where
 /// Returns effects of the execution.
 pub fn execute_only<S>(
     engine_state: &EngineState<S>,
+    chain_name: String,
     execution_state: SpeculativeExecutionState,
     deploy: DeployItem,
+    gas_profiler: Option<GasProfiler>,
 ) -> Result<Option<ExecutionResult>, engine_state::Error>
 where
     S: StateProvider + CommitProvider,
@@ -421,15 +687,23 @@ where
         state_root_hash,
         block_time,
         protocol_version,
+        block_height,
+        era_id,
+        parent_hash,
     } = execution_state;
     let deploy_hash = deploy.deploy_hash;
-    let execute_request = ExecuteRequest::new(
+    let mut execute_request = ExecuteRequest::new(
         state_root_hash,
         block_time.millis(),
+        block_height,
+        era_id,
+        *parent_hash.inner(),
+        chain_name,
         vec![deploy],
         protocol_version,
         PublicKey::System,
     );
+    execute_request.gas_profiler = gas_profiler;
     let results = execute(engine_state, None, execute_request);
     results.map(|mut execution_results| {
         let len = execution_results.len();
@@ -100,6 +100,7 @@ impl reactor::Reactor for Reactor {
             "test",
             MAX_TTL.into(),
             RECENT_ERA_COUNT,
+            ProtocolVersion::V1_0_0,
             Some(registry),
             false,
         )
@@ -122,9 +123,24 @@ impl reactor::Reactor for Reactor {
             registry,
             chainspec.core_config.administrators.clone(),
             chainspec.core_config.allow_auction_bids,
+            chainspec.core_config.delegation_rate_change_notice_eras,
             chainspec.core_config.allow_unrestricted_transfers,
             chainspec.core_config.refund_handling,
             chainspec.core_config.fee_handling,
+            chainspec
+                .core_config
+                .start_protocol_version_with_deploy_sequence_numbers,
+            chainspec
+                .core_config
+                .start_protocol_version_with_contract_migrations,
+            chainspec
+                .core_config
+                .start_protocol_version_with_scheduled_invocations,
+            chainspec
+                .core_config
+                .start_protocol_version_with_aggregate_finality_signatures,
+            chainspec.network_config.name.clone(),
+            false,
         )?;
 
         let reactor = Reactor {
@@ -240,6 +256,7 @@ async fn should_not_set_shared_pre_state_to_lower_block_height() {
         post_commit_genesis_state_hash,
         BlockHash::default(),
         Digest::default(),
+        Timestamp::now(),
     );
     runner
         .reactor_mut()
@@ -372,6 +389,7 @@ async fn should_not_set_shared_pre_state_to_lower_block_height() {
             Digest::hash(rng.next_u64().to_le_bytes()),
             BlockHash::random(rng),
             Digest::hash(rng.next_u64().to_le_bytes()),
+            Timestamp::now(),
         ));
 
     runner
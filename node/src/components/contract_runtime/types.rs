@@ -6,7 +6,7 @@ use casper_execution_engine::{
     core::engine_state::GetEraValidatorsRequest, shared::execution_journal::ExecutionJournal,
 };
 use casper_hashing::Digest;
-use casper_types::{EraId, ExecutionResult, ProtocolVersion, PublicKey, U512};
+use casper_types::{EraId, ExecutionResult, Key, ProtocolVersion, PublicKey, U512};
 
 use crate::types::{ApprovalsHashes, Block, DeployHash, DeployHeader};
 
@@ -103,6 +103,9 @@ pub struct BlockAndExecutionResults {
     pub(crate) approvals_hashes: Box<ApprovalsHashes>,
     /// The results from executing the deploys in the block.
     pub(crate) execution_results: Vec<(DeployHash, DeployHeader, ExecutionResult)>,
+    /// The results from executing the scheduled invocations which were due in the block, keyed by
+    /// the invocations' [`Key::ScheduledInvocation`]s.
+    pub(crate) scheduled_invocation_results: Vec<(Key, ExecutionResult)>,
     /// The [`ExecutionJournal`] and the upcoming validator sets determined by the `step`
     pub(crate) maybe_step_effect_and_upcoming_era_validators:
         Option<StepEffectAndUpcomingEraValidators>,
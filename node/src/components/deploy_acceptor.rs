@@ -5,9 +5,15 @@ mod event;
 mod metrics;
 mod tests;
 
-use std::{collections::BTreeSet, fmt::Debug, sync::Arc};
+use std::{
+    collections::{BTreeSet, HashMap, VecDeque},
+    fmt::Debug,
+    iter,
+    sync::Arc,
+};
 
 use datasize::DataSize;
+use futures::FutureExt;
 use prometheus::Registry;
 use serde::Serialize;
 use thiserror::Error;
@@ -24,20 +30,23 @@ use casper_types::{
     account::{Account, AccountHash},
     system::auction::ARG_AMOUNT,
     Contract, ContractHash, ContractPackage, ContractPackageHash, ContractVersion,
-    ContractVersionKey, Key, ProtocolVersion, Timestamp, U512,
+    ContractVersionKey, Key, ProtocolVersion, Tagged, Timestamp, BLS_TAG, U512,
 };
 
 use crate::{
     components::Component,
     effect::{
-        announcements::{DeployAcceptorAnnouncement, FatalAnnouncement},
-        requests::{ContractRuntimeRequest, StorageRequest},
+        announcements::{
+            DeployAcceptorAnnouncement, DeployCancellationAnnouncement, FatalAnnouncement,
+        },
+        requests::{ContractRuntimeRequest, DeployAcceptorRequest, StorageRequest},
         EffectBuilder, EffectExt, Effects, Responder,
     },
     fatal,
     types::{
         chainspec::{CoreConfig, DeployConfig},
-        BlockHash, BlockHeader, Chainspec, Deploy, DeployConfigurationFailure, FinalizedApprovals,
+        BlockHash, BlockHeader, Chainspec, Deploy, DeployCancellation, DeployConfigurationFailure,
+        DeployHash, FinalizedApprovals,
     },
     utils::Source,
     NodeRng,
@@ -84,6 +93,10 @@ pub(crate) enum Error {
         /// The timestamp when the node validated the expiry timestamp.
         current_node_timestamp: Timestamp,
     },
+
+    /// The deploy cancellation is invalid.
+    #[error("invalid deploy cancellation: {0}")]
+    InvalidCancellation(CancellationFailure),
 }
 
 impl Error {
@@ -144,12 +157,76 @@ pub(crate) enum DeployParameterFailure {
     /// Module bytes for session code cannot be empty.
     #[error("module bytes for session code cannot be empty")]
     MissingModuleBytes,
+    /// Fee payer account does not exist.
+    #[error("fee payer account with hash {account_hash} does not exist")]
+    NonexistentFeePayerAccount { account_hash: AccountHash },
+    /// The fee payer's approval is insufficient to authorize payment from its account.
+    #[error("fee payer authorization invalid for account with hash {account_hash}")]
+    InvalidFeePayerAuthorization { account_hash: AccountHash },
+}
+
+/// A representation of the way in which a deploy cancellation failed validation checks.
+#[derive(Clone, Debug, Error, Serialize)]
+pub(crate) enum CancellationFailure {
+    /// The cancellation's approvals are missing or invalid.
+    #[error(transparent)]
+    InvalidApprovals(DeployConfigurationFailure),
+    /// The cancellation has more approvals than an account can have associated keys.
+    #[error(
+        "got {got} approvals, but the maximum number of associated keys is {max_associated_keys}"
+    )]
+    ExcessiveApprovals {
+        got: usize,
+        max_associated_keys: u32,
+    },
+    /// The cancelled deploy is not held by this node.
+    #[error("deploy {deploy_hash} is unknown")]
+    UnknownDeploy { deploy_hash: DeployHash },
+    /// The cancelled deploy has expired, so can no longer be proposed anyway.
+    #[error("deploy {deploy_hash} expired at {expired_at}")]
+    ExpiredDeploy {
+        deploy_hash: DeployHash,
+        expired_at: Timestamp,
+    },
+    /// The replacement deploy is not held by this node.
+    #[error("replacement deploy {deploy_hash} is unknown")]
+    UnknownReplacement { deploy_hash: DeployHash },
+    /// The replacement deploy belongs to a different account than the cancelled deploy.
+    #[error("replacement deploy {deploy_hash} belongs to a different account")]
+    ReplacementAccountMismatch { deploy_hash: DeployHash },
+    /// The replacement deploy does not offer a higher gas price than the cancelled deploy.
+    #[error(
+        "replacement gas price {replacement_gas_price} must be higher than gas price {gas_price}"
+    )]
+    InsufficientGasPrice {
+        gas_price: u64,
+        replacement_gas_price: u64,
+    },
+    /// The cancelled deploy's account does not exist.
+    #[error("account with hash {account_hash} does not exist")]
+    NonexistentAccount { account_hash: AccountHash },
+    /// Invalid associated keys.
+    #[error("account authorization invalid")]
+    InvalidAssociatedKeys,
+    /// Insufficient cancellation signature weight.
+    #[error("insufficient cancellation signature weight")]
+    InsufficientSignatureWeight,
+}
+
+/// A record of a deploy having been rejected by the `DeployAcceptor`.
+#[derive(Clone, DataSize, Debug)]
+pub(crate) struct DeployRejection {
+    /// The time at which the deploy was rejected.
+    pub(crate) rejected_at: Timestamp,
+    /// The reason for the rejection.
+    pub(crate) error: String,
 }
 
 /// A helper trait constraining `DeployAcceptor` compatible reactor events.
 pub(crate) trait ReactorEventT:
     From<Event>
     + From<DeployAcceptorAnnouncement>
+    + From<DeployCancellationAnnouncement>
     + From<StorageRequest>
     + From<ContractRuntimeRequest>
     + From<FatalAnnouncement>
@@ -160,6 +237,7 @@ pub(crate) trait ReactorEventT:
 impl<REv> ReactorEventT for REv where
     REv: From<Event>
         + From<DeployAcceptorAnnouncement>
+        + From<DeployCancellationAnnouncement>
         + From<StorageRequest>
         + From<ContractRuntimeRequest>
         + From<FatalAnnouncement>
@@ -211,6 +289,10 @@ pub struct DeployAcceptor {
     deploy_config: DeployConfig,
     core_config: CoreConfig,
     max_associated_keys: u32,
+    /// The most recently rejected deploys, bounded by `acceptor_config.max_rejected_deploys`.
+    rejections: HashMap<DeployHash, DeployRejection>,
+    /// The order in which the deploys in `rejections` were rejected, oldest first.
+    rejection_order: VecDeque<DeployHash>,
     #[data_size(skip)]
     metrics: metrics::Metrics,
 }
@@ -228,10 +310,17 @@ impl DeployAcceptor {
             deploy_config: chainspec.deploy_config,
             core_config: chainspec.core_config.clone(),
             max_associated_keys: chainspec.core_config.max_associated_keys,
+            rejections: HashMap::new(),
+            rejection_order: VecDeque::new(),
             metrics: metrics::Metrics::new(registry)?,
         })
     }
 
+    /// Replaces the configuration used for all deploys accepted from now on.
+    pub(crate) fn set_config(&mut self, acceptor_config: Config) {
+        self.acceptor_config = acceptor_config;
+    }
+
     /// Handles receiving a new `Deploy` from a peer or client.
     /// In the case of a peer, there should be no responder and the variant should be `None`
     /// In the case of a client, there should be a responder to communicate the validity of the
@@ -263,6 +352,41 @@ impl DeployAcceptor {
             );
         }
 
+        let activation = self
+            .core_config
+            .start_protocol_version_with_deploy_sequence_numbers;
+        if deploy.header().sequence_number().is_some() && self.protocol_version < activation {
+            debug!(%deploy, %activation, "deploy sequence numbers are not yet supported");
+            return self.handle_invalid_deploy_result(
+                effect_builder,
+                Box::new(EventMetadata::new(deploy, source, maybe_responder)),
+                Error::InvalidDeployConfiguration(
+                    DeployConfigurationFailure::UnsupportedSequenceNumber { activation },
+                ),
+                verification_start_timestamp,
+            );
+        }
+
+        let activation = self
+            .core_config
+            .start_protocol_version_with_aggregate_finality_signatures;
+        let uses_bls_key = deploy.header().account().tag() == BLS_TAG
+            || deploy
+                .approvals()
+                .iter()
+                .any(|approval| approval.signer().tag() == BLS_TAG);
+        if uses_bls_key && self.protocol_version < activation {
+            debug!(%deploy, %activation, "BLS12-381 keys are not yet supported");
+            return self.handle_invalid_deploy_result(
+                effect_builder,
+                Box::new(EventMetadata::new(deploy, source, maybe_responder)),
+                Error::InvalidDeployConfiguration(DeployConfigurationFailure::UnsupportedBlsKey {
+                    activation,
+                }),
+                verification_start_timestamp,
+            );
+        }
+
         // We only perform expiry checks on deploys received from the client.
         if source.is_client() && deploy.header().expired(verification_start_timestamp) {
             let time_of_expiry = deploy.header().expires();
@@ -353,6 +477,15 @@ impl DeployAcceptor {
         }
     }
 
+    /// Returns the account hashes of the administrators, whose approvals authorize any account.
+    fn admin_set(&self) -> BTreeSet<AccountHash> {
+        self.core_config
+            .administrators
+            .iter()
+            .map(|public_key| public_key.to_account_hash())
+            .collect()
+    }
+
     fn handle_get_account_result<REv: ReactorEventT>(
         &mut self,
         effect_builder: EffectBuilder<REv>,
@@ -380,30 +513,24 @@ impl DeployAcceptor {
                 )
             }
             Some(account) => {
-                let authorization_keys = event_metadata
-                    .deploy
-                    .approvals()
-                    .iter()
-                    .map(|approval| approval.signer().to_account_hash())
-                    .collect();
-
-                let admin_set: BTreeSet<AccountHash> = {
-                    self.core_config
-                        .administrators
-                        .iter()
-                        .map(|public_key| public_key.to_account_hash())
-                        .collect()
-                };
+                let admin_set = self.admin_set();
+                let mut authorization_keys = event_metadata.deploy.authorization_keys();
+                // The approvals of a deploy with a fee payer include those of the fee payer's
+                // keys, which are checked against the fee payer's account.
+                if event_metadata.deploy.fee_payer().is_some() {
+                    authorization_keys.retain(|key| {
+                        admin_set.contains(key) || account.associated_keys().contains_key(key)
+                    });
+                }
+
                 if admin_set.intersection(&authorization_keys).next().is_some() {
-                    return effect_builder
-                        .check_purse_balance(*block_header.state_root_hash(), account.main_purse())
-                        .event(move |maybe_balance_value| Event::GetBalanceResult {
-                            event_metadata,
-                            maybe_balance_value,
-                            account_hash: account.account_hash(),
-                            verification_start_timestamp,
-                            block_header,
-                        });
+                    return self.verify_payer_balance(
+                        effect_builder,
+                        event_metadata,
+                        block_header,
+                        account,
+                        verification_start_timestamp,
+                    );
                 }
 
                 if !account.can_authorize(&authorization_keys) {
@@ -433,21 +560,139 @@ impl DeployAcceptor {
                         verification_start_timestamp,
                     );
                 }
-                effect_builder
-                    .check_purse_balance(*block_header.state_root_hash(), account.main_purse())
-                    .event(move |maybe_balance_value| Event::GetBalanceResult {
+                self.verify_payer_balance(
+                    effect_builder,
+                    event_metadata,
+                    block_header,
+                    account,
+                    verification_start_timestamp,
+                )
+            }
+        }
+    }
+
+    /// Checks the balance of the account paying for the deploy: the fee payer's if the deploy has
+    /// one, otherwise the deploy's own account.
+    fn verify_payer_balance<REv: ReactorEventT>(
+        &self,
+        effect_builder: EffectBuilder<REv>,
+        event_metadata: Box<EventMetadata>,
+        block_header: Box<BlockHeader>,
+        account: Account,
+        verification_start_timestamp: Timestamp,
+    ) -> Effects<Event> {
+        if let Some(fee_payer) = event_metadata.deploy.fee_payer() {
+            let fee_payer_key = Key::from(fee_payer.to_account_hash());
+            return effect_builder
+                .get_account_from_global_state(*block_header.state_root_hash(), fee_payer_key)
+                .event(
+                    move |maybe_fee_payer_account| Event::GetFeePayerAccountResult {
                         event_metadata,
                         block_header,
-                        maybe_balance_value,
-                        account_hash: account.account_hash(),
+                        account: Box::new(account),
+                        maybe_fee_payer_account,
                         verification_start_timestamp,
-                    })
+                    },
+                );
+        }
+
+        effect_builder
+            .check_purse_balance(*block_header.state_root_hash(), account.main_purse())
+            .event(move |maybe_balance_value| Event::GetBalanceResult {
+                event_metadata,
+                block_header,
+                maybe_balance_value,
+                account_hash: account.account_hash(),
+                verification_start_timestamp,
+            })
+    }
+
+    fn handle_get_fee_payer_account_result<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        event_metadata: Box<EventMetadata>,
+        block_header: Box<BlockHeader>,
+        account: Box<Account>,
+        maybe_fee_payer_account: Option<Account>,
+        verification_start_timestamp: Timestamp,
+    ) -> Effects<Event> {
+        let account_hash = match event_metadata.deploy.fee_payer() {
+            Some(fee_payer) => fee_payer.to_account_hash(),
+            None => {
+                error!("fee payer account checks for a deploy without fee payer");
+                return self.verify_payment_logic(
+                    effect_builder,
+                    event_metadata,
+                    block_header,
+                    verification_start_timestamp,
+                );
             }
+        };
+
+        let fee_payer_account = match maybe_fee_payer_account {
+            Some(fee_payer_account) => fee_payer_account,
+            None => {
+                let error = Error::parameter_failure(
+                    &block_header,
+                    DeployParameterFailure::NonexistentFeePayerAccount { account_hash },
+                );
+                debug!(?account_hash, "nonexistent fee payer account");
+                return self.handle_invalid_deploy_result(
+                    effect_builder,
+                    event_metadata,
+                    error,
+                    verification_start_timestamp,
+                );
+            }
+        };
+
+        // Every approval must authorize the deploy's account or the fee payer, and those of the fee
+        // payer's keys must meet its deploy threshold unless an administrator approved the deploy.
+        let admin_set = self.admin_set();
+        let mut authorization_keys = event_metadata.deploy.authorization_keys();
+        let all_keys_associated = authorization_keys.iter().all(|key| {
+            admin_set.contains(key)
+                || account.associated_keys().contains_key(key)
+                || fee_payer_account.associated_keys().contains_key(key)
+        });
+        authorization_keys.retain(|key| {
+            admin_set.contains(key) || fee_payer_account.associated_keys().contains_key(key)
+        });
+        let approved_by_admin = admin_set.intersection(&authorization_keys).next().is_some();
+        if !all_keys_associated
+            || (!approved_by_admin
+                && (!fee_payer_account.can_authorize(&authorization_keys)
+                    || !fee_payer_account.can_deploy_with(&authorization_keys)))
+        {
+            let error = Error::parameter_failure(
+                &block_header,
+                DeployParameterFailure::InvalidFeePayerAuthorization { account_hash },
+            );
+            debug!(?account_hash, "fee payer authorization invalid");
+            return self.handle_invalid_deploy_result(
+                effect_builder,
+                event_metadata,
+                error,
+                verification_start_timestamp,
+            );
         }
+
+        effect_builder
+            .check_purse_balance(
+                *block_header.state_root_hash(),
+                fee_payer_account.main_purse(),
+            )
+            .event(move |maybe_balance_value| Event::GetBalanceResult {
+                event_metadata,
+                block_header,
+                maybe_balance_value,
+                account_hash,
+                verification_start_timestamp,
+            })
     }
 
     fn handle_get_balance_result<REv: ReactorEventT>(
-        &self,
+        &mut self,
         effect_builder: EffectBuilder<REv>,
         event_metadata: Box<EventMetadata>,
         block_header: Box<BlockHeader>,
@@ -506,7 +751,7 @@ impl DeployAcceptor {
     }
 
     fn verify_payment_logic<REv: ReactorEventT>(
-        &self,
+        &mut self,
         effect_builder: EffectBuilder<REv>,
         event_metadata: Box<EventMetadata>,
         block_header: Box<BlockHeader>,
@@ -626,7 +871,7 @@ impl DeployAcceptor {
     }
 
     fn verify_session_logic<REv: ReactorEventT>(
-        &self,
+        &mut self,
         effect_builder: EffectBuilder<REv>,
         event_metadata: Box<EventMetadata>,
         block_header: Box<BlockHeader>,
@@ -735,7 +980,7 @@ impl DeployAcceptor {
 
     #[allow(clippy::too_many_arguments)]
     fn handle_get_contract_result<REv: ReactorEventT>(
-        &self,
+        &mut self,
         effect_builder: EffectBuilder<REv>,
         event_metadata: Box<EventMetadata>,
         block_header: Box<BlockHeader>,
@@ -794,7 +1039,7 @@ impl DeployAcceptor {
 
     #[allow(clippy::too_many_arguments)]
     fn handle_get_contract_package_result<REv: ReactorEventT>(
-        &self,
+        &mut self,
         effect_builder: EffectBuilder<REv>,
         event_metadata: Box<EventMetadata>,
         block_header: Box<BlockHeader>,
@@ -890,7 +1135,7 @@ impl DeployAcceptor {
     }
 
     fn validate_deploy_cryptography<REv: ReactorEventT>(
-        &self,
+        &mut self,
         effect_builder: EffectBuilder<REv>,
         event_metadata: Box<EventMetadata>,
         verification_start_timestamp: Timestamp,
@@ -929,7 +1174,7 @@ impl DeployAcceptor {
     }
 
     fn handle_invalid_deploy_result<REv: ReactorEventT>(
-        &self,
+        &mut self,
         effect_builder: EffectBuilder<REv>,
         event_metadata: Box<EventMetadata>,
         error: Error,
@@ -943,6 +1188,11 @@ impl DeployAcceptor {
         if !matches!(source, Source::SpeculativeExec(_)) {
             self.metrics.observe_rejected(verification_start_timestamp);
         }
+        // Only rejections of client submissions are recorded, so that peers can't make us report
+        // a deploy as rejected by gossiping an invalid copy of it.
+        if matches!(source, Source::Client) {
+            self.record_rejection(*deploy.hash(), &error);
+        }
         let mut effects = Effects::new();
         if let Some(responder) = maybe_responder {
             // The client has submitted an invalid deploy
@@ -961,6 +1211,23 @@ impl DeployAcceptor {
         effects
     }
 
+    /// Records the rejection of the given client-submitted deploy, evicting the oldest records if
+    /// there are more than `max_rejected_deploys`.
+    fn record_rejection(&mut self, deploy_hash: DeployHash, error: &Error) {
+        let rejection = DeployRejection {
+            rejected_at: Timestamp::now(),
+            error: error.to_string(),
+        };
+        if self.rejections.insert(deploy_hash, rejection).is_none() {
+            self.rejection_order.push_back(deploy_hash);
+        }
+        while self.rejection_order.len() > self.acceptor_config.max_rejected_deploys as usize {
+            if let Some(oldest) = self.rejection_order.pop_front() {
+                self.rejections.remove(&oldest);
+            }
+        }
+    }
+
     fn handle_put_to_storage<REv: ReactorEventT>(
         &self,
         effect_builder: EffectBuilder<REv>,
@@ -1030,6 +1297,205 @@ impl DeployAcceptor {
         }
         effects
     }
+
+    /// Handles receiving a deploy cancellation from a peer or client.
+    ///
+    /// The cancelled deploy, and the replacement if any, must already be held by this node, the
+    /// cancelled deploy must not have expired, and the cancellation's approvals must meet the
+    /// deployment threshold of the deploy's account.
+    fn accept_cancellation<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        cancellation: Box<DeployCancellation>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+    ) -> Effects<Event> {
+        debug!(%cancellation, "checking acceptance");
+        if let Err(failure) = cancellation.is_valid() {
+            return self.handle_invalid_cancellation(
+                cancellation,
+                CancellationFailure::InvalidApprovals(failure),
+                maybe_responder,
+            );
+        }
+
+        let approvals_count = cancellation.approvals().len();
+        if approvals_count > self.max_associated_keys as usize {
+            let failure = CancellationFailure::ExcessiveApprovals {
+                got: approvals_count,
+                max_associated_keys: self.max_associated_keys,
+            };
+            return self.handle_invalid_cancellation(cancellation, failure, maybe_responder);
+        }
+
+        let deploy_hashes = iter::once(*cancellation.deploy_hash())
+            .chain(cancellation.replacement().copied())
+            .collect();
+        effect_builder
+            .get_deploys_from_storage(deploy_hashes)
+            .then(move |deploys| async move {
+                let mut deploys = deploys.into_iter().map(|maybe_deploy| {
+                    maybe_deploy.map(|deploy| Box::new(deploy.discard_finalized_approvals()))
+                });
+                let maybe_deploy = deploys.next().flatten();
+                let maybe_replacement = deploys.next().flatten();
+                let maybe_block_header = effect_builder
+                    .get_highest_complete_block_header_from_storage()
+                    .await
+                    .map(Box::new);
+                (maybe_deploy, maybe_replacement, maybe_block_header)
+            })
+            .event(
+                move |(maybe_deploy, maybe_replacement, maybe_block_header)| {
+                    Event::GetCancelledDeploysResult {
+                        cancellation,
+                        maybe_responder,
+                        maybe_deploy,
+                        maybe_replacement,
+                        maybe_block_header,
+                    }
+                },
+            )
+    }
+
+    fn handle_get_cancelled_deploys_result<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        cancellation: Box<DeployCancellation>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+        maybe_deploy: Option<Box<Deploy>>,
+        maybe_replacement: Option<Box<Deploy>>,
+        maybe_block_header: Option<Box<BlockHeader>>,
+    ) -> Effects<Event> {
+        let deploy = match maybe_deploy {
+            Some(deploy) => deploy,
+            None => {
+                let failure = CancellationFailure::UnknownDeploy {
+                    deploy_hash: *cancellation.deploy_hash(),
+                };
+                return self.handle_invalid_cancellation(cancellation, failure, maybe_responder);
+            }
+        };
+
+        // Cancellations live no longer than the deploys they cancel: once the deploy has expired,
+        // its cancellation is neither honored nor gossiped any further.
+        if deploy.header().expired(Timestamp::now()) {
+            let failure = CancellationFailure::ExpiredDeploy {
+                deploy_hash: *cancellation.deploy_hash(),
+                expired_at: deploy.header().expires(),
+            };
+            return self.handle_invalid_cancellation(cancellation, failure, maybe_responder);
+        }
+
+        if let Some(replacement_hash) = cancellation.replacement() {
+            let failure = match maybe_replacement {
+                None => Some(CancellationFailure::UnknownReplacement {
+                    deploy_hash: *replacement_hash,
+                }),
+                Some(replacement)
+                    if replacement.header().account() != deploy.header().account() =>
+                {
+                    Some(CancellationFailure::ReplacementAccountMismatch {
+                        deploy_hash: *replacement_hash,
+                    })
+                }
+                Some(replacement)
+                    if replacement.header().gas_price() <= deploy.header().gas_price() =>
+                {
+                    Some(CancellationFailure::InsufficientGasPrice {
+                        gas_price: deploy.header().gas_price(),
+                        replacement_gas_price: replacement.header().gas_price(),
+                    })
+                }
+                Some(_) => None,
+            };
+            if let Some(failure) = failure {
+                return self.handle_invalid_cancellation(cancellation, failure, maybe_responder);
+            }
+        }
+
+        let block_header = match maybe_block_header {
+            Some(block_header) => block_header,
+            None => {
+                // this should be unreachable per current design of the system
+                let mut effects = Effects::new();
+                if let Some(responder) = maybe_responder {
+                    effects.extend(responder.respond(Err(Error::EmptyBlockchain)).ignore());
+                }
+                return effects;
+            }
+        };
+
+        let account_key = Key::from(deploy.header().account().to_account_hash());
+        effect_builder
+            .get_account_from_global_state(*block_header.state_root_hash(), account_key)
+            .event(move |maybe_account| Event::GetCancellationAccountResult {
+                cancellation,
+                maybe_responder,
+                account_hash: deploy.header().account().to_account_hash(),
+                maybe_account,
+            })
+    }
+
+    fn handle_get_cancellation_account_result<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        cancellation: Box<DeployCancellation>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+        account_hash: AccountHash,
+        maybe_account: Option<Account>,
+    ) -> Effects<Event> {
+        let account = match maybe_account {
+            Some(account) => account,
+            None => {
+                let failure = CancellationFailure::NonexistentAccount { account_hash };
+                return self.handle_invalid_cancellation(cancellation, failure, maybe_responder);
+            }
+        };
+
+        let authorization_keys = cancellation.authorization_keys();
+        if !account.can_authorize(&authorization_keys) {
+            debug!(?authorization_keys, "account authorization invalid");
+            return self.handle_invalid_cancellation(
+                cancellation,
+                CancellationFailure::InvalidAssociatedKeys,
+                maybe_responder,
+            );
+        }
+        if !account.can_deploy_with(&authorization_keys) {
+            debug!(
+                ?authorization_keys,
+                "insufficient cancellation signature weight"
+            );
+            return self.handle_invalid_cancellation(
+                cancellation,
+                CancellationFailure::InsufficientSignatureWeight,
+                maybe_responder,
+            );
+        }
+
+        let mut effects = effect_builder
+            .announce_deploy_cancellation_accepted(cancellation)
+            .ignore();
+        if let Some(responder) = maybe_responder {
+            effects.extend(responder.respond(Ok(())).ignore());
+        }
+        effects
+    }
+
+    fn handle_invalid_cancellation(
+        &self,
+        cancellation: Box<DeployCancellation>,
+        failure: CancellationFailure,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+    ) -> Effects<Event> {
+        debug!(%cancellation, %failure, "rejected deploy cancellation");
+        match maybe_responder {
+            Some(responder) => responder
+                .respond(Err(Error::InvalidCancellation(failure)))
+                .ignore(),
+            None => Effects::new(),
+        }
+    }
 }
 
 impl<REv: ReactorEventT> Component<REv> for DeployAcceptor {
@@ -1048,6 +1514,46 @@ impl<REv: ReactorEventT> Component<REv> for DeployAcceptor {
                 source,
                 maybe_responder: responder,
             } => self.accept(effect_builder, deploy, source, responder),
+            Event::Request(DeployAcceptorRequest::GetRejection {
+                deploy_hash,
+                responder,
+            }) => responder
+                .respond(self.rejections.get(&deploy_hash).cloned())
+                .ignore(),
+            Event::Request(DeployAcceptorRequest::AcceptCancellation {
+                cancellation,
+                responder,
+            }) => self.accept_cancellation(effect_builder, cancellation, Some(responder)),
+            Event::AcceptCancellation {
+                cancellation,
+                maybe_responder,
+            } => self.accept_cancellation(effect_builder, cancellation, maybe_responder),
+            Event::GetCancelledDeploysResult {
+                cancellation,
+                maybe_responder,
+                maybe_deploy,
+                maybe_replacement,
+                maybe_block_header,
+            } => self.handle_get_cancelled_deploys_result(
+                effect_builder,
+                cancellation,
+                maybe_responder,
+                maybe_deploy,
+                maybe_replacement,
+                maybe_block_header,
+            ),
+            Event::GetCancellationAccountResult {
+                cancellation,
+                maybe_responder,
+                account_hash,
+                maybe_account,
+            } => self.handle_get_cancellation_account_result(
+                effect_builder,
+                cancellation,
+                maybe_responder,
+                account_hash,
+                maybe_account,
+            ),
             Event::GetBlockHeaderResult {
                 event_metadata,
                 maybe_block_header,
@@ -1070,6 +1576,20 @@ impl<REv: ReactorEventT> Component<REv> for DeployAcceptor {
                 maybe_account,
                 verification_start_timestamp,
             ),
+            Event::GetFeePayerAccountResult {
+                event_metadata,
+                block_header,
+                account,
+                maybe_fee_payer_account,
+                verification_start_timestamp,
+            } => self.handle_get_fee_payer_account_result(
+                effect_builder,
+                event_metadata,
+                block_header,
+                account,
+                maybe_fee_payer_account,
+                verification_start_timestamp,
+            ),
             Event::GetBalanceResult {
                 event_metadata,
                 block_header,
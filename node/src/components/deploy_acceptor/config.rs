@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use casper_types::TimeDiff;
 
 const DEFAULT_TIMESTAMP_LEEWAY: &str = "2sec";
+const DEFAULT_MAX_REJECTED_DEPLOYS: u32 = 10_000;
 
 /// Configuration options for accepting deploys.
 #[derive(Copy, Clone, Serialize, Deserialize, Debug, DataSize)]
@@ -18,12 +19,21 @@ pub struct Config {
     /// The maximum value to which `timestamp_leeway` can be set is defined by the chainspec
     /// setting `deploys.max_timestamp_leeway`.
     pub timestamp_leeway: TimeDiff,
+    /// The maximum number of recently rejected deploys to remember, in order to report their
+    /// status.
+    #[serde(default = "default_max_rejected_deploys")]
+    pub max_rejected_deploys: u32,
+}
+
+fn default_max_rejected_deploys() -> u32 {
+    DEFAULT_MAX_REJECTED_DEPLOYS
 }
 
 impl Default for Config {
     fn default() -> Self {
         Config {
             timestamp_leeway: TimeDiff::from_str(DEFAULT_TIMESTAMP_LEEWAY).unwrap(),
+            max_rejected_deploys: DEFAULT_MAX_REJECTED_DEPLOYS,
         }
     }
 }
@@ -3,6 +3,7 @@ use std::{
     sync::Arc,
 };
 
+use derive_more::From;
 use serde::Serialize;
 
 use casper_types::{
@@ -13,8 +14,8 @@ use casper_types::{
 use super::Source;
 use crate::{
     components::deploy_acceptor::Error,
-    effect::Responder,
-    types::{BlockHeader, Deploy},
+    effect::{requests::DeployAcceptorRequest, Responder},
+    types::{BlockHeader, Deploy, DeployCancellation},
 };
 
 /// A utility struct to hold duplicated information across events.
@@ -40,7 +41,7 @@ impl EventMetadata {
 }
 
 /// `DeployAcceptor` events.
-#[derive(Debug, Serialize)]
+#[derive(Debug, From, Serialize)]
 pub(crate) enum Event {
     /// The initiating event to accept a new `Deploy`.
     Accept {
@@ -48,6 +49,9 @@ pub(crate) enum Event {
         source: Source,
         maybe_responder: Option<Responder<Result<(), Error>>>,
     },
+    /// A request made of the `DeployAcceptor`.
+    #[from]
+    Request(DeployAcceptorRequest),
     /// The result of the `DeployAcceptor` putting a `Deploy` to the storage component.
     PutToStorageResult {
         event_metadata: Box<EventMetadata>,
@@ -74,7 +78,15 @@ pub(crate) enum Event {
         maybe_account: Option<Account>,
         verification_start_timestamp: Timestamp,
     },
-    /// The result of querying the balance of the `Account` associated with the `Deploy`.
+    /// The result of querying global state for the `Account` paying for a sponsored `Deploy`.
+    GetFeePayerAccountResult {
+        event_metadata: Box<EventMetadata>,
+        block_header: Box<BlockHeader>,
+        account: Box<Account>,
+        maybe_fee_payer_account: Option<Account>,
+        verification_start_timestamp: Timestamp,
+    },
+    /// The result of querying the balance of the `Account` paying for the `Deploy`.
     GetBalanceResult {
         event_metadata: Box<EventMetadata>,
         block_header: Box<BlockHeader>,
@@ -101,6 +113,28 @@ pub(crate) enum Event {
         maybe_contract_package: Option<Box<ContractPackage>>,
         verification_start_timestamp: Timestamp,
     },
+    /// The initiating event to accept a new `DeployCancellation`.
+    AcceptCancellation {
+        cancellation: Box<DeployCancellation>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+    },
+    /// The result of querying the storage component for the cancelled and replacement `Deploy`s,
+    /// and for the highest available `BlockHeader`.
+    GetCancelledDeploysResult {
+        cancellation: Box<DeployCancellation>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+        maybe_deploy: Option<Box<Deploy>>,
+        maybe_replacement: Option<Box<Deploy>>,
+        maybe_block_header: Option<Box<BlockHeader>>,
+    },
+    /// The result of querying global state for the `Account` associated with the cancelled
+    /// `Deploy`.
+    GetCancellationAccountResult {
+        cancellation: Box<DeployCancellation>,
+        maybe_responder: Option<Responder<Result<(), Error>>>,
+        account_hash: AccountHash,
+        maybe_account: Option<Account>,
+    },
 }
 
 impl Display for Event {
@@ -109,6 +143,7 @@ impl Display for Event {
             Event::Accept { deploy, source, .. } => {
                 write!(formatter, "accept {} from {}", deploy.hash(), source)
             }
+            Event::Request(request) => write!(formatter, "{}", request),
             Event::PutToStorageResult {
                 event_metadata,
                 is_new,
@@ -161,6 +196,13 @@ impl Display for Event {
                     event_metadata.deploy.hash()
                 )
             }
+            Event::GetFeePayerAccountResult { event_metadata, .. } => {
+                write!(
+                    formatter,
+                    "verifying fee payer account to validate deploy with hash {}",
+                    event_metadata.deploy.hash()
+                )
+            }
             Event::GetBalanceResult { event_metadata, .. } => {
                 write!(
                     formatter,
@@ -192,6 +234,19 @@ impl Display for Event {
                     block_header.state_root_hash()
                 )
             }
+            Event::AcceptCancellation { cancellation, .. } => {
+                write!(formatter, "accept {}", cancellation)
+            }
+            Event::GetCancelledDeploysResult { cancellation, .. } => {
+                write!(
+                    formatter,
+                    "received deploys from storage to validate {}",
+                    cancellation
+                )
+            }
+            Event::GetCancellationAccountResult { cancellation, .. } => {
+                write!(formatter, "verifying account to validate {}", cancellation)
+            }
         }
     }
 }
@@ -13,6 +13,7 @@ use futures::{
     FutureExt,
 };
 use prometheus::Registry;
+use rand::Rng;
 use reactor::ReactorEvent;
 use serde::Serialize;
 use tempfile::TempDir;
@@ -37,7 +38,9 @@ use crate::{
         storage::{self, Storage},
     },
     effect::{
-        announcements::{ControlAnnouncement, DeployAcceptorAnnouncement},
+        announcements::{
+            ControlAnnouncement, DeployAcceptorAnnouncement, DeployCancellationAnnouncement,
+        },
         requests::{
             ContractRuntimeRequest, MakeBlockExecutableRequest, MarkBlockCompletedRequest,
             NetworkRequest,
@@ -57,6 +60,7 @@ const POLL_INTERVAL: Duration = Duration::from_millis(10);
 const TIMEOUT: Duration = Duration::from_secs(10);
 const ALICE_SECRET_KEY_BYTES: [u8; 32] = [123; 32];
 const BOB_SECRET_KEY_BYTES: [u8; 32] = [124; 32];
+const CAROL_SECRET_KEY_BYTES: [u8; 32] = [125; 32];
 
 /// Top-level event for the reactor.
 #[derive(Debug, From, Serialize)]
@@ -74,6 +78,8 @@ enum Event {
     #[from]
     DeployAcceptorAnnouncement(#[serde(skip_serializing)] DeployAcceptorAnnouncement),
     #[from]
+    DeployCancellationAnnouncement(#[serde(skip_serializing)] DeployCancellationAnnouncement),
+    #[from]
     ContractRuntime(#[serde(skip_serializing)] ContractRuntimeRequest),
     #[from]
     StorageRequest(StorageRequest),
@@ -119,6 +125,9 @@ impl Display for Event {
             Event::DeployAcceptorAnnouncement(ann) => {
                 write!(formatter, "deploy-acceptor announcement: {}", ann)
             }
+            Event::DeployCancellationAnnouncement(ann) => {
+                write!(formatter, "deploy-cancellation announcement: {}", ann)
+            }
 
             Event::ContractRuntime(event) => {
                 write!(formatter, "contract-runtime event: {:?}", event)
@@ -186,6 +195,12 @@ enum TestScenario {
     DeployWithoutTransferTarget,
     DeployWithoutTransferAmount,
     BalanceCheckForDeploySentByPeer,
+    FromClientValidSponsoredDeploy,
+    FromClientSelfSponsoredDeploy,
+    FromClientMissingFeePayerAccount,
+    FromClientValidSequencedDeploy,
+    FromClientSequencedDeployBeforeActivation,
+    FromClientBlsSignedDeployBeforeActivation,
     ShouldNotAcceptExpiredDeploySentByClient,
     ShouldAcceptExpiredDeploySentByPeer,
     ShouldAcceptDeployFromAdministrator {
@@ -237,6 +252,12 @@ impl TestScenario {
             | TestScenario::DeployWithEmptySessionModuleBytes
             | TestScenario::DeployWithNativeTransferInPayment
             | TestScenario::ShouldNotAcceptExpiredDeploySentByClient
+            | TestScenario::FromClientValidSponsoredDeploy
+            | TestScenario::FromClientSelfSponsoredDeploy
+            | TestScenario::FromClientMissingFeePayerAccount
+            | TestScenario::FromClientValidSequencedDeploy
+            | TestScenario::FromClientSequencedDeployBeforeActivation
+            | TestScenario::FromClientBlsSignedDeployBeforeActivation
             | TestScenario::ShouldAcceptDeployFromAdministrator { .. }
             | TestScenario::ShouldRejectDeployFromNonAdministrator { .. } => Source::Client,
         }
@@ -358,6 +379,48 @@ impl TestScenario {
 
                 deploy
             }
+            TestScenario::FromClientValidSponsoredDeploy
+            | TestScenario::FromClientMissingFeePayerAccount => {
+                let fee_payer = SecretKey::ed25519_from_bytes(CAROL_SECRET_KEY_BYTES)
+                    .expect("should create secret key");
+                Deploy::random_sponsored(rng, &fee_payer)
+            }
+            TestScenario::FromClientSelfSponsoredDeploy => {
+                let secret_key = SecretKey::ed25519_from_bytes(CAROL_SECRET_KEY_BYTES)
+                    .expect("should create secret key");
+                let deploy = Deploy::random_sponsored(rng, &secret_key);
+                Deploy::new_sponsored(
+                    deploy.header().timestamp(),
+                    deploy.header().ttl(),
+                    deploy.header().gas_price(),
+                    deploy.header().dependencies().clone(),
+                    deploy.header().chain_name().to_string(),
+                    deploy.payment().clone(),
+                    deploy.session().clone(),
+                    &secret_key,
+                    None,
+                    PublicKey::from(&secret_key),
+                )
+            }
+            TestScenario::FromClientValidSequencedDeploy
+            | TestScenario::FromClientSequencedDeployBeforeActivation => {
+                let sequence_number = rng.gen();
+                Deploy::random_sequenced(rng, sequence_number)
+            }
+            TestScenario::FromClientBlsSignedDeployBeforeActivation => {
+                let deploy = Deploy::random_valid_native_transfer(rng);
+                Deploy::new(
+                    deploy.header().timestamp(),
+                    deploy.header().ttl(),
+                    deploy.header().gas_price(),
+                    vec![],
+                    deploy.header().chain_name().to_string(),
+                    deploy.payment().clone(),
+                    deploy.session().clone(),
+                    &SecretKey::random_bls(rng),
+                    None,
+                )
+            }
             TestScenario::FromClientSlightlyFutureDatedDeploy => {
                 let timestamp = Timestamp::now() + (Config::default().timestamp_leeway / 2);
                 let ttl = TimeDiff::from_seconds(300);
@@ -383,6 +446,9 @@ impl TestScenario {
             | TestScenario::FromClientRepeatedValidDeploy
             | TestScenario::FromClientValidDeploy
             | TestScenario::FromClientSlightlyFutureDatedDeploy
+            | TestScenario::FromClientValidSponsoredDeploy
+            | TestScenario::FromClientSelfSponsoredDeploy
+            | TestScenario::FromClientValidSequencedDeploy
             | TestScenario::ShouldAcceptExpiredDeploySentByPeer=> true,
             TestScenario::FromPeerInvalidDeploy
             | TestScenario::FromClientInsufficientBalance
@@ -400,6 +466,9 @@ impl TestScenario {
             | TestScenario::DeployWithoutTransferAmount
             | TestScenario::DeployWithoutTransferTarget
             | TestScenario::BalanceCheckForDeploySentByPeer
+            | TestScenario::FromClientMissingFeePayerAccount
+            | TestScenario::FromClientSequencedDeployBeforeActivation
+            | TestScenario::FromClientBlsSignedDeployBeforeActivation
             | TestScenario::ShouldNotAcceptExpiredDeploySentByClient => false,
             TestScenario::FromPeerCustomPaymentContract(contract_scenario)
             | TestScenario::FromPeerSessionContract(contract_scenario)
@@ -456,6 +525,44 @@ impl TestScenario {
 
                 Arc::new(chainspec)
             }
+            TestScenario::FromClientSequencedDeployBeforeActivation => {
+                let mut chainspec = Chainspec {
+                    protocol_config: chainspec.protocol_config.clone(),
+                    network_config: chainspec.network_config.clone(),
+                    core_config: chainspec.core_config.clone(),
+                    highway_config: chainspec.highway_config,
+                    deploy_config: chainspec.deploy_config,
+                    wasm_config: chainspec.wasm_config,
+                    system_costs_config: chainspec.system_costs_config,
+                };
+
+                let current = chainspec.protocol_version();
+                chainspec
+                    .core_config
+                    .start_protocol_version_with_deploy_sequence_numbers =
+                    ProtocolVersion::from_parts(current.value().major + 1, 0, 0);
+
+                Arc::new(chainspec)
+            }
+            TestScenario::FromClientBlsSignedDeployBeforeActivation => {
+                let mut chainspec = Chainspec {
+                    protocol_config: chainspec.protocol_config.clone(),
+                    network_config: chainspec.network_config.clone(),
+                    core_config: chainspec.core_config.clone(),
+                    highway_config: chainspec.highway_config,
+                    deploy_config: chainspec.deploy_config,
+                    wasm_config: chainspec.wasm_config,
+                    system_costs_config: chainspec.system_costs_config,
+                };
+
+                let current = chainspec.protocol_version();
+                chainspec
+                    .core_config
+                    .start_protocol_version_with_aggregate_finality_signatures =
+                    ProtocolVersion::from_parts(current.value().major + 1, 0, 0);
+
+                Arc::new(chainspec)
+            }
             _ => chainspec,
         }
     }
@@ -486,6 +593,12 @@ fn create_account(account_hash: AccountHash, test_scenario: &TestScenario) -> Ac
     }
 }
 
+fn fee_payer_key() -> Key {
+    let fee_payer =
+        SecretKey::ed25519_from_bytes(CAROL_SECRET_KEY_BYTES).expect("should create secret key");
+    Key::from(PublicKey::from(&fee_payer).to_account_hash())
+}
+
 struct Reactor {
     storage: Storage,
     deploy_acceptor: DeployAcceptor,
@@ -525,6 +638,7 @@ impl reactor::Reactor for Reactor {
             "test",
             chainspec.deploy_config.max_ttl.into(),
             chainspec.core_config.recent_era_count(),
+            ProtocolVersion::V1_0_0,
             Some(registry),
             false,
         )
@@ -568,7 +682,7 @@ impl reactor::Reactor for Reactor {
             Event::FatalAnnouncement(fatal_ann) => {
                 panic!("unhandled fatal announcement: {}", fatal_ann)
             }
-            Event::DeployAcceptorAnnouncement(_) => {
+            Event::DeployAcceptorAnnouncement(_) | Event::DeployCancellationAnnouncement(_) => {
                 // We do not care about deploy acceptor announcements in the acceptor tests.
                 Effects::new()
             }
@@ -580,6 +694,8 @@ impl reactor::Reactor for Reactor {
                     let query_result = if self.test_scenario
                         == TestScenario::FromClientMissingAccount
                         || self.test_scenario == TestScenario::FromPeerMissingAccount
+                        || (self.test_scenario == TestScenario::FromClientMissingFeePayerAccount
+                            && query_request.key() == fee_payer_key())
                     {
                         QueryResult::ValueNotFound(String::new())
                     } else if let Key::Account(account_hash) = query_request.key() {
@@ -898,6 +1014,9 @@ async fn run_deploy_acceptor_without_timeout(
             | TestScenario::DeployWithMangledTransferAmount
             | TestScenario::DeployWithoutTransferTarget
             | TestScenario::DeployWithoutTransferAmount
+            | TestScenario::FromClientMissingFeePayerAccount
+            | TestScenario::FromClientSequencedDeployBeforeActivation
+            | TestScenario::FromClientBlsSignedDeployBeforeActivation
             | TestScenario::ShouldNotAcceptExpiredDeploySentByClient => {
                 matches!(
                     event,
@@ -992,7 +1111,10 @@ async fn run_deploy_acceptor_without_timeout(
             // Check that a, new and valid, deploy sent by a client raises an `AcceptedNewDeploy`
             // announcement with the appropriate source.
             TestScenario::FromClientValidDeploy
-            | TestScenario::FromClientSlightlyFutureDatedDeploy => {
+            | TestScenario::FromClientSlightlyFutureDatedDeploy
+            | TestScenario::FromClientValidSponsoredDeploy
+            | TestScenario::FromClientSelfSponsoredDeploy
+            | TestScenario::FromClientValidSequencedDeploy => {
                 matches!(
                     event,
                     Event::DeployAcceptorAnnouncement(
@@ -1709,3 +1831,55 @@ async fn should_reject_valid_deploy_from_peer_signed_by_non_administrator() {
     let result = run_deploy_acceptor(test_scenario).await;
     assert!(result.is_err())
 }
+
+#[tokio::test]
+async fn should_accept_valid_sponsored_deploy_from_client() {
+    let result = run_deploy_acceptor(TestScenario::FromClientValidSponsoredDeploy).await;
+    assert!(result.is_ok())
+}
+
+#[tokio::test]
+async fn should_accept_sponsored_deploy_paid_by_its_own_account_from_client() {
+    let result = run_deploy_acceptor(TestScenario::FromClientSelfSponsoredDeploy).await;
+    assert!(result.is_ok())
+}
+
+#[tokio::test]
+async fn should_reject_sponsored_deploy_from_client_for_missing_fee_payer_account() {
+    let result = run_deploy_acceptor(TestScenario::FromClientMissingFeePayerAccount).await;
+    assert!(matches!(
+        result,
+        Err(super::Error::InvalidDeployParameters {
+            failure: DeployParameterFailure::NonexistentFeePayerAccount { .. },
+            ..
+        })
+    ))
+}
+
+#[tokio::test]
+async fn should_accept_valid_sequenced_deploy_from_client() {
+    let result = run_deploy_acceptor(TestScenario::FromClientValidSequencedDeploy).await;
+    assert!(result.is_ok())
+}
+
+#[tokio::test]
+async fn should_reject_sequenced_deploy_from_client_before_activation() {
+    let result = run_deploy_acceptor(TestScenario::FromClientSequencedDeployBeforeActivation).await;
+    assert!(matches!(
+        result,
+        Err(super::Error::InvalidDeployConfiguration(
+            DeployConfigurationFailure::UnsupportedSequenceNumber { .. }
+        ))
+    ))
+}
+
+#[tokio::test]
+async fn should_reject_bls_signed_deploy_from_client_before_activation() {
+    let result = run_deploy_acceptor(TestScenario::FromClientBlsSignedDeployBeforeActivation).await;
+    assert!(matches!(
+        result,
+        Err(super::Error::InvalidDeployConfiguration(
+            DeployConfigurationFailure::UnsupportedBlsKey { .. }
+        ))
+    ))
+}
@@ -1,4 +1,5 @@
 mod config;
+mod debug;
 mod event;
 mod metrics;
 #[cfg(test)]
@@ -7,7 +8,7 @@ mod tests;
 use std::{
     collections::{btree_map, BTreeMap, BTreeSet, HashMap, HashSet},
     convert::TryInto,
-    iter::FromIterator,
+    iter::{self, FromIterator},
     mem,
 };
 
@@ -18,7 +19,8 @@ use prometheus::Registry;
 use smallvec::smallvec;
 use tracing::{debug, error, info, warn};
 
-use casper_types::Timestamp;
+use casper_hashing::Digest;
+use casper_types::{Key, PublicKey, Timestamp};
 
 use crate::{
     components::{
@@ -27,7 +29,7 @@ use crate::{
     },
     effect::{
         announcements::DeployBufferAnnouncement,
-        requests::{DeployBufferRequest, StorageRequest},
+        requests::{ContractRuntimeRequest, DeployBufferRequest, StorageRequest},
         EffectBuilder, EffectExt, Effects,
     },
     fatal,
@@ -36,26 +38,46 @@ use crate::{
     types::{
         appendable_block::{AddError, AppendableBlock},
         chainspec::DeployConfig,
-        Approval, Block, Deploy, DeployFootprint, DeployHash, DeployHashWithApprovals, DeployId,
-        FinalizedBlock,
+        Approval, Block, Deploy, DeployCancellation, DeployFootprint, DeployHash,
+        DeployHashWithApprovals, DeployId, FinalizedBlock,
     },
     utils::DisplayIter,
     NodeRng,
 };
 pub(crate) use config::Config;
+pub(crate) use debug::DeployBufferDump;
 pub(crate) use event::Event;
 
+use debug::BufferedDeployDump;
 use metrics::Metrics;
 
 const COMPONENT_NAME: &str = "deploy_buffer";
 
 type FootprintAndApprovals = (DeployFootprint, BTreeSet<Approval>);
 
+/// The status of a deploy known to the `DeployBuffer`.
+#[derive(Clone, Copy, DataSize, Debug, PartialEq, Eq)]
+pub(crate) enum DeployBufferStatus {
+    /// The deploy is awaiting inclusion in a proposed block.
+    Pending { expires_at: Timestamp },
+    /// The deploy is held, having been included in a block proposed at `proposed_at`.
+    Held {
+        proposed_at: Timestamp,
+        expires_at: Timestamp,
+    },
+    /// The deploy has been included in a finalized block.
+    Finalized,
+    /// The deploy will not be proposed, e.g. as it expired or became invalid while buffered.
+    Dead { expires_at: Timestamp },
+}
+
 #[derive(DataSize, Debug)]
 pub(crate) struct DeployBuffer {
     state: ComponentState,
     cfg: Config,
     deploy_config: DeployConfig,
+    // whether deploys are only proposed once their dependencies have been executed
+    dependencies_enforced: bool,
     // Keeps track of all deploys the buffer is currently aware of.
     //
     // `hold` and `dead` are used to filter it on demand as necessary.
@@ -72,6 +94,19 @@ pub(crate) struct DeployBuffer {
     hold: BTreeMap<Timestamp, HashSet<DeployHash>>,
     // deploy_hashes that should not be proposed, ever
     dead: HashSet<DeployHash>,
+    // deploy_hashes known to have been executed, either by being included in a finalized block or
+    // as reported by storage, mapped to the timestamp of the executing block; deploys are only
+    // proposed once all their dependencies are in here within `max_ttl` of the proposed block, or
+    // earlier in the same proposed block
+    executed: HashMap<DeployHash, Timestamp>,
+    // height and state root hash of the highest executed block known to the buffer, against which
+    // the sequence numbers of accounts are read
+    state_root: Option<(u64, Digest)>,
+    // the next sequence number expected by global state for accounts of buffered sequenced
+    // deploys, along with the height of the block it was read at; a sequenced deploy is only
+    // proposed if its sequence number is the next expected one, or directly follows the sequence
+    // number of a deploy earlier in the same proposed block
+    next_sequence_numbers: HashMap<PublicKey, (u64, u64)>,
     // deploy buffer metrics
     #[data_size(skip)]
     metrics: Metrics,
@@ -81,6 +116,7 @@ impl DeployBuffer {
     /// Create a deploy buffer for fun and profit.
     pub(crate) fn new(
         deploy_config: DeployConfig,
+        dependencies_enforced: bool,
         cfg: Config,
         registry: &Registry,
     ) -> Result<Self, prometheus::Error> {
@@ -88,13 +124,22 @@ impl DeployBuffer {
             state: ComponentState::Uninitialized,
             cfg,
             deploy_config,
+            dependencies_enforced,
             buffer: HashMap::new(),
             hold: BTreeMap::new(),
             dead: HashSet::new(),
+            executed: HashMap::new(),
+            state_root: None,
+            next_sequence_numbers: HashMap::new(),
             metrics: Metrics::new(registry)?,
         })
     }
 
+    /// Replaces the configuration, taking effect from the next expiry check on.
+    pub(crate) fn set_config(&mut self, cfg: Config) {
+        self.cfg = cfg;
+    }
+
     pub(crate) fn initialize_component(
         &mut self,
         effect_builder: EffectBuilder<MainEvent>,
@@ -173,6 +218,24 @@ impl DeployBuffer {
             .retain(|deploy_hash| freed.remove(deploy_hash).is_none());
         self.buffer = buffer;
 
+        // only keep track of executed deploys which are still buffered or are dependencies of
+        // buffered deploys
+        let dependencies: HashSet<DeployHash> = self
+            .buffer
+            .values()
+            .filter_map(|(_, maybe_data)| maybe_data.as_ref())
+            .flat_map(|(footprint, _)| footprint.header.dependencies().iter().copied())
+            .collect();
+        let buffer = &self.buffer;
+        self.executed.retain(|deploy_hash, _| {
+            buffer.contains_key(deploy_hash) || dependencies.contains(deploy_hash)
+        });
+
+        // only keep track of the sequence numbers of accounts with buffered sequenced deploys
+        let sequenced_accounts = self.sequenced_accounts(self.buffer.keys());
+        self.next_sequence_numbers
+            .retain(|account, _| sequenced_accounts.contains(account));
+
         if !freed.is_empty() {
             info!(
                 "DeployBuffer: expiring without executing {} deploy(s)",
@@ -210,6 +273,74 @@ impl DeployBuffer {
             .event(move |result| Event::StoredDeploy(deploy_id, result.map(Box::new)))
     }
 
+    /// Returns the dependencies of the given deploy not yet known to have been executed.
+    fn unknown_dependencies(&self, deploy: &Deploy) -> HashSet<DeployHash> {
+        deploy
+            .header()
+            .dependencies()
+            .iter()
+            .filter(|dependency| !self.executed.contains_key(dependency))
+            .copied()
+            .collect()
+    }
+
+    /// Returns the accounts of those of the given deploys which are buffered and sequenced.
+    fn sequenced_accounts<'a>(
+        &self,
+        deploy_hashes: impl Iterator<Item = &'a DeployHash>,
+    ) -> HashSet<PublicKey> {
+        deploy_hashes
+            .filter_map(|deploy_hash| match self.buffer.get(deploy_hash) {
+                Some((_, Some((footprint, _)))) => footprint
+                    .header
+                    .sequence_number()
+                    .map(|_| footprint.header.account().clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Reads the next sequence numbers expected for the given accounts from global state as of the
+    /// highest executed block known to the buffer.
+    fn read_next_sequence_numbers<REv>(
+        &self,
+        effect_builder: EffectBuilder<REv>,
+        accounts: HashSet<PublicKey>,
+    ) -> Effects<Event>
+    where
+        REv: From<ContractRuntimeRequest> + Send,
+    {
+        let (block_height, state_root_hash) = match self.state_root {
+            Some(state_root) => state_root,
+            None => return Effects::new(),
+        };
+        accounts
+            .into_iter()
+            .flat_map(|account| {
+                let account_key = Key::Account(account.to_account_hash());
+                effect_builder
+                    .get_account_from_global_state(state_root_hash, account_key)
+                    .event(move |maybe_account| Event::NextSequenceNumber {
+                        account,
+                        block_height,
+                        next: maybe_account.map_or(0, |account| account.sequence_number()),
+                    })
+            })
+            .collect()
+    }
+
+    /// Records the next sequence number expected for the account, unless one read at a higher
+    /// block is already known.
+    fn register_next_sequence_number(&mut self, account: PublicKey, block_height: u64, next: u64) {
+        match self.next_sequence_numbers.get(&account) {
+            Some((read_at, _)) if *read_at > block_height => {}
+            _ => {
+                self.next_sequence_numbers
+                    .insert(account, (block_height, next));
+            }
+        }
+    }
+
     /// Update buffer considering new stored deploy.
     fn register_deploy(&mut self, deploy: Deploy) {
         let deploy_hash = deploy.hash();
@@ -248,6 +379,22 @@ impl DeployBuffer {
         }
     }
 
+    /// Marks the deploy named by the cancellation dead, unless it is not buffered, or is already
+    /// executed or dead.  Returns `true` if the cancellation was honored.
+    fn register_cancellation(&mut self, cancellation: &DeployCancellation) -> bool {
+        let deploy_hash = cancellation.deploy_hash();
+        if !self.buffer.contains_key(deploy_hash) {
+            debug!(%deploy_hash, "DeployBuffer: attempt to cancel unknown deploy");
+            return false;
+        }
+        if self.executed.contains_key(deploy_hash) || !self.dead.insert(*deploy_hash) {
+            info!(%deploy_hash, "DeployBuffer: attempt to cancel executed or dead deploy");
+            return false;
+        }
+        debug!(%deploy_hash, "DeployBuffer: deploy cancelled");
+        true
+    }
+
     /// Update holds considering new proposed block.
     fn register_block_proposed(&mut self, proposed_block: ProposedBlock<ClContext>) {
         let timestamp = &proposed_block.context().timestamp();
@@ -283,6 +430,7 @@ impl DeployBuffer {
                 self.buffer.insert(*deploy_hash, (expiry_timestamp, None));
             }
             self.dead.insert(*deploy_hash);
+            self.executed.insert(*deploy_hash, timestamp);
         }
         // deploys held for proposed blocks which did not get finalized in time are eligible again
         let (hold, _) = mem::take(&mut self.hold)
@@ -297,6 +445,12 @@ impl DeployBuffer {
         let block_height = block.header().height();
         let timestamp = block.timestamp();
         debug!(%timestamp, "DeployBuffer: register_block({}) timestamp finalized", block_height);
+        if self
+            .state_root
+            .map_or(true, |(highest_height, _)| highest_height < block_height)
+        {
+            self.state_root = Some((block_height, *block.state_root_hash()));
+        }
         self.register_deploys(timestamp, block.deploy_and_transfer_hashes());
     }
 
@@ -326,87 +480,217 @@ impl DeployBuffer {
             .collect()
     }
 
+    /// Returns the status of the given deploy, or `None` if it is not buffered.
+    fn deploy_status(&self, deploy_hash: &DeployHash) -> Option<DeployBufferStatus> {
+        let (expires_at, _) = self.buffer.get(deploy_hash)?;
+        let expires_at = *expires_at;
+        if self.executed.contains_key(deploy_hash) {
+            return Some(DeployBufferStatus::Finalized);
+        }
+        if self.dead.contains(deploy_hash) {
+            return Some(DeployBufferStatus::Dead { expires_at });
+        }
+        let status = self
+            .hold
+            .iter()
+            .find(|(_, held_deploys)| held_deploys.contains(deploy_hash))
+            .map_or(
+                DeployBufferStatus::Pending { expires_at },
+                |(proposed_at, _)| DeployBufferStatus::Held {
+                    proposed_at: *proposed_at,
+                    expires_at,
+                },
+            );
+        Some(status)
+    }
+
+    /// Returns a debug dump of the buffer's contents.
+    fn dump(&self) -> DeployBufferDump {
+        let buffer = self
+            .buffer
+            .iter()
+            .map(
+                |(deploy_hash, (expires_at, maybe_data))| BufferedDeployDump {
+                    deploy_hash: *deploy_hash,
+                    expires_at: *expires_at,
+                    footprint: maybe_data.as_ref().map(|(footprint, _)| footprint.clone()),
+                },
+            )
+            .sorted_by_key(|buffered| buffered.deploy_hash)
+            .collect();
+        let hold = self
+            .hold
+            .iter()
+            .map(|(proposed_at, deploy_hashes)| {
+                (
+                    *proposed_at,
+                    deploy_hashes.iter().copied().sorted().collect(),
+                )
+            })
+            .collect();
+        let dead = self.dead.iter().copied().sorted().collect();
+        DeployBufferDump { buffer, hold, dead }
+    }
+
+    /// Returns `true` if all dependencies of the deploy have been executed within `max_ttl` of the
+    /// given block, or will be executed earlier in it.
+    ///
+    /// Always `true` before the protocol version from which dependencies are enforced.
+    fn dependencies_met(&self, footprint: &DeployFootprint, block: &AppendableBlock) -> bool {
+        if !self.dependencies_enforced {
+            return true;
+        }
+        let window_start = block.timestamp().saturating_sub(self.deploy_config.max_ttl);
+        footprint.header.dependencies().iter().all(|dependency| {
+            self.executed
+                .get(dependency)
+                .map_or(false, |executed_at| *executed_at >= window_start)
+                || block.executes_before_next(dependency, footprint.is_transfer)
+        })
+    }
+
+    /// Returns `true` if the deploy is not sequenced, if its sequence number is the next one
+    /// expected by global state for its account, or if the proposable deploy of the same account
+    /// with the preceding sequence number will be executed earlier in the given block.
+    fn sequence_met(
+        &self,
+        footprint: &DeployFootprint,
+        block: &AppendableBlock,
+        sequenced: &HashMap<PublicKey, BTreeMap<u64, DeployHash>>,
+    ) -> bool {
+        let sequence_number = match footprint.header.sequence_number() {
+            Some(sequence_number) => sequence_number,
+            None => return true,
+        };
+        let account = footprint.header.account();
+        if self
+            .next_sequence_numbers
+            .get(account)
+            .map_or(false, |(_, next)| *next == sequence_number)
+        {
+            return true;
+        }
+        sequence_number
+            .checked_sub(1)
+            .and_then(|previous| sequenced.get(account)?.get(&previous))
+            .map_or(false, |deploy_hash| {
+                block.executes_before_next(deploy_hash, footprint.is_transfer)
+            })
+    }
+
     /// Returns a right-sized payload of deploys that can be proposed.
     fn appendable_block(&mut self, timestamp: Timestamp) -> AppendableBlock {
         let mut ret = AppendableBlock::new(self.deploy_config, timestamp);
         let mut holds = HashSet::new();
         let mut have_hit_transfer_limit = false;
         let mut have_hit_deploy_limit = false;
-        for (with_approvals, footprint) in self.proposable() {
-            if footprint.is_transfer && have_hit_transfer_limit {
-                continue;
+        // deploys whose dependencies are unmet are deferred until no more deploys can be added, as
+        // their dependencies may get added to the block in the meantime; likewise, sequenced
+        // deploys are deferred until the account's deploy with the preceding sequence number is
+        // added
+        let mut pending = self.proposable();
+        let mut sequenced: HashMap<PublicKey, BTreeMap<u64, DeployHash>> = HashMap::new();
+        for (with_approvals, footprint) in &pending {
+            if let Some(sequence_number) = footprint.header.sequence_number() {
+                sequenced
+                    .entry(footprint.header.account().clone())
+                    .or_default()
+                    .insert(sequence_number, *with_approvals.deploy_hash());
             }
-            if !footprint.is_transfer && have_hit_deploy_limit {
-                continue;
+        }
+        'proposing: loop {
+            let (ready, deferred): (Vec<_>, Vec<_>) =
+                pending.into_iter().partition(|(_, footprint)| {
+                    self.dependencies_met(footprint, &ret)
+                        && self.sequence_met(footprint, &ret, &sequenced)
+                });
+            if ready.is_empty() {
+                if !deferred.is_empty() {
+                    debug!(
+                        count = deferred.len(),
+                        "DeployBuffer: not proposing deploys with unmet dependencies or sequence"
+                    );
+                }
+                break;
             }
-            let deploy_hash = *with_approvals.deploy_hash();
-            let has_multiple_approvals = with_approvals.approvals().len() > 1;
-            match ret.add(with_approvals, &footprint) {
-                Ok(_) => {
-                    debug!(%deploy_hash, "DeployBuffer: proposing deploy");
-                    holds.insert(deploy_hash);
+            pending = deferred;
+            for (with_approvals, footprint) in ready {
+                if footprint.is_transfer && have_hit_transfer_limit {
+                    continue;
                 }
-                Err(error) => {
-                    match error {
-                        AddError::Duplicate => {
-                            // it should be physically impossible for a duplicate deploy to
-                            // be in the deploy buffer, thus this should be unreachable
-                            error!(
-                                ?deploy_hash,
-                                "DeployBuffer: duplicated deploy in deploy buffer"
-                            );
-                            self.dead.insert(deploy_hash);
-                        }
-                        AddError::Expired => {
-                            info!(
-                                ?deploy_hash,
-                                "DeployBuffer: expired deploy in deploy buffer"
-                            );
-                            self.dead.insert(deploy_hash);
-                        }
-                        AddError::InvalidDeploy => {
-                            // It should not generally be possible for an invalid deploy to get
-                            // buffered in the first place, thus this should be unreachable.  There
-                            // is a small potential for a slightly future-dated deploy to be
-                            // accepted (if within `timestamp_leeway`) and still be future-dated by
-                            // the time we try and add it to a proposed block here.
-                            warn!(
-                                ?deploy_hash,
-                                "DeployBuffer: invalid deploy in deploy buffer"
-                            );
-                            self.dead.insert(deploy_hash);
-                        }
-                        AddError::TransferCount => {
-                            if have_hit_deploy_limit {
+                if !footprint.is_transfer && have_hit_deploy_limit {
+                    continue;
+                }
+                let deploy_hash = *with_approvals.deploy_hash();
+                let has_multiple_approvals = with_approvals.approvals().len() > 1;
+                match ret.add(with_approvals, &footprint) {
+                    Ok(_) => {
+                        debug!(%deploy_hash, "DeployBuffer: proposing deploy");
+                        holds.insert(deploy_hash);
+                    }
+                    Err(error) => {
+                        match error {
+                            AddError::Duplicate => {
+                                // it should be physically impossible for a duplicate deploy to
+                                // be in the deploy buffer, thus this should be unreachable
+                                error!(
+                                    ?deploy_hash,
+                                    "DeployBuffer: duplicated deploy in deploy buffer"
+                                );
+                                self.dead.insert(deploy_hash);
+                            }
+                            AddError::Expired => {
                                 info!(
                                     ?deploy_hash,
-                                    "DeployBuffer: block filled with transfers and deploys"
+                                    "DeployBuffer: expired deploy in deploy buffer"
                                 );
-                                break;
+                                self.dead.insert(deploy_hash);
                             }
-                            have_hit_transfer_limit = true;
-                        }
-                        AddError::DeployCount => {
-                            if have_hit_transfer_limit {
+                            AddError::InvalidDeploy => {
+                                // It should not generally be possible for an invalid deploy to get
+                                // buffered in the first place, thus this should be unreachable.
+                                // There is a small potential for a slightly future-dated deploy to
+                                // be accepted (if within `timestamp_leeway`) and still be
+                                // future-dated by the time we try and add it to a proposed block
+                                // here.
+                                warn!(
+                                    ?deploy_hash,
+                                    "DeployBuffer: invalid deploy in deploy buffer"
+                                );
+                                self.dead.insert(deploy_hash);
+                            }
+                            AddError::TransferCount => {
+                                if have_hit_deploy_limit {
+                                    info!(
+                                        ?deploy_hash,
+                                        "DeployBuffer: block filled with transfers and deploys"
+                                    );
+                                    break 'proposing;
+                                }
+                                have_hit_transfer_limit = true;
+                            }
+                            AddError::DeployCount => {
+                                if have_hit_transfer_limit {
+                                    info!(
+                                        ?deploy_hash,
+                                        "DeployBuffer: block filled with deploys and transfers"
+                                    );
+                                    break 'proposing;
+                                }
+                                have_hit_deploy_limit = true;
+                            }
+                            AddError::ApprovalCount if has_multiple_approvals => {
+                                // keep iterating, we can maybe fit in a deploy with fewer approvals
+                            }
+                            AddError::ApprovalCount | AddError::GasLimit | AddError::BlockSize => {
                                 info!(
                                     ?deploy_hash,
-                                    "DeployBuffer: block filled with deploys and transfers"
+                                    %error,
+                                    "DeployBuffer: a block limit has been reached"
                                 );
-                                break;
+                                // a block limit has been reached
+                                break 'proposing;
                             }
-                            have_hit_deploy_limit = true;
-                        }
-                        AddError::ApprovalCount if has_multiple_approvals => {
-                            // keep iterating, we can maybe fit in a deploy with fewer approvals
-                        }
-                        AddError::ApprovalCount | AddError::GasLimit | AddError::BlockSize => {
-                            info!(
-                                ?deploy_hash,
-                                %error,
-                                "DeployBuffer: a block limit has been reached"
-                            );
-                            // a block limit has been reached
-                            break;
                         }
                     }
                 }
@@ -461,7 +745,12 @@ impl DeployBuffer {
 
 impl<REv> InitializedComponent<REv> for DeployBuffer
 where
-    REv: From<Event> + From<DeployBufferAnnouncement> + From<StorageRequest> + Send + 'static,
+    REv: From<Event>
+        + From<DeployBufferAnnouncement>
+        + From<StorageRequest>
+        + From<ContractRuntimeRequest>
+        + Send
+        + 'static,
 {
     fn state(&self) -> &ComponentState {
         &self.state
@@ -480,7 +769,12 @@ where
 
 impl<REv> Component<REv> for DeployBuffer
 where
-    REv: From<Event> + From<DeployBufferAnnouncement> + From<StorageRequest> + Send + 'static,
+    REv: From<Event>
+        + From<DeployBufferAnnouncement>
+        + From<StorageRequest>
+        + From<ContractRuntimeRequest>
+        + Send
+        + 'static,
 {
     type Event = Event;
 
@@ -526,6 +820,9 @@ where
                     Event::Request(_)
                     | Event::ReceiveDeployGossiped(_)
                     | Event::StoredDeploy(_, _)
+                    | Event::ExecutedDependencies(_)
+                    | Event::NextSequenceNumber { .. }
+                    | Event::ReceiveCancellation(_)
                     | Event::BlockProposed(_)
                     | Event::Block(_)
                     | Event::BlockFinalized(_)
@@ -552,13 +849,23 @@ where
                     timestamp,
                     responder,
                 }) => responder.respond(self.appendable_block(timestamp)).ignore(),
+                Event::Request(DeployBufferRequest::GetDeployStatus {
+                    deploy_hash,
+                    responder,
+                }) => responder.respond(self.deploy_status(&deploy_hash)).ignore(),
+                Event::Request(DeployBufferRequest::Dump { responder }) => {
+                    responder.respond(self.dump()).ignore()
+                }
                 Event::BlockFinalized(finalized_block) => {
                     self.register_block_finalized(&finalized_block);
                     Effects::new()
                 }
                 Event::Block(block) => {
                     self.register_block(&block);
-                    Effects::new()
+                    // the block may have advanced the sequence numbers of accounts with buffered
+                    // deploys
+                    let accounts = self.sequenced_accounts(block.deploy_and_transfer_hashes());
+                    self.read_next_sequence_numbers(effect_builder, accounts)
                 }
                 Event::BlockProposed(proposed) => {
                     self.register_block_proposed(*proposed);
@@ -570,7 +877,30 @@ where
                 Event::StoredDeploy(deploy_id, maybe_deploy) => {
                     match maybe_deploy {
                         Some(deploy) => {
+                            let unknown_dependencies = self.unknown_dependencies(&deploy);
+                            let unknown_sequence_account = deploy
+                                .header()
+                                .sequence_number()
+                                .map(|_| deploy.header().account().clone())
+                                .filter(|account| {
+                                    !self.next_sequence_numbers.contains_key(account)
+                                });
                             self.register_deploy(*deploy);
+                            let mut effects = Effects::new();
+                            if !unknown_dependencies.is_empty() {
+                                effects.extend(
+                                    effect_builder
+                                        .get_executed_deploys(unknown_dependencies)
+                                        .event(Event::ExecutedDependencies),
+                                );
+                            }
+                            if let Some(account) = unknown_sequence_account {
+                                effects.extend(self.read_next_sequence_numbers(
+                                    effect_builder,
+                                    iter::once(account).collect(),
+                                ));
+                            }
+                            return effects;
                         }
                         None => {
                             warn!("cannot register un-stored deploy({})", deploy_id);
@@ -578,6 +908,30 @@ where
                     }
                     Effects::new()
                 }
+                Event::ExecutedDependencies(deploy_hashes) => {
+                    self.executed.extend(deploy_hashes);
+                    Effects::new()
+                }
+                Event::NextSequenceNumber {
+                    account,
+                    block_height,
+                    next,
+                } => {
+                    self.register_next_sequence_number(account, block_height, next);
+                    Effects::new()
+                }
+                Event::ReceiveCancellation(cancellation) => {
+                    if !self.register_cancellation(&cancellation) {
+                        return Effects::new();
+                    }
+                    self.update_all_metrics();
+                    effect_builder
+                        .announce_deploy_cancelled(
+                            *cancellation.deploy_hash(),
+                            cancellation.replacement().copied(),
+                        )
+                        .ignore()
+                }
                 Event::Expire => self.expire(effect_builder),
             },
         }
@@ -0,0 +1,81 @@
+//! Data types used solely for dumping of deploy buffer data via the diagnostics port.
+
+use std::fmt::{self, Display, Formatter};
+
+use datasize::DataSize;
+use serde::Serialize;
+
+use casper_types::Timestamp;
+
+use crate::types::{DeployFootprint, DeployHash};
+
+/// Debug dump of the deploy buffer used for serialization.
+#[derive(DataSize, Debug, Serialize)]
+pub(crate) struct DeployBufferDump {
+    /// All deploys the buffer is currently aware of, ordered by deploy hash.
+    pub(crate) buffer: Vec<BufferedDeployDump>,
+    /// Deploys held due to being included in a proposed block, keyed by the proposal timestamp.
+    pub(crate) hold: Vec<(Timestamp, Vec<DeployHash>)>,
+    /// Deploys that will never be proposed again.
+    pub(crate) dead: Vec<DeployHash>,
+}
+
+/// Debug dump of a single buffered deploy.
+#[derive(DataSize, Debug, Serialize)]
+pub(crate) struct BufferedDeployDump {
+    /// The hash of the deploy.
+    pub(crate) deploy_hash: DeployHash,
+    /// The time at which the deploy expires.
+    pub(crate) expires_at: Timestamp,
+    /// The footprint of the deploy, if it has been stored already.
+    pub(crate) footprint: Option<DeployFootprint>,
+}
+
+impl Display for DeployBufferDump {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} buffered, {} proposed blocks holding deploys, {} dead",
+            self.buffer.len(),
+            self.hold.len(),
+            self.dead.len()
+        )?;
+
+        writeln!(f, "buffer:")?;
+        for buffered in &self.buffer {
+            write!(
+                f,
+                "  {} expires {}: ",
+                buffered.deploy_hash, buffered.expires_at
+            )?;
+            match buffered.footprint {
+                Some(ref footprint) => writeln!(
+                    f,
+                    "gas estimate {}, size estimate {}, transfer: {}",
+                    footprint.gas_estimate, footprint.size_estimate, footprint.is_transfer
+                )?,
+                None => writeln!(f, "no footprint")?,
+            }
+        }
+
+        writeln!(f, "hold:")?;
+        for (proposed_at, deploy_hashes) in &self.hold {
+            writeln!(
+                f,
+                "  proposed {}: {} deploys",
+                proposed_at,
+                deploy_hashes.len()
+            )?;
+            for deploy_hash in deploy_hashes {
+                writeln!(f, "    {}", deploy_hash)?;
+            }
+        }
+
+        writeln!(f, "dead:")?;
+        for deploy_hash in &self.dead {
+            writeln!(f, "  {}", deploy_hash)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     fmt::{self, Display, Formatter},
     sync::Arc,
 };
@@ -6,10 +7,12 @@ use std::{
 use datasize::DataSize;
 use derive_more::From;
 
+use casper_types::{PublicKey, Timestamp};
+
 use crate::{
     components::consensus::{ClContext, ProposedBlock},
     effect::requests::DeployBufferRequest,
-    types::{Block, Deploy, DeployId, FinalizedBlock},
+    types::{Block, Deploy, DeployCancellation, DeployHash, DeployId, FinalizedBlock},
 };
 
 #[derive(Debug, From, DataSize)]
@@ -19,6 +22,13 @@ pub(crate) enum Event {
     Request(DeployBufferRequest),
     ReceiveDeployGossiped(DeployId),
     StoredDeploy(DeployId, Option<Box<Deploy>>),
+    ExecutedDependencies(HashMap<DeployHash, Timestamp>),
+    NextSequenceNumber {
+        account: PublicKey,
+        block_height: u64,
+        next: u64,
+    },
+    ReceiveCancellation(Box<DeployCancellation>),
     BlockProposed(Box<ProposedBlock<ClContext>>),
     Block(Arc<Block>),
     BlockFinalized(Box<FinalizedBlock>),
@@ -34,6 +44,12 @@ impl Display for Event {
             Event::Request(DeployBufferRequest::GetAppendableBlock { .. }) => {
                 write!(formatter, "get appendable block request")
             }
+            Event::Request(DeployBufferRequest::GetDeployStatus { deploy_hash, .. }) => {
+                write!(formatter, "get status of {} request", deploy_hash)
+            }
+            Event::Request(DeployBufferRequest::Dump { .. }) => {
+                write!(formatter, "dump request")
+            }
             Event::ReceiveDeployGossiped(deploy_id) => {
                 write!(formatter, "receive deploy gossiped {}", deploy_id)
             }
@@ -45,6 +61,27 @@ impl Display for Event {
                     maybe_deploy.is_some()
                 )
             }
+            Event::ExecutedDependencies(deploy_hashes) => {
+                write!(
+                    formatter,
+                    "{} dependencies found to be executed",
+                    deploy_hashes.len()
+                )
+            }
+            Event::NextSequenceNumber {
+                account,
+                block_height,
+                next,
+            } => {
+                write!(
+                    formatter,
+                    "next sequence number of {} at block {} is {}",
+                    account, block_height, next
+                )
+            }
+            Event::ReceiveCancellation(cancellation) => {
+                write!(formatter, "receive {}", cancellation)
+            }
             Event::BlockProposed(_) => {
                 write!(formatter, "proposed block")
             }
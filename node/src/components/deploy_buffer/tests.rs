@@ -1,3 +1,5 @@
+use std::iter;
+
 use super::*;
 use crate::{
     effect::announcements::DeployBufferAnnouncement::{self, DeploysExpired},
@@ -5,7 +7,7 @@ use crate::{
     types::{Block, FinalizedBlock},
     utils,
 };
-use casper_types::{testing::TestRng, EraId, TimeDiff};
+use casper_types::{testing::TestRng, EraId, SecretKey, TimeDiff};
 use prometheus::Registry;
 use rand::Rng;
 
@@ -15,7 +17,40 @@ enum DeployType {
     Random,
 }
 
-// Generates valid deploys
+// Returns a copy of the given deploy with the given dependencies, signed by a random account.
+fn with_dependencies(rng: &mut TestRng, deploy: &Deploy, dependencies: Vec<DeployHash>) -> Deploy {
+    let header = deploy.header();
+    Deploy::new(
+        header.timestamp(),
+        header.ttl(),
+        header.gas_price(),
+        dependencies,
+        header.chain_name().to_string(),
+        deploy.payment().clone(),
+        deploy.session().clone(),
+        &SecretKey::random(rng),
+        None,
+    )
+}
+
+// Returns a copy of the given deploy with the given sequence number, signed by the given account.
+fn with_sequence_number(deploy: &Deploy, secret_key: &SecretKey, sequence_number: u64) -> Deploy {
+    let header = deploy.header();
+    Deploy::new_sequenced(
+        header.timestamp(),
+        header.ttl(),
+        header.gas_price(),
+        vec![],
+        header.chain_name().to_string(),
+        deploy.payment().clone(),
+        deploy.session().clone(),
+        secret_key,
+        None,
+        sequence_number,
+    )
+}
+
+// Generates valid deploys without dependencies
 fn create_valid_deploys(
     rng: &mut TestRng,
     size: usize,
@@ -61,6 +96,9 @@ fn create_valid_deploys(
     }
 
     deploys
+        .iter()
+        .map(|deploy| with_dependencies(rng, deploy, vec![]))
+        .collect()
 }
 
 fn create_invalid_deploys(rng: &mut TestRng, size: usize) -> Vec<Deploy> {
@@ -108,8 +146,13 @@ fn assert_container_sizes(
 #[test]
 fn register_deploy_and_check_size() {
     let mut rng = TestRng::new();
-    let mut deploy_buffer =
-        DeployBuffer::new(DeployConfig::default(), Config::default(), &Registry::new()).unwrap();
+    let mut deploy_buffer = DeployBuffer::new(
+        DeployConfig::default(),
+        true,
+        Config::default(),
+        &Registry::new(),
+    )
+    .unwrap();
 
     // Try to register valid deploys
     let num_valid_deploys: usize = rng.gen_range(50..500);
@@ -145,8 +188,13 @@ fn register_deploy_and_check_size() {
 #[test]
 fn register_block_with_valid_deploys() {
     let mut rng = TestRng::new();
-    let mut deploy_buffer =
-        DeployBuffer::new(DeployConfig::default(), Config::default(), &Registry::new()).unwrap();
+    let mut deploy_buffer = DeployBuffer::new(
+        DeployConfig::default(),
+        true,
+        Config::default(),
+        &Registry::new(),
+    )
+    .unwrap();
 
     let deploys = create_valid_deploys(&mut rng, 10, DeployType::Random, None, None);
     let block = Block::random_with_deploys(&mut rng, deploys.iter());
@@ -158,8 +206,13 @@ fn register_block_with_valid_deploys() {
 #[test]
 fn register_finalized_block_with_valid_deploys() {
     let mut rng = TestRng::new();
-    let mut deploy_buffer =
-        DeployBuffer::new(DeployConfig::default(), Config::default(), &Registry::new()).unwrap();
+    let mut deploy_buffer = DeployBuffer::new(
+        DeployConfig::default(),
+        true,
+        Config::default(),
+        &Registry::new(),
+    )
+    .unwrap();
 
     let deploys = create_valid_deploys(&mut rng, 10, DeployType::Random, None, None);
     let block = FinalizedBlock::random_with_deploys(&mut rng, deploys.iter());
@@ -171,8 +224,13 @@ fn register_finalized_block_with_valid_deploys() {
 #[test]
 fn get_proposable_deploys() {
     let mut rng = TestRng::new();
-    let mut deploy_buffer =
-        DeployBuffer::new(DeployConfig::default(), Config::default(), &Registry::new()).unwrap();
+    let mut deploy_buffer = DeployBuffer::new(
+        DeployConfig::default(),
+        true,
+        Config::default(),
+        &Registry::new(),
+    )
+    .unwrap();
 
     // populate deploy buffer with some deploys
     let deploys = create_valid_deploys(&mut rng, 50, DeployType::Random, None, None);
@@ -225,6 +283,138 @@ fn get_proposable_deploys() {
     }
 }
 
+#[test]
+fn get_deploy_status() {
+    let mut rng = TestRng::new();
+    let mut deploy_buffer = DeployBuffer::new(
+        DeployConfig::default(),
+        true,
+        Config::default(),
+        &Registry::new(),
+    )
+    .unwrap();
+
+    let deploys = create_valid_deploys(&mut rng, 10, DeployType::Random, None, None);
+    deploys
+        .iter()
+        .for_each(|deploy| deploy_buffer.register_deploy(deploy.clone()));
+    for deploy in deploys.iter() {
+        assert_eq!(
+            deploy_buffer.deploy_status(deploy.hash()),
+            Some(DeployBufferStatus::Pending {
+                expires_at: deploy.header().expires()
+            })
+        );
+    }
+
+    // Deploys included in a proposed block should be reported as held.
+    let proposed_at = Timestamp::now();
+    let appendable_block = deploy_buffer.appendable_block(proposed_at);
+    for deploy_hash in appendable_block.deploy_and_transfer_set() {
+        assert!(matches!(
+            deploy_buffer.deploy_status(deploy_hash),
+            Some(DeployBufferStatus::Held { proposed_at: held_at, .. }) if held_at == proposed_at
+        ));
+    }
+
+    // Deploys included in a finalized block should be reported as finalized.
+    let block_deploys = create_valid_deploys(&mut rng, 10, DeployType::Random, None, None);
+    let block = FinalizedBlock::random_with_deploys(&mut rng, block_deploys.iter());
+    deploy_buffer.register_block_finalized(&block);
+    for deploy in block_deploys.iter() {
+        assert_eq!(
+            deploy_buffer.deploy_status(deploy.hash()),
+            Some(DeployBufferStatus::Finalized)
+        );
+    }
+
+    // Deploys never seen by the buffer should have no status.
+    let unknown_deploy = Deploy::random(&mut rng);
+    assert!(deploy_buffer.deploy_status(unknown_deploy.hash()).is_none());
+}
+
+#[test]
+fn dump_deploy_buffer() {
+    let mut rng = TestRng::new();
+    let mut deploy_buffer = DeployBuffer::new(
+        DeployConfig::default(),
+        true,
+        Config::default(),
+        &Registry::new(),
+    )
+    .unwrap();
+
+    let deploys = create_valid_deploys(&mut rng, 10, DeployType::Random, None, None);
+    deploys
+        .iter()
+        .for_each(|deploy| deploy_buffer.register_deploy(deploy.clone()));
+    let block_deploys = create_valid_deploys(&mut rng, 5, DeployType::Random, None, None);
+    let block = Block::random_with_deploys(&mut rng, block_deploys.iter());
+    deploy_buffer.register_block(&block);
+    let proposed_at = Timestamp::now();
+    let appendable_block = deploy_buffer.appendable_block(proposed_at);
+
+    let dump = deploy_buffer.dump();
+    assert_eq!(dump.buffer.len(), deploys.len() + block_deploys.len());
+    // Only deploys registered in full have a footprint, not those only known from the block.
+    for buffered in dump.buffer.iter() {
+        let registered = deploys
+            .iter()
+            .any(|deploy| *deploy.hash() == buffered.deploy_hash);
+        assert_eq!(buffered.footprint.is_some(), registered);
+    }
+    assert_eq!(dump.hold.len(), 1);
+    assert_eq!(dump.hold[0].0, proposed_at);
+    assert_eq!(
+        dump.hold[0].1.len(),
+        appendable_block.deploy_and_transfer_set().len()
+    );
+    assert_eq!(dump.dead.len(), block_deploys.len());
+    for deploy in block_deploys.iter() {
+        assert!(dump.dead.contains(deploy.hash()));
+    }
+}
+
+#[test]
+fn register_cancellation() {
+    let mut rng = TestRng::new();
+    let mut deploy_buffer = DeployBuffer::new(
+        DeployConfig::default(),
+        true,
+        Config::default(),
+        &Registry::new(),
+    )
+    .unwrap();
+    let secret_key = SecretKey::random(&mut rng);
+
+    let deploys = create_valid_deploys(&mut rng, 2, DeployType::Random, None, None);
+    deploys
+        .iter()
+        .for_each(|deploy| deploy_buffer.register_deploy(deploy.clone()));
+
+    // A buffered deploy can be cancelled exactly once, and is no longer proposable afterwards.
+    let cancellation = DeployCancellation::new(*deploys[0].hash(), None, &secret_key);
+    assert!(deploy_buffer.register_cancellation(&cancellation));
+    assert!(!deploy_buffer.register_cancellation(&cancellation));
+    assert!(matches!(
+        deploy_buffer.deploy_status(deploys[0].hash()),
+        Some(DeployBufferStatus::Dead { .. })
+    ));
+    let proposable = deploy_buffer.proposable();
+    assert_eq!(proposable.len(), 1);
+    assert_eq!(proposable[0].0.deploy_hash(), deploys[1].hash());
+
+    // Executed deploys cannot be cancelled.
+    let block = FinalizedBlock::random_with_deploys(&mut rng, iter::once(&deploys[1]));
+    deploy_buffer.register_block_finalized(&block);
+    let cancellation = DeployCancellation::new(*deploys[1].hash(), None, &secret_key);
+    assert!(!deploy_buffer.register_cancellation(&cancellation));
+
+    // Deploys never seen by the buffer cannot be cancelled.
+    let cancellation = DeployCancellation::new(*Deploy::random(&mut rng).hash(), None, &secret_key);
+    assert!(!deploy_buffer.register_cancellation(&cancellation));
+}
+
 #[test]
 fn get_appendable_block_with_native_transfers() {
     let mut rng = TestRng::new();
@@ -235,7 +425,7 @@ fn get_appendable_block_with_native_transfers() {
         ..Default::default()
     };
     let mut deploy_buffer =
-        DeployBuffer::new(deploy_config, Config::default(), &Registry::new()).unwrap();
+        DeployBuffer::new(deploy_config, true, Config::default(), &Registry::new()).unwrap();
     get_appendable_block(
         &mut rng,
         &mut deploy_buffer,
@@ -254,7 +444,7 @@ fn get_appendable_block_with_standard_deploys() {
         ..Default::default()
     };
     let mut deploy_buffer =
-        DeployBuffer::new(deploy_config, Config::default(), &Registry::new()).unwrap();
+        DeployBuffer::new(deploy_config, true, Config::default(), &Registry::new()).unwrap();
     get_appendable_block(
         &mut rng,
         &mut deploy_buffer,
@@ -273,7 +463,7 @@ fn get_appendable_block_with_random_deploys() {
         ..Default::default()
     };
     let mut deploy_buffer =
-        DeployBuffer::new(deploy_config, Config::default(), &Registry::new()).unwrap();
+        DeployBuffer::new(deploy_config, true, Config::default(), &Registry::new()).unwrap();
     get_appendable_block(
         &mut rng,
         &mut deploy_buffer,
@@ -307,11 +497,221 @@ fn get_appendable_block(
     );
 }
 
+#[test]
+fn get_appendable_block_with_dependencies() {
+    let mut rng = TestRng::new();
+    let mut deploy_buffer = DeployBuffer::new(
+        DeployConfig::default(),
+        true,
+        Config::default(),
+        &Registry::new(),
+    )
+    .unwrap();
+
+    let mut standard_deploys =
+        create_valid_deploys(&mut rng, 4, DeployType::Standard, None, None).into_iter();
+    let executed_later = standard_deploys.next().unwrap();
+    let independent = standard_deploys.next().unwrap();
+    let transfer = create_valid_deploys(&mut rng, 1, DeployType::Transfer, None, None)
+        .pop()
+        .unwrap();
+    // depends on a deploy earlier in the same block
+    let dependent = with_dependencies(
+        &mut rng,
+        &standard_deploys.next().unwrap(),
+        vec![*independent.hash()],
+    );
+    // a transfer is executed after all deploys in the same block
+    let dependent_transfer = with_dependencies(&mut rng, &transfer, vec![*independent.hash()]);
+    // depends on a transfer which is executed after it in the same block
+    let depends_on_transfer = with_dependencies(
+        &mut rng,
+        &standard_deploys.next().unwrap(),
+        vec![*dependent_transfer.hash()],
+    );
+    // depends on a deploy which has not been executed yet
+    let depends_on_unexecuted =
+        with_dependencies(&mut rng, &independent, vec![*executed_later.hash()]);
+
+    for deploy in [
+        &depends_on_unexecuted,
+        &depends_on_transfer,
+        &dependent_transfer,
+        &dependent,
+        &independent,
+    ] {
+        deploy_buffer.register_deploy(deploy.clone());
+    }
+
+    let appendable_block = deploy_buffer.appendable_block(Timestamp::now());
+    let proposed = appendable_block.deploy_and_transfer_set();
+    assert_eq!(proposed.len(), 3);
+    assert!(proposed.contains(independent.hash()));
+    assert!(proposed.contains(dependent.hash()));
+    assert!(proposed.contains(dependent_transfer.hash()));
+
+    // once its dependency has been executed, the deploy should be proposed
+    let block = Block::random_with_deploys(&mut rng, iter::once(&executed_later));
+    deploy_buffer.register_block(&block);
+    let appendable_block = deploy_buffer.appendable_block(Timestamp::now());
+    let proposed = appendable_block.deploy_and_transfer_set();
+    assert!(proposed.contains(depends_on_unexecuted.hash()));
+    assert!(!proposed.contains(depends_on_transfer.hash()));
+}
+
+#[test]
+fn get_appendable_block_with_dependencies_outside_max_ttl() {
+    let mut rng = TestRng::new();
+    let deploy_config = DeployConfig::default();
+    let max_ttl = deploy_config.max_ttl;
+
+    let mut standard_deploys =
+        create_valid_deploys(&mut rng, 2, DeployType::Standard, None, None).into_iter();
+    let dependency = standard_deploys.next().unwrap();
+    let dependent = with_dependencies(
+        &mut rng,
+        &standard_deploys.next().unwrap(),
+        vec![*dependency.hash()],
+    );
+    let proposal_timestamp = Timestamp::now();
+
+    for dependencies_enforced in [true, false] {
+        let mut deploy_buffer = DeployBuffer::new(
+            deploy_config,
+            dependencies_enforced,
+            Config::default(),
+            &Registry::new(),
+        )
+        .unwrap();
+        deploy_buffer.register_deploy(dependent.clone());
+
+        // a dependency executed longer than `max_ttl` before the proposal doesn't count
+        deploy_buffer.executed.insert(
+            *dependency.hash(),
+            proposal_timestamp
+                .saturating_sub(max_ttl)
+                .saturating_sub(TimeDiff::from_millis(1)),
+        );
+        let appendable_block = deploy_buffer.appendable_block(proposal_timestamp);
+        assert_eq!(
+            appendable_block
+                .deploy_and_transfer_set()
+                .contains(dependent.hash()),
+            !dependencies_enforced
+        );
+
+        // one executed exactly `max_ttl` before it does, unless the deploy is already held for the
+        // previous proposal
+        deploy_buffer.executed.insert(
+            *dependency.hash(),
+            proposal_timestamp.saturating_sub(max_ttl),
+        );
+        let appendable_block = deploy_buffer.appendable_block(proposal_timestamp);
+        assert_eq!(
+            appendable_block
+                .deploy_and_transfer_set()
+                .contains(dependent.hash()),
+            dependencies_enforced
+        );
+    }
+}
+
+#[test]
+fn get_appendable_block_with_sequenced_deploys() {
+    let mut rng = TestRng::new();
+    let mut deploy_buffer = DeployBuffer::new(
+        DeployConfig::default(),
+        true,
+        Config::default(),
+        &Registry::new(),
+    )
+    .unwrap();
+    let secret_key = SecretKey::random(&mut rng);
+
+    let mut standard_deploys =
+        create_valid_deploys(&mut rng, 3, DeployType::Standard, None, None).into_iter();
+    let transfer = create_valid_deploys(&mut rng, 1, DeployType::Transfer, None, None)
+        .pop()
+        .unwrap();
+    let first = with_sequence_number(&standard_deploys.next().unwrap(), &secret_key, 0);
+    let second = with_sequence_number(&standard_deploys.next().unwrap(), &secret_key, 1);
+    // a transfer is executed after all deploys in the same block
+    let third = with_sequence_number(&transfer, &secret_key, 2);
+    // can't be executed after the transfer preceding it in the same block
+    let fourth = with_sequence_number(&standard_deploys.next().unwrap(), &secret_key, 3);
+
+    for deploy in [&fourth, &third, &second, &first] {
+        deploy_buffer.register_deploy(deploy.clone());
+    }
+
+    // nothing is proposed while the next sequence number of the account is unknown
+    let appendable_block = deploy_buffer.appendable_block(Timestamp::now());
+    assert!(appendable_block.deploy_and_transfer_set().is_empty());
+    deploy_buffer.hold.clear();
+
+    deploy_buffer.register_next_sequence_number(PublicKey::from(&secret_key), 0, 0);
+    let appendable_block = deploy_buffer.appendable_block(Timestamp::now());
+    let proposed = appendable_block.deploy_and_transfer_set();
+    assert_eq!(proposed.len(), 3);
+    assert!(proposed.contains(third.hash()));
+    assert!(!proposed.contains(fourth.hash()));
+    let block_payload = appendable_block.into_block_payload(vec![], false);
+    let deploy_hashes: Vec<_> = block_payload.deploy_hashes().collect();
+    assert_eq!(deploy_hashes, vec![first.hash(), second.hash()]);
+}
+
+#[test]
+fn get_appendable_block_with_next_sequence_number() {
+    let mut rng = TestRng::new();
+    let mut deploy_buffer = DeployBuffer::new(
+        DeployConfig::default(),
+        true,
+        Config::default(),
+        &Registry::new(),
+    )
+    .unwrap();
+    let secret_key = SecretKey::random(&mut rng);
+    let account = PublicKey::from(&secret_key);
+
+    let mut standard_deploys =
+        create_valid_deploys(&mut rng, 4, DeployType::Standard, None, None).into_iter();
+    let first = with_sequence_number(&standard_deploys.next().unwrap(), &secret_key, 0);
+    let second = with_sequence_number(&standard_deploys.next().unwrap(), &secret_key, 1);
+    let third = with_sequence_number(&standard_deploys.next().unwrap(), &secret_key, 2);
+    // there is a gap before this one
+    let fifth = with_sequence_number(&standard_deploys.next().unwrap(), &secret_key, 4);
+    for deploy in [&first, &second, &third, &fifth] {
+        deploy_buffer.register_deploy(deploy.clone());
+    }
+
+    // global state expects the second deploy next, so the first can't be proposed any more
+    deploy_buffer.register_next_sequence_number(account.clone(), 1, 1);
+    // a sequence number read at a lower height is ignored
+    deploy_buffer.register_next_sequence_number(account.clone(), 0, 0);
+    let appendable_block = deploy_buffer.appendable_block(Timestamp::now());
+    let block_payload = appendable_block.into_block_payload(vec![], false);
+    let deploy_hashes: Vec<_> = block_payload.deploy_hashes().collect();
+    assert_eq!(deploy_hashes, vec![second.hash(), third.hash()]);
+    deploy_buffer.hold.clear();
+
+    // once the deploy with the missing sequence number is executed, the last one is proposable
+    deploy_buffer.register_next_sequence_number(account, 2, 4);
+    let appendable_block = deploy_buffer.appendable_block(Timestamp::now());
+    let proposed = appendable_block.deploy_and_transfer_set();
+    assert_eq!(proposed.len(), 1);
+    assert!(proposed.contains(fifth.hash()));
+}
+
 #[test]
 fn register_deploys_and_blocks() {
     let mut rng = TestRng::new();
-    let mut deploy_buffer =
-        DeployBuffer::new(DeployConfig::default(), Config::default(), &Registry::new()).unwrap();
+    let mut deploy_buffer = DeployBuffer::new(
+        DeployConfig::default(),
+        true,
+        Config::default(),
+        &Registry::new(),
+    )
+    .unwrap();
 
     // try to register valid deploys
     let num_valid_deploys: usize = rng.gen_range(50..500);
@@ -444,8 +844,13 @@ impl MockReactor {
 #[tokio::test]
 async fn expire_deploys_and_check_announcement() {
     let mut rng = TestRng::new();
-    let mut deploy_buffer =
-        DeployBuffer::new(DeployConfig::default(), Config::default(), &Registry::new()).unwrap();
+    let mut deploy_buffer = DeployBuffer::new(
+        DeployConfig::default(),
+        true,
+        Config::default(),
+        &Registry::new(),
+    )
+    .unwrap();
 
     let reactor = MockReactor::new();
     let event_queue_handle = EventQueueHandle::without_shutdown(reactor.scheduler);
@@ -25,7 +25,10 @@ use crate::{
     effect::{
         announcements::ControlAnnouncement,
         diagnostics_port::DumpConsensusStateRequest,
-        requests::{NetworkInfoRequest, SetNodeStopRequest},
+        requests::{
+            BlockAccumulatorRequest, BlockSynchronizerRequest, DeployBufferRequest,
+            NetworkInfoRequest, SetNodeStopRequest,
+        },
         EffectBuilder, EffectExt, Effects,
     },
     reactor::main_reactor::MainEvent,
@@ -108,6 +111,9 @@ where
         + From<ControlAnnouncement>
         + From<NetworkInfoRequest>
         + From<SetNodeStopRequest>
+        + From<DeployBufferRequest>
+        + From<BlockAccumulatorRequest>
+        + From<BlockSynchronizerRequest>
         + Send,
 {
     type Event = Event;
@@ -162,6 +168,9 @@ where
         + From<ControlAnnouncement>
         + From<NetworkInfoRequest>
         + From<SetNodeStopRequest>
+        + From<DeployBufferRequest>
+        + From<BlockAccumulatorRequest>
+        + From<BlockSynchronizerRequest>
         + Send,
 {
     fn state(&self) -> &ComponentState {
@@ -186,6 +195,9 @@ where
         + From<ControlAnnouncement>
         + From<NetworkInfoRequest>
         + From<SetNodeStopRequest>
+        + From<DeployBufferRequest>
+        + From<BlockAccumulatorRequest>
+        + From<BlockSynchronizerRequest>
         + Send,
 {
     type Error = Error;
@@ -89,8 +89,19 @@ pub(super) enum Action {
     },
     /// Dump the event queues.
     DumpQueues,
+    /// Dump the contents of the deploy buffer, including held and dead deploys.
+    DumpDeployBuffer,
+    /// Dump the state of the block accumulator, including all block acceptors and the local tip.
+    DumpBlockAccumulator,
+    /// Dump the state of the block synchronizer, including the peers and next need of every block
+    /// being synced.
+    DumpBlockSynchronizer,
     /// Get detailed networking insights.
     NetInfo,
+    /// Re-read the configuration file and apply all settings that can be changed at runtime.
+    ///
+    /// Reports which changed settings were applied and which only take effect after a restart.
+    ReloadConfig,
     /// Stop the node at a certain condition.
     Stop {
         /// When to stop the node.
@@ -162,5 +173,17 @@ mod tests {
 
         let cmd = Command::from_line("dump-queues").expect("command parsing failed");
         assert!(matches!(cmd.action, Action::DumpQueues));
+
+        let cmd = Command::from_line("dump-deploy-buffer").expect("command parsing failed");
+        assert!(matches!(cmd.action, Action::DumpDeployBuffer));
+
+        let cmd = Command::from_line("dump-block-accumulator").expect("command parsing failed");
+        assert!(matches!(cmd.action, Action::DumpBlockAccumulator));
+
+        let cmd = Command::from_line("dump-block-synchronizer").expect("command parsing failed");
+        assert!(matches!(cmd.action, Action::DumpBlockSynchronizer));
+
+        let cmd = Command::from_line("reload-config").expect("command parsing failed");
+        assert!(matches!(cmd.action, Action::ReloadConfig));
     }
 }
@@ -34,7 +34,10 @@ use crate::{
     effect::{
         announcements::{ControlAnnouncement, QueueDumpFormat},
         diagnostics_port::DumpConsensusStateRequest,
-        requests::{NetworkInfoRequest, SetNodeStopRequest},
+        requests::{
+            BlockAccumulatorRequest, BlockSynchronizerRequest, DeployBufferRequest,
+            NetworkInfoRequest, SetNodeStopRequest,
+        },
         EffectBuilder,
     },
     failpoints::FailpointActivation,
@@ -204,6 +207,9 @@ impl Session {
             + From<ControlAnnouncement>
             + From<NetworkInfoRequest>
             + From<SetNodeStopRequest>
+            + From<DeployBufferRequest>
+            + From<BlockAccumulatorRequest>
+            + From<BlockSynchronizerRequest>
             + Send,
     {
         debug!(%line, "line received");
@@ -318,12 +324,56 @@ impl Session {
                             }
                         };
                     }
+                    Action::DumpDeployBuffer => {
+                        self.send_outcome(writer, &Outcome::success("dumping deploy buffer"))
+                            .await?;
+                        let dump = effect_builder.diagnostics_port_dump_deploy_buffer().await;
+                        self.send_to_client(writer, &dump).await?;
+                    }
+                    Action::DumpBlockAccumulator => {
+                        self.send_outcome(writer, &Outcome::success("dumping block accumulator"))
+                            .await?;
+                        let dump = effect_builder
+                            .diagnostics_port_dump_block_accumulator()
+                            .await;
+                        self.send_to_client(writer, &dump).await?;
+                    }
+                    Action::DumpBlockSynchronizer => {
+                        self.send_outcome(writer, &Outcome::success("dumping block synchronizer"))
+                            .await?;
+                        let dump = effect_builder
+                            .diagnostics_port_dump_block_synchronizer()
+                            .await;
+                        self.send_to_client(writer, &dump).await?;
+                    }
                     Action::NetInfo => {
                         self.send_outcome(writer, &Outcome::success("collecting insights"))
                             .await?;
                         let insights = effect_builder.get_network_insights().await;
                         self.send_to_client(writer, &insights).await?;
                     }
+                    Action::ReloadConfig => {
+                        match effect_builder.diagnostics_port_reload_config().await {
+                            Ok(report) => {
+                                self.send_outcome(
+                                    writer,
+                                    &Outcome::success("configuration reloaded"),
+                                )
+                                .await?;
+                                self.send_to_client(writer, &report).await?;
+                            }
+                            Err(ref err) => {
+                                self.send_outcome(
+                                    writer,
+                                    &Outcome::failed(format!(
+                                        "failed to reload configuration: {}",
+                                        display_error(err)
+                                    )),
+                                )
+                                .await?;
+                            }
+                        }
+                    }
                     Action::Stop { at, clear } => {
                         let (msg, stop_at) = if clear {
                             ("clearing stopping point", None)
@@ -503,6 +553,9 @@ where
         + From<ControlAnnouncement>
         + From<NetworkInfoRequest>
         + From<SetNodeStopRequest>
+        + From<DeployBufferRequest>
+        + From<BlockAccumulatorRequest>
+        + From<BlockSynchronizerRequest>
         + Send,
 {
     debug!("accepted new connection on diagnostics port");
@@ -547,6 +600,9 @@ pub(super) async fn server<REv>(
         + From<ControlAnnouncement>
         + From<NetworkInfoRequest>
         + From<SetNodeStopRequest>
+        + From<DeployBufferRequest>
+        + From<BlockAccumulatorRequest>
+        + From<BlockSynchronizerRequest>
         + Send,
 {
     let handling_shutdown_receiver = shutdown_receiver.clone();
@@ -626,7 +682,10 @@ mod tests {
         effect::{
             announcements::ControlAnnouncement,
             diagnostics_port::DumpConsensusStateRequest,
-            requests::{NetworkInfoRequest, SetNodeStopRequest},
+            requests::{
+                BlockAccumulatorRequest, BlockSynchronizerRequest, DeployBufferRequest,
+                NetworkInfoRequest, SetNodeStopRequest,
+            },
             EffectBuilder, EffectExt, Effects,
         },
         reactor::{
@@ -686,6 +745,12 @@ mod tests {
         NetworkInfoRequest(NetworkInfoRequest),
         #[from]
         SetNodeStopRequest(SetNodeStopRequest),
+        #[from]
+        DeployBufferRequest(DeployBufferRequest),
+        #[from]
+        BlockAccumulatorRequest(BlockAccumulatorRequest),
+        #[from]
+        BlockSynchronizerRequest(BlockSynchronizerRequest),
     }
 
     impl Display for Event {
@@ -732,7 +797,10 @@ mod tests {
                 Event::DumpConsensusStateRequest(_)
                 | Event::SetNodeStopRequest(_)
                 | Event::ControlAnnouncement(_)
-                | Event::NetworkInfoRequest(_) => {
+                | Event::NetworkInfoRequest(_)
+                | Event::DeployBufferRequest(_)
+                | Event::BlockAccumulatorRequest(_)
+                | Event::BlockSynchronizerRequest(_) => {
                     panic!("unexpected: {}", event)
                 }
             }
@@ -16,44 +16,62 @@
 //! This component uses a ring buffer for outbound events providing some robustness against
 //! unintended subscriber disconnects, if a disconnected subscriber re-subscribes before the buffer
 //! has advanced past their last received event.
+//!
+//! If configured, all events are additionally persisted in an on-disk journal, allowing subscribers
+//! to resume from events older than the buffered ones, even across restarts of the node.  Blocks
+//! added before the oldest journaled event, including those added before the journal was enabled,
+//! are rebuilt from storage.
 
 mod config;
 mod event;
 mod event_indexer;
+mod event_journal;
 mod http_server;
 mod sse_server;
 #[cfg(test)]
 mod tests;
 
-use std::{fmt::Debug, net::SocketAddr, path::PathBuf};
+use std::{
+    fmt::Debug,
+    net::SocketAddr,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+};
 
 use datasize::DataSize;
+use futures::FutureExt;
 use tokio::sync::{
     mpsc::{self, UnboundedSender},
-    oneshot,
+    oneshot, watch,
 };
 use tracing::{error, info, warn};
 use warp::Filter;
 
-use casper_types::ProtocolVersion;
+use casper_types::{ExecutionResult, FeeBreakdown, ProtocolVersion};
 
 use super::Component;
 use crate::{
     components::{ComponentState, InitializedComponent, PortBoundComponent},
-    effect::{EffectBuilder, Effects},
+    effect::{requests::StorageRequest, EffectBuilder, Effects},
     reactor::main_reactor::MainEvent,
-    types::JsonBlock,
     utils::{self, ListeningError},
     NodeRng,
 };
-pub use config::Config;
+pub use config::{Config, JournalConfig};
 pub(crate) use event::Event;
 use event_indexer::{EventIndex, EventIndexer};
+use event_journal::{BlockBackfill, EventJournal};
 use sse_server::ChannelsAndFilter;
 pub(crate) use sse_server::SseData;
 
 const COMPONENT_NAME: &str = "event_stream_server";
 
+/// Name of the directory within the storage path holding the event journal.
+const JOURNAL_DIR_NAME: &str = "sse_journal";
+
 /// This is used to define the number of events to buffer in the tokio broadcast channel to help
 /// slower clients to try to avoid missing events (See
 /// <https://docs.rs/tokio/1.4.0/tokio/sync/broadcast/index.html#lagging> for further details).  The
@@ -66,9 +84,28 @@ const ADDITIONAL_PERCENT_FOR_BROADCAST_CHANNEL_SIZE: u32 = 20;
 
 /// A helper trait whose bounds represent the requirements for a reactor event that `run_server` can
 /// work with.
-pub trait ReactorEventT: From<Event> + Send {}
+pub trait ReactorEventT: From<Event> + From<StorageRequest> + Send + 'static {}
 
-impl<REv> ReactorEventT for REv where REv: From<Event> + Send + 'static {}
+impl<REv> ReactorEventT for REv where REv: From<Event> + From<StorageRequest> + Send + 'static {}
+
+/// Returns the total fees paid to the proposer and burned by the given execution results.
+///
+/// Totals are only reported if the network is configured to split fees, in which case every
+/// execution result records its fee breakdown.
+pub(crate) fn total_fee_breakdown<'a>(
+    execution_results: impl IntoIterator<Item = &'a ExecutionResult>,
+) -> Option<FeeBreakdown> {
+    execution_results
+        .into_iter()
+        .filter_map(ExecutionResult::fee_breakdown)
+        .fold(None, |total: Option<FeeBreakdown>, fee_breakdown| {
+            let total = total.unwrap_or_default();
+            Some(FeeBreakdown {
+                paid_to_proposer: total.paid_to_proposer + fee_breakdown.paid_to_proposer,
+                burned: total.burned + fee_breakdown.burned,
+            })
+        })
+}
 
 #[derive(DataSize, Debug)]
 struct InnerServer {
@@ -76,6 +113,12 @@ struct InnerServer {
     // TODO - this should not be skipped.  Awaiting support for `UnboundedSender` in datasize crate.
     #[data_size(skip)]
     sse_data_sender: UnboundedSender<(EventIndex, SseData)>,
+    /// Channel sender to pass changes of the number of buffered events to the event-stream server.
+    #[data_size(skip)]
+    buffer_length_sender: watch::Sender<u32>,
+    /// The maximum number of subscribers, shared with the event-stream server.
+    #[data_size(skip)]
+    max_concurrent_subscribers: Arc<AtomicU32>,
     event_indexer: EventIndexer,
     listening_address: SocketAddr,
 }
@@ -100,7 +143,32 @@ impl EventStreamServer {
         }
     }
 
-    fn listen(&mut self) -> Result<(), ListeningError> {
+    /// Changes the number of buffered events and the maximum number of subscribers.
+    ///
+    /// Takes effect for a running server as well, other than the size of the broadcast channel to
+    /// subscribers, which stays as derived from the buffer length at startup until a restart.
+    pub(crate) fn set_buffer_limits(
+        &mut self,
+        event_stream_buffer_length: u32,
+        max_concurrent_subscribers: u32,
+    ) {
+        self.config.event_stream_buffer_length = event_stream_buffer_length;
+        self.config.max_concurrent_subscribers = max_concurrent_subscribers;
+        if let Some(server) = self.sse_server.as_ref() {
+            server
+                .buffer_length_sender
+                .send_replace(event_stream_buffer_length);
+            server
+                .max_concurrent_subscribers
+                .store(max_concurrent_subscribers, Ordering::Relaxed);
+        }
+    }
+
+    /// Starts the server.
+    ///
+    /// `backfill`, if given, is used to provide subscribers with blocks added before the oldest
+    /// journaled event.
+    fn listen(&mut self, backfill: Option<BlockBackfill>) -> Result<(), ListeningError> {
         let required_address = utils::resolve_address(&self.config.address).map_err(|error| {
             warn!(
                 %error,
@@ -115,19 +183,43 @@ impl EventStreamServer {
             * (100 + ADDITIONAL_PERCENT_FOR_BROADCAST_CHANNEL_SIZE)
             / 100;
 
+        let max_concurrent_subscribers =
+            Arc::new(AtomicU32::new(self.config.max_concurrent_subscribers));
         let ChannelsAndFilter {
             event_broadcaster,
             new_subscriber_info_receiver,
             sse_filter,
         } = ChannelsAndFilter::new(
             broadcast_channel_size as usize,
-            self.config.max_concurrent_subscribers,
+            Arc::clone(&max_concurrent_subscribers),
         );
 
+        let (buffer_length_sender, buffer_length_receiver) =
+            watch::channel(self.config.event_stream_buffer_length);
+
         let (server_shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
 
         let (sse_data_sender, sse_data_receiver) = mpsc::unbounded_channel();
 
+        let journal = self.config.journal.clone().and_then(|journal_config| {
+            let dir = self.storage_path.join(JOURNAL_DIR_NAME);
+            EventJournal::open(dir.clone(), journal_config)
+                .map_err(|error| {
+                    warn!(
+                        %error,
+                        dir=%dir.display(),
+                        "failed to open event journal, continuing without it"
+                    );
+                })
+                .ok()
+        });
+        let mut event_indexer = EventIndexer::new(self.storage_path.clone());
+        // The journal may be ahead of the persisted index if the node did not shut down cleanly.
+        if let Some(last_id) = journal.as_ref().and_then(EventJournal::last_id) {
+            event_indexer.resume_after(last_id);
+        }
+        let journal = journal.map(|journal| journal.spawn(backfill.clone()));
+
         let listening_address = match self.config.cors_origin.as_str() {
             "" => {
                 let (listening_address, server_with_shutdown) = warp::serve(sse_filter)
@@ -140,13 +232,15 @@ impl EventStreamServer {
                     })?;
 
                 tokio::spawn(http_server::run(
-                    self.config.clone(),
+                    buffer_length_receiver,
                     self.api_version,
                     server_with_shutdown,
                     server_shutdown_sender,
                     sse_data_receiver,
                     event_broadcaster,
                     new_subscriber_info_receiver,
+                    journal,
+                    backfill,
                 ));
                 listening_address
             }
@@ -162,13 +256,15 @@ impl EventStreamServer {
                         })?;
 
                 tokio::spawn(http_server::run(
-                    self.config.clone(),
+                    buffer_length_receiver,
                     self.api_version,
                     server_with_shutdown,
                     server_shutdown_sender,
                     sse_data_receiver,
                     event_broadcaster,
                     new_subscriber_info_receiver,
+                    journal,
+                    backfill,
                 ));
                 listening_address
             }
@@ -185,13 +281,15 @@ impl EventStreamServer {
                 })?;
 
                 tokio::spawn(http_server::run(
-                    self.config.clone(),
+                    buffer_length_receiver,
                     self.api_version,
                     server_with_shutdown,
                     server_shutdown_sender,
                     sse_data_receiver,
                     event_broadcaster,
                     new_subscriber_info_receiver,
+                    journal,
+                    backfill,
                 ));
                 listening_address
             }
@@ -199,10 +297,10 @@ impl EventStreamServer {
 
         info!(address=%listening_address, "started event stream server");
 
-        let event_indexer = EventIndexer::new(self.storage_path.clone());
-
         self.sse_server = Some(InnerServer {
             sse_data_sender,
+            buffer_length_sender,
+            max_concurrent_subscribers,
             event_indexer,
             listening_address,
         });
@@ -261,13 +359,15 @@ where
                     <Self as InitializedComponent<MainEvent>>::set_state(self, state);
                     effects
                 }
-                Event::BlockAdded(_)
+                Event::BlockAdded { .. }
                 | Event::DeployAccepted(_)
                 | Event::DeployProcessed { .. }
                 | Event::DeploysExpired(_)
+                | Event::DeployCancelled { .. }
                 | Event::Fault { .. }
                 | Event::FinalitySignature(_)
-                | Event::Step { .. } => {
+                | Event::Step { .. }
+                | Event::ScheduledInvocationsProcessed { .. } => {
                     warn!(
                         ?event,
                         name = <Self as Component<MainEvent>>::name(self),
@@ -285,29 +385,33 @@ where
                     );
                     Effects::new()
                 }
-                Event::BlockAdded(block) => self.broadcast(SseData::BlockAdded {
-                    block_hash: *block.hash(),
-                    block: Box::new(JsonBlock::new(&block, None)),
-                }),
+                Event::BlockAdded {
+                    block,
+                    fee_breakdown,
+                } => self.broadcast(SseData::block_added(&block, fee_breakdown)),
                 Event::DeployAccepted(deploy) => self.broadcast(SseData::DeployAccepted { deploy }),
                 Event::DeployProcessed {
                     deploy_hash,
                     deploy_header,
                     block_hash,
                     execution_result,
-                } => self.broadcast(SseData::DeployProcessed {
-                    deploy_hash: Box::new(deploy_hash),
-                    account: Box::new(deploy_header.account().clone()),
-                    timestamp: deploy_header.timestamp(),
-                    ttl: deploy_header.ttl(),
-                    dependencies: deploy_header.dependencies().clone(),
-                    block_hash: Box::new(block_hash),
+                } => self.broadcast(SseData::deploy_processed(
+                    deploy_hash,
+                    &deploy_header,
+                    block_hash,
                     execution_result,
-                }),
+                )),
                 Event::DeploysExpired(deploy_hashes) => deploy_hashes
                     .into_iter()
                     .flat_map(|deploy_hash| self.broadcast(SseData::DeployExpired { deploy_hash }))
                     .collect(),
+                Event::DeployCancelled {
+                    deploy_hash,
+                    replacement,
+                } => self.broadcast(SseData::DeployCancelled {
+                    deploy_hash,
+                    replacement,
+                }),
                 Event::Fault {
                     era_id,
                     public_key,
@@ -325,6 +429,19 @@ where
                     era_id,
                     execution_effect,
                 }),
+                Event::ScheduledInvocationsProcessed {
+                    block_hash,
+                    execution_results,
+                } => execution_results
+                    .into_iter()
+                    .flat_map(|(invocation, execution_result)| {
+                        self.broadcast(SseData::ScheduledInvocationProcessed {
+                            invocation,
+                            block_hash: Box::new(block_hash),
+                            execution_result: Box::new(execution_result),
+                        })
+                    })
+                    .collect(),
             },
         }
     }
@@ -362,9 +479,19 @@ where
 
     fn listen(
         &mut self,
-        _effect_builder: EffectBuilder<REv>,
+        effect_builder: EffectBuilder<REv>,
     ) -> Result<Effects<Self::ComponentEvent>, Self::Error> {
-        self.listen()?;
+        let backfill: BlockBackfill = Arc::new(move |block_hash| {
+            async move {
+                let block = effect_builder.get_block_from_storage(block_hash).await?;
+                let execution_results = effect_builder
+                    .get_execution_results_from_storage(block_hash)
+                    .await?;
+                Some((block, execution_results))
+            }
+            .boxed()
+        });
+        self.listen(Some(backfill))?;
         Ok(Effects::new())
     }
 }
@@ -1,6 +1,8 @@
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
+use casper_types::TimeDiff;
+
 /// Default binding address for the SSE HTTP server.
 ///
 /// Uses a fixed port per node, but binds on any interface.
@@ -15,6 +17,15 @@ const DEFAULT_MAX_CONCURRENT_SUBSCRIBERS: u32 = 100;
 /// Default CORS origin.
 const DEFAULT_CORS_ORIGIN: &str = "";
 
+/// Default maximum size of the event journal: 1 GiB.
+const DEFAULT_JOURNAL_MAX_SIZE: u64 = 1 << 30;
+
+/// Default maximum age of journaled events: 7 days.
+const DEFAULT_JOURNAL_MAX_AGE: TimeDiff = TimeDiff::from_seconds(7 * 24 * 60 * 60);
+
+/// Default maximum number of blocks whose events can be rebuilt from storage.
+const DEFAULT_JOURNAL_MAX_BACKFILL_BLOCKS: u32 = 10_000;
+
 /// SSE HTTP server configuration.
 #[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
 // Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
@@ -34,6 +45,10 @@ pub struct Config {
 
     /// CORS origin.
     pub cors_origin: String,
+
+    /// On-disk event journal.  If not set, events are only kept in memory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub journal: Option<JournalConfig>,
 }
 
 impl Config {
@@ -45,6 +60,7 @@ impl Config {
             event_stream_buffer_length: DEFAULT_EVENT_STREAM_BUFFER_LENGTH,
             max_concurrent_subscribers: DEFAULT_MAX_CONCURRENT_SUBSCRIBERS,
             cors_origin: DEFAULT_CORS_ORIGIN.to_string(),
+            journal: None,
         }
     }
 }
@@ -54,3 +70,29 @@ impl Default for Config {
         Config::new()
     }
 }
+
+/// On-disk event journal configuration.
+#[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct JournalConfig {
+    /// Maximum size of the journal in bytes.  The oldest events are discarded beyond this size.
+    pub max_size: u64,
+
+    /// Maximum age of journaled events.  Older events are discarded.
+    pub max_age: TimeDiff,
+
+    /// Maximum number of blocks whose `BlockAdded` and `DeployProcessed` events are rebuilt from
+    /// storage when a client requests events older than the journal.
+    pub max_backfill_blocks: u32,
+}
+
+impl Default for JournalConfig {
+    fn default() -> Self {
+        JournalConfig {
+            max_size: DEFAULT_JOURNAL_MAX_SIZE,
+            max_age: DEFAULT_JOURNAL_MAX_AGE,
+            max_backfill_blocks: DEFAULT_JOURNAL_MAX_BACKFILL_BLOCKS,
+        }
+    }
+}
@@ -3,7 +3,9 @@ use std::{
     sync::Arc,
 };
 
-use casper_types::{EraId, ExecutionEffect, ExecutionResult, PublicKey, Timestamp};
+use casper_types::{
+    EraId, ExecutionEffect, ExecutionResult, FeeBreakdown, Key, PublicKey, Timestamp,
+};
 use itertools::Itertools;
 
 use crate::types::{Block, BlockHash, Deploy, DeployHash, DeployHeader, FinalitySignature};
@@ -11,7 +13,10 @@ use crate::types::{Block, BlockHash, Deploy, DeployHash, DeployHeader, FinalityS
 #[derive(Debug)]
 pub enum Event {
     Initialize,
-    BlockAdded(Arc<Block>),
+    BlockAdded {
+        block: Arc<Block>,
+        fee_breakdown: Option<FeeBreakdown>,
+    },
     DeployAccepted(Arc<Deploy>),
     DeployProcessed {
         deploy_hash: DeployHash,
@@ -20,6 +25,10 @@ pub enum Event {
         execution_result: Box<ExecutionResult>,
     },
     DeploysExpired(Vec<DeployHash>),
+    DeployCancelled {
+        deploy_hash: DeployHash,
+        replacement: Option<DeployHash>,
+    },
     Fault {
         era_id: EraId,
         public_key: Box<PublicKey>,
@@ -30,13 +39,17 @@ pub enum Event {
         era_id: EraId,
         execution_effect: ExecutionEffect,
     },
+    ScheduledInvocationsProcessed {
+        block_hash: BlockHash,
+        execution_results: Vec<(Key, ExecutionResult)>,
+    },
 }
 
 impl Display for Event {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         match self {
             Event::Initialize => write!(formatter, "initialize"),
-            Event::BlockAdded(block) => write!(formatter, "block added {}", block.hash()),
+            Event::BlockAdded { block, .. } => write!(formatter, "block added {}", block.hash()),
             Event::DeployAccepted(deploy_hash) => {
                 write!(formatter, "deploy accepted {}", deploy_hash)
             }
@@ -47,6 +60,9 @@ impl Display for Event {
                     deploy_hashes.iter().join(", ")
                 )
             }
+            Event::DeployCancelled { deploy_hash, .. } => {
+                write!(formatter, "deploy cancelled {}", deploy_hash)
+            }
             Event::DeployProcessed { deploy_hash, .. } => {
                 write!(formatter, "deploy processed {}", deploy_hash)
             }
@@ -61,6 +77,13 @@ impl Display for Event {
             ),
             Event::FinalitySignature(fs) => write!(formatter, "finality signature {}", fs),
             Event::Step { era_id, .. } => write!(formatter, "step committed for {}", era_id),
+            Event::ScheduledInvocationsProcessed { block_hash, .. } => {
+                write!(
+                    formatter,
+                    "scheduled invocations processed in {}",
+                    block_hash
+                )
+            }
         }
     }
 }
@@ -55,6 +55,22 @@ impl EventIndexer {
         index
     }
 
+    /// Continues with the index following `last_index`, if it is ahead of the cached one.
+    ///
+    /// The cache is only written on shutdown, so is outdated if the node didn't shut down cleanly.
+    pub(super) fn resume_after(&mut self, last_index: EventIndex) {
+        let index = last_index.wrapping_add(1);
+        let distance = index.wrapping_sub(self.index);
+        if distance != 0 && distance <= EventIndex::MAX / 2 {
+            debug!(
+                cached_index = %self.index,
+                %index,
+                "resuming sse index after last journaled event"
+            );
+            self.index = index;
+        }
+    }
+
     #[cfg(test)]
     pub(super) fn current_index(&self) -> EventIndex {
         self.index
@@ -121,6 +137,26 @@ mod tests {
         assert_eq!(event_indexer.next_index(), 0);
     }
 
+    #[test]
+    fn should_resume_after_newer_index() {
+        let _ = logging::init();
+        let tempdir = tempfile::tempdir().unwrap();
+
+        let mut event_indexer = EventIndexer::new(tempdir.path().to_path_buf());
+        event_indexer.index = 10;
+
+        // An older index, e.g. from a journal which has been disabled for a while, is ignored.
+        event_indexer.resume_after(5);
+        assert_eq!(event_indexer.next_index(), 10);
+
+        event_indexer.resume_after(20);
+        assert_eq!(event_indexer.next_index(), 21);
+
+        event_indexer.index = EventIndex::MAX - 1;
+        event_indexer.resume_after(1);
+        assert_eq!(event_indexer.next_index(), 2);
+    }
+
     #[test]
     fn should_reset_index_on_cache_read_failure() {
         let _ = logging::init();
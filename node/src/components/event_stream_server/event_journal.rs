@@ -0,0 +1,916 @@
+//! On-disk journal of the events sent on the event stream.
+//!
+//! Events are appended as JSON lines to segment files, which are discarded oldest first once the
+//! journal exceeds its maximum size, or the newest event of the oldest segment its maximum age.
+//!
+//! The IDs of `BlockAdded` events are additionally recorded in a block index, which is retained for
+//! a configured number of blocks regardless of the segments.  This allows the `BlockAdded` and
+//! `DeployProcessed` events of blocks no longer journaled to be rebuilt from storage, with the IDs
+//! they were originally sent with.  While the index has room for more blocks, it is seeded from
+//! storage with the blocks added before the journal was enabled, under reconstructed IDs.
+//!
+//! The journal's file I/O is run on a blocking task, see [`EventJournal::spawn`].
+
+use std::{
+    collections::VecDeque,
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, ErrorKind, Write},
+    iter,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use futures::future::BoxFuture;
+#[cfg(test)]
+use futures::FutureExt;
+use serde::{
+    de::{DeserializeOwned, IgnoredAny},
+    Deserialize, Serialize,
+};
+use tokio::{
+    sync::{
+        mpsc::{self, UnboundedSender},
+        oneshot,
+    },
+    task,
+};
+use tracing::{debug, warn};
+
+use casper_hashing::Digest;
+use casper_types::ExecutionResult;
+
+use super::{
+    config::JournalConfig,
+    sse_server::{Id, ServerSentEvent},
+    total_fee_breakdown, SseData,
+};
+use crate::types::{Block, BlockHash, DeployHash, DeployHeader};
+
+/// File extension of the segment files, which are named after their sequence number.
+const SEGMENT_EXTENSION: &str = "jsonl";
+
+/// File name of the block index.
+const BLOCK_INDEX_FILENAME: &str = "block_index";
+
+/// Size of a block index entry: the event ID followed by the block hash.
+const BLOCK_INDEX_ENTRY_SIZE: usize = 4 + Digest::LENGTH;
+
+/// Number of segments the journal is split into, bounding the share of events discarded at once.
+const SEGMENT_COUNT: u64 = 16;
+
+/// Retrieves a block and the execution results of its deploys from storage.
+pub(super) type BlockBackfill = Arc<
+    dyn Fn(BlockHash) -> BoxFuture<'static, Option<(Block, Vec<ExecutedDeploy>)>> + Send + Sync,
+>;
+
+/// A deploy executed as part of a block, along with its execution result.
+pub(super) type ExecutedDeploy = (DeployHash, DeployHeader, ExecutionResult);
+
+/// A single line of a segment file.
+#[derive(Serialize, Deserialize)]
+struct JournalEntry<D> {
+    id: Id,
+    data: D,
+}
+
+/// A segment file of the journal.
+#[derive(Debug)]
+struct Segment {
+    path: PathBuf,
+    sequence: u64,
+    /// The ID of the first event in the segment, or `None` if it is empty.
+    first_id: Option<Id>,
+    size: u64,
+    last_modified: SystemTime,
+}
+
+/// The on-disk journal of events.
+#[derive(Debug)]
+pub(super) struct EventJournal {
+    dir: PathBuf,
+    config: JournalConfig,
+    /// The segments, oldest first.  Events are appended to the last one.
+    segments: VecDeque<Segment>,
+    current_file: File,
+    /// The ID of the most recently journaled event.
+    last_id: Option<Id>,
+    /// The IDs of the retained `BlockAdded` events, oldest first.
+    block_index: VecDeque<(Id, BlockHash)>,
+    block_index_file: File,
+    /// The number of entries in the block index file, which may exceed the retained ones.
+    block_index_file_len: usize,
+}
+
+impl EventJournal {
+    /// Opens the journal in the given directory, creating it if necessary.
+    ///
+    /// Events are always appended to a new segment, as the last one may have been left with a
+    /// partially written event.
+    pub(super) fn open(dir: PathBuf, config: JournalConfig) -> io::Result<Self> {
+        fs::create_dir_all(&dir)?;
+
+        let mut segments = Vec::new();
+        for dir_entry in fs::read_dir(&dir)? {
+            let path = dir_entry?.path();
+            let sequence = match segment_sequence(&path) {
+                Some(sequence) => sequence,
+                None => continue,
+            };
+            let metadata = fs::metadata(&path)?;
+            if metadata.len() == 0 {
+                fs::remove_file(&path)?;
+                continue;
+            }
+            segments.push(Segment {
+                first_id: read_segment::<IgnoredAny>(&path)
+                    .next()
+                    .map(|entry| entry.id),
+                path,
+                sequence,
+                size: metadata.len(),
+                last_modified: metadata.modified()?,
+            });
+        }
+        segments.sort_by_key(|segment| segment.sequence);
+        let last_id = segments
+            .last()
+            .and_then(|segment| read_segment::<IgnoredAny>(&segment.path).last())
+            .map(|entry| entry.id);
+
+        let block_index = read_block_index(&dir, config.max_backfill_blocks as usize)?;
+        let block_index_file = write_block_index(&dir, &block_index)?;
+        let next_sequence = segments.last().map_or(0, |segment| segment.sequence + 1);
+        let mut journal = EventJournal {
+            current_file: create_segment_file(&dir, next_sequence)?,
+            dir,
+            config,
+            segments: segments.into(),
+            last_id,
+            block_index_file_len: block_index.len(),
+            block_index,
+            block_index_file,
+        };
+        journal.segments.push_back(Segment {
+            path: segment_path(&journal.dir, next_sequence),
+            sequence: next_sequence,
+            first_id: None,
+            size: 0,
+            last_modified: SystemTime::now(),
+        });
+        journal.prune();
+
+        debug!(
+            dir = %journal.dir.display(),
+            segment_count = journal.segments.len(),
+            last_id = ?journal.last_id,
+            "opened event journal"
+        );
+        Ok(journal)
+    }
+
+    /// Returns the ID of the most recently journaled event.
+    pub(super) fn last_id(&self) -> Option<Id> {
+        self.last_id
+    }
+
+    /// Appends the event to the journal, discarding the oldest events if necessary.
+    pub(super) fn append(&mut self, id: Id, data: &SseData) -> io::Result<()> {
+        let mut line = serde_json::to_vec(&JournalEntry { id, data })?;
+        line.push(b'\n');
+
+        let segment_size = (self.config.max_size / SEGMENT_COUNT).max(1);
+        if self.current_segment().size >= segment_size {
+            self.start_segment()?;
+        }
+        self.current_file.write_all(&line)?;
+        let segment = self.current_segment();
+        segment.first_id.get_or_insert(id);
+        segment.size += line.len() as u64;
+        segment.last_modified = SystemTime::now();
+        self.last_id = Some(id);
+
+        if let SseData::BlockAdded { block_hash, .. } = data {
+            self.index_block(id, *block_hash)?;
+        }
+        self.prune();
+        Ok(())
+    }
+
+    /// Returns the journaled events and block index as of now, to replay events from.
+    pub(super) fn snapshot(&self) -> JournalSnapshot {
+        JournalSnapshot {
+            segments: self
+                .segments
+                .iter()
+                .filter_map(|segment| Some((segment.path.clone(), segment.first_id?)))
+                .collect(),
+            block_index: self.block_index.iter().copied().collect(),
+            next_id: self.last_id.map(|last_id| last_id.wrapping_add(1)),
+        }
+    }
+
+    fn current_segment(&mut self) -> &mut Segment {
+        self.segments
+            .back_mut()
+            .expect("event journal should always have a current segment")
+    }
+
+    fn start_segment(&mut self) -> io::Result<()> {
+        let sequence = self.current_segment().sequence + 1;
+        self.current_file = create_segment_file(&self.dir, sequence)?;
+        self.segments.push_back(Segment {
+            path: segment_path(&self.dir, sequence),
+            sequence,
+            first_id: None,
+            size: 0,
+            last_modified: SystemTime::now(),
+        });
+        Ok(())
+    }
+
+    /// Discards the oldest segments, other than the current one, while the journal exceeds its
+    /// maximum size or the oldest segment its maximum age.
+    fn prune(&mut self) {
+        let max_age = Duration::from(self.config.max_age);
+        let oldest_retained = SystemTime::now()
+            .checked_sub(max_age)
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+        let mut total_size: u64 = self.segments.iter().map(|segment| segment.size).sum();
+        while self.segments.len() > 1 {
+            let oldest = &self.segments[0];
+            if total_size <= self.config.max_size && oldest.last_modified >= oldest_retained {
+                break;
+            }
+            total_size -= oldest.size;
+            match fs::remove_file(&oldest.path) {
+                Ok(()) => debug!(
+                    file = %oldest.path.display(),
+                    first_id = ?oldest.first_id,
+                    "discarded event journal segment"
+                ),
+                Err(error) => warn!(
+                    file = %oldest.path.display(),
+                    %error,
+                    "failed to remove event journal segment"
+                ),
+            }
+            self.segments.pop_front();
+        }
+    }
+
+    fn index_block(&mut self, id: Id, block_hash: BlockHash) -> io::Result<()> {
+        let max_len = self.config.max_backfill_blocks as usize;
+        if max_len == 0 {
+            return Ok(());
+        }
+        self.block_index.push_back((id, block_hash));
+        if self.block_index.len() > max_len {
+            self.block_index.pop_front();
+        }
+
+        // Rewrite the file once it holds twice the retained entries, rather than let it grow.
+        if self.block_index_file_len >= 2 * max_len {
+            self.block_index_file = write_block_index(&self.dir, &self.block_index)?;
+            self.block_index_file_len = self.block_index.len();
+        } else {
+            self.block_index_file
+                .write_all(&encode_block_index_entry(id, &block_hash))?;
+            self.block_index_file_len += 1;
+        }
+        Ok(())
+    }
+    /// Adds the given entries, oldest first, to the block index ahead of its oldest entry, as far
+    /// as the index has room for them.
+    fn prepend_to_block_index(&mut self, entries: Vec<(Id, BlockHash)>) -> io::Result<()> {
+        let max_len = self.config.max_backfill_blocks as usize;
+        let room = max_len.saturating_sub(self.block_index.len());
+        if room == 0 || entries.is_empty() {
+            return Ok(());
+        }
+        for entry in entries.into_iter().rev().take(room) {
+            self.block_index.push_front(entry);
+        }
+        self.block_index_file = write_block_index(&self.dir, &self.block_index)?;
+        self.block_index_file_len = self.block_index.len();
+        debug!(
+            block_index_len = self.block_index.len(),
+            "seeded event journal block index"
+        );
+        Ok(())
+    }
+
+    /// Moves the journal to a blocking task, so that its file I/O doesn't block the caller.
+    ///
+    /// If the block index has room for more blocks and `backfill` is given, it is seeded with the
+    /// blocks preceding its oldest entry, or if it is empty, the first one appended.
+    pub(super) fn spawn(self, backfill: Option<BlockBackfill>) -> JournalHandle {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let max_len = self.config.max_backfill_blocks as usize;
+        let seed_count = max_len.saturating_sub(self.block_index.len());
+        let oldest_indexed = self.block_index.front().copied();
+        let mut handle = JournalHandle {
+            sender,
+            last_id: self.last_id,
+            pending_seed: None,
+        };
+        if let Some(backfill) = backfill.filter(|_| seed_count > 0) {
+            match oldest_indexed {
+                Some(anchor) => handle.seed_block_index(backfill, anchor, seed_count),
+                None => handle.pending_seed = Some((backfill, seed_count)),
+            }
+        }
+
+        let mut journal = self;
+        task::spawn_blocking(move || {
+            while let Some(command) = receiver.blocking_recv() {
+                match command {
+                    JournalCommand::Append(id, data) => {
+                        if let Err(error) = journal.append(id, &data) {
+                            warn!(%error, "failed to append event to journal");
+                        }
+                    }
+                    JournalCommand::Snapshot(responder) => {
+                        let _ = responder.send(journal.snapshot());
+                    }
+                    JournalCommand::SeedBlockIndex(entries) => {
+                        if let Err(error) = journal.prepend_to_block_index(entries) {
+                            warn!(%error, "failed to seed event journal block index");
+                        }
+                    }
+                }
+            }
+        });
+        handle
+    }
+}
+
+/// A request to the task running the journal.
+enum JournalCommand {
+    Append(Id, SseData),
+    Snapshot(oneshot::Sender<JournalSnapshot>),
+    SeedBlockIndex(Vec<(Id, BlockHash)>),
+}
+
+/// Handle to a journal running on a blocking task.
+///
+/// Requests are handled in the order they are made, so a snapshot includes all events appended
+/// before it was requested.
+pub(super) struct JournalHandle {
+    sender: UnboundedSender<JournalCommand>,
+    /// The ID of the most recently appended event.
+    last_id: Option<Id>,
+    /// The means to seed the empty block index once the first block is appended, and the number
+    /// of blocks to seed it with.
+    pending_seed: Option<(BlockBackfill, usize)>,
+}
+
+impl JournalHandle {
+    /// Returns the ID of the most recently appended event.
+    pub(super) fn last_id(&self) -> Option<Id> {
+        self.last_id
+    }
+
+    /// Appends the event to the journal.
+    pub(super) fn append(&mut self, id: Id, data: &SseData) {
+        self.last_id = Some(id);
+        let _ = self.sender.send(JournalCommand::Append(id, data.clone()));
+        if let SseData::BlockAdded { block_hash, .. } = data {
+            if let Some((backfill, seed_count)) = self.pending_seed.take() {
+                self.seed_block_index(backfill, (id, *block_hash), seed_count);
+            }
+        }
+    }
+
+    /// Returns the journaled events and block index as of all events appended so far.
+    pub(super) fn snapshot(&self) -> oneshot::Receiver<JournalSnapshot> {
+        let (responder, receiver) = oneshot::channel();
+        let _ = self.sender.send(JournalCommand::Snapshot(responder));
+        receiver
+    }
+
+    /// Retrieves up to `count` blocks preceding the `anchor` block in the background, and adds them
+    /// to the block index.
+    fn seed_block_index(&self, backfill: BlockBackfill, anchor: (Id, BlockHash), count: usize) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            let entries = preceding_blocks(&backfill, anchor, count).await;
+            let _ = sender.send(JournalCommand::SeedBlockIndex(entries));
+        });
+    }
+}
+
+/// Returns up to `count` blocks preceding the `anchor` block, oldest first, along with the IDs of
+/// their `BlockAdded` events.
+///
+/// These blocks were added before the journal recorded their events, so their original IDs are
+/// unknown.  They are assigned the IDs they would have had if only their `BlockAdded` and
+/// `DeployProcessed` events had been sent before the anchor's `BlockAdded` event.  As the original
+/// IDs can only be lower, a client resuming from an ID is provided with all blocks it missed, and
+/// possibly a few it already received.
+async fn preceding_blocks(
+    backfill: &BlockBackfill,
+    anchor: (Id, BlockHash),
+    count: usize,
+) -> Vec<(Id, BlockHash)> {
+    let (mut next_id, anchor_hash) = anchor;
+    let mut entries = Vec::new();
+    let mut block = match backfill(anchor_hash).await {
+        Some((block, _)) => block,
+        None => return entries,
+    };
+    while entries.len() < count && block.header().height() > 0 {
+        let parent_hash = *block.header().parent_hash();
+        let (parent, executed_deploys) = match backfill(parent_hash).await {
+            Some(block_and_deploys) => block_and_deploys,
+            None => break,
+        };
+        next_id = next_id.wrapping_sub(1 + executed_deploys.len() as Id);
+        entries.push((next_id, parent_hash));
+        block = parent;
+    }
+    entries.reverse();
+    entries
+}
+
+/// The journaled events and block index at a point in time, from which events can be replayed.
+pub(super) struct JournalSnapshot {
+    /// The paths and first event IDs of the non-empty segments, oldest first.
+    segments: Vec<(PathBuf, Id)>,
+    block_index: Vec<(Id, BlockHash)>,
+    /// The ID following the most recently journaled event.
+    next_id: Option<Id>,
+}
+
+impl JournalSnapshot {
+    /// Sends all events from `start_from` up to, but excluding, `end` to `sender`.
+    ///
+    /// If given, `backfill` is used to rebuild the `BlockAdded` and `DeployProcessed` events older
+    /// than the journal.  Other kinds of events older than the journal are lost.
+    pub(super) async fn replay(
+        self,
+        backfill: Option<BlockBackfill>,
+        start_from: Id,
+        end: Id,
+        sender: &UnboundedSender<ServerSentEvent>,
+    ) {
+        let range = ReplayRange {
+            reference: self.next_id.unwrap_or(end),
+            start_from,
+            end,
+        };
+        let journal_start = self.segments.first().map_or(end, |(_, first_id)| *first_id);
+        if let Some(backfill) = backfill {
+            if range.precedes(start_from, journal_start)
+                && !self.backfill(&backfill, range, journal_start, sender).await
+            {
+                return;
+            }
+        }
+
+        let segments = self.segments;
+        let sender = sender.clone();
+        let result = task::spawn_blocking(move || {
+            for (index, (path, _)) in segments.iter().enumerate() {
+                // Skip segments whose events all precede `start_from`.
+                if let Some((_, next_first_id)) = segments.get(index + 1) {
+                    if !range.precedes(start_from, *next_first_id) {
+                        continue;
+                    }
+                }
+                for entry in read_segment::<SseData>(path) {
+                    if !range.contains(entry.id) {
+                        continue;
+                    }
+                    let event = ServerSentEvent {
+                        id: Some(entry.id),
+                        data: entry.data,
+                    };
+                    if sender.send(event).is_err() {
+                        return;
+                    }
+                }
+            }
+        })
+        .await;
+        if let Err(error) = result {
+            warn!(%error, "failed to replay events from journal");
+        }
+    }
+
+    /// Rebuilds the `BlockAdded` and `DeployProcessed` events in `range` preceding `journal_start`
+    /// from storage.
+    ///
+    /// Returns `false` if the client has disconnected.
+    async fn backfill(
+        &self,
+        backfill: &BlockBackfill,
+        range: ReplayRange,
+        journal_start: Id,
+        sender: &UnboundedSender<ServerSentEvent>,
+    ) -> bool {
+        let blocks: Vec<_> = self
+            .block_index
+            .iter()
+            .filter(|(id, _)| range.precedes(*id, journal_start))
+            .collect();
+        for (index, (block_added_id, block_hash)) in blocks.iter().enumerate() {
+            // Skip blocks whose events all precede `start_from`.
+            if let Some((next_block_added_id, _)) = blocks.get(index + 1) {
+                if !range.precedes(range.start_from, *next_block_added_id) {
+                    continue;
+                }
+            }
+            let (block, executed_deploys) = match backfill(*block_hash).await {
+                Some(block_and_deploys) => block_and_deploys,
+                None => {
+                    debug!(%block_hash, "block not available to rebuild events from");
+                    continue;
+                }
+            };
+
+            // The `DeployProcessed` events were sent directly after the block's `BlockAdded` event.
+            let fee_breakdown =
+                total_fee_breakdown(executed_deploys.iter().map(|(_, _, result)| result));
+            let events = iter::once(SseData::block_added(&block, fee_breakdown)).chain(
+                executed_deploys.into_iter().map(
+                    |(deploy_hash, deploy_header, execution_result)| {
+                        SseData::deploy_processed(
+                            deploy_hash,
+                            &deploy_header,
+                            *block_hash,
+                            Box::new(execution_result),
+                        )
+                    },
+                ),
+            );
+            for (offset, data) in events.enumerate() {
+                let id = block_added_id.wrapping_add(offset as Id);
+                if range.contains(id)
+                    && range.precedes(id, journal_start)
+                    && sender.send(ServerSentEvent { id: Some(id), data }).is_err()
+                {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+/// A range of event IDs to replay.
+///
+/// As IDs wrap around, they are ordered by their age relative to a reference ID following all
+/// events of interest.
+#[derive(Clone, Copy, Debug)]
+struct ReplayRange {
+    reference: Id,
+    start_from: Id,
+    /// The first ID past the range.
+    end: Id,
+}
+
+impl ReplayRange {
+    fn age(&self, id: Id) -> Id {
+        self.reference.wrapping_sub(id)
+    }
+
+    /// Returns whether the event with ID `id` was sent before the one with ID `other`.
+    fn precedes(&self, id: Id, other: Id) -> bool {
+        self.age(id) > self.age(other)
+    }
+
+    fn contains(&self, id: Id) -> bool {
+        !self.precedes(id, self.start_from) && self.precedes(id, self.end)
+    }
+}
+
+fn segment_path(dir: &Path, sequence: u64) -> PathBuf {
+    dir.join(format!("{:020}.{}", sequence, SEGMENT_EXTENSION))
+}
+
+fn segment_sequence(path: &Path) -> Option<u64> {
+    if path.extension()? != SEGMENT_EXTENSION {
+        return None;
+    }
+    path.file_stem()?.to_str()?.parse().ok()
+}
+
+fn create_segment_file(dir: &Path, sequence: u64) -> io::Result<File> {
+    OpenOptions::new()
+        .append(true)
+        .create_new(true)
+        .open(segment_path(dir, sequence))
+}
+
+/// Reads the entries of the given segment, stopping at the first one which can't be parsed.
+///
+/// The last entry may be partially written, either as it is being appended to or as the node
+/// didn't shut down cleanly.
+fn read_segment<D: DeserializeOwned>(path: &Path) -> impl Iterator<Item = JournalEntry<D>> {
+    let maybe_file = match File::open(path) {
+        Ok(file) => Some(file),
+        Err(error) => {
+            // The segment may have been discarded in the meantime.
+            if error.kind() != ErrorKind::NotFound {
+                warn!(file = %path.display(), %error, "failed to read event journal segment");
+            }
+            None
+        }
+    };
+    maybe_file
+        .into_iter()
+        .flat_map(|file| BufReader::new(file).lines())
+        .map_while(|line| serde_json::from_str(&line.ok()?).ok())
+}
+
+fn encode_block_index_entry(id: Id, block_hash: &BlockHash) -> [u8; BLOCK_INDEX_ENTRY_SIZE] {
+    let mut entry = [0; BLOCK_INDEX_ENTRY_SIZE];
+    entry[..4].copy_from_slice(&id.to_le_bytes());
+    entry[4..].copy_from_slice(block_hash.as_ref());
+    entry
+}
+
+/// Reads the newest `max_len` entries of the block index.
+fn read_block_index(dir: &Path, max_len: usize) -> io::Result<VecDeque<(Id, BlockHash)>> {
+    let bytes = match fs::read(dir.join(BLOCK_INDEX_FILENAME)) {
+        Ok(bytes) => bytes,
+        Err(error) if error.kind() == ErrorKind::NotFound => Vec::new(),
+        Err(error) => return Err(error),
+    };
+    let entries = bytes.chunks_exact(BLOCK_INDEX_ENTRY_SIZE);
+    let skip_count = entries.len().saturating_sub(max_len);
+    Ok(entries
+        .skip(skip_count)
+        .map(|entry| {
+            let mut id = [0; 4];
+            id.copy_from_slice(&entry[..4]);
+            let mut hash = [0; Digest::LENGTH];
+            hash.copy_from_slice(&entry[4..]);
+            (Id::from_le_bytes(id), BlockHash::new(Digest::from(hash)))
+        })
+        .collect())
+}
+
+/// Replaces the block index file with the given entries, returning the file to append to.
+fn write_block_index(dir: &Path, block_index: &VecDeque<(Id, BlockHash)>) -> io::Result<File> {
+    let path = dir.join(BLOCK_INDEX_FILENAME);
+    let temp_path = path.with_extension("tmp");
+    let bytes: Vec<u8> = block_index
+        .iter()
+        .flat_map(|(id, block_hash)| encode_block_index_entry(*id, block_hash))
+        .collect();
+    fs::write(&temp_path, bytes)?;
+    fs::rename(&temp_path, &path)?;
+    OpenOptions::new().append(true).open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use rand::Rng;
+    use tokio::sync::mpsc;
+
+    use casper_types::{testing::TestRng, EraId, ProtocolVersion};
+
+    use super::*;
+    use crate::types::Deploy;
+
+    fn config(max_size: u64, max_backfill_blocks: u32) -> JournalConfig {
+        JournalConfig {
+            max_size,
+            max_backfill_blocks,
+            ..Default::default()
+        }
+    }
+
+    async fn replay(
+        journal: &EventJournal,
+        backfill: Option<BlockBackfill>,
+        start_from: Id,
+        end: Id,
+    ) -> Vec<ServerSentEvent> {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        journal
+            .snapshot()
+            .replay(backfill, start_from, end, &sender)
+            .await;
+        drop(sender);
+        let mut events = Vec::new();
+        while let Some(event) = receiver.recv().await {
+            events.push(event);
+        }
+        events
+    }
+
+    fn ids(events: &[ServerSentEvent]) -> Vec<Id> {
+        events.iter().map(|event| event.id.unwrap()).collect()
+    }
+
+    #[tokio::test]
+    async fn should_replay_journaled_events_across_restarts() {
+        let mut rng = TestRng::new();
+        let tempdir = tempfile::tempdir().unwrap();
+        let events: Vec<_> = (0..20).map(|_| SseData::random_fault(&mut rng)).collect();
+
+        {
+            let mut journal =
+                EventJournal::open(tempdir.path().to_path_buf(), config(1 << 20, 0)).unwrap();
+            for (id, data) in events.iter().enumerate().take(10) {
+                journal.append(id as Id, data).unwrap();
+            }
+        }
+        let mut journal =
+            EventJournal::open(tempdir.path().to_path_buf(), config(1 << 20, 0)).unwrap();
+        assert_eq!(journal.last_id(), Some(9));
+        for (id, data) in events.iter().enumerate().skip(10) {
+            journal.append(id as Id, data).unwrap();
+        }
+
+        let replayed = replay(&journal, None, 5, 15).await;
+        assert_eq!(ids(&replayed), (5..15).collect::<Vec<_>>());
+        assert_eq!(replayed[0].data, events[5]);
+    }
+
+    #[tokio::test]
+    async fn should_discard_oldest_segments() {
+        let mut rng = TestRng::new();
+        let tempdir = tempfile::tempdir().unwrap();
+        let data = SseData::random_fault(&mut rng);
+        // All IDs have the same number of digits, so all entries have the same size.
+        let line_len = serde_json::to_vec(&JournalEntry {
+            id: 100,
+            data: &data,
+        })
+        .unwrap()
+        .len() as u64
+            + 1;
+
+        // Each segment holds a single event, so exactly `SEGMENT_COUNT` events are retained.
+        let mut journal = EventJournal::open(
+            tempdir.path().to_path_buf(),
+            config(line_len * SEGMENT_COUNT, 0),
+        )
+        .unwrap();
+        for id in 100..120 {
+            journal.append(id, &data).unwrap();
+        }
+
+        let replayed = replay(&journal, None, 100, 120).await;
+        assert_eq!(
+            ids(&replayed),
+            (120 - SEGMENT_COUNT as Id..120).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_replay_across_id_wraparound() {
+        let mut rng = TestRng::new();
+        let tempdir = tempfile::tempdir().unwrap();
+        let data = SseData::random_fault(&mut rng);
+        let mut journal =
+            EventJournal::open(tempdir.path().to_path_buf(), config(1 << 20, 0)).unwrap();
+        let first_id = Id::MAX - 2;
+        for offset in 0..6 {
+            journal
+                .append(first_id.wrapping_add(offset), &data)
+                .unwrap();
+        }
+
+        let replayed = replay(&journal, None, Id::MAX - 1, 2).await;
+        assert_eq!(ids(&replayed), vec![Id::MAX - 1, Id::MAX, 0, 1]);
+    }
+
+    #[tokio::test]
+    async fn should_backfill_blocks_older_than_journal() {
+        let mut rng = TestRng::new();
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut journal =
+            EventJournal::open(tempdir.path().to_path_buf(), config(1 << 20, 10)).unwrap();
+
+        // Journal two blocks without deploys and discard their segments, then journal a third.
+        let blocks: Vec<Block> = (0..3).map(|_| Block::random(&mut rng)).collect();
+        for (id, block) in blocks.iter().enumerate().take(2) {
+            journal
+                .append(id as Id, &SseData::block_added(block, None))
+                .unwrap();
+            journal.start_segment().unwrap();
+        }
+        journal.config.max_size = 0;
+        journal.prune();
+        journal
+            .append(2, &SseData::block_added(&blocks[2], None))
+            .unwrap();
+
+        let stored_blocks = blocks.clone();
+        let backfill: BlockBackfill = Arc::new(move |block_hash| {
+            let maybe_block = stored_blocks
+                .iter()
+                .find(|block| *block.hash() == block_hash)
+                .cloned();
+            async move { Some((maybe_block?, vec![])) }.boxed()
+        });
+
+        let replayed = replay(&journal, Some(backfill), 0, 3).await;
+        assert_eq!(ids(&replayed), vec![0, 1, 2]);
+        for (event, block) in replayed.iter().zip(&blocks) {
+            assert_eq!(event.data, SseData::block_added(block, None));
+        }
+    }
+
+    #[tokio::test]
+    async fn should_seed_block_index_with_blocks_preceding_journal() {
+        let mut rng = TestRng::new();
+        let tempdir = tempfile::tempdir().unwrap();
+
+        // A chain of four blocks, the second of which has two deploys.
+        let mut stored_blocks: Vec<(Block, Vec<ExecutedDeploy>)> = Vec::new();
+        for height in 0..4 {
+            let parent_hash = stored_blocks.last().map(|(parent, _)| *parent.hash());
+            let block = Block::random_with_specifics_and_parent_and_validator_weights(
+                &mut rng,
+                EraId::new(0),
+                height,
+                ProtocolVersion::V1_0_0,
+                false,
+                None,
+                parent_hash,
+                BTreeMap::new(),
+            );
+            let mut executed_deploys = Vec::new();
+            if height == 1 {
+                for _ in 0..2 {
+                    let deploy = Deploy::random(&mut rng);
+                    executed_deploys.push((*deploy.hash(), deploy.take_header(), rng.gen()));
+                }
+            }
+            stored_blocks.push((block, executed_deploys));
+        }
+        let hashes: Vec<BlockHash> = stored_blocks
+            .iter()
+            .map(|(block, _)| *block.hash())
+            .collect();
+        let backfill: BlockBackfill = Arc::new(move |block_hash| {
+            let maybe_block_and_deploys = stored_blocks
+                .iter()
+                .find(|(block, _)| *block.hash() == block_hash)
+                .cloned();
+            async move { maybe_block_and_deploys }.boxed()
+        });
+
+        // Only the last block was added while journaling.
+        let mut journal =
+            EventJournal::open(tempdir.path().to_path_buf(), config(1 << 20, 10)).unwrap();
+        let anchor = (100, hashes[3]);
+        journal
+            .append(
+                100,
+                &SseData::block_added(&backfill(hashes[3]).await.unwrap().0, None),
+            )
+            .unwrap();
+
+        assert_eq!(
+            preceding_blocks(&backfill, anchor, 2).await,
+            vec![(96, hashes[1]), (99, hashes[2])]
+        );
+        let entries = preceding_blocks(&backfill, anchor, 10).await;
+        assert_eq!(
+            entries,
+            vec![(95, hashes[0]), (96, hashes[1]), (99, hashes[2])]
+        );
+
+        journal.prepend_to_block_index(entries).unwrap();
+        let replayed = replay(&journal, Some(backfill), 95, 101).await;
+        assert_eq!(ids(&replayed), (95..101).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn should_retain_newest_block_index_entries() {
+        let mut rng = TestRng::new();
+        let tempdir = tempfile::tempdir().unwrap();
+        let blocks: Vec<Block> = (0..10).map(|_| Block::random(&mut rng)).collect();
+        {
+            let mut journal =
+                EventJournal::open(tempdir.path().to_path_buf(), config(1 << 20, 4)).unwrap();
+            for (id, block) in blocks.iter().enumerate() {
+                journal
+                    .append(id as Id, &SseData::block_added(block, None))
+                    .unwrap();
+            }
+            assert!(journal.block_index_file_len <= 8);
+        }
+
+        let journal = EventJournal::open(tempdir.path().to_path_buf(), config(1 << 20, 4)).unwrap();
+        let expected: Vec<_> = blocks
+            .iter()
+            .enumerate()
+            .skip(6)
+            .map(|(id, block)| (id as Id, *block.hash()))
+            .collect();
+        assert_eq!(
+            journal.block_index.iter().copied().collect::<Vec<_>>(),
+            expected
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use futures::{future, Future, FutureExt};
 use tokio::{
     select,
-    sync::{broadcast, mpsc, oneshot},
+    sync::{broadcast, mpsc, oneshot, watch},
     task,
 };
 use tracing::{info, trace};
@@ -10,12 +10,17 @@ use wheelbuf::WheelBuf;
 use casper_types::ProtocolVersion;
 
 use super::{
+    event_journal::{BlockBackfill, JournalHandle},
     sse_server::{BroadcastChannelMessage, Id, NewSubscriberInfo, ServerSentEvent},
-    Config, EventIndex, SseData,
+    EventIndex, SseData,
 };
 
+/// The buffer of the most recent events, provided to new subscribers.
+type EventBuffer = WheelBuf<Vec<ServerSentEvent>, ServerSentEvent>;
+
 /// Run the HTTP server.
 ///
+/// * `buffer_length` provides the number of events to buffer, which can be changed while running.
 /// * `server_with_shutdown` is the actual server as a future which can be gracefully shut down.
 /// * `server_shutdown_sender` is the channel by which the server will be notified to shut down.
 /// * `data_receiver` will provide the server with local events which should then be sent to all
@@ -25,22 +30,25 @@ use super::{
 /// * `new_subscriber_info_receiver` is used to notify the server of the details of a new client
 ///   having subscribed to the event stream.  It allows the server to populate that client's stream
 ///   with the requested number of historical events.
+/// * `journal`, if given, persists all events in the background, so that clients can be provided
+///   with events older than the ones buffered.
+/// * `backfill`, if given, is used to rebuild events older than the ones journaled.
+#[allow(clippy::too_many_arguments)]
 pub(super) async fn run(
-    config: Config,
+    mut buffer_length: watch::Receiver<u32>,
     api_version: ProtocolVersion,
     server_with_shutdown: impl Future<Output = ()> + Send + 'static,
     server_shutdown_sender: oneshot::Sender<()>,
     mut data_receiver: mpsc::UnboundedReceiver<(EventIndex, SseData)>,
     broadcaster: broadcast::Sender<BroadcastChannelMessage>,
     mut new_subscriber_info_receiver: mpsc::UnboundedReceiver<NewSubscriberInfo>,
+    mut journal: Option<JournalHandle>,
+    backfill: Option<BlockBackfill>,
 ) {
     let server_joiner = task::spawn(server_with_shutdown);
 
     // Initialize the index and buffer for the SSEs.
-    let mut buffer = WheelBuf::new(vec![
-        ServerSentEvent::initial_event(api_version);
-        config.event_stream_buffer_length as usize
-    ]);
+    let mut buffer = new_buffer(*buffer_length.borrow_and_update(), api_version);
 
     // Start handling received messages from the two channels; info on new client subscribers and
     // incoming events announced by node components.
@@ -54,9 +62,30 @@ pub(super) async fn run(
                         let _ = subscriber
                             .initial_events_sender
                             .send(ServerSentEvent::initial_event(api_version));
-                        // If the client supplied a "start_from" index, provide the buffered events.
-                        // If they requested more than is buffered, just provide the whole buffer.
-                        if let Some(start_index) = subscriber.start_from {
+                        // If the client supplied a "start_from" index older than the buffered
+                        // events, replay the journaled ones preceding the buffer in the background
+                        // before providing the whole buffer.
+                        let replay_end = subscriber.start_from.and_then(|start_index| {
+                            journal_replay_end(&buffer, journal.as_ref()?, start_index)
+                        });
+                        if let (Some(start_index), Some(end), Some(journal)) =
+                            (subscriber.start_from, replay_end, journal.as_ref())
+                        {
+                            let snapshot = journal.snapshot();
+                            let backfill = backfill.clone();
+                            let buffered_events: Vec<_> = buffer.iter().cloned().collect();
+                            tokio::spawn(async move {
+                                let sender = subscriber.initial_events_sender;
+                                if let Ok(snapshot) = snapshot.await {
+                                    snapshot.replay(backfill, start_index, end, &sender).await;
+                                }
+                                for event in buffered_events {
+                                    let _ = sender.send(event);
+                                }
+                            });
+                        } else if let Some(start_index) = subscriber.start_from {
+                            // Otherwise, provide the buffered events.  If they requested more than
+                            // is buffered, just provide the whole buffer.
                             // If the buffer's first event ID is in the range [0, buffer size) or
                             // (Id::MAX - buffer size, Id::MAX], then the events in the buffer are
                             // considered to have their IDs wrapping round, or that was recently the
@@ -88,11 +117,22 @@ pub(super) async fn run(
                     }
                 }
 
+                Ok(()) = buffer_length.changed() => {
+                    let length = *buffer_length.borrow_and_update();
+                    if length as usize != buffer.capacity() {
+                        info!(length, "resizing event stream buffer");
+                        buffer = resize_buffer(&buffer, length, api_version);
+                    }
+                }
+
                 maybe_data = data_receiver.recv() => {
                     match maybe_data {
                         Some((event_index, data)) => {
                             // Buffer the data and broadcast it to subscribed clients.
                             trace!("Event stream server received {:?}", data);
+                            if let Some(journal) = journal.as_mut() {
+                                journal.append(event_index, &data);
+                            }
                             let event = ServerSentEvent { id: Some(event_index), data };
                             buffer.push(event.clone());
                             let message = BroadcastChannelMessage::ServerSentEvent(event);
@@ -121,3 +161,37 @@ pub(super) async fn run(
 
     trace!("Event stream server stopped");
 }
+
+/// Returns an empty buffer for the given number of events.
+fn new_buffer(length: u32, api_version: ProtocolVersion) -> EventBuffer {
+    WheelBuf::new(vec![
+        ServerSentEvent::initial_event(api_version);
+        length as usize
+    ])
+}
+
+/// Returns a buffer for the given number of events, holding as many of the most recent events of
+/// the given buffer as fit.
+fn resize_buffer(buffer: &EventBuffer, length: u32, api_version: ProtocolVersion) -> EventBuffer {
+    let mut resized = new_buffer(length, api_version);
+    let events: Vec<_> = buffer.iter().cloned().collect();
+    let skipped = events.len().saturating_sub(length as usize);
+    for event in events.into_iter().skip(skipped) {
+        resized.push(event);
+    }
+    resized
+}
+
+/// Returns the ID up to which events need to be replayed from the journal for a client starting
+/// from `start_from`, or `None` if all requested events are buffered.
+fn journal_replay_end(buffer: &EventBuffer, journal: &JournalHandle, start_from: Id) -> Option<Id> {
+    // The oldest buffered event, or if none are buffered yet, the one following the last journaled.
+    let end = match buffer.iter().next() {
+        Some(event) => event.id?,
+        None => journal.last_id()?.wrapping_add(1),
+    };
+    // IDs wrap around, so IDs more than half the ID space before `end` are considered to be after
+    // it, and hence not in the journal.
+    let distance = end.wrapping_sub(start_from);
+    (distance != 0 && distance <= Id::MAX / 2).then_some(end)
+}
@@ -3,7 +3,10 @@
 use std::{
     collections::{HashMap, HashSet},
     net::SocketAddr,
-    sync::{Arc, RwLock},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, RwLock,
+    },
 };
 
 use datasize::DataSize;
@@ -35,12 +38,15 @@ use warp::{
 #[cfg(test)]
 use casper_types::testing::TestRng;
 use casper_types::{
-    EraId, ExecutionEffect, ExecutionResult, ProtocolVersion, PublicKey, TimeDiff, Timestamp,
+    EraId, ExecutionEffect, ExecutionResult, FeeBreakdown, Key, ProtocolVersion, PublicKey,
+    TimeDiff, Timestamp,
 };
 
-use crate::types::{BlockHash, Deploy, DeployHash, FinalitySignature, JsonBlock};
 #[cfg(test)]
-use crate::{testing, types::Block};
+use crate::testing;
+use crate::types::{
+    Block, BlockHash, Deploy, DeployHash, DeployHeader, FinalitySignature, JsonBlock,
+};
 
 /// The URL root path.
 pub const SSE_API_ROOT_PATH: &str = "events";
@@ -55,12 +61,14 @@ pub const SSE_API_SIGNATURES_PATH: &str = "sigs";
 pub const QUERY_FIELD: &str = "start_from";
 
 /// The filter associated with `/events/main` path.
-const MAIN_FILTER: [EventFilter; 5] = [
+const MAIN_FILTER: [EventFilter; 7] = [
     EventFilter::BlockAdded,
     EventFilter::DeployProcessed,
     EventFilter::DeployExpired,
+    EventFilter::DeployCancelled,
     EventFilter::Fault,
     EventFilter::Step,
+    EventFilter::ScheduledInvocationProcessed,
 ];
 /// The filter associated with `/events/deploys` path.
 const DEPLOYS_FILTER: [EventFilter; 1] = [EventFilter::DeployAccepted];
@@ -81,6 +89,11 @@ pub enum SseData {
     BlockAdded {
         block_hash: BlockHash,
         block: Box<JsonBlock>,
+        /// The total of the fees paid to the proposer and burned by the block's deploys, if the
+        /// network is configured to split fees.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[data_size(skip)]
+        fee_breakdown: Option<FeeBreakdown>,
     },
     /// The given deploy has been newly-accepted by this node.
     DeployAccepted {
@@ -100,6 +113,14 @@ pub enum SseData {
     },
     /// The given deploy has expired.
     DeployExpired { deploy_hash: DeployHash },
+    /// The given deploy has been cancelled by its account, and will no longer be proposed by this
+    /// node.
+    DeployCancelled {
+        deploy_hash: DeployHash,
+        /// The hash of the deploy replacing the cancelled one, if any.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        replacement: Option<DeployHash>,
+    },
     /// Generic representation of validator's fault in an era.
     Fault {
         era_id: EraId,
@@ -114,11 +135,46 @@ pub enum SseData {
         #[data_size(skip)]
         execution_effect: ExecutionEffect,
     },
+    /// The given scheduled invocation has been executed and committed as part of the given block.
+    ScheduledInvocationProcessed {
+        #[schemars(with = "String")]
+        invocation: Key,
+        block_hash: Box<BlockHash>,
+        #[data_size(skip)]
+        execution_result: Box<ExecutionResult>,
+    },
     /// The node is about to shut down.
     Shutdown,
 }
 
 impl SseData {
+    /// Returns a `BlockAdded` event for the given block.
+    pub(super) fn block_added(block: &Block, fee_breakdown: Option<FeeBreakdown>) -> Self {
+        SseData::BlockAdded {
+            block_hash: *block.hash(),
+            block: Box::new(JsonBlock::new(block, None)),
+            fee_breakdown,
+        }
+    }
+
+    /// Returns a `DeployProcessed` event for the given deploy, executed as part of the given block.
+    pub(super) fn deploy_processed(
+        deploy_hash: DeployHash,
+        deploy_header: &DeployHeader,
+        block_hash: BlockHash,
+        execution_result: Box<ExecutionResult>,
+    ) -> Self {
+        SseData::DeployProcessed {
+            deploy_hash: Box::new(deploy_hash),
+            account: Box::new(deploy_header.account().clone()),
+            timestamp: deploy_header.timestamp(),
+            ttl: deploy_header.ttl(),
+            dependencies: deploy_header.dependencies().clone(),
+            block_hash: Box::new(block_hash),
+            execution_result,
+        }
+    }
+
     pub(super) fn should_include(&self, filter: &[EventFilter]) -> bool {
         match self {
             SseData::ApiVersion(_) | SseData::Shutdown => true,
@@ -126,9 +182,13 @@ impl SseData {
             SseData::DeployAccepted { .. } => filter.contains(&EventFilter::DeployAccepted),
             SseData::DeployProcessed { .. } => filter.contains(&EventFilter::DeployProcessed),
             SseData::DeployExpired { .. } => filter.contains(&EventFilter::DeployExpired),
+            SseData::DeployCancelled { .. } => filter.contains(&EventFilter::DeployCancelled),
             SseData::Fault { .. } => filter.contains(&EventFilter::Fault),
             SseData::FinalitySignature(_) => filter.contains(&EventFilter::FinalitySignature),
             SseData::Step { .. } => filter.contains(&EventFilter::Step),
+            SseData::ScheduledInvocationProcessed { .. } => {
+                filter.contains(&EventFilter::ScheduledInvocationProcessed)
+            }
         }
     }
 }
@@ -151,6 +211,7 @@ impl SseData {
         SseData::BlockAdded {
             block_hash: *block.hash(),
             block: Box::new(JsonBlock::new(&block, None)),
+            fee_breakdown: rng.gen::<ExecutionResult>().fee_breakdown().copied(),
         }
     }
 
@@ -185,6 +246,14 @@ impl SseData {
         }
     }
 
+    /// Returns a random `SseData::DeployCancelled`.
+    pub(super) fn random_deploy_cancelled(rng: &mut TestRng) -> Self {
+        SseData::DeployCancelled {
+            deploy_hash: DeployHash::random(rng),
+            replacement: rng.gen::<bool>().then(|| DeployHash::random(rng)),
+        }
+    }
+
     /// Returns a random `SseData::Fault`.
     pub(super) fn random_fault(rng: &mut TestRng) -> Self {
         SseData::Fault {
@@ -214,6 +283,15 @@ impl SseData {
             execution_effect,
         }
     }
+
+    /// Returns a random `SseData::ScheduledInvocationProcessed`.
+    pub(super) fn random_scheduled_invocation_processed(rng: &mut TestRng) -> Self {
+        SseData::ScheduledInvocationProcessed {
+            invocation: Key::ScheduledInvocation(rng.gen()),
+            block_hash: Box::new(BlockHash::random(rng)),
+            execution_result: Box::new(rng.gen()),
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -270,9 +348,11 @@ pub(super) enum EventFilter {
     DeployAccepted,
     DeployProcessed,
     DeployExpired,
+    DeployCancelled,
     Fault,
     FinalitySignature,
     Step,
+    ScheduledInvocationProcessed,
 }
 
 /// Filters the `event`, mapping it to a warp event, or `None` if it should be filtered out.
@@ -312,8 +392,10 @@ async fn filter_map_server_sent_event(
         &SseData::BlockAdded { .. }
         | &SseData::DeployProcessed { .. }
         | &SseData::DeployExpired { .. }
+        | &SseData::DeployCancelled { .. }
         | &SseData::Fault { .. }
         | &SseData::Step { .. }
+        | &SseData::ScheduledInvocationProcessed { .. }
         | &SseData::FinalitySignature(_)
         | &SseData::Shutdown => Some(Ok(WarpServerSentEvent::default()
             .json_data(&event.data)
@@ -408,7 +490,13 @@ pub(super) struct ChannelsAndFilter {
 impl ChannelsAndFilter {
     /// Creates the message-passing channels required to run the event-stream server and the warp
     /// filter for the event-stream server.
-    pub(super) fn new(broadcast_channel_size: usize, max_concurrent_subscribers: u32) -> Self {
+    ///
+    /// `max_concurrent_subscribers` is read on every new subscription, so that it can be changed
+    /// while the server is running.
+    pub(super) fn new(
+        broadcast_channel_size: usize,
+        max_concurrent_subscribers: Arc<AtomicU32>,
+    ) -> Self {
         // Create a channel to broadcast new events to all subscribed clients' streams.
         let (event_broadcaster, _) = broadcast::channel(broadcast_channel_size);
         let cloned_broadcaster = event_broadcaster.clone();
@@ -426,6 +514,7 @@ impl ChannelsAndFilter {
             };
 
             // If we already have the maximum number of subscribers, reject this new one.
+            let max_concurrent_subscribers = max_concurrent_subscribers.load(Ordering::Relaxed);
             if cloned_broadcaster.receiver_count() >= max_concurrent_subscribers as usize {
                 info!(
                     %remote_address,
@@ -622,6 +711,10 @@ mod tests {
             id: Some(rng.gen()),
             data: SseData::random_deploy_expired(&mut rng),
         };
+        let deploy_cancelled = ServerSentEvent {
+            id: Some(rng.gen()),
+            data: SseData::random_deploy_cancelled(&mut rng),
+        };
         let fault = ServerSentEvent {
             id: Some(rng.gen()),
             data: SseData::random_fault(&mut rng),
@@ -634,6 +727,10 @@ mod tests {
             id: Some(rng.gen()),
             data: SseData::random_step(&mut rng),
         };
+        let scheduled_invocation_processed = ServerSentEvent {
+            id: Some(rng.gen()),
+            data: SseData::random_scheduled_invocation_processed(&mut rng),
+        };
         let shutdown = ServerSentEvent {
             id: Some(rng.gen()),
             data: SseData::Shutdown,
@@ -644,8 +741,10 @@ mod tests {
         should_not_filter_out(&block_added, &MAIN_FILTER[..]).await;
         should_not_filter_out(&deploy_processed, &MAIN_FILTER[..]).await;
         should_not_filter_out(&deploy_expired, &MAIN_FILTER[..]).await;
+        should_not_filter_out(&deploy_cancelled, &MAIN_FILTER[..]).await;
         should_not_filter_out(&fault, &MAIN_FILTER[..]).await;
         should_not_filter_out(&step, &MAIN_FILTER[..]).await;
+        should_not_filter_out(&scheduled_invocation_processed, &MAIN_FILTER[..]).await;
         should_not_filter_out(&shutdown, &MAIN_FILTER).await;
 
         should_filter_out(&deploy_accepted, &MAIN_FILTER[..]).await;
@@ -660,9 +759,11 @@ mod tests {
         should_filter_out(&block_added, &DEPLOYS_FILTER[..]).await;
         should_filter_out(&deploy_processed, &DEPLOYS_FILTER[..]).await;
         should_filter_out(&deploy_expired, &DEPLOYS_FILTER[..]).await;
+        should_filter_out(&deploy_cancelled, &DEPLOYS_FILTER[..]).await;
         should_filter_out(&fault, &DEPLOYS_FILTER[..]).await;
         should_filter_out(&finality_signature, &DEPLOYS_FILTER[..]).await;
         should_filter_out(&step, &DEPLOYS_FILTER[..]).await;
+        should_filter_out(&scheduled_invocation_processed, &DEPLOYS_FILTER[..]).await;
 
         // `EventFilter::Signatures` should filter out everything except `ApiVersion`s and
         // `FinalitySignature`s.
@@ -674,8 +775,10 @@ mod tests {
         should_filter_out(&deploy_accepted, &SIGNATURES_FILTER[..]).await;
         should_filter_out(&deploy_processed, &SIGNATURES_FILTER[..]).await;
         should_filter_out(&deploy_expired, &SIGNATURES_FILTER[..]).await;
+        should_filter_out(&deploy_cancelled, &SIGNATURES_FILTER[..]).await;
         should_filter_out(&fault, &SIGNATURES_FILTER[..]).await;
         should_filter_out(&step, &SIGNATURES_FILTER[..]).await;
+        should_filter_out(&scheduled_invocation_processed, &SIGNATURES_FILTER[..]).await;
     }
 
     /// This test checks that events with incorrect IDs (i.e. no types have an ID except for
@@ -708,6 +811,10 @@ mod tests {
             id: None,
             data: SseData::random_deploy_expired(&mut rng),
         };
+        let malformed_deploy_cancelled = ServerSentEvent {
+            id: None,
+            data: SseData::random_deploy_cancelled(&mut rng),
+        };
         let malformed_fault = ServerSentEvent {
             id: None,
             data: SseData::random_fault(&mut rng),
@@ -720,6 +827,10 @@ mod tests {
             id: None,
             data: SseData::random_step(&mut rng),
         };
+        let malformed_scheduled_invocation_processed = ServerSentEvent {
+            id: None,
+            data: SseData::random_scheduled_invocation_processed(&mut rng),
+        };
         let malformed_shutdown = ServerSentEvent {
             id: None,
             data: SseData::Shutdown,
@@ -735,9 +846,11 @@ mod tests {
             should_filter_out(&malformed_deploy_accepted, filter).await;
             should_filter_out(&malformed_deploy_processed, filter).await;
             should_filter_out(&malformed_deploy_expired, filter).await;
+            should_filter_out(&malformed_deploy_cancelled, filter).await;
             should_filter_out(&malformed_fault, filter).await;
             should_filter_out(&malformed_finality_signature, filter).await;
             should_filter_out(&malformed_step, filter).await;
+            should_filter_out(&malformed_scheduled_invocation_processed, filter).await;
             should_filter_out(&malformed_shutdown, filter).await;
         }
     }
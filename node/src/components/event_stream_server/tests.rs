@@ -199,7 +199,7 @@ struct TestFixture {
 impl TestFixture {
     /// Constructs a new `TestFixture` including `EVENT_COUNT` random events ready to be served.
     fn new(rng: &mut TestRng) -> Self {
-        const DISTINCT_EVENTS_COUNT: u32 = 7;
+        const DISTINCT_EVENTS_COUNT: u32 = 9;
 
         let _ = logging::init();
         let storage_dir = tempfile::tempdir().unwrap();
@@ -220,6 +220,8 @@ impl TestFixture {
                 4 => SseData::random_fault(rng),
                 5 => SseData::random_step(rng),
                 6 => SseData::random_finality_signature(rng),
+                7 => SseData::random_scheduled_invocation_processed(rng),
+                8 => SseData::random_deploy_cancelled(rng),
                 _ => unreachable!(),
             })
             .collect();
@@ -269,7 +271,7 @@ impl TestFixture {
             self.storage_dir.path().to_path_buf(),
             self.protocol_version,
         );
-        server.listen().unwrap();
+        server.listen(None).unwrap();
         assert!(server.sse_server.is_some());
 
         self.first_event_id = server
@@ -40,8 +40,8 @@ use crate::{
         ConditionCheckReactor, FakeDeployAcceptor,
     },
     types::{
-        Block, Chainspec, ChainspecRawBytes, Deploy, DeployHash, DeployId, FinalitySignature,
-        NodeId,
+        Block, Chainspec, ChainspecRawBytes, Deploy, DeployCancellation, DeployHash, DeployId,
+        FinalitySignature, NodeId,
     },
     utils::WithDir,
 };
@@ -130,6 +130,8 @@ enum Event {
     #[from]
     GossiperIncomingGossipedAddress(GossiperIncoming<GossipedAddress>),
     #[from]
+    GossiperIncomingDeployCancellation(GossiperIncoming<DeployCancellation>),
+    #[from]
     TrieRequestIncoming(TrieRequestIncoming),
     #[from]
     TrieResponseIncoming(TrieResponseIncoming),
@@ -258,6 +260,7 @@ impl ReactorTrait for Reactor {
             | Event::GossiperIncomingBlock(_)
             | Event::GossiperIncomingFinalitySignature(_)
             | Event::GossiperIncomingGossipedAddress(_)
+            | Event::GossiperIncomingDeployCancellation(_)
             | Event::TrieRequestIncoming(_)
             | Event::TrieResponseIncoming(_)
             | Event::ConsensusMessageIncoming(_)
@@ -289,6 +292,9 @@ impl ReactorTrait for Reactor {
             &chainspec.network_config.name,
             chainspec.deploy_config.max_ttl.into(),
             chainspec.core_config.unbonding_delay,
+            chainspec
+                .core_config
+                .start_protocol_version_with_aggregate_finality_signatures,
             Some(registry),
             false,
         )
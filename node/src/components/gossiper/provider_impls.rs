@@ -1,4 +1,5 @@
 mod address_provider;
 mod block_provider;
+mod deploy_cancellation_provider;
 mod deploy_provider;
 mod finality_signature_provider;
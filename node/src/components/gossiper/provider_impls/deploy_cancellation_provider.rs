@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use tracing::error;
+
+use crate::{
+    components::gossiper::{GossipItem, Gossiper, ItemProvider},
+    effect::EffectBuilder,
+    types::DeployCancellation,
+};
+
+#[async_trait]
+impl ItemProvider<DeployCancellation>
+    for Gossiper<{ DeployCancellation::ID_IS_COMPLETE_ITEM }, DeployCancellation>
+{
+    async fn is_stored<REv: Send>(
+        _effect_builder: EffectBuilder<REv>,
+        item_id: DeployCancellation,
+    ) -> bool {
+        error!(%item_id, "deploy cancellation gossiper should never try to check if item is stored");
+        false
+    }
+
+    async fn get_from_storage<REv: Send>(
+        _effect_builder: EffectBuilder<REv>,
+        item_id: DeployCancellation,
+    ) -> Option<Box<DeployCancellation>> {
+        error!(%item_id, "deploy cancellation gossiper should never try to get from storage");
+        None
+    }
+}
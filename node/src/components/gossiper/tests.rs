@@ -46,7 +46,9 @@ use crate::{
         network::{NetworkedReactor, TestingNetwork},
         ConditionCheckReactor, FakeDeployAcceptor,
     },
-    types::{Block, Chainspec, ChainspecRawBytes, Deploy, FinalitySignature, NodeId},
+    types::{
+        Block, Chainspec, ChainspecRawBytes, Deploy, DeployCancellation, FinalitySignature, NodeId,
+    },
     utils::WithDir,
     NodeRng,
 };
@@ -112,6 +114,7 @@ impl Unhandled for ConsensusMessageIncoming {}
 impl Unhandled for GossiperIncoming<Block> {}
 impl Unhandled for GossiperIncoming<FinalitySignature> {}
 impl Unhandled for GossiperIncoming<GossipedAddress> {}
+impl Unhandled for GossiperIncoming<DeployCancellation> {}
 impl Unhandled for NetRequestIncoming {}
 impl Unhandled for NetResponseIncoming {}
 impl Unhandled for TrieRequestIncoming {}
@@ -166,6 +169,7 @@ impl reactor::Reactor for Reactor {
             "test",
             MAX_TTL.into(),
             RECENT_ERA_COUNT,
+            ProtocolVersion::V1_0_0,
             Some(registry),
             false,
         )
@@ -1024,6 +1024,22 @@ where
             >= self.cfg.min_peers_for_initialization as usize
     }
 
+    /// Changes the rate limits imposed on non-validator peers.
+    ///
+    /// Existing connections are affected as well.
+    pub(crate) fn set_non_validator_rate_limits(
+        &mut self,
+        max_outgoing_byte_rate: u32,
+        max_incoming_message_rate: u32,
+    ) {
+        self.cfg.max_outgoing_byte_rate_non_validators = max_outgoing_byte_rate;
+        self.cfg.max_incoming_message_rate_non_validators = max_incoming_message_rate;
+        self.outgoing_limiter
+            .set_resources_per_second(max_outgoing_byte_rate);
+        self.incoming_limiter
+            .set_resources_per_second(max_incoming_message_rate);
+    }
+
     #[cfg(test)]
     /// Returns the node id of this network node.
     pub(crate) fn node_id(&self) -> NodeId {
@@ -5,7 +5,10 @@
 
 use std::{
     collections::{HashMap, HashSet},
-    sync::{Arc, RwLock},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, RwLock,
+    },
     time::{Duration, Instant},
 };
 
@@ -77,6 +80,15 @@ impl Limiter {
         }
     }
 
+    /// Changes the number of resource units allowed for non-validators per second.
+    ///
+    /// Takes effect for all existing handles as well.
+    pub(super) fn set_resources_per_second(&self, resources_per_second: u32) {
+        self.data
+            .resources_per_second
+            .store(resources_per_second, Ordering::Relaxed);
+    }
+
     pub(super) fn remove_connected_validator(&self, peer_id: &NodeId) {
         match self.data.connected_validators.write() {
             Ok(mut connected_validators) => {
@@ -139,7 +151,7 @@ impl Limiter {
 #[derive(Debug)]
 struct LimiterData {
     /// Number of resource units to allow for non-validators per second.
-    resources_per_second: u32,
+    resources_per_second: AtomicU32,
     /// A mapping from node IDs to public keys of validators to which we have an outgoing
     /// connection.
     connected_validators: RwLock<HashMap<NodeId, PublicKey>>,
@@ -166,7 +178,7 @@ impl LimiterData {
     /// Initial resources will be initialized to 0, with the last refill set to the current time.
     fn new(resources_per_second: u32, wait_time_sec: Counter) -> Self {
         LimiterData {
-            resources_per_second,
+            resources_per_second: AtomicU32::new(resources_per_second),
             connected_validators: Default::default(),
             resources: Mutex::new(ResourceData {
                 available: 0,
@@ -227,13 +239,13 @@ impl LimiterHandle {
                 // No limit imposed on validators.
             }
             PeerClass::NonValidator => {
-                if self.data.resources_per_second == 0 {
+                let resources_per_second = self.data.resources_per_second.load(Ordering::Relaxed);
+                if resources_per_second == 0 {
                     return;
                 }
 
-                let max_stored_resource = ((self.data.resources_per_second as f64)
-                    * STORED_BUFFER_SECS.as_secs_f64())
-                    as u32;
+                let max_stored_resource =
+                    ((resources_per_second as f64) * STORED_BUFFER_SECS.as_secs_f64()) as u32;
 
                 // We are a low-priority sender. Obtain a lock on the resources and wait an
                 // appropriate amount of time to fill them up.
@@ -249,16 +261,14 @@ impl LimiterHandle {
                         // Add appropriate amount of resources, capped at `max_stored_bytes`. We
                         // are still maintaining the lock here to avoid issues with other
                         // low-priority requestors.
-                        resources.available += ((elapsed.as_nanos()
-                            * self.data.resources_per_second as u128)
+                        resources.available += ((elapsed.as_nanos() * resources_per_second as u128)
                             / 1_000_000_000) as i64;
                         resources.available = resources.available.min(max_stored_resource as i64);
 
                         // If we do not have enough resources available, sleep until we do.
                         if resources.available < 0 {
                             let estimated_time_remaining = Duration::from_millis(
-                                (-resources.available) as u64 * 1000
-                                    / self.data.resources_per_second as u64,
+                                (-resources.available) as u64 * 1000 / resources_per_second as u64,
                             );
 
                             // Note: This sleep call is the reason we are using a tokio mutex
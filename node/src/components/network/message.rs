@@ -551,6 +551,7 @@ impl<'a> NetworkMessageEstimator<'a> {
                     + 1
             }
             "max_accusations_per_block" => self.chainspec.core_config.validator_slots as i64,
+            "max_associated_keys" => self.chainspec.core_config.max_associated_keys as i64,
             // `RADIX` from EE.
             "max_pointer_per_node" => 255,
             // Endorsements are currently hard-disabled (via code). If ever re-enabled, this
@@ -22,6 +22,7 @@ use std::{fmt::Debug, time::Instant};
 
 use datasize::DataSize;
 use futures::join;
+use tokio::sync::watch;
 use tracing::{error, info, warn};
 
 use casper_execution_engine::core::engine_state::{
@@ -40,8 +41,9 @@ use crate::{
     effect::{
         requests::{
             AcceptDeployRequest, BlockSynchronizerRequest, ChainspecRawBytesRequest,
-            ConsensusRequest, ContractRuntimeRequest, MetricsRequest, NetworkInfoRequest,
-            ReactorStatusRequest, RpcRequest, StorageRequest, UpgradeWatcherRequest,
+            ConsensusRequest, ContractRuntimeRequest, DeployAcceptorRequest, DeployBufferRequest,
+            MetricsRequest, NetworkInfoRequest, ReactorStatusRequest, RpcRequest, StorageRequest,
+            UpgradeWatcherRequest,
         },
         EffectBuilder, EffectExt, Effects, Responder,
     },
@@ -61,6 +63,8 @@ pub(crate) trait ReactorEventT:
     From<Event>
     + From<RpcRequest>
     + From<AcceptDeployRequest>
+    + From<DeployAcceptorRequest>
+    + From<DeployBufferRequest>
     + From<ChainspecRawBytesRequest>
     + From<UpgradeWatcherRequest>
     + From<ContractRuntimeRequest>
@@ -78,6 +82,8 @@ impl<REv> ReactorEventT for REv where
     REv: From<Event>
         + From<RpcRequest>
         + From<AcceptDeployRequest>
+        + From<DeployAcceptorRequest>
+        + From<DeployBufferRequest>
         + From<ChainspecRawBytesRequest>
         + From<UpgradeWatcherRequest>
         + From<ContractRuntimeRequest>
@@ -112,6 +118,12 @@ pub(crate) struct RpcServer {
     /// no fields and no methods because all that is needed to operate it is the
     /// spawned tokio task, so a unit struct will suffice here.
     speculative_exec: Option<()>,
+    /// Sender of changes to the JSON-RPC server's limit of queries per second.
+    #[data_size(skip)]
+    qps_limit: watch::Sender<u64>,
+    /// Sender of changes to the speculative execution server's limit of queries per second.
+    #[data_size(skip)]
+    speculative_exec_qps_limit: watch::Sender<u64>,
 }
 
 impl RpcServer {
@@ -122,6 +134,8 @@ impl RpcServer {
         network_name: String,
         node_startup_instant: Instant,
     ) -> Self {
+        let (qps_limit, _) = watch::channel(config.qps_limit);
+        let (speculative_exec_qps_limit, _) = watch::channel(speculative_exec_config.qps_limit);
         RpcServer {
             state: ComponentState::Uninitialized,
             config,
@@ -130,8 +144,21 @@ impl RpcServer {
             network_name,
             node_startup_instant,
             speculative_exec: None,
+            qps_limit,
+            speculative_exec_qps_limit,
         }
     }
+
+    /// Changes the limits of queries per second of the JSON-RPC and speculative execution servers.
+    ///
+    /// Takes effect for running servers as well.
+    pub(crate) fn set_qps_limits(&mut self, qps_limit: u64, speculative_exec_qps_limit: u64) {
+        self.config.qps_limit = qps_limit;
+        self.speculative_exec_config.qps_limit = speculative_exec_qps_limit;
+        self.qps_limit.send_replace(qps_limit);
+        self.speculative_exec_qps_limit
+            .send_replace(speculative_exec_qps_limit);
+    }
 }
 
 impl RpcServer {
@@ -451,7 +478,7 @@ where
                 builder,
                 effect_builder,
                 self.api_version,
-                cfg.qps_limit,
+                self.speculative_exec_qps_limit.subscribe(),
                 cfg.max_body_bytes,
                 cfg.cors_origin.clone(),
             ));
@@ -466,7 +493,7 @@ where
             builder,
             effect_builder,
             self.api_version,
-            cfg.qps_limit,
+            self.qps_limit.subscribe(),
             cfg.max_body_bytes,
             cfg.cors_origin.clone(),
         ));
@@ -1,19 +1,23 @@
 use hyper::server::{conn::AddrIncoming, Builder};
+use tokio::sync::watch;
 
 use casper_json_rpc::{CorsOrigin, RequestHandlersBuilder};
 use casper_types::ProtocolVersion;
 
 use super::{
     rpcs::{
-        account::PutDeploy,
+        account::{CancelDeploy, PutDeploy},
         chain::{
-            GetBlock, GetBlockTransfers, GetEraInfoBySwitchBlock, GetEraSummary, GetStateRootHash,
+            GetBlock, GetBlockTransfers, GetEraInfoBySwitchBlock, GetEraSummary, GetRewardsReport,
+            GetStateRootHash,
         },
         docs::ListRpcs,
-        info::{GetChainspec, GetDeploy, GetPeers, GetStatus, GetValidatorChanges},
+        info::{
+            GetChainspec, GetDeploy, GetDeployStatus, GetPeers, GetStatus, GetValidatorChanges,
+        },
         state::{
-            GetAccountInfo, GetAuctionInfo, GetBalance, GetDictionaryItem, GetItem, GetTrie,
-            QueryBalance, QueryGlobalState,
+            GetAccountInfo, GetAuctionInfo, GetBalance, GetDelegators, GetDictionaryItem, GetItem,
+            GetTrie, GetUnbondingQueue, QueryBalance, QueryGlobalState,
         },
         RpcWithOptionalParams, RpcWithParams, RpcWithoutParams,
     },
@@ -31,7 +35,7 @@ pub(super) async fn run<REv: ReactorEventT>(
     builder: Builder<AddrIncoming>,
     effect_builder: EffectBuilder<REv>,
     api_version: ProtocolVersion,
-    qps_limit: u64,
+    qps_limit: watch::Receiver<u64>,
     max_body_bytes: u32,
     cors_origin: String,
 ) {
@@ -56,6 +60,11 @@ pub(super) async fn run<REv: ReactorEventT>(
     GetDictionaryItem::register_as_handler(effect_builder, api_version, &mut handlers);
     GetChainspec::register_as_handler(effect_builder, api_version, &mut handlers);
     QueryBalance::register_as_handler(effect_builder, api_version, &mut handlers);
+    GetDelegators::register_as_handler(effect_builder, api_version, &mut handlers);
+    GetUnbondingQueue::register_as_handler(effect_builder, api_version, &mut handlers);
+    GetRewardsReport::register_as_handler(effect_builder, api_version, &mut handlers);
+    GetDeployStatus::register_as_handler(effect_builder, api_version, &mut handlers);
+    CancelDeploy::register_as_handler(effect_builder, api_version, &mut handlers);
     let handlers = handlers.build();
 
     match cors_origin.as_str() {
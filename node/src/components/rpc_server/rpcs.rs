@@ -11,7 +11,12 @@ pub mod info;
 pub mod speculative_exec;
 pub mod state;
 
-use std::{str, sync::Arc, time::Duration};
+use std::{
+    str,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
 
 use async_trait::async_trait;
 use http::header::ACCEPT_ENCODING;
@@ -19,8 +24,11 @@ use hyper::server::{conn::AddrIncoming, Builder};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tokio::sync::oneshot;
-use tower::ServiceBuilder;
+use tokio::sync::{oneshot, watch};
+use tower::{
+    limit::{rate::Rate, RateLimit},
+    Service,
+};
 use tracing::info;
 use warp::Filter;
 
@@ -254,11 +262,60 @@ pub(super) trait RpcWithOptionalParams {
     ) -> Result<Self::ResponseResult, Error>;
 }
 
+/// Rate-limits an inner service to the latest number of queries per second received, so that the
+/// limit can be changed while the server is running.
+struct ReloadableRateLimit<S> {
+    qps_limit: watch::Receiver<u64>,
+    /// Always `Some`, other than while being replaced on a change of the limit.
+    inner: Option<RateLimit<S>>,
+}
+
+impl<S> ReloadableRateLimit<S> {
+    fn new(service: S, mut qps_limit: watch::Receiver<u64>) -> Self {
+        let rate = Rate::new(*qps_limit.borrow_and_update(), Duration::from_secs(1));
+        ReloadableRateLimit {
+            qps_limit,
+            inner: Some(RateLimit::new(service, rate)),
+        }
+    }
+
+    fn inner(&mut self) -> &mut RateLimit<S> {
+        self.inner
+            .as_mut()
+            .expect("rate-limited service should be present")
+    }
+}
+
+impl<S, Request> Service<Request> for ReloadableRateLimit<S>
+where
+    S: Service<Request>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // A changed limit starts off a new rate-limiting period.
+        if self.qps_limit.has_changed().unwrap_or(false) {
+            let rate = Rate::new(*self.qps_limit.borrow_and_update(), Duration::from_secs(1));
+            self.inner = self
+                .inner
+                .take()
+                .map(|inner| RateLimit::new(inner.into_inner(), rate));
+        }
+        self.inner().poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        self.inner().call(request)
+    }
+}
+
 /// Start JSON RPC server with CORS enabled in a background.
 pub(super) async fn run_with_cors(
     builder: Builder<AddrIncoming>,
     handlers: RequestHandlers,
-    qps_limit: u64,
+    qps_limit: watch::Receiver<u64>,
     max_body_bytes: u32,
     api_path: &'static str,
     server_name: &'static str,
@@ -283,9 +340,7 @@ pub(super) async fn run_with_cors(
         async move { Ok::<_, Infallible>(service.clone()) }
     });
 
-    let make_svc = ServiceBuilder::new()
-        .rate_limit(qps_limit, Duration::from_secs(1))
-        .service(make_svc);
+    let make_svc = ReloadableRateLimit::new(make_svc, qps_limit);
 
     let server = builder.serve(make_svc);
     info!(address = %server.local_addr(), "started {} server", server_name);
@@ -304,7 +359,7 @@ pub(super) async fn run_with_cors(
 pub(super) async fn run(
     builder: Builder<AddrIncoming>,
     handlers: RequestHandlers,
-    qps_limit: u64,
+    qps_limit: watch::Receiver<u64>,
     max_body_bytes: u32,
     api_path: &'static str,
     server_name: &'static str,
@@ -327,9 +382,7 @@ pub(super) async fn run(
         async move { Ok::<_, Infallible>(service.clone()) }
     });
 
-    let make_svc = ServiceBuilder::new()
-        .rate_limit(qps_limit, Duration::from_secs(1))
-        .service(make_svc);
+    let make_svc = ReloadableRateLimit::new(make_svc, qps_limit);
 
     let server = builder.serve(make_svc);
     info!(address = %server.local_addr(), "started {} server", server_name);
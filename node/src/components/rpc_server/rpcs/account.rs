@@ -20,7 +20,7 @@ use super::{
 use crate::{
     components::rpc_server::rpcs::ErrorCode,
     effect::EffectBuilder,
-    types::{Deploy, DeployHash},
+    types::{Deploy, DeployCancellation, DeployHash},
 };
 
 static PUT_DEPLOY_PARAMS: Lazy<PutDeployParams> = Lazy::new(|| PutDeployParams {
@@ -30,6 +30,13 @@ static PUT_DEPLOY_RESULT: Lazy<PutDeployResult> = Lazy::new(|| PutDeployResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
     deploy_hash: *Deploy::doc_example().hash(),
 });
+static CANCEL_DEPLOY_PARAMS: Lazy<CancelDeployParams> = Lazy::new(|| CancelDeployParams {
+    cancellation: DeployCancellation::doc_example().clone(),
+});
+static CANCEL_DEPLOY_RESULT: Lazy<CancelDeployResult> = Lazy::new(|| CancelDeployResult {
+    api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+    deploy_hash: *Deploy::doc_example().hash(),
+});
 
 /// Params for "account_put_deploy" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
@@ -102,3 +109,79 @@ impl RpcWithParams for PutDeploy {
         }
     }
 }
+
+/// Params for "account_cancel_deploy" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CancelDeployParams {
+    /// The cancellation, approved by keys of the deploy's account meeting its deployment
+    /// threshold.  It is only honored on a best-effort basis by the nodes it reaches before
+    /// proposing the deploy, which may hence still be executed.
+    pub cancellation: DeployCancellation,
+}
+
+impl DocExample for CancelDeployParams {
+    fn doc_example() -> &'static Self {
+        &CANCEL_DEPLOY_PARAMS
+    }
+}
+
+/// Result for "account_cancel_deploy" RPC response.
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CancelDeployResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// The hash of the cancelled deploy.
+    pub deploy_hash: DeployHash,
+}
+
+impl DocExample for CancelDeployResult {
+    fn doc_example() -> &'static Self {
+        &CANCEL_DEPLOY_RESULT
+    }
+}
+
+/// "account_cancel_deploy" RPC
+pub struct CancelDeploy {}
+
+#[async_trait]
+impl RpcWithParams for CancelDeploy {
+    const METHOD: &'static str = "account_cancel_deploy";
+    type RequestParams = CancelDeployParams;
+    type ResponseResult = CancelDeployResult;
+
+    async fn do_handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        api_version: ProtocolVersion,
+        params: Self::RequestParams,
+    ) -> Result<Self::ResponseResult, Error> {
+        let deploy_hash = *params.cancellation.deploy_hash();
+
+        match effect_builder
+            .try_accept_deploy_cancellation(params.cancellation)
+            .await
+        {
+            Ok(()) => {
+                debug!(%deploy_hash, "deploy cancellation was accepted");
+                let result = Self::ResponseResult {
+                    api_version,
+                    deploy_hash,
+                };
+                Ok(result)
+            }
+            Err(error) => {
+                debug!(
+                    %deploy_hash,
+                    %error,
+                    "the deploy cancellation submitted by the client was invalid",
+                );
+                Err(Error::new(
+                    ErrorCode::InvalidDeployCancellation,
+                    error.to_string(),
+                ))
+            }
+        }
+    }
+}
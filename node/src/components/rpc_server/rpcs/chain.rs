@@ -4,6 +4,7 @@
 #![allow(clippy::field_reassign_with_default)]
 
 mod era_summary;
+mod rewards_report;
 
 use std::{clone::Clone, num::ParseIntError, str};
 
@@ -14,20 +15,27 @@ use serde::{Deserialize, Serialize};
 
 use casper_execution_engine::core::engine_state::{self, QueryResult};
 use casper_hashing::Digest;
-use casper_types::{Key, ProtocolVersion, Transfer};
+use casper_types::{
+    system::auction::EraInfo, EraId, Key, ProtocolVersion, PublicKey, StoredValue, Transfer,
+};
 
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
     Error, ErrorCode, ReactorEventT, ReservedErrorCode, RpcRequest, RpcWithOptionalParams,
+    RpcWithParams,
 };
 use crate::{
     effect::EffectBuilder,
     reactor::QueueKind,
     rpcs::{common, state},
-    types::{Block, BlockHash, BlockWithMetadata, JsonBlock},
+    types::{Block, BlockHash, BlockHeader, BlockWithMetadata, JsonBlock},
 };
 pub use era_summary::EraSummary;
 use era_summary::ERA_SUMMARY;
+pub use rewards_report::{DelegatorReward, EraRewards, RewardsReport};
+
+/// The maximum number of eras covered by a single "chain_get_rewards_report" request.
+const MAX_REWARDS_REPORT_ERAS: u64 = 100;
 
 static GET_BLOCK_PARAMS: Lazy<GetBlockParams> = Lazy::new(|| GetBlockParams {
     block_identifier: BlockIdentifier::Hash(*Block::doc_example().hash()),
@@ -69,6 +77,17 @@ static GET_ERA_SUMMARY_RESULT: Lazy<GetEraSummaryResult> = Lazy::new(|| GetEraSu
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
     era_summary: ERA_SUMMARY.clone(),
 });
+static GET_REWARDS_REPORT_PARAMS: Lazy<GetRewardsReportParams> =
+    Lazy::new(|| GetRewardsReportParams {
+        validator_public_key: RewardsReport::doc_example().validator_public_key.clone(),
+        start_era_id: RewardsReport::doc_example().start_era_id,
+        end_era_id: RewardsReport::doc_example().end_era_id,
+    });
+static GET_REWARDS_REPORT_RESULT: Lazy<GetRewardsReportResult> =
+    Lazy::new(|| GetRewardsReportResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+        rewards_report: RewardsReport::doc_example().clone(),
+    });
 
 /// Identifier for possible ways to retrieve a block.
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, JsonSchema)]
@@ -470,6 +489,110 @@ impl RpcWithOptionalParams for GetEraSummary {
     }
 }
 
+/// Params for "chain_get_rewards_report" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetRewardsReportParams {
+    /// The validator's public key.
+    pub validator_public_key: PublicKey,
+    /// The first era of the report.
+    pub start_era_id: EraId,
+    /// The last era of the report, inclusive.
+    pub end_era_id: EraId,
+}
+
+impl DocExample for GetRewardsReportParams {
+    fn doc_example() -> &'static Self {
+        &GET_REWARDS_REPORT_PARAMS
+    }
+}
+
+/// Result for "chain_get_rewards_report" RPC response.
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetRewardsReportResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// The validator's rewards and performance over the requested eras.
+    pub rewards_report: RewardsReport,
+}
+
+impl DocExample for GetRewardsReportResult {
+    fn doc_example() -> &'static Self {
+        &GET_REWARDS_REPORT_RESULT
+    }
+}
+
+/// "chain_get_rewards_report" RPC.
+pub struct GetRewardsReport {}
+
+#[async_trait]
+impl RpcWithParams for GetRewardsReport {
+    const METHOD: &'static str = "chain_get_rewards_report";
+    type RequestParams = GetRewardsReportParams;
+    type ResponseResult = GetRewardsReportResult;
+
+    async fn do_handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        api_version: ProtocolVersion,
+        params: Self::RequestParams,
+    ) -> Result<Self::ResponseResult, Error> {
+        let GetRewardsReportParams {
+            validator_public_key,
+            start_era_id,
+            end_era_id,
+        } = params;
+
+        if start_era_id > end_era_id
+            || end_era_id.value() - start_era_id.value() >= MAX_REWARDS_REPORT_ERAS
+        {
+            let message = format!(
+                "era range {}..={} must be non-empty and span at most {} eras",
+                start_era_id, end_era_id, MAX_REWARDS_REPORT_ERAS
+            );
+            return Err(Error::new(ErrorCode::InvalidEraRange, message));
+        }
+
+        // The validator weights of an era are recorded in the switch block of the previous era.
+        let mut validator_weights = match start_era_id.predecessor() {
+            Some(era_id) => get_switch_block_header(effect_builder, era_id)
+                .await?
+                .next_era_validator_weights()
+                .cloned(),
+            None => None,
+        };
+
+        let mut eras = vec![];
+        for era_id in start_era_id.iter_inclusive(end_era_id.value() - start_era_id.value()) {
+            let switch_block_header = get_switch_block_header(effect_builder, era_id).await?;
+            let era_info = get_era_info(effect_builder, &switch_block_header).await?;
+            eras.push(EraRewards::new(
+                &validator_public_key,
+                era_id,
+                switch_block_header.block_hash(),
+                switch_block_header
+                    .era_end()
+                    .map(|era_end| era_end.era_report()),
+                validator_weights.as_ref(),
+                era_info.as_ref(),
+            ));
+            validator_weights = switch_block_header.next_era_validator_weights().cloned();
+        }
+
+        let result = Self::ResponseResult {
+            api_version,
+            rewards_report: RewardsReport::new(
+                validator_public_key,
+                start_era_id,
+                end_era_id,
+                eras,
+            ),
+        };
+        Ok(result)
+    }
+}
+
 pub(super) async fn get_block_with_metadata<REv: ReactorEventT>(
     maybe_id: Option<BlockIdentifier>,
     only_from_available_block_range: bool,
@@ -591,3 +714,62 @@ async fn get_era_summary<REv: ReactorEventT>(
         .await;
     handle_query_result(effect_builder, block, era_info_query_result).await
 }
+
+/// Returns the header of the switch block of the given era.
+async fn get_switch_block_header<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+    era_id: EraId,
+) -> Result<BlockHeader, Error> {
+    // This RPC request is restricted by the block availability index.
+    let only_from_available_block_range = true;
+
+    match effect_builder
+        .get_switch_block_header_at_era_id_from_storage(era_id, only_from_available_block_range)
+        .await
+    {
+        Some(header) => Ok(header),
+        None => Err(common::missing_block_or_state_root_error(
+            effect_builder,
+            ErrorCode::NoSuchBlock,
+            format!("switch block of era {} not stored on this node", era_id),
+        )
+        .await),
+    }
+}
+
+/// Returns the `EraInfo` recorded at the given switch block, if any.
+///
+/// As in `get_era_summary`, the `EraInfo` is looked up under `Key::EraSummary` first, falling back
+/// to `Key::EraInfo(era_id)`.
+async fn get_era_info<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+    switch_block_header: &BlockHeader,
+) -> Result<Option<EraInfo>, Error> {
+    let state_root_hash = *switch_block_header.state_root_hash();
+    for base_key in [Key::EraSummary, Key::EraInfo(switch_block_header.era_id())] {
+        let query_result = effect_builder
+            .make_request(
+                |responder| RpcRequest::QueryGlobalState {
+                    state_root_hash,
+                    base_key,
+                    path: vec![],
+                    responder,
+                },
+                QueueKind::Api,
+            )
+            .await;
+        if matches!(query_result, Ok(QueryResult::ValueNotFound(_))) {
+            continue;
+        }
+        let (value, _proofs) =
+            state::handle_query_result(effect_builder, state_root_hash, query_result).await?;
+        return match value {
+            StoredValue::EraInfo(era_info) => Ok(Some(era_info)),
+            _ => Err(Error::new(
+                ErrorCode::QueryFailed,
+                format!("stored value under {} is not era info", base_key),
+            )),
+        };
+    }
+    Ok(None)
+}
@@ -0,0 +1,335 @@
+use std::collections::BTreeMap;
+
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{
+    system::auction::{EraInfo, SeigniorageAllocation},
+    AsymmetricType, EraId, PublicKey, U512,
+};
+
+use crate::{
+    rpcs::docs::DocExample,
+    types::{Block, BlockHash, EraReport},
+};
+
+pub(super) static REWARDS_REPORT: Lazy<RewardsReport> = Lazy::new(|| {
+    let validator_public_key =
+        PublicKey::from_hex("012a1732addc639ea43a89e25d3ad912e40232156dcaa4b9edfc709f43d2fb0876")
+            .unwrap();
+    let delegator_public_key =
+        PublicKey::from_hex("01e1b46a25baa8a5c28beb3c9cfb79b572effa04076f00befa57eb70b016153f18")
+            .unwrap();
+    let era_id = EraId::from(42);
+    let era_report = EraReport {
+        equivocators: vec![],
+        rewards: BTreeMap::from([(validator_public_key.clone(), 1_000_000_000_000)]),
+        inactive_validators: vec![],
+    };
+    let validator_weights = BTreeMap::from([(validator_public_key.clone(), U512::from(100_000))]);
+    let mut era_info = EraInfo::new();
+    *era_info.seigniorage_allocations_mut() = vec![
+        SeigniorageAllocation::delegator(
+            delegator_public_key,
+            validator_public_key.clone(),
+            U512::from(1000),
+        ),
+        SeigniorageAllocation::validator(validator_public_key.clone(), U512::from(2000)),
+    ];
+    let era_rewards = EraRewards::new(
+        &validator_public_key,
+        era_id,
+        *Block::doc_example().hash(),
+        Some(&era_report),
+        Some(&validator_weights),
+        Some(&era_info),
+    );
+    RewardsReport::new(validator_public_key, era_id, era_id, vec![era_rewards])
+});
+
+/// The rewards paid to a delegator.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DelegatorReward {
+    /// The delegator's public key.
+    pub delegator_public_key: PublicKey,
+    /// The amount paid to the delegator.
+    pub amount: U512,
+}
+
+/// A validator's rewards and performance in a single era.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EraRewards {
+    /// The era id.
+    pub era_id: EraId,
+    /// The hash of the switch block ending the era.
+    pub switch_block_hash: BlockHash,
+    /// The validator's weight in the era, if it was a validator and the weights are known.
+    pub validator_weight: Option<U512>,
+    /// The validator's reward factor passed to the auction's `distribute` entry point, measuring
+    /// its contribution to finalizing blocks of the era.
+    pub reward_factor: Option<u64>,
+    /// Whether the validator didn't produce any unit during the era.
+    pub inactive: bool,
+    /// Whether the validator equivocated during the era.
+    pub equivocated: bool,
+    /// The seigniorage paid to the validator itself.
+    pub validator_reward: U512,
+    /// The seigniorage paid to the validator's delegators, ordered by delegator public key.
+    pub delegator_rewards: Vec<DelegatorReward>,
+}
+
+impl EraRewards {
+    /// Collects the rewards and performance of `validator_public_key` in the given era from the
+    /// era's report, its validator weights and the seigniorage allocations recorded for it.
+    pub(crate) fn new(
+        validator_public_key: &PublicKey,
+        era_id: EraId,
+        switch_block_hash: BlockHash,
+        era_report: Option<&EraReport>,
+        validator_weights: Option<&BTreeMap<PublicKey, U512>>,
+        era_info: Option<&EraInfo>,
+    ) -> Self {
+        let mut validator_reward = U512::zero();
+        let mut delegator_rewards = BTreeMap::new();
+        let allocations = era_info
+            .into_iter()
+            .flat_map(|era_info| era_info.seigniorage_allocations());
+        for allocation in allocations {
+            match allocation {
+                SeigniorageAllocation::Validator {
+                    validator_public_key: allocation_validator,
+                    amount,
+                } if allocation_validator == validator_public_key => {
+                    validator_reward += *amount;
+                }
+                SeigniorageAllocation::Delegator {
+                    delegator_public_key,
+                    validator_public_key: allocation_validator,
+                    amount,
+                } if allocation_validator == validator_public_key => {
+                    *delegator_rewards
+                        .entry(delegator_public_key.clone())
+                        .or_insert_with(U512::zero) += *amount;
+                }
+                _ => {}
+            }
+        }
+
+        EraRewards {
+            era_id,
+            switch_block_hash,
+            validator_weight: validator_weights
+                .and_then(|weights| weights.get(validator_public_key))
+                .copied(),
+            reward_factor: era_report
+                .and_then(|report| report.rewards.get(validator_public_key))
+                .copied(),
+            inactive: era_report.map_or(false, |report| {
+                report.inactive_validators.contains(validator_public_key)
+            }),
+            equivocated: era_report.map_or(false, |report| {
+                report.equivocators.contains(validator_public_key)
+            }),
+            validator_reward,
+            delegator_rewards: into_delegator_rewards(delegator_rewards),
+        }
+    }
+
+    /// Returns `true` if the validator was part of the validator set of the era.
+    fn is_validator(&self) -> bool {
+        self.validator_weight.is_some() || self.reward_factor.is_some()
+    }
+}
+
+/// A summary of the rewards and performance of a validator and its delegators over a range of
+/// eras.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RewardsReport {
+    /// The validator's public key.
+    pub validator_public_key: PublicKey,
+    /// The first era of the range.
+    pub start_era_id: EraId,
+    /// The last era of the range, inclusive.
+    pub end_era_id: EraId,
+    /// The number of eras of the range in which the validator was part of the validator set.
+    pub eras_as_validator: u64,
+    /// The number of eras of the range in which the validator didn't produce any unit.
+    pub eras_inactive: u64,
+    /// The number of eras of the range in which the validator equivocated.
+    pub eras_equivocated: u64,
+    /// The total seigniorage paid to the validator itself over the range.
+    pub total_validator_reward: U512,
+    /// The total seigniorage paid to each of the validator's delegators over the range, ordered by
+    /// delegator public key.
+    pub total_delegator_rewards: Vec<DelegatorReward>,
+    /// The validator's rewards and performance in each era of the range.
+    pub eras: Vec<EraRewards>,
+}
+
+impl RewardsReport {
+    /// Summarizes the rewards and performance of the validator in the given eras.
+    pub(crate) fn new(
+        validator_public_key: PublicKey,
+        start_era_id: EraId,
+        end_era_id: EraId,
+        eras: Vec<EraRewards>,
+    ) -> Self {
+        let count = |predicate: fn(&EraRewards) -> bool| {
+            eras.iter().filter(|era| predicate(era)).count() as u64
+        };
+        let eras_as_validator = count(EraRewards::is_validator);
+        let eras_inactive = count(|era| era.inactive);
+        let eras_equivocated = count(|era| era.equivocated);
+
+        let mut total_validator_reward = U512::zero();
+        let mut total_delegator_rewards = BTreeMap::new();
+        for era in &eras {
+            total_validator_reward += era.validator_reward;
+            for reward in &era.delegator_rewards {
+                *total_delegator_rewards
+                    .entry(reward.delegator_public_key.clone())
+                    .or_insert_with(U512::zero) += reward.amount;
+            }
+        }
+
+        RewardsReport {
+            validator_public_key,
+            start_era_id,
+            end_era_id,
+            eras_as_validator,
+            eras_inactive,
+            eras_equivocated,
+            total_validator_reward,
+            total_delegator_rewards: into_delegator_rewards(total_delegator_rewards),
+            eras,
+        }
+    }
+}
+
+impl DocExample for RewardsReport {
+    fn doc_example() -> &'static Self {
+        &REWARDS_REPORT
+    }
+}
+
+fn into_delegator_rewards(rewards: BTreeMap<PublicKey, U512>) -> Vec<DelegatorReward> {
+    rewards
+        .into_iter()
+        .map(|(delegator_public_key, amount)| DelegatorReward {
+            delegator_public_key,
+            amount,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{testing::TestRng, SecretKey};
+
+    use super::*;
+
+    #[test]
+    fn should_summarize_rewards_over_eras() {
+        let mut rng = TestRng::new();
+        let validator = PublicKey::from(&SecretKey::random(&mut rng));
+        let other_validator = PublicKey::from(&SecretKey::random(&mut rng));
+        let delegator = PublicKey::from(&SecretKey::random(&mut rng));
+
+        let era_info = |validator_amount: u64, delegator_amount: u64| {
+            let mut era_info = EraInfo::new();
+            *era_info.seigniorage_allocations_mut() = vec![
+                SeigniorageAllocation::validator(validator.clone(), validator_amount.into()),
+                SeigniorageAllocation::delegator(
+                    delegator.clone(),
+                    validator.clone(),
+                    delegator_amount.into(),
+                ),
+                SeigniorageAllocation::validator(other_validator.clone(), 1_000.into()),
+                SeigniorageAllocation::delegator(
+                    delegator.clone(),
+                    other_validator.clone(),
+                    1_000.into(),
+                ),
+            ];
+            era_info
+        };
+        let weights = BTreeMap::from([(validator.clone(), U512::from(10))]);
+
+        let active_report = EraReport {
+            equivocators: vec![],
+            rewards: BTreeMap::from([(validator.clone(), 5), (other_validator.clone(), 7)]),
+            inactive_validators: vec![],
+        };
+        let first_era = EraRewards::new(
+            &validator,
+            EraId::new(1),
+            BlockHash::random(&mut rng),
+            Some(&active_report),
+            Some(&weights),
+            Some(&era_info(20, 3)),
+        );
+        assert_eq!(first_era.validator_weight, Some(U512::from(10)));
+        assert_eq!(first_era.reward_factor, Some(5));
+        assert!(!first_era.inactive);
+        assert!(!first_era.equivocated);
+        assert_eq!(first_era.validator_reward, U512::from(20));
+        assert_eq!(
+            first_era.delegator_rewards,
+            vec![DelegatorReward {
+                delegator_public_key: delegator.clone(),
+                amount: U512::from(3),
+            }]
+        );
+
+        let faulty_report = EraReport {
+            equivocators: vec![validator.clone()],
+            rewards: BTreeMap::new(),
+            inactive_validators: vec![validator.clone()],
+        };
+        let second_era = EraRewards::new(
+            &validator,
+            EraId::new(2),
+            BlockHash::random(&mut rng),
+            Some(&faulty_report),
+            Some(&weights),
+            Some(&era_info(0, 4)),
+        );
+        assert!(second_era.inactive);
+        assert!(second_era.equivocated);
+
+        // The validator is no longer bonded.
+        let third_era = EraRewards::new(
+            &validator,
+            EraId::new(3),
+            BlockHash::random(&mut rng),
+            Some(&active_report),
+            Some(&BTreeMap::new()),
+            None,
+        );
+        assert_eq!(third_era.reward_factor, Some(5));
+        assert_eq!(third_era.validator_reward, U512::zero());
+        assert!(third_era.delegator_rewards.is_empty());
+
+        let report = RewardsReport::new(
+            validator.clone(),
+            EraId::new(1),
+            EraId::new(4),
+            vec![first_era, second_era, third_era],
+        );
+        assert_eq!(report.eras_as_validator, 3);
+        assert_eq!(report.eras_inactive, 1);
+        assert_eq!(report.eras_equivocated, 1);
+        assert_eq!(report.total_validator_reward, U512::from(20));
+        assert_eq!(
+            report.total_delegator_rewards,
+            vec![DelegatorReward {
+                delegator_public_key: delegator,
+                amount: U512::from(7),
+            }]
+        );
+    }
+}
@@ -16,14 +16,15 @@ use serde_json::{json, Value};
 use casper_types::ProtocolVersion;
 
 use super::{
-    account::PutDeploy,
+    account::{CancelDeploy, PutDeploy},
     chain::{
-        GetBlock, GetBlockTransfers, GetEraInfoBySwitchBlock, GetEraSummary, GetStateRootHash,
+        GetBlock, GetBlockTransfers, GetEraInfoBySwitchBlock, GetEraSummary, GetRewardsReport,
+        GetStateRootHash,
     },
-    info::{GetChainspec, GetDeploy, GetPeers, GetStatus, GetValidatorChanges},
+    info::{GetChainspec, GetDeploy, GetDeployStatus, GetPeers, GetStatus, GetValidatorChanges},
     state::{
-        GetAccountInfo, GetAuctionInfo, GetBalance, GetDictionaryItem, GetItem, QueryBalance,
-        QueryGlobalState,
+        GetAccountInfo, GetAuctionInfo, GetBalance, GetDelegators, GetDictionaryItem, GetItem,
+        GetUnbondingQueue, QueryBalance, QueryGlobalState,
     },
     Error, ReactorEventT, RpcWithOptionalParams, RpcWithParams, RpcWithoutParams,
 };
@@ -109,6 +110,28 @@ pub(crate) static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
         "returns the era summary at either a specific block (by height or hash), or the most \
         recently added block",
     );
+    schema.push_with_params::<GetDelegators>(
+        "returns a page of the delegators of a validator as of either a specific block (by height \
+        or hash), or the most recently added block",
+    );
+    schema.push_with_params::<GetUnbondingQueue>(
+        "returns the pending unbonding and withdraw purses of an account, along with the eras in \
+        which they will be released, as of either a specific block (by height or hash), or the \
+        most recently added block",
+    );
+    schema.push_with_params::<GetRewardsReport>(
+        "returns a validator's rewards and performance, along with the rewards paid to its \
+        delegators, over an inclusive range of eras",
+    );
+    schema.push_with_params::<GetDeployStatus>(
+        "returns the status of a deploy on this node: unknown, rejected, pending, proposed, \
+        finalized, executed, dropped or expired",
+    );
+    schema.push_with_params::<CancelDeploy>(
+        "receives a cancellation of a pending Deploy, optionally in favor of a replacement Deploy \
+        from the same account offering a higher gas price; cancellations are best-effort and not \
+        enforced by block validation, so the cancelled Deploy may still be executed",
+    );
 
     schema
 });
@@ -570,7 +593,13 @@ mod tests {
 
     #[test]
     fn check_state_get_auction_info_required_fields() {
+        // `include_unbonding_queue` defaults to `false`, so may be omitted even when the block
+        // identifier is passed.
         let incorrect_optional_params = check_optional_params_fields::<GetAuctionInfo>();
-        assert!(incorrect_optional_params.is_empty())
+        let names = incorrect_optional_params
+            .iter()
+            .map(|param| param.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["include_unbonding_queue"])
     }
 }
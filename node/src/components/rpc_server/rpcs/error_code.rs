@@ -35,6 +35,20 @@ pub enum ErrorCode {
     FailedToGetTrie = -32011,
     /// The requested state root hash was not found.
     NoSuchStateRoot = -32012,
+    /// Evaluating the requested view entry point failed.
+    ViewCallFailed = -32013,
+    /// Executing the request to evaluate a view entry point failed.
+    ViewCallFailedToExecute = -32014,
+    /// The requested validator has no bid.
+    NoSuchValidator = -32015,
+    /// Executing the request to retrieve the delegators of a validator failed.
+    GetDelegatorsFailedToExecute = -32016,
+    /// Executing the request to retrieve the unbonding queue failed.
+    GetUnbondingQueueFailedToExecute = -32017,
+    /// The requested range of eras is empty or too large.
+    InvalidEraRange = -32018,
+    /// The submitted deploy cancellation is invalid.
+    InvalidDeployCancellation = -32019,
 }
 
 impl From<ErrorCode> for (i64, &'static str) {
@@ -59,6 +73,21 @@ impl From<ErrorCode> for (i64, &'static str) {
             }
             ErrorCode::FailedToGetTrie => (error_code as i64, "Failed to get trie"),
             ErrorCode::NoSuchStateRoot => (error_code as i64, "No such state root"),
+            ErrorCode::ViewCallFailed => (error_code as i64, "View call failed"),
+            ErrorCode::ViewCallFailedToExecute => {
+                (error_code as i64, "View call failed to execute")
+            }
+            ErrorCode::NoSuchValidator => (error_code as i64, "No such validator"),
+            ErrorCode::GetDelegatorsFailedToExecute => {
+                (error_code as i64, "get-delegators failed to execute")
+            }
+            ErrorCode::GetUnbondingQueueFailedToExecute => {
+                (error_code as i64, "get-unbonding-queue failed to execute")
+            }
+            ErrorCode::InvalidEraRange => (error_code as i64, "Invalid era range"),
+            ErrorCode::InvalidDeployCancellation => {
+                (error_code as i64, "Invalid deploy cancellation")
+            }
         }
     }
 }
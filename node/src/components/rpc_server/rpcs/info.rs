@@ -11,14 +11,14 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use casper_types::{EraId, ExecutionResult, ProtocolVersion, PublicKey};
+use casper_types::{EraId, ExecutionResult, ProtocolVersion, PublicKey, Timestamp};
 
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
     Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithParams, RpcWithoutParams,
 };
 use crate::{
-    components::consensus::ValidatorChange,
+    components::{consensus::ValidatorChange, deploy_buffer::DeployBufferStatus},
     effect::EffectBuilder,
     reactor::QueueKind,
     types::{
@@ -38,8 +38,25 @@ static GET_DEPLOY_RESULT: Lazy<GetDeployResult> = Lazy::new(|| GetDeployResult {
         block_hash: *Block::doc_example().hash(),
         result: ExecutionResult::example().clone(),
     }],
+    unmet_dependencies: vec![],
     block_hash_and_height: None,
 });
+static GET_DEPLOY_STATUS_PARAMS: Lazy<GetDeployStatusParams> =
+    Lazy::new(|| GetDeployStatusParams {
+        deploy_hash: *Deploy::doc_example().hash(),
+    });
+static GET_DEPLOY_STATUS_RESULT: Lazy<GetDeployStatusResult> = Lazy::new(|| {
+    let block = Block::doc_example();
+    GetDeployStatusResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+        deploy_hash: *Deploy::doc_example().hash(),
+        status: DeployStatus::Executed {
+            block_hash: *block.hash(),
+            block_height: block.height(),
+            executed_at: block.timestamp(),
+        },
+    }
+});
 static GET_PEERS_RESULT: Lazy<GetPeersResult> = Lazy::new(|| GetPeersResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
     peers: GetStatusResult::doc_example().peers.clone(),
@@ -102,6 +119,10 @@ pub struct GetDeployResult {
     pub deploy: Deploy,
     /// The map of block hash to execution result.
     pub execution_results: Vec<JsonExecutionResult>,
+    /// The dependencies of this deploy which have not been executed yet, only provided if this
+    /// deploy has not been executed either.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unmet_dependencies: Vec<DeployHash>,
     /// The hash and height of the block in which this deploy was executed,
     /// only provided if the full execution results are not know on this node.
     #[serde(skip_serializing_if = "Option::is_none", flatten)]
@@ -167,16 +188,225 @@ impl RpcWithParams for GetDeploy {
             DeployMetadataExt::Empty => (Vec::new(), None),
         };
 
+        let unmet_dependencies = if execution_results.is_empty() && block_hash_and_height.is_none()
+        {
+            let dependencies = deploy.header().dependencies();
+            let executed = effect_builder
+                .get_executed_deploys(dependencies.iter().copied().collect())
+                .await;
+            dependencies
+                .iter()
+                .filter(|dependency| !executed.contains_key(dependency))
+                .copied()
+                .collect()
+        } else {
+            vec![]
+        };
+
         let result = Self::ResponseResult {
             api_version,
             deploy,
             execution_results,
+            unmet_dependencies,
             block_hash_and_height,
         };
         Ok(result)
     }
 }
 
+/// Params for "info_get_deploy_status" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDeployStatusParams {
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+}
+
+impl DocExample for GetDeployStatusParams {
+    fn doc_example() -> &'static Self {
+        &GET_DEPLOY_STATUS_PARAMS
+    }
+}
+
+/// The status of a deploy as known to this node.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub enum DeployStatus {
+    /// The deploy is not known to this node.
+    Unknown,
+    /// The deploy has been included in a finalized block, but not executed yet.
+    Finalized,
+    /// The deploy was recently submitted to this node by a client and rejected.
+    Rejected {
+        /// The time at which the deploy was rejected.
+        rejected_at: Timestamp,
+        /// The reason for the rejection.
+        error: String,
+    },
+    /// The deploy is awaiting inclusion in a proposed block.
+    Pending {
+        /// The time at which the deploy expires.
+        expires_at: Timestamp,
+    },
+    /// The deploy is included in a proposed block which has not been finalized yet.
+    Proposed {
+        /// The timestamp of the proposed block.
+        proposed_at: Timestamp,
+        /// The time at which the deploy expires.
+        expires_at: Timestamp,
+    },
+    /// The deploy will not be proposed by this node, as it became invalid or was cancelled while
+    /// awaiting inclusion in a block.
+    Dropped {
+        /// The time at which the deploy expires.
+        expires_at: Timestamp,
+    },
+    /// The deploy expired before being included in a block.
+    Expired {
+        /// The time at which the deploy expired.
+        expired_at: Timestamp,
+    },
+    /// The deploy has been executed.
+    Executed {
+        /// The hash of the block in which the deploy was executed.
+        block_hash: BlockHash,
+        /// The height of the block in which the deploy was executed.
+        block_height: u64,
+        /// The timestamp of the block in which the deploy was executed.
+        executed_at: Timestamp,
+    },
+}
+
+/// Result for "info_get_deploy_status" RPC response.
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDeployStatusResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+    /// The status of the deploy.
+    pub status: DeployStatus,
+}
+
+impl DocExample for GetDeployStatusResult {
+    fn doc_example() -> &'static Self {
+        &GET_DEPLOY_STATUS_RESULT
+    }
+}
+
+/// "info_get_deploy_status" RPC.
+pub struct GetDeployStatus {}
+
+#[async_trait]
+impl RpcWithParams for GetDeployStatus {
+    const METHOD: &'static str = "info_get_deploy_status";
+    type RequestParams = GetDeployStatusParams;
+    type ResponseResult = GetDeployStatusResult;
+
+    async fn do_handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        api_version: ProtocolVersion,
+        params: Self::RequestParams,
+    ) -> Result<Self::ResponseResult, Error> {
+        let deploy_hash = params.deploy_hash;
+        let maybe_deploy_and_metadata = effect_builder
+            .make_request(
+                |responder| RpcRequest::GetDeploy {
+                    hash: deploy_hash,
+                    finalized_approvals: false,
+                    responder,
+                },
+                QueueKind::Api,
+            )
+            .await
+            .map(|deploy_and_metadata| *deploy_and_metadata);
+
+        // An executed deploy takes precedence over anything the deploy acceptor or deploy buffer
+        // know about it.
+        let maybe_block_hash = match &maybe_deploy_and_metadata {
+            Some((_, DeployMetadataExt::Metadata(metadata))) => {
+                metadata.execution_results.keys().next().copied()
+            }
+            Some((_, DeployMetadataExt::BlockInfo(block_hash_and_height))) => {
+                Some(block_hash_and_height.block_hash)
+            }
+            Some((_, DeployMetadataExt::Empty)) | None => None,
+        };
+        if let Some(block_hash) = maybe_block_hash {
+            let block_header = effect_builder
+                .get_block_header_from_storage(block_hash, false)
+                .await
+                .ok_or_else(|| {
+                    let message = format!(
+                        "failed to get header of block {} executing {}",
+                        block_hash, deploy_hash
+                    );
+                    info!("{}", message);
+                    Error::new(ErrorCode::NoSuchBlock, message)
+                })?;
+            let status = DeployStatus::Executed {
+                block_hash,
+                block_height: block_header.height(),
+                executed_at: block_header.timestamp(),
+            };
+            return Ok(Self::ResponseResult {
+                api_version,
+                deploy_hash,
+                status,
+            });
+        }
+
+        // The deploy buffer is consulted before the recorded rejections, as a deploy rejected once
+        // may since have been resubmitted and accepted.
+        let now = Timestamp::now();
+        let status = match effect_builder.get_deploy_buffer_status(deploy_hash).await {
+            Some(DeployBufferStatus::Finalized) => DeployStatus::Finalized,
+            Some(
+                DeployBufferStatus::Pending { expires_at }
+                | DeployBufferStatus::Held { expires_at, .. }
+                | DeployBufferStatus::Dead { expires_at },
+            ) if expires_at <= now => DeployStatus::Expired {
+                expired_at: expires_at,
+            },
+            Some(DeployBufferStatus::Pending { expires_at }) => {
+                DeployStatus::Pending { expires_at }
+            }
+            Some(DeployBufferStatus::Held {
+                proposed_at,
+                expires_at,
+            }) => DeployStatus::Proposed {
+                proposed_at,
+                expires_at,
+            },
+            Some(DeployBufferStatus::Dead { expires_at }) => DeployStatus::Dropped { expires_at },
+            None => match effect_builder.get_deploy_rejection(deploy_hash).await {
+                Some(rejection) => DeployStatus::Rejected {
+                    rejected_at: rejection.rejected_at,
+                    error: rejection.error,
+                },
+                // The deploy is stored but not (or no longer) buffered.
+                None => match maybe_deploy_and_metadata {
+                    Some((deploy, _)) if deploy.header().expired(now) => DeployStatus::Expired {
+                        expired_at: deploy.header().expires(),
+                    },
+                    Some((deploy, _)) => DeployStatus::Pending {
+                        expires_at: deploy.header().expires(),
+                    },
+                    None => DeployStatus::Unknown,
+                },
+            },
+        };
+
+        Ok(Self::ResponseResult {
+            api_version,
+            deploy_hash,
+            status,
+        })
+    }
+}
+
 /// Result for "info_get_peers" RPC response.
 #[derive(PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -10,7 +10,9 @@ use once_cell::sync::Lazy;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use casper_execution_engine::core::engine_state::Error as EngineStateError;
+use casper_execution_engine::{
+    core::engine_state::Error as EngineStateError, shared::gas_profile::GasProfiler,
+};
 use casper_json_rpc::ReservedErrorCode;
 use casper_types::{ExecutionResult, ProtocolVersion};
 
@@ -29,11 +31,13 @@ use crate::{
 static SPECULATIVE_EXEC_PARAMS: Lazy<SpeculativeExecParams> = Lazy::new(|| SpeculativeExecParams {
     block_identifier: Some(BlockIdentifier::Hash(*Block::doc_example().hash())),
     deploy: Deploy::doc_example().clone(),
+    profile_gas: false,
 });
 static SPECULATIVE_EXEC_RESULT: Lazy<SpeculativeExecResult> = Lazy::new(|| SpeculativeExecResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
     block_hash: *Block::doc_example().hash(),
     execution_result: ExecutionResult::example().clone(),
+    gas_profile: None,
 });
 
 /// Params for "speculative_exec" RPC request.
@@ -44,6 +48,9 @@ pub struct SpeculativeExecParams {
     pub block_identifier: Option<BlockIdentifier>,
     /// Deploy to execute.
     pub deploy: Deploy,
+    /// Whether to attribute the gas charged by the deploy and return it as a gas profile.
+    #[serde(default)]
+    pub profile_gas: bool,
 }
 
 impl DocExample for SpeculativeExecParams {
@@ -63,6 +70,10 @@ pub struct SpeculativeExecResult {
     pub block_hash: BlockHash,
     /// Result of the execution.
     pub execution_result: ExecutionResult,
+    /// Gas charged by the deploy attributed to Wasm functions, opcode classes, host functions and
+    /// storage, in the folded stack format used by flamegraph tooling.  Only present if requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_profile: Option<String>,
 }
 
 impl DocExample for SpeculativeExecResult {
@@ -88,6 +99,7 @@ impl RpcWithParams for SpeculativeExec {
         let SpeculativeExecParams {
             block_identifier: maybe_block_id,
             deploy,
+            profile_gas,
         } = params;
         let deploy = Arc::new(deploy);
         let only_from_available_block_range = true;
@@ -103,6 +115,9 @@ impl RpcWithParams for SpeculativeExec {
             state_root_hash: *block.state_root_hash(),
             block_time: block.timestamp(),
             protocol_version: block.protocol_version(),
+            block_height: block.height(),
+            era_id: block.header().era_id(),
+            parent_hash: *block.header().parent_hash(),
         };
 
         let accept_deploy_result = effect_builder
@@ -113,8 +128,13 @@ impl RpcWithParams for SpeculativeExec {
             return Err(Error::new(ErrorCode::InvalidDeploy, error.to_string()));
         }
 
+        let gas_profiler = profile_gas.then(GasProfiler::new);
         let result = effect_builder
-            .speculative_execute_deploy(execution_prestate, Arc::clone(&deploy))
+            .speculative_execute_deploy(
+                execution_prestate,
+                Arc::clone(&deploy),
+                gas_profiler.clone(),
+            )
             .await;
 
         match result {
@@ -123,6 +143,8 @@ impl RpcWithParams for SpeculativeExec {
                     api_version,
                     block_hash,
                     execution_result,
+                    gas_profile: gas_profiler
+                        .map(|gas_profiler| gas_profiler.take_profile().to_folded()),
                 };
                 Ok(result)
             }
@@ -12,7 +12,10 @@ use serde::{Deserialize, Serialize};
 use tracing::{debug, error, info, warn};
 
 use casper_execution_engine::{
-    core::engine_state::{self, BalanceResult, GetBidsResult, QueryResult},
+    core::engine_state::{
+        self, BalanceResult, GetBidsResult, GetDelegatorsRequest, GetUnbondingQueueRequest,
+        QueryResult,
+    },
     storage::trie::merkle_proof::TrieMerkleProof,
 };
 use casper_hashing::Digest;
@@ -20,11 +23,13 @@ use casper_json_rpc::ReservedErrorCode;
 use casper_types::{
     account::AccountHash,
     bytesrepr::{Bytes, ToBytes},
-    CLValue, Key, ProtocolVersion, PublicKey, SecretKey, StoredValue as DomainStoredValue, URef,
-    U512,
+    system::auction::{Delegator, UnbondingPurse},
+    AccessRights, CLValue, ContractHash, EraId, Key, ProtocolVersion, PublicKey, RuntimeArgs,
+    SecretKey, StoredValue as DomainStoredValue, URef, U512,
 };
 
 use crate::{
+    components::contract_runtime::SpeculativeExecutionState,
     effect::EffectBuilder,
     reactor::QueueKind,
     rpcs::{
@@ -34,7 +39,9 @@ use crate::{
         Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithOptionalParams, RpcWithParams,
     },
     types::{
-        json_compatibility::{Account as JsonAccount, AuctionState, StoredValue},
+        json_compatibility::{
+            Account as JsonAccount, AuctionState, JsonUnbondingPurse, StoredValue,
+        },
         Block, BlockHash, JsonBlockHeader,
     },
 };
@@ -61,6 +68,7 @@ static GET_BALANCE_RESULT: Lazy<GetBalanceResult> = Lazy::new(|| GetBalanceResul
 });
 static GET_AUCTION_INFO_PARAMS: Lazy<GetAuctionInfoParams> = Lazy::new(|| GetAuctionInfoParams {
     block_identifier: BlockIdentifier::Hash(*Block::doc_example().hash()),
+    include_unbonding_queue: false,
 });
 static GET_AUCTION_INFO_RESULT: Lazy<GetAuctionInfoResult> = Lazy::new(|| GetAuctionInfoResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
@@ -129,6 +137,81 @@ static QUERY_BALANCE_RESULT: Lazy<QueryBalanceResult> = Lazy::new(|| QueryBalanc
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
     balance: U512::from(123_456),
 });
+static CALL_VIEW_PARAMS: Lazy<CallViewParams> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([0; 32]).unwrap();
+    let public_key = PublicKey::from(&secret_key);
+    let mut args = RuntimeArgs::new();
+    args.insert("index", 1u64).unwrap();
+    CallViewParams {
+        block_identifier: Some(BlockIdentifier::Hash(*Block::doc_example().hash())),
+        contract_hash: ContractHash::new([42; 32]),
+        entry_point: "get_value".to_string(),
+        args,
+        caller: Some(AccountIdentifier::PublicKey(public_key)),
+    }
+});
+static CALL_VIEW_RESULT: Lazy<CallViewResult> = Lazy::new(|| CallViewResult {
+    api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+    block_hash: *Block::doc_example().hash(),
+    value: CLValue::from_t(1_000_000u64).unwrap(),
+    cost: U512::from(123_456),
+});
+static GET_DELEGATORS_PARAMS: Lazy<GetDelegatorsParams> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([0; 32]).unwrap();
+    GetDelegatorsParams {
+        block_identifier: Some(BlockIdentifier::Hash(*Block::doc_example().hash())),
+        validator_public_key: PublicKey::from(&secret_key),
+        cursor: None,
+        page_size: Some(DEFAULT_DELEGATORS_PAGE_SIZE),
+    }
+});
+static GET_DELEGATORS_RESULT: Lazy<GetDelegatorsResult> = Lazy::new(|| {
+    let validator_secret_key = SecretKey::ed25519_from_bytes([0; 32]).unwrap();
+    let delegator_secret_key = SecretKey::ed25519_from_bytes([1; 32]).unwrap();
+    let delegator = Delegator::unlocked(
+        PublicKey::from(&delegator_secret_key),
+        U512::from(10),
+        URef::new([251; 32], AccessRights::READ_ADD_WRITE),
+        PublicKey::from(&validator_secret_key),
+    );
+    GetDelegatorsResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+        block_hash: *Block::doc_example().hash(),
+        delegators: vec![delegator],
+        total: 1,
+        next_cursor: None,
+    }
+});
+
+static GET_UNBONDING_QUEUE_PARAMS: Lazy<GetUnbondingQueueParams> = Lazy::new(|| {
+    let secret_key = SecretKey::ed25519_from_bytes([0; 32]).unwrap();
+    GetUnbondingQueueParams {
+        block_identifier: Some(BlockIdentifier::Hash(*Block::doc_example().hash())),
+        account_identifier: AccountIdentifier::PublicKey(PublicKey::from(&secret_key)),
+    }
+});
+static GET_UNBONDING_QUEUE_RESULT: Lazy<GetUnbondingQueueResult> = Lazy::new(|| {
+    let validator_secret_key = SecretKey::ed25519_from_bytes([0; 32]).unwrap();
+    let unbonder_secret_key = SecretKey::ed25519_from_bytes([1; 32]).unwrap();
+    let unbonding_purse = UnbondingPurse::new(
+        URef::new([251; 32], AccessRights::READ_ADD_WRITE),
+        PublicKey::from(&validator_secret_key),
+        PublicKey::from(&unbonder_secret_key),
+        EraId::new(9),
+        U512::from(10),
+        None,
+    );
+    GetUnbondingQueueResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+        block_hash: *Block::doc_example().hash(),
+        unbonding_queue: vec![JsonUnbondingPurse::new(unbonding_purse, 7)],
+    }
+});
+
+/// The number of delegators returned by "state_get_delegators" if no page size is requested.
+const DEFAULT_DELEGATORS_PAGE_SIZE: u32 = 100;
+/// The largest number of delegators returned by a single "state_get_delegators" request.
+const MAX_DELEGATORS_PAGE_SIZE: u32 = 1_000;
 
 /// Params for "state_get_item" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
@@ -331,6 +414,10 @@ impl RpcWithParams for GetBalance {
 pub struct GetAuctionInfoParams {
     /// The block identifier.
     pub block_identifier: BlockIdentifier,
+    /// Whether to include all pending unbonding and withdraw purses in the auction state. If
+    /// `false` or omitted, they are not included.
+    #[serde(default)]
+    pub include_unbonding_queue: bool,
 }
 
 impl DocExample for GetAuctionInfoParams {
@@ -373,7 +460,13 @@ impl RpcWithOptionalParams for GetAuctionInfo {
         // This RPC request is restricted by the block availability index.
         let only_from_available_block_range = true;
 
-        let maybe_block_id = maybe_params.map(|params| params.block_identifier);
+        let (maybe_block_id, include_unbonding_queue) = match maybe_params {
+            Some(params) => (
+                Some(params.block_identifier),
+                params.include_unbonding_queue,
+            ),
+            None => (None, false),
+        };
         let block = common::get_block(
             maybe_block_id,
             only_from_available_block_range,
@@ -458,7 +551,13 @@ impl RpcWithOptionalParams for GetAuctionInfo {
             }
         };
 
-        let auction_state = AuctionState::new(state_root_hash, block_height, era_validators, bids);
+        let mut auction_state =
+            AuctionState::new(state_root_hash, block_height, era_validators, bids);
+        if include_unbonding_queue {
+            let (unbonding_purses, unbonding_delay) =
+                get_unbonding_queue(effect_builder, state_root_hash, None).await?;
+            auction_state.set_unbonding_queue(unbonding_purses, unbonding_delay);
+        }
 
         let result = Self::ResponseResult {
             api_version,
@@ -1098,6 +1197,385 @@ impl RpcWithParams for GetTrie {
     }
 }
 
+/// Params for "state_call_view" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CallViewParams {
+    /// The identifier of the block against whose global state the view entry point is evaluated.
+    /// If none is passed the most recently added block will be used.
+    pub block_identifier: Option<BlockIdentifier>,
+    /// The hash of the contract to call.
+    pub contract_hash: ContractHash,
+    /// The name of the view entry point.
+    pub entry_point: String,
+    /// The runtime arguments passed to the view entry point.
+    pub args: RuntimeArgs,
+    /// The account on whose behalf the view entry point is evaluated. If none is passed the
+    /// system account will be used.
+    pub caller: Option<AccountIdentifier>,
+}
+
+impl DocExample for CallViewParams {
+    fn doc_example() -> &'static Self {
+        &CALL_VIEW_PARAMS
+    }
+}
+
+/// Result for "state_call_view" RPC response.
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CallViewResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// Hash of the block against whose global state the view entry point was evaluated.
+    pub block_hash: BlockHash,
+    /// The value returned by the view entry point.
+    pub value: CLValue,
+    /// The gas consumed by the evaluation.
+    pub cost: U512,
+}
+
+impl DocExample for CallViewResult {
+    fn doc_example() -> &'static Self {
+        &CALL_VIEW_RESULT
+    }
+}
+
+/// "state_call_view" RPC.
+pub struct CallView {}
+
+#[async_trait]
+impl RpcWithParams for CallView {
+    const METHOD: &'static str = "state_call_view";
+    type RequestParams = CallViewParams;
+    type ResponseResult = CallViewResult;
+
+    async fn do_handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        api_version: ProtocolVersion,
+        params: Self::RequestParams,
+    ) -> Result<Self::ResponseResult, Error> {
+        // This RPC request is restricted by the block availability index.
+        let only_from_available_block_range = true;
+
+        let CallViewParams {
+            block_identifier,
+            contract_hash,
+            entry_point,
+            args,
+            caller,
+        } = params;
+
+        let block = common::get_block(
+            block_identifier,
+            only_from_available_block_range,
+            effect_builder,
+        )
+        .await?;
+        let block_hash = *block.hash();
+        let state_root_hash = *block.state_root_hash();
+        let execution_prestate = SpeculativeExecutionState {
+            state_root_hash,
+            block_time: block.timestamp(),
+            protocol_version: block.protocol_version(),
+            block_height: block.height(),
+            era_id: block.header().era_id(),
+            parent_hash: *block.header().parent_hash(),
+        };
+
+        let caller = match caller {
+            Some(AccountIdentifier::PublicKey(public_key)) => public_key.to_account_hash(),
+            Some(AccountIdentifier::AccountHash(account_hash)) => account_hash,
+            None => PublicKey::System.to_account_hash(),
+        };
+
+        let call_view_result = effect_builder
+            .call_view(execution_prestate, caller, contract_hash, entry_point, args)
+            .await;
+
+        match call_view_result {
+            Ok(engine_state::CallViewResult::Success { value, cost }) => Ok(Self::ResponseResult {
+                api_version,
+                block_hash,
+                value,
+                cost: cost.value(),
+            }),
+            Ok(engine_state::CallViewResult::RootNotFound) => {
+                info!("call view failed: root not found");
+                let error = common::missing_block_or_state_root_error(
+                    effect_builder,
+                    ErrorCode::NoSuchStateRoot,
+                    format!("failed to get state root at {:?}", state_root_hash),
+                )
+                .await;
+                Err(error)
+            }
+            Ok(engine_state::CallViewResult::CallerNotFound) => {
+                let error_msg = format!("failed to get account {}", caller);
+                info!("{}", error_msg);
+                Err(Error::new(ErrorCode::NoSuchAccount, error_msg))
+            }
+            Ok(engine_state::CallViewResult::Failure { error, cost }) => {
+                debug!(%error, %cost, "call view failed");
+                Err(Error::new(ErrorCode::ViewCallFailed, error))
+            }
+            Err(error) => {
+                info!(?error, "call view failed to execute");
+                Err(Error::new(
+                    ErrorCode::ViewCallFailedToExecute,
+                    format!("{:?}", error),
+                ))
+            }
+        }
+    }
+}
+
+/// Params for "state_get_delegators" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDelegatorsParams {
+    /// The identifier of the block against whose global state the delegators are read. If none
+    /// is passed the most recently added block will be used.
+    pub block_identifier: Option<BlockIdentifier>,
+    /// The public key of the validator whose delegators are requested.
+    pub validator_public_key: PublicKey,
+    /// The `next_cursor` returned with the previous page, after which the requested page starts.
+    /// Defaults to the first page.
+    pub cursor: Option<AccountHash>,
+    /// The maximum number of delegators in a page. Defaults to 100 and is capped at 1000.
+    pub page_size: Option<u32>,
+}
+
+impl DocExample for GetDelegatorsParams {
+    fn doc_example() -> &'static Self {
+        &GET_DELEGATORS_PARAMS
+    }
+}
+
+/// Result for "state_get_delegators" RPC response.
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDelegatorsResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// Hash of the block against whose global state the delegators were read.
+    pub block_hash: BlockHash,
+    /// The delegators on the requested page, ordered by delegator account hash.
+    pub delegators: Vec<Delegator>,
+    /// The total number of delegators of the validator.
+    pub total: u64,
+    /// The cursor to request the next page with, or `None` if this is the last page.
+    pub next_cursor: Option<AccountHash>,
+}
+
+impl DocExample for GetDelegatorsResult {
+    fn doc_example() -> &'static Self {
+        &GET_DELEGATORS_RESULT
+    }
+}
+
+/// "state_get_delegators" RPC.
+pub struct GetDelegators {}
+
+#[async_trait]
+impl RpcWithParams for GetDelegators {
+    const METHOD: &'static str = "state_get_delegators";
+    type RequestParams = GetDelegatorsParams;
+    type ResponseResult = GetDelegatorsResult;
+
+    async fn do_handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        api_version: ProtocolVersion,
+        params: Self::RequestParams,
+    ) -> Result<Self::ResponseResult, Error> {
+        // This RPC request is restricted by the block availability index.
+        let only_from_available_block_range = true;
+
+        let GetDelegatorsParams {
+            block_identifier,
+            validator_public_key,
+            cursor,
+            page_size,
+        } = params;
+
+        let block = common::get_block(
+            block_identifier,
+            only_from_available_block_range,
+            effect_builder,
+        )
+        .await?;
+        let block_hash = *block.hash();
+        let state_root_hash = *block.state_root_hash();
+
+        let page_size = page_size
+            .unwrap_or(DEFAULT_DELEGATORS_PAGE_SIZE)
+            .min(MAX_DELEGATORS_PAGE_SIZE);
+        let get_delegators_request = GetDelegatorsRequest::new(
+            state_root_hash,
+            validator_public_key.clone(),
+            cursor,
+            page_size,
+        );
+
+        match effect_builder.get_delegators(get_delegators_request).await {
+            Ok(engine_state::GetDelegatorsResult::Success {
+                delegators,
+                total,
+                next_cursor,
+            }) => Ok(Self::ResponseResult {
+                api_version,
+                block_hash,
+                delegators,
+                total,
+                next_cursor,
+            }),
+            Ok(engine_state::GetDelegatorsResult::RootNotFound) => {
+                info!("get delegators failed: root not found");
+                let error = common::missing_block_or_state_root_error(
+                    effect_builder,
+                    ErrorCode::NoSuchStateRoot,
+                    format!("failed to get state root at {:?}", state_root_hash),
+                )
+                .await;
+                Err(error)
+            }
+            Ok(engine_state::GetDelegatorsResult::ValidatorNotFound) => {
+                let error_msg = format!("failed to get bid of validator {}", validator_public_key);
+                info!("{}", error_msg);
+                Err(Error::new(ErrorCode::NoSuchValidator, error_msg))
+            }
+            Err(error) => {
+                info!(?error, "get delegators failed to execute");
+                Err(Error::new(
+                    ErrorCode::GetDelegatorsFailedToExecute,
+                    format!("{:?}", error),
+                ))
+            }
+        }
+    }
+}
+
+/// Params for "state_get_unbonding_queue" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetUnbondingQueueParams {
+    /// The identifier of the block against whose global state the unbonding queue is read. If
+    /// none is passed the most recently added block will be used.
+    pub block_identifier: Option<BlockIdentifier>,
+    /// The validator whose pending unbonding and withdraw purses, including those of its
+    /// delegators, are requested.
+    pub account_identifier: AccountIdentifier,
+}
+
+impl DocExample for GetUnbondingQueueParams {
+    fn doc_example() -> &'static Self {
+        &GET_UNBONDING_QUEUE_PARAMS
+    }
+}
+
+/// Result for "state_get_unbonding_queue" RPC response.
+#[derive(PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetUnbondingQueueResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// Hash of the block against whose global state the unbonding queue was read.
+    pub block_hash: BlockHash,
+    /// The pending unbonding and withdraw purses of the validator and its delegators, ordered by
+    /// era of creation.
+    pub unbonding_queue: Vec<JsonUnbondingPurse>,
+}
+
+impl DocExample for GetUnbondingQueueResult {
+    fn doc_example() -> &'static Self {
+        &GET_UNBONDING_QUEUE_RESULT
+    }
+}
+
+/// "state_get_unbonding_queue" RPC.
+pub struct GetUnbondingQueue {}
+
+#[async_trait]
+impl RpcWithParams for GetUnbondingQueue {
+    const METHOD: &'static str = "state_get_unbonding_queue";
+    type RequestParams = GetUnbondingQueueParams;
+    type ResponseResult = GetUnbondingQueueResult;
+
+    async fn do_handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        api_version: ProtocolVersion,
+        params: Self::RequestParams,
+    ) -> Result<Self::ResponseResult, Error> {
+        // This RPC request is restricted by the block availability index.
+        let only_from_available_block_range = true;
+
+        let block = common::get_block(
+            params.block_identifier,
+            only_from_available_block_range,
+            effect_builder,
+        )
+        .await?;
+        let block_hash = *block.hash();
+        let state_root_hash = *block.state_root_hash();
+
+        let account_hash = match params.account_identifier {
+            AccountIdentifier::PublicKey(public_key) => public_key.to_account_hash(),
+            AccountIdentifier::AccountHash(account_hash) => account_hash,
+        };
+        let (unbonding_purses, unbonding_delay) =
+            get_unbonding_queue(effect_builder, state_root_hash, Some(account_hash)).await?;
+        let unbonding_queue = unbonding_purses
+            .into_iter()
+            .map(|unbonding_purse| JsonUnbondingPurse::new(unbonding_purse, unbonding_delay))
+            .collect();
+
+        Ok(Self::ResponseResult {
+            api_version,
+            block_hash,
+            unbonding_queue,
+        })
+    }
+}
+
+/// Returns the pending unbonding and withdraw purses of `validator` and its delegators, or of all
+/// validators if `None`, along with the current unbonding delay.
+async fn get_unbonding_queue<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+    state_root_hash: Digest,
+    validator: Option<AccountHash>,
+) -> Result<(Vec<UnbondingPurse>, u64), Error> {
+    let get_unbonding_queue_request = GetUnbondingQueueRequest::new(state_root_hash, validator);
+    match effect_builder
+        .get_unbonding_queue(get_unbonding_queue_request)
+        .await
+    {
+        Ok(engine_state::GetUnbondingQueueResult::Success {
+            unbonding_delay,
+            unbonding_purses,
+        }) => Ok((unbonding_purses, unbonding_delay)),
+        Ok(engine_state::GetUnbondingQueueResult::RootNotFound) => {
+            info!("get unbonding queue failed: root not found");
+            let error = common::missing_block_or_state_root_error(
+                effect_builder,
+                ErrorCode::NoSuchStateRoot,
+                format!("failed to get state root at {:?}", state_root_hash),
+            )
+            .await;
+            Err(error)
+        }
+        Err(error) => {
+            info!(?error, "get unbonding queue failed to execute");
+            Err(Error::new(
+                ErrorCode::GetUnbondingQueueFailedToExecute,
+                format!("{:?}", error),
+            ))
+        }
+    }
+}
+
 type QuerySuccess = (
     DomainStoredValue,
     Vec<TrieMerkleProof<Key, DomainStoredValue>>,
@@ -1,4 +1,5 @@
 use hyper::server::{conn::AddrIncoming, Builder};
+use tokio::sync::watch;
 
 use casper_json_rpc::{CorsOrigin, RequestHandlersBuilder};
 use casper_types::ProtocolVersion;
@@ -6,7 +7,7 @@ use casper_types::ProtocolVersion;
 use super::ReactorEventT;
 use crate::{
     effect::EffectBuilder,
-    rpcs::{speculative_exec::SpeculativeExec, RpcWithParams},
+    rpcs::{speculative_exec::SpeculativeExec, state::CallView, RpcWithParams},
 };
 
 /// The URL path for all JSON-RPC requests.
@@ -19,12 +20,13 @@ pub(super) async fn run<REv: ReactorEventT>(
     builder: Builder<AddrIncoming>,
     effect_builder: EffectBuilder<REv>,
     api_version: ProtocolVersion,
-    qps_limit: u64,
+    qps_limit: watch::Receiver<u64>,
     max_body_bytes: u32,
     cors_origin: String,
 ) {
     let mut handlers = RequestHandlersBuilder::new();
     SpeculativeExec::register_as_handler(effect_builder, api_version, &mut handlers);
+    CallView::register_as_handler(effect_builder, api_version, &mut handlers);
     let handlers = handlers.build();
 
     match cors_origin.as_str() {
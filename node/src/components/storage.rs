@@ -213,6 +213,9 @@ pub struct Storage {
     /// The number of eras relative to the highest block's era which are considered as recent for
     /// the purpose of deciding how to respond to a `NetRequest::SyncLeap`.
     recent_era_count: u64,
+    /// The protocol version from which individual BLS finality signatures are served aggregated
+    /// in responses to a `NetRequest::SyncLeap`.
+    start_protocol_version_with_aggregate_finality_signatures: ProtocolVersion,
     #[data_size(skip)]
     metrics: Option<Metrics>,
     /// The maximum TTL of a deploy.
@@ -363,6 +366,7 @@ impl Storage {
         network_name: &str,
         max_ttl: MaxTtl,
         recent_era_count: u64,
+        start_protocol_version_with_aggregate_finality_signatures: ProtocolVersion,
         registry: Option<&Registry>,
         force_resync: bool,
     ) -> Result<Self, FatalStorageError> {
@@ -517,6 +521,7 @@ impl Storage {
             enable_mem_deduplication: config.enable_mem_deduplication,
             serialized_item_pool: ObjectPool::new(config.mem_pool_prune_interval),
             recent_era_count,
+            start_protocol_version_with_aggregate_finality_signatures,
             max_ttl,
             metrics,
         };
@@ -601,6 +606,109 @@ impl Storage {
         Ok(component)
     }
 
+    /// Opens an existing storage read-only, e.g. to inspect the data of a node which is not
+    /// running.
+    ///
+    /// Unlike [`Storage::new`], no files or databases are created, moved or purged, and the set of
+    /// completed blocks is left empty. Any attempt to write to the returned storage fails.
+    pub(crate) fn open_read_only(
+        cfg: &WithDir<Config>,
+        activation_era: EraId,
+        network_name: &str,
+        max_ttl: MaxTtl,
+        recent_era_count: u64,
+        start_protocol_version_with_aggregate_finality_signatures: ProtocolVersion,
+    ) -> Result<Self, FatalStorageError> {
+        let config = cfg.value();
+        let root = cfg.with_dir(config.path.clone()).join(network_name);
+
+        let total_size = config
+            .max_block_store_size
+            .saturating_add(config.max_deploy_store_size)
+            .saturating_add(config.max_deploy_metadata_store_size);
+
+        let env = Environment::new()
+            .set_flags(
+                OS_FLAGS
+                    | EnvironmentFlags::NO_SUB_DIR
+                    | EnvironmentFlags::NO_TLS
+                    | EnvironmentFlags::NO_READAHEAD
+                    | EnvironmentFlags::READ_ONLY,
+            )
+            .set_max_readers(MAX_TRANSACTIONS)
+            .set_max_dbs(MAX_DB_COUNT)
+            .set_map_size(total_size)
+            .open(&root.join(STORAGE_DB_FILENAME))?;
+
+        let block_header_db = env.open_db(Some("block_header"))?;
+        let block_metadata_db = env.open_db(Some("block_metadata"))?;
+        let deploy_db = env.open_db(Some("deploys"))?;
+        let deploy_metadata_db = env.open_db(Some("deploy_metadata"))?;
+        let transfer_db = env.open_db(Some("transfer"))?;
+        let state_store_db = env.open_db(Some("state_store"))?;
+        let finalized_approvals_db = env.open_db(Some("finalized_approvals"))?;
+        let block_body_db = env.open_db(Some("block_body"))?;
+        let approvals_hashes_db = env.open_db(Some("approvals_hashes"))?;
+
+        info!("indexing block store");
+        let mut block_height_index = BTreeMap::new();
+        let mut switch_block_era_id_index = BTreeMap::new();
+        let mut deploy_hash_index = BTreeMap::new();
+        let txn = env.begin_ro_txn()?;
+        let mut cursor = txn.open_ro_cursor(block_header_db)?;
+        for row in cursor.iter() {
+            let (_, raw_val) = row?;
+            let block_header: BlockHeader = lmdb_ext::deserialize(raw_val)?;
+            insert_to_block_header_indices(
+                &mut block_height_index,
+                &mut switch_block_era_id_index,
+                &block_header,
+            )?;
+
+            let mut body_txn = env.begin_ro_txn()?;
+            if let Some(block_body) =
+                get_body_for_block_header(&mut body_txn, block_header.body_hash(), block_body_db)?
+            {
+                insert_to_deploy_index(
+                    &mut deploy_hash_index,
+                    block_header.block_hash(),
+                    &block_body,
+                    block_header.height(),
+                    block_header.era_id(),
+                )?;
+            }
+        }
+        info!("block store reindexing complete");
+        drop(cursor);
+        txn.commit()?;
+
+        Ok(Self {
+            root,
+            env: Rc::new(env),
+            block_header_db,
+            block_body_db,
+            block_metadata_db,
+            approvals_hashes_db,
+            deploy_db,
+            deploy_metadata_db,
+            transfer_db,
+            state_store_db,
+            finalized_approvals_db,
+            block_height_index,
+            switch_block_era_id_index,
+            deploy_hash_index,
+            completed_blocks: Default::default(),
+            activation_era,
+            key_block_height_for_activation_point: None,
+            enable_mem_deduplication: config.enable_mem_deduplication,
+            serialized_item_pool: ObjectPool::new(config.mem_pool_prune_interval),
+            recent_era_count,
+            start_protocol_version_with_aggregate_finality_signatures,
+            max_ttl,
+            metrics: None,
+        })
+    }
+
     /// Reads from the state storage database.
     ///
     /// If key is non-empty, returns bytes from under the key. Otherwise returns `Ok(None)`.
@@ -834,6 +942,12 @@ impl Storage {
             } => responder
                 .respond(self.get_deploys_era_ids(deploy_hashes))
                 .ignore(),
+            StorageRequest::GetExecutedDeploys {
+                deploy_hashes,
+                responder,
+            } => responder
+                .respond(self.get_executed_deploys(deploy_hashes)?)
+                .ignore(),
             StorageRequest::GetBlockHeader {
                 block_hash,
                 only_from_available_block_range,
@@ -1084,7 +1198,7 @@ impl Storage {
                 signatures,
                 responder,
             } => {
-                if signatures.proofs.is_empty() {
+                if signatures.is_empty() {
                     error!(
                         ?signatures,
                         "should not attempt to store empty collection of block signatures"
@@ -1100,6 +1214,17 @@ impl Storage {
                         for (public_key, sig) in signatures.proofs {
                             data.insert_proof(public_key, sig);
                         }
+                        let validator_weights = match data.era_id.predecessor() {
+                            Some(parent_era_id) => self
+                                .get_switch_block_header_by_era_id(&mut txn, parent_era_id)?
+                                .and_then(|header| header.maybe_take_next_era_validator_weights())
+                                .unwrap_or_default(),
+                            None => BTreeMap::new(),
+                        };
+                        data.merge_aggregated_proof(
+                            signatures.aggregated_proof,
+                            &validator_weights,
+                        );
                         data
                     }
                 };
@@ -1137,6 +1262,25 @@ impl Storage {
                     .read_block_header_by_height(block_height, only_from_available_block_range)?;
                 responder.respond(maybe_header).ignore()
             }
+            StorageRequest::GetSwitchBlockHeaderByEra {
+                era_id,
+                only_from_available_block_range,
+                responder,
+            } => {
+                let mut txn = self.env.begin_ro_txn()?;
+                let maybe_header = match self.get_switch_block_header_by_era_id(&mut txn, era_id)? {
+                    Some(header)
+                        if self.should_return_block(
+                            header.height(),
+                            only_from_available_block_range,
+                        )? =>
+                    {
+                        Some(header)
+                    }
+                    _ => None,
+                };
+                responder.respond(maybe_header).ignore()
+            }
             StorageRequest::PutBlockHeader {
                 block_header,
                 responder,
@@ -1797,6 +1941,26 @@ impl Storage {
             .collect()
     }
 
+    /// Returns those of the given deploys which have been executed in a stored block, along with
+    /// the timestamp of that block.
+    pub(crate) fn get_executed_deploys(
+        &self,
+        deploy_hashes: HashSet<DeployHash>,
+    ) -> Result<HashMap<DeployHash, Timestamp>, FatalStorageError> {
+        let mut txn = self.env.begin_ro_txn()?;
+        let mut executed_deploys = HashMap::new();
+        for deploy_hash in deploy_hashes {
+            let block_hash = match self.deploy_hash_index.get(&deploy_hash) {
+                Some(block_hash_height_and_era) => block_hash_height_and_era.block_hash,
+                None => continue,
+            };
+            if let Some(block_header) = self.get_single_block_header(&mut txn, &block_hash)? {
+                executed_deploys.insert(deploy_hash, block_header.timestamp());
+            }
+        }
+        Ok(executed_deploys)
+    }
+
     /// Retrieves the block hash and height for a deploy hash by looking it up in the index
     /// and returning it.
     fn get_block_hash_and_height_by_deploy_hash(
@@ -2418,11 +2582,23 @@ impl Storage {
 
         // The `highest_complete_block_header` and `trusted_block_header` are both within the
         // highest complete block range, thus so are all the switch blocks between them.
-        if let Some(signed_block_headers) = self.get_signed_block_headers(
+        if let Some(mut signed_block_headers) = self.get_signed_block_headers(
             &mut txn,
             &trusted_block_header,
             &highest_complete_block_header,
         )? {
+            for signed_block_header in signed_block_headers.iter_mut().filter(|header| {
+                header.block_header.protocol_version()
+                    >= self.start_protocol_version_with_aggregate_finality_signatures
+            }) {
+                if let Err(error) = signed_block_header.block_signatures.aggregate_bls_proofs() {
+                    warn!(
+                        %error,
+                        block_hash = %signed_block_header.block_header.block_hash(),
+                        "failed to aggregate finality signatures"
+                    );
+                }
+            }
             return Ok(FetchResponse::Fetched(SyncLeap {
                 trusted_ancestor_only: false,
                 trusted_block_header,
@@ -2576,12 +2752,13 @@ impl Storage {
         Ok(Some(execution_results))
     }
 
+    /// Retrieves the execution results of all deploys and transfers included in the given block.
     #[allow(clippy::type_complexity)]
-    fn read_execution_results(
+    pub(crate) fn read_execution_results(
         &self,
         block_hash: &BlockHash,
     ) -> Result<Option<Vec<(DeployHash, DeployHeader, ExecutionResult)>>, FatalStorageError> {
-        let mut txn = self.env.begin_rw_txn()?;
+        let mut txn = self.env.begin_ro_txn()?;
         let execution_results = match self.get_execution_results(&mut txn, block_hash)? {
             Some(execution_results) => execution_results,
             None => return Ok(None),
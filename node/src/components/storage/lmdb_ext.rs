@@ -10,7 +10,7 @@
 //! Serialization errors are unified into a generic, type erased `std` error to allow for easy
 //! interchange of the serialization format if desired.
 
-use std::any::TypeId;
+use std::any::{Any, TypeId};
 
 use lmdb::{Database, RwTransaction, Transaction, WriteFlags};
 use serde::{de::DeserializeOwned, Serialize};
@@ -21,7 +21,15 @@ use casper_types::{
     system::auction::UnbondingPurse,
 };
 
+use crate::types::{
+    BlockSignatures, Deploy, DeployMetadata, LegacyDeployMetadata, LegacyStoredDeploy,
+};
+
 const UNBONDING_PURSE_V2_MAGIC_BYTES: &[u8] = &[121, 17, 133, 179, 91, 63, 69, 222];
+const BLOCK_SIGNATURES_V2_MAGIC_BYTES: &[u8] = &[203, 58, 14, 97, 240, 162, 35, 119];
+const DEPLOY_METADATA_V2_MAGIC_BYTES: &[u8] = &[82, 231, 9, 166, 44, 190, 117, 3];
+pub(super) const DEPLOY_V2_MAGIC_BYTES: &[u8] = &[17, 204, 96, 241, 58, 133, 12, 79];
+const DEPLOY_V3_MAGIC_BYTES: &[u8] = &[148, 61, 220, 7, 173, 29, 250, 106];
 
 /// Error wrapper for lower-level storage errors.
 ///
@@ -182,8 +190,9 @@ where
 }
 
 /// Serializes `value` into the buffer.
-/// In case the `value` is of the `UnbondingPurse` type it uses the specialized
-/// function to provide compatibility with the legacy version of the `UnbondingPurse` struct.
+/// In case the `value` is of the `UnbondingPurse`, `BlockSignatures`, `DeployMetadata` or `Deploy`
+/// type it uses the specialized function to provide compatibility with the legacy version of the
+/// struct.
 /// See [`serialize_unbonding_purse`] for more details.
 // TODO: Get rid of the 'static bound.
 pub(crate) fn serialize_internal<V: 'static + Serialize>(
@@ -191,6 +200,12 @@ pub(crate) fn serialize_internal<V: 'static + Serialize>(
 ) -> Result<Vec<u8>, LmdbExtError> {
     let buffer = if TypeId::of::<UnbondingPurse>() == TypeId::of::<V>() {
         serialize_unbonding_purse(value)?
+    } else if TypeId::of::<BlockSignatures>() == TypeId::of::<V>() {
+        serialize_block_signatures(value)?
+    } else if TypeId::of::<DeployMetadata>() == TypeId::of::<V>() {
+        serialize_deploy_metadata(value)?
+    } else if TypeId::of::<Deploy>() == TypeId::of::<V>() {
+        serialize_deploy(value)?
     } else {
         serialize(value)?
     };
@@ -198,14 +213,21 @@ pub(crate) fn serialize_internal<V: 'static + Serialize>(
 }
 
 /// Deserializes an object from the raw bytes.
-/// In case the expected object is of the `UnbondingPurse` type it uses the specialized
-/// function to provide compatibility with the legacy version of the `UnbondingPurse` struct.
+/// In case the expected object is of the `UnbondingPurse`, `BlockSignatures`, `DeployMetadata` or
+/// `Deploy` type it uses the specialized function to provide compatibility with the legacy version
+/// of the struct.
 /// See [`deserialize_unbonding_purse`] for more details.
 pub(crate) fn deserialize_internal<V: 'static + DeserializeOwned>(
     raw: &[u8],
 ) -> Result<Option<V>, LmdbExtError> {
     if TypeId::of::<UnbondingPurse>() == TypeId::of::<V>() {
         deserialize_unbonding_purse(raw).map(Some)
+    } else if TypeId::of::<BlockSignatures>() == TypeId::of::<V>() {
+        deserialize_block_signatures(raw).map(Some)
+    } else if TypeId::of::<DeployMetadata>() == TypeId::of::<V>() {
+        deserialize_deploy_metadata(raw).map(Some)
+    } else if TypeId::of::<Deploy>() == TypeId::of::<V>() {
+        deserialize_deploy(raw).map(Some)
     } else {
         deserialize(raw).map(Some)
     }
@@ -265,9 +287,10 @@ pub(super) fn deserialize<T: DeserializeOwned>(raw: &[u8]) -> Result<T, LmdbExtE
     bincode::deserialize(raw).map_err(|err| LmdbExtError::DataCorrupted(Box::new(err)))
 }
 
-/// Returns `true` if the specified bytes represent the legacy version of `UnbondingPurse`.
-fn is_legacy(raw: &[u8]) -> bool {
-    !raw.starts_with(UNBONDING_PURSE_V2_MAGIC_BYTES)
+/// Returns `true` if the specified bytes represent the legacy version of a struct whose current
+/// version is prefixed with the given magic bytes.
+fn is_legacy(raw: &[u8], magic_bytes: &[u8]) -> bool {
+    !raw.starts_with(magic_bytes)
 }
 
 /// Deserializes `UnbondingPurse` from a buffer.
@@ -282,13 +305,70 @@ pub(super) fn deserialize_unbonding_purse<T: DeserializeOwned>(
     raw: &[u8],
 ) -> Result<T, LmdbExtError> {
     const BINCODE_ENCODED_NONE: [u8; 4] = [0; 4];
-    if is_legacy(raw) {
+    if is_legacy(raw, UNBONDING_PURSE_V2_MAGIC_BYTES) {
         deserialize(&[raw, &BINCODE_ENCODED_NONE].concat())
     } else {
         deserialize(&raw[UNBONDING_PURSE_V2_MAGIC_BYTES.len()..])
     }
 }
 
+/// Deserializes `BlockSignatures` from a buffer.
+/// Works the same way as [`deserialize_unbonding_purse`], with the appended `None` simulating the
+/// existence of the `aggregated_proof` field added to the `BlockSignatures` struct.
+pub(super) fn deserialize_block_signatures<T: DeserializeOwned>(
+    raw: &[u8],
+) -> Result<T, LmdbExtError> {
+    const BINCODE_ENCODED_NONE: [u8; 1] = [0];
+    if is_legacy(raw, BLOCK_SIGNATURES_V2_MAGIC_BYTES) {
+        deserialize(&[raw, &BINCODE_ENCODED_NONE].concat())
+    } else {
+        deserialize(&raw[BLOCK_SIGNATURES_V2_MAGIC_BYTES.len()..])
+    }
+}
+
+/// Deserializes `DeployMetadata` from a buffer.
+/// Legacy entries are detected the same way as in [`deserialize_unbonding_purse`]. As the execution
+/// results gained the `fee_breakdown` field inside a map, legacy bytes can't be patched up, so they
+/// are deserialized as `LegacyDeployMetadata` and converted instead.
+pub(super) fn deserialize_deploy_metadata<T: 'static + DeserializeOwned>(
+    raw: &[u8],
+) -> Result<T, LmdbExtError> {
+    if !is_legacy(raw, DEPLOY_METADATA_V2_MAGIC_BYTES) {
+        return deserialize(&raw[DEPLOY_METADATA_V2_MAGIC_BYTES.len()..]);
+    }
+    let legacy: LegacyDeployMetadata = deserialize(raw)?;
+    let metadata: Box<dyn Any> = Box::new(DeployMetadata::from(legacy));
+    metadata
+        .downcast()
+        .map(|metadata| *metadata)
+        .map_err(|_| LmdbExtError::Other("expected to deserialize deploy metadata".into()))
+}
+
+/// Deserializes `Deploy` from a buffer.
+/// Entries prefixed with the V3 magic bytes are deserialized directly. Older entries predate the
+/// `sequence_number` field of the deploy header, so they are deserialized as `LegacyStoredDeploy`
+/// and converted, in the same way as [`deserialize_deploy_metadata`]. Of those, entries without the
+/// V2 magic bytes additionally get the `None` appended simulating the existence of the `fee_payer`
+/// field, as in [`deserialize_unbonding_purse`].
+pub(super) fn deserialize_deploy<T: 'static + DeserializeOwned>(
+    raw: &[u8],
+) -> Result<T, LmdbExtError> {
+    const BINCODE_ENCODED_NONE: [u8; 1] = [0];
+    if !is_legacy(raw, DEPLOY_V3_MAGIC_BYTES) {
+        return deserialize(&raw[DEPLOY_V3_MAGIC_BYTES.len()..]);
+    }
+    let legacy: LegacyStoredDeploy = if is_legacy(raw, DEPLOY_V2_MAGIC_BYTES) {
+        deserialize(&[raw, &BINCODE_ENCODED_NONE].concat())?
+    } else {
+        deserialize(&raw[DEPLOY_V2_MAGIC_BYTES.len()..])?
+    };
+    let deploy: Box<dyn Any> = Box::new(Deploy::from(legacy));
+    deploy
+        .downcast()
+        .map(|deploy| *deploy)
+        .map_err(|_| LmdbExtError::Other("expected to deserialize deploy".into()))
+}
+
 /// Serializes into a buffer.
 #[inline(always)]
 pub(super) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, LmdbExtError> {
@@ -306,6 +386,33 @@ pub(super) fn serialize_unbonding_purse<T: Serialize>(value: &T) -> Result<Vec<u
     Ok(serialized)
 }
 
+/// Serializes `BlockSignatures` into a buffer, prefixed with magic bytes in the same way as
+/// [`serialize_unbonding_purse`].
+#[inline(always)]
+pub(super) fn serialize_block_signatures<T: Serialize>(value: &T) -> Result<Vec<u8>, LmdbExtError> {
+    let mut serialized = BLOCK_SIGNATURES_V2_MAGIC_BYTES.to_vec();
+    serialized.extend(bincode::serialize(value).map_err(|err| LmdbExtError::Other(Box::new(err)))?);
+    Ok(serialized)
+}
+
+/// Serializes `DeployMetadata` into a buffer, prefixed with magic bytes in the same way as
+/// [`serialize_unbonding_purse`].
+#[inline(always)]
+pub(super) fn serialize_deploy_metadata<T: Serialize>(value: &T) -> Result<Vec<u8>, LmdbExtError> {
+    let mut serialized = DEPLOY_METADATA_V2_MAGIC_BYTES.to_vec();
+    serialized.extend(bincode::serialize(value).map_err(|err| LmdbExtError::Other(Box::new(err)))?);
+    Ok(serialized)
+}
+
+/// Serializes `Deploy` into a buffer, prefixed with magic bytes in the same way as
+/// [`serialize_unbonding_purse`].
+#[inline(always)]
+pub(super) fn serialize_deploy<T: Serialize>(value: &T) -> Result<Vec<u8>, LmdbExtError> {
+    let mut serialized = DEPLOY_V3_MAGIC_BYTES.to_vec();
+    serialized.extend(bincode::serialize(value).map_err(|err| LmdbExtError::Other(Box::new(err)))?);
+    Ok(serialized)
+}
+
 /// Deserializes from a buffer.
 #[inline(always)]
 pub(super) fn deserialize_bytesrepr<T: FromBytes>(raw: &[u8]) -> Result<T, LmdbExtError> {
@@ -13,15 +13,20 @@ use rand::{prelude::SliceRandom, Rng};
 use serde::{Deserialize, Serialize};
 use smallvec::smallvec;
 
+use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_hashing::Digest;
 use casper_types::{
     generate_ed25519_keypair, system::auction::UnbondingPurse, testing::TestRng, AccessRights,
-    EraId, ExecutionEffect, ExecutionResult, Key, ProtocolVersion, PublicKey, SecretKey, TimeDiff,
-    Transfer, Transform, TransformEntry, URef, U512,
+    EraId, ExecutionEffect, ExecutionResult, FeeBreakdown, Key, ProtocolVersion, PublicKey,
+    SecretKey, TimeDiff, Timestamp, Transfer, TransferAddr, Transform, TransformEntry, URef, U512,
 };
 
 use super::{
     initialize_block_metadata_db,
-    lmdb_ext::{deserialize_internal, serialize_internal, TransactionExt, WriteTransactionExt},
+    lmdb_ext::{
+        deserialize_internal, serialize_internal, TransactionExt, WriteTransactionExt,
+        DEPLOY_V2_MAGIC_BYTES,
+    },
     move_storage_files_to_network_subdir, should_move_storage_files_to_network_subdir, Config,
     Storage, FORCE_RESYNC_FILE_NAME,
 };
@@ -33,11 +38,11 @@ use crate::{
     },
     testing::{ComponentHarness, UnitTestEvent},
     types::{
-        sync_leap_validation_metadata::SyncLeapValidationMetaData, AvailableBlockRange, Block,
-        BlockHash, BlockHashAndHeight, BlockHashHeightAndEra, BlockHeader, BlockHeaderWithMetadata,
-        BlockSignatures, Chainspec, ChainspecRawBytes, Deploy, DeployHash, DeployMetadata,
-        DeployMetadataExt, DeployWithFinalizedApprovals, FinalitySignature, LegacyDeploy,
-        SyncLeapIdentifier, TestBlockBuilder,
+        sync_leap_validation_metadata::SyncLeapValidationMetaData, Approval, AvailableBlockRange,
+        Block, BlockHash, BlockHashAndHeight, BlockHashHeightAndEra, BlockHeader,
+        BlockHeaderWithMetadata, BlockSignatures, Chainspec, ChainspecRawBytes, Deploy, DeployHash,
+        DeployMetadata, DeployMetadataExt, DeployWithFinalizedApprovals, FinalitySignature,
+        LegacyDeploy, SyncLeapIdentifier, TestBlockBuilder,
     },
     utils::{Loadable, WithDir},
 };
@@ -155,6 +160,7 @@ fn create_sync_leap_test_chain(
             block_hash: *block.hash(),
             era_id: block.header().era_id(),
             proofs,
+            aggregated_proof: None,
         };
 
         if !non_signed_blocks.contains(&block.height()) {
@@ -184,6 +190,7 @@ fn storage_fixture(harness: &ComponentHarness<UnitTestEvent>) -> Storage {
         "test",
         MAX_TTL.into(),
         RECENT_ERA_COUNT,
+        ProtocolVersion::V1_0_0,
         None,
         false,
     )
@@ -214,6 +221,7 @@ fn storage_fixture_from_parts(
         network_name.unwrap_or("test"),
         max_ttl.unwrap_or(MAX_TTL).into(),
         recent_era_count.unwrap_or(RECENT_ERA_COUNT),
+        ProtocolVersion::V1_0_0,
         None,
         false,
     )
@@ -236,6 +244,7 @@ fn storage_fixture_with_force_resync(cfg: &WithDir<Config>) -> Storage {
         "test",
         MAX_TTL.into(),
         RECENT_ERA_COUNT,
+        ProtocolVersion::V1_0_0,
         None,
         true,
     )
@@ -1199,6 +1208,7 @@ fn prepare_exec_result_with_transfer(
         effect,
         transfers: vec![],
         cost: rng.gen(),
+        fee_breakdown: None,
     };
     (exec_result, transfer)
 }
@@ -1560,6 +1570,7 @@ fn should_create_subdir_named_after_network() {
         network_name,
         MAX_TTL.into(),
         RECENT_ERA_COUNT,
+        ProtocolVersion::V1_0_0,
         None,
         false,
     )
@@ -2117,6 +2128,237 @@ fn unbonding_purse_serialization_roundtrip() {
     assert!(deserialized.new_validator().is_some())
 }
 
+#[test]
+fn should_read_legacy_block_signatures() {
+    // Mirrors the `BlockSignatures` struct before the `aggregated_proof` field was added.
+    #[derive(Serialize)]
+    struct LegacyBlockSignatures {
+        block_hash: BlockHash,
+        era_id: EraId,
+        proofs: BTreeMap<PublicKey, casper_types::Signature>,
+    }
+
+    let mut rng = TestRng::new();
+    let block = Block::random(&mut rng);
+    let fs = FinalitySignature::random_for_block(*block.hash(), block.header().era_id().value());
+    let legacy = LegacyBlockSignatures {
+        block_hash: *block.hash(),
+        era_id: block.header().era_id(),
+        proofs: iter::once((fs.public_key.clone(), fs.signature)).collect(),
+    };
+
+    let legacy_bytes = bincode::serialize(&legacy).expect("serialization");
+    let deserialized: BlockSignatures = deserialize_internal(&legacy_bytes)
+        .expect("should deserialize w/o error")
+        .expect("should be Some");
+
+    assert_eq!(deserialized.block_hash, legacy.block_hash);
+    assert_eq!(deserialized.era_id, legacy.era_id);
+    assert_eq!(deserialized.proofs, legacy.proofs);
+    // Make sure the new field is set to default.
+    assert!(deserialized.aggregated_proof.is_none());
+
+    let serialized = serialize_internal(&deserialized).expect("serialization");
+    let roundtripped: BlockSignatures = deserialize_internal(&serialized)
+        .expect("should deserialize w/o error")
+        .expect("should be Some");
+    assert_eq!(roundtripped, deserialized);
+}
+
+#[test]
+fn should_read_legacy_deploy_metadata() {
+    // Mirrors the `ExecutionResult` enum before the `fee_breakdown` field was added.
+    #[derive(Serialize)]
+    enum LegacyExecutionResult {
+        #[allow(dead_code)]
+        Failure {
+            effect: ExecutionEffect,
+            transfers: Vec<TransferAddr>,
+            cost: U512,
+            error_message: String,
+        },
+        Success {
+            effect: ExecutionEffect,
+            transfers: Vec<TransferAddr>,
+            cost: U512,
+        },
+    }
+
+    // Mirrors the `DeployMetadata` struct holding legacy execution results.
+    #[derive(Serialize)]
+    struct LegacyDeployMetadata {
+        execution_results: HashMap<BlockHash, LegacyExecutionResult>,
+    }
+
+    let mut rng = TestRng::new();
+    let deploy = Deploy::random(&mut rng);
+    let block_hash = BlockHash::random(&mut rng);
+    let (execution_result, _) = prepare_exec_result_with_transfer(&mut rng, deploy.hash());
+    let (effect, transfers, cost) = match execution_result {
+        ExecutionResult::Success {
+            effect,
+            transfers,
+            cost,
+            ..
+        } => (effect, transfers, cost),
+        ExecutionResult::Failure { .. } => unreachable!("should be a success"),
+    };
+    let legacy = LegacyDeployMetadata {
+        execution_results: iter::once((
+            block_hash,
+            LegacyExecutionResult::Success {
+                effect: effect.clone(),
+                transfers: transfers.clone(),
+                cost,
+            },
+        ))
+        .collect(),
+    };
+
+    let legacy_bytes = bincode::serialize(&legacy).expect("serialization");
+    let deserialized: DeployMetadata = deserialize_internal(&legacy_bytes)
+        .expect("should deserialize w/o error")
+        .expect("should be Some");
+
+    // Make sure the new field is set to default.
+    let expected = ExecutionResult::Success {
+        effect,
+        transfers,
+        cost,
+        fee_breakdown: None,
+    };
+    assert_eq!(
+        deserialized.execution_results,
+        iter::once((block_hash, expected)).collect::<HashMap<_, _>>()
+    );
+
+    let mut metadata = deserialized;
+    if let Some(ExecutionResult::Success { fee_breakdown, .. }) =
+        metadata.execution_results.get_mut(&block_hash)
+    {
+        *fee_breakdown = Some(FeeBreakdown {
+            paid_to_proposer: U512::from(3),
+            burned: U512::from(7),
+        });
+    }
+    let serialized = serialize_internal(&metadata).expect("serialization");
+    let roundtripped: DeployMetadata = deserialize_internal(&serialized)
+        .expect("should deserialize w/o error")
+        .expect("should be Some");
+    assert_eq!(roundtripped, metadata);
+}
+
+/// Serializes the given deploy as it was stored before the `sequence_number` field was added to
+/// the `DeployHeader`.
+fn serialize_unsequenced_deploy(deploy: &Deploy) -> Vec<u8> {
+    // Mirrors the `DeployHeader` struct before the `sequence_number` field was added.
+    #[derive(Serialize)]
+    struct LegacyDeployHeader<'a> {
+        account: &'a PublicKey,
+        timestamp: Timestamp,
+        ttl: TimeDiff,
+        gas_price: u64,
+        body_hash: &'a Digest,
+        dependencies: &'a Vec<DeployHash>,
+        chain_name: &'a str,
+    }
+
+    // Mirrors the `Deploy` struct holding a legacy header.
+    #[derive(Serialize)]
+    struct LegacyDeploy<'a> {
+        hash: &'a DeployHash,
+        header: LegacyDeployHeader<'a>,
+        payment: &'a ExecutableDeployItem,
+        session: &'a ExecutableDeployItem,
+        approvals: &'a BTreeSet<Approval>,
+        fee_payer: Option<&'a PublicKey>,
+    }
+
+    assert!(deploy.header().sequence_number().is_none());
+    let header = deploy.header();
+    let legacy = LegacyDeploy {
+        hash: deploy.hash(),
+        header: LegacyDeployHeader {
+            account: header.account(),
+            timestamp: header.timestamp(),
+            ttl: header.ttl(),
+            gas_price: header.gas_price(),
+            body_hash: header.body_hash(),
+            dependencies: header.dependencies(),
+            chain_name: header.chain_name(),
+        },
+        payment: deploy.payment(),
+        session: deploy.session(),
+        approvals: deploy.approvals(),
+        fee_payer: deploy.fee_payer(),
+    };
+    bincode::serialize(&legacy).expect("serialization")
+}
+
+#[test]
+fn should_read_legacy_deploy() {
+    let mut rng = TestRng::new();
+    let deploy = Deploy::random(&mut rng);
+    assert!(deploy.fee_payer().is_none());
+
+    // The legacy `Deploy` had no `fee_payer` field, which is serialized last as a single-byte
+    // `None`.
+    let bytes = serialize_unsequenced_deploy(&deploy);
+    let legacy_bytes = &bytes[..bytes.len() - 1];
+    let deserialized: Deploy = deserialize_internal(legacy_bytes)
+        .expect("should deserialize w/o error")
+        .expect("should be Some");
+    assert_eq!(deserialized, deploy);
+    deserialized.is_valid().expect("should be valid");
+
+    let serialized = serialize_internal(&deserialized).expect("serialization");
+    let roundtripped: Deploy = deserialize_internal(&serialized)
+        .expect("should deserialize w/o error")
+        .expect("should be Some");
+    assert_eq!(roundtripped, deploy);
+}
+
+#[test]
+fn should_read_unsequenced_deploy() {
+    let mut rng = TestRng::new();
+    let fee_payer_secret_key = SecretKey::random(&mut rng);
+    let deploy = Deploy::random_sponsored(&mut rng, &fee_payer_secret_key);
+
+    let legacy_bytes = [
+        DEPLOY_V2_MAGIC_BYTES,
+        &serialize_unsequenced_deploy(&deploy),
+    ]
+    .concat();
+    let deserialized: Deploy = deserialize_internal(&legacy_bytes)
+        .expect("should deserialize w/o error")
+        .expect("should be Some");
+    assert_eq!(deserialized, deploy);
+    deserialized.is_valid().expect("should be valid");
+
+    let serialized = serialize_internal(&deserialized).expect("serialization");
+    let roundtripped: Deploy = deserialize_internal(&serialized)
+        .expect("should deserialize w/o error")
+        .expect("should be Some");
+    assert_eq!(roundtripped, deploy);
+}
+
+#[test]
+fn should_roundtrip_sequenced_deploy() {
+    let mut rng = TestRng::new();
+    let sequence_number = rng.gen();
+    let deploy = Deploy::random_sequenced(&mut rng, sequence_number);
+
+    let serialized = serialize_internal(&deploy).expect("serialization");
+    let roundtripped: Deploy = deserialize_internal(&serialized)
+        .expect("should deserialize w/o error")
+        .expect("should be Some");
+    assert_eq!(roundtripped, deploy);
+    assert_eq!(
+        roundtripped.header().sequence_number(),
+        deploy.header().sequence_number()
+    );
+}
+
 // Clippy complains because there's a `OnceCell` in `FinalitySignature`, hence it should not be used
 // as a key in `BTreeSet`. However, we don't change the content of the cell during the course of the
 // test so there's no risk the hash or order of keys will change.
@@ -76,7 +76,7 @@ pub(crate) enum Error {
 
     /// Error loading the secret key.
     #[error("error loading secret key: {0}")]
-    LoadSecretKey(LoadError<crypto::ErrorExt>),
+    LoadSecretKey(LoadError<anyhow::Error>),
 
     /// Error loading the chainspec.
     #[error("error loading chainspec: {0}")]
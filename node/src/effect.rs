@@ -116,28 +116,33 @@ use tracing::{debug, error, warn};
 
 use casper_execution_engine::{
     core::engine_state::{
-        self, era_validators::GetEraValidatorsError, BalanceRequest, BalanceResult, GetBidsRequest,
-        GetBidsResult, QueryRequest, QueryResult,
+        self, era_validators::GetEraValidatorsError, BalanceRequest, BalanceResult, CallViewResult,
+        GetBidsRequest, GetBidsResult, GetDelegatorsRequest, GetDelegatorsResult,
+        GetUnbondingQueueRequest, GetUnbondingQueueResult, QueryRequest, QueryResult,
     },
-    shared::execution_journal::ExecutionJournal,
+    shared::{execution_journal::ExecutionJournal, gas_profile::GasProfiler},
     storage::trie::TrieRaw,
 };
 use casper_hashing::Digest;
 use casper_types::{
-    account::Account, bytesrepr::Bytes, system::auction::EraValidators, Contract, ContractPackage,
-    EraId, ExecutionEffect, ExecutionResult, Key, PublicKey, TimeDiff, Timestamp, Transfer, URef,
-    U512,
+    account::{Account, AccountHash},
+    bytesrepr::Bytes,
+    system::auction::EraValidators,
+    Contract, ContractHash, ContractPackage, EraId, ExecutionEffect, ExecutionResult, Key,
+    PublicKey, RuntimeArgs, TimeDiff, Timestamp, Transfer, URef, U512,
 };
 
 use crate::{
     components::{
+        block_accumulator::BlockAccumulatorDump,
         block_synchronizer::{
-            BlockSynchronizerStatus, GlobalStateSynchronizerError, GlobalStateSynchronizerResponse,
-            TrieAccumulatorError, TrieAccumulatorResponse,
+            BlockSynchronizerDump, BlockSynchronizerStatus, GlobalStateSynchronizerError,
+            GlobalStateSynchronizerResponse, TrieAccumulatorError, TrieAccumulatorResponse,
         },
         consensus::{ClContext, EraDump, ProposedBlock, ValidatorChange},
         contract_runtime::{ContractRuntimeError, EraValidatorsRequest},
-        deploy_acceptor,
+        deploy_acceptor::{self, DeployRejection},
+        deploy_buffer::{DeployBufferDump, DeployBufferStatus},
         diagnostics_port::StopAtSpec,
         fetcher::{FetchItem, FetchResult},
         gossiper::GossipItem,
@@ -146,31 +151,36 @@ use crate::{
     },
     contract_runtime::SpeculativeExecutionState,
     failpoints::FailpointActivation,
-    reactor::{main_reactor::ReactorState, EventQueueHandle, QueueKind},
+    reactor::{
+        main_reactor::ReactorState, ConfigReloadError, ConfigReloadReport, EventQueueHandle,
+        QueueKind,
+    },
     types::{
         appendable_block::AppendableBlock, ApprovalsHashes, AvailableBlockRange, Block,
         BlockExecutionResultsOrChunk, BlockExecutionResultsOrChunkId, BlockHash, BlockHeader,
-        BlockSignatures, BlockWithMetadata, ChainspecRawBytes, Deploy, DeployHash, DeployHeader,
-        DeployId, DeployMetadataExt, DeployWithFinalizedApprovals, FinalitySignature,
-        FinalitySignatureId, FinalizedApprovals, FinalizedBlock, LegacyDeploy, MetaBlock,
-        MetaBlockState, NodeId, TrieOrChunk, TrieOrChunkId,
+        BlockSignatures, BlockWithMetadata, ChainspecRawBytes, Deploy, DeployCancellation,
+        DeployHash, DeployHeader, DeployId, DeployMetadataExt, DeployWithFinalizedApprovals,
+        FinalitySignature, FinalitySignatureId, FinalizedApprovals, FinalizedBlock, LegacyDeploy,
+        MetaBlock, MetaBlockState, NodeId, TrieOrChunk, TrieOrChunkId,
     },
     utils::{fmt_limit::FmtLimit, SharedFlag, Source},
 };
 use announcements::{
     BlockAccumulatorAnnouncement, ConsensusAnnouncement, ContractRuntimeAnnouncement,
-    ControlAnnouncement, DeployAcceptorAnnouncement, DeployBufferAnnouncement, FatalAnnouncement,
-    FetchedNewBlockAnnouncement, FetchedNewFinalitySignatureAnnouncement, GossiperAnnouncement,
-    MetaBlockAnnouncement, PeerBehaviorAnnouncement, QueueDumpFormat, UnexecutedBlockAnnouncement,
+    ControlAnnouncement, DeployAcceptorAnnouncement, DeployBufferAnnouncement,
+    DeployCancellationAnnouncement, FatalAnnouncement, FetchedNewBlockAnnouncement,
+    FetchedNewFinalitySignatureAnnouncement, GossiperAnnouncement, MetaBlockAnnouncement,
+    PeerBehaviorAnnouncement, QueueDumpFormat, UnexecutedBlockAnnouncement,
     UpgradeWatcherAnnouncement,
 };
 use diagnostics_port::DumpConsensusStateRequest;
 use requests::{
     AcceptDeployRequest, BeginGossipRequest, BlockAccumulatorRequest, BlockSynchronizerRequest,
     BlockValidationRequest, ChainspecRawBytesRequest, ConsensusRequest, ContractRuntimeRequest,
-    DeployBufferRequest, FetcherRequest, MakeBlockExecutableRequest, MarkBlockCompletedRequest,
-    MetricsRequest, NetworkInfoRequest, NetworkRequest, ReactorStatusRequest, SetNodeStopRequest,
-    StorageRequest, SyncGlobalStateRequest, TrieAccumulatorRequest, UpgradeWatcherRequest,
+    DeployAcceptorRequest, DeployBufferRequest, FetcherRequest, MakeBlockExecutableRequest,
+    MarkBlockCompletedRequest, MetricsRequest, NetworkInfoRequest, NetworkRequest,
+    ReactorStatusRequest, SetNodeStopRequest, StorageRequest, SyncGlobalStateRequest,
+    TrieAccumulatorRequest, UpgradeWatcherRequest,
 };
 
 /// A resource that will never be available, thus trying to acquire it will wait forever.
@@ -809,6 +819,25 @@ impl<REv> EffectBuilder<REv> {
             .await;
     }
 
+    /// Announces that a deploy has been cancelled.
+    pub(crate) async fn announce_deploy_cancelled(
+        self,
+        deploy_hash: DeployHash,
+        replacement: Option<DeployHash>,
+    ) where
+        REv: From<DeployBufferAnnouncement>,
+    {
+        self.event_queue
+            .schedule(
+                DeployBufferAnnouncement::DeployCancelled {
+                    deploy_hash,
+                    replacement,
+                },
+                QueueKind::Validation,
+            )
+            .await;
+    }
+
     /// Announces an incoming network message.
     pub(crate) async fn announce_incoming<P>(self, sender: NodeId, payload: P)
     where
@@ -933,6 +962,43 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Passes a deploy cancellation submitted by a client to the deploy acceptor for validation.
+    pub(crate) async fn try_accept_deploy_cancellation(
+        self,
+        cancellation: DeployCancellation,
+    ) -> Result<(), deploy_acceptor::Error>
+    where
+        REv: From<DeployAcceptorRequest>,
+    {
+        self.make_request(
+            |responder| DeployAcceptorRequest::AcceptCancellation {
+                cancellation: Box::new(cancellation),
+                responder,
+            },
+            QueueKind::Api,
+        )
+        .await
+    }
+
+    /// Returns the record of the given deploy's rejection by the deploy acceptor, if it was
+    /// rejected recently.
+    pub(crate) async fn get_deploy_rejection(
+        self,
+        deploy_hash: DeployHash,
+    ) -> Option<DeployRejection>
+    where
+        REv: From<DeployAcceptorRequest>,
+    {
+        self.make_request(
+            |responder| DeployAcceptorRequest::GetRejection {
+                deploy_hash,
+                responder,
+            },
+            QueueKind::Api,
+        )
+        .await
+    }
+
     /// Announces that a deploy not previously stored has now been accepted and stored.
     pub(crate) fn announce_new_deploy_accepted(
         self,
@@ -977,6 +1043,20 @@ impl<REv> EffectBuilder<REv> {
             .await;
     }
 
+    /// Announces that a valid deploy cancellation has been received.
+    pub(crate) fn announce_deploy_cancellation_accepted(
+        self,
+        cancellation: Box<DeployCancellation>,
+    ) -> impl Future<Output = ()>
+    where
+        REv: From<DeployCancellationAnnouncement>,
+    {
+        self.event_queue.schedule(
+            DeployCancellationAnnouncement(cancellation),
+            QueueKind::Validation,
+        )
+    }
+
     /// Announces that an invalid deploy has been received.
     pub(crate) fn announce_invalid_deploy(
         self,
@@ -1024,6 +1104,25 @@ impl<REv> EffectBuilder<REv> {
             .await
     }
 
+    /// Announces that scheduled invocations were executed as part of a block.
+    pub(crate) async fn announce_scheduled_invocations_processed(
+        self,
+        block_hash: BlockHash,
+        execution_results: Vec<(Key, ExecutionResult)>,
+    ) where
+        REv: From<ContractRuntimeAnnouncement>,
+    {
+        self.event_queue
+            .schedule(
+                ContractRuntimeAnnouncement::ScheduledInvocationsProcessed {
+                    block_hash,
+                    execution_results,
+                },
+                QueueKind::ContractRuntime,
+            )
+            .await
+    }
+
     /// Announces validators for upcoming era.
     pub(crate) async fn announce_upcoming_era_validators(
         self,
@@ -1199,6 +1298,26 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Gets the header of the switch block of the given era from storage.
+    pub(crate) async fn get_switch_block_header_at_era_id_from_storage(
+        self,
+        era_id: EraId,
+        only_from_available_block_range: bool,
+    ) -> Option<BlockHeader>
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::GetSwitchBlockHeaderByEra {
+                era_id,
+                only_from_available_block_range,
+                responder,
+            },
+            QueueKind::FromStorage,
+        )
+        .await
+    }
+
     /// Gets the requested signature for a given block hash.
     pub(crate) async fn get_signature_from_storage(
         self,
@@ -1253,7 +1372,7 @@ impl<REv> EffectBuilder<REv> {
 
     /// Puts the requested block signatures into storage.
     ///
-    /// If `signatures.proofs` is empty, no attempt to store will be made, an error will be logged,
+    /// If `signatures` is empty, no attempt to store will be made, an error will be logged,
     /// and this function will return `false`.
     pub(crate) async fn put_signatures_to_storage(self, signatures: BlockSignatures) -> bool
     where
@@ -1323,6 +1442,25 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Returns those of the given deploys which have been executed in a stored block, along with
+    /// the timestamp of that block.
+    pub(crate) async fn get_executed_deploys(
+        self,
+        deploy_hashes: HashSet<DeployHash>,
+    ) -> HashMap<DeployHash, Timestamp>
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::GetExecutedDeploys {
+                deploy_hashes,
+                responder,
+            },
+            QueueKind::FromStorage,
+        )
+        .await
+    }
+
     /// Requests the highest complete block.
     pub(crate) async fn get_highest_complete_block_from_storage(self) -> Option<Block>
     where
@@ -1719,6 +1857,24 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Returns the status of the given deploy in the deploy buffer, if it is buffered.
+    pub(crate) async fn get_deploy_buffer_status(
+        self,
+        deploy_hash: DeployHash,
+    ) -> Option<DeployBufferStatus>
+    where
+        REv: From<DeployBufferRequest>,
+    {
+        self.make_request(
+            |responder| DeployBufferRequest::GetDeployStatus {
+                deploy_hash,
+                responder,
+            },
+            QueueKind::Api,
+        )
+        .await
+    }
+
     /// Enqueues a finalized block execution.
     pub(crate) async fn enqueue_block_for_execution(
         self,
@@ -2024,6 +2180,42 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Requests a page of the delegators of a validator from the Contract Runtime component.
+    pub(crate) async fn get_delegators(
+        self,
+        get_delegators_request: GetDelegatorsRequest,
+    ) -> Result<GetDelegatorsResult, engine_state::Error>
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        self.make_request(
+            |responder| ContractRuntimeRequest::GetDelegators {
+                get_delegators_request,
+                responder,
+            },
+            QueueKind::ContractRuntime,
+        )
+        .await
+    }
+
+    /// Requests the pending unbonding and withdraw purses from the Contract Runtime component.
+    pub(crate) async fn get_unbonding_queue(
+        self,
+        get_unbonding_queue_request: GetUnbondingQueueRequest,
+    ) -> Result<GetUnbondingQueueResult, engine_state::Error>
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        self.make_request(
+            |responder| ContractRuntimeRequest::GetUnbondingQueue {
+                get_unbonding_queue_request,
+                responder,
+            },
+            QueueKind::ContractRuntime,
+        )
+        .await
+    }
+
     /// Returns the value of the execution results checksum stored in the ChecksumRegistry for the
     /// given state root hash.
     pub(crate) async fn get_execution_results_checksum(
@@ -2084,6 +2276,42 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Dump the contents of the deploy buffer.
+    pub(crate) async fn diagnostics_port_dump_deploy_buffer(self) -> DeployBufferDump
+    where
+        REv: From<DeployBufferRequest>,
+    {
+        self.make_request(
+            |responder| DeployBufferRequest::Dump { responder },
+            QueueKind::Control,
+        )
+        .await
+    }
+
+    /// Dump the state of the block accumulator.
+    pub(crate) async fn diagnostics_port_dump_block_accumulator(self) -> BlockAccumulatorDump
+    where
+        REv: From<BlockAccumulatorRequest>,
+    {
+        self.make_request(
+            |responder| BlockAccumulatorRequest::Dump { responder },
+            QueueKind::Control,
+        )
+        .await
+    }
+
+    /// Dump the state of the block synchronizer.
+    pub(crate) async fn diagnostics_port_dump_block_synchronizer(self) -> BlockSynchronizerDump
+    where
+        REv: From<BlockSynchronizerRequest>,
+    {
+        self.make_request(
+            |responder| BlockSynchronizerRequest::Dump { responder },
+            QueueKind::Control,
+        )
+        .await
+    }
+
     /// Dump the event queue contents to the diagnostics port, using the given serializer.
     pub(crate) async fn diagnostics_port_dump_queue(self, dump_format: QueueDumpFormat)
     where
@@ -2099,6 +2327,20 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Re-reads the node's configuration and applies all settings which can be changed at runtime.
+    pub(crate) async fn diagnostics_port_reload_config(
+        self,
+    ) -> Result<ConfigReloadReport, ConfigReloadError>
+    where
+        REv: From<ControlAnnouncement>,
+    {
+        self.make_request(
+            |responder| ControlAnnouncement::ReloadConfig { responder },
+            QueueKind::Control,
+        )
+        .await
+    }
+
     /// Activates/deactivates a failpoint from a given activation.
     pub(crate) async fn activate_failpoint(self, activation: FailpointActivation)
     where
@@ -2200,6 +2442,7 @@ impl<REv> EffectBuilder<REv> {
         self,
         execution_prestate: SpeculativeExecutionState,
         deploy: Arc<Deploy>,
+        gas_profiler: Option<GasProfiler>,
     ) -> Result<Option<ExecutionResult>, engine_state::Error>
     where
         REv: From<ContractRuntimeRequest>,
@@ -2208,6 +2451,33 @@ impl<REv> EffectBuilder<REv> {
             |responder| ContractRuntimeRequest::SpeculativeDeployExecution {
                 execution_prestate,
                 deploy,
+                gas_profiler,
+                responder,
+            },
+            QueueKind::ContractRuntime,
+        )
+        .await
+    }
+
+    /// Requests evaluation of a view entry point of a stored contract against the state of a block.
+    pub(crate) async fn call_view(
+        self,
+        execution_prestate: SpeculativeExecutionState,
+        caller: AccountHash,
+        contract_hash: ContractHash,
+        entry_point: String,
+        args: RuntimeArgs,
+    ) -> Result<CallViewResult, engine_state::Error>
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        self.make_request(
+            |responder| ContractRuntimeRequest::CallView {
+                execution_prestate,
+                caller,
+                contract_hash,
+                entry_point,
+                args,
                 responder,
             },
             QueueKind::ContractRuntime,
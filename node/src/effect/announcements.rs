@@ -14,7 +14,7 @@ use datasize::DataSize;
 use itertools::Itertools;
 use serde::Serialize;
 
-use casper_types::{EraId, ExecutionEffect, PublicKey, Timestamp, U512};
+use casper_types::{EraId, ExecutionEffect, ExecutionResult, Key, PublicKey, Timestamp, U512};
 
 use crate::{
     components::{
@@ -27,7 +27,11 @@ use crate::{
     },
     effect::Responder,
     failpoints::FailpointActivation,
-    types::{Block, Deploy, DeployHash, FinalitySignature, FinalizedBlock, MetaBlock, NodeId},
+    reactor::{ConfigReloadError, ConfigReloadReport},
+    types::{
+        Block, BlockHash, Deploy, DeployCancellation, DeployHash, FinalitySignature,
+        FinalizedBlock, MetaBlock, NodeId,
+    },
     utils::Source,
 };
 
@@ -69,6 +73,11 @@ pub(crate) enum ControlAnnouncement {
         /// The failpoint activation to process.
         activation: FailpointActivation,
     },
+    /// The configuration should be reloaded and applied to the running node.
+    ReloadConfig {
+        /// Responder called with the outcome of the reload.
+        responder: Responder<Result<ConfigReloadReport, ConfigReloadError>>,
+    },
 }
 
 impl Debug for ControlAnnouncement {
@@ -89,6 +98,9 @@ impl Debug for ControlAnnouncement {
                 .debug_struct("ActivateFailpoint")
                 .field("activation", activation)
                 .finish(),
+            ControlAnnouncement::ReloadConfig { .. } => {
+                f.debug_struct("ReloadConfig").finish_non_exhaustive()
+            }
         }
     }
 }
@@ -109,6 +121,7 @@ impl Display for ControlAnnouncement {
             ControlAnnouncement::ActivateFailpoint { activation } => {
                 write!(f, "failpoint activation: {}", activation)
             }
+            ControlAnnouncement::ReloadConfig { .. } => write!(f, "reload configuration"),
         }
     }
 }
@@ -219,10 +232,27 @@ impl Display for DeployAcceptorAnnouncement {
     }
 }
 
+/// An announcement by the `DeployAcceptor` that a valid deploy cancellation has been received.
+#[derive(Debug, Serialize)]
+pub(crate) struct DeployCancellationAnnouncement(pub(crate) Box<DeployCancellation>);
+
+impl Display for DeployCancellationAnnouncement {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "accepted {}", self.0)
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub(crate) enum DeployBufferAnnouncement {
     /// Hashes of the deploys that expired.
     DeploysExpired(Vec<DeployHash>),
+    /// A deploy was cancelled, and will no longer be proposed by this node.
+    DeployCancelled {
+        /// The hash of the cancelled deploy.
+        deploy_hash: DeployHash,
+        /// The hash of the deploy replacing the cancelled one, if any.
+        replacement: Option<DeployHash>,
+    },
 }
 
 impl Display for DeployBufferAnnouncement {
@@ -231,6 +261,9 @@ impl Display for DeployBufferAnnouncement {
             DeployBufferAnnouncement::DeploysExpired(hashes) => {
                 write!(f, "pruned hashes: {}", hashes.iter().join(", "))
             }
+            DeployBufferAnnouncement::DeployCancelled { deploy_hash, .. } => {
+                write!(f, "cancelled {}", deploy_hash)
+            }
         }
     }
 }
@@ -360,6 +393,13 @@ pub(crate) enum ContractRuntimeAnnouncement {
         /// The operations and transforms committed to global state.
         execution_effect: ExecutionEffect,
     },
+    /// Scheduled invocations were executed and committed to global state as part of a block.
+    ScheduledInvocationsProcessed {
+        /// The hash of the block in which the invocations were executed.
+        block_hash: BlockHash,
+        /// The `Key::ScheduledInvocation`s of the executed invocations and their results.
+        execution_results: Vec<(Key, ExecutionResult)>,
+    },
     /// New era validators.
     UpcomingEraValidators {
         /// The era id in which the step was committed to global state.
@@ -375,6 +415,9 @@ impl Display for ContractRuntimeAnnouncement {
             ContractRuntimeAnnouncement::CommitStepSuccess { era_id, .. } => {
                 write!(f, "commit step completed for {}", era_id)
             }
+            ContractRuntimeAnnouncement::ScheduledInvocationsProcessed { block_hash, .. } => {
+                write!(f, "scheduled invocations processed in {}", block_hash)
+            }
             ContractRuntimeAnnouncement::UpcomingEraValidators {
                 era_that_is_ending, ..
             } => {
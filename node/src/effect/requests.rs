@@ -19,27 +19,34 @@ use casper_execution_engine::{
     core::engine_state::{
         self,
         balance::{BalanceRequest, BalanceResult},
+        call_view::CallViewResult,
         era_validators::GetEraValidatorsError,
         get_bids::{GetBidsRequest, GetBidsResult},
+        get_delegators::{GetDelegatorsRequest, GetDelegatorsResult},
+        get_unbonding_queue::{GetUnbondingQueueRequest, GetUnbondingQueueResult},
         query::{QueryRequest, QueryResult},
     },
+    shared::gas_profile::GasProfiler,
     storage::trie::TrieRaw,
 };
 use casper_hashing::Digest;
 use casper_types::{
-    bytesrepr::Bytes, system::auction::EraValidators, EraId, ExecutionResult, Key, ProtocolVersion,
-    PublicKey, TimeDiff, Timestamp, Transfer, URef,
+    account::AccountHash, bytesrepr::Bytes, system::auction::EraValidators, ContractHash, EraId,
+    ExecutionResult, Key, ProtocolVersion, PublicKey, RuntimeArgs, TimeDiff, Timestamp, Transfer,
+    URef,
 };
 
 use crate::{
     components::{
+        block_accumulator::BlockAccumulatorDump,
         block_synchronizer::{
-            BlockSynchronizerStatus, GlobalStateSynchronizerError, GlobalStateSynchronizerResponse,
-            TrieAccumulatorError, TrieAccumulatorResponse,
+            BlockSynchronizerDump, BlockSynchronizerStatus, GlobalStateSynchronizerError,
+            GlobalStateSynchronizerResponse, TrieAccumulatorError, TrieAccumulatorResponse,
         },
         consensus::{ClContext, ProposedBlock, ValidatorChange},
         contract_runtime::EraValidatorsRequest,
-        deploy_acceptor,
+        deploy_acceptor::{self, DeployRejection},
+        deploy_buffer::{DeployBufferDump, DeployBufferStatus},
         diagnostics_port::StopAtSpec,
         fetcher::{FetchItem, FetchResult},
         gossiper::GossipItem,
@@ -53,10 +60,10 @@ use crate::{
     types::{
         appendable_block::AppendableBlock, ApprovalsHashes, AvailableBlockRange, Block,
         BlockExecutionResultsOrChunk, BlockExecutionResultsOrChunkId, BlockHash, BlockHeader,
-        BlockSignatures, BlockWithMetadata, ChainspecRawBytes, Deploy, DeployHash, DeployHeader,
-        DeployId, DeployMetadataExt, DeployWithFinalizedApprovals, FinalitySignature,
-        FinalitySignatureId, FinalizedApprovals, FinalizedBlock, LegacyDeploy, MetaBlockState,
-        NodeId, StatusFeed, TrieOrChunk, TrieOrChunkId,
+        BlockSignatures, BlockWithMetadata, ChainspecRawBytes, Deploy, DeployCancellation,
+        DeployHash, DeployHeader, DeployId, DeployMetadataExt, DeployWithFinalizedApprovals,
+        FinalitySignature, FinalitySignatureId, FinalizedApprovals, FinalizedBlock, LegacyDeploy,
+        MetaBlockState, NodeId, StatusFeed, TrieOrChunk, TrieOrChunkId,
     },
     utils::{DisplayIter, Source},
 };
@@ -323,6 +330,12 @@ pub(crate) enum StorageRequest {
         deploy_hashes: HashSet<DeployHash>,
         responder: Responder<HashSet<EraId>>,
     },
+    /// Retrieve those of the given deploys which have been executed in a stored block, along with
+    /// the timestamp of that block.
+    GetExecutedDeploys {
+        deploy_hashes: HashSet<DeployHash>,
+        responder: Responder<HashMap<DeployHash, Timestamp>>,
+    },
     /// Retrieve block header with given hash.
     GetBlockHeader {
         /// Hash of block to get header of.
@@ -344,6 +357,17 @@ pub(crate) enum StorageRequest {
         /// local storage.
         responder: Responder<Option<BlockHeader>>,
     },
+    /// Retrieve the header of the switch block of the given era.
+    GetSwitchBlockHeaderByEra {
+        /// Era of the switch block.
+        era_id: EraId,
+        /// If true, only return `Some` if the block is in the available block range, i.e. the
+        /// highest contiguous range of complete blocks.
+        only_from_available_block_range: bool,
+        /// Responder to call with the result.  Returns `None` if the switch block header doesn't
+        /// exist in local storage.
+        responder: Responder<Option<BlockHeader>>,
+    },
     /// Retrieve all transfers in a block with given hash.
     GetBlockTransfers {
         /// Hash of block to get transfers of.
@@ -527,12 +551,22 @@ impl Display for StorageRequest {
             StorageRequest::GetDeploysEraIds { deploy_hashes, .. } => {
                 write!(formatter, "get era ids for {} deploys", deploy_hashes.len())
             }
+            StorageRequest::GetExecutedDeploys { deploy_hashes, .. } => {
+                write!(
+                    formatter,
+                    "get executed deploys among {} deploys",
+                    deploy_hashes.len()
+                )
+            }
             StorageRequest::GetBlockHeader { block_hash, .. } => {
                 write!(formatter, "get {}", block_hash)
             }
             StorageRequest::GetBlockHeaderByHeight { block_height, .. } => {
                 write!(formatter, "get header for height {}", block_height)
             }
+            StorageRequest::GetSwitchBlockHeaderByEra { era_id, .. } => {
+                write!(formatter, "get switch block header for era {}", era_id)
+            }
             StorageRequest::GetBlockTransfers { block_hash, .. } => {
                 write!(formatter, "get transfers for {}", block_hash)
             }
@@ -664,6 +698,15 @@ pub(crate) enum DeployBufferRequest {
         timestamp: Timestamp,
         responder: Responder<AppendableBlock>,
     },
+    /// Retrieve the status of the given deploy in the deploy buffer, if it is buffered.
+    GetDeployStatus {
+        deploy_hash: DeployHash,
+        responder: Responder<Option<DeployBufferStatus>>,
+    },
+    /// Retrieve a debug dump of the deploy buffer's contents.
+    Dump {
+        responder: Responder<DeployBufferDump>,
+    },
 }
 
 impl Display for DeployBufferRequest {
@@ -676,6 +719,12 @@ impl Display for DeployBufferRequest {
                     timestamp
                 )
             }
+            DeployBufferRequest::GetDeployStatus { deploy_hash, .. } => {
+                write!(formatter, "request for status of {}", deploy_hash)
+            }
+            DeployBufferRequest::Dump { .. } => {
+                write!(formatter, "request for deploy buffer dump")
+            }
         }
     }
 }
@@ -889,6 +938,22 @@ pub(crate) enum ContractRuntimeRequest {
         /// Responder to call with the result.
         responder: Responder<Result<GetBidsResult, engine_state::Error>>,
     },
+    /// Return a page of the delegators of a validator at a given state root hash.
+    GetDelegators {
+        /// Get delegators request.
+        #[serde(skip_serializing)]
+        get_delegators_request: GetDelegatorsRequest,
+        /// Responder to call with the result.
+        responder: Responder<Result<GetDelegatorsResult, engine_state::Error>>,
+    },
+    /// Return the pending unbonding and withdraw purses at a given state root hash.
+    GetUnbondingQueue {
+        /// Get unbonding queue request.
+        #[serde(skip_serializing)]
+        get_unbonding_queue_request: GetUnbondingQueueRequest,
+        /// Responder to call with the result.
+        responder: Responder<Result<GetUnbondingQueueResult, engine_state::Error>>,
+    },
     /// Returns the value of the execution results checksum stored in the ChecksumRegistry for the
     /// given state root hash.
     GetExecutionResultsChecksum {
@@ -922,9 +987,27 @@ pub(crate) enum ContractRuntimeRequest {
         execution_prestate: SpeculativeExecutionState,
         /// Deploy to execute.
         deploy: Arc<Deploy>,
+        /// Profiler to which the gas charged by the deploy is attributed, if any.
+        #[serde(skip_serializing)]
+        gas_profiler: Option<GasProfiler>,
         /// Results
         responder: Responder<Result<Option<ExecutionResult>, engine_state::Error>>,
     },
+    /// Evaluate a view entry point of a stored contract without committing any effects.
+    CallView {
+        /// State of a block against which to evaluate the view entry point.
+        execution_prestate: SpeculativeExecutionState,
+        /// The account on whose behalf the view entry point is evaluated.
+        caller: AccountHash,
+        /// The hash of the contract to call.
+        contract_hash: ContractHash,
+        /// The name of the view entry point.
+        entry_point: String,
+        /// The arguments passed to the view entry point.
+        args: RuntimeArgs,
+        /// Responder to call with the result.
+        responder: Responder<Result<CallViewResult, engine_state::Error>>,
+    },
 }
 
 impl Display for ContractRuntimeRequest {
@@ -949,6 +1032,26 @@ impl Display for ContractRuntimeRequest {
             } => {
                 write!(formatter, "get bids request: {:?}", get_bids_request)
             }
+            ContractRuntimeRequest::GetDelegators {
+                get_delegators_request,
+                ..
+            } => {
+                write!(
+                    formatter,
+                    "get delegators request: {:?}",
+                    get_delegators_request
+                )
+            }
+            ContractRuntimeRequest::GetUnbondingQueue {
+                get_unbonding_queue_request,
+                ..
+            } => {
+                write!(
+                    formatter,
+                    "get unbonding queue request: {:?}",
+                    get_unbonding_queue_request
+                )
+            }
             ContractRuntimeRequest::GetExecutionResultsChecksum {
                 state_root_hash, ..
             } => write!(
@@ -979,6 +1082,18 @@ impl Display for ContractRuntimeRequest {
                     execution_prestate.state_root_hash
                 )
             }
+            ContractRuntimeRequest::CallView {
+                execution_prestate,
+                contract_hash,
+                entry_point,
+                ..
+            } => {
+                write!(
+                    formatter,
+                    "call view {} of {} on {}",
+                    entry_point, contract_hash, execution_prestate.state_root_hash
+                )
+            }
         }
     }
 }
@@ -1117,6 +1232,9 @@ pub(crate) enum BlockAccumulatorRequest {
         block_hash: BlockHash,
         responder: Responder<Option<Vec<NodeId>>>,
     },
+    Dump {
+        responder: Responder<BlockAccumulatorDump>,
+    },
 }
 
 impl Display for BlockAccumulatorRequest {
@@ -1125,6 +1243,7 @@ impl Display for BlockAccumulatorRequest {
             BlockAccumulatorRequest::GetPeersForBlock { block_hash, .. } => {
                 write!(f, "get peers for {}", block_hash)
             }
+            BlockAccumulatorRequest::Dump { .. } => write!(f, "dump block accumulator"),
         }
     }
 }
@@ -1137,6 +1256,9 @@ pub(crate) enum BlockSynchronizerRequest {
     Status {
         responder: Responder<BlockSynchronizerStatus>,
     },
+    Dump {
+        responder: Responder<BlockSynchronizerDump>,
+    },
 }
 
 impl Display for BlockSynchronizerRequest {
@@ -1151,6 +1273,9 @@ impl Display for BlockSynchronizerRequest {
             BlockSynchronizerRequest::Status { .. } => {
                 write!(f, "block synchronizer request: status")
             }
+            BlockSynchronizerRequest::Dump { .. } => {
+                write!(f, "block synchronizer request: dump")
+            }
             BlockSynchronizerRequest::SyncGlobalStates(_) => {
                 write!(f, "request to sync global states")
             }
@@ -1199,3 +1324,31 @@ impl Display for AcceptDeployRequest {
         }
     }
 }
+
+/// A request to the deploy acceptor.
+#[derive(DataSize, Debug, Serialize)]
+pub(crate) enum DeployAcceptorRequest {
+    /// Retrieve the record of the given deploy's rejection, if it was rejected recently.
+    GetRejection {
+        deploy_hash: DeployHash,
+        responder: Responder<Option<DeployRejection>>,
+    },
+    /// Validate a deploy cancellation submitted by a client.
+    AcceptCancellation {
+        cancellation: Box<DeployCancellation>,
+        responder: Responder<Result<(), deploy_acceptor::Error>>,
+    },
+}
+
+impl Display for DeployAcceptorRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DeployAcceptorRequest::GetRejection { deploy_hash, .. } => {
+                write!(f, "get rejection of {}", deploy_hash)
+            }
+            DeployAcceptorRequest::AcceptCancellation { cancellation, .. } => {
+                write!(f, "accept {}", cancellation)
+            }
+        }
+    }
+}
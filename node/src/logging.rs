@@ -1,6 +1,13 @@
 //! Logging via the tracing crate.
 
-use std::{env, fmt, io};
+#[cfg(feature = "opentelemetry")]
+mod span_export;
+
+use std::{
+    env, fmt, io,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use ansi_term::{Color, Style};
 use anyhow::anyhow;
@@ -13,15 +20,17 @@ use tracing::{
     Event, Level, Subscriber,
 };
 use tracing_subscriber::{
+    filter,
     fmt::{
-        format::{self, FieldFn, Format, Json, JsonFields, Writer},
+        format::{self, Writer},
         time::{FormatTime, SystemTime},
-        FmtContext, FormatEvent, FormatFields, FormattedFields, Layer,
+        FmtContext, FormatEvent, FormatFields, FormattedFields,
     },
-    layer::Layered,
+    layer::SubscriberExt,
     registry::LookupSpan,
     reload::{self, Handle},
-    EnvFilter, Registry,
+    util::SubscriberInitExt,
+    EnvFilter, Layer, Registry,
 };
 
 const LOG_CONFIGURATION_ENVVAR: &str = "RUST_LOG";
@@ -37,6 +46,16 @@ const LOG_FIELD_LINE: &str = "log.line";
 /// We use a static variable for the reload handle since our logger instance is also global.
 static RELOAD_HANDLE: OnceCell<ReloadHandle> = OnceCell::new();
 
+/// Whether log events are currently written in the JSON rather than the text format.
+///
+/// Both formatting layers are always installed, so that either one can format spans opened before
+/// the format was changed; this only selects the one writing events.
+static JSON_FORMAT_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Whether tracing spans are exported.
+#[cfg(feature = "opentelemetry")]
+static SPAN_EXPORT_ENABLED: AtomicBool = AtomicBool::new(false);
+
 /// Logging configuration.
 #[derive(Clone, DataSize, Debug, Default, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -55,6 +74,12 @@ pub struct LoggingConfig {
     /// If set, human-readable formats will abbreviate module names, `foo::bar::baz::bizz` will
     /// turn into `f:b:b:bizz`.
     pub abbreviate_modules: bool,
+
+    /// Export of tracing spans via OpenTelemetry, disabled if not set.
+    ///
+    /// Only supported if the node was built with the `opentelemetry` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span_export: Option<SpanExportConfig>,
 }
 
 impl LoggingConfig {
@@ -65,8 +90,32 @@ impl LoggingConfig {
             format,
             color,
             abbreviate_modules,
+            span_export: None,
         }
     }
+
+    /// Resolves all relative paths against the given root directory.
+    pub(crate) fn resolve_paths(&mut self, root: &Path) {
+        if let Some(SpanExportConfig::File { ref mut path }) = self.span_export {
+            *path = root.join(&*path);
+        }
+    }
+}
+
+/// Destination for exported tracing spans.
+#[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
+#[serde(tag = "exporter", rename_all = "lowercase", deny_unknown_fields)]
+pub enum SpanExportConfig {
+    /// Export to an OpenTelemetry collector using OTLP over gRPC.
+    Otlp {
+        /// The endpoint of the collector, e.g. `http://localhost:4317`.
+        endpoint: String,
+    },
+    /// Append spans as JSON to a local file.
+    File {
+        /// Path of the file, relative to the config file if not absolute.
+        path: PathBuf,
+    },
 }
 
 /// Logging output format.
@@ -263,39 +312,15 @@ pub fn init() -> anyhow::Result<()> {
     init_with_config(&Default::default())
 }
 
-/// A handle for reloading the logger.
-#[allow(clippy::type_complexity)] // Cannot be helped, unfortunately.
-pub enum ReloadHandle {
-    /// Text-logger reload handle.
-    Text(Handle<EnvFilter, Layered<Layer<Registry, FieldFn<FormatDebugFn>, FmtEvent>, Registry>>),
-    /// JSON-logger reload handle.
-    Json(Handle<EnvFilter, Layered<Layer<Registry, JsonFields, Format<Json>>, Registry>>),
-}
-
-impl ReloadHandle {
-    /// Swaps out the [`EnvFilter`] used to filter log events.
-    fn reload_env_filter(&self, new_filter: EnvFilter) -> Result<(), reload::Error> {
-        match self {
-            ReloadHandle::Text(handle) => handle.reload(new_filter),
-            ReloadHandle::Json(handle) => handle.reload(new_filter),
-        }
-    }
-
-    /// Returns a string representation of the current [`EnvFilter`], if set.
-    fn display_log_filter(&self) -> Result<String, reload::Error> {
-        match self {
-            ReloadHandle::Text(handle) => handle.with_current(|env_filter| env_filter.to_string()),
-            ReloadHandle::Json(handle) => handle.with_current(|env_filter| env_filter.to_string()),
-        }
-    }
-}
+/// A handle for reloading the logger's [`EnvFilter`].
+pub type ReloadHandle = Handle<EnvFilter, Registry>;
 
 /// Swaps out the global [`EnvFilter`].
 pub fn reload_global_env_filter(new_filter: EnvFilter) -> anyhow::Result<()> {
     let handle = RELOAD_HANDLE
         .get()
         .ok_or_else(|| anyhow!("could not fetch reload handle - logger not initialized?"))?;
-    handle.reload_env_filter(new_filter)?;
+    handle.reload(new_filter)?;
 
     Ok(())
 }
@@ -305,11 +330,16 @@ pub fn display_global_env_filter() -> anyhow::Result<String> {
     let handle = RELOAD_HANDLE
         .get()
         .ok_or_else(|| anyhow!("could not fetch reload handle - logger not initialized?"))?;
-    let formatted = handle.display_log_filter()?;
+    let formatted = handle.with_current(|env_filter| env_filter.to_string())?;
 
     Ok(formatted)
 }
 
+/// Changes the format of all subsequently written log events.
+pub fn set_global_format(format: &LoggingFormat) {
+    JSON_FORMAT_ENABLED.store(matches!(format, LoggingFormat::Json), Ordering::Relaxed);
+}
+
 /// Type alias for the formatting function used.
 pub type FormatDebugFn = fn(&mut Writer, &Field, &dyn std::fmt::Debug) -> fmt::Result;
 
@@ -336,38 +366,82 @@ fn format_into_debug_writer(
 pub fn init_with_config(config: &LoggingConfig) -> anyhow::Result<()> {
     let formatter = format::debug_fn(format_into_debug_writer as FormatDebugFn);
 
-    let filter = EnvFilter::new(
+    let env_filter = EnvFilter::new(
         env::var(LOG_CONFIGURATION_ENVVAR)
             .as_deref()
             .unwrap_or("warn,casper_node=info"),
     );
 
-    match config.format {
-        // Setup a new tracing-subscriber writing to `stdout` for logging.
-        LoggingFormat::Text => {
-            let builder = tracing_subscriber::fmt()
-                .with_writer(io::stdout as fn() -> std::io::Stdout)
-                .with_env_filter(filter)
-                .fmt_fields(formatter)
-                .event_format(FmtEvent::new(config.color, config.abbreviate_modules))
-                .with_filter_reloading();
-            let handle = ReloadHandle::Text(builder.reload_handle());
-            builder.try_init().map_err(|error| anyhow!(error))?;
-            drop(RELOAD_HANDLE.set(handle));
-            Ok(())
-        }
+    set_global_format(&config.format);
+    let (env_filter, handle) = reload::Layer::new(env_filter);
+
+    // Text logging writes to `stdout`.
+    let text_layer = tracing_subscriber::fmt::layer()
+        .with_writer(io::stdout as fn() -> std::io::Stdout)
+        .fmt_fields(formatter)
+        .event_format(FmtEvent::new(config.color, config.abbreviate_modules))
+        .with_filter(filter::dynamic_filter_fn(|metadata, _| {
+            metadata.is_span() || !JSON_FORMAT_ENABLED.load(Ordering::Relaxed)
+        }));
+
+    // JSON logging writes to `stdout` as well but uses the JSON format.
+    let json_layer = tracing_subscriber::fmt::layer()
+        .with_writer(io::stdout as fn() -> std::io::Stdout)
+        .json()
+        .with_filter(filter::dynamic_filter_fn(|metadata, _| {
+            metadata.is_span() || JSON_FORMAT_ENABLED.load(Ordering::Relaxed)
+        }));
+
+    let subscriber = Registry::default()
+        .with(env_filter)
+        .with(text_layer)
+        .with(json_layer);
+    try_init(subscriber, config.span_export.as_ref())?;
+    drop(RELOAD_HANDLE.set(handle));
+    Ok(())
+}
 
-        // JSON logging writes to `stdout` as well but uses the JSON format.
-        LoggingFormat::Json => {
-            let builder = tracing_subscriber::fmt()
-                .with_writer(io::stdout as fn() -> std::io::Stdout)
-                .with_env_filter(filter)
-                .json()
-                .with_filter_reloading();
-            let handle = ReloadHandle::Json(builder.reload_handle());
-            builder.try_init().map_err(|error| anyhow!(error))?;
-            drop(RELOAD_HANDLE.set(handle));
-            Ok(())
-        }
+/// Installs the given subscriber globally, extended by span export if configured.
+fn try_init<S>(subscriber: S, span_export: Option<&SpanExportConfig>) -> anyhow::Result<()>
+where
+    S: Subscriber + for<'a> LookupSpan<'a> + Send + Sync + 'static,
+{
+    #[cfg(feature = "opentelemetry")]
+    if let Some(span_export) = span_export {
+        let layer = span_export::layer(span_export)?;
+        subscriber
+            .with(layer)
+            .try_init()
+            .map_err(|error| anyhow!(error))?;
+        SPAN_EXPORT_ENABLED.store(true, Ordering::Relaxed);
+        return Ok(());
     }
+
+    subscriber.try_init().map_err(|error| anyhow!(error))?;
+
+    #[cfg(not(feature = "opentelemetry"))]
+    if span_export.is_some() {
+        tracing::warn!(
+            "ignoring 'logging.span_export', node was built without the 'opentelemetry' feature"
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns whether tracing spans are exported.
+///
+/// Always `false` if the node was built without the `opentelemetry` feature.
+pub(crate) fn is_span_export_enabled() -> bool {
+    #[cfg(feature = "opentelemetry")]
+    return SPAN_EXPORT_ENABLED.load(Ordering::Relaxed);
+
+    #[cfg(not(feature = "opentelemetry"))]
+    false
+}
+
+/// Flushes all pending exported spans and stops the span export, if any.
+pub fn shutdown_span_export() {
+    #[cfg(feature = "opentelemetry")]
+    span_export::shutdown();
 }
@@ -0,0 +1,73 @@
+//! Export of tracing spans via OpenTelemetry.
+//!
+//! Spans are exported in batches from a background task, either to an OpenTelemetry collector
+//! using OTLP or to a local file.
+
+use std::fs::OpenOptions;
+
+use anyhow::Context;
+use opentelemetry::{global, trace::TracerProvider as _, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    runtime,
+    trace::{self as sdktrace, Tracer, TracerProvider},
+    Resource,
+};
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+
+use super::SpanExportConfig;
+
+/// The service name exported spans are attributed to.
+const SERVICE_NAME: &str = "casper-node";
+
+/// Creates a layer exporting all spans to the configured destination.
+///
+/// Must be called from within a Tokio runtime.
+pub(super) fn layer<S>(config: &SpanExportConfig) -> anyhow::Result<OpenTelemetryLayer<S, Tracer>>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let trace_config = sdktrace::config().with_resource(Resource::new(vec![
+        KeyValue::new("service.name", SERVICE_NAME),
+        KeyValue::new("service.version", crate::VERSION_STRING.as_str()),
+    ]));
+
+    let tracer = match config {
+        SpanExportConfig::Otlp { endpoint } => opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(endpoint.clone()),
+            )
+            .with_trace_config(trace_config)
+            .install_batch(runtime::Tokio)
+            .context("could not set up OTLP span export")?,
+        SpanExportConfig::File { path } => {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("could not open span export file {}", path.display()))?;
+            let exporter = opentelemetry_stdout::SpanExporter::builder()
+                .with_writer(file)
+                .build();
+            let provider = TracerProvider::builder()
+                .with_config(trace_config)
+                .with_batch_exporter(exporter, runtime::Tokio)
+                .build();
+            let tracer = provider.tracer(SERVICE_NAME);
+            global::set_tracer_provider(provider);
+            tracer
+        }
+    };
+
+    Ok(tracing_opentelemetry::layer().with_tracer(tracer))
+}
+
+/// Flushes all pending spans and shuts down the exporter.
+pub(super) fn shutdown() {
+    global::shutdown_tracer_provider();
+}
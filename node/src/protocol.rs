@@ -27,7 +27,7 @@ use crate::{
         },
         AutoClosingResponder, EffectBuilder,
     },
-    types::{Block, Deploy, FinalitySignature, NodeId},
+    types::{Block, Deploy, DeployCancellation, FinalitySignature, NodeId},
 };
 
 /// Reactor message.
@@ -68,6 +68,9 @@ pub(crate) enum Message {
     /// Finality signature.
     #[from]
     FinalitySignature(Box<FinalitySignature>),
+    /// Deploy cancellation gossiper component message.
+    #[from]
+    DeployCancellationGossiper(gossiper::Message<DeployCancellation>),
 }
 
 impl Payload for Message {
@@ -91,6 +94,7 @@ impl Payload for Message {
             },
             Message::FinalitySignature(_) => MessageKind::Consensus,
             Message::FinalitySignatureGossiper(_) => MessageKind::FinalitySignatureGossip,
+            Message::DeployCancellationGossiper(_) => MessageKind::DeployGossip,
         }
     }
 
@@ -108,6 +112,7 @@ impl Payload for Message {
             Message::GetRequest { .. } => false,
             Message::GetResponse { .. } => false,
             Message::FinalitySignature(_) => false,
+            Message::DeployCancellationGossiper(_) => false,
         }
     }
 
@@ -143,6 +148,7 @@ impl Payload for Message {
                 Tag::BlockExecutionResults => weights.execution_results_responses,
             },
             Message::FinalitySignature(_) => weights.finality_signature_broadcasts,
+            Message::DeployCancellationGossiper(_) => weights.deploy_gossip,
         }
     }
 
@@ -159,6 +165,7 @@ impl Payload for Message {
             Message::GetRequest { .. } => false,
             Message::GetResponse { .. } => false,
             Message::FinalitySignature(_) => false,
+            Message::DeployCancellationGossiper(_) => false,
         }
     }
 }
@@ -220,6 +227,10 @@ impl Debug for Message {
             Message::FinalitySignature(fs) => {
                 f.debug_tuple("FinalitySignature").field(&fs).finish()
             }
+            Message::DeployCancellationGossiper(cancellation) => f
+                .debug_tuple("DeployCancellationGossiper")
+                .field(&cancellation)
+                .finish(),
         }
     }
 }
@@ -261,6 +272,11 @@ mod specimen_support {
                     MessageDiscriminants::FinalitySignature => Message::FinalitySignature(
                         LargestSpecimen::largest_specimen(estimator, cache),
                     ),
+                    MessageDiscriminants::DeployCancellationGossiper => {
+                        Message::DeployCancellationGossiper(LargestSpecimen::largest_specimen(
+                            estimator, cache,
+                        ))
+                    }
                 },
             )
         }
@@ -290,6 +306,9 @@ impl Display for Message {
             Message::FinalitySignature(fs) => {
                 write!(f, "FinalitySignature::({})", fs)
             }
+            Message::DeployCancellationGossiper(cancellation) => {
+                write!(f, "DeployCancellationGossiper::{}", cancellation)
+            }
         }
     }
 }
@@ -302,6 +321,7 @@ where
         + From<GossiperIncoming<Deploy>>
         + From<GossiperIncoming<FinalitySignature>>
         + From<GossiperIncoming<GossipedAddress>>
+        + From<GossiperIncoming<DeployCancellation>>
         + From<NetRequestIncoming>
         + From<NetResponseIncoming>
         + From<TrieRequestIncoming>
@@ -440,6 +460,11 @@ where
             Message::FinalitySignature(message) => {
                 FinalitySignatureIncoming { sender, message }.into()
             }
+            Message::DeployCancellationGossiper(message) => GossiperIncoming {
+                sender,
+                message: Box::new(message),
+            }
+            .into(),
         }
     }
 
@@ -29,6 +29,7 @@
 //! With all these set up, a reactor can be executed using a [`Runner`], either in a step-wise
 //! manner using [`Runner::crank`] or indefinitely using [`Runner::run`].
 
+mod config_reload;
 mod event_queue_metrics;
 pub(crate) mod main_reactor;
 mod queue_kind;
@@ -80,14 +81,18 @@ use crate::{
         Effect, EffectBuilder, EffectExt, Effects,
     },
     failpoints::FailpointActivation,
+    logging,
     types::{
         ApprovalsHashes, Block, BlockExecutionResultsOrChunk, BlockHeader, Chainspec,
         ChainspecRawBytes, Deploy, ExitCode, FinalitySignature, LegacyDeploy, NodeId, SyncLeap,
         TrieOrChunk,
     },
     unregister_metric,
-    utils::{self, SharedFlag, WeightedRoundRobin},
-    NodeRng, TERMINATION_REQUESTED,
+    utils::{self, display_error, SharedFlag, WeightedRoundRobin},
+    NodeRng, RELOAD_REQUESTED, TERMINATION_REQUESTED,
+};
+pub(crate) use config_reload::{
+    changed_settings, ConfigLoader, ConfigReloadError, ConfigReloadReport,
 };
 pub(crate) use queue_kind::QueueKind;
 
@@ -167,9 +172,39 @@ fn adjust_open_files_limit() {
 ///
 /// Components rarely use this, but use a bound `EventQueueHandle` instead.
 ///
-/// Schedule tuples contain an optional ancestor ID and the actual event. The ancestor ID indicates
-/// which potential previous event resulted in the event being created.
-pub(crate) type Scheduler<Ev> = WeightedRoundRobin<(Option<NonZeroU64>, Ev), QueueKind>;
+/// Schedule tuples contain the origin and the actual event. The origin indicates which potential
+/// previous event resulted in the event being created.
+pub(crate) type Scheduler<Ev> = WeightedRoundRobin<(EventOrigin, Ev), QueueKind>;
+
+/// The origin of a scheduled event.
+#[derive(Debug)]
+pub(crate) struct EventOrigin {
+    /// The ID of the event whose effects created this event, if any.
+    ancestor: Option<NonZeroU64>,
+    /// The span that was active when the event was scheduled, if spans are exported.
+    ///
+    /// Held until the event is dispatched, so that the dispatch can be linked to it.
+    #[cfg(feature = "opentelemetry")]
+    span: Option<Span>,
+}
+
+impl EventOrigin {
+    /// Creates a new event origin, capturing the current span if spans are exported.
+    fn new(ancestor: Option<NonZeroU64>) -> Self {
+        EventOrigin {
+            ancestor,
+            #[cfg(feature = "opentelemetry")]
+            span: logging::is_span_export_enabled().then(Span::current),
+        }
+    }
+}
+
+// Only the ancestor is included in queue dumps.
+impl Serialize for EventOrigin {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.ancestor.serialize(serializer)
+    }
+}
 
 /// Event queue handle
 ///
@@ -232,7 +267,7 @@ impl<REv> EventQueueHandle<REv> {
         REv: From<Ev>,
     {
         self.scheduler
-            .push((ancestor, event.into()), queue_kind)
+            .push((EventOrigin::new(ancestor), event.into()), queue_kind)
             .await
     }
 
@@ -301,6 +336,16 @@ pub(crate) trait Reactor: Sized {
         // Default is to ignore the failpoint. If failpoint support is enabled for a reactor, route
         // the activation to the respective components here.
     }
+
+    /// Applies a freshly loaded configuration to the running reactor.
+    ///
+    /// Returns which of the changed settings have been applied and which require a restart.
+    fn reload_config(
+        &mut self,
+        _cfg: Self::Config,
+    ) -> Result<ConfigReloadReport, ConfigReloadError> {
+        Err(ConfigReloadError::Unsupported)
+    }
 }
 
 /// A reactor event type.
@@ -377,6 +422,9 @@ where
 
     /// Flag indicating the reactor is being shut down.
     is_shutting_down: SharedFlag,
+
+    /// Loader used to re-read the reactor's configuration, if reloading is possible.
+    config_loader: Option<ConfigLoader<R::Config>>,
 }
 
 /// Metric data for the Runner
@@ -541,13 +589,35 @@ where
             event_metrics_threshold: 1000,
             clock: Clock::new(),
             is_shutting_down,
+            config_loader: None,
         })
     }
 
+    /// Sets the loader used to re-read the configuration when a reload is requested.
+    pub(crate) fn set_config_loader(&mut self, config_loader: ConfigLoader<R::Config>) {
+        self.config_loader = Some(config_loader);
+    }
+
+    /// Re-reads the configuration and applies it to the running reactor.
+    fn reload_config(&mut self) -> Result<ConfigReloadReport, ConfigReloadError> {
+        let cfg = self
+            .config_loader
+            .as_ref()
+            .ok_or(ConfigReloadError::NoConfigSource)?
+            .load()
+            .map_err(ConfigReloadError::Load)?;
+        self.reactor.reload_config(cfg)
+    }
+
     /// Processes a single event on the event queue.
     ///
     /// Returns `Some(exit_code)` if processing should stop.
-    #[instrument("dispatch", level = "debug", fields(a, ev = self.current_event_id), skip(self, rng))]
+    #[instrument(
+        "dispatch",
+        level = "debug",
+        fields(a, ev = self.current_event_id, otel.name),
+        skip(self, rng)
+    )]
     pub(crate) async fn crank(&mut self, rng: &mut NodeRng) -> Option<ExitCode> {
         self.metrics.events.inc();
 
@@ -579,7 +649,7 @@ where
             }
         }
 
-        let ((ancestor, event), queue_kind) = self.scheduler.pop().await;
+        let ((origin, event), queue_kind) = self.scheduler.pop().await;
         trace!(%event, %queue_kind, "current");
         let event_desc = event.description();
 
@@ -587,10 +657,18 @@ where
         Span::current().record("ev", self.current_event_id);
 
         // If we know the ancestor of an event, record it.
-        if let Some(ancestor) = ancestor {
+        if let Some(ancestor) = origin.ancestor {
             Span::current().record("a", ancestor.get());
         }
 
+        // If spans are exported, link to the span the event was scheduled from, e.g. the effect of
+        // a requesting component.
+        #[cfg(feature = "opentelemetry")]
+        if let Some(span) = &origin.span {
+            Span::current().record("otel.name", event_desc);
+            Span::current().follows_from(span);
+        }
+
         // Dispatch the event, then execute the resulting effect.
         let start = self.clock.start();
 
@@ -672,6 +750,10 @@ where
                     // No other effects, calling the method is all we had to do.
                     (Effects::new(), None, QueueKind::Control)
                 }
+                Some(ControlAnnouncement::ReloadConfig { responder }) => {
+                    let result = self.reload_config();
+                    (responder.respond(result).ignore(), None, QueueKind::Control)
+                }
             }
         } else {
             (
@@ -743,6 +825,14 @@ where
         loop {
             match TERMINATION_REQUESTED.load(Ordering::SeqCst) as i32 {
                 0 => {
+                    if RELOAD_REQUESTED.swap(false, Ordering::SeqCst) {
+                        match self.reload_config() {
+                            Ok(report) => info!(%report, "reloaded configuration"),
+                            Err(ref error) => {
+                                warn!(error = %display_error(error), "failed to reload configuration")
+                            }
+                        }
+                    }
                     if let Some(exit_code) = self.crank(rng).await {
                         self.is_shutting_down.set();
                         break exit_code;
@@ -863,8 +953,8 @@ where
     pub(crate) async fn drain_into_inner(self) -> R {
         self.is_shutting_down.set();
         self.scheduler.seal();
-        for (ancestor, event) in self.scheduler.drain_queues().await {
-            tracing::debug!(?ancestor, %event, "drained event");
+        for (origin, event) in self.scheduler.drain_queues().await {
+            tracing::debug!(ancestor = ?origin.ancestor, %event, "drained event");
         }
         self.reactor
     }
@@ -934,11 +1024,23 @@ async fn process_effects<Ev>(
     Ev: Send + 'static,
 {
     for effect in effects {
-        tokio::spawn(async move {
-            for event in effect.await {
-                scheduler.push((ancestor, event), queue_kind).await
+        // If spans are exported, every effect runs in its own span, a child of the span of the
+        // event that created it.
+        let span = if logging::is_span_export_enabled() {
+            debug_span!("effect", a = ancestor.map(NonZeroU64::get))
+        } else {
+            Span::none()
+        };
+        tokio::spawn(
+            async move {
+                for event in effect.await {
+                    scheduler
+                        .push((EventOrigin::new(ancestor), event), queue_kind)
+                        .await
+                }
             }
-        });
+            .instrument(span),
+        );
     }
 }
 
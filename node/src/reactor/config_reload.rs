@@ -0,0 +1,172 @@
+//! Reloading of a running reactor's configuration.
+//!
+//! A reload re-reads the configuration through a `ConfigLoader` and hands it to the reactor, which
+//! determines the changed settings and applies those it is able to change at runtime.
+
+use std::fmt::{self, Debug, Display, Formatter};
+
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::utils::DisplayIter;
+
+/// A function (re-)loading the configuration of a reactor from its original source.
+pub(crate) struct ConfigLoader<C>(Box<dyn Fn() -> anyhow::Result<C> + Send + Sync>);
+
+impl<C> ConfigLoader<C> {
+    /// Creates a new configuration loader.
+    pub(crate) fn new<F>(load: F) -> Self
+    where
+        F: Fn() -> anyhow::Result<C> + Send + Sync + 'static,
+    {
+        ConfigLoader(Box::new(load))
+    }
+
+    /// Loads the configuration.
+    pub(crate) fn load(&self) -> anyhow::Result<C> {
+        (self.0)()
+    }
+}
+
+impl<C> Debug for ConfigLoader<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConfigLoader").finish_non_exhaustive()
+    }
+}
+
+/// Error reloading the configuration.
+#[derive(Debug, Error)]
+pub(crate) enum ConfigReloadError {
+    /// The reactor does not support reloading its configuration.
+    #[error("reloading the configuration is not supported")]
+    Unsupported,
+    /// The reactor was not started from a configuration source that can be reloaded.
+    #[error("no configuration source to reload from")]
+    NoConfigSource,
+    /// Failed to load the configuration.
+    #[error("could not load configuration: {0:#}")]
+    Load(anyhow::Error),
+    /// Failed to determine the changed settings.
+    #[error("could not compare configurations")]
+    Compare(#[from] serde_json::Error),
+}
+
+/// The outcome of reloading the configuration.
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct ConfigReloadReport {
+    /// Changed settings that have been applied to the running node.
+    pub(crate) applied: Vec<String>,
+    /// Changed settings that only take effect after restarting the node.
+    pub(crate) requires_restart: Vec<String>,
+}
+
+impl ConfigReloadReport {
+    /// Returns whether or not the reloaded configuration contained any changes at all.
+    pub(crate) fn is_unchanged(&self) -> bool {
+        self.applied.is_empty() && self.requires_restart.is_empty()
+    }
+}
+
+impl Display for ConfigReloadReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_unchanged() {
+            return f.write_str("no configuration changes");
+        }
+        write!(f, "applied: [{}]", DisplayIter::new(&self.applied))?;
+        write!(
+            f,
+            ", requires restart: [{}]",
+            DisplayIter::new(&self.requires_restart)
+        )
+    }
+}
+
+/// Returns the paths of all settings differing between the two given configurations.
+///
+/// Settings are identified by the keys leading to them, joined by `.`, e.g. `network.bind_address`.
+/// Lists are compared as a whole.
+pub(crate) fn changed_settings<C: Serialize>(
+    old: &C,
+    new: &C,
+) -> Result<Vec<String>, serde_json::Error> {
+    let mut changed = Vec::new();
+    collect_changed(
+        "",
+        &serde_json::to_value(old)?,
+        &serde_json::to_value(new)?,
+        &mut changed,
+    );
+    Ok(changed)
+}
+
+fn collect_changed(path: &str, old: &Value, new: &Value, changed: &mut Vec<String>) {
+    match (old, new) {
+        (Value::Object(old_fields), Value::Object(new_fields)) => {
+            let mut keys: Vec<&String> = old_fields.keys().chain(new_fields.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let field_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match (old_fields.get(key), new_fields.get(key)) {
+                    (Some(old_value), Some(new_value)) => {
+                        collect_changed(&field_path, old_value, new_value, changed)
+                    }
+                    _ => changed.push(field_path),
+                }
+            }
+        }
+        (old, new) => {
+            if old != new {
+                changed.push(path.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+
+    use super::changed_settings;
+
+    #[derive(Clone, Default, Serialize)]
+    struct Inner {
+        rate: u32,
+        peers: Vec<String>,
+        limit: Option<u64>,
+    }
+
+    #[derive(Clone, Default, Serialize)]
+    struct Outer {
+        name: String,
+        inner: Inner,
+    }
+
+    #[test]
+    fn should_find_no_changes_in_identical_config() {
+        let config = Outer::default();
+        assert!(changed_settings(&config, &config.clone())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn should_find_changed_settings() {
+        let old = Outer::default();
+        let mut new = old.clone();
+        new.name = "changed".to_string();
+        new.inner.rate = 10;
+        new.inner.peers.push("peer".to_string());
+        new.inner.limit = Some(5);
+
+        assert_eq!(
+            changed_settings(&old, &new).unwrap(),
+            vec!["inner.limit", "inner.peers", "inner.rate", "name"]
+        );
+    }
+}
@@ -1,6 +1,7 @@
 //! Main reactor for nodes.
 
 mod config;
+mod config_reload;
 mod control;
 mod error;
 mod event;
@@ -55,9 +56,9 @@ use crate::{
         announcements::{
             BlockAccumulatorAnnouncement, ConsensusAnnouncement, ContractRuntimeAnnouncement,
             ControlAnnouncement, DeployAcceptorAnnouncement, DeployBufferAnnouncement,
-            FetchedNewBlockAnnouncement, FetchedNewFinalitySignatureAnnouncement,
-            GossiperAnnouncement, MetaBlockAnnouncement, PeerBehaviorAnnouncement,
-            UnexecutedBlockAnnouncement, UpgradeWatcherAnnouncement,
+            DeployCancellationAnnouncement, FetchedNewBlockAnnouncement,
+            FetchedNewFinalitySignatureAnnouncement, GossiperAnnouncement, MetaBlockAnnouncement,
+            PeerBehaviorAnnouncement, UnexecutedBlockAnnouncement, UpgradeWatcherAnnouncement,
         },
         incoming::{NetResponseIncoming, TrieResponseIncoming},
         requests::{AcceptDeployRequest, ChainspecRawBytesRequest},
@@ -70,11 +71,11 @@ use crate::{
         self,
         event_queue_metrics::EventQueueMetrics,
         main_reactor::{fetchers::Fetchers, upgrade_shutdown::SignatureGossipTracker},
-        EventQueueHandle, QueueKind,
+        ConfigReloadError, ConfigReloadReport, EventQueueHandle, QueueKind,
     },
     types::{
-        Block, BlockHash, Chainspec, ChainspecRawBytes, Deploy, FinalitySignature, MetaBlock,
-        MetaBlockState, SyncHandling, TrieOrChunk, ValidatorMatrix,
+        Block, BlockHash, Chainspec, ChainspecRawBytes, Deploy, DeployCancellation,
+        FinalitySignature, MetaBlock, MetaBlockState, SyncHandling, TrieOrChunk, ValidatorMatrix,
     },
     utils::{Source, WithDir},
     NodeRng,
@@ -156,6 +157,8 @@ pub(crate) struct MainReactor {
     // gossiping components
     address_gossiper: Gossiper<{ GossipedAddress::ID_IS_COMPLETE_ITEM }, GossipedAddress>,
     deploy_gossiper: Gossiper<{ Deploy::ID_IS_COMPLETE_ITEM }, Deploy>,
+    deploy_cancellation_gossiper:
+        Gossiper<{ DeployCancellation::ID_IS_COMPLETE_ITEM }, DeployCancellation>,
     block_gossiper: Gossiper<{ Block::ID_IS_COMPLETE_ITEM }, Block>,
     finality_signature_gossiper:
         Gossiper<{ FinalitySignature::ID_IS_COMPLETE_ITEM }, FinalitySignature>,
@@ -177,6 +180,7 @@ pub(crate) struct MainReactor {
     trusted_hash: Option<BlockHash>,
     chainspec: Arc<Chainspec>,
     chainspec_raw_bytes: Arc<ChainspecRawBytes>,
+    config: Config,
 
     //   control logic
     state: ReactorState,
@@ -696,6 +700,9 @@ impl reactor::Reactor for MainReactor {
                         .handle_event(effect_builder, rng, event),
                 )
             }
+            MainEvent::DeployAcceptorRequest(req) => {
+                self.dispatch_event(effect_builder, rng, MainEvent::DeployAcceptor(req.into()))
+            }
             MainEvent::DeployAcceptorAnnouncement(
                 DeployAcceptorAnnouncement::AcceptedNewDeploy { deploy, source },
             ) => {
@@ -807,6 +814,64 @@ impl reactor::Reactor for MainReactor {
                 );
                 self.dispatch_event(effect_builder, rng, reactor_event)
             }
+            MainEvent::DeployBufferAnnouncement(DeployBufferAnnouncement::DeployCancelled {
+                deploy_hash,
+                replacement,
+            }) => {
+                let reactor_event =
+                    MainEvent::EventStreamServer(event_stream_server::Event::DeployCancelled {
+                        deploy_hash,
+                        replacement,
+                    });
+                self.dispatch_event(effect_builder, rng, reactor_event)
+            }
+            MainEvent::DeployCancellationAnnouncement(DeployCancellationAnnouncement(
+                cancellation,
+            )) => {
+                // gossip onwards; a no-op if the cancellation was itself received via gossip
+                let mut effects = self.dispatch_event(
+                    effect_builder,
+                    rng,
+                    MainEvent::DeployCancellationGossiper(gossiper::Event::ItemReceived {
+                        item_id: cancellation.gossip_id(),
+                        source: Source::Ourself,
+                        target: cancellation.gossip_target(),
+                    }),
+                );
+                effects.extend(self.dispatch_event(
+                    effect_builder,
+                    rng,
+                    MainEvent::DeployBuffer(deploy_buffer::Event::ReceiveCancellation(
+                        cancellation,
+                    )),
+                ));
+                effects
+            }
+            MainEvent::DeployCancellationGossiper(event) => reactor::wrap_effects(
+                MainEvent::DeployCancellationGossiper,
+                self.deploy_cancellation_gossiper
+                    .handle_event(effect_builder, rng, event),
+            ),
+            MainEvent::DeployCancellationGossiperIncoming(incoming) => reactor::wrap_effects(
+                MainEvent::DeployCancellationGossiper,
+                self.deploy_cancellation_gossiper.handle_event(
+                    effect_builder,
+                    rng,
+                    incoming.into(),
+                ),
+            ),
+            MainEvent::DeployCancellationGossiperAnnouncement(gossiper_ann) => match gossiper_ann {
+                GossiperAnnouncement::GossipReceived { .. }
+                | GossiperAnnouncement::NewItemBody { .. }
+                | GossiperAnnouncement::FinishedGossiping(_) => Effects::new(),
+                GossiperAnnouncement::NewCompleteItem(cancellation) => {
+                    let event = deploy_acceptor::Event::AcceptCancellation {
+                        cancellation: Box::new(cancellation),
+                        maybe_responder: None,
+                    };
+                    self.dispatch_event(effect_builder, rng, MainEvent::DeployAcceptor(event))
+                }
+            },
 
             // CONTRACT RUNTIME & GLOBAL STATE
             MainEvent::ContractRuntime(event) => reactor::wrap_effects(
@@ -832,6 +897,20 @@ impl reactor::Reactor for MainReactor {
                     });
                 self.dispatch_event(effect_builder, rng, reactor_event)
             }
+            MainEvent::ContractRuntimeAnnouncement(
+                ContractRuntimeAnnouncement::ScheduledInvocationsProcessed {
+                    block_hash,
+                    execution_results,
+                },
+            ) => {
+                let reactor_event = MainEvent::EventStreamServer(
+                    event_stream_server::Event::ScheduledInvocationsProcessed {
+                        block_hash,
+                        execution_results,
+                    },
+                );
+                self.dispatch_event(effect_builder, rng, reactor_event)
+            }
             MainEvent::ContractRuntimeAnnouncement(
                 ContractRuntimeAnnouncement::UpcomingEraValidators {
                     era_that_is_ending,
@@ -1004,6 +1083,7 @@ impl reactor::Reactor for MainReactor {
 
         let trusted_hash = config.value().node.trusted_hash;
         let (root_dir, config) = config.into_parts();
+        let running_config = config.clone();
         let (our_secret_key, our_public_key) = config.consensus.load_keys(&root_dir)?;
         let validator_matrix = ValidatorMatrix::new(
             chainspec.core_config.finality_threshold_fraction,
@@ -1029,6 +1109,9 @@ impl reactor::Reactor for MainReactor {
             &chainspec.network_config.name,
             chainspec.deploy_config.max_ttl.into(),
             chainspec.core_config.recent_era_count(),
+            chainspec
+                .core_config
+                .start_protocol_version_with_aggregate_finality_signatures,
             Some(registry),
             config.node.force_resync,
         )?;
@@ -1057,9 +1140,24 @@ impl reactor::Reactor for MainReactor {
             registry,
             chainspec.core_config.administrators.clone(),
             chainspec.core_config.allow_auction_bids,
+            chainspec.core_config.delegation_rate_change_notice_eras,
             chainspec.core_config.allow_unrestricted_transfers,
             chainspec.core_config.refund_handling,
             chainspec.core_config.fee_handling,
+            chainspec
+                .core_config
+                .start_protocol_version_with_deploy_sequence_numbers,
+            chainspec
+                .core_config
+                .start_protocol_version_with_contract_migrations,
+            chainspec
+                .core_config
+                .start_protocol_version_with_scheduled_invocations,
+            chainspec
+                .core_config
+                .start_protocol_version_with_aggregate_finality_signatures,
+            chainspec.network_config.name.clone(),
+            false,
         )?;
 
         let network = Network::new(
@@ -1114,6 +1212,12 @@ impl reactor::Reactor for MainReactor {
             config.gossip,
             registry,
         )?;
+        let deploy_cancellation_gossiper =
+            Gossiper::<{ DeployCancellation::ID_IS_COMPLETE_ITEM }, _>::new(
+                "deploy_cancellation_gossiper",
+                config.gossip,
+                registry,
+            )?;
         let finality_signature_gossiper =
             Gossiper::<{ FinalitySignature::ID_IS_COMPLETE_ITEM }, _>::new(
                 "finality_signature_gossiper",
@@ -1153,12 +1257,20 @@ impl reactor::Reactor for MainReactor {
             UpgradeWatcher::new(chainspec.as_ref(), config.upgrade_watcher, &root_dir)?;
         let deploy_acceptor =
             DeployAcceptor::new(config.deploy_acceptor, chainspec.as_ref(), registry)?;
-        let deploy_buffer =
-            DeployBuffer::new(chainspec.deploy_config, config.deploy_buffer, registry)?;
+        let deploy_buffer = DeployBuffer::new(
+            chainspec.deploy_config,
+            chainspec.protocol_version()
+                >= chainspec
+                    .core_config
+                    .start_protocol_version_with_deploy_dependencies,
+            config.deploy_buffer,
+            registry,
+        )?;
 
         let reactor = MainReactor {
             chainspec,
             chainspec_raw_bytes,
+            config: running_config,
             storage,
             contract_runtime,
             upgrade_watcher,
@@ -1173,6 +1285,7 @@ impl reactor::Reactor for MainReactor {
 
             block_gossiper,
             deploy_gossiper,
+            deploy_cancellation_gossiper,
             finality_signature_gossiper,
             sync_leaper,
             deploy_buffer,
@@ -1222,6 +1335,13 @@ impl reactor::Reactor for MainReactor {
             );
         }
     }
+
+    fn reload_config(
+        &mut self,
+        cfg: Self::Config,
+    ) -> Result<ConfigReloadReport, ConfigReloadError> {
+        self.apply_config(cfg.into_parts().1)
+    }
 }
 
 impl MainReactor {
@@ -1499,12 +1619,20 @@ impl MainReactor {
             block.height(),
             block.hash(),
         );
+        let fee_breakdown = event_stream_server::total_fee_breakdown(
+            execution_results
+                .iter()
+                .map(|(_, _, execution_result)| execution_result),
+        );
         effects.extend(reactor::wrap_effects(
             MainEvent::EventStreamServer,
             self.event_stream_server.handle_event(
                 effect_builder,
                 rng,
-                event_stream_server::Event::BlockAdded(Arc::clone(&block)),
+                event_stream_server::Event::BlockAdded {
+                    block: Arc::clone(&block),
+                    fee_breakdown,
+                },
             ),
         ));
 
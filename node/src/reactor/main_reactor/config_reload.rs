@@ -0,0 +1,125 @@
+//! Applying a reloaded configuration to the running main reactor.
+
+use crate::{
+    logging,
+    reactor::{
+        self,
+        main_reactor::{Config, MainReactor},
+        ConfigReloadError, ConfigReloadReport,
+    },
+};
+
+/// Settings that can be changed on a running node, identified by their path in the config file.
+///
+/// Changes to any other setting are reported as requiring a restart.
+const HOT_RELOADABLE_SETTINGS: &[&str] = &[
+    "network.max_outgoing_byte_rate_non_validators",
+    "network.max_incoming_message_rate_non_validators",
+    "rpc_server.qps_limit",
+    "speculative_exec_server.qps_limit",
+    "event_stream_server.event_stream_buffer_length",
+    "event_stream_server.max_concurrent_subscribers",
+    "logging.format",
+    "deploy_buffer.expiry_check_interval",
+    "deploy_acceptor.timestamp_leeway",
+    "deploy_acceptor.max_rejected_deploys",
+    "block_synchronizer.need_next_interval",
+    "block_synchronizer.latch_reset_interval",
+    "block_synchronizer.disconnect_dishonest_peers_interval",
+    "node.max_attempts",
+    "node.idle_tolerance",
+    "node.control_logic_default_delay",
+];
+
+impl MainReactor {
+    /// Applies all hot-reloadable settings of `new_config` to the running components.
+    pub(super) fn apply_config(
+        &mut self,
+        new_config: Config,
+    ) -> Result<ConfigReloadReport, ConfigReloadError> {
+        let mut report = ConfigReloadReport::default();
+        for setting in reactor::changed_settings(&self.config, &new_config)? {
+            if HOT_RELOADABLE_SETTINGS.contains(&setting.as_str()) {
+                report.applied.push(setting);
+            } else {
+                report.requires_restart.push(setting);
+            }
+        }
+
+        if report.applied.is_empty() {
+            return Ok(report);
+        }
+
+        // Only take over the hot-reloadable settings, all others stay in effect until a restart.
+        let config = &mut self.config;
+        config.network.max_outgoing_byte_rate_non_validators =
+            new_config.network.max_outgoing_byte_rate_non_validators;
+        config.network.max_incoming_message_rate_non_validators =
+            new_config.network.max_incoming_message_rate_non_validators;
+        config.rpc_server.qps_limit = new_config.rpc_server.qps_limit;
+        config.speculative_exec_server.qps_limit = new_config.speculative_exec_server.qps_limit;
+        config.event_stream_server.event_stream_buffer_length =
+            new_config.event_stream_server.event_stream_buffer_length;
+        config.event_stream_server.max_concurrent_subscribers =
+            new_config.event_stream_server.max_concurrent_subscribers;
+        config.logging.format = new_config.logging.format;
+        config.deploy_buffer.expiry_check_interval = new_config.deploy_buffer.expiry_check_interval;
+        config.deploy_acceptor.timestamp_leeway = new_config.deploy_acceptor.timestamp_leeway;
+        config.deploy_acceptor.max_rejected_deploys =
+            new_config.deploy_acceptor.max_rejected_deploys;
+        config.block_synchronizer.need_next_interval =
+            new_config.block_synchronizer.need_next_interval;
+        config.block_synchronizer.latch_reset_interval =
+            new_config.block_synchronizer.latch_reset_interval;
+        config
+            .block_synchronizer
+            .disconnect_dishonest_peers_interval = new_config
+            .block_synchronizer
+            .disconnect_dishonest_peers_interval;
+        config.node.max_attempts = new_config.node.max_attempts;
+        config.node.idle_tolerance = new_config.node.idle_tolerance;
+        config.node.control_logic_default_delay = new_config.node.control_logic_default_delay;
+
+        self.net.set_non_validator_rate_limits(
+            config.network.max_outgoing_byte_rate_non_validators,
+            config.network.max_incoming_message_rate_non_validators,
+        );
+        self.rpc_server.set_qps_limits(
+            config.rpc_server.qps_limit,
+            config.speculative_exec_server.qps_limit,
+        );
+        self.event_stream_server.set_buffer_limits(
+            config.event_stream_server.event_stream_buffer_length,
+            config.event_stream_server.max_concurrent_subscribers,
+        );
+        logging::set_global_format(&config.logging.format);
+        self.deploy_buffer.set_config(config.deploy_buffer);
+        self.deploy_acceptor.set_config(config.deploy_acceptor);
+        self.block_synchronizer
+            .set_config(config.block_synchronizer);
+        self.max_attempts = config.node.max_attempts;
+        self.idle_tolerance = config.node.idle_tolerance;
+        self.control_logic_default_delay = config.node.control_logic_default_delay;
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HOT_RELOADABLE_SETTINGS;
+    use crate::reactor::main_reactor::Config;
+
+    #[test]
+    fn hot_reloadable_settings_should_exist() {
+        let config = serde_json::to_value(Config::default()).unwrap();
+        for setting in HOT_RELOADABLE_SETTINGS {
+            let pointer = format!("/{}", setting.replace('.', "/"));
+            assert!(
+                config.pointer(&pointer).is_some(),
+                "unknown setting {}",
+                setting
+            );
+        }
+    }
+}
@@ -350,6 +350,7 @@ impl MainReactor {
             post_state_hash,
             BlockHash::default(),
             Digest::default(),
+            genesis_timestamp,
         );
 
         let era_id = EraId::default();
@@ -444,6 +445,7 @@ impl MainReactor {
                         post_state_hash,
                         header.block_hash(),
                         header.accumulated_seed(),
+                        header.timestamp(),
                     );
 
                     let finalized_block = FinalizedBlock::new(
@@ -516,6 +518,7 @@ impl MainReactor {
                 *state_root_hash,
                 block_hash,
                 accumulated_seed,
+                block_header.timestamp(),
             );
         }
         Ok(())
@@ -527,6 +530,7 @@ impl MainReactor {
         pre_state_root_hash: Digest,
         parent_hash: BlockHash,
         parent_seed: Digest,
+        parent_timestamp: Timestamp,
     ) {
         // a better approach might be to have an announcement for immediate switch block
         // creation, which the contract runtime handles and sets itself into
@@ -537,6 +541,7 @@ impl MainReactor {
             pre_state_root_hash,
             parent_hash,
             parent_seed,
+            parent_timestamp,
         );
         self.contract_runtime.set_initial_state(initial_pre_state);
     }
@@ -1,7 +1,7 @@
 use thiserror::Error;
 
 use casper_execution_engine::core::engine_state;
-use casper_types::{bytesrepr, crypto::ErrorExt as CryptoError};
+use casper_types::bytesrepr;
 
 use crate::{
     components::{
@@ -60,7 +60,7 @@ pub(crate) enum Error {
 
     /// Error while loading the signing key pair.
     #[error("signing key pair load error: {0}")]
-    LoadSigningKeyPair(#[from] LoadError<CryptoError>),
+    LoadSigningKeyPair(#[from] LoadError<anyhow::Error>),
 }
 
 impl From<bytesrepr::Error> for Error {
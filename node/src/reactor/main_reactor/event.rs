@@ -22,7 +22,7 @@ use crate::{
         announcements::{
             BlockAccumulatorAnnouncement, ConsensusAnnouncement, ContractRuntimeAnnouncement,
             ControlAnnouncement, DeployAcceptorAnnouncement, DeployBufferAnnouncement,
-            FatalAnnouncement, FetchedNewBlockAnnouncement,
+            DeployCancellationAnnouncement, FatalAnnouncement, FetchedNewBlockAnnouncement,
             FetchedNewFinalitySignatureAnnouncement, GossiperAnnouncement, MetaBlockAnnouncement,
             PeerBehaviorAnnouncement, UnexecutedBlockAnnouncement, UpgradeWatcherAnnouncement,
         },
@@ -35,8 +35,8 @@ use crate::{
         requests::{
             AcceptDeployRequest, BeginGossipRequest, BlockAccumulatorRequest,
             BlockSynchronizerRequest, BlockValidationRequest, ChainspecRawBytesRequest,
-            ConsensusRequest, ContractRuntimeRequest, DeployBufferRequest, FetcherRequest,
-            MakeBlockExecutableRequest, MarkBlockCompletedRequest, MetricsRequest,
+            ConsensusRequest, ContractRuntimeRequest, DeployAcceptorRequest, DeployBufferRequest,
+            FetcherRequest, MakeBlockExecutableRequest, MarkBlockCompletedRequest, MetricsRequest,
             NetworkInfoRequest, NetworkRequest, ReactorStatusRequest, RestRequest, RpcRequest,
             SetNodeStopRequest, StorageRequest, SyncGlobalStateRequest, TrieAccumulatorRequest,
             UpgradeWatcherRequest,
@@ -46,7 +46,7 @@ use crate::{
     reactor::ReactorEvent,
     types::{
         ApprovalsHashes, Block, BlockExecutionResultsOrChunk, BlockHeader, Deploy,
-        FinalitySignature, LegacyDeploy, SyncLeap, TrieOrChunk,
+        DeployCancellation, FinalitySignature, LegacyDeploy, SyncLeap, TrieOrChunk,
     },
 };
 
@@ -187,6 +187,8 @@ pub(crate) enum MainEvent {
     #[from]
     AcceptDeployRequest(AcceptDeployRequest),
     #[from]
+    DeployAcceptorRequest(DeployAcceptorRequest),
+    #[from]
     DeployAcceptorAnnouncement(#[serde(skip_serializing)] DeployAcceptorAnnouncement),
     #[from]
     DeployGossiper(#[serde(skip_serializing)] gossiper::Event<Deploy>),
@@ -195,6 +197,16 @@ pub(crate) enum MainEvent {
     #[from]
     DeployGossiperAnnouncement(#[serde(skip_serializing)] GossiperAnnouncement<Deploy>),
     #[from]
+    DeployCancellationAnnouncement(#[serde(skip_serializing)] DeployCancellationAnnouncement),
+    #[from]
+    DeployCancellationGossiper(#[serde(skip_serializing)] gossiper::Event<DeployCancellation>),
+    #[from]
+    DeployCancellationGossiperIncoming(GossiperIncoming<DeployCancellation>),
+    #[from]
+    DeployCancellationGossiperAnnouncement(
+        #[serde(skip_serializing)] GossiperAnnouncement<DeployCancellation>,
+    ),
+    #[from]
     DeployBuffer(#[serde(skip_serializing)] deploy_buffer::Event),
     #[from]
     DeployBufferAnnouncement(#[serde(skip_serializing)] DeployBufferAnnouncement),
@@ -277,9 +289,11 @@ impl ReactorEvent for MainEvent {
             MainEvent::Consensus(_) => "Consensus",
             MainEvent::DeployAcceptor(_) => "DeployAcceptor",
             MainEvent::AcceptDeployRequest(_) => "AcceptDeployRequest",
+            MainEvent::DeployAcceptorRequest(_) => "DeployAcceptorRequest",
             MainEvent::LegacyDeployFetcher(_) => "LegacyDeployFetcher",
             MainEvent::DeployFetcher(_) => "DeployFetcher",
             MainEvent::DeployGossiper(_) => "DeployGossiper",
+            MainEvent::DeployCancellationGossiper(_) => "DeployCancellationGossiper",
             MainEvent::FinalitySignatureGossiper(_) => "FinalitySignatureGossiper",
             MainEvent::AddressGossiper(_) => "AddressGossiper",
             MainEvent::BlockValidator(_) => "BlockValidator",
@@ -320,6 +334,10 @@ impl ReactorEvent for MainEvent {
             MainEvent::ConsensusAnnouncement(_) => "ConsensusAnnouncement",
             MainEvent::ContractRuntimeAnnouncement(_) => "ContractRuntimeAnnouncement",
             MainEvent::DeployGossiperAnnouncement(_) => "DeployGossiperAnnouncement",
+            MainEvent::DeployCancellationAnnouncement(_) => "DeployCancellationAnnouncement",
+            MainEvent::DeployCancellationGossiperAnnouncement(_) => {
+                "DeployCancellationGossiperAnnouncement"
+            }
             MainEvent::AddressGossiperAnnouncement(_) => "AddressGossiperAnnouncement",
             MainEvent::UpgradeWatcherAnnouncement(_) => "UpgradeWatcherAnnouncement",
             MainEvent::NetworkPeerBehaviorAnnouncement(_) => "BlocklistAnnouncement",
@@ -331,6 +349,9 @@ impl ReactorEvent for MainEvent {
             MainEvent::ConsensusMessageIncoming(_) => "ConsensusMessageIncoming",
             MainEvent::ConsensusDemand(_) => "ConsensusDemand",
             MainEvent::DeployGossiperIncoming(_) => "DeployGossiperIncoming",
+            MainEvent::DeployCancellationGossiperIncoming(_) => {
+                "DeployCancellationGossiperIncoming"
+            }
             MainEvent::FinalitySignatureGossiperIncoming(_) => "FinalitySignatureGossiperIncoming",
             MainEvent::AddressGossiperIncoming(_) => "AddressGossiperIncoming",
             MainEvent::NetworkPeerRequestingData(_) => "NetRequestIncoming",
@@ -383,9 +404,13 @@ impl Display for MainEvent {
             MainEvent::Consensus(event) => write!(f, "consensus: {}", event),
             MainEvent::DeployAcceptor(event) => write!(f, "deploy acceptor: {}", event),
             MainEvent::AcceptDeployRequest(req) => write!(f, "{}", req),
+            MainEvent::DeployAcceptorRequest(req) => write!(f, "{}", req),
             MainEvent::LegacyDeployFetcher(event) => write!(f, "legacy deploy fetcher: {}", event),
             MainEvent::DeployFetcher(event) => write!(f, "deploy fetcher: {}", event),
             MainEvent::DeployGossiper(event) => write!(f, "deploy gossiper: {}", event),
+            MainEvent::DeployCancellationGossiper(event) => {
+                write!(f, "deploy cancellation gossiper: {}", event)
+            }
             MainEvent::FinalitySignatureGossiper(event) => {
                 write!(f, "block signature gossiper: {}", event)
             }
@@ -498,6 +523,12 @@ impl Display for MainEvent {
             MainEvent::DeployGossiperAnnouncement(ann) => {
                 write!(f, "deploy gossiper announcement: {}", ann)
             }
+            MainEvent::DeployCancellationAnnouncement(ann) => {
+                write!(f, "deploy cancellation announcement: {}", ann)
+            }
+            MainEvent::DeployCancellationGossiperAnnouncement(ann) => {
+                write!(f, "deploy cancellation gossiper announcement: {}", ann)
+            }
             MainEvent::FinalitySignatureGossiperAnnouncement(ann) => {
                 write!(f, "block signature gossiper announcement: {}", ann)
             }
@@ -519,6 +550,7 @@ impl Display for MainEvent {
             MainEvent::ConsensusMessageIncoming(inner) => Display::fmt(inner, f),
             MainEvent::ConsensusDemand(inner) => Display::fmt(inner, f),
             MainEvent::DeployGossiperIncoming(inner) => Display::fmt(inner, f),
+            MainEvent::DeployCancellationGossiperIncoming(inner) => Display::fmt(inner, f),
             MainEvent::FinalitySignatureGossiperIncoming(inner) => Display::fmt(inner, f),
             MainEvent::AddressGossiperIncoming(inner) => Display::fmt(inner, f),
             MainEvent::NetworkPeerRequestingData(inner) => Display::fmt(inner, f),
@@ -604,6 +636,12 @@ impl From<NetworkRequest<gossiper::Message<Deploy>>> for MainEvent {
     }
 }
 
+impl From<NetworkRequest<gossiper::Message<DeployCancellation>>> for MainEvent {
+    fn from(request: NetworkRequest<gossiper::Message<DeployCancellation>>) -> Self {
+        MainEvent::NetworkRequest(request.map_payload(Message::from))
+    }
+}
+
 impl From<NetworkRequest<gossiper::Message<Block>>> for MainEvent {
     fn from(request: NetworkRequest<gossiper::Message<Block>>) -> Self {
         MainEvent::NetworkRequest(request.map_payload(Message::from))
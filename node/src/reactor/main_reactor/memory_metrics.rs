@@ -19,6 +19,7 @@ pub(super) struct MemoryMetrics {
     mem_event_stream_server: IntGauge,
     mem_consensus: IntGauge,
     mem_deploy_gossiper: IntGauge,
+    mem_deploy_cancellation_gossiper: IntGauge,
     mem_finality_signature_gossiper: IntGauge,
     mem_block_gossiper: IntGauge,
     mem_deploy_buffer: IntGauge,
@@ -63,6 +64,10 @@ impl MemoryMetrics {
             "mem_deploy_gossiper",
             "deploy gossiper memory usage in bytes",
         )?;
+        let mem_deploy_cancellation_gossiper = IntGauge::new(
+            "mem_deploy_cancellation_gossiper",
+            "deploy cancellation gossiper memory usage in bytes",
+        )?;
         let mem_finality_signature_gossiper = IntGauge::new(
             "mem_finality_signature_gossiper",
             "finality signature gossiper memory usage in bytes",
@@ -118,6 +123,7 @@ impl MemoryMetrics {
         registry.register(Box::new(mem_consensus.clone()))?;
         registry.register(Box::new(mem_fetchers.clone()))?;
         registry.register(Box::new(mem_deploy_gossiper.clone()))?;
+        registry.register(Box::new(mem_deploy_cancellation_gossiper.clone()))?;
         registry.register(Box::new(mem_finality_signature_gossiper.clone()))?;
         registry.register(Box::new(mem_block_gossiper.clone()))?;
         registry.register(Box::new(mem_deploy_buffer.clone()))?;
@@ -143,6 +149,7 @@ impl MemoryMetrics {
             mem_consensus,
             mem_fetchers,
             mem_deploy_gossiper,
+            mem_deploy_cancellation_gossiper,
             mem_finality_signature_gossiper,
             mem_block_gossiper,
             mem_deploy_buffer,
@@ -173,6 +180,8 @@ impl MemoryMetrics {
         let consensus = reactor.consensus.estimate_heap_size() as i64;
         let fetchers = reactor.fetchers.estimate_heap_size() as i64;
         let deploy_gossiper = reactor.deploy_gossiper.estimate_heap_size() as i64;
+        let deploy_cancellation_gossiper =
+            reactor.deploy_cancellation_gossiper.estimate_heap_size() as i64;
         let finality_signature_gossiper =
             reactor.finality_signature_gossiper.estimate_heap_size() as i64;
         let block_gossiper = reactor.block_gossiper.estimate_heap_size() as i64;
@@ -196,6 +205,7 @@ impl MemoryMetrics {
             + consensus
             + fetchers
             + deploy_gossiper
+            + deploy_cancellation_gossiper
             + finality_signature_gossiper
             + block_gossiper
             + deploy_buffer
@@ -217,6 +227,8 @@ impl MemoryMetrics {
         self.mem_consensus.set(consensus);
         self.mem_fetchers.set(fetchers);
         self.mem_deploy_gossiper.set(deploy_gossiper);
+        self.mem_deploy_cancellation_gossiper
+            .set(deploy_cancellation_gossiper);
         self.mem_finality_signature_gossiper
             .set(finality_signature_gossiper);
         self.mem_block_gossiper.set(block_gossiper);
@@ -248,6 +260,7 @@ impl MemoryMetrics {
                %consensus,
                %fetchers,
                %deploy_gossiper,
+               %deploy_cancellation_gossiper,
                %finality_signature_gossiper,
                %block_gossiper,
                %deploy_buffer,
@@ -278,6 +291,7 @@ impl Drop for MemoryMetrics {
         unregister_metric!(self.registry, self.mem_consensus);
         unregister_metric!(self.registry, self.mem_fetchers);
         unregister_metric!(self.registry, self.mem_deploy_gossiper);
+        unregister_metric!(self.registry, self.mem_deploy_cancellation_gossiper);
         unregister_metric!(self.registry, self.mem_finality_signature_gossiper);
         unregister_metric!(self.registry, self.mem_block_gossiper);
         unregister_metric!(self.registry, self.mem_deploy_buffer);
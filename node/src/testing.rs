@@ -295,6 +295,9 @@ impl<REv: 'static> ComponentHarness<REv> {
                         panic!("currently no failpoint activations implemented in test harness")
                         // TODO: forward to component instead
                     },
+                    ControlAnnouncement::ReloadConfig { .. } => {
+                        panic!("configuration reloads are not supported in the test harness")
+                    }
                 }
             }
 
@@ -31,7 +31,10 @@ use std::{
     time::{SystemTime, UNIX_EPOCH},
 };
 
-use casper_types::file_utils::{read_file, ReadFileError};
+use casper_types::{
+    file_utils::{read_file, ReadFileError},
+    SecretKey,
+};
 use datasize::DataSize;
 use hex_fmt::HexFmt;
 use nid::Nid;
@@ -55,6 +58,8 @@ use rand::{
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::utils::key_passphrase::{key_passphrase, PassphraseError};
+
 // This is inside a private module so that the generated `BigArray` does not form part of this
 // crate's public API, and hence also doesn't appear in the rustdocs.
 mod big_array {
@@ -283,10 +288,22 @@ pub(crate) enum LoadSecretKeyError {
         #[source]
         ErrorStack,
     ),
+    #[error("unable to obtain passphrase of encrypted private key: {0}")]
+    Passphrase(
+        #[serde(skip_serializing)]
+        #[source]
+        PassphraseError,
+    ),
 }
 
+/// Loads a PEM-encoded private key, decrypting it if it is an encrypted PKCS#8 key.
 pub(crate) fn load_secret_key<P: AsRef<Path>>(src: P) -> Result<PKey<Private>, LoadSecretKeyError> {
     let pem = read_file(src.as_ref()).map_err(LoadSecretKeyError::ReadFile)?;
+    if SecretKey::is_encrypted_pem(&pem) {
+        let passphrase = key_passphrase().map_err(LoadSecretKeyError::Passphrase)?;
+        return PKey::private_key_from_pem_passphrase(&pem, &passphrase)
+            .map_err(LoadSecretKeyError::PrivateKeyFromPem);
+    }
     PKey::private_key_from_pem(&pem).map_err(LoadSecretKeyError::PrivateKeyFromPem)
 }
 
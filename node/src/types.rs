@@ -33,7 +33,7 @@ pub(crate) use block::{
 };
 pub use block::{
     json_compatibility::{JsonBlock, JsonBlockHeader},
-    Block, BlockAndDeploys, BlockBody, BlockExecutionResultsOrChunk,
+    AggregatedProof, Block, BlockAndDeploys, BlockBody, BlockExecutionResultsOrChunk,
     BlockExecutionResultsOrChunkId, BlockExecutionResultsOrChunkIdDisplay, BlockHash, BlockHeader,
     BlockSignatures, FinalitySignature, FinalizedBlock,
 };
@@ -43,12 +43,13 @@ pub(crate) use chainspec::{ActivationPoint, ChainspecRawBytes};
 pub use chunkable::Chunkable;
 pub use datasize::DataSize;
 pub use deploy::{
-    Approval, ApprovalsHash, Deploy, DeployConfigurationFailure, DeployError, DeployHash,
-    DeployHeader, DeployOrTransferHash, ExcessiveSizeError as ExcessiveSizeDeployError,
+    Approval, ApprovalsHash, Deploy, DeployCancellation, DeployConfigurationFailure, DeployError,
+    DeployHash, DeployHeader, DeployOrTransferHash, ExcessiveSizeError as ExcessiveSizeDeployError,
 };
 pub(crate) use deploy::{
     DeployFootprint, DeployHashWithApprovals, DeployId, DeployMetadata, DeployMetadataExt,
-    DeployWithFinalizedApprovals, FinalizedApprovals, LegacyDeploy,
+    DeployWithFinalizedApprovals, FinalizedApprovals, LegacyDeploy, LegacyDeployMetadata,
+    LegacyStoredDeploy,
 };
 pub use error::BlockValidationError;
 pub use exit_code::ExitCode;
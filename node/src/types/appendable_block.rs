@@ -193,6 +193,21 @@ impl AppendableBlock {
         self.timestamp
     }
 
+    /// Returns `true` if `dependency` is already in this block and will be executed before the
+    /// next deploy to be added.
+    ///
+    /// Transfers are executed after all non-transfer deploys, so a non-transfer deploy can only
+    /// depend on non-transfer deploys in the same block.
+    pub(crate) fn executes_before_next(&self, dependency: &DeployHash, is_transfer: bool) -> bool {
+        if is_transfer {
+            self.deploy_and_transfer_set.contains(dependency)
+        } else {
+            self.deploys
+                .iter()
+                .any(|deploy| deploy.deploy_hash() == dependency)
+        }
+    }
+
     /// Returns `true` if the number of transfers is already the maximum allowed count, i.e. no
     /// more transfers can be added to this block.
     fn has_max_transfer_count(&self) -> bool {
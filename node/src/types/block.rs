@@ -31,7 +31,8 @@ use tracing::error;
 use casper_hashing::{ChunkWithProofVerificationError, Digest};
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes},
-    crypto, EraId, ProtocolVersion, PublicKey, SecretKey, Signature, Timestamp, U512,
+    crypto, EraId, ProtocolVersion, PublicKey, SecretKey, Signature, Tagged, Timestamp, BLS_TAG,
+    U512,
 };
 #[cfg(any(feature = "testing", test))]
 use casper_types::{
@@ -926,6 +927,7 @@ pub(crate) mod specimen_support {
                 block_hash: LargestSpecimen::largest_specimen(estimator, cache),
                 era_id: LargestSpecimen::largest_specimen(estimator, cache),
                 proofs: btree_map_distinct_from_prop(estimator, "validator_count", cache),
+                aggregated_proof: None,
             }
         }
     }
@@ -1320,6 +1322,15 @@ impl FromBytes for BlockBody {
     }
 }
 
+/// The BLS12-381 finality signatures of a block, aggregated into a single signature.
+#[derive(Clone, Debug, PartialOrd, Ord, Hash, Serialize, Deserialize, DataSize, Eq, PartialEq)]
+pub struct AggregatedProof {
+    /// The validators whose signatures are aggregated.
+    pub(crate) signers: BTreeSet<PublicKey>,
+    /// The aggregate of the signers' signatures.
+    pub(crate) signature: Signature,
+}
+
 /// A storage representation of finality signatures with the associated block hash.
 #[derive(Clone, Debug, PartialOrd, Ord, Hash, Serialize, Deserialize, DataSize, Eq, PartialEq)]
 pub struct BlockSignatures {
@@ -1329,6 +1340,8 @@ pub struct BlockSignatures {
     pub(crate) era_id: EraId,
     /// The signatures associated with the block hash.
     pub(crate) proofs: BTreeMap<PublicKey, Signature>,
+    /// The BLS12-381 signatures associated with the block hash, aggregated into one.
+    pub(crate) aggregated_proof: Option<AggregatedProof>,
 }
 
 impl BlockSignatures {
@@ -1337,9 +1350,108 @@ impl BlockSignatures {
             block_hash,
             era_id,
             proofs: BTreeMap::new(),
+            aggregated_proof: None,
         }
     }
 
+    /// Returns `true` if there are neither individual nor aggregated signatures.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.proofs.is_empty() && self.aggregated_proof.is_none()
+    }
+
+    /// Returns the public keys of all the validators which signed, individually or as part of the
+    /// aggregated signature.
+    pub(crate) fn signers(&self) -> BTreeSet<&PublicKey> {
+        self.proofs
+            .keys()
+            .chain(
+                self.aggregated_proof
+                    .iter()
+                    .flat_map(|aggregated_proof| aggregated_proof.signers.iter()),
+            )
+            .collect()
+    }
+
+    /// Moves all the individual BLS12-381 signatures into the aggregated signature.
+    ///
+    /// Leaves `self` unchanged if any of the signatures can't be aggregated.
+    pub(crate) fn aggregate_bls_proofs(&mut self) -> Result<(), crypto::Error> {
+        let bls_proofs: Vec<(&PublicKey, &Signature)> = self
+            .proofs
+            .iter()
+            .filter(|(public_key, _)| public_key.tag() == BLS_TAG)
+            .filter(|(public_key, _)| {
+                !self
+                    .aggregated_proof
+                    .as_ref()
+                    .map_or(false, |proof| proof.signers.contains(*public_key))
+            })
+            .collect();
+        if bls_proofs.is_empty() {
+            return Ok(());
+        }
+        let signature = crypto::aggregate_signatures(
+            bls_proofs
+                .iter()
+                .map(|(_, signature)| *signature)
+                .chain(self.aggregated_proof.iter().map(|proof| &proof.signature)),
+        )?;
+        let mut signers: BTreeSet<PublicKey> = bls_proofs
+            .into_iter()
+            .map(|(public_key, _)| public_key.clone())
+            .collect();
+        for public_key in &signers {
+            self.proofs.remove(public_key);
+        }
+        if let Some(aggregated_proof) = self.aggregated_proof.take() {
+            signers.extend(aggregated_proof.signers);
+        }
+        self.aggregated_proof = Some(AggregatedProof { signers, signature });
+        Ok(())
+    }
+
+    /// Merges the aggregated signature of `other` into `self`.
+    ///
+    /// Aggregates over disjoint sets of signers are combined into one.  Otherwise, the aggregate
+    /// whose signers carry more of the given `validator_weights` is kept.
+    pub(crate) fn merge_aggregated_proof(
+        &mut self,
+        other: Option<AggregatedProof>,
+        validator_weights: &BTreeMap<PublicKey, U512>,
+    ) {
+        let other = match other {
+            Some(other) => other,
+            None => return,
+        };
+        let own = match self.aggregated_proof.take() {
+            Some(own) => own,
+            None => {
+                self.aggregated_proof = Some(other);
+                return;
+            }
+        };
+        if own.signers.is_disjoint(&other.signers) {
+            if let Ok(signature) = crypto::aggregate_signatures([&own.signature, &other.signature])
+            {
+                let signers = own.signers.union(&other.signers).cloned().collect();
+                self.aggregated_proof = Some(AggregatedProof { signers, signature });
+                return;
+            }
+        }
+        let weight = |proof: &AggregatedProof| -> U512 {
+            proof
+                .signers
+                .iter()
+                .filter_map(|public_key| validator_weights.get(public_key))
+                .fold(U512::zero(), |total, weight| total.saturating_add(*weight))
+        };
+        self.aggregated_proof = if weight(&other) > weight(&own) {
+            Some(other)
+        } else {
+            Some(own)
+        };
+    }
+
     pub(crate) fn insert_proof(
         &mut self,
         public_key: PublicKey,
@@ -1360,6 +1472,13 @@ impl BlockSignatures {
             };
             signature.is_verified()?;
         }
+        if let Some(aggregated_proof) = &self.aggregated_proof {
+            crypto::verify_aggregate(
+                FinalitySignature::bytes_to_sign(&self.block_hash, self.era_id),
+                &aggregated_proof.signature,
+                &aggregated_proof.signers,
+            )?;
+        }
         Ok(())
     }
 
@@ -1387,10 +1506,13 @@ impl Display for BlockSignatures {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         write!(
             formatter,
-            "block signatures for {} in {} with {} proofs",
+            "block signatures for {} in {} with {} proofs and {} aggregated proofs",
             self.block_hash,
             self.era_id,
-            self.proofs.len()
+            self.proofs.len(),
+            self.aggregated_proof
+                .as_ref()
+                .map_or(0, |aggregated_proof| aggregated_proof.signers.len())
         )
     }
 }
@@ -1825,7 +1947,7 @@ impl Display for BlockWithMetadata {
             "block #{}, {}, with {} block signatures",
             self.block.height(),
             self.block.hash(),
-            self.block_signatures.proofs.len()
+            self.block_signatures.signers().len()
         )
     }
 }
@@ -2433,8 +2555,7 @@ impl FinalitySignature {
         secret_key: &SecretKey,
         public_key: PublicKey,
     ) -> Self {
-        let mut bytes = block_hash.inner().into_vec();
-        bytes.extend_from_slice(&era_id.to_le_bytes());
+        let bytes = Self::bytes_to_sign(&block_hash, era_id);
         let signature = crypto::sign(bytes, secret_key, &public_key);
         FinalitySignature {
             block_hash,
@@ -2465,14 +2586,19 @@ impl FinalitySignature {
     pub fn is_verified(&self) -> Result<(), crypto::Error> {
         self.is_verified
             .get_or_init(|| {
-                // NOTE: This needs to be in sync with the `new` constructor.
-                let mut bytes = self.block_hash.inner().into_vec();
-                bytes.extend_from_slice(&self.era_id.to_le_bytes());
+                let bytes = Self::bytes_to_sign(&self.block_hash, self.era_id);
                 crypto::verify(bytes, &self.signature, &self.public_key)
             })
             .clone()
     }
 
+    /// Returns the message signed by validators to confirm the given block is finalized.
+    fn bytes_to_sign(block_hash: &BlockHash, era_id: EraId) -> Vec<u8> {
+        let mut bytes = block_hash.inner().into_vec();
+        bytes.extend_from_slice(&era_id.to_le_bytes());
+        bytes
+    }
+
     /// Returns a random `FinalitySignature` for the provided `block_hash` and `era_id`.
     #[cfg(any(feature = "testing", test))]
     pub fn random_for_block(block_hash: BlockHash, era_id: u64) -> Self {
@@ -2766,4 +2892,91 @@ mod tests {
         // Test should fail b/c `signature` is over `era_id=1` and here we're using `era_id=2`.
         assert!(fs_manufactured.is_verified().is_err());
     }
+
+    #[test]
+    fn aggregated_block_signatures() {
+        let mut rng = TestRng::new();
+        let block = Block::random(&mut rng);
+        let era_id = block.header().era_id();
+        let mut block_signatures = BlockSignatures::new(*block.hash(), era_id);
+        let add_signature = |block_signatures: &mut BlockSignatures, secret_key: SecretKey| {
+            let public_key = PublicKey::from(&secret_key);
+            let fs = FinalitySignature::create(*block.hash(), era_id, &secret_key, public_key);
+            block_signatures.insert_proof(fs.public_key, fs.signature);
+        };
+        for _ in 0..3 {
+            add_signature(&mut block_signatures, SecretKey::random_bls(&mut rng));
+        }
+        add_signature(&mut block_signatures, SecretKey::random(&mut rng));
+        assert_eq!(block_signatures.proofs.len(), 4);
+
+        block_signatures.aggregate_bls_proofs().unwrap();
+        assert_eq!(block_signatures.proofs.len(), 1);
+        assert_eq!(block_signatures.signers().len(), 4);
+        assert!(block_signatures.verify().is_ok());
+
+        // Aggregating again with a new BLS signature should keep the earlier signers.
+        add_signature(&mut block_signatures, SecretKey::random_bls(&mut rng));
+        block_signatures.aggregate_bls_proofs().unwrap();
+        assert_eq!(block_signatures.proofs.len(), 1);
+        assert_eq!(block_signatures.signers().len(), 5);
+        assert!(block_signatures.verify().is_ok());
+
+        // A signer claimed without a matching signature must fail verification.
+        let bogus_signer = PublicKey::from(&SecretKey::random_bls(&mut rng));
+        block_signatures
+            .aggregated_proof
+            .as_mut()
+            .unwrap()
+            .signers
+            .insert(bogus_signer);
+        assert!(block_signatures.verify().is_err());
+    }
+
+    #[test]
+    fn merging_aggregated_proofs_keeps_the_heavier_aggregate() {
+        let mut rng = TestRng::new();
+        let block = Block::random(&mut rng);
+        let era_id = block.header().era_id();
+        let secret_keys: Vec<SecretKey> = (0..3).map(|_| SecretKey::random_bls(&mut rng)).collect();
+        let validator_weights: BTreeMap<PublicKey, U512> = secret_keys
+            .iter()
+            .zip([1_u64, 2, 10])
+            .map(|(secret_key, weight)| (PublicKey::from(secret_key), U512::from(weight)))
+            .collect();
+        let aggregated = |signers: &[usize]| {
+            let mut block_signatures = BlockSignatures::new(*block.hash(), era_id);
+            for index in signers {
+                let secret_key = &secret_keys[*index];
+                let public_key = PublicKey::from(secret_key);
+                let fs = FinalitySignature::create(*block.hash(), era_id, secret_key, public_key);
+                block_signatures.insert_proof(fs.public_key, fs.signature);
+            }
+            block_signatures.aggregate_bls_proofs().unwrap();
+            block_signatures
+        };
+
+        // Disjoint aggregates are combined.
+        let mut block_signatures = aggregated(&[0]);
+        block_signatures
+            .merge_aggregated_proof(aggregated(&[1]).aggregated_proof, &validator_weights);
+        assert_eq!(block_signatures.signers().len(), 2);
+        assert!(block_signatures.verify().is_ok());
+
+        // Overlapping aggregates can't be combined, so the heavier one is kept regardless of the
+        // number of signers.
+        let mut block_signatures = aggregated(&[0, 1]);
+        block_signatures
+            .merge_aggregated_proof(aggregated(&[1, 2]).aggregated_proof, &validator_weights);
+        let heavier_signers: Vec<PublicKey> =
+            secret_keys[1..].iter().map(PublicKey::from).collect();
+        let expected_signers: BTreeSet<&PublicKey> = heavier_signers.iter().collect();
+        assert_eq!(block_signatures.signers(), expected_signers);
+        assert!(block_signatures.verify().is_ok());
+
+        let mut block_signatures = aggregated(&[1, 2]);
+        block_signatures
+            .merge_aggregated_proof(aggregated(&[0, 1]).aggregated_proof, &validator_weights);
+        assert_eq!(block_signatures.signers(), expected_signers);
+    }
 }
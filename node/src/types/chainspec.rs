@@ -363,6 +363,13 @@ mod tests {
             blake2b: HostFunction::new(133, [0, 1, 2, 3]),
             random_bytes: HostFunction::new(123, [0, 1]),
             enable_contract_version: HostFunction::new(142, [0, 1, 2, 3]),
+            get_block_info: HostFunction::new(143, [0, 1]),
+            call_contract_view: HostFunction::new(144, [0, 1, 2, 3, 4, 5, 6]),
+            add_contract_version_with_storage_schema: HostFunction::new(
+                145,
+                [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+            ),
+            schedule_invocation: HostFunction::new(146, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]),
         });
     static EXPECTED_GENESIS_WASM_COSTS: Lazy<WasmConfig> = Lazy::new(|| {
         WasmConfig::new(
@@ -14,7 +14,7 @@ use casper_execution_engine::core::engine_state::{genesis::AdministratorAccount,
 use casper_types::testing::TestRng;
 use casper_types::{
     bytesrepr::{self, Bytes, FromBytes, ToBytes},
-    file_utils, PublicKey,
+    crypto, file_utils, PublicKey, Signature, Tagged, BLS_TAG,
 };
 
 use super::error::ChainspecAccountsLoadError;
@@ -34,6 +34,49 @@ where
     Ok(vec)
 }
 
+/// The proofs of possession of the genesis validators' BLS12-381 secret keys in accounts.toml.
+///
+/// They are only needed to validate the file, so they are not part of the `AccountsConfig`.
+#[derive(Deserialize)]
+struct ProofsOfPossession {
+    accounts: Vec<AccountProofOfPossession>,
+}
+
+#[derive(Deserialize)]
+struct AccountProofOfPossession {
+    public_key: PublicKey,
+    validator: Option<ValidatorProofOfPossession>,
+}
+
+#[derive(Deserialize)]
+struct ValidatorProofOfPossession {
+    proof_of_possession: Option<Signature>,
+}
+
+impl ProofsOfPossession {
+    /// Verifies that every genesis validator with a BLS12-381 key has a valid proof of possession.
+    fn verify(self) -> Result<(), ChainspecAccountsLoadError> {
+        for account in self.accounts {
+            let proof_of_possession = match account.validator {
+                Some(validator) if account.public_key.tag() == BLS_TAG => {
+                    validator.proof_of_possession
+                }
+                _ => continue,
+            };
+            let proof = proof_of_possession.ok_or_else(|| {
+                ChainspecAccountsLoadError::MissingProofOfPossession(account.public_key.clone())
+            })?;
+            crypto::verify_proof_of_possession(&account.public_key, &proof).map_err(|error| {
+                ChainspecAccountsLoadError::InvalidProofOfPossession {
+                    public_key: account.public_key,
+                    error,
+                }
+            })?;
+        }
+        Ok(())
+    }
+}
+
 /// Configuration values associated with accounts.toml
 #[derive(PartialEq, Eq, Serialize, Deserialize, DataSize, Debug, Clone)]
 pub struct AccountsConfig {
@@ -106,6 +149,7 @@ impl AccountsConfig {
         }
         let bytes = file_utils::read_file(accounts_path)?;
         let config: AccountsConfig = toml::from_slice(&bytes)?;
+        toml::from_slice::<ProofsOfPossession>(&bytes)?.verify()?;
         Ok((config, Some(Bytes::from(bytes))))
     }
 
@@ -54,6 +54,26 @@ pub struct CoreConfig {
     /// `start_protocol_version_with_strict_finality_signatures_required`.
     pub legacy_required_finality: LegacyRequiredFinality,
 
+    /// Protocol version from which the BLS12-381 finality signatures of a block may be sent as a
+    /// single aggregate signature.
+    pub(crate) start_protocol_version_with_aggregate_finality_signatures: ProtocolVersion,
+
+    /// Protocol version from which deploys may carry an account sequence number.
+    pub(crate) start_protocol_version_with_deploy_sequence_numbers: ProtocolVersion,
+
+    /// Protocol version from which adding a contract version runs the `migrate` entry point of the
+    /// new version.
+    pub(crate) start_protocol_version_with_contract_migrations: ProtocolVersion,
+
+    /// Protocol version from which contract invocations may be scheduled, and due scheduled
+    /// invocations are executed at the start of each block.
+    pub(crate) start_protocol_version_with_scheduled_invocations: ProtocolVersion,
+
+    /// Protocol version from which deploys are only proposed and accepted in blocks once their
+    /// dependencies have been executed, either earlier in the same block or in a block at most
+    /// `max_ttl` older than it.
+    pub(crate) start_protocol_version_with_deploy_dependencies: ProtocolVersion,
+
     /// Number of eras before an auction actually defines the set of validators.
     /// If you bond with a sufficient bid in era N, you will be a validator in era N +
     /// auction_delay + 1
@@ -100,6 +120,9 @@ pub struct CoreConfig {
     /// `false`. Setting up this option makes sense only for private chains where validator set
     /// rotation is unnecessary.
     pub(crate) allow_auction_bids: bool,
+    /// The number of eras a validator's delegation rate increase is delayed by before it applies.
+    /// Decreases always apply immediately.
+    pub(crate) delegation_rate_change_notice_eras: u64,
     /// Allows unrestricted transfers between users.
     pub(crate) allow_unrestricted_transfers: bool,
     /// If set to false then consensus doesn't compute rewards and always uses 0.
@@ -161,6 +184,16 @@ impl CoreConfig {
             return false;
         }
 
+        if let FeeHandling::Split { burn_ratio } = self.fee_handling {
+            if *burn_ratio.denom() == 0 || burn_ratio > Ratio::new(1, 1) {
+                error!(
+                    burn_ratio = %burn_ratio,
+                    "fee handling burn ratio is not in the range [0, 1]",
+                );
+                return false;
+            }
+        }
+
         true
     }
 }
@@ -177,6 +210,16 @@ impl CoreConfig {
         let start_protocol_version_with_strict_finality_signatures_required =
             ProtocolVersion::from_parts(1, rng.gen_range(5..10), rng.gen_range(0..100));
         let legacy_required_finality = rng.gen();
+        let start_protocol_version_with_aggregate_finality_signatures =
+            ProtocolVersion::from_parts(rng.gen_range(1..3), rng.gen(), rng.gen());
+        let start_protocol_version_with_deploy_sequence_numbers =
+            ProtocolVersion::from_parts(rng.gen_range(1..3), rng.gen(), rng.gen());
+        let start_protocol_version_with_contract_migrations =
+            ProtocolVersion::from_parts(rng.gen_range(1..3), rng.gen(), rng.gen());
+        let start_protocol_version_with_scheduled_invocations =
+            ProtocolVersion::from_parts(rng.gen_range(1..3), rng.gen(), rng.gen());
+        let start_protocol_version_with_deploy_dependencies =
+            ProtocolVersion::from_parts(rng.gen_range(1..3), rng.gen(), rng.gen());
         let auction_delay = rng.gen_range(1..5);
         let locked_funds_period = TimeDiff::from_seconds(rng.gen_range(600..604_800));
         let vesting_schedule_period = TimeDiff::from_seconds(rng.gen_range(600..604_800));
@@ -193,6 +236,7 @@ impl CoreConfig {
         let simultaneous_peer_requests = rng.gen_range(3..100);
         let consensus_protocol = rng.gen();
         let allow_auction_bids = rng.gen();
+        let delegation_rate_change_notice_eras = rng.gen_range(0..10);
         let allow_unrestricted_transfers = rng.gen();
         let compute_rewards = rng.gen();
         let administrators = (0..rng.gen_range(0..=10u32))
@@ -204,10 +248,12 @@ impl CoreConfig {
             RefundHandling::Refund { refund_ratio }
         };
 
-        let fee_handling = if rng.gen() {
-            FeeHandling::PayToProposer
-        } else {
-            FeeHandling::Accumulate
+        let fee_handling = match rng.gen_range(0..3) {
+            0 => FeeHandling::PayToProposer,
+            1 => FeeHandling::Accumulate,
+            _ => FeeHandling::Split {
+                burn_ratio: Ratio::new_raw(rng.gen_range(0..=100), 100),
+            },
         };
 
         CoreConfig {
@@ -218,6 +264,11 @@ impl CoreConfig {
             finality_threshold_fraction,
             start_protocol_version_with_strict_finality_signatures_required,
             legacy_required_finality,
+            start_protocol_version_with_aggregate_finality_signatures,
+            start_protocol_version_with_deploy_sequence_numbers,
+            start_protocol_version_with_contract_migrations,
+            start_protocol_version_with_scheduled_invocations,
+            start_protocol_version_with_deploy_dependencies,
             auction_delay,
             locked_funds_period,
             vesting_schedule_period,
@@ -232,6 +283,7 @@ impl CoreConfig {
             consensus_protocol,
             max_delegators_per_validator: 0,
             allow_auction_bids,
+            delegation_rate_change_notice_eras,
             administrators,
             allow_unrestricted_transfers,
             compute_rewards,
@@ -254,6 +306,26 @@ impl ToBytes for CoreConfig {
                 .to_bytes()?,
         );
         buffer.extend(self.legacy_required_finality.to_bytes()?);
+        buffer.extend(
+            self.start_protocol_version_with_aggregate_finality_signatures
+                .to_bytes()?,
+        );
+        buffer.extend(
+            self.start_protocol_version_with_deploy_sequence_numbers
+                .to_bytes()?,
+        );
+        buffer.extend(
+            self.start_protocol_version_with_contract_migrations
+                .to_bytes()?,
+        );
+        buffer.extend(
+            self.start_protocol_version_with_scheduled_invocations
+                .to_bytes()?,
+        );
+        buffer.extend(
+            self.start_protocol_version_with_deploy_dependencies
+                .to_bytes()?,
+        );
         buffer.extend(self.auction_delay.to_bytes()?);
         buffer.extend(self.locked_funds_period.to_bytes()?);
         buffer.extend(self.vesting_schedule_period.to_bytes()?);
@@ -268,6 +340,7 @@ impl ToBytes for CoreConfig {
         buffer.extend(self.consensus_protocol.to_bytes()?);
         buffer.extend(self.max_delegators_per_validator.to_bytes()?);
         buffer.extend(self.allow_auction_bids.to_bytes()?);
+        buffer.extend(self.delegation_rate_change_notice_eras.to_bytes()?);
         buffer.extend(self.allow_unrestricted_transfers.to_bytes()?);
         buffer.extend(self.compute_rewards.to_bytes()?);
         buffer.extend(self.administrators.to_bytes()?);
@@ -286,6 +359,21 @@ impl ToBytes for CoreConfig {
                 .start_protocol_version_with_strict_finality_signatures_required
                 .serialized_length()
             + self.legacy_required_finality.serialized_length()
+            + self
+                .start_protocol_version_with_aggregate_finality_signatures
+                .serialized_length()
+            + self
+                .start_protocol_version_with_deploy_sequence_numbers
+                .serialized_length()
+            + self
+                .start_protocol_version_with_contract_migrations
+                .serialized_length()
+            + self
+                .start_protocol_version_with_scheduled_invocations
+                .serialized_length()
+            + self
+                .start_protocol_version_with_deploy_dependencies
+                .serialized_length()
             + self.auction_delay.serialized_length()
             + self.locked_funds_period.serialized_length()
             + self.vesting_schedule_period.serialized_length()
@@ -300,6 +388,7 @@ impl ToBytes for CoreConfig {
             + self.consensus_protocol.serialized_length()
             + self.max_delegators_per_validator.serialized_length()
             + self.allow_auction_bids.serialized_length()
+            + self.delegation_rate_change_notice_eras.serialized_length()
             + self.allow_unrestricted_transfers.serialized_length()
             + self.compute_rewards.serialized_length()
             + self.administrators.serialized_length()
@@ -318,6 +407,16 @@ impl FromBytes for CoreConfig {
         let (start_protocol_version_with_strict_finality_signatures_required, remainder) =
             ProtocolVersion::from_bytes(remainder)?;
         let (legacy_required_finality, remainder) = LegacyRequiredFinality::from_bytes(remainder)?;
+        let (start_protocol_version_with_aggregate_finality_signatures, remainder) =
+            ProtocolVersion::from_bytes(remainder)?;
+        let (start_protocol_version_with_deploy_sequence_numbers, remainder) =
+            ProtocolVersion::from_bytes(remainder)?;
+        let (start_protocol_version_with_contract_migrations, remainder) =
+            ProtocolVersion::from_bytes(remainder)?;
+        let (start_protocol_version_with_scheduled_invocations, remainder) =
+            ProtocolVersion::from_bytes(remainder)?;
+        let (start_protocol_version_with_deploy_dependencies, remainder) =
+            ProtocolVersion::from_bytes(remainder)?;
         let (auction_delay, remainder) = u64::from_bytes(remainder)?;
         let (locked_funds_period, remainder) = TimeDiff::from_bytes(remainder)?;
         let (vesting_schedule_period, remainder) = TimeDiff::from_bytes(remainder)?;
@@ -332,6 +431,7 @@ impl FromBytes for CoreConfig {
         let (consensus_protocol, remainder) = ConsensusProtocolName::from_bytes(remainder)?;
         let (max_delegators_per_validator, remainder) = FromBytes::from_bytes(remainder)?;
         let (allow_auction_bids, remainder) = FromBytes::from_bytes(remainder)?;
+        let (delegation_rate_change_notice_eras, remainder) = u64::from_bytes(remainder)?;
         let (allow_unrestricted_transfers, remainder) = FromBytes::from_bytes(remainder)?;
         let (compute_rewards, remainder) = bool::from_bytes(remainder)?;
         let (administrative_accounts, remainder) = FromBytes::from_bytes(remainder)?;
@@ -345,6 +445,11 @@ impl FromBytes for CoreConfig {
             finality_threshold_fraction,
             start_protocol_version_with_strict_finality_signatures_required,
             legacy_required_finality,
+            start_protocol_version_with_aggregate_finality_signatures,
+            start_protocol_version_with_deploy_sequence_numbers,
+            start_protocol_version_with_contract_migrations,
+            start_protocol_version_with_scheduled_invocations,
+            start_protocol_version_with_deploy_dependencies,
             auction_delay,
             locked_funds_period,
             vesting_schedule_period,
@@ -359,6 +464,7 @@ impl FromBytes for CoreConfig {
             consensus_protocol,
             max_delegators_per_validator,
             allow_auction_bids,
+            delegation_rate_change_notice_eras,
             allow_unrestricted_transfers,
             compute_rewards,
             administrators: administrative_accounts,
@@ -559,4 +665,28 @@ mod tests {
         config.finality_threshold_fraction = Ratio::new(u64::MAX, u64::MAX - 1);
         assert!(!config.is_valid());
     }
+
+    #[test]
+    fn should_validate_for_fee_split_burn_ratio() {
+        let mut rng = crate::new_rng();
+        let mut config = CoreConfig::random(&mut rng);
+        // Should be valid for a burn ratio in [0, 1].
+        config.fee_handling = FeeHandling::Split {
+            burn_ratio: Ratio::new(0, 1),
+        };
+        assert!(config.is_valid());
+        config.fee_handling = FeeHandling::Split {
+            burn_ratio: Ratio::new(1, 1),
+        };
+        assert!(config.is_valid());
+        // Should be invalid for a burn ratio > 1 or a zero denominator.
+        config.fee_handling = FeeHandling::Split {
+            burn_ratio: Ratio::new(u64::MAX, u64::MAX - 1),
+        };
+        assert!(!config.is_valid());
+        config.fee_handling = FeeHandling::Split {
+            burn_ratio: Ratio::new_raw(1, 0),
+        };
+        assert!(!config.is_valid());
+    }
 }
@@ -1,7 +1,7 @@
 use thiserror::Error;
 use uint::FromDecStrErr;
 
-use casper_types::file_utils::ReadFileError;
+use casper_types::{file_utils::ReadFileError, PublicKey};
 
 /// Error returned when loading the chainspec.
 #[derive(Debug, Error)]
@@ -49,6 +49,19 @@ pub enum ChainspecAccountsLoadError {
     /// Error while decoding a chainspec account's key hash from base-64 format.
     #[error("crypto module error: {0}")]
     Crypto(#[from] casper_types::crypto::ErrorExt),
+
+    /// A genesis validator's BLS12-381 key has no proof of possession.
+    #[error("missing proof of possession for genesis validator {0}")]
+    MissingProofOfPossession(PublicKey),
+
+    /// A genesis validator's BLS12-381 proof of possession is invalid.
+    #[error("invalid proof of possession for genesis validator {public_key}: {error}")]
+    InvalidProofOfPossession {
+        /// The validator's public key.
+        public_key: PublicKey,
+        /// The verification error.
+        error: casper_types::crypto::Error,
+    },
 }
 
 /// Error loading global state update file.
@@ -69,4 +82,8 @@ pub enum GlobalStateUpdateLoadError {
     /// Error while decoding a key from formatted string.
     #[error("decoding from formatted string error: {0}")]
     DecodingKeyFromStr(String),
+
+    /// A validator's BLS12-381 key lacks a valid proof of possession.
+    #[error("invalid proof of possession: {0}")]
+    InvalidProofOfPossession(String),
 }
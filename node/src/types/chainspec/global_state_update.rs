@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use casper_types::testing::TestRng;
 use casper_types::{
     bytesrepr::{self, Bytes, FromBytes, ToBytes},
-    file_utils, AsymmetricType, Key, PublicKey, U512,
+    crypto, file_utils, AsymmetricType, Key, PublicKey, Signature, Tagged, BLS_TAG, U512,
 };
 
 use super::error::GlobalStateUpdateLoadError;
@@ -28,6 +28,9 @@ pub struct GlobalStateUpdateEntry {
 pub struct GlobalStateUpdateValidatorInfo {
     public_key: String,
     weight: String,
+    /// Hex-encoded proof of possession of the secret key, required for BLS12-381 keys.
+    #[serde(default)]
+    proof_of_possession: Option<String>,
 }
 
 #[derive(PartialEq, Eq, Serialize, Deserialize, DataSize, Debug, Clone)]
@@ -107,6 +110,9 @@ impl TryFrom<GlobalStateUpdateConfig> for GlobalStateUpdate {
                         index, error
                     ))
                 })?;
+                if public_key.tag() == BLS_TAG {
+                    verify_proof_of_possession(index, &public_key, validator.proof_of_possession)?;
+                }
                 let weight = U512::from_dec_str(&validator.weight).map_err(|error| {
                     GlobalStateUpdateLoadError::DecodingKeyFromStr(format!(
                         "failed to decode validator weight {}: {}",
@@ -137,6 +143,31 @@ impl TryFrom<GlobalStateUpdateConfig> for GlobalStateUpdate {
     }
 }
 
+fn verify_proof_of_possession(
+    index: usize,
+    public_key: &PublicKey,
+    maybe_proof: Option<String>,
+) -> Result<(), GlobalStateUpdateLoadError> {
+    let proof_hex = maybe_proof.ok_or_else(|| {
+        GlobalStateUpdateLoadError::InvalidProofOfPossession(format!(
+            "validator {} has a BLS12-381 key but no proof of possession",
+            index
+        ))
+    })?;
+    let proof = Signature::from_hex(&proof_hex).map_err(|error| {
+        GlobalStateUpdateLoadError::DecodingKeyFromStr(format!(
+            "failed to decode validator proof of possession {}: {}",
+            index, error
+        ))
+    })?;
+    crypto::verify_proof_of_possession(public_key, &proof).map_err(|error| {
+        GlobalStateUpdateLoadError::InvalidProofOfPossession(format!(
+            "validator {}: {}",
+            index, error
+        ))
+    })
+}
+
 #[cfg(test)]
 impl GlobalStateUpdate {
     pub fn random(rng: &mut TestRng) -> Self {
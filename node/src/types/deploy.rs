@@ -3,6 +3,7 @@
 
 mod approval;
 mod approvals_hash;
+mod cancellation;
 mod deploy_hash;
 mod deploy_hash_with_approvals;
 mod deploy_header;
@@ -28,7 +29,7 @@ use once_cell::sync::{Lazy, OnceCell};
 #[cfg(any(feature = "testing", test))]
 use rand::{Rng, RngCore};
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
 use tracing::{debug, error, warn};
 
 #[cfg(test)]
@@ -42,10 +43,11 @@ use casper_types::bytesrepr::Bytes;
 #[cfg(any(feature = "testing", test))]
 use casper_types::testing::TestRng;
 use casper_types::{
+    account::AccountHash,
     bytesrepr::{self, FromBytes, ToBytes},
     crypto, runtime_args,
     system::standard_payment::ARG_AMOUNT,
-    PublicKey, RuntimeArgs, SecretKey, TimeDiff, Timestamp, U512,
+    Phase, PublicKey, RuntimeArgs, SecretKey, TimeDiff, Timestamp, U512,
 };
 
 use crate::{
@@ -60,9 +62,11 @@ use crate::{
 };
 pub use approval::Approval;
 pub use approvals_hash::ApprovalsHash;
+pub use cancellation::Cancellation as DeployCancellation;
 pub use deploy_hash::DeployHash;
 pub(crate) use deploy_hash_with_approvals::DeployHashWithApprovals;
 pub use deploy_header::DeployHeader;
+use deploy_header::LegacyHeader as LegacyDeployHeader;
 pub use deploy_or_transfer_hash::DeployOrTransferHash;
 pub(crate) use deploy_with_finalized_approvals::DeployWithFinalizedApprovals;
 pub use error::{DeployConfigurationFailure, Error as DeployError, ExcessiveSizeError};
@@ -70,7 +74,21 @@ pub(crate) use finalized_approvals::FinalizedApprovals;
 pub(crate) use footprint::Footprint as DeployFootprint;
 pub use id::Id as DeployId;
 pub(crate) use legacy_deploy::LegacyDeploy;
-pub(crate) use metadata::{Metadata as DeployMetadata, MetadataExt as DeployMetadataExt};
+pub(crate) use metadata::{
+    LegacyMetadata as LegacyDeployMetadata, Metadata as DeployMetadata,
+    MetadataExt as DeployMetadataExt,
+};
+
+/// Tag written before a [`Deploy`] serialized in a versioned layout, followed by the layout
+/// version.
+///
+/// Deploys without a fee payer keep the original layout, starting with the header whose first byte
+/// is a public key tag, so that their encoding is unchanged.
+const VERSIONED_DEPLOY_TAG: u8 = u8::MAX;
+
+/// Version of the versioned [`Deploy`] layout, in which the approvals are followed by the fee
+/// payer.
+const DEPLOY_LAYOUT_VERSION_1: u8 = 1;
 
 static DEPLOY: Lazy<Deploy> = Lazy::new(|| {
     let payment_args = runtime_args! {
@@ -97,9 +115,9 @@ static DEPLOY: Lazy<Deploy> = Lazy::new(|| {
         body_hash,
         vec![DeployHash::new(Digest::from([1u8; Digest::LENGTH]))],
         String::from("casper-example"),
+        None,
     );
-    let serialized_header = serialize_header(&header);
-    let hash = DeployHash::new(Digest::hash(serialized_header));
+    let hash = compute_hash(&header, None);
 
     let mut approvals = BTreeSet::new();
     let approval = Approval::create(&hash, secret_key);
@@ -111,12 +129,13 @@ static DEPLOY: Lazy<Deploy> = Lazy::new(|| {
         payment,
         session,
         approvals,
+        fee_payer: None,
         is_valid: OnceCell::new(),
     }
 });
 
 /// A deploy; an item containing a smart contract along with the requester's signature(s).
-#[derive(Clone, DataSize, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[derive(Clone, DataSize, Eq, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Deploy {
     hash: DeployHash,
@@ -124,6 +143,8 @@ pub struct Deploy {
     payment: ExecutableDeployItem,
     session: ExecutableDeployItem,
     approvals: BTreeSet<Approval>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fee_payer: Option<PublicKey>,
     #[serde(skip)]
     #[data_size(with = ds::once_cell)]
     is_valid: OnceCell<Result<(), DeployConfigurationFailure>>,
@@ -142,6 +163,99 @@ impl Deploy {
         session: ExecutableDeployItem,
         secret_key: &SecretKey,
         account: Option<PublicKey>,
+    ) -> Deploy {
+        Deploy::build(
+            timestamp,
+            ttl,
+            gas_price,
+            dependencies,
+            chain_name,
+            payment,
+            session,
+            secret_key,
+            account,
+            None,
+            None,
+        )
+    }
+
+    /// Constructs a new signed `Deploy` carrying the given account sequence number.
+    ///
+    /// The deploy will only be executed once all deploys of its account with lower sequence
+    /// numbers have been executed, and can't be executed more than once.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_sequenced(
+        timestamp: Timestamp,
+        ttl: TimeDiff,
+        gas_price: u64,
+        dependencies: Vec<DeployHash>,
+        chain_name: String,
+        payment: ExecutableDeployItem,
+        session: ExecutableDeployItem,
+        secret_key: &SecretKey,
+        account: Option<PublicKey>,
+        sequence_number: u64,
+    ) -> Deploy {
+        Deploy::build(
+            timestamp,
+            ttl,
+            gas_price,
+            dependencies,
+            chain_name,
+            payment,
+            session,
+            secret_key,
+            account,
+            None,
+            Some(sequence_number),
+        )
+    }
+
+    /// Constructs a new signed `Deploy` whose payment is funded by the main purse of the
+    /// `fee_payer` account rather than the deploy's account.
+    ///
+    /// The deploy only becomes valid once the fee payer has also signed it via [`Deploy::sign`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_sponsored(
+        timestamp: Timestamp,
+        ttl: TimeDiff,
+        gas_price: u64,
+        dependencies: Vec<DeployHash>,
+        chain_name: String,
+        payment: ExecutableDeployItem,
+        session: ExecutableDeployItem,
+        secret_key: &SecretKey,
+        account: Option<PublicKey>,
+        fee_payer: PublicKey,
+    ) -> Deploy {
+        Deploy::build(
+            timestamp,
+            ttl,
+            gas_price,
+            dependencies,
+            chain_name,
+            payment,
+            session,
+            secret_key,
+            account,
+            Some(fee_payer),
+            None,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build(
+        timestamp: Timestamp,
+        ttl: TimeDiff,
+        gas_price: u64,
+        dependencies: Vec<DeployHash>,
+        chain_name: String,
+        payment: ExecutableDeployItem,
+        session: ExecutableDeployItem,
+        secret_key: &SecretKey,
+        account: Option<PublicKey>,
+        fee_payer: Option<PublicKey>,
+        sequence_number: Option<u64>,
     ) -> Deploy {
         let serialized_body = serialize_body(&payment, &session);
         let body_hash = Digest::hash(serialized_body);
@@ -158,9 +272,9 @@ impl Deploy {
             body_hash,
             dependencies,
             chain_name,
+            sequence_number,
         );
-        let serialized_header = serialize_header(&header);
-        let hash = DeployHash::new(Digest::hash(serialized_header));
+        let hash = compute_hash(&header, fee_payer.as_ref());
 
         let mut deploy = Deploy {
             hash,
@@ -168,6 +282,7 @@ impl Deploy {
             payment,
             session,
             approvals: BTreeSet::new(),
+            fee_payer,
             is_valid: OnceCell::new(),
         };
 
@@ -216,6 +331,12 @@ impl Deploy {
         &self.approvals
     }
 
+    /// Returns the public key of the account paying for this deploy, if other than the deploy's
+    /// account.
+    pub fn fee_payer(&self) -> Option<&PublicKey> {
+        self.fee_payer.as_ref()
+    }
+
     /// Replaces the set of approvals attached to this deploy.
     pub fn replace_approvals(&mut self, approvals: BTreeSet<Approval>) {
         self.approvals = approvals;
@@ -230,6 +351,17 @@ impl Deploy {
         }
     }
 
+    /// Returns the account hashes of the signers of the deploy's approvals.
+    ///
+    /// For a deploy with a fee payer, these include the keys authorizing the fee payer, each key
+    /// authorizing whichever of the deploy's account and the fee payer it is associated with.
+    pub(crate) fn authorization_keys(&self) -> BTreeSet<AccountHash> {
+        self.approvals
+            .iter()
+            .map(|approval| approval.signer().to_account_hash())
+            .collect()
+    }
+
     pub(crate) fn with_approvals(mut self, approvals: BTreeSet<Approval>) -> Self {
         self.approvals = approvals;
         self
@@ -276,8 +408,7 @@ impl Deploy {
             return Err(DeployConfigurationFailure::InvalidBodyHash);
         }
 
-        let serialized_header = serialize_header(&self.header);
-        let hash = DeployHash::new(Digest::hash(serialized_header));
+        let hash = compute_hash(&self.header, self.fee_payer.as_ref());
         if hash != self.hash {
             warn!(?self, ?hash, "invalid deploy hash");
             return Err(DeployConfigurationFailure::InvalidDeployHash);
@@ -286,10 +417,13 @@ impl Deploy {
     }
 
     /// Returns Ok if and only if:
-    ///   * the deploy hash is correct (should be the hash of the header), and
+    ///   * the deploy hash is correct (should be the hash of the header, followed by the fee payer
+    ///     if any), and
     ///   * the body hash is correct (should be the hash of the body), and
     ///   * approvals are non empty, and
-    ///   * all approvals are valid signatures of the deploy hash
+    ///   * all approvals are valid signatures of the deploy hash, and
+    ///   * if the deploy has a fee payer, it uses standard payment, isn't a native transfer and is
+    ///     approved by the fee payer
     pub fn is_valid(&self) -> Result<(), DeployConfigurationFailure> {
         self.is_valid.get_or_init(|| validate_deploy(self)).clone()
     }
@@ -430,6 +564,7 @@ impl hash::Hash for Deploy {
             payment,
             session,
             approvals,
+            fee_payer,
             is_valid: _,
         } = self;
         hash.hash(state);
@@ -437,6 +572,7 @@ impl hash::Hash for Deploy {
         payment.hash(state);
         session.hash(state);
         approvals.hash(state);
+        fee_payer.hash(state);
     }
 }
 
@@ -449,6 +585,7 @@ impl PartialEq for Deploy {
             payment,
             session,
             approvals,
+            fee_payer,
             is_valid: _,
         } = self;
         *hash == other.hash
@@ -456,6 +593,7 @@ impl PartialEq for Deploy {
             && *payment == other.payment
             && *session == other.session
             && *approvals == other.approvals
+            && *fee_payer == other.fee_payer
     }
 }
 
@@ -468,6 +606,7 @@ impl Ord for Deploy {
             payment,
             session,
             approvals,
+            fee_payer,
             is_valid: _,
         } = self;
         hash.cmp(&other.hash)
@@ -475,6 +614,7 @@ impl Ord for Deploy {
             .then_with(|| payment.cmp(&other.payment))
             .then_with(|| session.cmp(&other.session))
             .then_with(|| approvals.cmp(&other.approvals))
+            .then_with(|| fee_payer.cmp(&other.fee_payer))
     }
 }
 
@@ -484,13 +624,52 @@ impl PartialOrd for Deploy {
     }
 }
 
+// `fee_payer` is only skipped if unset in human-readable formats: bincode, used for storage and
+// networking, can't tell a skipped field apart from the next one, so it always includes it.
+impl Serialize for Deploy {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Destructure to make sure we don't accidentally omit fields.
+        let Deploy {
+            hash,
+            header,
+            payment,
+            session,
+            approvals,
+            fee_payer,
+            is_valid: _,
+        } = self;
+        let skip_fee_payer = serializer.is_human_readable() && fee_payer.is_none();
+        let mut state =
+            serializer.serialize_struct("Deploy", if skip_fee_payer { 5 } else { 6 })?;
+        state.serialize_field("hash", hash)?;
+        state.serialize_field("header", header)?;
+        state.serialize_field("payment", payment)?;
+        state.serialize_field("session", session)?;
+        state.serialize_field("approvals", approvals)?;
+        if skip_fee_payer {
+            state.skip_field("fee_payer")?;
+        } else {
+            state.serialize_field("fee_payer", fee_payer)?;
+        }
+        state.end()
+    }
+}
+
 impl ToBytes for Deploy {
     fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        if self.fee_payer.is_some() {
+            writer.push(VERSIONED_DEPLOY_TAG);
+            writer.push(DEPLOY_LAYOUT_VERSION_1);
+        }
         self.header.write_bytes(writer)?;
         self.hash.write_bytes(writer)?;
         self.payment.write_bytes(writer)?;
         self.session.write_bytes(writer)?;
-        self.approvals.write_bytes(writer)
+        self.approvals.write_bytes(writer)?;
+        if let Some(fee_payer) = &self.fee_payer {
+            fee_payer.write_bytes(writer)?;
+        }
+        Ok(())
     }
 
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
@@ -505,22 +684,43 @@ impl ToBytes for Deploy {
             + self.payment.serialized_length()
             + self.session.serialized_length()
             + self.approvals.serialized_length()
+            + self
+                .fee_payer
+                .as_ref()
+                .map_or(0, |fee_payer| 2 + fee_payer.serialized_length())
     }
 }
 
 impl FromBytes for Deploy {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (header, remainder) = DeployHeader::from_bytes(bytes)?;
+        let (versioned, remainder) = match bytes.first() {
+            Some(&VERSIONED_DEPLOY_TAG) => {
+                let (version, remainder) = u8::from_bytes(&bytes[1..])?;
+                if version != DEPLOY_LAYOUT_VERSION_1 {
+                    return Err(bytesrepr::Error::Formatting);
+                }
+                (true, remainder)
+            }
+            _ => (false, bytes),
+        };
+        let (header, remainder) = DeployHeader::from_bytes(remainder)?;
         let (hash, remainder) = DeployHash::from_bytes(remainder)?;
         let (payment, remainder) = ExecutableDeployItem::from_bytes(remainder)?;
         let (session, remainder) = ExecutableDeployItem::from_bytes(remainder)?;
         let (approvals, remainder) = BTreeSet::<Approval>::from_bytes(remainder)?;
+        let (fee_payer, remainder) = if versioned {
+            let (fee_payer, remainder) = PublicKey::from_bytes(remainder)?;
+            (Some(fee_payer), remainder)
+        } else {
+            (None, remainder)
+        };
         let maybe_valid_deploy = Deploy {
             header,
             hash,
             payment,
             session,
             approvals,
+            fee_payer,
             is_valid: OnceCell::new(),
         };
         Ok((maybe_valid_deploy, remainder))
@@ -593,20 +793,51 @@ impl DocExample for Deploy {
 impl From<Deploy> for DeployItem {
     fn from(deploy: Deploy) -> Self {
         let address = deploy.header().account().to_account_hash();
-        let authorization_keys = deploy
-            .approvals()
-            .iter()
-            .map(|approval| approval.signer().to_account_hash())
-            .collect();
+        let authorization_keys = deploy.authorization_keys();
 
-        DeployItem::new(
+        let deploy_item = DeployItem::new(
             address,
             deploy.session().clone(),
             deploy.payment().clone(),
             deploy.header().gas_price(),
             authorization_keys,
             casper_types::DeployHash::new(deploy.hash().inner().value()),
-        )
+        );
+        let deploy_item = match deploy.fee_payer() {
+            Some(fee_payer) => deploy_item.with_fee_payer(fee_payer.to_account_hash()),
+            None => deploy_item,
+        };
+        match deploy.header().sequence_number() {
+            Some(sequence_number) => deploy_item.with_sequence_number(sequence_number),
+            None => deploy_item,
+        }
+    }
+}
+
+/// A `Deploy` as stored before its header could carry a sequence number.
+///
+/// Only used to read legacy entries from storage.
+#[derive(Deserialize)]
+pub(crate) struct LegacyStoredDeploy {
+    hash: DeployHash,
+    header: LegacyDeployHeader,
+    payment: ExecutableDeployItem,
+    session: ExecutableDeployItem,
+    approvals: BTreeSet<Approval>,
+    fee_payer: Option<PublicKey>,
+}
+
+impl From<LegacyStoredDeploy> for Deploy {
+    fn from(legacy: LegacyStoredDeploy) -> Self {
+        Deploy {
+            hash: legacy.hash,
+            header: legacy.header.into(),
+            payment: legacy.payment,
+            session: legacy.session,
+            approvals: legacy.approvals,
+            fee_payer: legacy.fee_payer,
+            is_valid: OnceCell::new(),
+        }
     }
 }
 
@@ -616,6 +847,20 @@ fn serialize_header(header: &DeployHeader) -> Vec<u8> {
         .unwrap_or_else(|error| panic!("should serialize deploy header: {}", error))
 }
 
+/// Computes the deploy hash, which commits to the fee payer as well as the header for sponsored
+/// deploys, and to the sequence number for sequenced deploys.
+fn compute_hash(header: &DeployHeader, fee_payer: Option<&PublicKey>) -> DeployHash {
+    let mut serialized = serialize_header(header);
+    if let Some(fee_payer) = fee_payer {
+        serialized.extend(
+            fee_payer
+                .to_bytes()
+                .unwrap_or_else(|error| panic!("should serialize fee payer: {}", error)),
+        );
+    }
+    DeployHash::new(Digest::hash(serialized))
+}
+
 fn serialize_body(payment: &ExecutableDeployItem, session: &ExecutableDeployItem) -> Vec<u8> {
     let mut buffer = payment
         .to_bytes()
@@ -648,6 +893,25 @@ fn validate_deploy(deploy: &Deploy) -> Result<(), DeployConfigurationFailure> {
         }
     }
 
+    if let Some(fee_payer) = deploy.fee_payer() {
+        if !deploy.payment.is_standard_payment(Phase::Payment) || deploy.session.is_transfer() {
+            warn!(
+                ?deploy,
+                "fee payer used without standard payment or for a native transfer"
+            );
+            return Err(DeployConfigurationFailure::UnsupportedFeePayer);
+        }
+
+        if !deploy
+            .approvals
+            .iter()
+            .any(|approval| approval.signer() == fee_payer)
+        {
+            warn!(?deploy, "deploy has no approval from its fee payer");
+            return Err(DeployConfigurationFailure::MissingFeePayerApproval);
+        }
+    }
+
     Ok(())
 }
 
@@ -1057,6 +1321,53 @@ impl Deploy {
             None,
         )
     }
+
+    /// Returns a random deploy with custom session specified as a stored versioned contract by
+    /// name, paid for and approved by the fee payer with the given secret key.
+    pub(crate) fn random_sponsored(rng: &mut TestRng, fee_payer_secret_key: &SecretKey) -> Self {
+        let deploy = Self::random_valid_native_transfer(rng);
+        let session = ExecutableDeployItem::StoredVersionedContractByName {
+            name: "Test".to_string(),
+            version: None,
+            entry_point: "call".to_string(),
+            args: Default::default(),
+        };
+        let secret_key = SecretKey::random(rng);
+
+        let mut sponsored_deploy = Deploy::new_sponsored(
+            deploy.header.timestamp(),
+            deploy.header.ttl(),
+            deploy.header.gas_price(),
+            deploy.header.dependencies().clone(),
+            deploy.header.chain_name().to_string(),
+            deploy.payment,
+            session,
+            &secret_key,
+            None,
+            PublicKey::from(fee_payer_secret_key),
+        );
+        sponsored_deploy.sign(fee_payer_secret_key);
+        sponsored_deploy
+    }
+
+    /// Returns a random deploy carrying the given account sequence number.
+    pub(crate) fn random_sequenced(rng: &mut TestRng, sequence_number: u64) -> Self {
+        let deploy = Self::random_valid_native_transfer(rng);
+        let secret_key = SecretKey::random(rng);
+
+        Deploy::new_sequenced(
+            deploy.header.timestamp(),
+            deploy.header.ttl(),
+            deploy.header.gas_price(),
+            deploy.header.dependencies().clone(),
+            deploy.header.chain_name().to_string(),
+            deploy.payment,
+            deploy.session,
+            &secret_key,
+            None,
+            sequence_number,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -1066,7 +1377,10 @@ mod tests {
     use casper_execution_engine::core::engine_state::MAX_PAYMENT_AMOUNT;
     use casper_types::{bytesrepr::Bytes, CLValue};
 
-    use super::*;
+    use super::{
+        deploy_header::{DEPLOY_HEADER_LAYOUT_VERSION_1, VERSIONED_DEPLOY_HEADER_TAG},
+        *,
+    };
 
     const DEFAULT_MAX_ASSOCIATED_KEYS: u32 = 100;
 
@@ -1088,6 +1402,20 @@ mod tests {
         assert_eq!(deploy, deserialized);
     }
 
+    #[test]
+    fn json_should_omit_unset_fee_payer() {
+        let mut rng = crate::new_rng();
+        let deploy = Deploy::random(&mut rng);
+        let json = serde_json::to_value(&deploy).unwrap();
+        assert!(json.get("fee_payer").is_none());
+
+        let fee_payer_secret_key = SecretKey::random(&mut rng);
+        let deploy = Deploy::random_sponsored(&mut rng, &fee_payer_secret_key);
+        let json = serde_json::to_value(&deploy).unwrap();
+        assert!(json.get("fee_payer").is_some());
+        assert_eq!(deploy, serde_json::from_value(json).unwrap());
+    }
+
     #[test]
     fn bytesrepr_roundtrip() {
         let mut rng = crate::new_rng();
@@ -1242,6 +1570,180 @@ mod tests {
         );
     }
 
+    fn create_sponsored_deploy(
+        rng: &mut TestRng,
+        session: ExecutableDeployItem,
+        fee_payer_secret_key: &SecretKey,
+    ) -> Deploy {
+        let secret_key = SecretKey::random(rng);
+        let payment = ExecutableDeployItem::ModuleBytes {
+            module_bytes: Bytes::new(),
+            args: runtime_args! {
+                ARG_AMOUNT => U512::from(MAX_PAYMENT_AMOUNT)
+            },
+        };
+        Deploy::new_sponsored(
+            Timestamp::now(),
+            DeployConfig::default().max_ttl,
+            1,
+            vec![],
+            "net-1".to_string(),
+            payment,
+            session,
+            &secret_key,
+            None,
+            PublicKey::from(fee_payer_secret_key),
+        )
+    }
+
+    #[test]
+    fn sponsored_deploy_is_valid() {
+        let mut rng = crate::new_rng();
+        let fee_payer_secret_key = SecretKey::random(&mut rng);
+        let session = ExecutableDeployItem::StoredContractByName {
+            name: "Test".to_string(),
+            entry_point: "call".to_string(),
+            args: RuntimeArgs::new(),
+        };
+        let mut deploy = create_sponsored_deploy(&mut rng, session, &fee_payer_secret_key);
+        deploy.sign(&fee_payer_secret_key);
+
+        // The fee payer is committed to by the deploy hash.
+        let mut tampered_deploy = deploy.clone();
+        tampered_deploy.fee_payer = Some(PublicKey::random(&mut rng));
+
+        deploy.is_valid().expect("should be valid");
+        check_is_not_valid(
+            tampered_deploy,
+            DeployConfigurationFailure::InvalidDeployHash,
+        );
+    }
+
+    #[test]
+    fn sponsored_deploy_bytesrepr_roundtrip() {
+        let mut rng = crate::new_rng();
+        let fee_payer_secret_key = SecretKey::random(&mut rng);
+        let mut deploy = create_sponsored_deploy(&mut rng, rng.gen(), &fee_payer_secret_key);
+        deploy.sign(&fee_payer_secret_key);
+        bytesrepr::test_serialization_roundtrip(&deploy);
+        let serialized = bincode::serialize(&deploy).unwrap();
+        assert_eq!(deploy, bincode::deserialize(&serialized).unwrap());
+
+        let bytes = deploy.to_bytes().unwrap();
+        assert_eq!(bytes[..2], [VERSIONED_DEPLOY_TAG, DEPLOY_LAYOUT_VERSION_1]);
+        let mut unknown_version_bytes = bytes;
+        unknown_version_bytes[1] = DEPLOY_LAYOUT_VERSION_1 + 1;
+        assert!(Deploy::from_bytes(&unknown_version_bytes).is_err());
+    }
+
+    #[test]
+    fn unsponsored_deploy_should_keep_original_encoding() {
+        let mut rng = crate::new_rng();
+        let deploy = Deploy::random(&mut rng);
+        let mut expected = deploy.header().to_bytes().unwrap();
+        expected.extend(deploy.hash().to_bytes().unwrap());
+        expected.extend(deploy.payment().to_bytes().unwrap());
+        expected.extend(deploy.session().to_bytes().unwrap());
+        expected.extend(deploy.approvals().to_bytes().unwrap());
+        assert_eq!(deploy.to_bytes().unwrap(), expected);
+    }
+
+    #[test]
+    fn sequenced_deploy_is_valid() {
+        let mut rng = crate::new_rng();
+        let deploy = Deploy::random_sequenced(&mut rng, 7);
+        assert_eq!(deploy.header().sequence_number(), Some(7));
+
+        // The sequence number is committed to by the deploy hash.
+        let mut tampered_deploy = deploy.clone();
+        tampered_deploy.header.sequence_number = Some(8);
+
+        deploy.is_valid().expect("should be valid");
+        check_is_not_valid(
+            tampered_deploy,
+            DeployConfigurationFailure::InvalidDeployHash,
+        );
+    }
+
+    #[test]
+    fn sequenced_deploy_bytesrepr_roundtrip() {
+        let mut rng = crate::new_rng();
+        let sequence_number = rng.gen();
+        let deploy = Deploy::random_sequenced(&mut rng, sequence_number);
+        bytesrepr::test_serialization_roundtrip(deploy.header());
+        bytesrepr::test_serialization_roundtrip(&deploy);
+        let serialized = bincode::serialize(&deploy).unwrap();
+        assert_eq!(deploy, bincode::deserialize(&serialized).unwrap());
+    }
+
+    #[test]
+    fn json_should_omit_unset_sequence_number() {
+        let mut rng = crate::new_rng();
+        let deploy = Deploy::random(&mut rng);
+        let json = serde_json::to_value(&deploy).unwrap();
+        assert!(json["header"].get("sequence_number").is_none());
+
+        let sequence_number = rng.gen();
+        let deploy = Deploy::random_sequenced(&mut rng, sequence_number);
+        let json = serde_json::to_value(&deploy).unwrap();
+        assert!(json["header"].get("sequence_number").is_some());
+        assert_eq!(deploy, serde_json::from_value(json).unwrap());
+    }
+
+    #[test]
+    fn unsequenced_deploy_hash_should_not_commit_to_sequence_number() {
+        let mut rng = crate::new_rng();
+        let deploy = Deploy::random(&mut rng);
+
+        // A header without a sequence number keeps the original layout, so the hash of a deploy
+        // created before sequence numbers were introduced is unchanged.
+        let header_bytes = deploy.header().to_bytes().unwrap();
+        assert_ne!(header_bytes[0], VERSIONED_DEPLOY_HEADER_TAG);
+        assert_eq!(*deploy.hash(), DeployHash::new(Digest::hash(header_bytes)));
+    }
+
+    #[test]
+    fn sequenced_deploy_header_should_be_versioned() {
+        let mut rng = crate::new_rng();
+        let deploy = Deploy::random_sequenced(&mut rng, rng.gen());
+
+        let bytes = deploy.header().to_bytes().unwrap();
+        assert_eq!(
+            bytes[..2],
+            [VERSIONED_DEPLOY_HEADER_TAG, DEPLOY_HEADER_LAYOUT_VERSION_1]
+        );
+        let mut unknown_version_bytes = bytes;
+        unknown_version_bytes[1] = DEPLOY_HEADER_LAYOUT_VERSION_1 + 1;
+        assert!(DeployHeader::from_bytes(&unknown_version_bytes).is_err());
+    }
+
+    #[test]
+    fn not_valid_due_to_missing_fee_payer_approval() {
+        let mut rng = crate::new_rng();
+        let fee_payer_secret_key = SecretKey::random(&mut rng);
+        let session = ExecutableDeployItem::StoredContractByName {
+            name: "Test".to_string(),
+            entry_point: "call".to_string(),
+            args: RuntimeArgs::new(),
+        };
+        let deploy = create_sponsored_deploy(&mut rng, session, &fee_payer_secret_key);
+        check_is_not_valid(deploy, DeployConfigurationFailure::MissingFeePayerApproval);
+    }
+
+    #[test]
+    fn not_valid_due_to_sponsored_transfer() {
+        let mut rng = crate::new_rng();
+        let fee_payer_secret_key = SecretKey::random(&mut rng);
+        let session = ExecutableDeployItem::Transfer {
+            args: runtime_args! {
+                ARG_AMOUNT => U512::from(MAX_PAYMENT_AMOUNT)
+            },
+        };
+        let mut deploy = create_sponsored_deploy(&mut rng, session, &fee_payer_secret_key);
+        deploy.sign(&fee_payer_secret_key);
+        check_is_not_valid(deploy, DeployConfigurationFailure::UnsupportedFeePayer);
+    }
+
     #[test]
     fn is_acceptable() {
         let mut rng = crate::new_rng();
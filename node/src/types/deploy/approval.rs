@@ -4,6 +4,7 @@ use datasize::DataSize;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use casper_hashing::Digest;
 #[cfg(any(feature = "testing", test))]
 use casper_types::testing::TestRng;
 use casper_types::{
@@ -31,6 +32,14 @@ impl Approval {
         Self { signer, signature }
     }
 
+    /// Creates an approval for the given digest, rather than a deploy hash, using the given secret
+    /// key.
+    pub(crate) fn create_for_digest(digest: &Digest, secret_key: &SecretKey) -> Self {
+        let signer = PublicKey::from(secret_key);
+        let signature = crypto::sign(digest, secret_key, &signer);
+        Self { signer, signature }
+    }
+
     /// Returns the public key of the approval's signer.
     pub fn signer(&self) -> &PublicKey {
         &self.signer
@@ -0,0 +1,286 @@
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display, Formatter},
+};
+
+use datasize::DataSize;
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
+use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
+use tracing::warn;
+
+use casper_hashing::Digest;
+use casper_types::{account::AccountHash, crypto, SecretKey};
+
+use super::{Approval, Deploy, DeployConfigurationFailure, DeployHash};
+use crate::{
+    components::gossiper::{GossipItem, SmallGossipItem},
+    effect::GossipTarget,
+    rpcs::docs::DocExample,
+};
+
+/// Prefix of the message signed by a cancellation's approvals, so that the approvals of a deploy
+/// can never be replayed as a cancellation of that same deploy.
+const SIGNING_DOMAIN: &[u8] = b"casper-deploy-cancellation";
+
+static CANCELLATION: Lazy<Cancellation> = Lazy::new(|| {
+    let deploy = Deploy::doc_example();
+    let replacement = DeployHash::new(Digest::from([2u8; Digest::LENGTH]));
+    Cancellation::new(*deploy.hash(), Some(replacement), SecretKey::doc_example())
+});
+
+/// A request, signed by the account of a deploy, to stop proposing the deploy, optionally in
+/// favor of a replacement deploy from the same account offering a higher gas price.
+///
+/// Cancellations are best-effort: they are only honored by nodes which receive them before
+/// proposing the deploy, and are not checked when validating blocks, so a cancelled deploy may
+/// still be executed.
+#[derive(Clone, DataSize, Eq, PartialEq, Hash, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+#[schemars(rename = "DeployCancellation")]
+pub struct Cancellation {
+    /// The hash of the deploy to be cancelled.
+    deploy_hash: DeployHash,
+    /// The hash of the deploy replacing the cancelled one, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    replacement: Option<DeployHash>,
+    /// The approvals of the cancellation by the deploy's account keys.
+    approvals: BTreeSet<Approval>,
+}
+
+impl Cancellation {
+    /// Creates a cancellation of the given deploy, approved using the given secret key.
+    pub fn new(
+        deploy_hash: DeployHash,
+        replacement: Option<DeployHash>,
+        secret_key: &SecretKey,
+    ) -> Self {
+        let mut cancellation = Cancellation {
+            deploy_hash,
+            replacement,
+            approvals: BTreeSet::new(),
+        };
+        cancellation.sign(secret_key);
+        cancellation
+    }
+
+    /// Adds an approval of the cancellation using the given secret key.
+    pub fn sign(&mut self, secret_key: &SecretKey) {
+        let approval = Approval::create_for_digest(&self.signing_digest(), secret_key);
+        self.approvals.insert(approval);
+    }
+
+    /// Returns the hash of the deploy to be cancelled.
+    pub fn deploy_hash(&self) -> &DeployHash {
+        &self.deploy_hash
+    }
+
+    /// Returns the hash of the deploy replacing the cancelled one, if any.
+    pub fn replacement(&self) -> Option<&DeployHash> {
+        self.replacement.as_ref()
+    }
+
+    /// Returns the approvals of the cancellation.
+    pub fn approvals(&self) -> &BTreeSet<Approval> {
+        &self.approvals
+    }
+
+    /// Returns the hashes of the keys which approved the cancellation.
+    pub(crate) fn authorization_keys(&self) -> BTreeSet<AccountHash> {
+        self.approvals
+            .iter()
+            .map(|approval| approval.signer().to_account_hash())
+            .collect()
+    }
+
+    /// Returns `Ok` if the cancellation has at least one approval, and all its approvals are
+    /// valid signatures of the cancellation.
+    pub(crate) fn is_valid(&self) -> Result<(), DeployConfigurationFailure> {
+        if self.approvals.is_empty() {
+            warn!(cancellation = %self, "deploy cancellation has no approvals");
+            return Err(DeployConfigurationFailure::EmptyApprovals);
+        }
+
+        let signing_digest = self.signing_digest();
+        for (index, approval) in self.approvals.iter().enumerate() {
+            if let Err(error) =
+                crypto::verify(signing_digest, approval.signature(), approval.signer())
+            {
+                warn!(
+                    cancellation = %self,
+                    "failed to verify approval {}: {}", index, error
+                );
+                return Err(DeployConfigurationFailure::InvalidApproval {
+                    index,
+                    error_msg: error.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the digest signed by the cancellation's approvals.
+    fn signing_digest(&self) -> Digest {
+        let mut bytes = SIGNING_DOMAIN.to_vec();
+        bytes.extend_from_slice(self.deploy_hash.as_ref());
+        if let Some(replacement) = &self.replacement {
+            bytes.extend_from_slice(replacement.as_ref());
+        }
+        Digest::hash(bytes)
+    }
+}
+
+impl Display for Cancellation {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        match &self.replacement {
+            Some(replacement) => write!(
+                formatter,
+                "cancellation of {} replaced by {}",
+                self.deploy_hash, replacement
+            ),
+            None => write!(formatter, "cancellation of {}", self.deploy_hash),
+        }
+    }
+}
+
+// `replacement` is only skipped if unset in human-readable formats, for the same reason as
+// `fee_payer` in the `Serialize` implementation of `Deploy`.
+impl Serialize for Cancellation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Destructure to make sure we don't accidentally omit fields.
+        let Cancellation {
+            deploy_hash,
+            replacement,
+            approvals,
+        } = self;
+        let skip_replacement = serializer.is_human_readable() && replacement.is_none();
+        let mut state =
+            serializer.serialize_struct("Cancellation", if skip_replacement { 2 } else { 3 })?;
+        state.serialize_field("deploy_hash", deploy_hash)?;
+        if skip_replacement {
+            state.skip_field("replacement")?;
+        } else {
+            state.serialize_field("replacement", replacement)?;
+        }
+        state.serialize_field("approvals", approvals)?;
+        state.end()
+    }
+}
+
+impl GossipItem for Cancellation {
+    const ID_IS_COMPLETE_ITEM: bool = true;
+    const REQUIRES_GOSSIP_RECEIVED_ANNOUNCEMENT: bool = false;
+
+    type Id = Cancellation;
+
+    fn gossip_id(&self) -> Self::Id {
+        self.clone()
+    }
+
+    fn gossip_target(&self) -> GossipTarget {
+        GossipTarget::All
+    }
+}
+
+impl SmallGossipItem for Cancellation {
+    fn id_as_item(id: &Self::Id) -> &Self {
+        id
+    }
+}
+
+impl DocExample for Cancellation {
+    fn doc_example() -> &'static Self {
+        &CANCELLATION
+    }
+}
+
+mod specimen_support {
+    use crate::utils::specimen::{
+        btree_set_distinct_from_prop, Cache, LargestSpecimen, SizeEstimator,
+    };
+
+    use super::Cancellation;
+
+    impl LargestSpecimen for Cancellation {
+        fn largest_specimen<E: SizeEstimator>(estimator: &E, cache: &mut Cache) -> Self {
+            Cancellation {
+                deploy_hash: LargestSpecimen::largest_specimen(estimator, cache),
+                replacement: LargestSpecimen::largest_specimen(estimator, cache),
+                approvals: btree_set_distinct_from_prop(estimator, "max_associated_keys", cache),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::testing::TestRng;
+
+    use super::*;
+
+    #[test]
+    fn should_validate_signed_cancellation() {
+        let mut rng = TestRng::new();
+        let deploy_hash = DeployHash::random(&mut rng);
+        let replacement = DeployHash::random(&mut rng);
+        let secret_key = SecretKey::random(&mut rng);
+
+        let mut cancellation = Cancellation::new(deploy_hash, Some(replacement), &secret_key);
+        assert!(cancellation.is_valid().is_ok());
+
+        cancellation.sign(&SecretKey::random(&mut rng));
+        assert!(cancellation.is_valid().is_ok());
+        assert_eq!(cancellation.authorization_keys().len(), 2);
+    }
+
+    #[test]
+    fn should_not_validate_deploy_approval_as_cancellation() {
+        let mut rng = TestRng::new();
+        let deploy_hash = DeployHash::random(&mut rng);
+        let secret_key = SecretKey::random(&mut rng);
+
+        let cancellation = Cancellation {
+            deploy_hash,
+            replacement: None,
+            approvals: BTreeSet::from([Approval::create(&deploy_hash, &secret_key)]),
+        };
+        assert!(matches!(
+            cancellation.is_valid(),
+            Err(DeployConfigurationFailure::InvalidApproval { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn should_not_validate_cancellation_with_altered_replacement() {
+        let mut rng = TestRng::new();
+        let deploy_hash = DeployHash::random(&mut rng);
+        let secret_key = SecretKey::random(&mut rng);
+
+        let mut cancellation =
+            Cancellation::new(deploy_hash, Some(DeployHash::random(&mut rng)), &secret_key);
+        cancellation.replacement = Some(DeployHash::random(&mut rng));
+        assert!(cancellation.is_valid().is_err());
+
+        cancellation.approvals.clear();
+        assert!(matches!(
+            cancellation.is_valid(),
+            Err(DeployConfigurationFailure::EmptyApprovals)
+        ));
+    }
+
+    #[test]
+    fn should_roundtrip_cancellation_without_replacement() {
+        let mut rng = TestRng::new();
+        let deploy_hash = DeployHash::random(&mut rng);
+        let secret_key = SecretKey::random(&mut rng);
+        let cancellation = Cancellation::new(deploy_hash, None, &secret_key);
+
+        let serialized = bincode::serialize(&cancellation).unwrap();
+        assert_eq!(cancellation, bincode::deserialize(&serialized).unwrap());
+
+        let json = serde_json::to_value(&cancellation).unwrap();
+        assert!(json.get("replacement").is_none());
+        assert_eq!(cancellation, serde_json::from_value(json).unwrap());
+    }
+}
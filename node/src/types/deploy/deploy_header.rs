@@ -2,7 +2,7 @@ use std::fmt::{self, Display, Formatter};
 
 use datasize::DataSize;
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
 use tracing::debug;
 
 use casper_hashing::Digest;
@@ -16,10 +16,21 @@ use super::Deploy;
 use super::{DeployConfigurationFailure, DeployHash};
 use crate::{types::chainspec::DeployConfig, utils::DisplayIter};
 
+/// Tag written before a [`DeployHeader`] serialized in a versioned layout, followed by the layout
+/// version.
+///
+/// Headers without a sequence number keep the original layout, starting with the account whose
+/// first byte is a public key tag, so that their encoding and hence their deploy hashes are
+/// unchanged.  The tag differs from the one used for versioned deploys so that the two layouts
+/// can't be confused.
+pub(super) const VERSIONED_DEPLOY_HEADER_TAG: u8 = u8::MAX - 1;
+
+/// Version of the versioned [`DeployHeader`] layout, in which the chain name is followed by the
+/// sequence number.
+pub(super) const DEPLOY_HEADER_LAYOUT_VERSION_1: u8 = 1;
+
 /// The header portion of a [`Deploy`].
-#[derive(
-    Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize, Debug, JsonSchema,
-)]
+#[derive(Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct DeployHeader {
     account: PublicKey,
@@ -29,9 +40,12 @@ pub struct DeployHeader {
     body_hash: Digest,
     dependencies: Vec<DeployHash>,
     chain_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sequence_number: Option<u64>,
 }
 
 impl DeployHeader {
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn new(
         account: PublicKey,
         timestamp: Timestamp,
@@ -40,6 +54,7 @@ impl DeployHeader {
         body_hash: Digest,
         dependencies: Vec<DeployHash>,
         chain_name: String,
+        sequence_number: Option<u64>,
     ) -> Self {
         DeployHeader {
             account,
@@ -49,6 +64,7 @@ impl DeployHeader {
             body_hash,
             dependencies,
             chain_name,
+            sequence_number,
         }
     }
 
@@ -92,6 +108,11 @@ impl DeployHeader {
         &self.chain_name
     }
 
+    /// The position of the deploy among the deploys of its account, if the deploy is sequenced.
+    pub fn sequence_number(&self) -> Option<u64> {
+        self.sequence_number
+    }
+
     /// Returns Ok if and only if the dependencies count and TTL are within limits, and the
     /// timestamp is not later than `at + timestamp_leeway`.  Does NOT check for expiry.
     pub fn is_valid(
@@ -145,15 +166,57 @@ impl DeployHeader {
     }
 }
 
+// `sequence_number` is only skipped if unset in human-readable formats, for the same reason as
+// `fee_payer` in the `Serialize` implementation of `Deploy`.
+impl Serialize for DeployHeader {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Destructure to make sure we don't accidentally omit fields.
+        let DeployHeader {
+            account,
+            timestamp,
+            ttl,
+            gas_price,
+            body_hash,
+            dependencies,
+            chain_name,
+            sequence_number,
+        } = self;
+        let skip_sequence_number = serializer.is_human_readable() && sequence_number.is_none();
+        let mut state = serializer
+            .serialize_struct("DeployHeader", if skip_sequence_number { 7 } else { 8 })?;
+        state.serialize_field("account", account)?;
+        state.serialize_field("timestamp", timestamp)?;
+        state.serialize_field("ttl", ttl)?;
+        state.serialize_field("gas_price", gas_price)?;
+        state.serialize_field("body_hash", body_hash)?;
+        state.serialize_field("dependencies", dependencies)?;
+        state.serialize_field("chain_name", chain_name)?;
+        if skip_sequence_number {
+            state.skip_field("sequence_number")?;
+        } else {
+            state.serialize_field("sequence_number", sequence_number)?;
+        }
+        state.end()
+    }
+}
+
 impl ToBytes for DeployHeader {
     fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        if self.sequence_number.is_some() {
+            writer.push(VERSIONED_DEPLOY_HEADER_TAG);
+            writer.push(DEPLOY_HEADER_LAYOUT_VERSION_1);
+        }
         self.account.write_bytes(writer)?;
         self.timestamp.write_bytes(writer)?;
         self.ttl.write_bytes(writer)?;
         self.gas_price.write_bytes(writer)?;
         self.body_hash.write_bytes(writer)?;
         self.dependencies.write_bytes(writer)?;
-        self.chain_name.write_bytes(writer)
+        self.chain_name.write_bytes(writer)?;
+        if let Some(sequence_number) = self.sequence_number {
+            sequence_number.write_bytes(writer)?;
+        }
+        Ok(())
     }
 
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
@@ -170,18 +233,37 @@ impl ToBytes for DeployHeader {
             + self.body_hash.serialized_length()
             + self.dependencies.serialized_length()
             + self.chain_name.serialized_length()
+            + self
+                .sequence_number
+                .map_or(0, |sequence_number| 2 + sequence_number.serialized_length())
     }
 }
 
 impl FromBytes for DeployHeader {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (account, remainder) = PublicKey::from_bytes(bytes)?;
+        let (versioned, remainder) = match bytes.first() {
+            Some(&VERSIONED_DEPLOY_HEADER_TAG) => {
+                let (version, remainder) = u8::from_bytes(&bytes[1..])?;
+                if version != DEPLOY_HEADER_LAYOUT_VERSION_1 {
+                    return Err(bytesrepr::Error::Formatting);
+                }
+                (true, remainder)
+            }
+            _ => (false, bytes),
+        };
+        let (account, remainder) = PublicKey::from_bytes(remainder)?;
         let (timestamp, remainder) = Timestamp::from_bytes(remainder)?;
         let (ttl, remainder) = TimeDiff::from_bytes(remainder)?;
         let (gas_price, remainder) = u64::from_bytes(remainder)?;
         let (body_hash, remainder) = Digest::from_bytes(remainder)?;
         let (dependencies, remainder) = Vec::<DeployHash>::from_bytes(remainder)?;
         let (chain_name, remainder) = String::from_bytes(remainder)?;
+        let (sequence_number, remainder) = if versioned {
+            let (sequence_number, remainder) = u64::from_bytes(remainder)?;
+            (Some(sequence_number), remainder)
+        } else {
+            (None, remainder)
+        };
         let deploy_header = DeployHeader {
             account,
             timestamp,
@@ -190,6 +272,7 @@ impl FromBytes for DeployHeader {
             body_hash,
             dependencies,
             chain_name,
+            sequence_number,
         };
         Ok((deploy_header, remainder))
     }
@@ -199,7 +282,7 @@ impl Display for DeployHeader {
     fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
         write!(
             formatter,
-            "deploy-header[account: {}, timestamp: {}, ttl: {}, gas_price: {}, body_hash: {}, dependencies: [{}], chain_name: {}]",
+            "deploy-header[account: {}, timestamp: {}, ttl: {}, gas_price: {}, body_hash: {}, dependencies: [{}], chain_name: {}, sequence_number: {:?}]",
             self.account,
             self.timestamp,
             self.ttl,
@@ -207,10 +290,40 @@ impl Display for DeployHeader {
             self.body_hash,
             DisplayIter::new(self.dependencies.iter()),
             self.chain_name,
+            self.sequence_number,
         )
     }
 }
 
+/// The header portion of a [`Deploy`] as stored before it could carry a sequence number.
+///
+/// Only used to read legacy entries from storage.
+#[derive(Deserialize)]
+pub(crate) struct LegacyHeader {
+    account: PublicKey,
+    timestamp: Timestamp,
+    ttl: TimeDiff,
+    gas_price: u64,
+    body_hash: Digest,
+    dependencies: Vec<DeployHash>,
+    chain_name: String,
+}
+
+impl From<LegacyHeader> for DeployHeader {
+    fn from(legacy: LegacyHeader) -> Self {
+        DeployHeader {
+            account: legacy.account,
+            timestamp: legacy.timestamp,
+            ttl: legacy.ttl,
+            gas_price: legacy.gas_price,
+            body_hash: legacy.body_hash,
+            dependencies: legacy.dependencies,
+            chain_name: legacy.chain_name,
+            sequence_number: None,
+        }
+    }
+}
+
 #[cfg(test)]
 impl DeployHeader {
     pub(super) fn invalidate(&mut self) {
@@ -4,7 +4,7 @@ use datasize::DataSize;
 use serde::Serialize;
 use thiserror::Error;
 
-use casper_types::{TimeDiff, Timestamp, U512};
+use casper_types::{ProtocolVersion, TimeDiff, Timestamp, U512};
 
 /// A representation of the way in which a deploy failed validation checks.
 #[derive(Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Error, Serialize)]
@@ -135,6 +135,29 @@ pub enum DeployConfigurationFailure {
         /// The chainspec limit for max_associated_keys.
         max_associated_keys: u32,
     },
+
+    /// The deploy has a fee payer but no approval from it.
+    #[error("deploy has a fee payer but is not approved by it")]
+    MissingFeePayerApproval,
+
+    /// The deploy has a fee payer but doesn't use standard payment, or is a native transfer.
+    #[error("fee payer is only supported for non-transfer deploys using standard payment")]
+    UnsupportedFeePayer,
+
+    /// The deploy has a sequence number, but sequence numbers are not yet activated.
+    #[error("deploy sequence numbers are not supported before protocol version {activation}")]
+    UnsupportedSequenceNumber {
+        /// The protocol version from which deploy sequence numbers are supported.
+        activation: ProtocolVersion,
+    },
+
+    /// The deploy's account or one of its approvals uses a BLS12-381 key, but BLS12-381 keys are
+    /// not yet activated.
+    #[error("BLS12-381 keys are not supported before protocol version {activation}")]
+    UnsupportedBlsKey {
+        /// The protocol version from which BLS12-381 keys are supported.
+        activation: ProtocolVersion,
+    },
 }
 
 /// Error returned when a Deploy is too large.
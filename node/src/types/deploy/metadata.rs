@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use casper_types::ExecutionResult;
+use casper_types::{ExecutionEffect, ExecutionResult, TransferAddr, U512};
 
 use crate::types::{BlockHash, BlockHashAndHeight};
 
@@ -17,6 +17,71 @@ pub(crate) struct Metadata {
     pub(crate) execution_results: HashMap<BlockHash, ExecutionResult>,
 }
 
+/// The deploy mutable metadata as stored before execution results recorded how the fee was split.
+///
+/// Only used to read legacy entries from storage.
+#[derive(Deserialize)]
+pub(crate) struct LegacyMetadata {
+    execution_results: HashMap<BlockHash, LegacyExecutionResult>,
+}
+
+/// Mirrors `ExecutionResult` before the `fee_breakdown` field was added.
+#[derive(Deserialize)]
+enum LegacyExecutionResult {
+    Failure {
+        effect: ExecutionEffect,
+        transfers: Vec<TransferAddr>,
+        cost: U512,
+        error_message: String,
+    },
+    Success {
+        effect: ExecutionEffect,
+        transfers: Vec<TransferAddr>,
+        cost: U512,
+    },
+}
+
+impl From<LegacyExecutionResult> for ExecutionResult {
+    fn from(legacy: LegacyExecutionResult) -> Self {
+        match legacy {
+            LegacyExecutionResult::Failure {
+                effect,
+                transfers,
+                cost,
+                error_message,
+            } => ExecutionResult::Failure {
+                effect,
+                transfers,
+                cost,
+                error_message,
+                fee_breakdown: None,
+            },
+            LegacyExecutionResult::Success {
+                effect,
+                transfers,
+                cost,
+            } => ExecutionResult::Success {
+                effect,
+                transfers,
+                cost,
+                fee_breakdown: None,
+            },
+        }
+    }
+}
+
+impl From<LegacyMetadata> for Metadata {
+    fn from(legacy: LegacyMetadata) -> Self {
+        Metadata {
+            execution_results: legacy
+                .execution_results
+                .into_iter()
+                .map(|(block_hash, execution_result)| (block_hash, execution_result.into()))
+                .collect(),
+        }
+    }
+}
+
 /// Additional information describing a deploy.
 #[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub(crate) enum MetadataExt {
@@ -10,7 +10,9 @@ use serde::{Deserialize, Serialize};
 
 use casper_hashing::Digest;
 use casper_types::{
-    system::auction::{Bid, Bids, DelegationRate, Delegator, EraValidators},
+    system::auction::{
+        Bid, Bids, DelegationRate, DelegationRateChange, Delegator, EraValidators, UnbondingPurse,
+    },
     AccessRights, EraId, PublicKey, SecretKey, URef, U512,
 };
 
@@ -93,6 +95,17 @@ pub struct JsonDelegator {
     staked_amount: U512,
     bonding_purse: URef,
     delegatee: PublicKey,
+    vesting_schedule: Option<JsonVestingSchedule>,
+}
+
+/// The schedule under which a delegator's locked stake is released.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct JsonVestingSchedule {
+    /// The time from which the stake starts to be released.
+    initial_release_timestamp_millis: u64,
+    /// The amounts remaining locked in each week of the release, once the release has started.
+    locked_amounts: Option<Vec<U512>>,
 }
 
 /// An entry in a founding validator map representing a bid.
@@ -109,6 +122,10 @@ pub struct JsonBid {
     delegators: Vec<JsonDelegator>,
     /// Is this an inactive validator.
     inactive: bool,
+    /// A delegation rate increase which has not taken effect yet.
+    pending_delegation_rate: Option<DelegationRateChange>,
+    /// The most recent delegation rate changes, oldest first.
+    delegation_rate_history: Vec<DelegationRateChange>,
 }
 
 impl From<Bid> for JsonBid {
@@ -120,6 +137,13 @@ impl From<Bid> for JsonBid {
                 staked_amount: *delegator.staked_amount(),
                 bonding_purse: *delegator.bonding_purse(),
                 delegatee: delegator.validator_public_key().clone(),
+                vesting_schedule: delegator.vesting_schedule().map(|vesting_schedule| {
+                    JsonVestingSchedule {
+                        initial_release_timestamp_millis: vesting_schedule
+                            .initial_release_timestamp_millis(),
+                        locked_amounts: vesting_schedule.locked_amounts().map(<[U512]>::to_vec),
+                    }
+                }),
             });
         }
         JsonBid {
@@ -128,6 +152,45 @@ impl From<Bid> for JsonBid {
             delegation_rate: *bid.delegation_rate(),
             delegators: json_delegators,
             inactive: bid.inactive(),
+            pending_delegation_rate: bid.pending_delegation_rate().copied(),
+            delegation_rate_history: bid.delegation_rate_history().to_vec(),
+        }
+    }
+}
+
+/// A pending unbonding or withdraw purse, along with the era in which it will be released.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct JsonUnbondingPurse {
+    /// The purse that was used for bonding.
+    bonding_purse: URef,
+    /// The public key of the validator the funds were bonded to.
+    validator_public_key: PublicKey,
+    /// The public key of the account unbonding the funds.
+    unbonder_public_key: PublicKey,
+    /// The amount being unbonded.
+    amount: U512,
+    /// The era in which the unbonding request was created.
+    era_of_creation: EraId,
+    /// The era at whose end the funds will be released, given the current unbonding delay.
+    release_era: EraId,
+    /// The validator the funds will be re-delegated to on release, if any.
+    new_validator: Option<PublicKey>,
+}
+
+impl JsonUnbondingPurse {
+    /// Creates a new `JsonUnbondingPurse`, computing its release era from `unbonding_delay`.
+    pub fn new(unbonding_purse: UnbondingPurse, unbonding_delay: u64) -> Self {
+        JsonUnbondingPurse {
+            bonding_purse: *unbonding_purse.bonding_purse(),
+            validator_public_key: unbonding_purse.validator_public_key().clone(),
+            unbonder_public_key: unbonding_purse.unbonder_public_key().clone(),
+            amount: *unbonding_purse.amount(),
+            era_of_creation: unbonding_purse.era_of_creation(),
+            release_era: unbonding_purse
+                .era_of_creation()
+                .saturating_add(unbonding_delay),
+            new_validator: unbonding_purse.new_validator().clone(),
         }
     }
 }
@@ -152,6 +215,9 @@ pub struct AuctionState {
     pub era_validators: Vec<JsonEraValidators>,
     /// All bids contained within a vector.
     bids: Vec<JsonBids>,
+    /// All pending unbonding and withdraw purses, if requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    unbonding_queue: Option<Vec<JsonUnbondingPurse>>,
 }
 
 impl AuctionState {
@@ -191,8 +257,24 @@ impl AuctionState {
             block_height,
             era_validators: json_era_validators,
             bids: json_bids,
+            unbonding_queue: None,
         }
     }
+
+    /// Sets the pending unbonding and withdraw purses, computing their release eras from
+    /// `unbonding_delay`.
+    pub fn set_unbonding_queue(
+        &mut self,
+        unbonding_purses: Vec<UnbondingPurse>,
+        unbonding_delay: u64,
+    ) {
+        self.unbonding_queue = Some(
+            unbonding_purses
+                .into_iter()
+                .map(|unbonding_purse| JsonUnbondingPurse::new(unbonding_purse, unbonding_delay))
+                .collect(),
+        );
+    }
 }
 
 impl DocExample for AuctionState {
@@ -27,6 +27,13 @@ pub struct DisabledVersion {
     contract_version: u32,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, DataSize, JsonSchema)]
+pub struct ContractStorageSchemaVersion {
+    protocol_version_major: u32,
+    contract_version: u32,
+    storage_schema_version: u32,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, DataSize, JsonSchema)]
 pub struct Groups {
     group: String,
@@ -73,6 +80,8 @@ pub struct ContractPackage {
     disabled_versions: Vec<DisabledVersion>,
     groups: Vec<Groups>,
     lock_status: ContractPackageStatus,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    storage_schema_versions: Vec<ContractStorageSchemaVersion>,
 }
 
 impl From<&DomainContractPackage> for ContractPackage {
@@ -105,12 +114,25 @@ impl From<&DomainContractPackage> for ContractPackage {
             })
             .collect();
 
+        let storage_schema_versions = contract_package
+            .storage_schema_versions()
+            .iter()
+            .map(
+                |(version_key, storage_schema_version)| ContractStorageSchemaVersion {
+                    protocol_version_major: version_key.protocol_version_major(),
+                    contract_version: version_key.contract_version(),
+                    storage_schema_version: *storage_schema_version,
+                },
+            )
+            .collect();
+
         ContractPackage {
             access_key: contract_package.access_key(),
             versions,
             disabled_versions,
             groups,
             lock_status: contract_package.get_lock_status(),
+            storage_schema_versions,
         }
     }
 }
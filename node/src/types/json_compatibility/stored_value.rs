@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 
 use casper_types::{
     bytesrepr::{self, ToBytes},
-    system::auction::{Bid, EraInfo, UnbondingPurse, WithdrawPurse},
+    system::auction::{Bid, Delegator, EraInfo, UnbondingPurse, WithdrawPurse},
     CLValue, DeployInfo, StoredValue as ExecutionEngineStoredValue, Transfer,
 };
 
@@ -45,6 +45,8 @@ pub enum StoredValue {
     Withdraw(Vec<WithdrawPurse>),
     /// A collection of unbonding purses
     Unbonding(Vec<UnbondingPurse>),
+    /// A delegator's stake in a validator
+    Delegator(Box<Delegator>),
 }
 
 impl TryFrom<ExecutionEngineStoredValue> for StoredValue {
@@ -75,6 +77,7 @@ impl TryFrom<ExecutionEngineStoredValue> for StoredValue {
             ExecutionEngineStoredValue::Unbonding(unbonding_purses) => {
                 StoredValue::Unbonding(unbonding_purses)
             }
+            ExecutionEngineStoredValue::Delegator(delegator) => StoredValue::Delegator(delegator),
         };
 
         Ok(stored_value)
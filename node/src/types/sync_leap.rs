@@ -49,6 +49,8 @@ pub(crate) enum SyncLeapValidationError {
     UnexpectedAncestorSwitchBlock,
     #[error("Signed block headers present despite trusted_ancestor_only flag.")]
     UnexpectedSignedBlockHeaders,
+    #[error("Aggregated block signatures present before their activation protocol version.")]
+    UnexpectedAggregatedSignatures,
 }
 
 /// Identifier for a SyncLeap.
@@ -310,6 +312,13 @@ impl FetchItem for SyncLeap {
         if self.trusted_ancestor_only && !self.signed_block_headers.is_empty() {
             return Err(SyncLeapValidationError::UnexpectedSignedBlockHeaders);
         }
+        if self.signed_block_headers.iter().any(|signed_header| {
+            signed_header.block_signatures.aggregated_proof.is_some()
+                && signed_header.block_header.protocol_version()
+                    < validation_metadata.start_protocol_version_with_aggregate_finality_signatures
+        }) {
+            return Err(SyncLeapValidationError::UnexpectedAggregatedSignatures);
+        }
 
         let mut headers: BTreeMap<BlockHash, &BlockHeader> = self
             .headers()
@@ -464,9 +473,9 @@ mod tests {
             chainspec::{GlobalStateUpdate, ProtocolConfig},
             sync_leap::SyncLeapValidationError,
             sync_leap_validation_metadata::SyncLeapValidationMetaData,
-            ActivationPoint, Block, BlockHash, BlockHeader, BlockHeaderWithMetadata,
-            BlockSignatures, EraValidatorWeights, FinalitySignature, FinalizedBlock,
-            SyncLeapIdentifier,
+            ActivationPoint, AggregatedProof, Block, BlockHash, BlockHeader,
+            BlockHeaderWithMetadata, BlockSignatures, EraValidatorWeights, FinalitySignature,
+            FinalizedBlock, SyncLeapIdentifier,
         },
         utils::BlockSignatureError,
     };
@@ -636,6 +645,7 @@ mod tests {
             activation_point,
             None,
             finality_threshold_fraction,
+            ProtocolVersion::V1_0_0,
         )
     }
 
@@ -938,6 +948,46 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn should_detect_unexpected_aggregated_signatures() {
+        // Chain
+        // 0   1   2   3   4   5   6   7   8   9   10   11
+        // S           S           S           S
+        let switch_blocks = [0, 3, 6, 9];
+        let validation_metadata = test_sync_leap_validation_metadata();
+
+        let mut rng = TestRng::new();
+
+        let query = 5;
+        let trusted_ancestor_headers = [4, 3];
+        let signed_block_headers = [6, 9, 11];
+        let add_proofs = true;
+        let mut sync_leap = make_test_sync_leap(
+            &mut rng,
+            &switch_blocks,
+            query,
+            &trusted_ancestor_headers,
+            &signed_block_headers,
+            add_proofs,
+        );
+
+        // The test blocks predate the protocol version activating aggregated signatures.
+        let secret_key = SecretKey::random_bls(&mut rng);
+        let public_key = PublicKey::from(&secret_key);
+        let signature = crypto::sign(rng.gen::<[u8; 32]>(), &secret_key, &public_key);
+        let signed_block_header = sync_leap.signed_block_headers.last_mut().unwrap();
+        signed_block_header.block_signatures.aggregated_proof = Some(AggregatedProof {
+            signers: iter::once(public_key).collect(),
+            signature,
+        });
+
+        let result = sync_leap.validate(&validation_metadata);
+        assert!(matches!(
+            result,
+            Err(SyncLeapValidationError::UnexpectedAggregatedSignatures)
+        ));
+    }
+
     #[test]
     fn should_detect_not_sufficiently_signed_headers() {
         // Chain
@@ -1161,6 +1211,7 @@ mod tests {
             activation_point,
             Some(global_state_update),
             finality_threshold_fraction,
+            ProtocolVersion::V1_0_0,
         );
 
         let result = sync_leap.validate(&validation_metadata);
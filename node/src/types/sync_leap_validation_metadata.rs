@@ -2,6 +2,8 @@ use datasize::DataSize;
 use num_rational::Ratio;
 use serde::Serialize;
 
+use casper_types::ProtocolVersion;
+
 use super::{chainspec::GlobalStateUpdate, ActivationPoint, Chainspec};
 
 #[derive(Clone, DataSize, Debug, Eq, PartialEq, Serialize)]
@@ -11,6 +13,7 @@ pub(crate) struct SyncLeapValidationMetaData {
     pub(crate) global_state_update: Option<GlobalStateUpdate>,
     #[data_size(skip)]
     pub(crate) finality_threshold_fraction: Ratio<u64>,
+    pub(crate) start_protocol_version_with_aggregate_finality_signatures: ProtocolVersion,
 }
 
 impl SyncLeapValidationMetaData {
@@ -20,12 +23,14 @@ impl SyncLeapValidationMetaData {
         activation_point: ActivationPoint,
         global_state_update: Option<GlobalStateUpdate>,
         finality_threshold_fraction: Ratio<u64>,
+        start_protocol_version_with_aggregate_finality_signatures: ProtocolVersion,
     ) -> Self {
         Self {
             recent_era_count,
             activation_point,
             global_state_update,
             finality_threshold_fraction,
+            start_protocol_version_with_aggregate_finality_signatures,
         }
     }
 
@@ -35,6 +40,9 @@ impl SyncLeapValidationMetaData {
             activation_point: chainspec.protocol_config.activation_point,
             global_state_update: chainspec.protocol_config.global_state_update.clone(),
             finality_threshold_fraction: chainspec.core_config.finality_threshold_fraction,
+            start_protocol_version_with_aggregate_finality_signatures: chainspec
+                .core_config
+                .start_protocol_version_with_aggregate_finality_signatures,
         }
     }
 }
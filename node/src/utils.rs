@@ -6,6 +6,7 @@ mod display_error;
 pub(crate) mod ds;
 mod external;
 pub(crate) mod fmt_limit;
+pub(crate) mod key_passphrase;
 pub(crate) mod opt_display;
 #[cfg(target_os = "linux")]
 pub(crate) mod rlimit;
@@ -44,6 +45,7 @@ pub(crate) use display_error::display_error;
 #[cfg(test)]
 pub(crate) use external::RESOURCES_PATH;
 pub use external::{External, LoadError, Loadable};
+pub use key_passphrase::take_key_passphrase_from_environment;
 pub(crate) use round_robin::WeightedRoundRobin;
 
 /// DNS resolution error.
@@ -40,7 +40,7 @@ where
     match maybe_block_signatures {
         Some(block_signatures) => {
             let mut bogus_validators = vec![];
-            for (public_key, _) in block_signatures.proofs.iter() {
+            for public_key in block_signatures.signers() {
                 match trusted_validator_weights.get(public_key) {
                     None => {
                         bogus_validators.push(public_key.clone());
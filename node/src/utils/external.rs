@@ -9,7 +9,7 @@ use std::{
     sync::Arc,
 };
 
-use casper_types::crypto;
+use anyhow::Context;
 use datasize::DataSize;
 #[cfg(test)]
 use once_cell::sync::Lazy;
@@ -25,6 +25,7 @@ use casper_types::{
     SecretKey,
 };
 
+use super::key_passphrase;
 use crate::tls::{self, LoadCertError, LoadSecretKeyError};
 
 /// Path to bundled resources.
@@ -157,6 +158,9 @@ impl Loadable for PKey<Private> {
             Err(LoadSecretKeyError::PrivateKeyFromPem(error)) => {
                 anyhow::Error::new(error).context("parsing private key")
             }
+            Err(LoadSecretKeyError::Passphrase(error)) => {
+                anyhow::Error::new(error).context("failed to decrypt private key")
+            }
         };
 
         Err(error)
@@ -164,10 +168,17 @@ impl Loadable for PKey<Private> {
 }
 
 impl Loadable for Arc<SecretKey> {
-    type Error = crypto::ErrorExt;
+    type Error = anyhow::Error;
 
     fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Self::Error> {
-        Ok(Arc::new(SecretKey::from_file(path)?))
+        let pem = read_file(path).context("failed to load secret key")?;
+        let secret_key = if SecretKey::is_encrypted_pem(&pem) {
+            let passphrase = key_passphrase::key_passphrase()?;
+            SecretKey::from_encrypted_pem(&pem, &passphrase).context("decrypting secret key")?
+        } else {
+            SecretKey::from_pem(&pem).context("parsing secret key")?
+        };
+        Ok(Arc::new(secret_key))
     }
 }
 
@@ -0,0 +1,153 @@
+//! Passphrase for decrypting encrypted secret key files.
+//!
+//! The passphrase is read once, at startup and before any other threads are spawned, from the file
+//! descriptor given by `CASPER_KEY_PASSPHRASE_FD` if set, or else from the `CASPER_KEY_PASSPHRASE`
+//! environment variable.  Both variables are then removed from the environment of the process.
+
+use std::{
+    env,
+    fs::File,
+    io::Read,
+    os::unix::{
+        ffi::OsStringExt,
+        io::{FromRawFd, RawFd},
+    },
+};
+
+use once_cell::sync::OnceCell;
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+/// Environment variable holding the passphrase of encrypted secret key files.
+pub(crate) const KEY_PASSPHRASE_ENV_VAR: &str = "CASPER_KEY_PASSPHRASE";
+/// Environment variable holding the number of an open file descriptor from which the passphrase
+/// of encrypted secret key files is read.
+pub(crate) const KEY_PASSPHRASE_FD_ENV_VAR: &str = "CASPER_KEY_PASSPHRASE_FD";
+
+/// The passphrase, cached since a file descriptor can only be read once.
+static KEY_PASSPHRASE: OnceCell<Result<Option<Zeroizing<Vec<u8>>>, PassphraseError>> =
+    OnceCell::new();
+
+/// Error obtaining the passphrase of encrypted secret key files.
+#[derive(Clone, Debug, Error)]
+pub enum PassphraseError {
+    /// Neither environment variable is set.
+    #[error(
+        "secret key is encrypted, but neither {} nor {} is set",
+        KEY_PASSPHRASE_FD_ENV_VAR,
+        KEY_PASSPHRASE_ENV_VAR
+    )]
+    Missing,
+    /// The file descriptor environment variable is not a valid file descriptor.
+    #[error("{}={0} is not a valid file descriptor", KEY_PASSPHRASE_FD_ENV_VAR)]
+    InvalidFd(String),
+    /// The file descriptor is a standard stream, not open or not open for reading.
+    #[error(
+        "{}={0} is not a file descriptor open for reading other than stdout or stderr",
+        KEY_PASSPHRASE_FD_ENV_VAR
+    )]
+    UnreadableFd(RawFd),
+    /// Reading from the file descriptor failed.
+    #[error("could not read passphrase from file descriptor {fd}: {error}")]
+    ReadFd {
+        /// The file descriptor.
+        fd: RawFd,
+        /// The underlying I/O error.
+        error: String,
+    },
+}
+
+/// Reads the passphrase of encrypted secret key files and clears it from the environment.
+///
+/// Must be called from `main` before the runtime or any other thread is started, as modifying the
+/// environment is not thread-safe.  Calling it again has no effect.
+pub fn take_key_passphrase_from_environment() {
+    KEY_PASSPHRASE.get_or_init(read_key_passphrase);
+    env::remove_var(KEY_PASSPHRASE_FD_ENV_VAR);
+    env::remove_var(KEY_PASSPHRASE_ENV_VAR);
+}
+
+/// Returns the passphrase of encrypted secret key files.
+pub(crate) fn key_passphrase() -> Result<Zeroizing<Vec<u8>>, PassphraseError> {
+    match KEY_PASSPHRASE.get() {
+        Some(Ok(Some(passphrase))) => Ok(passphrase.clone()),
+        Some(Ok(None)) | None => Err(PassphraseError::Missing),
+        Some(Err(error)) => Err(error.clone()),
+    }
+}
+
+fn read_key_passphrase() -> Result<Option<Zeroizing<Vec<u8>>>, PassphraseError> {
+    if let Some(fd) = env::var_os(KEY_PASSPHRASE_FD_ENV_VAR) {
+        let fd = fd.to_string_lossy();
+        let fd: RawFd = fd
+            .parse()
+            .ok()
+            .filter(|fd| *fd >= 0)
+            .ok_or_else(|| PassphraseError::InvalidFd(fd.to_string()))?;
+        if !is_readable_fd(fd) {
+            return Err(PassphraseError::UnreadableFd(fd));
+        }
+        // SAFETY: the file descriptor was checked above to be open for reading, and is handed to
+        // us by the parent process for the sole purpose of reading the passphrase.  Taking
+        // ownership closes it once read.
+        let mut file = unsafe { File::from_raw_fd(fd) };
+        let mut passphrase = Zeroizing::new(Vec::new());
+        file.read_to_end(&mut passphrase)
+            .map_err(|error| PassphraseError::ReadFd {
+                fd,
+                error: error.to_string(),
+            })?;
+        trim_trailing_newline(&mut passphrase);
+        return Ok(Some(passphrase));
+    }
+
+    Ok(env::var_os(KEY_PASSPHRASE_ENV_VAR).map(|passphrase| Zeroizing::new(passphrase.into_vec())))
+}
+
+/// Returns `true` if `fd` is open for reading and is not stdout or stderr.
+fn is_readable_fd(fd: RawFd) -> bool {
+    if fd == libc::STDOUT_FILENO || fd == libc::STDERR_FILENO {
+        return false;
+    }
+    // SAFETY: `F_GETFL` only queries the file status flags, and fails with `EBADF` if `fd` is not
+    // an open file descriptor.
+    let flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+    flags != -1 && flags & libc::O_ACCMODE != libc::O_WRONLY
+}
+
+/// Strips a single trailing line ending, as left by e.g. `echo` or a text editor.
+fn trim_trailing_newline(passphrase: &mut Vec<u8>) {
+    if passphrase.last() == Some(&b'\n') {
+        passphrase.pop();
+        if passphrase.last() == Some(&b'\r') {
+            passphrase.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_readable_fd, trim_trailing_newline};
+
+    fn trimmed(passphrase: &[u8]) -> Vec<u8> {
+        let mut passphrase = passphrase.to_vec();
+        trim_trailing_newline(&mut passphrase);
+        passphrase
+    }
+
+    #[test]
+    fn should_trim_single_trailing_newline() {
+        assert_eq!(trimmed(b"secret"), b"secret");
+        assert_eq!(trimmed(b"secret\n"), b"secret");
+        assert_eq!(trimmed(b"secret\r\n"), b"secret");
+        assert_eq!(trimmed(b"secret\n\n"), b"secret\n");
+        assert_eq!(trimmed(b"secret\r"), b"secret\r");
+    }
+
+    #[test]
+    fn should_reject_unusable_fds() {
+        assert!(!is_readable_fd(libc::STDOUT_FILENO));
+        assert!(!is_readable_fd(libc::STDERR_FILENO));
+        assert!(!is_readable_fd(i32::MAX));
+    }
+}
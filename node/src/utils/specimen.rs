@@ -418,9 +418,9 @@ where
                 //
                 // 1. The required seed bytes for Ed25519 and Secp256k1 are both the same length of
                 //    32 bytes.
-                // 2. While Secp256k1 does not allow the most trivial seed bytes of 0x00..0001, a
-                //    a hash function output seems to satisfy it, and our current hashing scheme
-                //    also output 32 bytes.
+                // 2. While Secp256k1 does not allow the most trivial seed bytes of 0x00..0001, a a
+                //    hash function output seems to satisfy it, and our current hashing scheme also
+                //    output 32 bytes.
                 let seed_bytes = Digest::hash(seed.to_be_bytes()).value();
 
                 match variant {
@@ -643,8 +643,8 @@ impl LargestSpecimen for Deploy {
         //       generated here is guaranteed to exceed this maximum size due to the session code
         //       being this maximum size already (see the [`LargestSpecimen`] implementation of
         //       [`ExecutableDeployItem`]). For this reason, we leave `dependencies` and `payment`
-        //       small.
-        Deploy::new(
+        //       small. A fee payer is included as it makes the deploy larger.
+        Deploy::new_sponsored(
             LargestSpecimen::largest_specimen(estimator, cache),
             LargestSpecimen::largest_specimen(estimator, cache),
             LargestSpecimen::largest_specimen(estimator, cache),
@@ -656,6 +656,7 @@ impl LargestSpecimen for Deploy {
             },
             &LargestSpecimen::largest_specimen(estimator, cache),
             LargestSpecimen::largest_specimen(estimator, cache),
+            LargestSpecimen::largest_specimen(estimator, cache),
         )
     }
 }
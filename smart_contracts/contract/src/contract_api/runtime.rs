@@ -1,6 +1,6 @@
 //! Functions for interacting with the current runtime.
 
-use alloc::{collections::BTreeSet, vec, vec::Vec};
+use alloc::{collections::BTreeSet, string::String, vec, vec::Vec};
 use core::mem::MaybeUninit;
 
 use casper_types::{
@@ -9,8 +9,9 @@ use casper_types::{
     bytesrepr::{self, FromBytes},
     contracts::{ContractVersion, NamedKeys},
     system::CallStackElement,
-    ApiError, BlockTime, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, Phase,
-    RuntimeArgs, URef, BLAKE2B_DIGEST_LENGTH, BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH,
+    ApiError, BlockInfoField, BlockTime, CLTyped, CLValue, ContractHash, ContractPackageHash,
+    DeployHash, EraId, Key, Phase, RuntimeArgs, ScheduledInvocationAddr, ScheduledTime, URef,
+    BLAKE2B_DIGEST_LENGTH, BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH, U512,
 };
 
 use crate::{contract_api, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
@@ -74,6 +75,88 @@ pub fn call_contract<T: CLTyped + FromBytes>(
     deserialize_contract_result(bytes_written)
 }
 
+/// Calls the given view entry point of a stored contract, passing the given arguments to it.
+///
+/// The view is executed read-only: if it, or any contract it calls, attempts to modify global state
+/// then execution stops and `call_contract_view` doesn't return. Calling an entry point which isn't
+/// marked as a view is also an error. Otherwise the value passed to [`ret`] by the view is
+/// returned, or `()` if it doesn't call [`ret`].
+pub fn call_contract_view<T: CLTyped + FromBytes>(
+    contract_hash: ContractHash,
+    entry_point_name: &str,
+    runtime_args: RuntimeArgs,
+) -> T {
+    let (contract_hash_ptr, contract_hash_size, _bytes1) = contract_api::to_ptr(contract_hash);
+    let (entry_point_name_ptr, entry_point_name_size, _bytes2) =
+        contract_api::to_ptr(entry_point_name);
+    let (runtime_args_ptr, runtime_args_size, _bytes2) = contract_api::to_ptr(runtime_args);
+
+    let bytes_written = {
+        let mut bytes_written = MaybeUninit::uninit();
+        let ret = unsafe {
+            ext_ffi::casper_call_contract_view(
+                contract_hash_ptr,
+                contract_hash_size,
+                entry_point_name_ptr,
+                entry_point_name_size,
+                runtime_args_ptr,
+                runtime_args_size,
+                bytes_written.as_mut_ptr(),
+            )
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { bytes_written.assume_init() }
+    };
+    deserialize_contract_result(bytes_written)
+}
+
+/// Schedules a call to the given entry point of a stored contract, to be executed by the system on
+/// behalf of the caller in the first block at or after `scheduled_time`.
+///
+/// Invocations can only be scheduled by session code, as the calling account is the one the
+/// invocation is executed for. The call is prepaid with `amount` motes taken from `source`, which
+/// are used as its payment when it is executed. The entry point has to be of type
+/// [`EntryPointType::Contract`] and `scheduled_time` has to be in the future. Returns the address
+/// under which the invocation is stored in global state until it is executed.
+///
+/// [`EntryPointType::Contract`]: casper_types::EntryPointType::Contract
+pub fn schedule_invocation(
+    contract_hash: ContractHash,
+    entry_point_name: &str,
+    runtime_args: RuntimeArgs,
+    scheduled_time: ScheduledTime,
+    source: URef,
+    amount: U512,
+) -> Result<ScheduledInvocationAddr, ApiError> {
+    let (contract_hash_ptr, contract_hash_size, _bytes1) = contract_api::to_ptr(contract_hash);
+    let (entry_point_name_ptr, entry_point_name_size, _bytes2) =
+        contract_api::to_ptr(entry_point_name);
+    let (runtime_args_ptr, runtime_args_size, _bytes3) = contract_api::to_ptr(runtime_args);
+    let (scheduled_time_ptr, scheduled_time_size, _bytes4) = contract_api::to_ptr(scheduled_time);
+    let (source_ptr, source_size, _bytes5) = contract_api::to_ptr(source);
+    let (amount_ptr, amount_size, _bytes6) = contract_api::to_ptr(amount);
+
+    let mut addr = ScheduledInvocationAddr::default();
+    let ret = unsafe {
+        ext_ffi::casper_schedule_invocation(
+            contract_hash_ptr,
+            contract_hash_size,
+            entry_point_name_ptr,
+            entry_point_name_size,
+            runtime_args_ptr,
+            runtime_args_size,
+            scheduled_time_ptr,
+            scheduled_time_size,
+            source_ptr,
+            source_size,
+            amount_ptr,
+            amount_size,
+            addr.as_mut_ptr(),
+        )
+    };
+    api_error::result_from(ret).map(|_| addr)
+}
+
 /// Invokes the specified `entry_point_name` of stored logic at a specific `contract_package_hash`
 /// address, for the most current version of a contract package by default or a specific
 /// `contract_version` if one is provided, and passing the provided `runtime_args` to it
@@ -212,6 +295,43 @@ pub fn get_blocktime() -> BlockTime {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+fn get_block_info<T: FromBytes>(field: BlockInfoField) -> T {
+    let output_size = {
+        let mut output_size = MaybeUninit::uninit();
+        let ret =
+            unsafe { ext_ffi::casper_get_block_info(field.to_u8(), output_size.as_mut_ptr()) };
+        api_error::result_from(ret).unwrap_or_revert();
+        unsafe { output_size.assume_init() }
+    };
+    let buf = read_host_buffer(output_size).unwrap_or_revert();
+    bytesrepr::deserialize(buf).unwrap_or_revert()
+}
+
+/// Returns the height of the block in which the current deploy is executed.
+pub fn get_block_height() -> u64 {
+    get_block_info(BlockInfoField::BlockHeight)
+}
+
+/// Returns the [`EraId`] of the block in which the current deploy is executed.
+pub fn get_era_id() -> EraId {
+    get_block_info(BlockInfoField::EraId)
+}
+
+/// Returns the hash of the parent of the block in which the current deploy is executed.
+pub fn get_parent_block_hash() -> [u8; BLAKE2B_DIGEST_LENGTH] {
+    get_block_info(BlockInfoField::ParentBlockHash)
+}
+
+/// Returns the [`DeployHash`] of the currently executing deploy.
+pub fn get_deploy_hash() -> DeployHash {
+    DeployHash::new(get_block_info(BlockInfoField::DeployHash))
+}
+
+/// Returns the name of the chain on which the current deploy is executed.
+pub fn get_chain_name() -> String {
+    get_block_info(BlockInfoField::ChainName)
+}
+
 /// Returns the current [`Phase`].
 pub fn get_phase() -> Phase {
     let dest_non_null_ptr = contract_api::alloc_bytes(PHASE_SERIALIZED_LENGTH);
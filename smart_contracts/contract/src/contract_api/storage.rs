@@ -6,7 +6,7 @@ use core::{convert::From, mem::MaybeUninit};
 use casper_types::{
     api_error,
     bytesrepr::{self, FromBytes, ToBytes},
-    contracts::{ContractVersion, EntryPoints, NamedKeys},
+    contracts::{ContractVersion, EntryPoints, NamedKeys, StorageSchemaVersion},
     AccessRights, ApiError, CLTyped, CLValue, ContractHash, ContractPackageHash, HashAddr, Key,
     URef, DICTIONARY_ITEM_KEY_MAX_LENGTH, UREF_SERIALIZED_LENGTH,
 };
@@ -317,6 +317,54 @@ pub fn add_contract_version(
     (contract_hash, contract_version)
 }
 
+/// Add a new version of a contract to the contract stored at the given `Key`, recording
+/// `storage_schema_version` as the layout of the state kept by the new version. Note that this
+/// contract must have been created by `create_contract` or `create_contract_package_at_hash` first.
+///
+/// If `entry_points` contains a [`casper_types::contracts::MIGRATE_ENTRY_POINT_NAME`] entry point,
+/// the host calls it within the same execution, passing the previous contract hash and storage
+/// schema version. A failing migration fails the whole execution, so the new version is never
+/// added.
+pub fn add_contract_version_with_storage_schema(
+    contract_package_hash: ContractPackageHash,
+    entry_points: EntryPoints,
+    named_keys: NamedKeys,
+    storage_schema_version: StorageSchemaVersion,
+) -> (ContractHash, ContractVersion) {
+    let (contract_package_hash_ptr, contract_package_hash_size, _bytes1) =
+        contract_api::to_ptr(contract_package_hash);
+    let (entry_points_ptr, entry_points_size, _bytes4) = contract_api::to_ptr(entry_points);
+    let (named_keys_ptr, named_keys_size, _bytes5) = contract_api::to_ptr(named_keys);
+
+    let mut output_ptr = vec![0u8; Key::max_serialized_length()];
+    let mut total_bytes: usize = 0;
+
+    let mut contract_version: ContractVersion = 0;
+
+    let ret = unsafe {
+        ext_ffi::casper_add_contract_version_with_storage_schema(
+            contract_package_hash_ptr,
+            contract_package_hash_size,
+            &mut contract_version as *mut ContractVersion,
+            entry_points_ptr,
+            entry_points_size,
+            named_keys_ptr,
+            named_keys_size,
+            storage_schema_version,
+            output_ptr.as_mut_ptr(),
+            output_ptr.len(),
+            &mut total_bytes as *mut usize,
+        )
+    };
+    match api_error::result_from(ret) {
+        Ok(_) => {}
+        Err(e) => revert(e),
+    }
+    output_ptr.truncate(total_bytes);
+    let contract_hash = bytesrepr::deserialize(output_ptr).unwrap_or_revert();
+    (contract_hash, contract_version)
+}
+
 /// Disable a version of a contract from the contract stored at the given
 /// `Key`. That version of the contract will no longer be callable by
 /// `call_versioned_contract`. Note that this contract must have been created by
@@ -805,4 +805,104 @@ extern "C" {
         contract_hash_ptr: *const u8,
         contract_hash_size: usize,
     ) -> i32;
+    /// This function writes the requested piece of the current block context (block height, era
+    /// id, parent block hash, deploy hash or chain name) into the host buffer, from where it can
+    /// be read via [`casper_read_host_buffer`]. Returns non-zero standard error for a failure,
+    /// otherwise a zero indicates success.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_idx` - identifier of the requested field, see `casper_types::BlockInfoField`
+    /// * `output_size` - pointer to a value where the host will write the size of the serialized
+    ///   field
+    pub fn casper_get_block_info(field_idx: u8, output_size: *mut usize) -> i32;
+    /// Calls a view entry point of a contract by its hash. The view is executed in a read-only
+    /// context, so any attempt to modify global state from within it (or from contracts it calls)
+    /// causes the execution to fail. Returns a standard error code in case of failure, otherwise a
+    /// successful execution returns zero. Bytes returned from contract execution are set to
+    /// `result_size` pointer.
+    ///
+    /// # Arguments
+    /// * `contract_hash_ptr` - pointer to serialized contract hash.
+    /// * `contract_hash_size` - size of contract hash in serialized form.
+    /// * `entry_point_name_ptr` - pointer to serialized view entry point name
+    /// * `entry_point_name_size` - size of serialized view entry point name
+    /// * `runtime_args_ptr` - pointer to serialized runtime arguments
+    /// * `runtime_args_size` - size of serialized runtime arguments
+    /// * `result_size` - a pointer to a value which will be set to a size of bytes of called
+    ///   contract return value
+    pub fn casper_call_contract_view(
+        contract_hash_ptr: *const u8,
+        contract_hash_size: usize,
+        entry_point_name_ptr: *const u8,
+        entry_point_name_size: usize,
+        runtime_args_ptr: *const u8,
+        runtime_args_size: usize,
+        result_size: *mut usize,
+    ) -> i32;
+    /// Adds new contract version to a contract package, recording the storage schema version it
+    /// declares. If the new version has a `migrate` entry point, the host calls it before
+    /// returning, and the version is only added if the migration succeeds.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_package_hash_ptr` - pointer to serialized contract package hash.
+    /// * `contract_package_hash_size` - size of contract package hash in serialized form.
+    /// * `version_ptr` - output parameter where new version assigned by host is set
+    /// * `entry_points_ptr` - pointer to serialized [`casper_types::EntryPoints`]
+    /// * `entry_points_size` - size of serialized [`casper_types::EntryPoints`]
+    /// * `named_keys_ptr` - pointer to serialized [`casper_types::contracts::NamedKeys`]
+    /// * `named_keys_size` - size of serialized [`casper_types::contracts::NamedKeys`]
+    /// * `storage_schema_version` - storage schema version declared by the new contract version
+    /// * `output_ptr` - pointer to a memory where host assigned contract hash is set to
+    /// * `output_size` - size of memory area that host can write to
+    /// * `bytes_written_ptr` - pointer to a value where host will set a number of bytes written to
+    ///   the `output_size` pointer
+    pub fn casper_add_contract_version_with_storage_schema(
+        contract_package_hash_ptr: *const u8,
+        contract_package_hash_size: usize,
+        version_ptr: *const u32,
+        entry_points_ptr: *const u8,
+        entry_points_size: usize,
+        named_keys_ptr: *const u8,
+        named_keys_size: usize,
+        storage_schema_version: u32,
+        output_ptr: *mut u8,
+        output_size: usize,
+        bytes_written_ptr: *mut usize,
+    ) -> i32;
+    /// Registers an invocation of a stored contract to be executed by the system on behalf of the
+    /// caller once the scheduled time is reached. The invocation is prepaid with the given amount
+    /// taken from the source purse.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_hash_ptr` - pointer to serialized contract hash.
+    /// * `contract_hash_size` - size of contract hash in serialized form.
+    /// * `entry_point_name_ptr` - pointer to serialized entry point name
+    /// * `entry_point_name_size` - size of serialized entry point name
+    /// * `runtime_args_ptr` - pointer to serialized runtime arguments
+    /// * `runtime_args_size` - size of serialized runtime arguments
+    /// * `scheduled_time_ptr` - pointer to serialized [`casper_types::ScheduledTime`]
+    /// * `scheduled_time_size` - size of serialized [`casper_types::ScheduledTime`]
+    /// * `source_ptr` - pointer to serialized source purse
+    /// * `source_size` - size of serialized source purse
+    /// * `amount_ptr` - pointer to serialized prepaid amount
+    /// * `amount_size` - size of serialized prepaid amount
+    /// * `addr_ptr` - output parameter where the address of the invocation is written
+    pub fn casper_schedule_invocation(
+        contract_hash_ptr: *const u8,
+        contract_hash_size: usize,
+        entry_point_name_ptr: *const u8,
+        entry_point_name_size: usize,
+        runtime_args_ptr: *const u8,
+        runtime_args_size: usize,
+        scheduled_time_ptr: *const u8,
+        scheduled_time_size: usize,
+        source_ptr: *const u8,
+        source_size: usize,
+        amount_ptr: *const u8,
+        amount_size: usize,
+        addr_ptr: *mut u8,
+    ) -> i32;
 }
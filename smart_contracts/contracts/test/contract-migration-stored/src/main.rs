@@ -0,0 +1,62 @@
+#![no_std]
+#![no_main]
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints, NamedKeys, Parameters},
+    CLType, CLValue, EntryPointAccess, EntryPointType, URef,
+};
+
+const VALUE_KEY_NAME: &str = "value";
+const HASH_KEY_NAME: &str = "contract_migration_hash";
+const PACKAGE_HASH_KEY_NAME: &str = "contract_migration_package_hash";
+const ACCESS_KEY_NAME: &str = "contract_migration_access";
+const ARG_INITIAL_VALUE: &str = "initial_value";
+const ENTRY_POINT_GET_VALUE: &str = "get_value";
+
+#[no_mangle]
+pub extern "C" fn get_value() {
+    let value_uref: URef = runtime::get_key(VALUE_KEY_NAME)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    let value: u64 = storage::read(value_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(value).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let initial_value: u64 = runtime::get_named_arg(ARG_INITIAL_VALUE);
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_GET_VALUE,
+            Parameters::new(),
+            CLType::U64,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points
+    };
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        VALUE_KEY_NAME.into(),
+        storage::new_uref(initial_value).into(),
+    );
+
+    let (contract_hash, _contract_version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(PACKAGE_HASH_KEY_NAME.into()),
+        Some(ACCESS_KEY_NAME.into()),
+    );
+
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+}
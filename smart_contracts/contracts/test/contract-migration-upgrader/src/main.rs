@@ -0,0 +1,128 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{
+        EntryPoint, EntryPoints, NamedKeys, Parameters, StorageSchemaVersion,
+        MIGRATE_ARG_PREVIOUS_CONTRACT_HASH, MIGRATE_ARG_PREVIOUS_STORAGE_SCHEMA_VERSION,
+        MIGRATE_ENTRY_POINT_NAME,
+    },
+    ApiError, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash, EntryPointAccess,
+    EntryPointType, Key, Parameter, URef,
+};
+
+const VALUE_KEY_NAME: &str = "value";
+const PREVIOUS_CONTRACT_KEY_NAME: &str = "previous_contract";
+const PREVIOUS_STORAGE_SCHEMA_VERSION_KEY_NAME: &str = "previous_storage_schema_version";
+const HASH_KEY_NAME: &str = "contract_migration_hash";
+const PACKAGE_HASH_KEY_NAME: &str = "contract_migration_package_hash";
+const ARG_STORAGE_SCHEMA_VERSION: &str = "storage_schema_version";
+const ENTRY_POINT_GET_VALUE: &str = "get_value";
+
+#[repr(u16)]
+enum Error {
+    NothingToMigrate = 1,
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::User(error as u16)
+    }
+}
+
+fn value_uref() -> URef {
+    runtime::get_key(VALUE_KEY_NAME)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+#[no_mangle]
+pub extern "C" fn get_value() {
+    let value: u64 = storage::read(value_uref())
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    runtime::ret(CLValue::from_t(value).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn migrate() {
+    let previous_contract_hash: Option<ContractHash> =
+        runtime::get_named_arg(MIGRATE_ARG_PREVIOUS_CONTRACT_HASH);
+    let previous_storage_schema_version: Option<StorageSchemaVersion> =
+        runtime::get_named_arg(MIGRATE_ARG_PREVIOUS_STORAGE_SCHEMA_VERSION);
+
+    let value: u64 = storage::read(value_uref())
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+    if value == 0 {
+        runtime::revert(Error::NothingToMigrate);
+    }
+    storage::write(value_uref(), value * 2);
+
+    if let Some(previous_contract_hash) = previous_contract_hash {
+        runtime::put_key(
+            PREVIOUS_CONTRACT_KEY_NAME,
+            Key::from(previous_contract_hash),
+        );
+    }
+    runtime::put_key(
+        PREVIOUS_STORAGE_SCHEMA_VERSION_KEY_NAME,
+        storage::new_uref(previous_storage_schema_version).into(),
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let storage_schema_version: StorageSchemaVersion =
+        runtime::get_named_arg(ARG_STORAGE_SCHEMA_VERSION);
+    let contract_package_hash: ContractPackageHash = runtime::get_key(PACKAGE_HASH_KEY_NAME)
+        .and_then(Key::into_hash)
+        .map(ContractPackageHash::new)
+        .unwrap_or_revert();
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_GET_VALUE,
+            Parameters::new(),
+            CLType::U64,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points.add_entry_point(EntryPoint::new(
+            MIGRATE_ENTRY_POINT_NAME,
+            vec![
+                Parameter::new(
+                    MIGRATE_ARG_PREVIOUS_CONTRACT_HASH,
+                    Option::<ContractHash>::cl_type(),
+                ),
+                Parameter::new(
+                    MIGRATE_ARG_PREVIOUS_STORAGE_SCHEMA_VERSION,
+                    Option::<StorageSchemaVersion>::cl_type(),
+                ),
+            ],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points
+    };
+
+    let (contract_hash, _contract_version) = storage::add_contract_version_with_storage_schema(
+        contract_package_hash,
+        entry_points,
+        NamedKeys::new(),
+        storage_schema_version,
+    );
+
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+}
@@ -0,0 +1,23 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{ContractHash, RuntimeArgs};
+
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_ENTRY_POINT: &str = "entry_point";
+const RESULT_KEY_NAME: &str = "result";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    let entry_point: String = runtime::get_named_arg(ARG_ENTRY_POINT);
+
+    let result: u64 = runtime::call_contract_view(contract_hash, &entry_point, RuntimeArgs::new());
+
+    runtime::put_key(RESULT_KEY_NAME, storage::new_uref(result).into());
+}
@@ -0,0 +1,92 @@
+#![no_std]
+#![no_main]
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints, NamedKeys, Parameters},
+    CLType, CLValue, EntryPointAccess, EntryPointType, URef,
+};
+
+const VALUE_KEY_NAME: &str = "value";
+const HASH_KEY_NAME: &str = "contract_view_hash";
+const PACKAGE_HASH_KEY_NAME: &str = "contract_view_package_hash";
+const INITIAL_VALUE: u64 = 42;
+
+const ENTRY_POINT_GET_VALUE: &str = "get_value";
+const ENTRY_POINT_WRITING_VIEW: &str = "writing_view";
+const ENTRY_POINT_NOT_A_VIEW: &str = "not_a_view";
+
+fn value_uref() -> URef {
+    runtime::get_key(VALUE_KEY_NAME)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+fn read_value() -> u64 {
+    storage::read(value_uref())
+        .unwrap_or_revert()
+        .unwrap_or_revert()
+}
+
+#[no_mangle]
+pub extern "C" fn get_value() {
+    runtime::ret(CLValue::from_t(read_value()).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn writing_view() {
+    let value = read_value() + 1;
+    storage::write(value_uref(), value);
+    runtime::ret(CLValue::from_t(value).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn not_a_view() {
+    runtime::ret(CLValue::from_t(read_value()).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        entry_points.add_entry_point(EntryPoint::new_view(
+            ENTRY_POINT_GET_VALUE,
+            Parameters::new(),
+            CLType::U64,
+            EntryPointAccess::Public,
+        ));
+        entry_points.add_entry_point(EntryPoint::new_view(
+            ENTRY_POINT_WRITING_VIEW,
+            Parameters::new(),
+            CLType::U64,
+            EntryPointAccess::Public,
+        ));
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_NOT_A_VIEW,
+            Parameters::new(),
+            CLType::U64,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points
+    };
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(
+        VALUE_KEY_NAME.into(),
+        storage::new_uref(INITIAL_VALUE).into(),
+    );
+
+    let (contract_hash, _contract_version) = storage::new_contract(
+        entry_points,
+        Some(named_keys),
+        Some(PACKAGE_HASH_KEY_NAME.into()),
+        None,
+    );
+
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+}
@@ -0,0 +1,38 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use casper_contract::{
+    contract_api::{account, runtime},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ContractHash, Key, RuntimeArgs, ScheduledTime, U512};
+
+const INVOCATION_KEY_NAME: &str = "scheduled_invocation";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const ARG_ENTRY_POINT: &str = "entry_point";
+const ARG_SCHEDULED_TIME: &str = "scheduled_time";
+const ARG_AMOUNT: &str = "amount";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    let entry_point: String = runtime::get_named_arg(ARG_ENTRY_POINT);
+    let scheduled_time: ScheduledTime = runtime::get_named_arg(ARG_SCHEDULED_TIME);
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+
+    let addr = runtime::schedule_invocation(
+        contract_hash,
+        &entry_point,
+        RuntimeArgs::new(),
+        scheduled_time,
+        account::get_main_purse(),
+        amount,
+    )
+    .unwrap_or_revert();
+
+    runtime::put_key(INVOCATION_KEY_NAME, Key::ScheduledInvocation(addr));
+}
@@ -0,0 +1,93 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use casper_contract::{
+    contract_api::{runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::{EntryPoint, EntryPoints, NamedKeys, Parameters},
+    CLType, CLTyped, ContractHash, EntryPointAccess, EntryPointType, EraId, Parameter, RuntimeArgs,
+    ScheduledTime, URef, U512,
+};
+
+const COUNT_KEY_NAME: &str = "count";
+const HASH_KEY_NAME: &str = "scheduled_counter_hash";
+const ENTRY_POINT_INCREMENT: &str = "increment";
+const ENTRY_POINT_SESSION_INCREMENT: &str = "session_increment";
+const ENTRY_POINT_SCHEDULE_INCREMENT: &str = "schedule_increment";
+const ARG_CONTRACT_HASH: &str = "contract_hash";
+const SCHEDULED_ERA: u64 = 5;
+
+fn increment_count() {
+    let count_uref: URef = runtime::get_key(COUNT_KEY_NAME)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert();
+    storage::add(count_uref, 1u64);
+}
+
+#[no_mangle]
+pub extern "C" fn increment() {
+    increment_count()
+}
+
+#[no_mangle]
+pub extern "C" fn session_increment() {
+    increment_count()
+}
+
+#[no_mangle]
+pub extern "C" fn schedule_increment() {
+    let contract_hash: ContractHash = runtime::get_named_arg(ARG_CONTRACT_HASH);
+    runtime::schedule_invocation(
+        contract_hash,
+        ENTRY_POINT_INCREMENT,
+        RuntimeArgs::new(),
+        ScheduledTime::Era(EraId::new(SCHEDULED_ERA)),
+        system::create_purse(),
+        U512::one(),
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_INCREMENT,
+            Parameters::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_SESSION_INCREMENT,
+            Parameters::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Session,
+        ));
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_SCHEDULE_INCREMENT,
+            vec![Parameter::new(ARG_CONTRACT_HASH, ContractHash::cl_type())],
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points
+    };
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(COUNT_KEY_NAME.into(), storage::new_uref(0u64).into());
+
+    let (contract_hash, _contract_version) =
+        storage::new_contract(entry_points, Some(named_keys), None, None);
+
+    runtime::put_key(HASH_KEY_NAME, contract_hash.into());
+}
@@ -30,9 +30,21 @@ pub use self::{
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
     contracts::NamedKeys,
-    crypto, AccessRights, ContextAccessRights, Key, URef, BLAKE2B_DIGEST_LENGTH,
+    crypto, AccessRights, ContextAccessRights, Key, URef, URefAddr, BLAKE2B_DIGEST_LENGTH,
 };
 
+/// Tag written in place of the access rights of the main purse of an [`Account`] serialized in a
+/// versioned layout, followed by the layout version.
+///
+/// It is never a valid set of [`AccessRights`].  Accounts which have never executed a sequenced
+/// deploy keep the original layout, so that accounts stored before sequence numbers were introduced
+/// keep their encoding.
+const VERSIONED_ACCOUNT_TAG: u8 = u8::MAX;
+
+/// Version of the versioned [`Account`] layout, in which the access rights of the main purse are
+/// followed by the associated keys, the action thresholds and the next sequence number.
+const ACCOUNT_LAYOUT_VERSION_1: u8 = 1;
+
 /// Represents an Account in the global state.
 #[derive(PartialEq, Eq, Clone, Debug, Serialize)]
 #[cfg_attr(feature = "datasize", derive(DataSize))]
@@ -42,6 +54,7 @@ pub struct Account {
     main_purse: URef,
     associated_keys: AssociatedKeys,
     action_thresholds: ActionThresholds,
+    sequence_number: u64,
 }
 
 impl Account {
@@ -59,6 +72,7 @@ impl Account {
             main_purse,
             associated_keys,
             action_thresholds,
+            sequence_number: 0,
         }
     }
 
@@ -130,6 +144,45 @@ impl Account {
         &self.action_thresholds
     }
 
+    /// Returns the sequence number the next sequenced deploy of the account must carry.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Sets the sequence number the next sequenced deploy of the account must carry.
+    pub fn set_sequence_number(&mut self, sequence_number: u64) {
+        self.sequence_number = sequence_number;
+    }
+
+    /// Writes the main purse, the associated keys and the action thresholds, switching to the
+    /// versioned layout ending in the sequence number if the account has one.
+    fn write_versioned_fields(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        if self.sequence_number == 0 {
+            self.main_purse.write_bytes(writer)?;
+            self.associated_keys.write_bytes(writer)?;
+            self.action_thresholds.write_bytes(writer)
+        } else {
+            self.main_purse.addr().write_bytes(writer)?;
+            writer.push(VERSIONED_ACCOUNT_TAG);
+            writer.push(ACCOUNT_LAYOUT_VERSION_1);
+            self.main_purse.access_rights().write_bytes(writer)?;
+            self.associated_keys.write_bytes(writer)?;
+            self.action_thresholds.write_bytes(writer)?;
+            self.sequence_number.write_bytes(writer)
+        }
+    }
+
+    fn versioned_fields_serialized_length(&self) -> usize {
+        let original_length = self.main_purse.serialized_length()
+            + self.associated_keys.serialized_length()
+            + self.action_thresholds.serialized_length();
+        if self.sequence_number == 0 {
+            original_length
+        } else {
+            2 + original_length + self.sequence_number.serialized_length()
+        }
+    }
+
     /// Adds an associated key to an account.
     pub fn add_associated_key(
         &mut self,
@@ -278,27 +331,20 @@ impl ToBytes for Account {
         let mut result = bytesrepr::allocate_buffer(self)?;
         self.account_hash().write_bytes(&mut result)?;
         self.named_keys().write_bytes(&mut result)?;
-        self.main_purse.write_bytes(&mut result)?;
-        self.associated_keys().write_bytes(&mut result)?;
-        self.action_thresholds().write_bytes(&mut result)?;
+        self.write_versioned_fields(&mut result)?;
         Ok(result)
     }
 
     fn serialized_length(&self) -> usize {
         self.account_hash.serialized_length()
             + self.named_keys.serialized_length()
-            + self.main_purse.serialized_length()
-            + self.associated_keys.serialized_length()
-            + self.action_thresholds.serialized_length()
+            + self.versioned_fields_serialized_length()
     }
 
     fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
         self.account_hash().write_bytes(writer)?;
         self.named_keys().write_bytes(writer)?;
-        self.main_purse().write_bytes(writer)?;
-        self.associated_keys().write_bytes(writer)?;
-        self.action_thresholds().write_bytes(writer)?;
-        Ok(())
+        self.write_versioned_fields(writer)
     }
 }
 
@@ -306,9 +352,39 @@ impl FromBytes for Account {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (account_hash, rem) = AccountHash::from_bytes(bytes)?;
         let (named_keys, rem) = NamedKeys::from_bytes(rem)?;
-        let (main_purse, rem) = URef::from_bytes(rem)?;
-        let (associated_keys, rem) = AssociatedKeys::from_bytes(rem)?;
-        let (action_thresholds, rem) = ActionThresholds::from_bytes(rem)?;
+        let (main_purse_addr, rem) = URefAddr::from_bytes(rem)?;
+        let (main_purse, associated_keys, action_thresholds, sequence_number, rem) =
+            match rem.first() {
+                Some(&VERSIONED_ACCOUNT_TAG) => {
+                    let (version, rem) = u8::from_bytes(&rem[1..])?;
+                    if version != ACCOUNT_LAYOUT_VERSION_1 {
+                        return Err(bytesrepr::Error::Formatting);
+                    }
+                    let (access_rights, rem) = AccessRights::from_bytes(rem)?;
+                    let (associated_keys, rem) = AssociatedKeys::from_bytes(rem)?;
+                    let (action_thresholds, rem) = ActionThresholds::from_bytes(rem)?;
+                    let (sequence_number, rem) = u64::from_bytes(rem)?;
+                    (
+                        URef::new(main_purse_addr, access_rights),
+                        associated_keys,
+                        action_thresholds,
+                        sequence_number,
+                        rem,
+                    )
+                }
+                _ => {
+                    let (access_rights, rem) = AccessRights::from_bytes(rem)?;
+                    let (associated_keys, rem) = AssociatedKeys::from_bytes(rem)?;
+                    let (action_thresholds, rem) = ActionThresholds::from_bytes(rem)?;
+                    (
+                        URef::new(main_purse_addr, access_rights),
+                        associated_keys,
+                        action_thresholds,
+                        0,
+                        rem,
+                    )
+                }
+            };
         Ok((
             Account {
                 account_hash,
@@ -316,6 +392,7 @@ impl FromBytes for Account {
                 main_purse,
                 associated_keys,
                 action_thresholds,
+                sequence_number,
             },
             rem,
         ))
@@ -494,15 +571,18 @@ pub mod gens {
             purse in uref_arb(),
             thresholds in action_thresholds_arb(),
             mut associated_keys in associated_keys_arb(),
+            sequence_number in prop_oneof![Just(0), any::<u64>()],
         ) -> Account {
                 associated_keys.add_key(account_hash, Weight::new(1)).unwrap();
-                Account::new(
+                let mut account = Account::new(
                     account_hash,
                     urefs,
                     purse,
                     associated_keys,
                     thresholds,
-                )
+                );
+                account.set_sequence_number(sequence_number);
+                account
         }
     }
 }
@@ -994,6 +1074,37 @@ mod tests {
             ContextAccessRights::new(Key::from(account_hash), vec![MAIN_PURSE, OTHER_UREF]);
         assert_eq!(actual_access_rights, expected_access_rights)
     }
+
+    #[test]
+    fn should_serialize_account_compatibly() {
+        let account_hash = AccountHash::new([1u8; 32]);
+        let main_purse = URef::new([42; 32], AccessRights::READ_ADD_WRITE);
+        let mut account = Account::create(account_hash, NamedKeys::new(), main_purse);
+        let bytes = account.to_bytes().unwrap();
+        let access_rights_index = account_hash.serialized_length()
+            + account.named_keys().serialized_length()
+            + main_purse.addr().len();
+        assert_eq!(
+            bytes[access_rights_index],
+            AccessRights::READ_ADD_WRITE.bits(),
+            "accounts without a sequence number should keep their original encoding"
+        );
+        let (deserialized, remainder) = Account::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(deserialized, account);
+
+        account.set_sequence_number(3);
+        let versioned_bytes = account.to_bytes().unwrap();
+        assert_eq!(versioned_bytes.len(), account.serialized_length());
+        assert_eq!(versioned_bytes[access_rights_index], VERSIONED_ACCOUNT_TAG);
+        let (deserialized, remainder) = Account::from_bytes(&versioned_bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(deserialized, account);
+
+        let mut unknown_version_bytes = versioned_bytes;
+        unknown_version_bytes[access_rights_index + 1] = ACCOUNT_LAYOUT_VERSION_1 + 1;
+        assert!(Account::from_bytes(&unknown_version_bytes).is_err());
+    }
 }
 
 #[cfg(test)]
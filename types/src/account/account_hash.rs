@@ -62,7 +62,7 @@ impl AccountHash {
             .strip_prefix(ACCOUNT_HASH_FORMATTED_STRING_PREFIX)
             .ok_or(FromStrError::InvalidPrefix)?;
         let bytes =
-            <[u8; ACCOUNT_HASH_LENGTH]>::try_from(checksummed_hex::decode(remainder)?.as_ref())?;
+            <[u8; ACCOUNT_HASH_LENGTH]>::try_from(checksummed_hex::decode(remainder)?.as_slice())?;
         Ok(AccountHash(bytes))
     }
 
@@ -74,11 +74,15 @@ impl AccountHash {
         const SYSTEM_LOWERCASE: &str = "system";
         const ED25519_LOWERCASE: &str = "ed25519";
         const SECP256K1_LOWERCASE: &str = "secp256k1";
+        #[cfg(any(feature = "bls", test))]
+        const BLS_LOWERCASE: &str = "bls";
 
         let algorithm_name = match public_key {
             PublicKey::System => SYSTEM_LOWERCASE,
             PublicKey::Ed25519(_) => ED25519_LOWERCASE,
             PublicKey::Secp256k1(_) => SECP256K1_LOWERCASE,
+            #[cfg(any(feature = "bls", test))]
+            PublicKey::Bls(_) => BLS_LOWERCASE,
         };
         let public_key_bytes: Vec<u8> = public_key.into();
 
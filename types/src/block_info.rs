@@ -0,0 +1,51 @@
+use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::{FromPrimitive, ToPrimitive};
+
+/// Selects which piece of the current block context is returned by the `casper_get_block_info`
+/// host function.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, FromPrimitive, ToPrimitive)]
+#[repr(u8)]
+pub enum BlockInfoField {
+    /// The height of the block in which the deploy is executed, as a `u64`.
+    BlockHeight = 0,
+    /// The era of the block in which the deploy is executed, as an [`EraId`](crate::EraId).
+    EraId = 1,
+    /// The hash of the parent of the block in which the deploy is executed, as a `[u8; 32]`.
+    ParentBlockHash = 2,
+    /// The hash of the executing deploy, as a `[u8; 32]`.
+    DeployHash = 3,
+    /// The name of the chain, as a `String`.
+    ChainName = 4,
+}
+
+impl BlockInfoField {
+    /// Returns the `u8` identifier of the field as passed across the host boundary.
+    pub fn to_u8(self) -> u8 {
+        // NOTE: Assumed safe as [`BlockInfoField`] is represented as u8.
+        ToPrimitive::to_u8(&self).expect("BlockInfoField is represented as a u8")
+    }
+
+    /// Returns the field identified by `value`, or `None` if it is not a known identifier.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        FromPrimitive::from_u8(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BlockInfoField;
+
+    #[test]
+    fn u8_roundtrip() {
+        for field in [
+            BlockInfoField::BlockHeight,
+            BlockInfoField::EraId,
+            BlockInfoField::ParentBlockHash,
+            BlockInfoField::DeployHash,
+            BlockInfoField::ChainName,
+        ] {
+            assert_eq!(BlockInfoField::from_u8(field.to_u8()), Some(field));
+        }
+        assert_eq!(BlockInfoField::from_u8(5), None);
+    }
+}
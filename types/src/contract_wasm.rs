@@ -115,7 +115,7 @@ impl ContractWasmHash {
         let remainder = input
             .strip_prefix(WASM_STRING_PREFIX)
             .ok_or(FromStrError::InvalidPrefix)?;
-        let bytes = HashAddr::try_from(checksummed_hex::decode(remainder)?.as_ref())?;
+        let bytes = HashAddr::try_from(checksummed_hex::decode(remainder)?.as_slice())?;
         Ok(ContractWasmHash(bytes))
     }
 }
@@ -334,6 +334,12 @@ pub type DisabledVersions = BTreeSet<ContractVersionKey>;
 /// Collection of named groups.
 pub type Groups = BTreeMap<Group, BTreeSet<URef>>;
 
+/// Version tag describing the layout of the state a contract version keeps in global state.
+pub type StorageSchemaVersion = u32;
+
+/// Collection of storage schema versions declared by contract versions.
+pub type StorageSchemaVersions = BTreeMap<ContractVersionKey, StorageSchemaVersion>;
+
 /// A newtype wrapping a `HashAddr` which references a [`Contract`] in the global state.
 #[derive(Default, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
 #[cfg_attr(feature = "datasize", derive(DataSize))]
@@ -370,7 +376,7 @@ impl ContractHash {
         let remainder = input
             .strip_prefix(CONTRACT_STRING_PREFIX)
             .ok_or(FromStrError::InvalidPrefix)?;
-        let bytes = HashAddr::try_from(checksummed_hex::decode(remainder)?.as_ref())?;
+        let bytes = HashAddr::try_from(checksummed_hex::decode(remainder)?.as_slice())?;
         Ok(ContractHash(bytes))
     }
 }
@@ -523,7 +529,7 @@ impl ContractPackageHash {
             .strip_prefix(PACKAGE_STRING_LEGACY_EXTRA_PREFIX)
             .unwrap_or(remainder);
 
-        let bytes = HashAddr::try_from(checksummed_hex::decode(hex_addr)?.as_ref())?;
+        let bytes = HashAddr::try_from(checksummed_hex::decode(hex_addr)?.as_slice())?;
         Ok(ContractPackageHash(bytes))
     }
 }
@@ -694,6 +700,18 @@ impl ToBytes for ContractPackageStatus {
     }
 }
 
+/// Tag written in place of the [`ContractPackageStatus`] of a [`ContractPackage`] serialized in a
+/// versioned layout, followed by the layout version.
+///
+/// Packages without storage schema versions keep the original layout ending in their
+/// `ContractPackageStatus`, so that packages stored before storage schema versions were introduced
+/// keep their encoding.
+const VERSIONED_CONTRACT_PACKAGE_TAG: u8 = 2;
+
+/// Version of the versioned [`ContractPackage`] layout, in which the `ContractPackageStatus` is
+/// followed by the package's [`StorageSchemaVersions`].
+const CONTRACT_PACKAGE_LAYOUT_VERSION_1: u8 = 1;
+
 impl FromBytes for ContractPackageStatus {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (val, bytes) = bool::from_bytes(bytes)?;
@@ -719,6 +737,9 @@ pub struct ContractPackage {
     groups: Groups,
     /// A flag that determines whether a contract is locked
     lock_status: ContractPackageStatus,
+    /// Storage schema versions declared by contract versions when they were added.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    storage_schema_versions: StorageSchemaVersions,
 }
 
 impl CLTyped for ContractPackage {
@@ -742,6 +763,7 @@ impl ContractPackage {
             disabled_versions,
             groups,
             lock_status,
+            storage_schema_versions: StorageSchemaVersions::new(),
         }
     }
 
@@ -921,6 +943,60 @@ impl ContractPackage {
     pub fn get_lock_status(&self) -> ContractPackageStatus {
         self.lock_status.clone()
     }
+
+    /// Returns the storage schema versions declared by this package's contract versions.
+    pub fn storage_schema_versions(&self) -> &StorageSchemaVersions {
+        &self.storage_schema_versions
+    }
+
+    /// Returns the storage schema version declared by the given contract version (if any).
+    pub fn storage_schema_version(
+        &self,
+        contract_version_key: ContractVersionKey,
+    ) -> Option<StorageSchemaVersion> {
+        self.storage_schema_versions
+            .get(&contract_version_key)
+            .copied()
+    }
+
+    /// Records the storage schema version declared by an existing contract version.
+    pub fn set_storage_schema_version(
+        &mut self,
+        contract_version_key: ContractVersionKey,
+        storage_schema_version: StorageSchemaVersion,
+    ) -> Result<(), Error> {
+        if !self.versions.contains_key(&contract_version_key) {
+            return Err(Error::ContractNotFound);
+        }
+        self.storage_schema_versions
+            .insert(contract_version_key, storage_schema_version);
+        Ok(())
+    }
+
+    /// Writes the lock status, preceded by the layout version and followed by the storage schema
+    /// versions if there are any.
+    fn write_lock_status_and_storage_schema_versions(
+        &self,
+        writer: &mut Vec<u8>,
+    ) -> Result<(), bytesrepr::Error> {
+        if self.storage_schema_versions.is_empty() {
+            self.lock_status.write_bytes(writer)
+        } else {
+            writer.push(VERSIONED_CONTRACT_PACKAGE_TAG);
+            writer.push(CONTRACT_PACKAGE_LAYOUT_VERSION_1);
+            self.lock_status.write_bytes(writer)?;
+            self.storage_schema_versions.write_bytes(writer)
+        }
+    }
+
+    fn lock_status_and_storage_schema_versions_serialized_length(&self) -> usize {
+        if self.storage_schema_versions.is_empty() {
+            self.lock_status.serialized_length()
+        } else {
+            2 + self.lock_status.serialized_length()
+                + self.storage_schema_versions.serialized_length()
+        }
+    }
 }
 
 impl ToBytes for ContractPackage {
@@ -930,7 +1006,7 @@ impl ToBytes for ContractPackage {
         self.versions().write_bytes(&mut result)?;
         self.disabled_versions().write_bytes(&mut result)?;
         self.groups().write_bytes(&mut result)?;
-        self.lock_status.write_bytes(&mut result)?;
+        self.write_lock_status_and_storage_schema_versions(&mut result)?;
         Ok(result)
     }
 
@@ -939,7 +1015,7 @@ impl ToBytes for ContractPackage {
             + self.versions.serialized_length()
             + self.disabled_versions.serialized_length()
             + self.groups.serialized_length()
-            + self.lock_status.serialized_length()
+            + self.lock_status_and_storage_schema_versions_serialized_length()
     }
 
     fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
@@ -947,8 +1023,7 @@ impl ToBytes for ContractPackage {
         self.versions().write_bytes(writer)?;
         self.disabled_versions().write_bytes(writer)?;
         self.groups().write_bytes(writer)?;
-        self.lock_status.write_bytes(writer)?;
-        Ok(())
+        self.write_lock_status_and_storage_schema_versions(writer)
     }
 }
 
@@ -958,13 +1033,28 @@ impl FromBytes for ContractPackage {
         let (versions, bytes) = ContractVersions::from_bytes(bytes)?;
         let (disabled_versions, bytes) = DisabledVersions::from_bytes(bytes)?;
         let (groups, bytes) = Groups::from_bytes(bytes)?;
-        let (lock_status, bytes) = ContractPackageStatus::from_bytes(bytes)?;
+        let (lock_status, storage_schema_versions, bytes) = match bytes.first() {
+            Some(&VERSIONED_CONTRACT_PACKAGE_TAG) => {
+                let (version, bytes) = u8::from_bytes(&bytes[1..])?;
+                if version != CONTRACT_PACKAGE_LAYOUT_VERSION_1 {
+                    return Err(bytesrepr::Error::Formatting);
+                }
+                let (lock_status, bytes) = ContractPackageStatus::from_bytes(bytes)?;
+                let (storage_schema_versions, bytes) = StorageSchemaVersions::from_bytes(bytes)?;
+                (lock_status, storage_schema_versions, bytes)
+            }
+            _ => {
+                let (lock_status, bytes) = ContractPackageStatus::from_bytes(bytes)?;
+                (lock_status, StorageSchemaVersions::new(), bytes)
+            }
+        };
         let result = ContractPackage {
             access_key,
             versions,
             disabled_versions,
             groups,
             lock_status,
+            storage_schema_versions,
         };
 
         Ok((result, bytes))
@@ -1301,6 +1391,29 @@ pub const ENTRY_POINT_NAME_INSTALL: &str = "install";
 /// Default name for an upgrade entry point
 pub const UPGRADE_ENTRY_POINT_NAME: &str = "upgrade";
 
+/// Name of the entry point which, if present on a newly added contract version, is called by the
+/// host within the same execution to migrate the state of the previous version.
+pub const MIGRATE_ENTRY_POINT_NAME: &str = "migrate";
+
+/// Name of the [`MIGRATE_ENTRY_POINT_NAME`] argument holding the hash of the previously current
+/// contract version, if any.
+pub const MIGRATE_ARG_PREVIOUS_CONTRACT_HASH: &str = "previous_contract_hash";
+
+/// Name of the [`MIGRATE_ENTRY_POINT_NAME`] argument holding the storage schema version declared by
+/// the previously current contract version, if any.
+pub const MIGRATE_ARG_PREVIOUS_STORAGE_SCHEMA_VERSION: &str = "previous_storage_schema_version";
+
+/// Tag written in place of the [`EntryPointType`] of an [`EntryPoint`] serialized in a versioned
+/// layout, followed by the layout version.
+///
+/// Entry points which are not views keep the original layout ending in their `EntryPointType`, so
+/// that contracts stored before views were introduced keep their encoding.
+const VERSIONED_ENTRY_POINT_TAG: u8 = 2;
+
+/// Version of the versioned [`EntryPoint`] layout, in which the `EntryPointType` is followed by
+/// whether the entry point is a view.
+const ENTRY_POINT_LAYOUT_VERSION_1: u8 = 1;
+
 /// Collection of entry point parameters.
 pub type Parameters = Vec<Parameter>;
 
@@ -1315,9 +1428,26 @@ pub struct EntryPoint {
     ret: CLType,
     access: EntryPointAccess,
     entry_point_type: EntryPointType,
+    /// Whether the entry point is a read-only view which can be evaluated without modifying
+    /// global state.
+    #[serde(default, skip_serializing_if = "is_false")]
+    view: bool,
 }
 
-impl From<EntryPoint> for (String, Parameters, CLType, EntryPointAccess, EntryPointType) {
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+impl From<EntryPoint>
+    for (
+        String,
+        Parameters,
+        CLType,
+        EntryPointAccess,
+        EntryPointType,
+        bool,
+    )
+{
     fn from(entry_point: EntryPoint) -> Self {
         (
             entry_point.name,
@@ -1325,6 +1455,7 @@ impl From<EntryPoint> for (String, Parameters, CLType, EntryPointAccess, EntryPo
             entry_point.ret,
             entry_point.access,
             entry_point.entry_point_type,
+            entry_point.view,
         )
     }
 }
@@ -1344,6 +1475,28 @@ impl EntryPoint {
             ret,
             access,
             entry_point_type,
+            view: false,
+        }
+    }
+
+    /// Constructs a view `EntryPoint`.
+    ///
+    /// View entry points are contract entry points which are not allowed to modify global state.
+    /// They can be invoked read-only by other contracts and evaluated against historical state
+    /// via the node's RPC interface.
+    pub fn new_view<T: Into<String>>(
+        name: T,
+        args: Parameters,
+        ret: CLType,
+        access: EntryPointAccess,
+    ) -> Self {
+        EntryPoint {
+            name: name.into(),
+            args,
+            ret,
+            access,
+            entry_point_type: EntryPointType::Contract,
+            view: true,
         }
     }
 
@@ -1379,6 +1532,34 @@ impl EntryPoint {
     pub fn entry_point_type(&self) -> EntryPointType {
         self.entry_point_type
     }
+
+    /// Returns `true` if this is a read-only view entry point.
+    pub fn is_view(&self) -> bool {
+        self.view
+    }
+
+    /// Writes the entry point type, preceded by the layout version if the entry point is a view.
+    fn write_entry_point_type_and_attributes(
+        &self,
+        writer: &mut Vec<u8>,
+    ) -> Result<(), bytesrepr::Error> {
+        if self.view {
+            writer.push(VERSIONED_ENTRY_POINT_TAG);
+            writer.push(ENTRY_POINT_LAYOUT_VERSION_1);
+            self.entry_point_type.write_bytes(writer)?;
+            self.view.write_bytes(writer)
+        } else {
+            self.entry_point_type.write_bytes(writer)
+        }
+    }
+
+    fn entry_point_type_and_attributes_serialized_length(&self) -> usize {
+        if self.view {
+            2 + self.entry_point_type.serialized_length() + self.view.serialized_length()
+        } else {
+            self.entry_point_type.serialized_length()
+        }
+    }
 }
 
 impl Default for EntryPoint {
@@ -1390,6 +1571,7 @@ impl Default for EntryPoint {
             ret: CLType::Unit,
             access: EntryPointAccess::Public,
             entry_point_type: EntryPointType::Session,
+            view: false,
         }
     }
 }
@@ -1401,7 +1583,7 @@ impl ToBytes for EntryPoint {
         result.append(&mut self.args.to_bytes()?);
         self.ret.append_bytes(&mut result)?;
         result.append(&mut self.access.to_bytes()?);
-        result.append(&mut self.entry_point_type.to_bytes()?);
+        self.write_entry_point_type_and_attributes(&mut result)?;
 
         Ok(result)
     }
@@ -1411,7 +1593,7 @@ impl ToBytes for EntryPoint {
             + self.args.serialized_length()
             + self.ret.serialized_length()
             + self.access.serialized_length()
-            + self.entry_point_type.serialized_length()
+            + self.entry_point_type_and_attributes_serialized_length()
     }
 
     fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
@@ -1419,8 +1601,7 @@ impl ToBytes for EntryPoint {
         self.args.write_bytes(writer)?;
         self.ret.append_bytes(writer)?;
         self.access().write_bytes(writer)?;
-        self.entry_point_type().write_bytes(writer)?;
-        Ok(())
+        self.write_entry_point_type_and_attributes(writer)
     }
 }
 
@@ -1430,7 +1611,21 @@ impl FromBytes for EntryPoint {
         let (args, bytes) = Vec::<Parameter>::from_bytes(bytes)?;
         let (ret, bytes) = CLType::from_bytes(bytes)?;
         let (access, bytes) = EntryPointAccess::from_bytes(bytes)?;
-        let (entry_point_type, bytes) = EntryPointType::from_bytes(bytes)?;
+        let (entry_point_type, view, bytes) = match bytes.first() {
+            Some(&VERSIONED_ENTRY_POINT_TAG) => {
+                let (version, bytes) = u8::from_bytes(&bytes[1..])?;
+                if version != ENTRY_POINT_LAYOUT_VERSION_1 {
+                    return Err(bytesrepr::Error::Formatting);
+                }
+                let (entry_point_type, bytes) = EntryPointType::from_bytes(bytes)?;
+                let (view, bytes) = bool::from_bytes(bytes)?;
+                (entry_point_type, view, bytes)
+            }
+            _ => {
+                let (entry_point_type, bytes) = EntryPointType::from_bytes(bytes)?;
+                (entry_point_type, false, bytes)
+            }
+        };
 
         Ok((
             EntryPoint {
@@ -1439,6 +1634,7 @@ impl FromBytes for EntryPoint {
                 ret,
                 access,
                 entry_point_type,
+                view,
             },
             bytes,
         ))
@@ -2079,6 +2275,88 @@ mod tests {
             "multiple access right bits to the same uref should coalesce"
         );
     }
+
+    #[test]
+    fn should_serialize_view_entry_point_compatibly() {
+        let entry_point = EntryPoint::new(
+            "method",
+            vec![Parameter::new("Foo", CLType::U32)],
+            CLType::U32,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        );
+        let bytes = entry_point.to_bytes().expect("should serialize");
+        assert_eq!(
+            *bytes.last().unwrap(),
+            EntryPointType::Contract as u8,
+            "non-view entry points should keep their original encoding"
+        );
+
+        let view_entry_point = EntryPoint::new_view(
+            "method",
+            vec![Parameter::new("Foo", CLType::U32)],
+            CLType::U32,
+            EntryPointAccess::Public,
+        );
+        assert!(view_entry_point.is_view());
+        let view_bytes = view_entry_point.to_bytes().expect("should serialize");
+        assert_eq!(view_bytes.len(), view_entry_point.serialized_length());
+        let (decoded, rem) = EntryPoint::from_bytes(&view_bytes).expect("should deserialize");
+        assert!(rem.is_empty());
+        assert_eq!(decoded, view_entry_point);
+
+        let mut unknown_version_bytes = view_bytes.clone();
+        let version_index = bytes.len();
+        unknown_version_bytes[version_index] = ENTRY_POINT_LAYOUT_VERSION_1 + 1;
+        assert!(EntryPoint::from_bytes(&unknown_version_bytes).is_err());
+
+        let (_, _, _, _, _, view) = view_entry_point.into();
+        assert!(view);
+
+        let json = serde_json::to_string(&entry_point).unwrap();
+        assert!(!json.contains("view"));
+        let decoded: EntryPoint = serde_json::from_str(&json).unwrap();
+        assert!(!decoded.is_view());
+    }
+
+    #[test]
+    fn should_serialize_storage_schema_versions_compatibly() {
+        let mut contract_package = make_contract_package();
+        let bytes = contract_package.to_bytes().expect("should serialize");
+        assert_eq!(
+            *bytes.last().unwrap(),
+            u8::from(false),
+            "packages without storage schema versions should keep their original encoding"
+        );
+
+        let v1 = ContractVersionKey::new(1, 1);
+        contract_package
+            .set_storage_schema_version(v1, 7)
+            .expect("should set storage schema version");
+        assert_eq!(contract_package.storage_schema_version(v1), Some(7));
+        assert_eq!(
+            contract_package.storage_schema_version(ContractVersionKey::new(1, 2)),
+            None
+        );
+        assert_eq!(
+            contract_package.set_storage_schema_version(ContractVersionKey::new(1, 3), 1),
+            Err(Error::ContractNotFound)
+        );
+
+        let bytes = contract_package.to_bytes().expect("should serialize");
+        assert_eq!(bytes.len(), contract_package.serialized_length());
+        let (decoded, rem) = ContractPackage::from_bytes(&bytes).expect("should deserialize");
+        assert!(rem.is_empty());
+        assert_eq!(decoded, contract_package);
+
+        let mut unversioned = contract_package.clone();
+        unversioned.storage_schema_versions = StorageSchemaVersions::new();
+        let version_index = unversioned.to_bytes().unwrap().len();
+        assert_eq!(bytes[version_index - 1], VERSIONED_CONTRACT_PACKAGE_TAG);
+        let mut unknown_version_bytes = bytes;
+        unknown_version_bytes[version_index] = CONTRACT_PACKAGE_LAYOUT_VERSION_1 + 1;
+        assert!(ContractPackage::from_bytes(&unknown_version_bytes).is_err());
+    }
 }
 
 #[cfg(test)]
@@ -2,6 +2,8 @@
 
 mod asymmetric_key;
 mod error;
+#[cfg(any(feature = "hd-keys", test))]
+mod hd_key;
 
 use blake2::{
     digest::{Update, VariableOutput},
@@ -13,13 +15,21 @@ use crate::key::BLAKE2B_DIGEST_LENGTH;
 pub use asymmetric_key::generate_ed25519_keypair;
 #[cfg(any(feature = "testing", feature = "gens", test))]
 pub use asymmetric_key::gens;
+#[cfg(any(feature = "bls", test))]
 pub use asymmetric_key::{
-    sign, verify, AsymmetricType, PublicKey, SecretKey, Signature, ED25519_TAG, SECP256K1_TAG,
-    SYSTEM_ACCOUNT, SYSTEM_TAG,
+    aggregate_signatures, prove_possession, verify_aggregate, verify_proof_of_possession,
+};
+pub use asymmetric_key::{
+    sign, verify, AsymmetricType, PublicKey, SecretKey, Signature, BLS_TAG, ED25519_TAG,
+    SECP256K1_TAG, SYSTEM_ACCOUNT, SYSTEM_TAG,
 };
 pub use error::Error;
 #[cfg(any(feature = "std", test))]
 pub use error::ErrorExt;
+#[cfg(any(feature = "hd-keys", test))]
+pub use hd_key::{
+    mnemonic_to_seed, ChildNumber, DerivationPath, HdKeyError, CASPER_COIN_TYPE, HARDENED_OFFSET,
+};
 
 #[doc(hidden)]
 pub fn blake2b<T: AsRef<[u8]>>(data: T) -> [u8; BLAKE2B_DIGEST_LENGTH] {
@@ -16,6 +16,11 @@ use core::{
 #[cfg(any(feature = "std", test))]
 use std::path::Path;
 
+#[cfg(any(feature = "bls", test))]
+use bls12_381::{
+    hash_to_curve::{ExpandMsgXmd, HashToCurve},
+    pairing, G1Affine, G1Projective, G2Affine, G2Projective, Scalar,
+};
 #[cfg(feature = "datasize")]
 use datasize::DataSize;
 #[cfg(any(feature = "std", test))]
@@ -35,6 +40,12 @@ use k256::ecdsa::{
 use once_cell::sync::Lazy;
 #[cfg(any(feature = "std", test))]
 use pem::Pem;
+#[cfg(any(feature = "std", test))]
+use pkcs8::{
+    der::{Decode, Encode},
+    pkcs5::pbes2,
+    DecodePrivateKey, EncodePrivateKey, EncryptedPrivateKeyInfo, SecretDocument,
+};
 #[cfg(any(all(feature = "std", feature = "testing"), test))]
 use rand::{Rng, RngCore};
 #[cfg(feature = "json-schema")]
@@ -80,10 +91,34 @@ const ED25519: &str = "Ed25519";
 pub const SECP256K1_TAG: u8 = 2;
 const SECP256K1: &str = "Secp256k1";
 
+/// Tag for BLS12-381 variant.
+pub const BLS_TAG: u8 = 3;
+#[cfg(any(feature = "bls", test))]
+const BLS: &str = "Bls";
+
 const SECP256K1_SECRET_KEY_LENGTH: usize = 32;
 const SECP256K1_COMPRESSED_PUBLIC_KEY_LENGTH: usize = 33;
 const SECP256K1_SIGNATURE_LENGTH: usize = 64;
 
+#[cfg(any(feature = "bls", test))]
+const BLS_SECRET_KEY_LENGTH: usize = 32;
+#[cfg(any(feature = "bls", test))]
+const BLS_COMPRESSED_PUBLIC_KEY_LENGTH: usize = 48;
+#[cfg(any(feature = "bls", test))]
+const BLS_COMPRESSED_SIGNATURE_LENGTH: usize = 96;
+
+// Domain separation tag of the minimal-pubkey-size proof-of-possession ciphersuite.  See
+// https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-05#section-4.2.3
+//
+// Aggregating signatures over a common message is only safe against rogue key attacks if every
+// BLS12-381 public key involved comes with a proof of possession of its secret key, which is a
+// signature over the public key itself using a distinct domain separation tag.
+#[cfg(any(feature = "bls", test))]
+const BLS_SIGNATURE_DOMAIN_SEPARATION_TAG: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+#[cfg(any(feature = "bls", test))]
+const BLS_PROOF_OF_POSSESSION_DOMAIN_SEPARATION_TAG: &[u8] =
+    b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
 /// Public key for system account.
 pub const SYSTEM_ACCOUNT: PublicKey = PublicKey::System;
 
@@ -107,6 +142,32 @@ const SECP256K1_PEM_SECRET_KEY_TAG: &str = "EC PRIVATE KEY";
 #[cfg(any(feature = "std", test))]
 const SECP256K1_PEM_PUBLIC_KEY_TAG: &str = "PUBLIC KEY";
 
+// BLS12-381 keys have no standard DER encoding, so their PEM contents are the raw key bytes.
+#[cfg(any(feature = "std", test))]
+#[cfg(any(feature = "bls", test))]
+const BLS_PEM_SECRET_KEY_TAG: &str = "BLS PRIVATE KEY";
+#[cfg(any(feature = "std", test))]
+#[cfg(any(feature = "bls", test))]
+const BLS_PEM_PUBLIC_KEY_TAG: &str = "BLS PUBLIC KEY";
+
+// See https://datatracker.ietf.org/doc/html/rfc5958#section-3
+#[cfg(any(feature = "std", test))]
+const ENCRYPTED_PEM_SECRET_KEY_TAG: &str = "ENCRYPTED PRIVATE KEY";
+// The scrypt parameters used when encrypting secret keys: N = 2^15, r = 8, p = 1 costs 32 MiB of
+// memory per derivation.
+#[cfg(any(feature = "std", test))]
+const SCRYPT_LOG_N: u8 = 15;
+#[cfg(any(feature = "std", test))]
+const SCRYPT_R: u32 = 8;
+#[cfg(any(feature = "std", test))]
+const SCRYPT_P: u32 = 1;
+#[cfg(any(feature = "std", test))]
+const SCRYPT_SALT_LENGTH: usize = 16;
+#[cfg(any(feature = "std", test))]
+const AES_256_KEY_LENGTH: usize = 32;
+#[cfg(any(feature = "std", test))]
+const AES_256_CBC_IV_LENGTH: usize = 16;
+
 #[cfg(any(feature = "std", test))]
 static ED25519_SECRET_KEY: Lazy<SecretKey> = Lazy::new(|| {
     let bytes = [15u8; SecretKey::ED25519_LENGTH];
@@ -160,9 +221,15 @@ where
             }
             ED25519_TAG => Self::ed25519_from_bytes(&key_bytes),
             SECP256K1_TAG => Self::secp256k1_from_bytes(&key_bytes),
+            #[cfg(any(feature = "bls", test))]
+            BLS_TAG => Self::bls_from_bytes(&key_bytes),
+            #[cfg(not(any(feature = "bls", test)))]
+            BLS_TAG => Err(Error::AsymmetricKey(
+                "failed to decode from hex: BLS12-381 requires the `bls` feature".to_string(),
+            )),
             _ => Err(Error::AsymmetricKey(format!(
-                "failed to decode from hex: invalid tag.  Expected {}, {} or {}, got {}",
-                SYSTEM_TAG, ED25519_TAG, SECP256K1_TAG, tag[0]
+                "failed to decode from hex: invalid tag.  Expected {}, {}, {} or {}, got {}",
+                SYSTEM_TAG, ED25519_TAG, SECP256K1_TAG, BLS_TAG, tag[0]
             ))),
         }
     }
@@ -175,6 +242,10 @@ where
 
     /// Constructs a new secp256k1 variant from a byte slice.
     fn secp256k1_from_bytes<T: AsRef<[u8]>>(bytes: T) -> Result<Self, Error>;
+
+    /// Constructs a new BLS12-381 variant from a byte slice.
+    #[cfg(any(feature = "bls", test))]
+    fn bls_from_bytes<T: AsRef<[u8]>>(bytes: T) -> Result<Self, Error>;
 }
 
 /// A secret or private asymmetric key.
@@ -190,6 +261,10 @@ pub enum SecretKey {
     /// secp256k1 secret key.
     #[cfg_attr(feature = "datasize", data_size(skip))]
     Secp256k1(Secp256k1SecretKey),
+    /// BLS12-381 secret key.
+    #[cfg(any(feature = "bls", test))]
+    #[cfg_attr(feature = "datasize", data_size(skip))]
+    Bls(Scalar),
 }
 
 impl SecretKey {
@@ -202,6 +277,10 @@ impl SecretKey {
     /// The length in bytes of a secp256k1 secret key.
     pub const SECP256K1_LENGTH: usize = SECP256K1_SECRET_KEY_LENGTH;
 
+    /// The length in bytes of a BLS12-381 secret key.
+    #[cfg(any(feature = "bls", test))]
+    pub const BLS_LENGTH: usize = BLS_SECRET_KEY_LENGTH;
+
     /// Constructs a new system variant.
     pub fn system() -> Self {
         SecretKey::System
@@ -221,11 +300,35 @@ impl SecretKey {
         ))
     }
 
+    /// Constructs a new BLS12-381 variant from a big-endian byte slice.
+    ///
+    /// The bytes must represent a non-zero scalar less than the order of the BLS12-381 groups.
+    #[cfg(any(feature = "bls", test))]
+    pub fn bls_from_bytes<T: AsRef<[u8]>>(bytes: T) -> Result<Self, Error> {
+        let mut le_bytes = <[u8; BLS_SECRET_KEY_LENGTH]>::try_from(bytes.as_ref())
+            .map_err(|_| Error::SignatureError)?;
+        le_bytes.reverse();
+        let scalar: Option<Scalar> = Scalar::from_bytes(&le_bytes).into();
+        match scalar {
+            Some(scalar) if scalar != Scalar::zero() => Ok(SecretKey::Bls(scalar)),
+            _ => Err(Error::SignatureError),
+        }
+    }
+
+    /// Constructs a new BLS12-381 variant by reducing the given uniformly random bytes.
+    #[cfg(any(feature = "std", feature = "testing", test))]
+    #[cfg(any(feature = "bls", test))]
+    fn bls_from_random_bytes(bytes: [u8; 2 * BLS_SECRET_KEY_LENGTH]) -> Self {
+        SecretKey::Bls(Scalar::from_bytes_wide(&bytes))
+    }
+
     fn variant_name(&self) -> &str {
         match self {
             SecretKey::System => SYSTEM,
             SecretKey::Ed25519(_) => ED25519,
             SecretKey::Secp256k1(_) => SECP256K1,
+            #[cfg(any(feature = "bls", test))]
+            SecretKey::Bls(_) => BLS,
         }
     }
 }
@@ -246,6 +349,14 @@ impl SecretKey {
         SecretKey::secp256k1_from_bytes(bytes).map_err(Into::into)
     }
 
+    /// Generates a new BLS12-381 variant using the system's secure random number generator.
+    #[cfg(any(feature = "bls", test))]
+    pub fn generate_bls() -> Result<Self, ErrorExt> {
+        let mut bytes = [0u8; 2 * Self::BLS_LENGTH];
+        getrandom::getrandom(&mut bytes[..])?;
+        Ok(SecretKey::bls_from_random_bytes(bytes))
+    }
+
     /// Attempts to write the key bytes to the configured file path.
     pub fn to_file<P: AsRef<Path>>(&self, file: P) -> Result<(), ErrorExt> {
         write_private_file(file, self.to_pem()?).map_err(ErrorExt::SecretKeySave)
@@ -291,6 +402,11 @@ impl SecretKey {
                 })?;
                 Ok(encoded)
             }
+            #[cfg(any(feature = "bls", test))]
+            SecretKey::Bls(_) => Err(Error::AsymmetricKey(String::from(
+                "BLS12-381 keys have no DER encoding",
+            ))
+            .into()),
         }
     }
 
@@ -371,12 +487,116 @@ impl SecretKey {
         }
     }
 
+    /// Encodes the key as a PKCS#8 `PrivateKeyInfo`.
+    fn to_private_key_info_der(&self) -> Result<SecretDocument, ErrorExt> {
+        match self {
+            SecretKey::System => Err(Error::System(String::from("to_encrypted_pem")).into()),
+            SecretKey::Ed25519(secret_key) => Ok(secret_key.to_pkcs8_der()?),
+            SecretKey::Secp256k1(secret_key) => {
+                Ok(k256::SecretKey::from(secret_key).to_pkcs8_der()?)
+            }
+            #[cfg(any(feature = "bls", test))]
+            SecretKey::Bls(_) => Err(Error::AsymmetricKey(String::from(
+                "BLS12-381 keys have no PKCS#8 encoding",
+            ))
+            .into()),
+        }
+    }
+
+    /// PEM encodes a key as a PKCS#8 `EncryptedPrivateKeyInfo`.
+    ///
+    /// The key is encrypted with AES-256-CBC under a key derived from `passphrase` using scrypt.
+    pub fn to_encrypted_pem<T: AsRef<[u8]>>(&self, passphrase: T) -> Result<String, ErrorExt> {
+        let private_key_info = self.to_private_key_info_der()?;
+
+        let mut salt = [0u8; SCRYPT_SALT_LENGTH];
+        getrandom::getrandom(&mut salt[..])?;
+        let mut iv = [0u8; AES_256_CBC_IV_LENGTH];
+        getrandom::getrandom(&mut iv[..])?;
+
+        let scrypt_params =
+            scrypt::Params::new(SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P, AES_256_KEY_LENGTH)
+                .map_err(|error| ErrorExt::Pkcs8(error.to_string()))?;
+        let encryption_params = pbes2::Parameters::scrypt_aes256cbc(scrypt_params, &salt, &iv)?;
+        let encrypted_data = encryption_params.encrypt(passphrase, private_key_info.as_bytes())?;
+        let contents = EncryptedPrivateKeyInfo {
+            encryption_algorithm: encryption_params.into(),
+            encrypted_data: &encrypted_data,
+        }
+        .to_der()?;
+
+        let pem = Pem {
+            tag: ENCRYPTED_PEM_SECRET_KEY_TAG.to_string(),
+            contents,
+        };
+        Ok(pem::encode(&pem))
+    }
+
+    /// Decodes a key from a PEM-encoded PKCS#8 `EncryptedPrivateKeyInfo`.
+    ///
+    /// Keys encrypted using PBES2 with either scrypt or PBKDF2 are supported.
+    pub fn from_encrypted_pem<T: AsRef<[u8]>, U: AsRef<[u8]>>(
+        input: T,
+        passphrase: U,
+    ) -> Result<Self, ErrorExt> {
+        let pem = pem::parse(input)?;
+        if pem.tag != ENCRYPTED_PEM_SECRET_KEY_TAG {
+            return Err(ErrorExt::FromPem(format!(
+                "invalid tag: expected {}, got {}",
+                ENCRYPTED_PEM_SECRET_KEY_TAG, pem.tag
+            )));
+        }
+
+        let private_key_info =
+            EncryptedPrivateKeyInfo::from_der(&pem.contents)?.decrypt(passphrase)?;
+        if let Ok(secret_key) = Ed25519SecretKey::from_pkcs8_der(private_key_info.as_bytes()) {
+            return Ok(SecretKey::Ed25519(secret_key));
+        }
+        Ok(SecretKey::Secp256k1(Secp256k1SecretKey::from_pkcs8_der(
+            private_key_info.as_bytes(),
+        )?))
+    }
+
+    /// Returns `true` if `input` is a PEM-encoded encrypted secret key.
+    pub fn is_encrypted_pem<T: AsRef<[u8]>>(input: T) -> bool {
+        pem::parse(input).map_or(false, |pem| pem.tag == ENCRYPTED_PEM_SECRET_KEY_TAG)
+    }
+
+    /// Attempts to write the key bytes, encrypted under `passphrase`, to the configured file path.
+    pub fn to_encrypted_file<P: AsRef<Path>, T: AsRef<[u8]>>(
+        &self,
+        file: P,
+        passphrase: T,
+    ) -> Result<(), ErrorExt> {
+        write_private_file(file, self.to_encrypted_pem(passphrase)?)
+            .map_err(ErrorExt::SecretKeySave)
+    }
+
+    /// Attempts to read and decrypt the key bytes from configured file path.
+    pub fn from_encrypted_file<P: AsRef<Path>, T: AsRef<[u8]>>(
+        file: P,
+        passphrase: T,
+    ) -> Result<Self, ErrorExt> {
+        let data = read_file(file).map_err(ErrorExt::SecretKeyLoad)?;
+        Self::from_encrypted_pem(data, passphrase)
+    }
+
     /// PEM encodes a key.
     pub fn to_pem(&self) -> Result<String, ErrorExt> {
         let tag = match self {
             SecretKey::System => return Err(Error::System(String::from("to_pem")).into()),
             SecretKey::Ed25519(_) => ED25519_PEM_SECRET_KEY_TAG.to_string(),
             SecretKey::Secp256k1(_) => SECP256K1_PEM_SECRET_KEY_TAG.to_string(),
+            #[cfg(any(feature = "bls", test))]
+            SecretKey::Bls(secret_key) => {
+                let mut contents = secret_key.to_bytes().to_vec();
+                contents.reverse();
+                let pem = Pem {
+                    tag: BLS_PEM_SECRET_KEY_TAG.to_string(),
+                    contents,
+                };
+                return Ok(pem::encode(&pem));
+            }
         };
         let contents = self.to_der()?;
         let pem = Pem { tag, contents };
@@ -387,6 +607,16 @@ impl SecretKey {
     pub fn from_pem<T: AsRef<[u8]>>(input: T) -> Result<Self, ErrorExt> {
         let pem = pem::parse(input)?;
 
+        #[cfg(any(feature = "bls", test))]
+        if pem.tag == BLS_PEM_SECRET_KEY_TAG {
+            return SecretKey::bls_from_bytes(&pem.contents).map_err(Into::into);
+        }
+        if pem.tag == ENCRYPTED_PEM_SECRET_KEY_TAG {
+            return Err(ErrorExt::FromPem(String::from(
+                "secret key is encrypted and requires a passphrase",
+            )));
+        }
+
         let secret_key = Self::from_der(&pem.contents)?;
 
         let bad_tag = |expected_tag: &str| {
@@ -408,6 +638,8 @@ impl SecretKey {
                     return Err(bad_tag(SECP256K1_PEM_SECRET_KEY_TAG));
                 }
             }
+            #[cfg(any(feature = "bls", test))]
+            SecretKey::Bls(_) => return Err(bad_tag(BLS_PEM_SECRET_KEY_TAG)),
         }
 
         Ok(secret_key)
@@ -439,6 +671,15 @@ impl SecretKey {
         SecretKey::secp256k1_from_bytes(bytes).unwrap()
     }
 
+    /// Generates a random BLS12-381 instance using a `TestRng`.
+    #[cfg(any(feature = "testing", test))]
+    #[cfg(any(feature = "bls", test))]
+    pub fn random_bls(rng: &mut TestRng) -> Self {
+        let mut bytes = [0u8; 2 * Self::BLS_LENGTH];
+        rng.fill_bytes(&mut bytes[..]);
+        SecretKey::bls_from_random_bytes(bytes)
+    }
+
     /// Returns an example value for documentation purposes.
     pub fn doc_example() -> &'static Self {
         &ED25519_SECRET_KEY
@@ -463,6 +704,8 @@ impl Tagged<u8> for SecretKey {
             SecretKey::System => SYSTEM_TAG,
             SecretKey::Ed25519(_) => ED25519_TAG,
             SecretKey::Secp256k1(_) => SECP256K1_TAG,
+            #[cfg(any(feature = "bls", test))]
+            SecretKey::Bls(_) => BLS_TAG,
         }
     }
 }
@@ -480,6 +723,10 @@ pub enum PublicKey {
     /// secp256k1 public key.
     #[cfg_attr(feature = "datasize", data_size(skip))]
     Secp256k1(Secp256k1PublicKey),
+    /// BLS12-381 public key.
+    #[cfg(any(feature = "bls", test))]
+    #[cfg_attr(feature = "datasize", data_size(skip))]
+    Bls(G1Affine),
 }
 
 impl PublicKey {
@@ -492,6 +739,10 @@ impl PublicKey {
     /// The length in bytes of a secp256k1 public key.
     pub const SECP256K1_LENGTH: usize = SECP256K1_COMPRESSED_PUBLIC_KEY_LENGTH;
 
+    /// The length in bytes of a BLS12-381 public key.
+    #[cfg(any(feature = "bls", test))]
+    pub const BLS_LENGTH: usize = BLS_COMPRESSED_PUBLIC_KEY_LENGTH;
+
     /// Creates an `AccountHash` from a given `PublicKey` instance.
     pub fn to_account_hash(&self) -> AccountHash {
         AccountHash::from(self)
@@ -507,6 +758,8 @@ impl PublicKey {
             PublicKey::System => SYSTEM,
             PublicKey::Ed25519(_) => ED25519,
             PublicKey::Secp256k1(_) => SECP256K1,
+            #[cfg(any(feature = "bls", test))]
+            PublicKey::Bls(_) => BLS,
         }
     }
 }
@@ -565,6 +818,11 @@ impl PublicKey {
                 })?;
                 Ok(encoded)
             }
+            #[cfg(any(feature = "bls", test))]
+            PublicKey::Bls(_) => Err(Error::AsymmetricKey(String::from(
+                "BLS12-381 keys have no DER encoding",
+            ))
+            .into()),
         }
     }
 
@@ -613,6 +871,14 @@ impl PublicKey {
             PublicKey::System => return Err(Error::System(String::from("to_pem")).into()),
             PublicKey::Ed25519(_) => ED25519_PEM_PUBLIC_KEY_TAG.to_string(),
             PublicKey::Secp256k1(_) => SECP256K1_PEM_PUBLIC_KEY_TAG.to_string(),
+            #[cfg(any(feature = "bls", test))]
+            PublicKey::Bls(public_key) => {
+                let pem = Pem {
+                    tag: BLS_PEM_PUBLIC_KEY_TAG.to_string(),
+                    contents: public_key.to_compressed().to_vec(),
+                };
+                return Ok(pem::encode(&pem));
+            }
         };
         let contents = self.to_der()?;
         let pem = Pem { tag, contents };
@@ -622,6 +888,10 @@ impl PublicKey {
     /// Decodes a key from a PEM-encoded slice.
     pub fn from_pem<T: AsRef<[u8]>>(input: T) -> Result<Self, ErrorExt> {
         let pem = pem::parse(input)?;
+        #[cfg(any(feature = "bls", test))]
+        if pem.tag == BLS_PEM_PUBLIC_KEY_TAG {
+            return PublicKey::bls_from_bytes(&pem.contents).map_err(Into::into);
+        }
         let public_key = Self::from_der(&pem.contents)?;
         let bad_tag = |expected_tag: &str| {
             ErrorExt::FromPem(format!(
@@ -641,6 +911,8 @@ impl PublicKey {
                     return Err(bad_tag(SECP256K1_PEM_PUBLIC_KEY_TAG));
                 }
             }
+            #[cfg(any(feature = "bls", test))]
+            PublicKey::Bls(_) => return Err(bad_tag(BLS_PEM_PUBLIC_KEY_TAG)),
         }
         Ok(public_key)
     }
@@ -666,6 +938,14 @@ impl PublicKey {
         PublicKey::from(&secret_key)
     }
 
+    /// Generates a random BLS12-381 instance using a `TestRng`.
+    #[cfg(any(feature = "testing", test))]
+    #[cfg(any(feature = "bls", test))]
+    pub fn random_bls(rng: &mut TestRng) -> Self {
+        let secret_key = SecretKey::random_bls(rng);
+        PublicKey::from(&secret_key)
+    }
+
     /// Returns an example value for documentation purposes.
     pub fn doc_example() -> &'static Self {
         &ED25519_PUBLIC_KEY
@@ -689,6 +969,19 @@ impl AsymmetricType<'_> for PublicKey {
                 .map_err(|_| Error::SignatureError)?,
         ))
     }
+
+    #[cfg(any(feature = "bls", test))]
+    fn bls_from_bytes<T: AsRef<[u8]>>(bytes: T) -> Result<Self, Error> {
+        let compressed = <[u8; BLS_COMPRESSED_PUBLIC_KEY_LENGTH]>::try_from(bytes.as_ref())
+            .map_err(|_| Error::SignatureError)?;
+        let public_key: Option<G1Affine> = G1Affine::from_compressed(&compressed).into();
+        match public_key {
+            Some(public_key) if !bool::from(public_key.is_identity()) => {
+                Ok(PublicKey::Bls(public_key))
+            }
+            _ => Err(Error::SignatureError),
+        }
+    }
 }
 
 impl From<&SecretKey> for PublicKey {
@@ -697,6 +990,10 @@ impl From<&SecretKey> for PublicKey {
             SecretKey::System => PublicKey::System,
             SecretKey::Ed25519(secret_key) => PublicKey::Ed25519(secret_key.into()),
             SecretKey::Secp256k1(secret_key) => PublicKey::Secp256k1(secret_key.into()),
+            #[cfg(any(feature = "bls", test))]
+            SecretKey::Bls(secret_key) => {
+                PublicKey::Bls(G1Affine::from(G1Projective::generator() * secret_key))
+            }
         }
     }
 }
@@ -707,6 +1004,8 @@ impl From<&PublicKey> for Vec<u8> {
             PublicKey::System => Vec::new(),
             PublicKey::Ed25519(key) => key.to_bytes().into(),
             PublicKey::Secp256k1(key) => key.to_encoded_point(true).as_ref().into(),
+            #[cfg(any(feature = "bls", test))]
+            PublicKey::Bls(key) => key.to_compressed().into(),
         }
     }
 }
@@ -773,6 +1072,8 @@ impl Tagged<u8> for PublicKey {
             PublicKey::System => SYSTEM_TAG,
             PublicKey::Ed25519(_) => ED25519_TAG,
             PublicKey::Secp256k1(_) => SECP256K1_TAG,
+            #[cfg(any(feature = "bls", test))]
+            PublicKey::Bls(_) => BLS_TAG,
         }
     }
 }
@@ -790,6 +1091,8 @@ impl ToBytes for PublicKey {
                 PublicKey::System => Self::SYSTEM_LENGTH,
                 PublicKey::Ed25519(_) => Self::ED25519_LENGTH,
                 PublicKey::Secp256k1(_) => Self::SECP256K1_LENGTH,
+                #[cfg(any(feature = "bls", test))]
+                PublicKey::Bls(_) => Self::BLS_LENGTH,
             }
     }
 
@@ -804,6 +1107,11 @@ impl ToBytes for PublicKey {
                 writer.push(SECP256K1_TAG);
                 writer.extend_from_slice(public_key.to_encoded_point(true).as_ref());
             }
+            #[cfg(any(feature = "bls", test))]
+            PublicKey::Bls(public_key) => {
+                writer.push(BLS_TAG);
+                writer.extend_from_slice(&public_key.to_compressed());
+            }
         }
         Ok(())
     }
@@ -828,6 +1136,14 @@ impl FromBytes for PublicKey {
                     .map_err(|_error| bytesrepr::Error::Formatting)?;
                 Ok((public_key, remainder))
             }
+            #[cfg(any(feature = "bls", test))]
+            BLS_TAG => {
+                let (raw_bytes, remainder): ([u8; Self::BLS_LENGTH], _) =
+                    FromBytes::from_bytes(remainder)?;
+                let public_key = Self::bls_from_bytes(raw_bytes)
+                    .map_err(|_error| bytesrepr::Error::Formatting)?;
+                Ok((public_key, remainder))
+            }
             _ => Err(bytesrepr::Error::Formatting),
         }
     }
@@ -878,6 +1194,13 @@ impl JsonSchema for PublicKey {
                 "value": "0203408e9526316fd1f8def480dd45b2cc72ffd732771c9ceb5d92ffa4051e6ee084"
             }),
         ];
+        #[cfg(any(feature = "bls", test))]
+        schema_object.metadata().examples.push(json!({
+            "name": "BlsPublicKey",
+            "description": "A BLS12-381 public key. Its hex-encoded value begins '03' and is \
+            followed by 96 characters",
+            "value": "038540794f07558ab18c4e6bdf3fc33eb1c7cf835906930a00f2116feaab4aa48304a33e883b314909867a66175eb73191"
+        }));
         schema_object.into()
     }
 }
@@ -901,6 +1224,10 @@ pub enum Signature {
     /// Secp256k1 signature.
     #[cfg_attr(feature = "datasize", data_size(skip))]
     Secp256k1(Secp256k1Signature),
+    /// BLS12-381 signature, possibly an aggregate of several signatures of the same message.
+    #[cfg(any(feature = "bls", test))]
+    #[cfg_attr(feature = "datasize", data_size(skip))]
+    Bls(G2Affine),
 }
 
 impl Signature {
@@ -913,6 +1240,10 @@ impl Signature {
     /// The length in bytes of a secp256k1 signature
     pub const SECP256K1_LENGTH: usize = SECP256K1_SIGNATURE_LENGTH;
 
+    /// The length in bytes of a BLS12-381 signature
+    #[cfg(any(feature = "bls", test))]
+    pub const BLS_LENGTH: usize = BLS_COMPRESSED_SIGNATURE_LENGTH;
+
     /// Constructs a new Ed25519 variant from a byte array.
     pub fn ed25519(bytes: [u8; Self::ED25519_LENGTH]) -> Result<Self, Error> {
         let signature = Ed25519Signature::from_bytes(&bytes);
@@ -931,11 +1262,25 @@ impl Signature {
         Ok(Signature::Secp256k1(signature))
     }
 
+    /// Constructs a new BLS12-381 variant from a byte array.
+    #[cfg(any(feature = "bls", test))]
+    pub fn bls(bytes: [u8; Self::BLS_LENGTH]) -> Result<Self, Error> {
+        let signature: Option<G2Affine> = G2Affine::from_compressed(&bytes).into();
+        signature.map(Signature::Bls).ok_or_else(|| {
+            Error::AsymmetricKey(format!(
+                "failed to construct BLS12-381 signature from {:?}",
+                &bytes[..]
+            ))
+        })
+    }
+
     fn variant_name(&self) -> &str {
         match self {
             Signature::System => SYSTEM,
             Signature::Ed25519(_) => ED25519,
             Signature::Secp256k1(_) => SECP256K1,
+            #[cfg(any(feature = "bls", test))]
+            Signature::Bls(_) => BLS,
         }
     }
 }
@@ -964,6 +1309,18 @@ impl AsymmetricType<'_> for Signature {
         })?;
         Ok(Signature::Secp256k1(signature))
     }
+
+    #[cfg(any(feature = "bls", test))]
+    fn bls_from_bytes<T: AsRef<[u8]>>(bytes: T) -> Result<Self, Error> {
+        let bytes =
+            <[u8; BLS_COMPRESSED_SIGNATURE_LENGTH]>::try_from(bytes.as_ref()).map_err(|_| {
+                Error::AsymmetricKey(format!(
+                    "failed to construct BLS12-381 signature from {:?}",
+                    bytes.as_ref()
+                ))
+            })?;
+        Signature::bls(bytes)
+    }
 }
 
 impl Debug for Signature {
@@ -1027,6 +1384,8 @@ impl Tagged<u8> for Signature {
             Signature::System => SYSTEM_TAG,
             Signature::Ed25519(_) => ED25519_TAG,
             Signature::Secp256k1(_) => SECP256K1_TAG,
+            #[cfg(any(feature = "bls", test))]
+            Signature::Bls(_) => BLS_TAG,
         }
     }
 }
@@ -1044,6 +1403,8 @@ impl ToBytes for Signature {
                 Signature::System => Self::SYSTEM_LENGTH,
                 Signature::Ed25519(_) => Self::ED25519_LENGTH,
                 Signature::Secp256k1(_) => Self::SECP256K1_LENGTH,
+                #[cfg(any(feature = "bls", test))]
+                Signature::Bls(_) => Self::BLS_LENGTH,
             }
     }
 
@@ -1060,6 +1421,11 @@ impl ToBytes for Signature {
                 writer.push(SECP256K1_TAG);
                 writer.extend_from_slice(&signature.to_bytes());
             }
+            #[cfg(any(feature = "bls", test))]
+            Signature::Bls(signature) => {
+                writer.push(BLS_TAG);
+                writer.extend_from_slice(&signature.to_compressed());
+            }
         }
         Ok(())
     }
@@ -1084,6 +1450,14 @@ impl FromBytes for Signature {
                     Self::secp256k1(raw_bytes).map_err(|_error| bytesrepr::Error::Formatting)?;
                 Ok((public_key, remainder))
             }
+            #[cfg(any(feature = "bls", test))]
+            BLS_TAG => {
+                let (raw_bytes, remainder): ([u8; Self::BLS_LENGTH], _) =
+                    FromBytes::from_bytes(remainder)?;
+                let signature =
+                    Self::bls(raw_bytes).map_err(|_error| bytesrepr::Error::Formatting)?;
+                Ok((signature, remainder))
+            }
             _ => Err(bytesrepr::Error::Formatting),
         }
     }
@@ -1107,6 +1481,8 @@ impl From<&Signature> for Vec<u8> {
             Signature::System => Vec::new(),
             Signature::Ed25519(signature) => signature.to_bytes().into(),
             Signature::Secp256k1(signature) => (*signature.to_bytes()).into(),
+            #[cfg(any(feature = "bls", test))]
+            Signature::Bls(signature) => signature.to_compressed().into(),
         }
     }
 }
@@ -1154,6 +1530,13 @@ pub fn sign<T: AsRef<[u8]>>(
                 .expect("should create signature");
             Signature::Secp256k1(signature)
         }
+        #[cfg(any(feature = "bls", test))]
+        (SecretKey::Bls(secret_key), PublicKey::Bls(_public_key)) => {
+            let signature =
+                bls_hash_to_curve(message.as_ref(), BLS_SIGNATURE_DOMAIN_SEPARATION_TAG)
+                    * secret_key;
+            Signature::Bls(G2Affine::from(signature))
+        }
         _ => panic!("secret and public key types must match"),
     }
 }
@@ -1179,6 +1562,13 @@ pub fn verify<T: AsRef<[u8]>>(
                     Error::AsymmetricKey(format!("failed to verify secp256k1 signature: {}", error))
                 })
         }
+        #[cfg(any(feature = "bls", test))]
+        (Signature::Bls(signature), PublicKey::Bls(public_key)) => bls_verify(
+            message.as_ref(),
+            signature,
+            public_key,
+            BLS_SIGNATURE_DOMAIN_SEPARATION_TAG,
+        ),
         _ => Err(Error::AsymmetricKey(format!(
             "type mismatch between {} and {}",
             signature, public_key
@@ -1186,6 +1576,155 @@ pub fn verify<T: AsRef<[u8]>>(
     }
 }
 
+/// Aggregates the given BLS12-381 signatures into a single signature.
+///
+/// The aggregate of signatures of the same message can be verified against the corresponding
+/// public keys via [`verify_aggregate`].
+#[cfg(any(feature = "bls", test))]
+pub fn aggregate_signatures<'a, I>(signatures: I) -> Result<Signature, Error>
+where
+    I: IntoIterator<Item = &'a Signature>,
+{
+    let mut aggregate = G2Projective::identity();
+    let mut count = 0_usize;
+    for signature in signatures {
+        match signature {
+            Signature::Bls(signature) => aggregate += G2Projective::from(signature),
+            _ => {
+                return Err(Error::AsymmetricKey(format!(
+                    "cannot aggregate non-BLS12-381 signature {}",
+                    signature
+                )))
+            }
+        }
+        count += 1;
+    }
+    if count == 0 {
+        return Err(Error::AsymmetricKey(String::from(
+            "cannot aggregate an empty collection of signatures",
+        )));
+    }
+    Ok(Signature::Bls(G2Affine::from(aggregate)))
+}
+
+/// Verifies an aggregate BLS12-381 signature of the given message against all the given public
+/// keys, each of which is expected to have signed the message exactly once.
+#[cfg(any(feature = "bls", test))]
+pub fn verify_aggregate<'a, T, I>(
+    message: T,
+    aggregate_signature: &Signature,
+    public_keys: I,
+) -> Result<(), Error>
+where
+    T: AsRef<[u8]>,
+    I: IntoIterator<Item = &'a PublicKey>,
+{
+    let signature = match aggregate_signature {
+        Signature::Bls(signature) => signature,
+        _ => {
+            return Err(Error::AsymmetricKey(format!(
+                "{} is not a BLS12-381 aggregate signature",
+                aggregate_signature
+            )))
+        }
+    };
+    let mut aggregate_public_key = G1Projective::identity();
+    let mut count = 0_usize;
+    for public_key in public_keys {
+        match public_key {
+            PublicKey::Bls(public_key) => aggregate_public_key += G1Projective::from(public_key),
+            _ => {
+                return Err(Error::AsymmetricKey(format!(
+                    "cannot aggregate non-BLS12-381 public key {}",
+                    public_key
+                )))
+            }
+        }
+        count += 1;
+    }
+    if count == 0 {
+        return Err(Error::AsymmetricKey(String::from(
+            "cannot verify an aggregate signature without public keys",
+        )));
+    }
+    bls_verify(
+        message.as_ref(),
+        signature,
+        &G1Affine::from(aggregate_public_key),
+        BLS_SIGNATURE_DOMAIN_SEPARATION_TAG,
+    )
+}
+
+/// Creates a proof of possession of the given BLS12-381 secret key.
+///
+/// A BLS12-381 validator key must be accompanied by such a proof before its signatures may be
+/// aggregated with others, see [`verify_proof_of_possession`].
+#[cfg(any(feature = "bls", test))]
+pub fn prove_possession(secret_key: &SecretKey) -> Result<Signature, Error> {
+    match secret_key {
+        SecretKey::Bls(secret_key) => {
+            let public_key = G1Affine::from(G1Projective::generator() * secret_key);
+            let proof = bls_hash_to_curve(
+                &public_key.to_compressed(),
+                BLS_PROOF_OF_POSSESSION_DOMAIN_SEPARATION_TAG,
+            ) * secret_key;
+            Ok(Signature::Bls(G2Affine::from(proof)))
+        }
+        _ => Err(Error::AsymmetricKey(format!(
+            "cannot prove possession of non-BLS12-381 secret key {}",
+            secret_key
+        ))),
+    }
+}
+
+/// Verifies a proof of possession of the secret key corresponding to the given BLS12-381 public
+/// key, as created by [`prove_possession`].
+#[cfg(any(feature = "bls", test))]
+pub fn verify_proof_of_possession(public_key: &PublicKey, proof: &Signature) -> Result<(), Error> {
+    match (public_key, proof) {
+        (PublicKey::Bls(public_key), Signature::Bls(proof)) => bls_verify(
+            &public_key.to_compressed(),
+            proof,
+            public_key,
+            BLS_PROOF_OF_POSSESSION_DOMAIN_SEPARATION_TAG,
+        ),
+        _ => Err(Error::AsymmetricKey(format!(
+            "{} is not a BLS12-381 proof of possession for {}",
+            proof, public_key
+        ))),
+    }
+}
+
+#[cfg(any(feature = "bls", test))]
+fn bls_hash_to_curve(message: &[u8], domain_separation_tag: &[u8]) -> G2Projective {
+    <G2Projective as HashToCurve<ExpandMsgXmd<sha2_09::Sha256>>>::hash_to_curve(
+        message,
+        domain_separation_tag,
+    )
+}
+
+#[cfg(any(feature = "bls", test))]
+fn bls_verify(
+    message: &[u8],
+    signature: &G2Affine,
+    public_key: &G1Affine,
+    domain_separation_tag: &[u8],
+) -> Result<(), Error> {
+    if bool::from(public_key.is_identity()) {
+        return Err(Error::AsymmetricKey(String::from(
+            "failed to verify BLS12-381 signature: identity public key",
+        )));
+    }
+    let hashed_message = G2Affine::from(bls_hash_to_curve(message, domain_separation_tag));
+    if pairing(&G1Affine::generator(), signature) == pairing(public_key, &hashed_message) {
+        Ok(())
+    } else {
+        Err(Error::AsymmetricKey(String::from(
+            "failed to verify BLS12-381 signature",
+        )))
+    }
+}
+
 /// Generates an Ed25519 keypair using the operating system's cryptographically secure random number
 /// generator.
 #[cfg(any(feature = "std", test))]
@@ -1212,6 +1751,8 @@ mod detail {
         System,
         Ed25519(Vec<u8>),
         Secp256k1(Vec<u8>),
+        #[cfg(any(feature = "bls", test))]
+        Bls(Vec<u8>),
     }
 
     impl From<&PublicKey> for AsymmetricTypeAsBytes {
@@ -1220,6 +1761,8 @@ mod detail {
                 PublicKey::System => AsymmetricTypeAsBytes::System,
                 key @ PublicKey::Ed25519(_) => AsymmetricTypeAsBytes::Ed25519(key.into()),
                 key @ PublicKey::Secp256k1(_) => AsymmetricTypeAsBytes::Secp256k1(key.into()),
+                #[cfg(any(feature = "bls", test))]
+                key @ PublicKey::Bls(_) => AsymmetricTypeAsBytes::Bls(key.into()),
             }
         }
     }
@@ -1230,6 +1773,8 @@ mod detail {
                 Signature::System => AsymmetricTypeAsBytes::System,
                 key @ Signature::Ed25519(_) => AsymmetricTypeAsBytes::Ed25519(key.into()),
                 key @ Signature::Secp256k1(_) => AsymmetricTypeAsBytes::Secp256k1(key.into()),
+                #[cfg(any(feature = "bls", test))]
+                key @ Signature::Bls(_) => AsymmetricTypeAsBytes::Bls(key.into()),
             }
         }
     }
@@ -1269,6 +1814,10 @@ mod detail {
             AsymmetricTypeAsBytes::Secp256k1(raw_bytes) => {
                 T::secp256k1_from_bytes(raw_bytes).map_err(D::Error::custom)
             }
+            #[cfg(any(feature = "bls", test))]
+            AsymmetricTypeAsBytes::Bls(raw_bytes) => {
+                T::bls_from_bytes(raw_bytes).map_err(D::Error::custom)
+            }
         }
     }
 }
@@ -1,44 +1,67 @@
 //! Generators for asymmetric key types
 
+use alloc::vec;
 use core::convert::TryInto;
 
 use proptest::{
     collection,
     prelude::{Arbitrary, Just, Strategy},
-    prop_oneof,
+    strategy::{BoxedStrategy, Union},
 };
 
 use crate::{crypto::SecretKey, PublicKey};
 
 /// Creates an arbitrary [`PublicKey`]
 pub fn public_key_arb() -> impl Strategy<Value = PublicKey> {
-    prop_oneof![
-        Just(PublicKey::System),
-        collection::vec(<u8>::arbitrary(), SecretKey::ED25519_LENGTH).prop_map(|bytes| {
-            let byte_array: [u8; SecretKey::ED25519_LENGTH] = bytes.try_into().unwrap();
-            let secret_key = SecretKey::ed25519_from_bytes(byte_array).unwrap();
-            PublicKey::from(&secret_key)
-        }),
-        collection::vec(<u8>::arbitrary(), SecretKey::SECP256K1_LENGTH).prop_map(|bytes| {
-            let bytes_array: [u8; SecretKey::SECP256K1_LENGTH] = bytes.try_into().unwrap();
-            let secret_key = SecretKey::secp256k1_from_bytes(bytes_array).unwrap();
-            PublicKey::from(&secret_key)
-        })
-    ]
+    Union::new(vec![
+        Just(PublicKey::System).boxed(),
+        ed25519_public_key_arb(),
+        secp256k1_public_key_arb(),
+        #[cfg(any(feature = "bls", test))]
+        bls_public_key_arb(),
+    ])
 }
 
 /// Returns a strategy for creating random [`PublicKey`] instances but NOT system variant.
 pub fn public_key_arb_no_system() -> impl Strategy<Value = PublicKey> {
-    prop_oneof![
-        collection::vec(<u8>::arbitrary(), SecretKey::ED25519_LENGTH).prop_map(|bytes| {
+    Union::new(vec![
+        ed25519_public_key_arb(),
+        secp256k1_public_key_arb(),
+        #[cfg(any(feature = "bls", test))]
+        bls_public_key_arb(),
+    ])
+}
+
+fn ed25519_public_key_arb() -> BoxedStrategy<PublicKey> {
+    collection::vec(<u8>::arbitrary(), SecretKey::ED25519_LENGTH)
+        .prop_map(|bytes| {
             let byte_array: [u8; SecretKey::ED25519_LENGTH] = bytes.try_into().unwrap();
             let secret_key = SecretKey::ed25519_from_bytes(byte_array).unwrap();
             PublicKey::from(&secret_key)
-        }),
-        collection::vec(<u8>::arbitrary(), SecretKey::SECP256K1_LENGTH).prop_map(|bytes| {
+        })
+        .boxed()
+}
+
+fn secp256k1_public_key_arb() -> BoxedStrategy<PublicKey> {
+    collection::vec(<u8>::arbitrary(), SecretKey::SECP256K1_LENGTH)
+        .prop_map(|bytes| {
             let bytes_array: [u8; SecretKey::SECP256K1_LENGTH] = bytes.try_into().unwrap();
             let secret_key = SecretKey::secp256k1_from_bytes(bytes_array).unwrap();
             PublicKey::from(&secret_key)
         })
-    ]
+        .boxed()
+}
+
+#[cfg(any(feature = "bls", test))]
+fn bls_public_key_arb() -> BoxedStrategy<PublicKey> {
+    collection::vec(<u8>::arbitrary(), SecretKey::BLS_LENGTH)
+        .prop_map(|bytes| {
+            let mut bytes_array: [u8; SecretKey::BLS_LENGTH] = bytes.try_into().unwrap();
+            // Ensure the big-endian scalar is non-zero and less than the group order.
+            bytes_array[0] &= 0x3f;
+            bytes_array[SecretKey::BLS_LENGTH - 1] |= 1;
+            let secret_key = SecretKey::bls_from_bytes(bytes_array).unwrap();
+            PublicKey::from(&secret_key)
+        })
+        .boxed()
 }
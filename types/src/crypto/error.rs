@@ -101,6 +101,10 @@ pub enum ErrorExt {
     /// Error in getting random bytes from the system's preferred random number source.
     #[error("failed to get random bytes: {0}")]
     GetRandomBytes(#[from] getrandom::Error),
+
+    /// PKCS#8 encoding, encryption or decryption error.
+    #[error("pkcs8 error: {0}")]
+    Pkcs8(String),
 }
 
 #[cfg(any(feature = "std", test))]
@@ -109,3 +113,24 @@ impl From<PemError> for ErrorExt {
         ErrorExt::FromPem(error.to_string())
     }
 }
+
+#[cfg(any(feature = "std", test))]
+impl From<pkcs8::Error> for ErrorExt {
+    fn from(error: pkcs8::Error) -> Self {
+        ErrorExt::Pkcs8(error.to_string())
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+impl From<pkcs8::der::Error> for ErrorExt {
+    fn from(error: pkcs8::der::Error) -> Self {
+        ErrorExt::Pkcs8(error.to_string())
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+impl From<pkcs8::pkcs5::Error> for ErrorExt {
+    fn from(error: pkcs8::pkcs5::Error) -> Self {
+        ErrorExt::Pkcs8(error.to_string())
+    }
+}
@@ -0,0 +1,314 @@
+//! Hierarchical deterministic derivation of secret keys from seeds and BIP-39 mnemonics.
+//!
+//! Ed25519 keys are derived as per [SLIP-10], which only supports hardened child keys.
+//! Secp256k1 keys are derived as per [BIP-32].
+//!
+//! [SLIP-10]: https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+//! [BIP-32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+
+#[cfg(test)]
+mod tests;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use bip39::Mnemonic;
+use hmac::{Hmac, Mac};
+use k256::{
+    elliptic_curve::{sec1::ToEncodedPoint, Field, PrimeField},
+    AffinePoint, FieldBytes, ProjectivePoint, Scalar,
+};
+use sha2::Sha512;
+use thiserror::Error;
+
+use super::SecretKey;
+
+/// The offset added to the index of a hardened child key.
+pub const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// The SLIP-44 coin type registered for Casper, for use in BIP-44 style derivation paths.
+pub const CASPER_COIN_TYPE: u32 = 506;
+
+/// The minimum length in bytes of a seed from which keys can be derived.
+const MIN_SEED_LENGTH: usize = 16;
+/// The maximum length in bytes of a seed from which keys can be derived.
+const MAX_SEED_LENGTH: usize = 64;
+
+const ED25519_CURVE_KEY: &[u8] = b"ed25519 seed";
+const SECP256K1_CURVE_KEY: &[u8] = b"Bitcoin seed";
+
+/// Errors arising when deriving secret keys from seeds or mnemonics.
+#[derive(Clone, PartialEq, Eq, Debug, Error)]
+#[non_exhaustive]
+pub enum HdKeyError {
+    /// The mnemonic is not a valid BIP-39 English mnemonic.
+    #[error("invalid mnemonic: {0}")]
+    InvalidMnemonic(String),
+
+    /// The seed is shorter than 16 bytes or longer than 64 bytes.
+    #[error("invalid seed length: {0} bytes, expected between 16 and 64")]
+    InvalidSeedLength(usize),
+
+    /// The derivation path couldn't be parsed.
+    #[error("invalid derivation path: {0}")]
+    InvalidDerivationPath(String),
+
+    /// The child index is too large to be represented once the hardened offset is applied.
+    #[error("child index {0} out of range")]
+    ChildIndexOutOfRange(u32),
+
+    /// Ed25519 keys only support hardened derivation.
+    #[error("ed25519 keys can only be derived along fully hardened paths, got {0}")]
+    NonHardenedDerivation(DerivationPath),
+
+    /// The derived key is not a valid secret key; the next child index should be used instead.
+    #[error("derived key at {0} is invalid")]
+    InvalidDerivedKey(DerivationPath),
+}
+
+/// The index of a child key in a derivation path.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ChildNumber(u32);
+
+impl ChildNumber {
+    /// Returns the non-hardened child number with the given index.
+    pub fn normal(index: u32) -> Result<Self, HdKeyError> {
+        if index >= HARDENED_OFFSET {
+            return Err(HdKeyError::ChildIndexOutOfRange(index));
+        }
+        Ok(ChildNumber(index))
+    }
+
+    /// Returns the hardened child number with the given index.
+    pub fn hardened(index: u32) -> Result<Self, HdKeyError> {
+        if index >= HARDENED_OFFSET {
+            return Err(HdKeyError::ChildIndexOutOfRange(index));
+        }
+        Ok(ChildNumber(index | HARDENED_OFFSET))
+    }
+
+    /// Returns `true` if this is a hardened child number.
+    pub fn is_hardened(&self) -> bool {
+        self.0 & HARDENED_OFFSET != 0
+    }
+
+    /// Returns the index of the child, excluding the hardened offset.
+    pub fn index(&self) -> u32 {
+        self.0 & !HARDENED_OFFSET
+    }
+
+    /// Returns the child number as serialized in derivation, including the hardened offset.
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+impl Display for ChildNumber {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        if self.is_hardened() {
+            write!(formatter, "{}'", self.index())
+        } else {
+            write!(formatter, "{}", self.index())
+        }
+    }
+}
+
+impl FromStr for ChildNumber {
+    type Err = HdKeyError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (index, hardened) = match input
+            .strip_suffix('\'')
+            .or_else(|| input.strip_suffix('h'))
+            .or_else(|| input.strip_suffix('H'))
+        {
+            Some(index) => (index, true),
+            None => (input, false),
+        };
+        let index = index
+            .parse::<u32>()
+            .map_err(|_| HdKeyError::InvalidDerivationPath(input.to_string()))?;
+        if hardened {
+            ChildNumber::hardened(index)
+        } else {
+            ChildNumber::normal(index)
+        }
+    }
+}
+
+/// A derivation path from a master key to a child key, e.g. `m/44'/506'/0'/0/0`.
+///
+/// Hardened child numbers may be suffixed with `'`, `h` or `H`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct DerivationPath(Vec<ChildNumber>);
+
+impl DerivationPath {
+    /// Returns the empty path, identifying the master key.
+    pub fn master() -> Self {
+        DerivationPath(Vec::new())
+    }
+
+    /// Returns a copy of this path extended by the given child number.
+    pub fn child(&self, child_number: ChildNumber) -> Self {
+        let mut children = self.0.clone();
+        children.push(child_number);
+        DerivationPath(children)
+    }
+
+    /// Returns the child numbers of the path, starting at the master key.
+    pub fn children(&self) -> &[ChildNumber] {
+        &self.0
+    }
+
+    /// Returns `true` if every child number in the path is hardened.
+    pub fn is_hardened(&self) -> bool {
+        self.0.iter().all(ChildNumber::is_hardened)
+    }
+}
+
+impl From<Vec<ChildNumber>> for DerivationPath {
+    fn from(children: Vec<ChildNumber>) -> Self {
+        DerivationPath(children)
+    }
+}
+
+impl Display for DerivationPath {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "m")?;
+        for child_number in &self.0 {
+            write!(formatter, "/{}", child_number)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = HdKeyError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut parts = input.split('/');
+        if parts.next() != Some("m") {
+            return Err(HdKeyError::InvalidDerivationPath(input.to_string()));
+        }
+        parts
+            .map(ChildNumber::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map(DerivationPath)
+            .map_err(|_| HdKeyError::InvalidDerivationPath(input.to_string()))
+    }
+}
+
+/// Converts a BIP-39 English mnemonic and optional passphrase into a 64-byte seed.
+///
+/// The mnemonic's checksum is validated.  An empty passphrase should be used if none is required.
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> Result<[u8; 64], HdKeyError> {
+    let mnemonic = Mnemonic::parse(mnemonic)
+        .map_err(|error| HdKeyError::InvalidMnemonic(error.to_string()))?;
+    Ok(mnemonic.to_seed(passphrase))
+}
+
+impl SecretKey {
+    /// Derives an Ed25519 secret key from the given seed as per SLIP-10.
+    ///
+    /// Every child number in `path` must be hardened.
+    pub fn ed25519_from_seed(seed: &[u8], path: &DerivationPath) -> Result<Self, HdKeyError> {
+        if !path.is_hardened() {
+            return Err(HdKeyError::NonHardenedDerivation(path.clone()));
+        }
+        let (mut key, mut chain_code) = master_key(ED25519_CURVE_KEY, seed)?;
+        for child_number in path.children() {
+            let mut data = Vec::with_capacity(37);
+            data.push(0);
+            data.extend_from_slice(&key);
+            data.extend_from_slice(&child_number.value().to_be_bytes());
+            (key, chain_code) = hmac_sha512(&chain_code, &data);
+        }
+        SecretKey::ed25519_from_bytes(key).map_err(|_| HdKeyError::InvalidDerivedKey(path.clone()))
+    }
+
+    /// Derives a secp256k1 secret key from the given seed as per BIP-32.
+    pub fn secp256k1_from_seed(seed: &[u8], path: &DerivationPath) -> Result<Self, HdKeyError> {
+        let (key, mut chain_code) = master_key(SECP256K1_CURVE_KEY, seed)?;
+        let mut key = secp256k1_scalar(key)
+            .ok_or_else(|| HdKeyError::InvalidDerivedKey(DerivationPath::master()))?;
+        for (depth, child_number) in path.children().iter().enumerate() {
+            let mut data = Vec::with_capacity(37);
+            if child_number.is_hardened() {
+                data.push(0);
+                data.extend_from_slice(&key.to_repr());
+            } else {
+                let public_point = AffinePoint::from(ProjectivePoint::GENERATOR * key);
+                data.extend_from_slice(public_point.to_encoded_point(true).as_bytes());
+            }
+            data.extend_from_slice(&child_number.value().to_be_bytes());
+            let (tweak, child_chain_code) = hmac_sha512(&chain_code, &data);
+            let child_key = secp256k1_scalar(tweak)
+                .map(|tweak| tweak + key)
+                .filter(|child_key| !bool::from(child_key.is_zero()))
+                .ok_or_else(|| {
+                    HdKeyError::InvalidDerivedKey(DerivationPath(
+                        path.children()[..=depth].to_vec(),
+                    ))
+                })?;
+            key = child_key;
+            chain_code = child_chain_code;
+        }
+        SecretKey::secp256k1_from_bytes(key.to_repr())
+            .map_err(|_| HdKeyError::InvalidDerivedKey(path.clone()))
+    }
+
+    /// Derives an Ed25519 secret key from the given BIP-39 mnemonic and passphrase as per SLIP-10.
+    ///
+    /// Every child number in `path` must be hardened.
+    pub fn ed25519_from_mnemonic(
+        mnemonic: &str,
+        passphrase: &str,
+        path: &DerivationPath,
+    ) -> Result<Self, HdKeyError> {
+        let seed = mnemonic_to_seed(mnemonic, passphrase)?;
+        SecretKey::ed25519_from_seed(&seed, path)
+    }
+
+    /// Derives a secp256k1 secret key from the given BIP-39 mnemonic and passphrase as per BIP-32.
+    pub fn secp256k1_from_mnemonic(
+        mnemonic: &str,
+        passphrase: &str,
+        path: &DerivationPath,
+    ) -> Result<Self, HdKeyError> {
+        let seed = mnemonic_to_seed(mnemonic, passphrase)?;
+        SecretKey::secp256k1_from_seed(&seed, path)
+    }
+}
+
+/// Returns the master key and chain code derived from `seed`.
+fn master_key(curve_key: &[u8], seed: &[u8]) -> Result<([u8; 32], [u8; 32]), HdKeyError> {
+    if !(MIN_SEED_LENGTH..=MAX_SEED_LENGTH).contains(&seed.len()) {
+        return Err(HdKeyError::InvalidSeedLength(seed.len()));
+    }
+    Ok(hmac_sha512(curve_key, seed))
+}
+
+/// Returns the left and right halves of the HMAC-SHA512 of `data` under `key`.
+fn hmac_sha512(key: &[u8], data: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    let output = mac.finalize().into_bytes();
+    let mut left = [0; 32];
+    let mut right = [0; 32];
+    left.copy_from_slice(&output[..32]);
+    right.copy_from_slice(&output[32..]);
+    (left, right)
+}
+
+/// Parses the big-endian bytes as a secp256k1 scalar, returning `None` if they are not less than
+/// the curve order or represent zero.
+fn secp256k1_scalar(bytes: [u8; 32]) -> Option<Scalar> {
+    Option::<Scalar>::from(Scalar::from_repr(FieldBytes::from(bytes)))
+        .filter(|scalar| !bool::from(scalar.is_zero()))
+}
@@ -0,0 +1,204 @@
+use core::str::FromStr;
+
+use super::*;
+use crate::{AsymmetricType, PublicKey};
+
+/// Seed of test vector 1 of both SLIP-10 and BIP-32.
+const TEST_VECTOR_1_SEED: &str = "000102030405060708090a0b0c0d0e0f";
+
+fn seed() -> Vec<u8> {
+    base16::decode(TEST_VECTOR_1_SEED).unwrap()
+}
+
+fn path(path: &str) -> DerivationPath {
+    DerivationPath::from_str(path).unwrap()
+}
+
+// `SecretKey` does not implement `PartialEq`, so compare the DER encodings of the raw key bytes.
+fn assert_secret_keys_equal(lhs: &SecretKey, rhs: &SecretKey) {
+    assert_eq!(lhs.to_der().unwrap(), rhs.to_der().unwrap());
+}
+
+#[test]
+fn should_derive_slip10_ed25519_test_vector_1() {
+    // From https://github.com/satoshilabs/slips/blob/master/slip-0010.md#test-vector-1-for-ed25519
+    let vectors = [
+        (
+            "m",
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+            "a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed",
+        ),
+        (
+            "m/0'",
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+            "8c8a13df77a28f3445213a0f432fde644acaa215fc72dcdf300d5efaa85d350c",
+        ),
+        (
+            "m/0'/1'",
+            "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2",
+            "1932a5270f335bed617d5b935c80aedb1a35bd9fc1e31acafd5372c30f5c1187",
+        ),
+        (
+            "m/0'/1'/2'",
+            "92a5b23c0b8a99e37d07df3fb9966917f5d06e02ddbd909c7e184371463e9fc9",
+            "ae98736566d30ed0e9d2f4486a64bc95740d89c7db33f52121f8ea8f76ff0fc1",
+        ),
+        (
+            "m/0'/1'/2'/2'",
+            "30d1dc7e5fc04c31219ab25a27ae00b50f6fd66622f6e9c913253d6511d1e662",
+            "8abae2d66361c879b900d204ad2cc4984fa2aa344dd7ddc46007329ac76c429c",
+        ),
+        (
+            "m/0'/1'/2'/2'/1000000000'",
+            "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793",
+            "3c24da049451555d51a7014a37337aa4e12d41e485abccfa46b47dfb2af54b7a",
+        ),
+    ];
+
+    for (derivation_path, secret_key_hex, public_key_hex) in vectors {
+        let secret_key = SecretKey::ed25519_from_seed(&seed(), &path(derivation_path)).unwrap();
+        let expected_secret_key =
+            SecretKey::ed25519_from_bytes(base16::decode(secret_key_hex).unwrap()).unwrap();
+        assert_secret_keys_equal(&secret_key, &expected_secret_key);
+        assert_eq!(
+            PublicKey::from(&secret_key),
+            PublicKey::ed25519_from_bytes(base16::decode(public_key_hex).unwrap()).unwrap(),
+            "{}",
+            derivation_path
+        );
+    }
+}
+
+#[test]
+fn should_derive_bip32_secp256k1_test_vector_1() {
+    // From https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#test-vector-1
+    let vectors = [
+        (
+            "m",
+            "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35",
+            "0339a36013301597daef41fbe593a02cc513d0b55527ec2df1050e2e8ff49c85c2",
+        ),
+        (
+            "m/0H",
+            "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+            "035a784662a4a20a65bf6aab9ae98a6c068a81c52e4b032c0fb5400c706cfccc56",
+        ),
+        (
+            "m/0H/1",
+            "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+            "03501e454bf00751f24b1b489aa925215d66af2234e3891c3b21a52bedb3cd711c",
+        ),
+        (
+            "m/0H/1/2H",
+            "cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca",
+            "0357bfe1e341d01c69fe5654309956cbea516822fba8a601743a012a7896ee8dc2",
+        ),
+        (
+            "m/0H/1/2H/2",
+            "0f479245fb19a38a1954c5c7c0ebab2f9bdfd96a17563ef28a6a4b1a2a764ef4",
+            "02e8445082a72f29b75ca48748a914df60622a609cacfce8ed0e35804560741d29",
+        ),
+        (
+            "m/0H/1/2H/2/1000000000",
+            "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8",
+            "022a471424da5e657499d1ff51cb43c47481a03b1e77f951fe64cec9f5a48f7011",
+        ),
+    ];
+
+    for (derivation_path, secret_key_hex, public_key_hex) in vectors {
+        let secret_key = SecretKey::secp256k1_from_seed(&seed(), &path(derivation_path)).unwrap();
+        let expected_secret_key =
+            SecretKey::secp256k1_from_bytes(base16::decode(secret_key_hex).unwrap()).unwrap();
+        assert_secret_keys_equal(&secret_key, &expected_secret_key);
+        assert_eq!(
+            PublicKey::from(&secret_key),
+            PublicKey::secp256k1_from_bytes(base16::decode(public_key_hex).unwrap()).unwrap(),
+            "{}",
+            derivation_path
+        );
+    }
+}
+
+#[test]
+fn should_convert_bip39_mnemonic_to_seed() {
+    // From https://github.com/trezor/python-mnemonic/blob/master/vectors.json
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+        abandon abandon about";
+    let expected_seed = "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a69\
+        87599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04";
+    let seed = mnemonic_to_seed(mnemonic, "TREZOR").unwrap();
+    assert_eq!(base16::encode_lower(&seed), expected_seed);
+
+    let path = path("m/44'/506'/0'/0'/0'");
+    assert_secret_keys_equal(
+        &SecretKey::ed25519_from_mnemonic(mnemonic, "TREZOR", &path).unwrap(),
+        &SecretKey::ed25519_from_seed(&seed, &path).unwrap(),
+    );
+    assert_secret_keys_equal(
+        &SecretKey::secp256k1_from_mnemonic(mnemonic, "TREZOR", &path).unwrap(),
+        &SecretKey::secp256k1_from_seed(&seed, &path).unwrap(),
+    );
+}
+
+#[test]
+fn should_reject_invalid_mnemonic() {
+    // Valid words, but the checksum doesn't match.
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon";
+    assert!(matches!(
+        mnemonic_to_seed(mnemonic, ""),
+        Err(HdKeyError::InvalidMnemonic(_))
+    ));
+    assert!(matches!(
+        mnemonic_to_seed("not a mnemonic", ""),
+        Err(HdKeyError::InvalidMnemonic(_))
+    ));
+}
+
+#[test]
+fn should_reject_non_hardened_ed25519_derivation() {
+    assert!(matches!(
+        SecretKey::ed25519_from_seed(&seed(), &path("m/44'/506'/0'/0/0")),
+        Err(HdKeyError::NonHardenedDerivation(_))
+    ));
+}
+
+#[test]
+fn should_reject_invalid_seed_length() {
+    assert!(matches!(
+        SecretKey::ed25519_from_seed(&[0; 15], &DerivationPath::master()),
+        Err(HdKeyError::InvalidSeedLength(15))
+    ));
+    assert!(matches!(
+        SecretKey::secp256k1_from_seed(&[0; 65], &DerivationPath::master()),
+        Err(HdKeyError::InvalidSeedLength(65))
+    ));
+}
+
+#[test]
+fn derivation_path_string_roundtrip() {
+    let derivation_path = path("m/44'/506h/0H/0/7");
+    assert_eq!(derivation_path.to_string(), "m/44'/506'/0'/0/7");
+    assert_eq!(path(&derivation_path.to_string()), derivation_path);
+    assert_eq!(
+        derivation_path,
+        DerivationPath::master()
+            .child(ChildNumber::hardened(44).unwrap())
+            .child(ChildNumber::hardened(CASPER_COIN_TYPE).unwrap())
+            .child(ChildNumber::hardened(0).unwrap())
+            .child(ChildNumber::normal(0).unwrap())
+            .child(ChildNumber::normal(7).unwrap())
+    );
+    assert_eq!(path("m"), DerivationPath::master());
+
+    for invalid in ["", "44'/0'", "m/", "m/x", "m/0''", "m/2147483648", "n/0"] {
+        assert!(
+            matches!(
+                DerivationPath::from_str(invalid),
+                Err(HdKeyError::InvalidDerivationPath(_))
+            ),
+            "{}",
+            invalid
+        );
+    }
+}
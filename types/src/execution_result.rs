@@ -30,14 +30,14 @@ use rand::{
 };
 #[cfg(feature = "json-schema")]
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{ser::SerializeStructVariant, Deserialize, Serialize, Serializer};
 
 #[cfg(feature = "json-schema")]
 use crate::KEY_HASH_LENGTH;
 use crate::{
     account::AccountHash,
     bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
-    system::auction::{Bid, EraInfo, UnbondingPurse, WithdrawPurse},
+    system::auction::{Bid, Delegator, EraInfo, UnbondingPurse, WithdrawPurse},
     CLValue, DeployInfo, NamedKey, Transfer, TransferAddr, U128, U256, U512,
 };
 
@@ -46,6 +46,8 @@ use crate::{
 enum ExecutionResultTag {
     Failure = 0,
     Success = 1,
+    FailureWithFeeBreakdown = 2,
+    SuccessWithFeeBreakdown = 3,
 }
 
 impl TryFrom<u8> for ExecutionResultTag {
@@ -95,6 +97,7 @@ enum TransformTag {
     AddKeys = 16,
     Failure = 17,
     WriteUnbonding = 18,
+    WriteDelegator = 19,
 }
 
 impl TryFrom<u8> for TransformTag {
@@ -147,11 +150,12 @@ static EXECUTION_RESULT: Lazy<ExecutionResult> = Lazy::new(|| {
         effect,
         transfers,
         cost: U512::from(123_456),
+        fee_breakdown: None,
     }
 });
 
 /// The result of executing a single deploy.
-#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
+#[derive(Clone, Eq, PartialEq, Deserialize, Debug)]
 #[cfg_attr(feature = "datasize", derive(DataSize))]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
@@ -166,6 +170,11 @@ pub enum ExecutionResult {
         cost: U512,
         /// The error message associated with executing the deploy.
         error_message: String,
+        /// How the fee charged for the deploy was split between the block proposer and burning.
+        ///
+        /// Only recorded if the network is configured to split fees.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fee_breakdown: Option<FeeBreakdown>,
     },
     /// The result of a successful execution.
     Success {
@@ -175,6 +184,11 @@ pub enum ExecutionResult {
         transfers: Vec<TransferAddr>,
         /// The cost of executing the deploy.
         cost: U512,
+        /// How the fee charged for the deploy was split between the block proposer and burning.
+        ///
+        /// Only recorded if the network is configured to split fees.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fee_breakdown: Option<FeeBreakdown>,
     },
 }
 
@@ -186,19 +200,90 @@ impl ExecutionResult {
         &EXECUTION_RESULT
     }
 
+    /// Returns how the fee charged for the deploy was split between the block proposer and
+    /// burning, if recorded.
+    pub fn fee_breakdown(&self) -> Option<&FeeBreakdown> {
+        match self {
+            ExecutionResult::Failure { fee_breakdown, .. }
+            | ExecutionResult::Success { fee_breakdown, .. } => fee_breakdown.as_ref(),
+        }
+    }
+
     fn tag(&self) -> ExecutionResultTag {
         match self {
             ExecutionResult::Failure {
-                effect: _,
-                transfers: _,
-                cost: _,
-                error_message: _,
+                fee_breakdown: None,
+                ..
             } => ExecutionResultTag::Failure,
             ExecutionResult::Success {
-                effect: _,
-                transfers: _,
-                cost: _,
+                fee_breakdown: None,
+                ..
             } => ExecutionResultTag::Success,
+            ExecutionResult::Failure {
+                fee_breakdown: Some(_),
+                ..
+            } => ExecutionResultTag::FailureWithFeeBreakdown,
+            ExecutionResult::Success {
+                fee_breakdown: Some(_),
+                ..
+            } => ExecutionResultTag::SuccessWithFeeBreakdown,
+        }
+    }
+}
+
+// `fee_breakdown` is only skipped if unset in human-readable formats: non-self-describing formats
+// such as bincode can't tell a skipped field apart from the next one, so they always include it.
+impl Serialize for ExecutionResult {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let skip_fee_breakdown = serializer.is_human_readable() && self.fee_breakdown().is_none();
+        let fee_breakdown_len = if skip_fee_breakdown { 0 } else { 1 };
+        match self {
+            ExecutionResult::Failure {
+                effect,
+                transfers,
+                cost,
+                error_message,
+                fee_breakdown,
+            } => {
+                let mut state = serializer.serialize_struct_variant(
+                    "ExecutionResult",
+                    0,
+                    "Failure",
+                    4 + fee_breakdown_len,
+                )?;
+                state.serialize_field("effect", effect)?;
+                state.serialize_field("transfers", transfers)?;
+                state.serialize_field("cost", cost)?;
+                state.serialize_field("error_message", error_message)?;
+                if skip_fee_breakdown {
+                    state.skip_field("fee_breakdown")?;
+                } else {
+                    state.serialize_field("fee_breakdown", fee_breakdown)?;
+                }
+                state.end()
+            }
+            ExecutionResult::Success {
+                effect,
+                transfers,
+                cost,
+                fee_breakdown,
+            } => {
+                let mut state = serializer.serialize_struct_variant(
+                    "ExecutionResult",
+                    1,
+                    "Success",
+                    3 + fee_breakdown_len,
+                )?;
+                state.serialize_field("effect", effect)?;
+                state.serialize_field("transfers", transfers)?;
+                state.serialize_field("cost", cost)?;
+                if skip_fee_breakdown {
+                    state.skip_field("fee_breakdown")?;
+                } else {
+                    state.serialize_field("fee_breakdown", fee_breakdown)?;
+                }
+                state.end()
+            }
         }
     }
 }
@@ -234,24 +319,38 @@ impl Distribution<ExecutionResult> for Standard {
             transfers.push(TransferAddr::new(rng.gen()))
         }
 
+        let fee_breakdown = if rng.gen() {
+            Some(FeeBreakdown {
+                paid_to_proposer: rng.gen::<u64>().into(),
+                burned: rng.gen::<u64>().into(),
+            })
+        } else {
+            None
+        };
+
         if rng.gen() {
             ExecutionResult::Failure {
                 effect: execution_effect,
                 transfers,
                 cost: rng.gen::<u64>().into(),
                 error_message: format!("Error message {}", rng.gen::<u64>()),
+                fee_breakdown,
             }
         } else {
             ExecutionResult::Success {
                 effect: execution_effect,
                 transfers,
                 cost: rng.gen::<u64>().into(),
+                fee_breakdown,
             }
         }
     }
 }
 
 // TODO[goral09]: Add `write_bytes` impl.
+//
+// Results without a fee breakdown are serialized exactly as before fee breakdowns were introduced,
+// so that the results of executing legacy blocks keep their checksums.
 impl ToBytes for ExecutionResult {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
@@ -263,20 +362,28 @@ impl ToBytes for ExecutionResult {
                 transfers,
                 cost,
                 error_message,
+                fee_breakdown,
             } => {
                 buffer.extend(effect.to_bytes()?);
                 buffer.extend(transfers.to_bytes()?);
                 buffer.extend(cost.to_bytes()?);
                 buffer.extend(error_message.to_bytes()?);
+                if let Some(fee_breakdown) = fee_breakdown {
+                    buffer.extend(fee_breakdown.to_bytes()?);
+                }
             }
             ExecutionResult::Success {
                 effect,
                 transfers,
                 cost,
+                fee_breakdown,
             } => {
                 buffer.extend(effect.to_bytes()?);
                 buffer.extend(transfers.to_bytes()?);
                 buffer.extend(cost.to_bytes()?);
+                if let Some(fee_breakdown) = fee_breakdown {
+                    buffer.extend(fee_breakdown.to_bytes()?);
+                }
             }
         }
         Ok(buffer)
@@ -290,20 +397,28 @@ impl ToBytes for ExecutionResult {
                     transfers,
                     cost,
                     error_message,
+                    fee_breakdown,
                 } => {
                     execution_effect.serialized_length()
                         + transfers.serialized_length()
                         + cost.serialized_length()
                         + error_message.serialized_length()
+                        + fee_breakdown
+                            .as_ref()
+                            .map_or(0, FeeBreakdown::serialized_length)
                 }
                 ExecutionResult::Success {
                     effect: execution_effect,
                     transfers,
                     cost,
+                    fee_breakdown,
                 } => {
                     execution_effect.serialized_length()
                         + transfers.serialized_length()
                         + cost.serialized_length()
+                        + fee_breakdown
+                            .as_ref()
+                            .map_or(0, FeeBreakdown::serialized_length)
                 }
             }
     }
@@ -313,27 +428,43 @@ impl FromBytes for ExecutionResult {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (tag, remainder) = u8::from_bytes(bytes)?;
         match TryFrom::try_from(tag)? {
-            ExecutionResultTag::Failure => {
+            tag @ (ExecutionResultTag::Failure | ExecutionResultTag::FailureWithFeeBreakdown) => {
                 let (effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
                 let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
                 let (cost, remainder) = U512::from_bytes(remainder)?;
                 let (error_message, remainder) = String::from_bytes(remainder)?;
+                let (fee_breakdown, remainder) =
+                    if matches!(tag, ExecutionResultTag::FailureWithFeeBreakdown) {
+                        let (fee_breakdown, remainder) = FeeBreakdown::from_bytes(remainder)?;
+                        (Some(fee_breakdown), remainder)
+                    } else {
+                        (None, remainder)
+                    };
                 let execution_result = ExecutionResult::Failure {
                     effect,
                     transfers,
                     cost,
                     error_message,
+                    fee_breakdown,
                 };
                 Ok((execution_result, remainder))
             }
-            ExecutionResultTag::Success => {
+            tag @ (ExecutionResultTag::Success | ExecutionResultTag::SuccessWithFeeBreakdown) => {
                 let (execution_effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
                 let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
                 let (cost, remainder) = U512::from_bytes(remainder)?;
+                let (fee_breakdown, remainder) =
+                    if matches!(tag, ExecutionResultTag::SuccessWithFeeBreakdown) {
+                        let (fee_breakdown, remainder) = FeeBreakdown::from_bytes(remainder)?;
+                        (Some(fee_breakdown), remainder)
+                    } else {
+                        (None, remainder)
+                    };
                 let execution_result = ExecutionResult::Success {
                     effect: execution_effect,
                     transfers,
                     cost,
+                    fee_breakdown,
                 };
                 Ok((execution_result, remainder))
             }
@@ -341,6 +472,43 @@ impl FromBytes for ExecutionResult {
     }
 }
 
+/// How the fee charged for executing a deploy was split between the block proposer and burning.
+#[derive(Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Default, Debug)]
+#[cfg_attr(feature = "datasize", derive(DataSize))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct FeeBreakdown {
+    /// The part of the fee paid to the block proposer.
+    pub paid_to_proposer: U512,
+    /// The part of the fee which was burned.
+    pub burned: U512,
+}
+
+impl ToBytes for FeeBreakdown {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.paid_to_proposer.to_bytes()?);
+        buffer.extend(self.burned.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.paid_to_proposer.serialized_length() + self.burned.serialized_length()
+    }
+}
+
+impl FromBytes for FeeBreakdown {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (paid_to_proposer, remainder) = U512::from_bytes(bytes)?;
+        let (burned, remainder) = U512::from_bytes(remainder)?;
+        let fee_breakdown = FeeBreakdown {
+            paid_to_proposer,
+            burned,
+        };
+        Ok((fee_breakdown, remainder))
+    }
+}
+
 /// The journal of execution transforms from a single deploy.
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Default, Debug)]
 #[cfg_attr(feature = "datasize", derive(DataSize))]
@@ -554,6 +722,8 @@ pub enum Transform {
     Failure(String),
     /// Writes the given Unbonding to global state.
     WriteUnbonding(Vec<UnbondingPurse>),
+    /// Writes the given Delegator to global state.
+    WriteDelegator(Box<Delegator>),
 }
 
 impl Transform {
@@ -578,6 +748,7 @@ impl Transform {
             Transform::AddKeys(_) => TransformTag::AddKeys,
             Transform::Failure(_) => TransformTag::Failure,
             Transform::WriteUnbonding(_) => TransformTag::WriteUnbonding,
+            Transform::WriteDelegator(_) => TransformTag::WriteDelegator,
         }
     }
 }
@@ -638,6 +809,9 @@ impl ToBytes for Transform {
             Transform::WriteUnbonding(value) => {
                 buffer.extend(value.to_bytes()?);
             }
+            Transform::WriteDelegator(delegator) => {
+                buffer.extend(delegator.to_bytes()?);
+            }
         }
         Ok(buffer)
     }
@@ -663,6 +837,7 @@ impl ToBytes for Transform {
             Transform::WriteBid(value) => value.serialized_length(),
             Transform::WriteWithdraw(value) => value.serialized_length(),
             Transform::WriteUnbonding(value) => value.serialized_length(),
+            Transform::WriteDelegator(value) => value.serialized_length(),
         };
         U8_SERIALIZED_LENGTH + body_len
     }
@@ -738,6 +913,10 @@ impl FromBytes for Transform {
                     <Vec<UnbondingPurse> as FromBytes>::from_bytes(remainder)?;
                 Ok((Transform::WriteUnbonding(unbonding_purses), remainder))
             }
+            TransformTag::WriteDelegator => {
+                let (delegator, remainder) = Delegator::from_bytes(remainder)?;
+                Ok((Transform::WriteDelegator(Box::new(delegator)), remainder))
+            }
         }
     }
 }
@@ -798,4 +977,72 @@ mod tests {
         let execution_result: ExecutionResult = rng.gen();
         bytesrepr::test_serialization_roundtrip(&execution_result);
     }
+
+    #[test]
+    fn should_serialize_execution_result_without_fee_breakdown_in_legacy_format() {
+        let mut rng = get_rng();
+        let effect = ExecutionEffect::new(vec![TransformEntry {
+            key: rng.gen::<u64>().to_string(),
+            transform: rng.gen(),
+        }]);
+        let transfers = vec![TransferAddr::new(rng.gen())];
+        let cost = U512::from(rng.gen::<u64>());
+        let execution_result = ExecutionResult::Success {
+            effect: effect.clone(),
+            transfers: transfers.clone(),
+            cost,
+            fee_breakdown: None,
+        };
+
+        let mut legacy_bytes = vec![ExecutionResultTag::Success as u8];
+        legacy_bytes.extend(effect.to_bytes().unwrap());
+        legacy_bytes.extend(transfers.to_bytes().unwrap());
+        legacy_bytes.extend(cost.to_bytes().unwrap());
+        assert_eq!(execution_result.to_bytes().unwrap(), legacy_bytes);
+    }
+
+    #[test]
+    fn should_omit_unset_fee_breakdown_only_from_json() {
+        let mut rng = get_rng();
+        let execution_result = ExecutionResult::Failure {
+            effect: ExecutionEffect::default(),
+            transfers: vec![TransferAddr::new(rng.gen())],
+            cost: U512::from(rng.gen::<u64>()),
+            error_message: "error".to_string(),
+            fee_breakdown: None,
+        };
+
+        let json = serde_json::to_value(&execution_result).unwrap();
+        assert!(json["Failure"].get("fee_breakdown").is_none());
+        assert_eq!(
+            serde_json::from_value::<ExecutionResult>(json).unwrap(),
+            execution_result
+        );
+
+        let serialized = bincode::serialize(&execution_result).unwrap();
+        assert_eq!(
+            bincode::deserialize::<ExecutionResult>(&serialized).unwrap(),
+            execution_result
+        );
+
+        let execution_result = ExecutionResult::Success {
+            effect: ExecutionEffect::default(),
+            transfers: vec![],
+            cost: U512::from(rng.gen::<u64>()),
+            fee_breakdown: Some(FeeBreakdown {
+                paid_to_proposer: U512::from(rng.gen::<u64>()),
+                burned: U512::from(rng.gen::<u64>()),
+            }),
+        };
+        let json = serde_json::to_value(&execution_result).unwrap();
+        assert_eq!(
+            serde_json::from_value::<ExecutionResult>(json).unwrap(),
+            execution_result
+        );
+        let serialized = bincode::serialize(&execution_result).unwrap();
+        assert_eq!(
+            bincode::deserialize::<ExecutionResult>(&serialized).unwrap(),
+            execution_result
+        );
+    }
 }
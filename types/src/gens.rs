@@ -24,12 +24,17 @@ use crate::{
     },
     transfer::TransferAddr,
     AccessRights, CLType, CLValue, Contract, ContractHash, ContractPackage, ContractVersionKey,
-    ContractWasm, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, EraId, Group, Key,
-    NamedArg, Parameter, Phase, ProtocolVersion, SemVer, StoredValue, URef, U128, U256, U512,
+    ContractWasm, DelegatorAddr, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, EraId,
+    Group, Key, NamedArg, Parameter, Phase, ProtocolVersion, SemVer, StoredValue, URef, U128, U256,
+    U512,
 };
 
 use crate::deploy_info::gens::{deploy_hash_arb, transfer_addr_arb};
-pub use crate::{deploy_info::gens::deploy_info_arb, transfer::gens::transfer_arb};
+pub use crate::{
+    deploy_info::gens::deploy_info_arb,
+    scheduled_invocation::gens::{scheduled_invocation_arb, scheduled_time_arb},
+    transfer::gens::transfer_arb,
+};
 
 pub fn u8_slice_32() -> impl Strategy<Value = [u8; 32]> {
     collection::vec(any::<u8>(), 32).prop_map(|b| {
@@ -95,6 +100,10 @@ pub fn key_arb() -> impl Strategy<Value = Key> {
         account_hash_arb().prop_map(Key::Withdraw),
         u8_slice_32().prop_map(Key::Dictionary),
         Just(Key::EraSummary),
+        u8_slice_32().prop_map(Key::ScheduledInvocation),
+        (account_hash_arb(), account_hash_arb()).prop_map(|(validator, delegator)| {
+            Key::Delegator(DelegatorAddr::new(validator, delegator))
+        }),
     ]
 }
 
@@ -302,10 +311,15 @@ pub fn entry_point_arb() -> impl Strategy<Value = EntryPoint> {
         entry_point_type_arb(),
         entry_point_access_arb(),
         cl_type_arb(),
+        any::<bool>(),
     )
         .prop_map(
-            |(name, parameters, entry_point_type, entry_point_access, ret)| {
-                EntryPoint::new(name, parameters, ret, entry_point_access, entry_point_type)
+            |(name, parameters, entry_point_type, entry_point_access, ret, view)| {
+                if view {
+                    EntryPoint::new_view(name, parameters, ret, entry_point_access)
+                } else {
+                    EntryPoint::new(name, parameters, ret, entry_point_access, entry_point_type)
+                }
             },
         )
 }
@@ -372,16 +386,28 @@ pub fn contract_package_arb() -> impl Strategy<Value = ContractPackage> {
         contract_versions_arb(),
         disabled_versions_arb(),
         groups_arb(),
+        option::of(any::<u32>()),
     )
-        .prop_map(|(access_key, versions, disabled_versions, groups)| {
-            ContractPackage::new(
-                access_key,
-                versions,
-                disabled_versions,
-                groups,
-                ContractPackageStatus::default(),
-            )
-        })
+        .prop_map(
+            |(access_key, versions, disabled_versions, groups, storage_schema_version)| {
+                let mut contract_package = ContractPackage::new(
+                    access_key,
+                    versions,
+                    disabled_versions,
+                    groups,
+                    ContractPackageStatus::default(),
+                );
+                let first_version = contract_package.versions().keys().next().copied();
+                if let (Some(contract_version_key), Some(storage_schema_version)) =
+                    (first_version, storage_schema_version)
+                {
+                    contract_package
+                        .set_storage_schema_version(contract_version_key, storage_schema_version)
+                        .expect("version should exist");
+                }
+                contract_package
+            },
+        )
 }
 
 fn delegator_arb() -> impl Strategy<Value = Delegator> {
@@ -510,7 +536,8 @@ pub fn stored_value_arb() -> impl Strategy<Value = StoredValue> {
         era_info_arb(1..10).prop_map(StoredValue::EraInfo),
         bid_arb(0..100).prop_map(|bid| StoredValue::Bid(Box::new(bid))),
         withdraws_arb(1..50).prop_map(StoredValue::Withdraw),
-        unbondings_arb(1..50).prop_map(StoredValue::Unbonding)
+        unbondings_arb(1..50).prop_map(StoredValue::Unbonding),
+        delegator_arb().prop_map(|delegator| StoredValue::Delegator(Box::new(delegator)))
     ]
     .prop_map(|stored_value|
         // The following match statement is here only to make sure
@@ -527,5 +554,6 @@ pub fn stored_value_arb() -> impl Strategy<Value = StoredValue> {
             StoredValue::Bid(_) => stored_value,
             StoredValue::Withdraw(_) => stored_value,
             StoredValue::Unbonding(_) => stored_value,
+            StoredValue::Delegator(_) => stored_value,
         })
 }
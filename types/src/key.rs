@@ -47,6 +47,8 @@ const SYSTEM_CONTRACT_REGISTRY_PREFIX: &str = "system-contract-registry-";
 const ERA_SUMMARY_PREFIX: &str = "era-summary-";
 const CHAINSPEC_REGISTRY_PREFIX: &str = "chainspec-registry-";
 const CHECKSUM_REGISTRY_PREFIX: &str = "checksum-registry-";
+const SCHEDULED_INVOCATION_PREFIX: &str = "scheduled-invocation-";
+const DELEGATOR_PREFIX: &str = "delegator-";
 
 /// The number of bytes in a Blake2b hash
 pub const BLAKE2B_DIGEST_LENGTH: usize = 32;
@@ -80,6 +82,12 @@ const KEY_CHAINSPEC_REGISTRY_SERIALIZED_LENGTH: usize =
     KEY_ID_SERIALIZED_LENGTH + PADDING_BYTES.len();
 const KEY_CHECKSUM_REGISTRY_SERIALIZED_LENGTH: usize =
     KEY_ID_SERIALIZED_LENGTH + PADDING_BYTES.len();
+const KEY_SCHEDULED_INVOCATION_SERIALIZED_LENGTH: usize =
+    KEY_ID_SERIALIZED_LENGTH + KEY_HASH_LENGTH;
+const KEY_DELEGATOR_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + DELEGATOR_ADDR_LENGTH;
+
+/// The number of bytes in a [`DelegatorAddr`].
+pub const DELEGATOR_ADDR_LENGTH: usize = 2 * ACCOUNT_HASH_LENGTH;
 
 /// An alias for [`Key`]s hash variant.
 pub type HashAddr = [u8; KEY_HASH_LENGTH];
@@ -87,6 +95,81 @@ pub type HashAddr = [u8; KEY_HASH_LENGTH];
 /// An alias for [`Key`]s dictionary variant.
 pub type DictionaryAddr = [u8; KEY_DICTIONARY_LENGTH];
 
+/// An alias for [`Key`]s scheduled invocation variant.
+pub type ScheduledInvocationAddr = [u8; KEY_HASH_LENGTH];
+
+/// The address of a [`Key::Delegator`].
+///
+/// The validator's account hash is serialized first, so all delegations to a given validator
+/// share a common key prefix and can be read together.
+#[derive(
+    Default, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy, Debug, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "datasize", derive(DataSize))]
+pub struct DelegatorAddr {
+    validator: AccountHash,
+    delegator: AccountHash,
+}
+
+impl DelegatorAddr {
+    /// Constructs a new `DelegatorAddr`.
+    pub const fn new(validator: AccountHash, delegator: AccountHash) -> Self {
+        DelegatorAddr {
+            validator,
+            delegator,
+        }
+    }
+
+    /// Returns the account hash of the validator.
+    pub fn validator(&self) -> AccountHash {
+        self.validator
+    }
+
+    /// Returns the account hash of the delegator.
+    pub fn delegator(&self) -> AccountHash {
+        self.delegator
+    }
+
+    /// Returns the key prefix shared by all delegations to the given validator.
+    pub fn validator_prefix(validator: &AccountHash) -> Vec<u8> {
+        let mut prefix = Vec::with_capacity(KEY_ID_SERIALIZED_LENGTH + ACCOUNT_HASH_LENGTH);
+        prefix.push(KeyTag::Delegator as u8);
+        prefix.extend_from_slice(validator.as_bytes());
+        prefix
+    }
+}
+
+impl ToBytes for DelegatorAddr {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut result = bytesrepr::unchecked_allocate_buffer(self);
+        self.write_bytes(&mut result)?;
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        DELEGATOR_ADDR_LENGTH
+    }
+
+    fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), Error> {
+        self.validator.write_bytes(writer)?;
+        self.delegator.write_bytes(writer)
+    }
+}
+
+impl FromBytes for DelegatorAddr {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (validator, remainder) = AccountHash::from_bytes(bytes)?;
+        let (delegator, remainder) = AccountHash::from_bytes(remainder)?;
+        Ok((DelegatorAddr::new(validator, delegator), remainder))
+    }
+}
+
+impl Distribution<DelegatorAddr> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DelegatorAddr {
+        DelegatorAddr::new(AccountHash::new(rng.gen()), AccountHash::new(rng.gen()))
+    }
+}
+
 #[allow(missing_docs)]
 #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
 #[repr(u8)]
@@ -106,6 +189,8 @@ pub enum KeyTag {
     Unbond = 12,
     ChainspecRegistry = 13,
     ChecksumRegistry = 14,
+    ScheduledInvocation = 15,
+    Delegator = 16,
 }
 
 /// The type under which data (e.g. [`CLValue`](crate::CLValue)s, smart contracts, user accounts)
@@ -145,6 +230,10 @@ pub enum Key {
     ChainspecRegistry,
     /// A `Key` variant under which we store a registry of checksums.
     ChecksumRegistry,
+    /// A `Key` under which we store a scheduled contract invocation.
+    ScheduledInvocation(ScheduledInvocationAddr),
+    /// A `Key` under which we store a single delegation to a validator.
+    Delegator(DelegatorAddr),
 }
 
 /// Errors produced when converting a `String` into a `Key`.
@@ -181,6 +270,10 @@ pub enum FromStrError {
     ChainspecRegistry(String),
     /// Checksum registry error.
     ChecksumRegistry(String),
+    /// Scheduled invocation parse error.
+    ScheduledInvocation(String),
+    /// Delegator parse error.
+    Delegator(String),
     /// Unknown prefix.
     UnknownPrefix,
 }
@@ -239,6 +332,12 @@ impl Display for FromStrError {
             FromStrError::ChecksumRegistry(error) => {
                 write!(f, "checksum-registry-key from string error: {}", error)
             }
+            FromStrError::ScheduledInvocation(error) => {
+                write!(f, "scheduled-invocation-key from string error: {}", error)
+            }
+            FromStrError::Delegator(error) => {
+                write!(f, "delegator-key from string error: {}", error)
+            }
             FromStrError::UnknownPrefix => write!(f, "unknown prefix for key"),
         }
     }
@@ -264,12 +363,14 @@ impl Key {
             Key::Unbond(_) => String::from("Key::Unbond"),
             Key::ChainspecRegistry => String::from("Key::ChainspecRegistry"),
             Key::ChecksumRegistry => String::from("Key::ChecksumRegistry"),
+            Key::ScheduledInvocation(_) => String::from("Key::ScheduledInvocation"),
+            Key::Delegator(_) => String::from("Key::Delegator"),
         }
     }
 
     /// Returns the maximum size a [`Key`] can be serialized into.
     pub const fn max_serialized_length() -> usize {
-        KEY_UREF_SERIALIZED_LENGTH
+        KEY_DELEGATOR_SERIALIZED_LENGTH
     }
 
     /// If `self` is of type [`Key::URef`], returns `self` with the
@@ -347,6 +448,21 @@ impl Key {
                     base16::encode_lower(&PADDING_BYTES)
                 )
             }
+            Key::ScheduledInvocation(addr) => {
+                format!(
+                    "{}{}",
+                    SCHEDULED_INVOCATION_PREFIX,
+                    base16::encode_lower(&addr)
+                )
+            }
+            Key::Delegator(addr) => {
+                format!(
+                    "{}{}{}",
+                    DELEGATOR_PREFIX,
+                    base16::encode_lower(&addr.validator()),
+                    base16::encode_lower(&addr.delegator())
+                )
+            }
         }
     }
 
@@ -361,7 +477,7 @@ impl Key {
         if let Some(hex) = input.strip_prefix(HASH_PREFIX) {
             let addr = checksummed_hex::decode(hex)
                 .map_err(|error| FromStrError::Hash(error.to_string()))?;
-            let hash_addr = HashAddr::try_from(addr.as_ref())
+            let hash_addr = HashAddr::try_from(addr.as_slice())
                 .map_err(|error| FromStrError::Hash(error.to_string()))?;
             return Ok(Key::Hash(hash_addr));
         }
@@ -369,7 +485,7 @@ impl Key {
         if let Some(hex) = input.strip_prefix(DEPLOY_INFO_PREFIX) {
             let hash = checksummed_hex::decode(hex)
                 .map_err(|error| FromStrError::DeployInfo(error.to_string()))?;
-            let hash_array = <[u8; DEPLOY_HASH_LENGTH]>::try_from(hash.as_ref())
+            let hash_array = <[u8; DEPLOY_HASH_LENGTH]>::try_from(hash.as_slice())
                 .map_err(|error| FromStrError::DeployInfo(error.to_string()))?;
             return Ok(Key::DeployInfo(DeployHash::new(hash_array)));
         }
@@ -389,7 +505,7 @@ impl Key {
         if let Some(era_summary_padding) = input.strip_prefix(ERA_SUMMARY_PREFIX) {
             let padded_bytes = checksummed_hex::decode(era_summary_padding)
                 .map_err(|error| FromStrError::EraSummary(error.to_string()))?;
-            let _padding: [u8; 32] = TryFrom::try_from(padded_bytes.as_ref()).map_err(|_| {
+            let _padding: [u8; 32] = TryFrom::try_from(padded_bytes.as_slice()).map_err(|_| {
                 FromStrError::EraSummary("Failed to deserialize era summary key".to_string())
             })?;
             return Ok(Key::EraSummary);
@@ -404,7 +520,7 @@ impl Key {
         if let Some(hex) = input.strip_prefix(BALANCE_PREFIX) {
             let addr = checksummed_hex::decode(hex)
                 .map_err(|error| FromStrError::Balance(error.to_string()))?;
-            let uref_addr = URefAddr::try_from(addr.as_ref())
+            let uref_addr = URefAddr::try_from(addr.as_slice())
                 .map_err(|error| FromStrError::Balance(error.to_string()))?;
             return Ok(Key::Balance(uref_addr));
         }
@@ -412,7 +528,7 @@ impl Key {
         if let Some(hex) = input.strip_prefix(BID_PREFIX) {
             let hash = checksummed_hex::decode(hex)
                 .map_err(|error| FromStrError::Bid(error.to_string()))?;
-            let account_hash = <[u8; ACCOUNT_HASH_LENGTH]>::try_from(hash.as_ref())
+            let account_hash = <[u8; ACCOUNT_HASH_LENGTH]>::try_from(hash.as_slice())
                 .map_err(|error| FromStrError::Bid(error.to_string()))?;
             return Ok(Key::Bid(AccountHash::new(account_hash)));
         }
@@ -420,7 +536,7 @@ impl Key {
         if let Some(hex) = input.strip_prefix(WITHDRAW_PREFIX) {
             let hash = checksummed_hex::decode(hex)
                 .map_err(|error| FromStrError::Withdraw(error.to_string()))?;
-            let account_hash = <[u8; ACCOUNT_HASH_LENGTH]>::try_from(hash.as_ref())
+            let account_hash = <[u8; ACCOUNT_HASH_LENGTH]>::try_from(hash.as_slice())
                 .map_err(|error| FromStrError::Withdraw(error.to_string()))?;
             return Ok(Key::Withdraw(AccountHash::new(account_hash)));
         }
@@ -428,7 +544,7 @@ impl Key {
         if let Some(hex) = input.strip_prefix(UNBOND_PREFIX) {
             let hash = checksummed_hex::decode(hex)
                 .map_err(|error| FromStrError::Unbond(error.to_string()))?;
-            let account_hash = <[u8; ACCOUNT_HASH_LENGTH]>::try_from(hash.as_ref())
+            let account_hash = <[u8; ACCOUNT_HASH_LENGTH]>::try_from(hash.as_slice())
                 .map_err(|error| FromStrError::Unbond(error.to_string()))?;
             return Ok(Key::Unbond(AccountHash::new(account_hash)));
         }
@@ -436,7 +552,7 @@ impl Key {
         if let Some(dictionary_addr) = input.strip_prefix(DICTIONARY_PREFIX) {
             let dictionary_addr_bytes = checksummed_hex::decode(dictionary_addr)
                 .map_err(|error| FromStrError::Dictionary(error.to_string()))?;
-            let addr = DictionaryAddr::try_from(dictionary_addr_bytes.as_ref())
+            let addr = DictionaryAddr::try_from(dictionary_addr_bytes.as_slice())
                 .map_err(|error| FromStrError::Dictionary(error.to_string()))?;
             return Ok(Key::Dictionary(addr));
         }
@@ -444,7 +560,7 @@ impl Key {
         if let Some(registry_address) = input.strip_prefix(SYSTEM_CONTRACT_REGISTRY_PREFIX) {
             let padded_bytes = checksummed_hex::decode(registry_address)
                 .map_err(|error| FromStrError::SystemContractRegistry(error.to_string()))?;
-            let _padding: [u8; 32] = TryFrom::try_from(padded_bytes.as_ref()).map_err(|_| {
+            let _padding: [u8; 32] = TryFrom::try_from(padded_bytes.as_slice()).map_err(|_| {
                 FromStrError::SystemContractRegistry(
                     "Failed to deserialize system registry key".to_string(),
                 )
@@ -455,7 +571,7 @@ impl Key {
         if let Some(registry_address) = input.strip_prefix(CHAINSPEC_REGISTRY_PREFIX) {
             let padded_bytes = checksummed_hex::decode(registry_address)
                 .map_err(|error| FromStrError::ChainspecRegistry(error.to_string()))?;
-            let _padding: [u8; 32] = TryFrom::try_from(padded_bytes.as_ref()).map_err(|_| {
+            let _padding: [u8; 32] = TryFrom::try_from(padded_bytes.as_slice()).map_err(|_| {
                 FromStrError::ChainspecRegistry(
                     "Failed to deserialize chainspec registry key".to_string(),
                 )
@@ -466,7 +582,7 @@ impl Key {
         if let Some(registry_address) = input.strip_prefix(CHECKSUM_REGISTRY_PREFIX) {
             let padded_bytes = checksummed_hex::decode(registry_address)
                 .map_err(|error| FromStrError::ChecksumRegistry(error.to_string()))?;
-            let _padding: [u8; 32] = TryFrom::try_from(padded_bytes.as_ref()).map_err(|_| {
+            let _padding: [u8; 32] = TryFrom::try_from(padded_bytes.as_slice()).map_err(|_| {
                 FromStrError::ChecksumRegistry(
                     "Failed to deserialize checksum registry key".to_string(),
                 )
@@ -474,6 +590,27 @@ impl Key {
             return Ok(Key::ChecksumRegistry);
         }
 
+        if let Some(hex) = input.strip_prefix(SCHEDULED_INVOCATION_PREFIX) {
+            let addr = checksummed_hex::decode(hex)
+                .map_err(|error| FromStrError::ScheduledInvocation(error.to_string()))?;
+            let scheduled_invocation_addr = ScheduledInvocationAddr::try_from(addr.as_slice())
+                .map_err(|error| FromStrError::ScheduledInvocation(error.to_string()))?;
+            return Ok(Key::ScheduledInvocation(scheduled_invocation_addr));
+        }
+
+        if let Some(hex) = input.strip_prefix(DELEGATOR_PREFIX) {
+            let bytes = checksummed_hex::decode(hex)
+                .map_err(|error| FromStrError::Delegator(error.to_string()))?;
+            let (delegator_addr, remainder) = DelegatorAddr::from_bytes(&bytes)
+                .map_err(|error| FromStrError::Delegator(error.to_string()))?;
+            if !remainder.is_empty() {
+                return Err(FromStrError::Delegator(
+                    "unexpected trailing bytes".to_string(),
+                ));
+            }
+            return Ok(Key::Delegator(delegator_addr));
+        }
+
         Err(FromStrError::UnknownPrefix)
     }
 
@@ -621,6 +758,21 @@ impl Display for Key {
                     base16::encode_lower(&PADDING_BYTES)
                 )
             }
+            Key::ScheduledInvocation(addr) => {
+                write!(
+                    f,
+                    "Key::ScheduledInvocation({})",
+                    base16::encode_lower(addr)
+                )
+            }
+            Key::Delegator(addr) => {
+                write!(
+                    f,
+                    "Key::Delegator({}, {})",
+                    addr.validator(),
+                    addr.delegator()
+                )
+            }
         }
     }
 }
@@ -649,6 +801,8 @@ impl Tagged<KeyTag> for Key {
             Key::Unbond(_) => KeyTag::Unbond,
             Key::ChainspecRegistry => KeyTag::ChainspecRegistry,
             Key::ChecksumRegistry => KeyTag::ChecksumRegistry,
+            Key::ScheduledInvocation(_) => KeyTag::ScheduledInvocation,
+            Key::Delegator(_) => KeyTag::Delegator,
         }
     }
 }
@@ -722,6 +876,8 @@ impl ToBytes for Key {
             Key::Unbond(_) => KEY_UNBOND_SERIALIZED_LENGTH,
             Key::ChainspecRegistry => KEY_CHAINSPEC_REGISTRY_SERIALIZED_LENGTH,
             Key::ChecksumRegistry => KEY_CHECKSUM_REGISTRY_SERIALIZED_LENGTH,
+            Key::ScheduledInvocation(_) => KEY_SCHEDULED_INVOCATION_SERIALIZED_LENGTH,
+            Key::Delegator(_) => KEY_DELEGATOR_SERIALIZED_LENGTH,
         }
     }
 
@@ -739,6 +895,8 @@ impl ToBytes for Key {
             Key::Withdraw(account_hash) => account_hash.write_bytes(writer),
             Key::Dictionary(addr) => addr.write_bytes(writer),
             Key::Unbond(account_hash) => account_hash.write_bytes(writer),
+            Key::ScheduledInvocation(addr) => addr.write_bytes(writer),
+            Key::Delegator(addr) => addr.write_bytes(writer),
             Key::SystemContractRegistry
             | Key::EraSummary
             | Key::ChainspecRegistry
@@ -811,6 +969,14 @@ impl FromBytes for Key {
                 let (_, rem) = <[u8; 32]>::from_bytes(remainder)?;
                 Ok((Key::ChecksumRegistry, rem))
             }
+            tag if tag == KeyTag::ScheduledInvocation as u8 => {
+                let (addr, rem) = ScheduledInvocationAddr::from_bytes(remainder)?;
+                Ok((Key::ScheduledInvocation(addr), rem))
+            }
+            tag if tag == KeyTag::Delegator as u8 => {
+                let (addr, rem) = DelegatorAddr::from_bytes(remainder)?;
+                Ok((Key::Delegator(addr), rem))
+            }
             _ => Err(Error::Formatting),
         }
     }
@@ -836,12 +1002,14 @@ fn please_add_to_distribution_impl(key: Key) {
         Key::Unbond(_) => unimplemented!(),
         Key::ChainspecRegistry => unimplemented!(),
         Key::ChecksumRegistry => unimplemented!(),
+        Key::ScheduledInvocation(_) => unimplemented!(),
+        Key::Delegator(_) => unimplemented!(),
     }
 }
 
 impl Distribution<Key> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Key {
-        match rng.gen_range(0..=14) {
+        match rng.gen_range(0..=16) {
             0 => Key::Account(rng.gen()),
             1 => Key::Hash(rng.gen()),
             2 => Key::URef(rng.gen()),
@@ -857,6 +1025,8 @@ impl Distribution<Key> for Standard {
             12 => Key::Unbond(rng.gen()),
             13 => Key::ChainspecRegistry,
             14 => Key::ChecksumRegistry,
+            15 => Key::ScheduledInvocation(rng.gen()),
+            16 => Key::Delegator(rng.gen()),
             _ => unreachable!(),
         }
     }
@@ -882,6 +1052,8 @@ mod serde_helpers {
         Unbond(String),
         ChainspecRegistry(String),
         ChecksumRegistry(String),
+        ScheduledInvocation(String),
+        Delegator(String),
     }
 
     impl From<&Key> for HumanReadable {
@@ -905,6 +1077,8 @@ mod serde_helpers {
                 Key::Unbond(_) => HumanReadable::Unbond(formatted_string),
                 Key::ChainspecRegistry => HumanReadable::ChainspecRegistry(formatted_string),
                 Key::ChecksumRegistry => HumanReadable::ChecksumRegistry(formatted_string),
+                Key::ScheduledInvocation(_) => HumanReadable::ScheduledInvocation(formatted_string),
+                Key::Delegator(_) => HumanReadable::Delegator(formatted_string),
             }
         }
     }
@@ -928,7 +1102,9 @@ mod serde_helpers {
                 | HumanReadable::EraSummary(formatted_string)
                 | HumanReadable::Unbond(formatted_string)
                 | HumanReadable::ChainspecRegistry(formatted_string)
-                | HumanReadable::ChecksumRegistry(formatted_string) => {
+                | HumanReadable::ChecksumRegistry(formatted_string)
+                | HumanReadable::ScheduledInvocation(formatted_string)
+                | HumanReadable::Delegator(formatted_string) => {
                     Key::from_formatted_str(&formatted_string)
                 }
             }
@@ -952,6 +1128,8 @@ mod serde_helpers {
         Unbond(&'a AccountHash),
         ChainspecRegistry,
         ChecksumRegistry,
+        ScheduledInvocation(&'a ScheduledInvocationAddr),
+        Delegator(&'a DelegatorAddr),
     }
 
     impl<'a> From<&'a Key> for BinarySerHelper<'a> {
@@ -972,6 +1150,8 @@ mod serde_helpers {
                 Key::Unbond(account_hash) => BinarySerHelper::Unbond(account_hash),
                 Key::ChainspecRegistry => BinarySerHelper::ChainspecRegistry,
                 Key::ChecksumRegistry => BinarySerHelper::ChecksumRegistry,
+                Key::ScheduledInvocation(addr) => BinarySerHelper::ScheduledInvocation(addr),
+                Key::Delegator(addr) => BinarySerHelper::Delegator(addr),
             }
         }
     }
@@ -993,6 +1173,8 @@ mod serde_helpers {
         Unbond(AccountHash),
         ChainspecRegistry,
         ChecksumRegistry,
+        ScheduledInvocation(ScheduledInvocationAddr),
+        Delegator(DelegatorAddr),
     }
 
     impl From<BinaryDeserHelper> for Key {
@@ -1013,6 +1195,8 @@ mod serde_helpers {
                 BinaryDeserHelper::Unbond(account_hash) => Key::Unbond(account_hash),
                 BinaryDeserHelper::ChainspecRegistry => Key::ChainspecRegistry,
                 BinaryDeserHelper::ChecksumRegistry => Key::ChecksumRegistry,
+                BinaryDeserHelper::ScheduledInvocation(addr) => Key::ScheduledInvocation(addr),
+                BinaryDeserHelper::Delegator(addr) => Key::Delegator(addr),
             }
         }
     }
@@ -1070,6 +1254,11 @@ mod tests {
     const UNBOND_KEY: Key = Key::Unbond(AccountHash::new([42; 32]));
     const CHAINSPEC_REGISTRY_KEY: Key = Key::ChainspecRegistry;
     const CHECKSUM_REGISTRY_KEY: Key = Key::ChecksumRegistry;
+    const SCHEDULED_INVOCATION_KEY: Key = Key::ScheduledInvocation([42; 32]);
+    const DELEGATOR_KEY: Key = Key::Delegator(DelegatorAddr::new(
+        AccountHash::new([42; 32]),
+        AccountHash::new([42; 32]),
+    ));
     const KEYS: &[Key] = &[
         ACCOUNT_KEY,
         HASH_KEY,
@@ -1086,6 +1275,8 @@ mod tests {
         UNBOND_KEY,
         CHAINSPEC_REGISTRY_KEY,
         CHECKSUM_REGISTRY_KEY,
+        SCHEDULED_INVOCATION_KEY,
+        DELEGATOR_KEY,
     ];
     const HEX_STRING: &str = "2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a2a";
 
@@ -1202,6 +1393,18 @@ mod tests {
                 base16::encode_lower(&PADDING_BYTES),
             )
         );
+        assert_eq!(
+            format!("{}", SCHEDULED_INVOCATION_KEY),
+            format!("Key::ScheduledInvocation({})", HEX_STRING)
+        );
+        assert_eq!(
+            format!("{}", DELEGATOR_KEY),
+            format!(
+                "Key::Delegator({}, {})",
+                AccountHash::new([42; 32]),
+                AccountHash::new([42; 32])
+            )
+        );
     }
 
     #[test]
@@ -1334,6 +1537,14 @@ mod tests {
             .unwrap_err()
             .to_string()
             .starts_with("checksum-registry-key from string error: "));
+        assert!(Key::from_formatted_str(SCHEDULED_INVOCATION_PREFIX)
+            .unwrap_err()
+            .to_string()
+            .starts_with("scheduled-invocation-key from string error: "));
+        assert!(Key::from_formatted_str(DELEGATOR_PREFIX)
+            .unwrap_err()
+            .to_string()
+            .starts_with("delegator-key from string error: "));
         let invalid_prefix = "a-0000000000000000000000000000000000000000000000000000000000000000";
         assert_eq!(
             Key::from_formatted_str(invalid_prefix)
@@ -1393,6 +1604,8 @@ mod tests {
                 "ChecksumRegistry":
                     format!("checksum-registry-{}", base16::encode_lower(&PADDING_BYTES))
             }),
+            json!({ "ScheduledInvocation": format!("scheduled-invocation-{}", HEX_STRING) }),
+            json!({ "Delegator": format!("delegator-{}{}", HEX_STRING, HEX_STRING) }),
         ];
 
         assert_eq!(
@@ -1444,5 +1657,10 @@ mod tests {
         round_trip(&Key::Unbond(AccountHash::new(zeros)));
         round_trip(&Key::ChainspecRegistry);
         round_trip(&Key::ChecksumRegistry);
+        round_trip(&Key::ScheduledInvocation(zeros));
+        round_trip(&Key::Delegator(DelegatorAddr::new(
+            AccountHash::new(zeros),
+            AccountHash::new(zeros),
+        )));
     }
 }
@@ -24,6 +24,7 @@ extern crate alloc;
 mod access_rights;
 pub mod account;
 pub mod api_error;
+mod block_info;
 mod block_time;
 pub mod bytesrepr;
 pub mod checksummed_hex;
@@ -47,6 +48,7 @@ mod named_key;
 mod phase;
 mod protocol_version;
 pub mod runtime_args;
+mod scheduled_invocation;
 mod semver;
 mod stored_value;
 pub mod system;
@@ -64,6 +66,7 @@ pub use access_rights::{
 };
 #[doc(inline)]
 pub use api_error::ApiError;
+pub use block_info::BlockInfoField;
 pub use block_time::{BlockTime, BLOCKTIME_SERIALIZED_LENGTH};
 pub use cl_type::{named_key_type, CLType, CLTyped};
 pub use cl_value::{CLTypeMismatch, CLValue, CLValueError};
@@ -77,13 +80,14 @@ pub use contracts::{
 pub use crypto::*;
 pub use deploy_info::DeployInfo;
 pub use execution_result::{
-    ExecutionEffect, ExecutionResult, OpKind, Operation, Transform, TransformEntry,
+    ExecutionEffect, ExecutionResult, FeeBreakdown, OpKind, Operation, Transform, TransformEntry,
 };
 pub use gas::Gas;
 pub use json_pretty_printer::json_pretty_print;
 #[doc(inline)]
 pub use key::{
-    DictionaryAddr, FromStrError as KeyFromStrError, HashAddr, Key, KeyTag, BLAKE2B_DIGEST_LENGTH,
+    DelegatorAddr, DictionaryAddr, FromStrError as KeyFromStrError, HashAddr, Key, KeyTag,
+    ScheduledInvocationAddr, BLAKE2B_DIGEST_LENGTH, DELEGATOR_ADDR_LENGTH,
     DICTIONARY_ITEM_KEY_MAX_LENGTH, KEY_DICTIONARY_LENGTH, KEY_HASH_LENGTH,
 };
 pub use motes::Motes;
@@ -92,6 +96,10 @@ pub use phase::{Phase, PHASE_SERIALIZED_LENGTH};
 pub use protocol_version::{ProtocolVersion, VersionCheckResult};
 #[doc(inline)]
 pub use runtime_args::{NamedArg, RuntimeArgs};
+pub use scheduled_invocation::{
+    ScheduledInvocation, ScheduledInvocationBucket, ScheduledTime,
+    SCHEDULED_INVOCATION_BUCKET_LENGTH, SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS,
+};
 pub use semver::{ParseSemVerError, SemVer, SEM_VER_SERIALIZED_LENGTH};
 pub use stored_value::{StoredValue, TypeMismatch as StoredValueTypeMismatch};
 pub use tagged::Tagged;
@@ -0,0 +1,435 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "datasize")]
+use datasize::DataSize;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+#[cfg(any(feature = "std", test))]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes, U64_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH},
+    CLType, CLTyped, ContractHash, EraId, KeyTag, RuntimeArgs, ScheduledInvocationAddr, Timestamp,
+    URef, U512,
+};
+
+const ERA_TAG: u8 = 0;
+const TIMESTAMP_TAG: u8 = 1;
+
+/// The number of leading bytes of a [`ScheduledInvocationAddr`] identifying the
+/// [`ScheduledInvocationBucket`] of the invocation.
+pub const SCHEDULED_INVOCATION_BUCKET_LENGTH: usize = U8_SERIALIZED_LENGTH + U64_SERIALIZED_LENGTH;
+
+/// The span of time covered by a [`ScheduledInvocationBucket::Timestamp`], in milliseconds.
+pub const SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS: u64 = 60_000;
+
+/// The point in time from which a [`ScheduledInvocation`] is due to be executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    any(feature = "std", test),
+    derive(Serialize, Deserialize),
+    serde(deny_unknown_fields)
+)]
+#[cfg_attr(feature = "datasize", derive(DataSize))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub enum ScheduledTime {
+    /// Due in the first block of the given era.
+    Era(EraId),
+    /// Due in the first block whose timestamp is at or after the given timestamp.
+    Timestamp(Timestamp),
+}
+
+impl ScheduledTime {
+    /// Returns `true` if an invocation scheduled at this time is due in a block of the given era
+    /// and timestamp.
+    pub fn is_due(&self, era_id: EraId, block_time: Timestamp) -> bool {
+        match self {
+            ScheduledTime::Era(due_era_id) => *due_era_id <= era_id,
+            ScheduledTime::Timestamp(due_time) => *due_time <= block_time,
+        }
+    }
+
+    /// Returns the bucket of the invocations scheduled at this time.
+    pub fn bucket(&self) -> ScheduledInvocationBucket {
+        match self {
+            ScheduledTime::Era(era_id) => ScheduledInvocationBucket::Era(*era_id),
+            ScheduledTime::Timestamp(timestamp) => {
+                ScheduledInvocationBucket::for_timestamp(*timestamp)
+            }
+        }
+    }
+}
+
+/// A group of scheduled invocations becoming due together.
+///
+/// The address of a scheduled invocation starts with its bucket, so that the invocations of a
+/// bucket can be read by key prefix rather than by going through all the scheduled invocations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ScheduledInvocationBucket {
+    /// The invocations scheduled for the given era.
+    Era(EraId),
+    /// The invocations scheduled for a timestamp within the given span of
+    /// [`SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS`] since the Unix epoch.
+    Timestamp(u64),
+}
+
+impl ScheduledInvocationBucket {
+    /// Returns the bucket of the invocations scheduled for the given timestamp.
+    pub fn for_timestamp(timestamp: Timestamp) -> Self {
+        ScheduledInvocationBucket::Timestamp(
+            timestamp.millis() / SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS,
+        )
+    }
+
+    /// Returns the address of the invocation of this bucket identified by `id`.
+    ///
+    /// The address consists of the bucket followed by the leading bytes of `id`.
+    pub fn addr(&self, id: &[u8; 32]) -> ScheduledInvocationAddr {
+        let mut addr = ScheduledInvocationAddr::default();
+        addr[..SCHEDULED_INVOCATION_BUCKET_LENGTH].copy_from_slice(&self.bytes());
+        addr[SCHEDULED_INVOCATION_BUCKET_LENGTH..]
+            .copy_from_slice(&id[..addr.len() - SCHEDULED_INVOCATION_BUCKET_LENGTH]);
+        addr
+    }
+
+    /// Returns the key prefix shared by all the invocations of this bucket.
+    pub fn key_prefix(&self) -> Vec<u8> {
+        let mut prefix =
+            Vec::with_capacity(U8_SERIALIZED_LENGTH + SCHEDULED_INVOCATION_BUCKET_LENGTH);
+        prefix.push(KeyTag::ScheduledInvocation as u8);
+        prefix.extend_from_slice(&self.bytes());
+        prefix
+    }
+
+    // The bucket value is written big-endian so that addresses sort by due time.
+    fn bytes(&self) -> [u8; SCHEDULED_INVOCATION_BUCKET_LENGTH] {
+        let (tag, value) = match self {
+            ScheduledInvocationBucket::Era(era_id) => (ERA_TAG, era_id.value()),
+            ScheduledInvocationBucket::Timestamp(span) => (TIMESTAMP_TAG, *span),
+        };
+        let mut bytes = [0; SCHEDULED_INVOCATION_BUCKET_LENGTH];
+        bytes[0] = tag;
+        bytes[U8_SERIALIZED_LENGTH..].copy_from_slice(&value.to_be_bytes());
+        bytes
+    }
+}
+
+impl ToBytes for ScheduledTime {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        self.write_bytes(&mut result)?;
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                ScheduledTime::Era(era_id) => era_id.serialized_length(),
+                ScheduledTime::Timestamp(timestamp) => timestamp.serialized_length(),
+            }
+    }
+
+    fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        match self {
+            ScheduledTime::Era(era_id) => {
+                writer.push(ERA_TAG);
+                era_id.write_bytes(writer)
+            }
+            ScheduledTime::Timestamp(timestamp) => {
+                writer.push(TIMESTAMP_TAG);
+                timestamp.write_bytes(writer)
+            }
+        }
+    }
+}
+
+impl FromBytes for ScheduledTime {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, rem) = u8::from_bytes(bytes)?;
+        match tag {
+            ERA_TAG => {
+                let (era_id, rem) = EraId::from_bytes(rem)?;
+                Ok((ScheduledTime::Era(era_id), rem))
+            }
+            TIMESTAMP_TAG => {
+                let (timestamp, rem) = Timestamp::from_bytes(rem)?;
+                Ok((ScheduledTime::Timestamp(timestamp), rem))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+impl CLTyped for ScheduledTime {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+/// A call to a stored contract registered to be executed by the system once its scheduled time is
+/// reached.
+///
+/// The gas for the call is prepaid: `payment_amount` is held in `payment_purse` until the
+/// invocation is executed, at which point it is used as the payment of the call on behalf of the
+/// `initiator`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    any(feature = "std", test),
+    derive(Serialize, Deserialize),
+    serde(deny_unknown_fields)
+)]
+#[cfg_attr(feature = "datasize", derive(DataSize))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct ScheduledInvocation {
+    initiator: AccountHash,
+    contract_hash: ContractHash,
+    entry_point: String,
+    args: RuntimeArgs,
+    scheduled_time: ScheduledTime,
+    payment_purse: URef,
+    payment_amount: U512,
+}
+
+impl ScheduledInvocation {
+    /// Creates a new [`ScheduledInvocation`].
+    pub fn new(
+        initiator: AccountHash,
+        contract_hash: ContractHash,
+        entry_point: String,
+        args: RuntimeArgs,
+        scheduled_time: ScheduledTime,
+        payment_purse: URef,
+        payment_amount: U512,
+    ) -> Self {
+        ScheduledInvocation {
+            initiator,
+            contract_hash,
+            entry_point,
+            args,
+            scheduled_time,
+            payment_purse,
+            payment_amount,
+        }
+    }
+
+    /// Returns the account on whose behalf the invocation is executed.
+    pub fn initiator(&self) -> AccountHash {
+        self.initiator
+    }
+
+    /// Returns the hash of the contract to call.
+    pub fn contract_hash(&self) -> ContractHash {
+        self.contract_hash
+    }
+
+    /// Returns the name of the entry point to call.
+    pub fn entry_point(&self) -> &str {
+        &self.entry_point
+    }
+
+    /// Returns the arguments of the call.
+    pub fn args(&self) -> &RuntimeArgs {
+        &self.args
+    }
+
+    /// Returns the time from which the invocation is due.
+    pub fn scheduled_time(&self) -> ScheduledTime {
+        self.scheduled_time
+    }
+
+    /// Returns the purse holding the prepaid payment of the invocation.
+    pub fn payment_purse(&self) -> URef {
+        self.payment_purse
+    }
+
+    /// Returns the prepaid payment amount of the invocation.
+    pub fn payment_amount(&self) -> U512 {
+        self.payment_amount
+    }
+}
+
+impl CLTyped for ScheduledInvocation {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for ScheduledInvocation {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        self.write_bytes(&mut result)?;
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.initiator.serialized_length()
+            + self.contract_hash.serialized_length()
+            + self.entry_point.serialized_length()
+            + self.args.serialized_length()
+            + self.scheduled_time.serialized_length()
+            + self.payment_purse.serialized_length()
+            + self.payment_amount.serialized_length()
+    }
+
+    fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        self.initiator.write_bytes(writer)?;
+        self.contract_hash.write_bytes(writer)?;
+        self.entry_point.write_bytes(writer)?;
+        self.args.write_bytes(writer)?;
+        self.scheduled_time.write_bytes(writer)?;
+        self.payment_purse.write_bytes(writer)?;
+        self.payment_amount.write_bytes(writer)?;
+        Ok(())
+    }
+}
+
+impl FromBytes for ScheduledInvocation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (initiator, rem) = AccountHash::from_bytes(bytes)?;
+        let (contract_hash, rem) = ContractHash::from_bytes(rem)?;
+        let (entry_point, rem) = String::from_bytes(rem)?;
+        let (args, rem) = RuntimeArgs::from_bytes(rem)?;
+        let (scheduled_time, rem) = ScheduledTime::from_bytes(rem)?;
+        let (payment_purse, rem) = URef::from_bytes(rem)?;
+        let (payment_amount, rem) = U512::from_bytes(rem)?;
+        Ok((
+            ScheduledInvocation {
+                initiator,
+                contract_hash,
+                entry_point,
+                args,
+                scheduled_time,
+                payment_purse,
+                payment_amount,
+            },
+            rem,
+        ))
+    }
+}
+
+/// Generators for a [`ScheduledInvocation`].
+#[cfg(any(feature = "testing", feature = "gens", test))]
+pub(crate) mod gens {
+    use proptest::{
+        collection,
+        prelude::{any, Strategy},
+        prop_oneof,
+    };
+
+    use crate::{
+        gens::{account_hash_arb, era_id_arb, named_args_arb, u512_arb, u8_slice_32, uref_arb},
+        ContractHash, RuntimeArgs, Timestamp,
+    };
+
+    use super::{ScheduledInvocation, ScheduledTime};
+
+    /// Creates an arbitrary [`ScheduledTime`].
+    pub fn scheduled_time_arb() -> impl Strategy<Value = ScheduledTime> {
+        prop_oneof![
+            era_id_arb().prop_map(ScheduledTime::Era),
+            any::<u64>().prop_map(|millis| ScheduledTime::Timestamp(Timestamp::from(millis))),
+        ]
+    }
+
+    /// Creates an arbitrary [`ScheduledInvocation`].
+    pub fn scheduled_invocation_arb() -> impl Strategy<Value = ScheduledInvocation> {
+        (
+            account_hash_arb(),
+            u8_slice_32().prop_map(ContractHash::new),
+            "[a-z_]{1,16}",
+            collection::vec(named_args_arb(), 0..5).prop_map(RuntimeArgs::from),
+            scheduled_time_arb(),
+            uref_arb(),
+            u512_arb(),
+        )
+            .prop_map(
+                |(
+                    initiator,
+                    contract_hash,
+                    entry_point,
+                    args,
+                    scheduled_time,
+                    payment_purse,
+                    payment_amount,
+                )| {
+                    ScheduledInvocation::new(
+                        initiator,
+                        contract_hash,
+                        entry_point,
+                        args,
+                        scheduled_time,
+                        payment_purse,
+                        payment_amount,
+                    )
+                },
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::{bytesrepr, EraId, Key, Timestamp};
+
+    use super::{
+        gens, ScheduledInvocationBucket, ScheduledTime,
+        SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS,
+    };
+
+    #[test]
+    fn should_be_due_from_scheduled_time() {
+        let era = ScheduledTime::Era(EraId::new(5));
+        assert!(!era.is_due(EraId::new(4), Timestamp::from(u64::MAX)));
+        assert!(era.is_due(EraId::new(5), Timestamp::zero()));
+        assert!(era.is_due(EraId::new(6), Timestamp::zero()));
+
+        let timestamp = ScheduledTime::Timestamp(Timestamp::from(1_000));
+        assert!(!timestamp.is_due(EraId::new(u64::MAX), Timestamp::from(999)));
+        assert!(timestamp.is_due(EraId::new(0), Timestamp::from(1_000)));
+    }
+
+    #[test]
+    fn should_prefix_addr_with_bucket() {
+        let id = [7; 32];
+        let buckets = [
+            ScheduledTime::Era(EraId::new(5)).bucket(),
+            ScheduledTime::Timestamp(Timestamp::from(
+                SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS,
+            ))
+            .bucket(),
+        ];
+        for bucket in buckets {
+            let key_bytes =
+                bytesrepr::serialize(Key::ScheduledInvocation(bucket.addr(&id))).unwrap();
+            assert!(key_bytes.starts_with(&bucket.key_prefix()));
+        }
+        assert_ne!(buckets[0].key_prefix(), buckets[1].key_prefix());
+
+        assert_eq!(
+            ScheduledTime::Timestamp(Timestamp::from(
+                SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS - 1
+            ))
+            .bucket(),
+            ScheduledInvocationBucket::Timestamp(0)
+        );
+        assert_eq!(
+            ScheduledTime::Timestamp(Timestamp::from(
+                SCHEDULED_INVOCATION_TIMESTAMP_BUCKET_MILLIS
+            ))
+            .bucket(),
+            ScheduledInvocationBucket::Timestamp(1)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_serialization_roundtrip(scheduled_invocation in gens::scheduled_invocation_arb()) {
+            bytesrepr::test_serialization_roundtrip(&scheduled_invocation)
+        }
+    }
+}
@@ -16,7 +16,7 @@ use crate::{
     account::Account,
     bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
     contracts::ContractPackage,
-    system::auction::{Bid, EraInfo, UnbondingPurse, WithdrawPurse},
+    system::auction::{Bid, Delegator, EraInfo, UnbondingPurse, WithdrawPurse},
     CLValue, Contract, ContractWasm, DeployInfo, Transfer,
 };
 pub use type_mismatch::TypeMismatch;
@@ -35,6 +35,7 @@ enum Tag {
     Bid = 8,
     Withdraw = 9,
     Unbonding = 10,
+    Delegator = 11,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -64,6 +65,8 @@ pub enum StoredValue {
     Withdraw(Vec<WithdrawPurse>),
     /// Variant that stores unbonding information.
     Unbonding(Vec<UnbondingPurse>),
+    /// Variant that stores a single [`Delegator`].
+    Delegator(Box<Delegator>),
 }
 
 impl StoredValue {
@@ -147,6 +150,14 @@ impl StoredValue {
         }
     }
 
+    /// Returns a wrapped [`Delegator`] if this is a `Delegator` variant.
+    pub fn as_delegator(&self) -> Option<&Delegator> {
+        match self {
+            StoredValue::Delegator(delegator) => Some(delegator),
+            _ => None,
+        }
+    }
+
     /// Returns the type name of the [`StoredValue`] enum variant.
     ///
     /// For [`CLValue`] variants it will return the name of the [`CLType`](crate::cl_type::CLType)
@@ -163,6 +174,7 @@ impl StoredValue {
             StoredValue::Bid(_) => "Bid".to_string(),
             StoredValue::Withdraw(_) => "Withdraw".to_string(),
             StoredValue::Unbonding(_) => "Unbonding".to_string(),
+            StoredValue::Delegator(_) => "Delegator".to_string(),
         }
     }
 
@@ -179,6 +191,7 @@ impl StoredValue {
             StoredValue::Bid(_) => Tag::Bid,
             StoredValue::Withdraw(_) => Tag::Withdraw,
             StoredValue::Unbonding(_) => Tag::Unbonding,
+            StoredValue::Delegator(_) => Tag::Delegator,
         }
     }
 }
@@ -213,6 +226,11 @@ impl From<Bid> for StoredValue {
         StoredValue::Bid(Box::new(bid))
     }
 }
+impl From<Delegator> for StoredValue {
+    fn from(delegator: Delegator) -> StoredValue {
+        StoredValue::Delegator(Box::new(delegator))
+    }
+}
 
 impl TryFrom<StoredValue> for CLValue {
     type Error = TypeMismatch;
@@ -341,6 +359,7 @@ impl ToBytes for StoredValue {
             StoredValue::Unbonding(unbonding_purses) => {
                 (Tag::Unbonding, unbonding_purses.to_bytes()?)
             }
+            StoredValue::Delegator(delegator) => (Tag::Delegator, delegator.to_bytes()?),
         };
         result.push(tag as u8);
         result.append(&mut serialized_data);
@@ -363,6 +382,7 @@ impl ToBytes for StoredValue {
                 StoredValue::Bid(bid) => bid.serialized_length(),
                 StoredValue::Withdraw(withdraw_purses) => withdraw_purses.serialized_length(),
                 StoredValue::Unbonding(unbonding_purses) => unbonding_purses.serialized_length(),
+                StoredValue::Delegator(delegator) => delegator.serialized_length(),
             }
     }
 
@@ -382,6 +402,7 @@ impl ToBytes for StoredValue {
             StoredValue::Bid(bid) => bid.write_bytes(writer)?,
             StoredValue::Withdraw(unbonding_purses) => unbonding_purses.write_bytes(writer)?,
             StoredValue::Unbonding(unbonding_purses) => unbonding_purses.write_bytes(writer)?,
+            StoredValue::Delegator(delegator) => delegator.write_bytes(writer)?,
         };
         Ok(())
     }
@@ -425,6 +446,11 @@ impl FromBytes for StoredValue {
                     (StoredValue::Unbonding(unbonding_purses), remainder)
                 })
             }
+            tag if tag == Tag::Delegator as u8 => {
+                Delegator::from_bytes(remainder).map(|(delegator, remainder)| {
+                    (StoredValue::Delegator(Box::new(delegator)), remainder)
+                })
+            }
             _ => Err(bytesrepr::Error::Formatting),
         }
     }
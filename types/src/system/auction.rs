@@ -11,7 +11,9 @@ mod withdraw_purse;
 
 use alloc::{collections::BTreeMap, vec::Vec};
 
-pub use bid::{Bid, VESTING_SCHEDULE_LENGTH_MILLIS};
+pub use bid::{
+    Bid, DelegationRateChange, MAX_DELEGATION_RATE_HISTORY_LENGTH, VESTING_SCHEDULE_LENGTH_MILLIS,
+};
 pub use constants::*;
 pub use delegator::Delegator;
 pub use entry_points::auction_entry_points;
@@ -1,6 +1,7 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
+mod delegation_rate_change;
 mod vesting;
 
 use alloc::{collections::BTreeMap, vec::Vec};
@@ -14,11 +15,25 @@ use serde::{Deserialize, Serialize};
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
     system::auction::{DelegationRate, Delegator, Error},
-    CLType, CLTyped, PublicKey, URef, U512,
+    CLType, CLTyped, EraId, PublicKey, URef, U512,
 };
 
+pub use delegation_rate_change::{DelegationRateChange, MAX_DELEGATION_RATE_HISTORY_LENGTH};
 pub use vesting::{VestingSchedule, VESTING_SCHEDULE_LENGTH_MILLIS};
 
+/// Tag written in place of the `inactive` flag of a [`Bid`] serialized in a versioned layout,
+/// followed by the layout version.
+///
+/// Bids without delegation rate changes and delegators stored apart keep the original layout
+/// ending in their `inactive` flag, so that bids stored before these were introduced keep their
+/// encoding.
+const VERSIONED_BID_TAG: u8 = 2;
+
+/// Version of the versioned [`Bid`] layout, in which the `inactive` flag is followed by the
+/// pending delegation rate change, the delegation rate history, the delegator count and the
+/// delegated amount.
+const BID_LAYOUT_VERSION_1: u8 = 1;
+
 /// An entry in the validator map.
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "datasize", derive(DataSize))]
@@ -39,6 +54,14 @@ pub struct Bid {
     delegators: BTreeMap<PublicKey, Delegator>,
     /// `true` if validator has been "evicted"
     inactive: bool,
+    /// Delegation rate increase which has been requested but not yet applied
+    pending_delegation_rate: Option<DelegationRateChange>,
+    /// Most recent delegation rate changes, oldest first
+    delegation_rate_history: Vec<DelegationRateChange>,
+    /// The number of delegators with a non-zero stake, which are stored apart from the bid
+    delegator_count: u32,
+    /// The total amount of tokens staked by the delegators stored apart from the bid
+    delegated_amount: U512,
 }
 
 impl Bid {
@@ -61,6 +84,10 @@ impl Bid {
             vesting_schedule,
             delegators,
             inactive,
+            pending_delegation_rate: None,
+            delegation_rate_history: Vec::new(),
+            delegator_count: 0,
+            delegated_amount: U512::zero(),
         }
     }
 
@@ -82,6 +109,10 @@ impl Bid {
             vesting_schedule,
             delegators,
             inactive,
+            pending_delegation_rate: None,
+            delegation_rate_history: Vec::new(),
+            delegator_count: 0,
+            delegated_amount: U512::zero(),
         }
     }
 
@@ -100,6 +131,10 @@ impl Bid {
             vesting_schedule,
             delegators,
             inactive,
+            pending_delegation_rate: None,
+            delegation_rate_history: Vec::new(),
+            delegator_count: 0,
+            delegated_amount: U512::zero(),
         }
     }
 
@@ -175,11 +210,76 @@ impl Bid {
         &mut self.delegators
     }
 
+    /// Returns the number of delegators with a non-zero stake which are stored apart from the
+    /// provided bid
+    pub fn delegator_count(&self) -> u32 {
+        self.delegator_count
+    }
+
+    /// Returns the total amount staked by the delegators which are stored apart from the provided
+    /// bid
+    pub fn delegated_amount(&self) -> &U512 {
+        &self.delegated_amount
+    }
+
+    /// Records a change of the stake of one of the delegators stored apart from the provided bid,
+    /// from `previous_staked_amount` to `staked_amount`.
+    ///
+    /// A delegator counts towards the delegators of the bid while its stake is non-zero.
+    pub fn record_delegation_change(
+        &mut self,
+        previous_staked_amount: U512,
+        staked_amount: U512,
+    ) -> Result<(), Error> {
+        let delegated_amount = self
+            .delegated_amount
+            .checked_sub(previous_staked_amount)
+            .and_then(|amount| amount.checked_add(staked_amount))
+            .ok_or(Error::InvalidAmount)?;
+        let delegator_count = match (previous_staked_amount.is_zero(), staked_amount.is_zero()) {
+            (true, false) => self.delegator_count.checked_add(1),
+            (false, true) => self.delegator_count.checked_sub(1),
+            _ => Some(self.delegator_count),
+        }
+        .ok_or(Error::InvalidAmount)?;
+        self.delegated_amount = delegated_amount;
+        self.delegator_count = delegator_count;
+        Ok(())
+    }
+
+    /// Removes the delegators held by the provided bid, recording them as stored apart from it.
+    ///
+    /// Returns the removed delegators with a non-zero stake, which are the ones to be stored.
+    pub fn take_delegators(&mut self) -> Result<Vec<Delegator>, Error> {
+        let mut delegators = Vec::with_capacity(self.delegators.len());
+        for (_, delegator) in core::mem::take(&mut self.delegators) {
+            if delegator.staked_amount().is_zero() {
+                continue;
+            }
+            self.record_delegation_change(U512::zero(), *delegator.staked_amount())?;
+            delegators.push(delegator);
+        }
+        Ok(delegators)
+    }
+
     /// Returns `true` if validator is inactive
     pub fn inactive(&self) -> bool {
         self.inactive
     }
 
+    /// Returns the delegation rate increase which is waiting for its notice period to elapse, if
+    /// any.
+    pub fn pending_delegation_rate(&self) -> Option<&DelegationRateChange> {
+        self.pending_delegation_rate.as_ref()
+    }
+
+    /// Returns the most recent applied delegation rate changes, oldest first.
+    ///
+    /// At most [`MAX_DELEGATION_RATE_HISTORY_LENGTH`] entries are retained.
+    pub fn delegation_rate_history(&self) -> &[DelegationRateChange] {
+        &self.delegation_rate_history
+    }
+
     /// Decreases the stake of the provided bid
     pub fn decrease_stake(
         &mut self,
@@ -233,6 +333,59 @@ impl Bid {
         self
     }
 
+    /// Requests a change of the delegation rate of the provided bid in `current_era_id`.
+    ///
+    /// An increase is recorded as pending and only takes effect `notice_period_eras` eras later,
+    /// replacing any previously pending change. A decrease, or any change when the notice period
+    /// is zero, takes effect immediately and cancels a pending increase. Requesting the current
+    /// delegation rate changes nothing.
+    pub fn change_delegation_rate(
+        &mut self,
+        delegation_rate: DelegationRate,
+        current_era_id: EraId,
+        notice_period_eras: u64,
+    ) {
+        if delegation_rate == self.delegation_rate {
+            return;
+        }
+
+        if delegation_rate > self.delegation_rate && notice_period_eras > 0 {
+            let effective_era_id = current_era_id.saturating_add(notice_period_eras);
+            self.pending_delegation_rate =
+                Some(DelegationRateChange::new(delegation_rate, effective_era_id));
+            return;
+        }
+
+        self.pending_delegation_rate = None;
+        self.apply_delegation_rate_change(DelegationRateChange::new(
+            delegation_rate,
+            current_era_id,
+        ));
+    }
+
+    /// Applies the pending delegation rate change of the provided bid if it is due by `era_id`.
+    ///
+    /// Returns `true` if the delegation rate was changed.
+    pub fn apply_pending_delegation_rate(&mut self, era_id: EraId) -> bool {
+        match self.pending_delegation_rate {
+            Some(change) if change.era_id() <= era_id => {
+                self.pending_delegation_rate = None;
+                self.apply_delegation_rate_change(change);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn apply_delegation_rate_change(&mut self, change: DelegationRateChange) {
+        self.delegation_rate = change.delegation_rate();
+        self.delegation_rate_history.push(change);
+        if self.delegation_rate_history.len() > MAX_DELEGATION_RATE_HISTORY_LENGTH {
+            let excess = self.delegation_rate_history.len() - MAX_DELEGATION_RATE_HISTORY_LENGTH;
+            self.delegation_rate_history.drain(..excess);
+        }
+    }
+
     /// Initializes the vesting schedule of provided bid if the provided timestamp is greater than
     /// or equal to the bid's initial release timestamp and the bid is owned by a genesis
     /// validator. This method initializes with default 14 week vesting schedule.
@@ -242,11 +395,10 @@ impl Bid {
         self.process_with_vesting_schedule(timestamp_millis, VESTING_SCHEDULE_LENGTH_MILLIS)
     }
 
-    /// Initializes the vesting schedule of provided bid if the provided timestamp is greater than
-    /// or equal to the bid's initial release timestamp and the bid is owned by a genesis
-    /// validator.
+    /// Initializes the vesting schedules of provided bid and of its delegators whose initial
+    /// release timestamps are less than or equal to the provided timestamp.
     ///
-    /// Returns `true` if the provided bid's vesting schedule was initialized.
+    /// Returns `true` if any of the vesting schedules was initialized.
     pub fn process_with_vesting_schedule(
         &mut self,
         timestamp_millis: u64,
@@ -254,30 +406,22 @@ impl Bid {
     ) -> bool {
         // Put timestamp-sensitive processing logic in here
         let staked_amount = self.staked_amount;
-        let vesting_schedule = match self.vesting_schedule_mut() {
-            Some(vesting_schedule) => vesting_schedule,
-            None => return false,
-        };
-        if timestamp_millis < vesting_schedule.initial_release_timestamp_millis() {
-            return false;
-        }
-
         let mut initialized = false;
 
-        if vesting_schedule.initialize_with_schedule(staked_amount, vesting_schedule_period_millis)
-        {
-            initialized = true;
+        if let Some(vesting_schedule) = self.vesting_schedule_mut() {
+            if timestamp_millis >= vesting_schedule.initial_release_timestamp_millis()
+                && vesting_schedule
+                    .initialize_with_schedule(staked_amount, vesting_schedule_period_millis)
+            {
+                initialized = true;
+            }
         }
 
         for delegator in self.delegators_mut().values_mut() {
-            let staked_amount = *delegator.staked_amount();
-            if let Some(vesting_schedule) = delegator.vesting_schedule_mut() {
-                if timestamp_millis >= vesting_schedule.initial_release_timestamp_millis()
-                    && vesting_schedule
-                        .initialize_with_schedule(staked_amount, vesting_schedule_period_millis)
-                {
-                    initialized = true;
-                }
+            if delegator
+                .process_with_vesting_schedule(timestamp_millis, vesting_schedule_period_millis)
+            {
+                initialized = true;
             }
         }
 
@@ -297,13 +441,53 @@ impl Bid {
     }
 
     /// Returns the total staked amount of validator + all delegators
+    ///
+    /// Delegators are accounted for by the total recorded for those stored apart from the bid, so
+    /// they don't need to be loaded into it.
     pub fn total_staked_amount(&self) -> Result<U512, Error> {
-        self.delegators
-            .iter()
-            .try_fold(U512::zero(), |a, (_, b)| a.checked_add(*b.staked_amount()))
-            .and_then(|delegators_sum| delegators_sum.checked_add(*self.staked_amount()))
+        self.staked_amount
+            .checked_add(self.delegated_amount)
             .ok_or(Error::InvalidAmount)
     }
+
+    /// Returns `true` if the provided bid can be serialized in the original layout.
+    fn has_original_layout(&self) -> bool {
+        self.pending_delegation_rate.is_none()
+            && self.delegation_rate_history.is_empty()
+            && self.delegator_count == 0
+            && self.delegated_amount.is_zero()
+    }
+
+    /// Writes the `inactive` flag, preceded by the layout version and followed by the fields added
+    /// since the original layout if any of them is set.
+    fn write_inactive_and_versioned_fields(
+        &self,
+        writer: &mut Vec<u8>,
+    ) -> Result<(), bytesrepr::Error> {
+        if self.has_original_layout() {
+            self.inactive.write_bytes(writer)
+        } else {
+            writer.push(VERSIONED_BID_TAG);
+            writer.push(BID_LAYOUT_VERSION_1);
+            self.inactive.write_bytes(writer)?;
+            self.pending_delegation_rate.write_bytes(writer)?;
+            self.delegation_rate_history.write_bytes(writer)?;
+            self.delegator_count.write_bytes(writer)?;
+            self.delegated_amount.write_bytes(writer)
+        }
+    }
+
+    fn inactive_and_versioned_fields_serialized_length(&self) -> usize {
+        if self.has_original_layout() {
+            self.inactive.serialized_length()
+        } else {
+            2 + self.inactive.serialized_length()
+                + self.pending_delegation_rate.serialized_length()
+                + self.delegation_rate_history.serialized_length()
+                + self.delegator_count.serialized_length()
+                + self.delegated_amount.serialized_length()
+        }
+    }
 }
 
 impl CLTyped for Bid {
@@ -321,7 +505,7 @@ impl ToBytes for Bid {
         self.delegation_rate.write_bytes(&mut result)?;
         self.vesting_schedule.write_bytes(&mut result)?;
         self.delegators().write_bytes(&mut result)?;
-        self.inactive.write_bytes(&mut result)?;
+        self.write_inactive_and_versioned_fields(&mut result)?;
         Ok(result)
     }
 
@@ -332,7 +516,7 @@ impl ToBytes for Bid {
             + self.delegation_rate.serialized_length()
             + self.vesting_schedule.serialized_length()
             + self.delegators.serialized_length()
-            + self.inactive.serialized_length()
+            + self.inactive_and_versioned_fields_serialized_length()
     }
 
     fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
@@ -342,8 +526,7 @@ impl ToBytes for Bid {
         self.delegation_rate.write_bytes(writer)?;
         self.vesting_schedule.write_bytes(writer)?;
         self.delegators().write_bytes(writer)?;
-        self.inactive.write_bytes(writer)?;
-        Ok(())
+        self.write_inactive_and_versioned_fields(writer)
     }
 }
 
@@ -355,7 +538,38 @@ impl FromBytes for Bid {
         let (delegation_rate, bytes) = FromBytes::from_bytes(bytes)?;
         let (vesting_schedule, bytes) = FromBytes::from_bytes(bytes)?;
         let (delegators, bytes) = FromBytes::from_bytes(bytes)?;
-        let (inactive, bytes) = FromBytes::from_bytes(bytes)?;
+        let (
+            inactive,
+            pending_delegation_rate,
+            delegation_rate_history,
+            delegator_count,
+            delegated_amount,
+            bytes,
+        ) = match bytes.first() {
+            Some(&VERSIONED_BID_TAG) => {
+                let (version, bytes) = u8::from_bytes(&bytes[1..])?;
+                if version != BID_LAYOUT_VERSION_1 {
+                    return Err(bytesrepr::Error::Formatting);
+                }
+                let (inactive, bytes) = FromBytes::from_bytes(bytes)?;
+                let (pending_delegation_rate, bytes) = FromBytes::from_bytes(bytes)?;
+                let (delegation_rate_history, bytes) = FromBytes::from_bytes(bytes)?;
+                let (delegator_count, bytes) = FromBytes::from_bytes(bytes)?;
+                let (delegated_amount, bytes) = FromBytes::from_bytes(bytes)?;
+                (
+                    inactive,
+                    pending_delegation_rate,
+                    delegation_rate_history,
+                    delegator_count,
+                    delegated_amount,
+                    bytes,
+                )
+            }
+            _ => {
+                let (inactive, bytes) = FromBytes::from_bytes(bytes)?;
+                (inactive, None, Vec::new(), 0, U512::zero(), bytes)
+            }
+        };
         Ok((
             Bid {
                 validator_public_key,
@@ -365,6 +579,10 @@ impl FromBytes for Bid {
                 vesting_schedule,
                 delegators,
                 inactive,
+                pending_delegation_rate,
+                delegation_rate_history,
+                delegator_count,
+                delegated_amount,
             },
             bytes,
         ))
@@ -376,9 +594,13 @@ mod tests {
     use alloc::collections::BTreeMap;
 
     use crate::{
-        bytesrepr,
-        system::auction::{bid::VestingSchedule, Bid, DelegationRate, Delegator},
-        AccessRights, PublicKey, SecretKey, URef, U512,
+        bytesrepr::{self, FromBytes, ToBytes},
+        system::auction::{
+            bid::{VestingSchedule, BID_LAYOUT_VERSION_1, VERSIONED_BID_TAG},
+            Bid, DelegationRate, DelegationRateChange, Delegator,
+            MAX_DELEGATION_RATE_HISTORY_LENGTH,
+        },
+        AccessRights, EraId, PublicKey, SecretKey, URef, U512,
     };
 
     const WEEK_MILLIS: u64 = 7 * 24 * 60 * 60 * 1000;
@@ -396,10 +618,165 @@ mod tests {
             vesting_schedule: Some(VestingSchedule::default()),
             delegators: BTreeMap::default(),
             inactive: true,
+            pending_delegation_rate: Some(DelegationRateChange::new(10, EraId::new(3))),
+            delegation_rate_history: vec![DelegationRateChange::new(5, EraId::new(1))],
+            delegator_count: 2,
+            delegated_amount: U512::from(10),
         };
         bytesrepr::test_serialization_roundtrip(&founding_validator);
     }
 
+    #[test]
+    fn should_serialize_bid_compatibly() {
+        let mut bid = Bid::unlocked(
+            PublicKey::from(&SecretKey::ed25519_from_bytes([1u8; 32]).unwrap()),
+            URef::new([42; 32], AccessRights::READ_ADD_WRITE),
+            U512::one(),
+            0,
+        );
+        let bytes = bid.to_bytes().unwrap();
+        assert_eq!(
+            *bytes.last().unwrap(),
+            u8::from(false),
+            "bids without delegation rate changes or delegators should keep their original encoding"
+        );
+        let (deserialized, remainder) = Bid::from_bytes(&bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(deserialized, bid);
+
+        bid.record_delegation_change(U512::zero(), U512::from(10))
+            .unwrap();
+        let versioned_bytes = bid.to_bytes().unwrap();
+        assert_eq!(versioned_bytes.len(), bid.serialized_length());
+        let version_index = bytes.len();
+        assert_eq!(versioned_bytes[version_index - 1], VERSIONED_BID_TAG);
+        let (deserialized, remainder) = Bid::from_bytes(&versioned_bytes).unwrap();
+        assert!(remainder.is_empty());
+        assert_eq!(deserialized, bid);
+
+        let mut unknown_version_bytes = versioned_bytes;
+        unknown_version_bytes[version_index] = BID_LAYOUT_VERSION_1 + 1;
+        assert!(Bid::from_bytes(&unknown_version_bytes).is_err());
+    }
+
+    #[test]
+    fn should_record_delegators_stored_apart() {
+        let validator_pk = PublicKey::from(&SecretKey::ed25519_from_bytes([1u8; 32]).unwrap());
+        let mut bid = Bid::unlocked(
+            validator_pk.clone(),
+            URef::new([42; 32], AccessRights::READ_ADD_WRITE),
+            U512::from(5),
+            0,
+        );
+        for (seed, staked_amount) in [(2u8, 10u64), (3, 0)] {
+            let delegator_pk = PublicKey::from(&SecretKey::ed25519_from_bytes([seed; 32]).unwrap());
+            let delegator = Delegator::unlocked(
+                delegator_pk.clone(),
+                U512::from(staked_amount),
+                URef::new([seed; 32], AccessRights::READ_ADD_WRITE),
+                validator_pk.clone(),
+            );
+            bid.delegators_mut().insert(delegator_pk, delegator);
+        }
+
+        let delegators = bid.take_delegators().unwrap();
+        assert_eq!(delegators.len(), 1);
+        assert!(bid.delegators().is_empty());
+        assert_eq!(bid.delegator_count(), 1);
+        assert_eq!(bid.total_staked_amount().unwrap(), U512::from(15));
+
+        bid.record_delegation_change(U512::zero(), U512::from(7))
+            .unwrap();
+        bid.record_delegation_change(U512::from(10), U512::zero())
+            .unwrap();
+        assert_eq!(bid.delegator_count(), 1);
+        assert_eq!(*bid.delegated_amount(), U512::from(7));
+        assert!(bid
+            .record_delegation_change(U512::from(8), U512::zero())
+            .is_err());
+    }
+
+    #[test]
+    fn should_delay_delegation_rate_increase() {
+        let mut bid = Bid::unlocked(
+            PublicKey::from(&SecretKey::ed25519_from_bytes([1u8; 32]).unwrap()),
+            URef::new([42; 32], AccessRights::READ_ADD_WRITE),
+            U512::one(),
+            10,
+        );
+
+        bid.change_delegation_rate(20, EraId::new(5), 3);
+        assert_eq!(*bid.delegation_rate(), 10);
+        assert_eq!(
+            bid.pending_delegation_rate(),
+            Some(&DelegationRateChange::new(20, EraId::new(8)))
+        );
+
+        // Requesting the current delegation rate again leaves the pending increase in place.
+        bid.change_delegation_rate(10, EraId::new(6), 3);
+        assert_eq!(
+            bid.pending_delegation_rate(),
+            Some(&DelegationRateChange::new(20, EraId::new(8)))
+        );
+
+        assert!(!bid.apply_pending_delegation_rate(EraId::new(7)));
+        assert_eq!(*bid.delegation_rate(), 10);
+
+        assert!(bid.apply_pending_delegation_rate(EraId::new(8)));
+        assert_eq!(*bid.delegation_rate(), 20);
+        assert!(bid.pending_delegation_rate().is_none());
+        assert_eq!(
+            bid.delegation_rate_history(),
+            &[DelegationRateChange::new(20, EraId::new(8))]
+        );
+    }
+
+    #[test]
+    fn should_apply_delegation_rate_decrease_immediately() {
+        let mut bid = Bid::unlocked(
+            PublicKey::from(&SecretKey::ed25519_from_bytes([1u8; 32]).unwrap()),
+            URef::new([42; 32], AccessRights::READ_ADD_WRITE),
+            U512::one(),
+            10,
+        );
+
+        bid.change_delegation_rate(20, EraId::new(5), 3);
+        bid.change_delegation_rate(5, EraId::new(6), 3);
+        assert_eq!(*bid.delegation_rate(), 5);
+        assert!(bid.pending_delegation_rate().is_none());
+        assert_eq!(
+            bid.delegation_rate_history(),
+            &[DelegationRateChange::new(5, EraId::new(6))]
+        );
+    }
+
+    #[test]
+    fn should_bound_delegation_rate_history() {
+        let mut bid = Bid::unlocked(
+            PublicKey::from(&SecretKey::ed25519_from_bytes([1u8; 32]).unwrap()),
+            URef::new([42; 32], AccessRights::READ_ADD_WRITE),
+            U512::one(),
+            DelegationRate::max_value(),
+        );
+
+        let changes = MAX_DELEGATION_RATE_HISTORY_LENGTH as u8 + 5;
+        for rate in 0..changes {
+            bid.change_delegation_rate(changes - rate - 1, EraId::new(rate.into()), 1);
+        }
+        assert_eq!(*bid.delegation_rate(), 0);
+        assert_eq!(
+            bid.delegation_rate_history().len(),
+            MAX_DELEGATION_RATE_HISTORY_LENGTH
+        );
+        assert_eq!(
+            bid.delegation_rate_history().last(),
+            Some(&DelegationRateChange::new(
+                0,
+                EraId::new((changes - 1).into())
+            ))
+        );
+    }
+
     #[test]
     fn should_immediately_initialize_unlock_amounts() {
         const TIMESTAMP_MILLIS: u64 = 0;
@@ -430,6 +807,43 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn should_initialize_delegator_vesting_schedule_of_unlocked_validator() {
+        const TIMESTAMP_MILLIS: u64 = WEEK_MILLIS;
+
+        let validator_pk: PublicKey = (&SecretKey::ed25519_from_bytes([42; 32]).unwrap()).into();
+        let delegator_pk: PublicKey = (&SecretKey::ed25519_from_bytes([43; 32]).unwrap()).into();
+
+        let mut bid = Bid::unlocked(
+            validator_pk.clone(),
+            URef::new([42; 32], AccessRights::ADD),
+            U512::from(1000),
+            0,
+        );
+        let delegator = Delegator::locked(
+            delegator_pk.clone(),
+            U512::from(2000),
+            URef::new([52; 32], AccessRights::ADD),
+            validator_pk,
+            TIMESTAMP_MILLIS,
+        );
+        bid.delegators_mut().insert(delegator_pk.clone(), delegator);
+
+        assert!(!bid.process_with_vesting_schedule(
+            TIMESTAMP_MILLIS - 1,
+            TEST_VESTING_SCHEDULE_LENGTH_MILLIS
+        ));
+        assert!(bid
+            .process_with_vesting_schedule(TIMESTAMP_MILLIS, TEST_VESTING_SCHEDULE_LENGTH_MILLIS));
+
+        let delegator = bid.delegators().get(&delegator_pk).unwrap();
+        assert!(delegator
+            .vesting_schedule()
+            .unwrap()
+            .locked_amounts()
+            .is_some());
+    }
+
     #[test]
     fn should_initialize_delegators_different_timestamps() {
         const TIMESTAMP_MILLIS: u64 = WEEK_MILLIS;
@@ -0,0 +1,95 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "datasize")]
+use datasize::DataSize;
+#[cfg(feature = "json-schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    system::auction::DelegationRate,
+    EraId,
+};
+
+/// Maximum number of past delegation rate changes retained in a bid.
+pub const MAX_DELEGATION_RATE_HISTORY_LENGTH: usize = 10;
+
+/// A change of a validator's delegation rate, taking effect from the given era.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "datasize", derive(DataSize))]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct DelegationRateChange {
+    /// The new delegation rate.
+    delegation_rate: DelegationRate,
+    /// The era from which the new delegation rate applies.
+    era_id: EraId,
+}
+
+impl DelegationRateChange {
+    /// Creates a new delegation rate change.
+    pub fn new(delegation_rate: DelegationRate, era_id: EraId) -> Self {
+        DelegationRateChange {
+            delegation_rate,
+            era_id,
+        }
+    }
+
+    /// Returns the new delegation rate.
+    pub fn delegation_rate(&self) -> DelegationRate {
+        self.delegation_rate
+    }
+
+    /// Returns the era from which the new delegation rate applies.
+    pub fn era_id(&self) -> EraId {
+        self.era_id
+    }
+}
+
+impl ToBytes for DelegationRateChange {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        self.write_bytes(&mut result)?;
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.delegation_rate.serialized_length() + self.era_id.serialized_length()
+    }
+
+    fn write_bytes(&self, writer: &mut Vec<u8>) -> Result<(), bytesrepr::Error> {
+        self.delegation_rate.write_bytes(writer)?;
+        self.era_id.write_bytes(writer)?;
+        Ok(())
+    }
+}
+
+impl FromBytes for DelegationRateChange {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (delegation_rate, bytes) = FromBytes::from_bytes(bytes)?;
+        let (era_id, bytes) = FromBytes::from_bytes(bytes)?;
+        Ok((
+            DelegationRateChange {
+                delegation_rate,
+                era_id,
+            },
+            bytes,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DelegationRateChange;
+    use crate::{bytesrepr, EraId};
+
+    #[test]
+    fn serialization_roundtrip() {
+        let change = DelegationRateChange::new(42, EraId::new(7));
+        bytesrepr::test_serialization_roundtrip(&change);
+    }
+}
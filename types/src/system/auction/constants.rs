@@ -22,6 +22,8 @@ pub const ARG_AMOUNT: &str = "amount";
 pub const ARG_DELEGATION_RATE: &str = "delegation_rate";
 /// Named constant for `account_hash`.
 pub const ARG_PUBLIC_KEY: &str = "public_key";
+/// Named constant for `proof_of_possession`.
+pub const ARG_PROOF_OF_POSSESSION: &str = "proof_of_possession";
 /// Named constant for `validator`.
 pub const ARG_VALIDATOR: &str = "validator";
 /// Named constant for `delegator`.
@@ -58,6 +60,8 @@ pub const ARG_UNBONDING_DELAY: &str = "unbonding_delay";
 pub const ARG_ERA_END_TIMESTAMP_MILLIS: &str = "era_end_timestamp_millis";
 /// Named constant for `evicted_validators`;
 pub const ARG_EVICTED_VALIDATORS: &str = "evicted_validators";
+/// Named constant for `release_timestamp_millis`.
+pub const ARG_RELEASE_TIMESTAMP_MILLIS: &str = "release_timestamp_millis";
 
 /// Named constant for method `get_era_validators`.
 pub const METHOD_GET_ERA_VALIDATORS: &str = "get_era_validators";
@@ -67,6 +71,8 @@ pub const METHOD_ADD_BID: &str = "add_bid";
 pub const METHOD_WITHDRAW_BID: &str = "withdraw_bid";
 /// Named constant for method `delegate`.
 pub const METHOD_DELEGATE: &str = "delegate";
+/// Named constant for method `delegate_with_vesting`.
+pub const METHOD_DELEGATE_WITH_VESTING: &str = "delegate_with_vesting";
 /// Named constant for method `undelegate`.
 pub const METHOD_UNDELEGATE: &str = "undelegate";
 /// Named constant for method `redelegate`.
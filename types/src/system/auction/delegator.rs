@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
     system::auction::{bid::VestingSchedule, Error},
-    CLType, CLTyped, PublicKey, URef, U512,
+    CLType, CLTyped, DelegatorAddr, PublicKey, URef, U512,
 };
 
 /// Represents a party delegating their stake to a validator (or "delegatee")
@@ -89,6 +89,14 @@ impl Delegator {
         &self.validator_public_key
     }
 
+    /// Returns the address under which this delegation is stored in global state.
+    pub fn delegator_addr(&self) -> DelegatorAddr {
+        DelegatorAddr::new(
+            self.validator_public_key.to_account_hash(),
+            self.delegator_public_key.to_account_hash(),
+        )
+    }
+
     /// Decreases the stake of the provided bid
     pub fn decrease_stake(
         &mut self,
@@ -147,6 +155,26 @@ impl Delegator {
     pub fn vesting_schedule_mut(&mut self) -> Option<&mut VestingSchedule> {
         self.vesting_schedule.as_mut()
     }
+
+    /// Initializes the vesting schedule of the provided delegator bid if the provided timestamp is
+    /// greater than or equal to its initial release timestamp.
+    ///
+    /// Returns `true` if the vesting schedule was initialized.
+    pub fn process_with_vesting_schedule(
+        &mut self,
+        timestamp_millis: u64,
+        vesting_schedule_period_millis: u64,
+    ) -> bool {
+        let staked_amount = self.staked_amount;
+        match self.vesting_schedule_mut() {
+            Some(vesting_schedule) => {
+                timestamp_millis >= vesting_schedule.initial_release_timestamp_millis()
+                    && vesting_schedule
+                        .initialize_with_schedule(staked_amount, vesting_schedule_period_millis)
+            }
+            None => false,
+        }
+    }
 }
 
 impl CLTyped for Delegator {
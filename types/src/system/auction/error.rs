@@ -327,6 +327,24 @@ pub enum Error {
     /// assert_eq!(49, Error::TransferToAdministrator as u8);
     /// ```
     TransferToAdministrator = 49,
+    /// A delegation with a vesting schedule can't be added to an existing delegation.
+    /// ```
+    /// # use casper_types::system::auction::Error;
+    /// assert_eq!(50, Error::DelegationAlreadyExists as u8);
+    /// ```
+    DelegationAlreadyExists = 50,
+    /// BLS12-381 validator keys are not allowed before the configured protocol version.
+    /// ```
+    /// # use casper_types::system::auction::Error;
+    /// assert_eq!(51, Error::BlsKeysNotAllowed as u8);
+    /// ```
+    BlsKeysNotAllowed = 51,
+    /// A bid for a BLS12-381 key has a missing or invalid proof of possession.
+    /// ```
+    /// # use casper_types::system::auction::Error;
+    /// assert_eq!(52, Error::InvalidProofOfPossession as u8);
+    /// ```
+    InvalidProofOfPossession = 52,
 }
 
 impl Display for Error {
@@ -382,6 +400,13 @@ impl Display for Error {
             Error::AuctionBidsDisabled => formatter.write_str("Auction bids are disabled"),
             Error::GetAccumulationPurse => formatter.write_str("Get accumulation purse error"),
             Error::TransferToAdministrator => formatter.write_str("Transfer to administrator error"),
+            Error::DelegationAlreadyExists => {
+                formatter.write_str("The delegator already delegates to the validator")
+            }
+            Error::BlsKeysNotAllowed => formatter.write_str("BLS12-381 keys are not allowed yet"),
+            Error::InvalidProofOfPossession => {
+                formatter.write_str("Missing or invalid proof of possession")
+            }
         }
     }
 }
@@ -463,6 +488,9 @@ impl TryFrom<u8> for Error {
             d if d == Error::AuctionBidsDisabled as u8 => Ok(Error::AuctionBidsDisabled),
             d if d == Error::GetAccumulationPurse as u8 => Ok(Error::GetAccumulationPurse),
             d if d == Error::TransferToAdministrator as u8 => Ok(Error::TransferToAdministrator),
+            d if d == Error::DelegationAlreadyExists as u8 => Ok(Error::DelegationAlreadyExists),
+            d if d == Error::BlsKeysNotAllowed as u8 => Ok(Error::BlsKeysNotAllowed),
+            d if d == Error::InvalidProofOfPossession as u8 => Ok(Error::InvalidProofOfPossession),
             _ => Err(TryFromU8ForError(())),
         }
     }
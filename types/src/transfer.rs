@@ -105,7 +105,8 @@ impl<'de> Deserialize<'de> for DeployHash {
             let hex_string = String::deserialize(deserializer)?;
             let vec_bytes =
                 checksummed_hex::decode(hex_string.as_bytes()).map_err(SerdeError::custom)?;
-            <[u8; DEPLOY_HASH_LENGTH]>::try_from(vec_bytes.as_ref()).map_err(SerdeError::custom)?
+            <[u8; DEPLOY_HASH_LENGTH]>::try_from(vec_bytes.as_slice())
+                .map_err(SerdeError::custom)?
         } else {
             <[u8; DEPLOY_HASH_LENGTH]>::deserialize(deserializer)?
         };
@@ -312,7 +313,7 @@ impl TransferAddr {
             .strip_prefix(TRANSFER_ADDR_FORMATTED_STRING_PREFIX)
             .ok_or(FromStrError::InvalidPrefix)?;
         let bytes =
-            <[u8; TRANSFER_ADDR_LENGTH]>::try_from(checksummed_hex::decode(remainder)?.as_ref())?;
+            <[u8; TRANSFER_ADDR_LENGTH]>::try_from(checksummed_hex::decode(remainder)?.as_slice())?;
         Ok(TransferAddr(bytes))
     }
 }
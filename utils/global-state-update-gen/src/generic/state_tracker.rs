@@ -239,6 +239,22 @@ impl<T: StateReader> StateTracker<T> {
         // Replace the bid (overwrite the previous bid, if any):
         self.write_entry(Key::Bid(account_hash), bid.clone().into());
 
+        // Delegators are stored under their own keys, which the upgrade moves any delegators
+        // embedded in the bid to. Those dropped from the bid have to be removed explicitly, by
+        // writing a record without stake.
+        if let Some(old_bid) = maybe_current_bid.as_ref() {
+            for (delegator_pub_key, delegator) in old_bid.delegators() {
+                if !bid.delegators().contains_key(delegator_pub_key) {
+                    let mut removed_delegator = delegator.clone();
+                    *removed_delegator.staked_amount_mut() = U512::zero();
+                    self.write_entry(
+                        Key::Delegator(removed_delegator.delegator_addr()),
+                        StoredValue::Delegator(Box::new(removed_delegator)),
+                    );
+                }
+            }
+        }
+
         // Update the bonding purses - this will also take care of the total supply changes.
         if let Some(old_bid) = maybe_current_bid {
             // only zero the bonding purse and write the balance to the new purse if the new one is
@@ -1135,14 +1135,15 @@ fn should_replace_a_delegator() {
     update.assert_written_purse_is_unit(delegator2_bid_purse);
     update.assert_written_balance(delegator2_bid_purse, 14);
 
-    // 6 keys should be written:
+    // 7 keys should be written:
     // - seigniorage recipients
     // - total supply
     // - bid for validator 1
     // - bonding purse balance for old delegator
     // - bonding purse for new delegator
     // - bonding purse balance for new delegator
-    assert_eq!(update.len(), 6);
+    // - removed record for old delegator
+    assert_eq!(update.len(), 7);
 }
 
 #[test]
@@ -1235,14 +1236,15 @@ fn should_replace_a_delegator_with_unbonding() {
     update.assert_written_purse_is_unit(delegator2_bid_purse);
     update.assert_written_balance(delegator2_bid_purse, 14);
 
-    // 6 keys should be written:
+    // 7 keys should be written:
     // - seigniorage recipients
     // - total supply
     // - bid for validator 1
     // - unbonding purse for old delegator
     // - bonding purse for new delegator
     // - bonding purse balance for new delegator
-    assert_eq!(update.len(), 6);
+    // - removed record for old delegator
+    assert_eq!(update.len(), 7);
 }
 
 #[test]
@@ -1390,13 +1392,14 @@ fn should_remove_the_delegator() {
     // check that the old delegator's bid purse got zeroed
     update.assert_written_balance(delegator1_bid_purse, 0);
 
-    // 5 keys should be written:
+    // 6 keys should be written:
     // - seigniorage recipients
     // - total supply
     // - bid for validator 1
     // - bonding purse balance for validator 1
     // - bonding purse balance for delegator
-    assert_eq!(update.len(), 5);
+    // - removed record for delegator
+    assert_eq!(update.len(), 6);
 }
 
 #[test]
@@ -1478,13 +1481,14 @@ fn should_remove_the_delegator_with_unbonding() {
     // check that the unbonding purse got created
     update.assert_unbonding_purse(delegator1_bid_purse, &validator1, &delegator1, 13);
 
-    // 5 keys should be written:
+    // 6 keys should be written:
     // - seigniorage recipients
     // - total supply
     // - bid for validator 1
     // - bonding purse balance for validator 1
     // - unbonding purse for delegator
-    assert_eq!(update.len(), 5);
+    // - removed record for delegator
+    assert_eq!(update.len(), 6);
 }
 
 #[test]
@@ -1601,7 +1605,7 @@ fn should_slash_a_validator_and_delegator_with_enqueued_withdraws() {
     // check the withdraws under validator 1 are unchanged
     update.assert_key_absent(&Key::Withdraw(validator1.to_account_hash()));
 
-    // 7 keys should be written:
+    // 8 keys should be written:
     // - seigniorage recipients
     // - total supply
     // - bid for validator 2
@@ -1609,7 +1613,8 @@ fn should_slash_a_validator_and_delegator_with_enqueued_withdraws() {
     // - bonding purse balance for delegator 2
     // - bonding purse balance for past delegator 2
     // - empty WithdrawPurses for validator 2
-    assert_eq!(update.len(), 7);
+    // - removed record for delegator 2
+    assert_eq!(update.len(), 8);
 }
 
 #[test]
@@ -1726,7 +1731,7 @@ fn should_slash_a_validator_and_delegator_with_enqueued_unbonds() {
     // check the withdraws under validator 1 are unchanged
     update.assert_key_absent(&Key::Unbond(validator1.to_account_hash()));
 
-    // 7 keys should be written:
+    // 8 keys should be written:
     // - seigniorage recipients
     // - total supply
     // - bid for validator 2
@@ -1734,7 +1739,8 @@ fn should_slash_a_validator_and_delegator_with_enqueued_unbonds() {
     // - bonding purse balance for delegator 2
     // - bonding purse balance for past delegator 2
     // - empty UnbondingPurses for validator 2
-    assert_eq!(update.len(), 7);
+    // - removed record for delegator 2
+    assert_eq!(update.len(), 8);
 }
 
 #[test]
@@ -1987,7 +1993,7 @@ fn should_handle_unbonding_to_a_delegator_correctly() {
     update.assert_written_purse_is_unit(*bid_write.bonding_purse());
     update.assert_written_balance(*bid_write.bonding_purse(), NEW_STAKE);
 
-    // 10 keys should be written:
+    // 11 keys should be written:
     // - seigniorage recipients
     // - total supply
     // - bid for old validator
@@ -1998,7 +2004,8 @@ fn should_handle_unbonding_to_a_delegator_correctly() {
     // - bid for new validator
     // - bonding purse for new validator
     // - bonding purse balance for new validator
-    assert_eq!(update.len(), 10);
+    // - removed record for delegator
+    assert_eq!(update.len(), 11);
 }
 
 #[test]
@@ -2255,7 +2262,7 @@ fn should_handle_legacy_unbonding_to_a_delegator_correctly() {
     update.assert_written_purse_is_unit(*bid_write.bonding_purse());
     update.assert_written_balance(*bid_write.bonding_purse(), NEW_STAKE);
 
-    // 10 keys should be written:
+    // 11 keys should be written:
     // - seigniorage recipients
     // - total supply
     // - bid for old validator
@@ -2266,5 +2273,6 @@ fn should_handle_legacy_unbonding_to_a_delegator_correctly() {
     // - bid for new validator
     // - bonding purse for new validator
     // - bonding purse balance for new validator
-    assert_eq!(update.len(), 10);
+    // - removed record for delegator
+    assert_eq!(update.len(), 11);
 }
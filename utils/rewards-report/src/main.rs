@@ -0,0 +1,131 @@
+mod rpc_types;
+
+use clap::Parser;
+use serde_json::{json, Value};
+
+use casper_types::{AsymmetricType, EraId, PublicKey, U512};
+
+use rpc_types::{EraRewards, GetRewardsReportResult, RewardsReport};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Hex-encoded public key of the validator.
+    validator_public_key: String,
+    /// The first era of the report.
+    start_era: u64,
+    /// The last era of the report, inclusive.
+    end_era: u64,
+    /// Address of the node's JSON-RPC server.
+    #[arg(short, long, default_value = "http://localhost:7777")]
+    node_address: String,
+    /// Print the validator's rewards and performance in each era.
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let validator_public_key = PublicKey::from_hex(&args.validator_public_key)
+        .unwrap_or_else(|error| panic!("invalid validator public key: {}", error));
+
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "chain_get_rewards_report",
+        "params": {
+            "validator_public_key": validator_public_key,
+            "start_era_id": EraId::new(args.start_era),
+            "end_era_id": EraId::new(args.end_era),
+        }
+    });
+
+    let response: Value = reqwest::blocking::Client::new()
+        .post(format!("{}/rpc", args.node_address.trim_end_matches('/')))
+        .json(&request)
+        .send()
+        .and_then(|response| response.json())
+        .unwrap_or_else(|error| panic!("failed to query {}: {}", args.node_address, error));
+
+    if let Some(error) = response.get("error") {
+        eprintln!("the node returned an error: {}", error);
+        std::process::exit(1);
+    }
+
+    let result: GetRewardsReportResult = serde_json::from_value(response["result"].clone())
+        .unwrap_or_else(|error| panic!("failed to parse the node's response: {}", error));
+
+    print_summary(&result.rewards_report);
+
+    if args.verbose {
+        print_eras(&result.rewards_report.eras);
+    }
+}
+
+fn print_summary(report: &RewardsReport) {
+    let era_count = report.end_era_id.value() - report.start_era_id.value() + 1;
+    println!(
+        "Validator {} in eras {} to {}:",
+        report.validator_public_key.to_hex(),
+        report.start_era_id,
+        report.end_era_id
+    );
+    println!(
+        "  validator in {} of {} eras, inactive in {}, equivocated in {}",
+        report.eras_as_validator, era_count, report.eras_inactive, report.eras_equivocated
+    );
+    println!(
+        "  total validator reward: {}",
+        report.total_validator_reward
+    );
+
+    let total_delegator_reward = report
+        .total_delegator_rewards
+        .iter()
+        .fold(U512::zero(), |total, reward| total + reward.amount);
+    println!(
+        "  total delegator reward: {} across {} delegators",
+        total_delegator_reward,
+        report.total_delegator_rewards.len()
+    );
+
+    let mut delegator_rewards = report.total_delegator_rewards.iter().collect::<Vec<_>>();
+    delegator_rewards.sort_by(|reward1, reward2| reward2.amount.cmp(&reward1.amount));
+    for reward in delegator_rewards {
+        println!(
+            "    {}: {}",
+            reward.delegator_public_key.to_hex(),
+            reward.amount
+        );
+    }
+}
+
+fn print_eras(eras: &[EraRewards]) {
+    println!();
+    println!("era\tweight\treward factor\tvalidator reward\tdelegator reward\tfaults");
+    for era in eras {
+        let delegator_reward = era
+            .delegator_rewards
+            .iter()
+            .fold(U512::zero(), |total, reward| total + reward.amount);
+        let mut faults = vec![];
+        if era.inactive {
+            faults.push("inactive");
+        }
+        if era.equivocated {
+            faults.push("equivocated");
+        }
+        println!(
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            era.era_id,
+            era.validator_weight
+                .map_or_else(|| "-".to_string(), |weight| weight.to_string()),
+            era.reward_factor
+                .map_or_else(|| "-".to_string(), |factor| factor.to_string()),
+            era.validator_reward,
+            delegator_reward,
+            faults.join(", ")
+        );
+    }
+}
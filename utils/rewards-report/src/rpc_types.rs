@@ -0,0 +1,66 @@
+//! The parts of the node's "chain_get_rewards_report" RPC response used by the tool.
+//!
+//! These mirror the node's types, so the tool doesn't need to depend on the node itself.  Fields
+//! the tool doesn't use are ignored when deserializing.
+
+use serde::Deserialize;
+
+use casper_types::{EraId, PublicKey, U512};
+
+/// Result for "chain_get_rewards_report" RPC response.
+#[derive(Deserialize, Debug)]
+pub(crate) struct GetRewardsReportResult {
+    /// The validator's rewards and performance over the requested eras.
+    pub(crate) rewards_report: RewardsReport,
+}
+
+/// The seigniorage paid to a single delegator.
+#[derive(Deserialize, Debug)]
+pub(crate) struct DelegatorReward {
+    /// The delegator's public key.
+    pub(crate) delegator_public_key: PublicKey,
+    /// The amount paid to the delegator.
+    pub(crate) amount: U512,
+}
+
+/// A validator's rewards and performance in a single era.
+#[derive(Deserialize, Debug)]
+pub(crate) struct EraRewards {
+    /// The era id.
+    pub(crate) era_id: EraId,
+    /// The validator's weight in the era, if it was a validator and the weights are known.
+    pub(crate) validator_weight: Option<U512>,
+    /// The validator's reward factor passed to the auction's `distribute` entry point.
+    pub(crate) reward_factor: Option<u64>,
+    /// Whether the validator didn't produce any unit during the era.
+    pub(crate) inactive: bool,
+    /// Whether the validator equivocated during the era.
+    pub(crate) equivocated: bool,
+    /// The seigniorage paid to the validator itself.
+    pub(crate) validator_reward: U512,
+    /// The seigniorage paid to the validator's delegators.
+    pub(crate) delegator_rewards: Vec<DelegatorReward>,
+}
+
+/// A validator's rewards and performance over a range of eras.
+#[derive(Deserialize, Debug)]
+pub(crate) struct RewardsReport {
+    /// The validator's public key.
+    pub(crate) validator_public_key: PublicKey,
+    /// The first era of the range.
+    pub(crate) start_era_id: EraId,
+    /// The last era of the range, inclusive.
+    pub(crate) end_era_id: EraId,
+    /// The number of eras of the range in which the validator was part of the validator set.
+    pub(crate) eras_as_validator: u64,
+    /// The number of eras of the range in which the validator didn't produce any unit.
+    pub(crate) eras_inactive: u64,
+    /// The number of eras of the range in which the validator equivocated.
+    pub(crate) eras_equivocated: u64,
+    /// The total seigniorage paid to the validator itself over the range.
+    pub(crate) total_validator_reward: U512,
+    /// The total seigniorage paid to each of the validator's delegators over the range.
+    pub(crate) total_delegator_rewards: Vec<DelegatorReward>,
+    /// The validator's rewards and performance in each era of the range.
+    pub(crate) eras: Vec<EraRewards>,
+}